@@ -0,0 +1,25 @@
+//! Alexa side of the skill, independent of Cookidoo and AWS: the request
+//! and response models of the Alexa Skills Kit, the permissions a request
+//! grants, the state kept across a session's requests, and the catalog of
+//! everything the skill says.
+//!
+//! The handler answering requests on top of these lives in the
+//! `alexa-cookidoo-skill` crate, which re-exports them under
+//! `adapters::alexa`.
+
+use shopping_list_domain as domain;
+
+pub mod models;
+pub mod permission_checker;
+pub mod phrases;
+pub mod session_state;
+pub mod speech;
+
+pub use models::{
+    interfaces, AlexaRequest, AlexaResponse, Card, DialogState, Directive, OnCompletion,
+    UpdatedIntent,
+};
+pub use permission_checker::PermissionChecker;
+pub use phrases::{PhraseSelector, PhraseVariation};
+pub use session_state::{LastResponse, PendingAction, SessionState};
+pub use speech::{Language, MessageId, Plurality, Speech};
@@ -0,0 +1,1483 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::models::Secret;
+
+// ============================================================================
+// Request Models
+// ============================================================================
+
+/// Top-level Alexa request wrapper.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlexaRequest {
+    pub version: String,
+    pub session: Option<Session>,
+    pub context: Option<Context>,
+    pub request: Request,
+}
+
+impl AlexaRequest {
+    /// Returns the Alexa user ID.
+    ///
+    /// Skill events arrive without a session, so the request context is used
+    /// as a fallback.
+    pub fn user_id(&self) -> Option<&str> {
+        self.session
+            .as_ref()
+            .map(|session| session.user.user_id.as_str())
+            .or_else(|| {
+                self.context
+                    .as_ref()
+                    .map(|context| context.system.user.user_id.as_str())
+            })
+    }
+
+    /// Returns the account-linking access token of this request.
+    ///
+    /// A recognized speaker who linked their own account is served with
+    /// that account, so each voice in a household can use its own Cookidoo
+    /// account. Otherwise, like the user ID, the token is read from the
+    /// session or, for requests without one, from the context.
+    pub fn linked_access_token(&self) -> Option<&str> {
+        if let Some(token) = self
+            .person()
+            .and_then(|person| person.access_token.as_ref())
+        {
+            return Some(token.expose());
+        }
+
+        self.session
+            .as_ref()
+            .map(|session| &session.user)
+            .or_else(|| self.context.as_ref().map(|context| &context.system.user))
+            .and_then(|user| user.access_token.as_ref())
+            .map(Secret::expose)
+    }
+
+    /// Returns the Alexa API endpoint and access token of this request.
+    ///
+    /// Both are needed to call Alexa APIs (e.g. reminders) on behalf of the
+    /// user.
+    pub fn api_access(&self) -> Option<(&str, &str)> {
+        let system = &self.context.as_ref()?.system;
+        Some((
+            system.api_endpoint.as_deref()?,
+            system.api_access_token.as_ref()?.expose(),
+        ))
+    }
+
+    /// Returns the recognized speaker, if Alexa identified the voice.
+    pub fn person(&self) -> Option<&Person> {
+        self.context.as_ref()?.system.person.as_ref()
+    }
+
+    /// Returns the ID of the recognized speaker, if any.
+    pub fn person_id(&self) -> Option<&str> {
+        self.person().map(|person| person.person_id.as_str())
+    }
+
+    /// Returns the ID of the device the request came from.
+    pub fn device_id(&self) -> Option<&str> {
+        let device = self.context.as_ref()?.system.device.as_ref()?;
+        Some(device.device_id.as_str())
+    }
+
+    /// Returns true if the device supports the interface, e.g.
+    /// [`interfaces::APL`]; directives of other interfaces are rejected.
+    pub fn supports_interface(&self, interface: &str) -> bool {
+        self.context
+            .as_ref()
+            .and_then(|context| context.system.device.as_ref())
+            .is_some_and(|device| device.supported_interfaces.contains_key(interface))
+    }
+}
+
+/// Interface names as listed in the device's supported interfaces.
+pub mod interfaces {
+    pub const APL: &str = "Alexa.Presentation.APL";
+}
+
+/// Session information from Alexa.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Session {
+    pub new: bool,
+    pub session_id: String,
+    pub application: Application,
+    pub user: User,
+    /// Attributes returned by the previous response of this session.
+    #[serde(default)]
+    pub attributes: HashMap<String, serde_json::Value>,
+}
+
+/// Skill application information.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Application {
+    pub application_id: String,
+}
+
+/// User information.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct User {
+    pub user_id: String,
+    /// Account-linking token, here a Cookidoo bearer token.
+    #[serde(default)]
+    pub access_token: Option<Secret>,
+    /// Present once the user granted at least one skill permission.
+    #[serde(default)]
+    pub permissions: Option<UserPermissions>,
+}
+
+/// Permissions the user has granted to the skill.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserPermissions {
+    pub consent_token: Option<Secret>,
+    /// Per-scope status, keyed by scope name.
+    #[serde(default)]
+    pub scopes: HashMap<String, ScopeStatus>,
+}
+
+/// Grant status of a single permission scope.
+#[derive(Debug, Deserialize)]
+pub struct ScopeStatus {
+    /// "GRANTED" or "DENIED".
+    pub status: String,
+}
+
+/// Request context describing the calling device and user.
+#[derive(Debug, Deserialize)]
+pub struct Context {
+    #[serde(rename = "System")]
+    pub system: SystemContext,
+}
+
+/// System part of the request context.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SystemContext {
+    pub application: Option<Application>,
+    pub user: User,
+    pub api_endpoint: Option<String>,
+    pub api_access_token: Option<Secret>,
+    #[serde(default)]
+    pub device: Option<Device>,
+    /// Present when Alexa recognized the speaker's voice profile.
+    #[serde(default)]
+    pub person: Option<Person>,
+}
+
+/// Recognized speaker within the household account.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Person {
+    pub person_id: String,
+    /// Account-linking token of this person, present if they linked their
+    /// own account (here a Cookidoo bearer token).
+    #[serde(default)]
+    pub access_token: Option<Secret>,
+}
+
+/// Device the request came from.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Device {
+    pub device_id: String,
+    /// Interfaces such as APL the device supports, keyed by name.
+    #[serde(default)]
+    pub supported_interfaces: HashMap<String, serde_json::Value>,
+}
+
+/// Request type names as sent in the `type` field.
+mod request_types {
+    pub const LAUNCH: &str = "LaunchRequest";
+    pub const INTENT: &str = "IntentRequest";
+    pub const SESSION_ENDED: &str = "SessionEndedRequest";
+    pub const PERMISSION_ACCEPTED: &str = "AlexaSkillEvent.SkillPermissionAccepted";
+    pub const PERMISSION_CHANGED: &str = "AlexaSkillEvent.SkillPermissionChanged";
+    pub const SUBSCRIPTION_CHANGED: &str = "AlexaSkillEvent.ProactiveSubscriptionChanged";
+    pub const SKILL_ENABLED: &str = "AlexaSkillEvent.SkillEnabled";
+    pub const SKILL_DISABLED: &str = "AlexaSkillEvent.SkillDisabled";
+    pub const ACCOUNT_LINKED: &str = "AlexaSkillEvent.SkillAccountLinked";
+    pub const APL_USER_EVENT: &str = "Alexa.Presentation.APL.UserEvent";
+}
+
+/// Alexa request types.
+///
+/// Alexa adds request types over time; types the skill does not know are
+/// kept as [`Request::Unknown`] instead of failing the whole request.
+#[derive(Debug)]
+pub enum Request {
+    Launch(LaunchRequest),
+    Intent(IntentRequest),
+    SessionEnded(SessionEndedRequest),
+    PermissionAccepted(PermissionEventRequest),
+    PermissionChanged(PermissionEventRequest),
+    ProactiveSubscriptionChanged(SubscriptionEventRequest),
+    SkillEnabled(LifecycleEventRequest),
+    SkillDisabled(LifecycleEventRequest),
+    AccountLinked(LifecycleEventRequest),
+    AplUserEvent(AplUserEventRequest),
+    Unknown(UnknownRequest),
+}
+
+impl<'de> Deserialize<'de> for Request {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error;
+
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let request_type = value
+            .get("type")
+            .and_then(serde_json::Value::as_str)
+            .ok_or_else(|| D::Error::missing_field("type"))?
+            .to_string();
+
+        let request = match request_type.as_str() {
+            request_types::LAUNCH => serde_json::from_value(value).map(Request::Launch),
+            request_types::INTENT => serde_json::from_value(value).map(Request::Intent),
+            request_types::SESSION_ENDED => {
+                serde_json::from_value(value).map(Request::SessionEnded)
+            }
+            request_types::PERMISSION_ACCEPTED => {
+                serde_json::from_value(value).map(Request::PermissionAccepted)
+            }
+            request_types::PERMISSION_CHANGED => {
+                serde_json::from_value(value).map(Request::PermissionChanged)
+            }
+            request_types::SUBSCRIPTION_CHANGED => {
+                serde_json::from_value(value).map(Request::ProactiveSubscriptionChanged)
+            }
+            request_types::SKILL_ENABLED => {
+                serde_json::from_value(value).map(Request::SkillEnabled)
+            }
+            request_types::SKILL_DISABLED => {
+                serde_json::from_value(value).map(Request::SkillDisabled)
+            }
+            request_types::ACCOUNT_LINKED => {
+                serde_json::from_value(value).map(Request::AccountLinked)
+            }
+            request_types::APL_USER_EVENT => {
+                serde_json::from_value(value).map(Request::AplUserEvent)
+            }
+            _ => serde_json::from_value(value).map(Request::Unknown),
+        };
+        request.map_err(D::Error::custom)
+    }
+}
+
+/// Returns true for request types the skill handles itself rather than as
+/// [`Request::Unknown`].
+pub fn is_known_request_type(request_type: &str) -> bool {
+    matches!(
+        request_type,
+        request_types::LAUNCH
+            | request_types::INTENT
+            | request_types::SESSION_ENDED
+            | request_types::PERMISSION_ACCEPTED
+            | request_types::PERMISSION_CHANGED
+            | request_types::SUBSCRIPTION_CHANGED
+            | request_types::SKILL_ENABLED
+            | request_types::SKILL_DISABLED
+            | request_types::ACCOUNT_LINKED
+            | request_types::APL_USER_EVENT
+    )
+}
+
+impl Request {
+    /// Returns the unique ID Alexa assigned to this request.
+    ///
+    /// The ID stays the same when Alexa re-delivers the request.
+    pub fn request_id(&self) -> &str {
+        match self {
+            Request::Launch(request) => &request.request_id,
+            Request::Intent(request) => &request.request_id,
+            Request::SessionEnded(request) => &request.request_id,
+            Request::PermissionAccepted(event) | Request::PermissionChanged(event) => {
+                &event.request_id
+            }
+            Request::ProactiveSubscriptionChanged(event) => &event.request_id,
+            Request::SkillEnabled(event)
+            | Request::SkillDisabled(event)
+            | Request::AccountLinked(event) => &event.request_id,
+            Request::AplUserEvent(event) => &event.request_id,
+            Request::Unknown(request) => &request.request_id,
+        }
+    }
+
+    /// Returns the ISO 8601 timestamp at which Alexa sent this request.
+    pub fn timestamp(&self) -> &str {
+        match self {
+            Request::Launch(request) => &request.timestamp,
+            Request::Intent(request) => &request.timestamp,
+            Request::SessionEnded(request) => &request.timestamp,
+            Request::PermissionAccepted(event) | Request::PermissionChanged(event) => {
+                &event.timestamp
+            }
+            Request::ProactiveSubscriptionChanged(event) => &event.timestamp,
+            Request::SkillEnabled(event)
+            | Request::SkillDisabled(event)
+            | Request::AccountLinked(event) => &event.timestamp,
+            Request::AplUserEvent(event) => &event.timestamp,
+            Request::Unknown(request) => &request.timestamp,
+        }
+    }
+
+    /// Returns the user's locale; skill events carry none.
+    pub fn locale(&self) -> Option<&str> {
+        match self {
+            Request::Launch(request) => Some(&request.locale),
+            Request::Intent(request) => Some(&request.locale),
+            Request::SessionEnded(request) => Some(&request.locale),
+            Request::AplUserEvent(event) => Some(&event.locale),
+            Request::Unknown(request) => request.locale.as_deref(),
+            Request::PermissionAccepted(_)
+            | Request::PermissionChanged(_)
+            | Request::ProactiveSubscriptionChanged(_)
+            | Request::SkillEnabled(_)
+            | Request::SkillDisabled(_)
+            | Request::AccountLinked(_) => None,
+        }
+    }
+}
+
+/// Touch event sent by an APL document, e.g. tapping an item on an Echo Show.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AplUserEventRequest {
+    pub request_id: String,
+    pub timestamp: String,
+    pub locale: String,
+    /// Token of the APL document that sent the event.
+    #[serde(default)]
+    pub token: Option<String>,
+    /// Arguments of the document's `SendEvent` command.
+    #[serde(default)]
+    pub arguments: Vec<serde_json::Value>,
+}
+
+/// A request of a type the skill does not handle, e.g. a new skill event.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnknownRequest {
+    /// The raw request type, e.g. `Alexa.Presentation.HTML.Message`.
+    #[serde(rename = "type")]
+    pub request_type: String,
+    pub request_id: String,
+    pub timestamp: String,
+    #[serde(default)]
+    pub locale: Option<String>,
+}
+
+/// Launch request when user opens the skill.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LaunchRequest {
+    pub request_id: String,
+    pub timestamp: String,
+    pub locale: String,
+}
+
+/// Intent request when user speaks a command.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntentRequest {
+    pub request_id: String,
+    pub timestamp: String,
+    pub locale: String,
+    /// Progress of a multi-turn dialog defined in the interaction model;
+    /// absent for intents without a dialog model.
+    #[serde(default)]
+    pub dialog_state: Option<DialogState>,
+    pub intent: Intent,
+    /// What the user said, which Alexa only includes in some locales.
+    #[serde(default)]
+    pub input: Option<UtteranceInput>,
+}
+
+impl IntentRequest {
+    /// Returns the transcribed utterance, if Alexa sent it.
+    pub fn utterance(&self) -> Option<&str> {
+        self.input
+            .as_ref()
+            .map(|input| input.text.trim())
+            .filter(|text| !text.is_empty())
+    }
+}
+
+/// The transcribed utterance of an intent request.
+#[derive(Debug, Clone, Deserialize)]
+pub struct UtteranceInput {
+    pub text: String,
+}
+
+/// Progress of a dialog Alexa manages for an intent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum DialogState {
+    Started,
+    InProgress,
+    Completed,
+}
+
+/// Session ended request.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionEndedRequest {
+    pub request_id: String,
+    pub timestamp: String,
+    pub locale: String,
+    /// `USER_INITIATED`, `ERROR` or `EXCEEDED_MAX_REPROMPTS`.
+    pub reason: String,
+    /// Details when the session ended because of an error.
+    #[serde(default)]
+    pub error: Option<SessionEndedError>,
+}
+
+/// Error that made Alexa end a session, e.g. an invalid skill response.
+#[derive(Debug, Deserialize)]
+pub struct SessionEndedError {
+    /// Error type such as `INVALID_RESPONSE` or `INTERNAL_SERVICE_ERROR`.
+    #[serde(rename = "type")]
+    pub error_type: String,
+    #[serde(default)]
+    pub message: Option<String>,
+}
+
+/// Skill event sent when the user grants or changes permissions.
+///
+/// The body always lists the complete set of currently accepted scopes.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PermissionEventRequest {
+    pub request_id: String,
+    pub timestamp: String,
+    #[serde(default)]
+    pub body: PermissionEventBody,
+}
+
+/// Body of a permission skill event.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PermissionEventBody {
+    #[serde(default)]
+    pub accepted_permissions: Vec<AcceptedPermission>,
+}
+
+/// A single accepted permission scope.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AcceptedPermission {
+    pub scope: String,
+}
+
+/// Skill event sent when the user changes proactive event subscriptions.
+///
+/// The body lists all current subscriptions; it is empty or missing when
+/// the user unsubscribed from everything.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubscriptionEventRequest {
+    pub request_id: String,
+    pub timestamp: String,
+    #[serde(default)]
+    pub body: SubscriptionEventBody,
+}
+
+/// Body of a proactive subscription skill event.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubscriptionEventBody {
+    #[serde(default)]
+    pub subscriptions: Vec<Subscription>,
+}
+
+/// Skill event sent when the user enables, disables or links the skill.
+///
+/// The body of `SkillAccountLinked` carries the new access token; it is
+/// not read here, the skill uses the token of each request instead.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LifecycleEventRequest {
+    pub request_id: String,
+    pub timestamp: String,
+    #[serde(default)]
+    pub body: LifecycleEventBody,
+}
+
+impl LifecycleEventRequest {
+    /// Returns true if Alexa keeps the user ID after the skill was
+    /// disabled, so the user's data is still theirs on re-enabling.
+    pub fn keeps_user_information(&self) -> bool {
+        self.body.user_information_persistence_status.as_deref() == Some("PERSISTED")
+    }
+}
+
+/// Body of a skill lifecycle event.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LifecycleEventBody {
+    /// `PERSISTED` or `NOT_PERSISTED`; only sent with `SkillDisabled`.
+    #[serde(default)]
+    pub user_information_persistence_status: Option<String>,
+}
+
+/// A single proactive event subscription.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Subscription {
+    pub event_name: String,
+}
+
+/// Intent with name and slots.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Intent {
+    pub name: String,
+    #[serde(default)]
+    pub confirmation_status: ConfirmationStatus,
+    #[serde(default)]
+    pub slots: HashMap<String, Slot>,
+}
+
+/// Entity resolution status code of a match against a slot type's values.
+const ER_SUCCESS_MATCH: &str = "ER_SUCCESS_MATCH";
+
+/// Slot value from user speech.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Slot {
+    pub name: String,
+    pub value: Option<String>,
+    #[serde(default)]
+    pub confirmation_status: ConfirmationStatus,
+    /// Entity resolution of the value against custom slot values and
+    /// synonyms; absent for built-in types without custom values.
+    #[serde(default)]
+    pub resolutions: Option<Resolutions>,
+}
+
+impl Slot {
+    /// Returns the canonical value entity resolution matched, e.g.
+    /// "Brötchen" for a spoken "Semmel", if any authority matched.
+    pub fn resolved_value(&self) -> Option<&str> {
+        self.resolutions
+            .as_ref()?
+            .resolutions_per_authority
+            .iter()
+            .filter(|resolution| resolution.status.code == ER_SUCCESS_MATCH)
+            .flat_map(|resolution| &resolution.values)
+            .map(|value| value.value.name.as_str())
+            .find(|name| !name.is_empty())
+    }
+
+    /// Returns the value as recognized. A value the user denied in a
+    /// confirmation counts as absent.
+    pub fn spoken_value(&self) -> Option<&str> {
+        if self.confirmation_status == ConfirmationStatus::Denied {
+            return None;
+        }
+        self.value.as_deref()
+    }
+
+    /// Returns the value to act on: the resolved canonical value if there
+    /// is one, else the value as recognized. A value the user denied in a
+    /// confirmation counts as absent.
+    pub fn best_value(&self) -> Option<&str> {
+        self.spoken_value()?;
+        self.resolved_value().or(self.value.as_deref())
+    }
+}
+
+/// Whether the user confirmed a slot value or intent in a dialog.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ConfirmationStatus {
+    Confirmed,
+    Denied,
+    /// Not asked, or a status added after this skill was built.
+    #[default]
+    #[serde(other)]
+    None,
+}
+
+/// Entity resolution results of a slot, one per authority (the slot type,
+/// and dynamic entities if the skill set any).
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Resolutions {
+    #[serde(default)]
+    pub resolutions_per_authority: Vec<Resolution>,
+}
+
+/// Entity resolution result of one authority.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Resolution {
+    pub authority: String,
+    pub status: ResolutionStatus,
+    #[serde(default)]
+    pub values: Vec<ResolutionValue>,
+}
+
+/// Status of an entity resolution, e.g. `ER_SUCCESS_MATCH` or
+/// `ER_SUCCESS_NO_MATCH`.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResolutionStatus {
+    pub code: String,
+}
+
+/// Wrapper of a resolved value.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResolutionValue {
+    pub value: ResolvedValue,
+}
+
+/// A slot type value matched by entity resolution.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResolvedValue {
+    pub name: String,
+    #[serde(default)]
+    pub id: Option<String>,
+}
+
+// ============================================================================
+// Response Models
+// ============================================================================
+
+/// Top-level Alexa response.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlexaResponse {
+    pub version: String,
+    /// Attributes Alexa sends back with the next request of the session.
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    pub session_attributes: HashMap<String, serde_json::Value>,
+    pub response: ResponseBody,
+}
+
+impl AlexaResponse {
+    /// Adds a directive after those already in the response.
+    pub fn with_directive(mut self, directive: Directive) -> Self {
+        self.response.directives.push(directive);
+        self
+    }
+
+    /// Sets the session attributes returned to Alexa.
+    pub fn with_session_attributes(
+        mut self,
+        attributes: HashMap<String, serde_json::Value>,
+    ) -> Self {
+        self.session_attributes = attributes;
+        self
+    }
+}
+
+/// Response body containing speech and session control.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResponseBody {
+    #[serde(skip_serializing_if = "OutputSpeech::is_omitted")]
+    pub output_speech: OutputSpeech,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reprompt: Option<Reprompt>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub card: Option<Card>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub directives: Vec<Directive>,
+    pub should_end_session: bool,
+}
+
+/// Directive asking Alexa to take over part of the conversation, show a
+/// screen or hand the user to another skill or service.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type")]
+pub enum Directive {
+    /// Lets Alexa continue the intent's dialog as defined in the
+    /// interaction model: elicit missing required slots, confirm slots and
+    /// the intent. The response must not contain speech.
+    #[serde(rename = "Dialog.Delegate", rename_all = "camelCase")]
+    DialogDelegate {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        updated_intent: Option<UpdatedIntent>,
+    },
+    /// Shows an APL document on devices with a screen; only allowed if the
+    /// device supports [`interfaces::APL`]. Touch events name the token.
+    #[serde(rename = "Alexa.Presentation.APL.RenderDocument")]
+    AplRenderDocument {
+        token: String,
+        document: serde_json::Value,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        datasources: Option<serde_json::Value>,
+    },
+    /// Starts a task provided by Alexa, e.g. an in-skill purchase (`Buy`);
+    /// the result arrives as a `Connections.Response` request.
+    #[serde(rename = "Connections.SendRequest")]
+    ConnectionsSendRequest {
+        name: String,
+        payload: serde_json::Value,
+        token: String,
+    },
+    /// Starts a task of another skill or of Alexa identified by `uri`, e.g.
+    /// `connection://AMAZON.PrintPDF/1`.
+    #[serde(rename = "Connections.StartConnection", rename_all = "camelCase")]
+    ConnectionsStartConnection {
+        uri: String,
+        input: serde_json::Value,
+        token: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        on_completion: Option<OnCompletion>,
+    },
+}
+
+impl Directive {
+    /// Returns true for directives of the `Dialog` interface, which need
+    /// an open session.
+    pub fn is_dialog(&self) -> bool {
+        matches!(self, Directive::DialogDelegate { .. })
+    }
+
+    /// Returns true for directives of the `Connections` interface, which
+    /// hand the session over and must not be combined with a reprompt.
+    pub fn is_connection(&self) -> bool {
+        matches!(
+            self,
+            Directive::ConnectionsSendRequest { .. } | Directive::ConnectionsStartConnection { .. }
+        )
+    }
+}
+
+/// What happens after a task started with
+/// [`Directive::ConnectionsStartConnection`] completes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum OnCompletion {
+    /// Alexa sends the result back to this skill.
+    ResumeSession,
+    /// Only failures are sent back.
+    SendErrorsOnly,
+}
+
+/// Intent sent back with a dialog directive, e.g. with slot values the
+/// skill filled in or changed.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdatedIntent {
+    pub name: String,
+    pub confirmation_status: ConfirmationStatus,
+    pub slots: HashMap<String, UpdatedSlot>,
+}
+
+/// Slot of an [`UpdatedIntent`].
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdatedSlot {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    pub confirmation_status: ConfirmationStatus,
+}
+
+impl From<&Intent> for UpdatedIntent {
+    /// Echoes the intent as received, with the recognized slot values.
+    fn from(intent: &Intent) -> Self {
+        Self {
+            name: intent.name.clone(),
+            confirmation_status: intent.confirmation_status,
+            slots: intent
+                .slots
+                .iter()
+                .map(|(name, slot)| {
+                    (
+                        name.clone(),
+                        UpdatedSlot {
+                            name: slot.name.clone(),
+                            value: slot.value.clone(),
+                            confirmation_status: slot.confirmation_status,
+                        },
+                    )
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Card shown in the Alexa app alongside the spoken response.
+#[derive(Debug, Serialize)]
+#[serde(tag = "type")]
+pub enum Card {
+    /// Shows plain text in the Alexa app.
+    Simple { title: String, content: String },
+    /// Shows text in the Alexa app with the layout of a standard card,
+    /// which keeps its line breaks.
+    Standard { title: String, text: String },
+    /// Asks the user to grant the listed permission scopes.
+    AskForPermissionsConsent { permissions: Vec<String> },
+}
+
+/// Speech played when the user does not answer while the session is open.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Reprompt {
+    pub output_speech: OutputSpeech,
+}
+
+/// Output speech in plain text or SSML format.
+///
+/// `text` holds the markup for SSML speech; it is serialized as `ssml`
+/// then, as Alexa expects.
+#[derive(Debug)]
+pub struct OutputSpeech {
+    pub speech_type: String,
+    pub text: String,
+}
+
+impl OutputSpeech {
+    /// Speech type of plain text output.
+    pub const PLAIN_TEXT: &'static str = "PlainText";
+
+    /// Speech type of SSML output.
+    pub const SSML: &'static str = "SSML";
+
+    /// Creates a plain text output speech.
+    pub fn plain_text(text: impl Into<String>) -> Self {
+        Self {
+            speech_type: Self::PLAIN_TEXT.to_string(),
+            text: text.into(),
+        }
+    }
+
+    /// Creates an SSML output speech from `<speak>` markup.
+    pub fn ssml(ssml: impl Into<String>) -> Self {
+        Self {
+            speech_type: Self::SSML.to_string(),
+            text: ssml.into(),
+        }
+    }
+
+    /// Returns true if the speech is SSML markup.
+    pub fn is_ssml(&self) -> bool {
+        self.speech_type == Self::SSML
+    }
+
+    /// Creates a speech that is left out of the response, as required
+    /// next to a `Dialog.Delegate` directive.
+    pub fn omitted() -> Self {
+        Self {
+            speech_type: String::new(),
+            text: String::new(),
+        }
+    }
+
+    /// Returns true if the speech is left out of the response.
+    pub fn is_omitted(&self) -> bool {
+        self.speech_type.is_empty()
+    }
+}
+
+impl Serialize for OutputSpeech {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let mut speech = serializer.serialize_struct("OutputSpeech", 2)?;
+        speech.serialize_field("type", &self.speech_type)?;
+        let field = if self.is_ssml() { "ssml" } else { "text" };
+        speech.serialize_field(field, &self.text)?;
+        speech.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserializes_launch_request() {
+        let json = r#"{
+            "version": "1.0",
+            "session": {
+                "new": true,
+                "sessionId": "session-123",
+                "application": {"applicationId": "app-123"},
+                "user": {"userId": "user-123"}
+            },
+            "request": {
+                "type": "LaunchRequest",
+                "requestId": "req-123",
+                "timestamp": "2024-01-27T10:00:00Z",
+                "locale": "de-DE"
+            }
+        }"#;
+
+        let request: AlexaRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(request.version, "1.0");
+        assert!(matches!(request.request, Request::Launch(_)));
+    }
+
+    #[test]
+    fn deserializes_intent_request_with_slot() {
+        let json = r#"{
+            "version": "1.0",
+            "session": {
+                "new": false,
+                "sessionId": "session-123",
+                "application": {"applicationId": "app-123"},
+                "user": {"userId": "user-123"}
+            },
+            "request": {
+                "type": "IntentRequest",
+                "requestId": "req-123",
+                "timestamp": "2024-01-27T10:00:00Z",
+                "locale": "de-DE",
+                "intent": {
+                    "name": "AddItemIntent",
+                    "slots": {
+                        "Item": {
+                            "name": "Item",
+                            "value": "Milch"
+                        }
+                    }
+                }
+            }
+        }"#;
+
+        let request: AlexaRequest = serde_json::from_str(json).unwrap();
+        if let Request::Intent(intent_req) = &request.request {
+            assert_eq!(intent_req.intent.name, "AddItemIntent");
+            assert_eq!(
+                intent_req.intent.slots.get("Item").unwrap().value,
+                Some("Milch".to_string())
+            );
+        } else {
+            panic!("Expected IntentRequest");
+        }
+    }
+
+    fn slot(json: &str) -> Slot {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn deserializes_slot_resolutions() {
+        let slot = slot(
+            r#"{
+                "name": "Item",
+                "value": "semmeln",
+                "confirmationStatus": "CONFIRMED",
+                "resolutions": {
+                    "resolutionsPerAuthority": [{
+                        "authority": "amzn1.er-authority.echo-sdk.skill-123.AMAZON.Food",
+                        "status": {"code": "ER_SUCCESS_MATCH"},
+                        "values": [{"value": {"name": "Brötchen", "id": "abc"}}]
+                    }]
+                }
+            }"#,
+        );
+
+        assert_eq!(slot.confirmation_status, ConfirmationStatus::Confirmed);
+        assert_eq!(slot.resolved_value(), Some("Brötchen"));
+        assert_eq!(slot.best_value(), Some("Brötchen"));
+    }
+
+    #[test]
+    fn falls_back_to_spoken_value_without_match() {
+        let slot = slot(
+            r#"{
+                "name": "Item",
+                "value": "Kardamom",
+                "resolutions": {
+                    "resolutionsPerAuthority": [{
+                        "authority": "amzn1.er-authority.echo-sdk.skill-123.AMAZON.Food",
+                        "status": {"code": "ER_SUCCESS_NO_MATCH"}
+                    }]
+                }
+            }"#,
+        );
+
+        assert_eq!(slot.confirmation_status, ConfirmationStatus::None);
+        assert_eq!(slot.resolved_value(), None);
+        assert_eq!(slot.best_value(), Some("Kardamom"));
+    }
+
+    #[test]
+    fn denied_slot_has_no_value() {
+        let slot = slot(r#"{"name": "Item", "value": "Milch", "confirmationStatus": "DENIED"}"#);
+
+        assert_eq!(slot.best_value(), None);
+    }
+
+    #[test]
+    fn deserializes_intent_request_without_slots() {
+        let json = r#"{
+            "version": "1.0",
+            "request": {
+                "type": "IntentRequest",
+                "requestId": "req-123",
+                "timestamp": "2024-01-27T10:00:00Z",
+                "locale": "de-DE",
+                "intent": {
+                    "name": "AMAZON.HelpIntent"
+                }
+            }
+        }"#;
+
+        let request: AlexaRequest = serde_json::from_str(json).unwrap();
+        if let Request::Intent(intent_req) = &request.request {
+            assert_eq!(intent_req.intent.name, "AMAZON.HelpIntent");
+            assert!(intent_req.intent.slots.is_empty());
+        } else {
+            panic!("Expected IntentRequest");
+        }
+    }
+
+    #[test]
+    fn deserializes_dialog_state_and_intent_confirmation() {
+        let json = r#"{
+            "version": "1.0",
+            "request": {
+                "type": "IntentRequest",
+                "requestId": "req-123",
+                "timestamp": "2024-01-27T10:00:00Z",
+                "locale": "de-DE",
+                "dialogState": "IN_PROGRESS",
+                "intent": {
+                    "name": "AddItemIntent",
+                    "confirmationStatus": "CONFIRMED",
+                    "slots": {
+                        "Item": {"name": "Item", "value": "Milch", "confirmationStatus": "NONE"}
+                    }
+                }
+            }
+        }"#;
+
+        let request: AlexaRequest = serde_json::from_str(json).unwrap();
+        let Request::Intent(intent_req) = &request.request else {
+            panic!("Expected IntentRequest");
+        };
+        assert_eq!(intent_req.dialog_state, Some(DialogState::InProgress));
+        assert_eq!(
+            intent_req.intent.confirmation_status,
+            ConfirmationStatus::Confirmed
+        );
+    }
+
+    #[test]
+    fn deserializes_permission_accepted_event() {
+        let json = r#"{
+            "version": "1.0",
+            "context": {
+                "System": {
+                    "application": {"applicationId": "app-123"},
+                    "user": {"userId": "user-123"},
+                    "apiEndpoint": "https://api.eu.amazonalexa.com"
+                }
+            },
+            "request": {
+                "type": "AlexaSkillEvent.SkillPermissionAccepted",
+                "requestId": "req-123",
+                "timestamp": "2024-01-27T10:00:00Z",
+                "body": {
+                    "acceptedPermissions": [
+                        {"scope": "alexa::alerts:reminders:skill:readwrite"}
+                    ]
+                }
+            }
+        }"#;
+
+        let request: AlexaRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(request.user_id(), Some("user-123"));
+        if let Request::PermissionAccepted(event) = &request.request {
+            assert_eq!(
+                event.body.accepted_permissions[0].scope,
+                "alexa::alerts:reminders:skill:readwrite"
+            );
+        } else {
+            panic!("Expected SkillPermissionAccepted event");
+        }
+    }
+
+    #[test]
+    fn deserializes_subscription_event_without_body() {
+        let json = r#"{
+            "version": "1.0",
+            "context": {
+                "System": {"user": {"userId": "user-123"}}
+            },
+            "request": {
+                "type": "AlexaSkillEvent.ProactiveSubscriptionChanged",
+                "requestId": "req-123",
+                "timestamp": "2024-01-27T10:00:00Z"
+            }
+        }"#;
+
+        let request: AlexaRequest = serde_json::from_str(json).unwrap();
+        if let Request::ProactiveSubscriptionChanged(event) = &request.request {
+            assert!(event.body.subscriptions.is_empty());
+        } else {
+            panic!("Expected ProactiveSubscriptionChanged event");
+        }
+    }
+
+    #[test]
+    fn user_id_prefers_session_user() {
+        let json = r#"{
+            "version": "1.0",
+            "session": {
+                "new": true,
+                "sessionId": "session-123",
+                "application": {"applicationId": "app-123"},
+                "user": {"userId": "session-user"}
+            },
+            "context": {
+                "System": {"user": {"userId": "context-user"}}
+            },
+            "request": {
+                "type": "LaunchRequest",
+                "requestId": "req-123",
+                "timestamp": "2024-01-27T10:00:00Z",
+                "locale": "de-DE"
+            }
+        }"#;
+
+        let request: AlexaRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(request.user_id(), Some("session-user"));
+    }
+
+    #[test]
+    fn reads_linked_access_token() {
+        let json = r#"{
+            "version": "1.0",
+            "session": {
+                "new": true,
+                "sessionId": "session-123",
+                "application": {"applicationId": "app-123"},
+                "user": {"userId": "user-123", "accessToken": "cookidoo-token"}
+            },
+            "request": {
+                "type": "LaunchRequest",
+                "requestId": "req-123",
+                "timestamp": "2024-01-27T10:00:00Z",
+                "locale": "de-DE"
+            }
+        }"#;
+
+        let request: AlexaRequest = serde_json::from_str(json).unwrap();
+
+        assert_eq!(request.linked_access_token(), Some("cookidoo-token"));
+    }
+
+    #[test]
+    fn prefers_linked_token_of_recognized_speaker() {
+        let json = r#"{
+            "version": "1.0",
+            "session": {
+                "new": true,
+                "sessionId": "session-123",
+                "application": {"applicationId": "app-123"},
+                "user": {"userId": "user-123", "accessToken": "household-token"}
+            },
+            "context": {
+                "System": {
+                    "user": {"userId": "user-123", "accessToken": "household-token"},
+                    "person": {"personId": "person-1", "accessToken": "person-token"}
+                }
+            },
+            "request": {
+                "type": "LaunchRequest",
+                "requestId": "req-123",
+                "timestamp": "2024-01-27T10:00:00Z",
+                "locale": "de-DE"
+            }
+        }"#;
+
+        let request: AlexaRequest = serde_json::from_str(json).unwrap();
+
+        assert_eq!(request.person_id(), Some("person-1"));
+        assert_eq!(request.linked_access_token(), Some("person-token"));
+    }
+
+    #[test]
+    fn api_access_requires_endpoint_and_token() {
+        let json = r#"{
+            "version": "1.0",
+            "context": {
+                "System": {
+                    "user": {"userId": "user-123"},
+                    "apiEndpoint": "https://api.eu.amazonalexa.com",
+                    "apiAccessToken": "token-123"
+                }
+            },
+            "request": {
+                "type": "LaunchRequest",
+                "requestId": "req-123",
+                "timestamp": "2024-01-27T10:00:00Z",
+                "locale": "de-DE"
+            }
+        }"#;
+
+        let mut request: AlexaRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            request.api_access(),
+            Some(("https://api.eu.amazonalexa.com", "token-123"))
+        );
+
+        request.context.as_mut().unwrap().system.api_access_token = None;
+        assert_eq!(request.api_access(), None);
+    }
+
+    #[test]
+    fn api_access_ignores_person_access_token() {
+        let json = r#"{
+            "version": "1.0",
+            "context": {
+                "System": {
+                    "user": {"userId": "user-123"},
+                    "person": {"personId": "person-1", "accessToken": "person-token"},
+                    "apiEndpoint": "https://api.eu.amazonalexa.com",
+                    "apiAccessToken": "token-123"
+                }
+            },
+            "request": {
+                "type": "LaunchRequest",
+                "requestId": "req-123",
+                "timestamp": "2024-01-27T10:00:00Z",
+                "locale": "de-DE"
+            }
+        }"#;
+
+        let request: AlexaRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            request.api_access(),
+            Some(("https://api.eu.amazonalexa.com", "token-123"))
+        );
+    }
+
+    #[test]
+    fn deserializes_session_ended_error() {
+        let json = r#"{
+            "version": "1.0",
+            "request": {
+                "type": "SessionEndedRequest",
+                "requestId": "req-123",
+                "timestamp": "2024-01-27T10:00:00Z",
+                "locale": "de-DE",
+                "reason": "ERROR",
+                "error": {"type": "INVALID_RESPONSE", "message": "Invalid SSML"}
+            }
+        }"#;
+
+        let request: AlexaRequest = serde_json::from_str(json).unwrap();
+        let Request::SessionEnded(ended) = request.request else {
+            panic!("expected session ended request");
+        };
+        assert_eq!(ended.reason, "ERROR");
+        let error = ended.error.unwrap();
+        assert_eq!(error.error_type, "INVALID_RESPONSE");
+        assert_eq!(error.message.as_deref(), Some("Invalid SSML"));
+    }
+
+    #[test]
+    fn serializes_ssml_speech_under_ssml_key() {
+        let json = serde_json::to_value(OutputSpeech::ssml("<speak>Hallo</speak>")).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({"type": "SSML", "ssml": "<speak>Hallo</speak>"})
+        );
+    }
+
+    #[test]
+    fn keeps_unknown_request_type() {
+        let json = r#"{
+            "version": "1.0",
+            "request": {
+                "type": "Alexa.Presentation.HTML.Message",
+                "requestId": "req-123",
+                "timestamp": "2024-01-27T10:00:00Z",
+                "locale": "de-DE",
+                "message": {"action": "refresh"}
+            }
+        }"#;
+
+        let request: AlexaRequest = serde_json::from_str(json).unwrap();
+
+        let Request::Unknown(unknown) = &request.request else {
+            panic!("expected unknown request");
+        };
+        assert_eq!(unknown.request_type, "Alexa.Presentation.HTML.Message");
+        assert_eq!(request.request.request_id(), "req-123");
+        assert_eq!(request.request.locale(), Some("de-DE"));
+    }
+
+    #[test]
+    fn deserializes_apl_user_event() {
+        let json = r#"{
+            "version": "1.0",
+            "request": {
+                "type": "Alexa.Presentation.APL.UserEvent",
+                "requestId": "req-123",
+                "timestamp": "2024-01-27T10:00:00Z",
+                "locale": "de-DE",
+                "token": "shoppingList",
+                "arguments": ["checkOff", "id-1"],
+                "source": {"type": "TouchWrapper", "handler": "Press"}
+            }
+        }"#;
+
+        let request: AlexaRequest = serde_json::from_str(json).unwrap();
+
+        let Request::AplUserEvent(event) = &request.request else {
+            panic!("expected APL user event");
+        };
+        assert_eq!(event.token.as_deref(), Some("shoppingList"));
+        assert_eq!(event.arguments, vec!["checkOff", "id-1"]);
+    }
+
+    #[test]
+    fn rejects_known_request_type_with_invalid_body() {
+        let json = r#"{
+            "version": "1.0",
+            "request": {"type": "IntentRequest", "requestId": "req-123"}
+        }"#;
+
+        assert!(serde_json::from_str::<AlexaRequest>(json).is_err());
+    }
+
+    #[test]
+    fn serializes_response() {
+        let response = AlexaResponse {
+            version: "1.0".to_string(),
+            session_attributes: HashMap::new(),
+            response: ResponseBody {
+                output_speech: OutputSpeech::plain_text("Hello"),
+                reprompt: None,
+                card: None,
+                directives: Vec::new(),
+                should_end_session: true,
+            },
+        };
+
+        let json = serde_json::to_string(&response).unwrap();
+        assert!(json.contains("\"version\":\"1.0\""));
+        assert!(json.contains("\"text\":\"Hello\""));
+        assert!(json.contains("\"shouldEndSession\":true"));
+        assert!(!json.contains("reprompt"));
+        assert!(!json.contains("card"));
+        assert!(!json.contains("sessionAttributes"));
+    }
+
+    #[test]
+    fn serializes_delegate_with_updated_intent() {
+        let intent: Intent = serde_json::from_value(serde_json::json!({
+            "name": "AddItemIntent",
+            "slots": {"Item": {"name": "Item", "value": "Milch"}}
+        }))
+        .unwrap();
+        let directive = Directive::DialogDelegate {
+            updated_intent: Some(UpdatedIntent::from(&intent)),
+        };
+
+        let json = serde_json::to_value(&directive).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "type": "Dialog.Delegate",
+                "updatedIntent": {
+                    "name": "AddItemIntent",
+                    "confirmationStatus": "NONE",
+                    "slots": {
+                        "Item": {"name": "Item", "value": "Milch", "confirmationStatus": "NONE"}
+                    }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn serializes_apl_and_connection_directives() {
+        let render = Directive::AplRenderDocument {
+            token: "shopping-list".to_string(),
+            document: serde_json::json!({"type": "APL"}),
+            datasources: None,
+        };
+        assert_eq!(
+            serde_json::to_value(&render).unwrap(),
+            serde_json::json!({
+                "type": "Alexa.Presentation.APL.RenderDocument",
+                "token": "shopping-list",
+                "document": {"type": "APL"}
+            })
+        );
+
+        let start = Directive::ConnectionsStartConnection {
+            uri: "connection://AMAZON.PrintPDF/1".to_string(),
+            input: serde_json::json!({}),
+            token: "print".to_string(),
+            on_completion: Some(OnCompletion::SendErrorsOnly),
+        };
+        let json = serde_json::to_value(&start).unwrap();
+        assert_eq!(json["type"], "Connections.StartConnection");
+        assert_eq!(json["onCompletion"], "SEND_ERRORS_ONLY");
+        assert!(start.is_connection() && !start.is_dialog());
+    }
+
+    #[test]
+    fn reads_supported_interfaces() {
+        let request: AlexaRequest = serde_json::from_value(serde_json::json!({
+            "version": "1.0",
+            "context": {"System": {
+                "user": {"userId": "user-1"},
+                "device": {
+                    "deviceId": "device-1",
+                    "supportedInterfaces": {"Alexa.Presentation.APL": {"runtime": {}}}
+                }
+            }},
+            "request": {
+                "type": "LaunchRequest",
+                "requestId": "req-1",
+                "timestamp": "2024-01-27T10:00:00Z",
+                "locale": "de-DE"
+            }
+        }))
+        .unwrap();
+        assert!(request.supports_interface(interfaces::APL));
+        assert!(!request.supports_interface("Alexa.Presentation.HTML"));
+    }
+
+    #[test]
+    fn omitted_speech_is_not_serialized() {
+        let body = ResponseBody {
+            output_speech: OutputSpeech::omitted(),
+            reprompt: None,
+            card: None,
+            directives: vec![Directive::DialogDelegate {
+                updated_intent: None,
+            }],
+            should_end_session: false,
+        };
+
+        let json = serde_json::to_string(&body).unwrap();
+        assert!(!json.contains("outputSpeech"));
+    }
+
+    #[test]
+    fn serializes_permission_card() {
+        let card = Card::AskForPermissionsConsent {
+            permissions: vec!["alexa::alerts:reminders:skill:readwrite".to_string()],
+        };
+
+        let json = serde_json::to_value(&card).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "type": "AskForPermissionsConsent",
+                "permissions": ["alexa::alerts:reminders:skill:readwrite"]
+            })
+        );
+    }
+}
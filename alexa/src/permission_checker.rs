@@ -0,0 +1,140 @@
+use super::models::{AlexaRequest, User};
+
+/// Status Alexa reports for a granted scope.
+const GRANTED: &str = "GRANTED";
+
+/// Checks which permissions the user granted, based on the request itself.
+///
+/// Alexa lists granted scopes in `context.System.user.permissions`. Features
+/// that call Alexa APIs check this before the call, so a missing scope can
+/// be answered with a consent card instead of a failed request.
+pub struct PermissionChecker;
+
+impl PermissionChecker {
+    /// Returns true if the user granted `scope` to the skill.
+    ///
+    /// Requests without per-scope status fall back to the consent token,
+    /// which Alexa only sends once the user granted the skill's permissions.
+    pub fn is_granted(request: &AlexaRequest, scope: &str) -> bool {
+        let Some(permissions) = Self::user(request).and_then(|user| user.permissions.as_ref())
+        else {
+            return false;
+        };
+
+        match permissions.scopes.get(scope) {
+            Some(scope_status) => scope_status.status == GRANTED,
+            None => permissions.consent_token.is_some(),
+        }
+    }
+
+    /// Returns the scopes from `scopes` the user has not granted yet.
+    pub fn missing<'a>(request: &AlexaRequest, scopes: &[&'a str]) -> Vec<&'a str> {
+        scopes
+            .iter()
+            .copied()
+            .filter(|scope| !Self::is_granted(request, scope))
+            .collect()
+    }
+
+    /// The context user is authoritative; the session user is a fallback
+    /// for requests without context.
+    fn user(request: &AlexaRequest) -> Option<&User> {
+        request
+            .context
+            .as_ref()
+            .map(|context| &context.system.user)
+            .or_else(|| request.session.as_ref().map(|session| &session.user))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REMINDERS: &str = "alexa::alerts:reminders:skill:readwrite";
+    const LISTS: &str = "read::alexa:household:list";
+
+    fn make_request(user_json: &str) -> AlexaRequest {
+        let json = format!(
+            r#"{{
+                "version": "1.0",
+                "context": {{"System": {{"user": {user_json}}}}},
+                "request": {{
+                    "type": "LaunchRequest",
+                    "requestId": "req-123",
+                    "timestamp": "2024-01-27T10:00:00Z",
+                    "locale": "de-DE"
+                }}
+            }}"#
+        );
+        serde_json::from_str(&json).unwrap()
+    }
+
+    #[test]
+    fn granted_scope_is_granted() {
+        let request = make_request(
+            r#"{
+                "userId": "user-123",
+                "permissions": {
+                    "consentToken": "token",
+                    "scopes": {
+                        "alexa::alerts:reminders:skill:readwrite": {"status": "GRANTED"}
+                    }
+                }
+            }"#,
+        );
+        assert!(PermissionChecker::is_granted(&request, REMINDERS));
+    }
+
+    #[test]
+    fn denied_scope_is_missing() {
+        let request = make_request(
+            r#"{
+                "userId": "user-123",
+                "permissions": {
+                    "consentToken": "token",
+                    "scopes": {
+                        "alexa::alerts:reminders:skill:readwrite": {"status": "DENIED"}
+                    }
+                }
+            }"#,
+        );
+        assert!(!PermissionChecker::is_granted(&request, REMINDERS));
+    }
+
+    #[test]
+    fn consent_token_is_fallback_without_scope_status() {
+        let request =
+            make_request(r#"{"userId": "user-123", "permissions": {"consentToken": "token"}}"#);
+        assert!(PermissionChecker::is_granted(&request, REMINDERS));
+    }
+
+    #[test]
+    fn no_permissions_means_nothing_granted() {
+        let request = make_request(r#"{"userId": "user-123"}"#);
+        assert!(!PermissionChecker::is_granted(&request, REMINDERS));
+        assert_eq!(
+            PermissionChecker::missing(&request, &[REMINDERS, LISTS]),
+            vec![REMINDERS, LISTS]
+        );
+    }
+
+    #[test]
+    fn missing_lists_only_ungranted_scopes() {
+        let request = make_request(
+            r#"{
+                "userId": "user-123",
+                "permissions": {
+                    "scopes": {
+                        "alexa::alerts:reminders:skill:readwrite": {"status": "GRANTED"},
+                        "read::alexa:household:list": {"status": "DENIED"}
+                    }
+                }
+            }"#,
+        );
+        assert_eq!(
+            PermissionChecker::missing(&request, &[REMINDERS, LISTS]),
+            vec![LISTS]
+        );
+    }
+}
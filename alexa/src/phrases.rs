@@ -0,0 +1,179 @@
+use super::speech::Language;
+
+/// How phrasings are chosen for a request.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PhraseVariation {
+    /// A fresh random choice for every request.
+    #[default]
+    Random,
+    /// Derived from the session ID, so a phrasing stays the same for the
+    /// whole conversation but differs between sessions.
+    PerSession,
+    /// Derived from a fixed seed regardless of the session, so tests can
+    /// assert exact speech; seed 0 always picks the first phrasing.
+    Seeded(u64),
+}
+
+impl PhraseVariation {
+    /// Parses `random`, `session` or a numeric seed.
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "random" => Some(Self::Random),
+            "session" => Some(Self::PerSession),
+            seed => seed.parse().ok().map(Self::Seeded),
+        }
+    }
+
+    /// Returns the selector for a request in the given session.
+    pub fn selector(self, session_id: Option<&str>) -> PhraseSelector {
+        match self {
+            Self::Random => PhraseSelector::random(),
+            Self::PerSession => PhraseSelector::for_session(session_id),
+            Self::Seeded(seed) => PhraseSelector::seeded(seed),
+        }
+    }
+}
+
+/// Chooses among alternative phrasings of the same message.
+///
+/// The choice is derived from a seed; the default selector always gets the
+/// first phrasing.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PhraseSelector {
+    seed: u64,
+    language: Language,
+}
+
+impl PhraseSelector {
+    /// Creates a selector for the given session. Requests without a
+    /// session always get the first phrasing.
+    pub fn for_session(session_id: Option<&str>) -> Self {
+        Self::seeded(session_id.map(fnv1a).unwrap_or_default())
+    }
+
+    /// Creates a selector with a random seed.
+    pub fn random() -> Self {
+        // Without a random source, vary by time rather than not at all
+        let seed = getrandom::u64().unwrap_or_else(|_| {
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|elapsed| u64::from(elapsed.subsec_nanos()))
+                .unwrap_or_default()
+        });
+        Self::seeded(seed)
+    }
+
+    /// Creates a selector with a fixed seed.
+    pub fn seeded(seed: u64) -> Self {
+        Self {
+            seed,
+            language: Language::default(),
+        }
+    }
+
+    /// Phrases in the language of the request locale.
+    pub fn with_locale(mut self, locale: Option<&str>) -> Self {
+        self.language = locale.map(Language::from_locale).unwrap_or_default();
+        self
+    }
+
+    /// Returns the language phrasings are picked in.
+    pub fn language(&self) -> Language {
+        self.language
+    }
+
+    /// Picks one phrasing from a non-empty pool.
+    ///
+    /// Each pool is varied independently, keyed by its first entry.
+    pub fn pick(&self, pool: &'static [&'static str]) -> &'static str {
+        if self.seed == 0 || pool.len() < 2 {
+            return pool[0];
+        }
+
+        let index = (self.seed ^ fnv1a(pool[0])) % pool.len() as u64;
+        pool[index as usize]
+    }
+}
+
+/// FNV-1a hash; stable across processes and Rust versions, unlike the std hasher.
+fn fnv1a(value: &str) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    value.bytes().fold(OFFSET_BASIS, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(PRIME)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const POOL: &[&str] = &["eins", "zwei", "drei", "vier"];
+
+    #[test]
+    fn without_session_picks_first_phrase() {
+        let selector = PhraseSelector::for_session(None);
+        assert_eq!(selector.pick(POOL), "eins");
+    }
+
+    #[test]
+    fn selection_is_stable_within_session() {
+        let first = PhraseSelector::for_session(Some("session-1")).pick(POOL);
+        let second = PhraseSelector::for_session(Some("session-1")).pick(POOL);
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn selection_varies_across_sessions() {
+        let picks: std::collections::HashSet<_> = (0..50)
+            .map(|i| PhraseSelector::for_session(Some(&format!("session-{i}"))).pick(POOL))
+            .collect();
+        assert!(picks.len() > 1);
+    }
+
+    #[test]
+    fn seeded_selection_ignores_session() {
+        let seeded = PhraseVariation::Seeded(42);
+        assert_eq!(
+            seeded.selector(Some("session-1")).pick(POOL),
+            seeded.selector(Some("session-2")).pick(POOL)
+        );
+        assert_eq!(PhraseVariation::Seeded(0).selector(None).pick(POOL), "eins");
+    }
+
+    #[test]
+    fn random_selection_varies_across_requests() {
+        let picks: std::collections::HashSet<_> = (0..50)
+            .map(|_| {
+                PhraseVariation::Random
+                    .selector(Some("session-1"))
+                    .pick(POOL)
+            })
+            .collect();
+        assert!(picks.len() > 1);
+    }
+
+    #[test]
+    fn parses_variations() {
+        assert_eq!(
+            PhraseVariation::parse("Random"),
+            Some(PhraseVariation::Random)
+        );
+        assert_eq!(
+            PhraseVariation::parse("session"),
+            Some(PhraseVariation::PerSession)
+        );
+        assert_eq!(
+            PhraseVariation::parse("7"),
+            Some(PhraseVariation::Seeded(7))
+        );
+        assert_eq!(PhraseVariation::parse("sometimes"), None);
+    }
+
+    #[test]
+    fn single_phrase_pool_always_returns_it() {
+        let selector = PhraseSelector::for_session(Some("session-1"));
+        assert_eq!(selector.pick(&["nur"]), "nur");
+    }
+}
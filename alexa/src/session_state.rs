@@ -0,0 +1,284 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::models::AlexaRequest;
+use crate::domain::models::ListName;
+
+/// Session attribute holding the pending action.
+const PENDING_ACTION_ATTRIBUTE: &str = "pendingAction";
+
+/// Session attribute holding the last spoken response.
+const LAST_RESPONSE_ATTRIBUTE: &str = "lastResponse";
+
+/// Session attribute holding the name the user was greeted with.
+const GREETING_ATTRIBUTE: &str = "greeting";
+
+/// An action the skill asked the user about and is waiting to complete.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PendingAction {
+    /// The skill offered to add something and waits for the item.
+    AddItem,
+    /// The skill added an item and asked "Noch etwas?"; another item name
+    /// is added to the same list, "Nein" ends the session. `list` is the
+    /// occasion list the item went to, `None` for the shopping list.
+    AddMore {
+        #[serde(default, skip_serializing_if = "Option::is_none", with = "list_key")]
+        list: Option<ListName>,
+    },
+    /// The skill read part of a long list and asked whether to go on from
+    /// the entry at `offset`; `open_only` if only entries not yet checked
+    /// off were read.
+    ContinueReading {
+        offset: usize,
+        #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+        open_only: bool,
+    },
+    /// The skill asked whether to delete the user's stored data.
+    DeleteMyData,
+}
+
+/// A response that kept the session open, kept so it can be repeated.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LastResponse {
+    pub speech: String,
+    pub reprompt: String,
+}
+
+/// The given name read from the Alexa profile at launch, kept for the rest
+/// of the session so a repeated launch does not read it again.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Greeting {
+    /// The recognized speaker the name was read for, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub person_id: Option<String>,
+    /// The given name, or `None` if it is not available.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// Conversation state carried between turns in Alexa session attributes.
+///
+/// Alexa echoes the attributes of a response in the next request of the
+/// same session. State that a response does not return is gone on the next
+/// turn, so every response starts from a clean slate unless it opts in.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SessionState {
+    pending: Option<PendingAction>,
+    last_response: Option<LastResponse>,
+    greeting: Option<Greeting>,
+}
+
+impl SessionState {
+    /// Reads the state from the session attributes of a request.
+    ///
+    /// Missing or unrecognized attributes yield an empty state.
+    pub fn from_request(request: &AlexaRequest) -> Self {
+        match &request.session {
+            Some(session) => Self::from_attributes(&session.attributes),
+            None => Self::default(),
+        }
+    }
+
+    /// Reads the state from session attributes.
+    pub fn from_attributes(attributes: &HashMap<String, Value>) -> Self {
+        Self {
+            pending: attribute(attributes, PENDING_ACTION_ATTRIBUTE),
+            last_response: attribute(attributes, LAST_RESPONSE_ATTRIBUTE),
+            greeting: attribute(attributes, GREETING_ATTRIBUTE),
+        }
+    }
+
+    /// Creates a state waiting for the given action.
+    pub fn pending(action: PendingAction) -> Self {
+        Self {
+            pending: Some(action),
+            last_response: None,
+            greeting: None,
+        }
+    }
+
+    /// Remembers the given response for a later repeat.
+    pub fn with_last_response(
+        mut self,
+        speech: impl Into<String>,
+        reprompt: impl Into<String>,
+    ) -> Self {
+        self.last_response = Some(LastResponse {
+            speech: speech.into(),
+            reprompt: reprompt.into(),
+        });
+        self
+    }
+
+    /// Keeps the given greeting for the rest of the session.
+    pub fn with_greeting(mut self, greeting: Greeting) -> Self {
+        self.greeting = Some(greeting);
+        self
+    }
+
+    /// Returns the action the skill is waiting for, if any.
+    pub fn pending_action(&self) -> Option<PendingAction> {
+        self.pending.clone()
+    }
+
+    /// Returns the last response that kept the session open, if any.
+    pub fn last_response(&self) -> Option<&LastResponse> {
+        self.last_response.as_ref()
+    }
+
+    /// Returns the greeting read earlier in the session, if any.
+    pub fn greeting(&self) -> Option<&Greeting> {
+        self.greeting.as_ref()
+    }
+
+    /// Converts the state into session attributes for a response.
+    pub fn to_attributes(&self) -> HashMap<String, Value> {
+        let mut attributes = HashMap::new();
+        if let Some(action) = &self.pending {
+            attributes.insert(
+                PENDING_ACTION_ATTRIBUTE.to_string(),
+                serde_json::to_value(action).expect("pending action serializes"),
+            );
+        }
+        if let Some(last_response) = &self.last_response {
+            attributes.insert(
+                LAST_RESPONSE_ATTRIBUTE.to_string(),
+                serde_json::to_value(last_response).expect("last response serializes"),
+            );
+        }
+        if let Some(greeting) = &self.greeting {
+            attributes.insert(
+                GREETING_ATTRIBUTE.to_string(),
+                serde_json::to_value(greeting).expect("greeting serializes"),
+            );
+        }
+        attributes
+    }
+}
+
+/// Keeps a list name in the session as its storage key.
+mod list_key {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::domain::models::ListName;
+
+    pub fn serialize<S: Serializer>(
+        list: &Option<ListName>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        list.as_ref().map(ListName::key).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<ListName>, D::Error> {
+        Ok(Option::<String>::deserialize(deserializer)?
+            .as_deref()
+            .and_then(ListName::new))
+    }
+}
+
+/// Deserializes a single attribute, treating malformed values as absent.
+fn attribute<T: serde::de::DeserializeOwned>(
+    attributes: &HashMap<String, Value>,
+    name: &str,
+) -> Option<T> {
+    attributes
+        .get(name)
+        .and_then(|value| serde_json::from_value(value.clone()).ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request_with_attributes(attributes: &str) -> AlexaRequest {
+        serde_json::from_str(&format!(
+            r#"{{
+                "version": "1.0",
+                "session": {{
+                    "new": false,
+                    "sessionId": "session-123",
+                    "application": {{"applicationId": "skill-123"}},
+                    "user": {{"userId": "user-123"}},
+                    "attributes": {attributes}
+                }},
+                "request": {{
+                    "type": "LaunchRequest",
+                    "requestId": "req-123",
+                    "timestamp": "2024-01-27T10:00:00Z",
+                    "locale": "de-DE"
+                }}
+            }}"#
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn round_trips_pending_action() {
+        let attributes = SessionState::pending(PendingAction::AddItem).to_attributes();
+        let request = request_with_attributes(&serde_json::to_string(&attributes).unwrap());
+
+        let state = SessionState::from_request(&request);
+
+        assert_eq!(state.pending_action(), Some(PendingAction::AddItem));
+    }
+
+    #[test]
+    fn round_trips_list_of_add_more() {
+        let list = ListName::new("Geburtstagsliste");
+        let state = SessionState::pending(PendingAction::AddMore { list: list.clone() });
+
+        let restored = SessionState::from_attributes(&state.to_attributes());
+
+        assert_eq!(
+            restored.pending_action(),
+            Some(PendingAction::AddMore { list })
+        );
+    }
+
+    #[test]
+    fn round_trips_last_response_with_pending_action() {
+        let state = SessionState::pending(PendingAction::AddItem).with_last_response(
+            "Deine Einkaufsliste ist leer.",
+            "Was möchtest du hinzufügen?",
+        );
+
+        let restored = SessionState::from_attributes(&state.to_attributes());
+
+        assert_eq!(restored, state);
+        assert_eq!(
+            restored.last_response().unwrap().speech,
+            "Deine Einkaufsliste ist leer."
+        );
+    }
+
+    #[test]
+    fn round_trips_greeting() {
+        let state = SessionState::default().with_greeting(Greeting {
+            person_id: Some("person-1".to_string()),
+            name: Some("Anna".to_string()),
+        });
+
+        assert_eq!(SessionState::from_attributes(&state.to_attributes()), state);
+    }
+
+    #[test]
+    fn empty_state_has_no_attributes() {
+        assert!(SessionState::default().to_attributes().is_empty());
+    }
+
+    #[test]
+    fn unknown_pending_action_is_ignored() {
+        let request = request_with_attributes(r#"{"pendingAction": "launchRocket"}"#);
+
+        assert_eq!(
+            SessionState::from_request(&request),
+            SessionState::default()
+        );
+    }
+}
@@ -0,0 +1,507 @@
+use super::phrases::PhraseSelector;
+
+/// Language of a message catalog.
+///
+/// The skill is published for the German-speaking markets only, so every
+/// locale resolves to German; a further language gets a variant here and
+/// a catalog in [`templates`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Language {
+    #[default]
+    German,
+}
+
+impl Language {
+    /// Returns the catalog language for a locale such as "de-AT", falling
+    /// back to German.
+    pub fn from_locale(locale: &str) -> Self {
+        match locale.split(['-', '_']).next() {
+            Some("de") => Self::German,
+            _ => Self::default(),
+        }
+    }
+}
+
+/// Grammatical number a message is worded for.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Plurality {
+    One,
+    #[default]
+    Other,
+}
+
+impl Plurality {
+    /// Returns the plurality of `count` things.
+    pub fn of(count: usize) -> Self {
+        match count {
+            1 => Self::One,
+            _ => Self::Other,
+        }
+    }
+}
+
+/// Identifies a message independently of its wording.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MessageId {
+    Welcome,
+    WelcomeBrief,
+    Greeting,
+    Help,
+    Goodbye,
+    Cancelled,
+    CancelledReprompt,
+    NothingToRepeat,
+    Maintenance,
+    Unknown,
+    Reprompt,
+    Acknowledgement,
+    BriefConfirmation,
+    AddMore,
+    ItemAdded,
+    ItemsAdded,
+    InvalidItemName,
+    ItemNotOnList,
+    ItemRemoved,
+    ItemCheckedOff,
+    AuthFailed,
+    AddUnavailable,
+    CookidooMaintenance,
+    RateLimited,
+    ListFull,
+    PermissionDenied,
+    PermissionRequired,
+    Unreachable,
+    InternalError,
+    ErrorCardTitle,
+    ErrorCardContent,
+    ListCardTitle,
+    ListCardContent,
+    ItemCount,
+    CheckedOffCount,
+    AllCheckedOff,
+    OpenCategories,
+    ShoppingListLabel,
+    ListEmpty,
+    ListEmptyReprompt,
+    NamedListEmpty,
+    ListEntries,
+    ListOverview,
+    OpenEntries,
+    OpenOverview,
+    NothingOpen,
+    ReadOnward,
+    ContinueReading,
+    ContinueReadingReprompt,
+    NothingLeft,
+    HistoryEmpty,
+    History,
+    AddWhat,
+    RequestInvalid,
+    ReadFailed,
+    RemoveFailed,
+    NoItemAtPosition,
+    ItemGone,
+    OccasionItemAdded,
+    OccasionItemRemoved,
+    OccasionItemNotOnList,
+    OccasionListUnavailable,
+    TransferEmpty,
+    TransferOnlyStaples,
+    TransferFailed,
+    TransferredItem,
+    TransferredAll,
+    TransferredSome,
+    StaplesSkipped,
+    HistoryUnavailable,
+    ReminderText,
+    ReminderPermission,
+    ReminderConsent,
+    ReminderSet,
+    ReminderScheduled,
+    ReminderFailed,
+    Days,
+    Hours,
+    Minutes,
+    NoReminders,
+    NoUserList,
+    NoUserHistory,
+    NoUserSettings,
+    SpeechStyleBrief,
+    SpeechStyleFull,
+    DefaultListSet,
+    DefaultListCleared,
+    LocaleSet,
+    RegionGermany,
+    RegionAustria,
+    RegionSwitzerland,
+    AccountSet,
+    HouseholdAccountSet,
+    SettingSaveFailed,
+    NoUserBundle,
+    NoUserData,
+    NoLinkedAccountList,
+    NoLinkedAccountBundle,
+    AlexaOnly,
+    UnknownAccount,
+    UnrecognizedSpeaker,
+    UnknownBundle,
+    BundleCreated,
+    BundleExtended,
+    AlreadyInBundle,
+    BundleDeleted,
+    BundleConfigured,
+    BundleItems,
+    BundleNames,
+    NoBundles,
+    BundlesUnavailable,
+    DeleteDataQuestion,
+    DeleteDataReprompt,
+    DataDeleted,
+    DeleteDataFailed,
+}
+
+/// A message with the values of its `{placeholders}`.
+///
+/// Rendering picks one of the message's variants with the
+/// [`PhraseSelector`], in the selector's language and the message's
+/// plurality, so "Alles klar", "Okay" and "Erledigt" alternate between
+/// sessions without the callers knowing the wording.
+#[derive(Debug, Clone)]
+pub struct Speech {
+    id: MessageId,
+    plurality: Plurality,
+    values: Vec<(&'static str, String)>,
+}
+
+impl Speech {
+    /// Creates the message `id` without values.
+    pub fn new(id: MessageId) -> Self {
+        Self {
+            id,
+            plurality: Plurality::default(),
+            values: Vec::new(),
+        }
+    }
+
+    /// Sets the value of the placeholder `{name}`.
+    pub fn with(mut self, name: &'static str, value: impl Into<String>) -> Self {
+        self.values.push((name, value.into()));
+        self
+    }
+
+    /// Words the message for `count` things and sets `{count}`.
+    pub fn counting(mut self, count: usize) -> Self {
+        self.plurality = Plurality::of(count);
+        self.with("count", count.to_string())
+    }
+
+    /// Renders the message in the selector's language.
+    pub fn render(&self, phrases: &PhraseSelector) -> String {
+        let template = phrases.pick(templates(self.id, phrases.language(), self.plurality));
+        fill(template, &self.values)
+    }
+
+    /// Renders the message in the first variant of the default language,
+    /// for answers outside an Alexa session.
+    pub fn render_default(&self) -> String {
+        self.render(&PhraseSelector::default())
+    }
+}
+
+/// Replaces each `{name}` in `template` with its value, in a single pass so
+/// values containing braces stay as they are. Unknown placeholders are kept.
+fn fill(template: &str, values: &[(&str, String)]) -> String {
+    let mut text = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        text.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        let value = after.find('}').and_then(|end| {
+            let name = &after[..end];
+            values
+                .iter()
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| (value, end))
+        });
+        match value {
+            Some((value, end)) => {
+                text.push_str(value);
+                rest = &after[end + 1..];
+            }
+            None => {
+                text.push('{');
+                rest = after;
+            }
+        }
+    }
+    text.push_str(rest);
+    text
+}
+
+/// Returns the variants of a message.
+fn templates(id: MessageId, language: Language, plurality: Plurality) -> &'static [&'static str] {
+    match language {
+        Language::German => german(id, plurality),
+    }
+}
+
+/// German catalog.
+fn german(id: MessageId, plurality: Plurality) -> &'static [&'static str] {
+    use MessageId::*;
+
+    match (id, plurality) {
+        (Welcome, _) => &["Willkommen bei der Cookidoo Einkaufsliste. \
+            Du kannst Artikel hinzufügen, indem du zum Beispiel sagst: \
+            Füge Milch hinzu."],
+        (WelcomeBrief, _) => &["Cookidoo Einkaufsliste. Was brauchst du?"],
+        (Greeting, _) => &["Hallo {name}!"],
+        (Help, _) => &[
+            "Du kannst Artikel zu deiner Cookidoo Einkaufsliste hinzufügen. \
+            Sage zum Beispiel: Füge Milch hinzu, oder: Ich brauche Eier. \
+            Was möchtest du hinzufügen?",
+        ],
+        (Goodbye, _) => &["Auf Wiedersehen!"],
+        (Cancelled, _) => &["Okay, abgebrochen. Was möchtest du sonst tun?"],
+        (CancelledReprompt, _) => &["Was möchtest du sonst tun?"],
+        (NothingToRepeat, _) => {
+            &["Ich habe gerade nichts gesagt, das ich wiederholen könnte. Was möchtest du tun?"]
+        }
+        (Maintenance, _) => &["Ich werde gerade aktualisiert, versuch es in ein paar Minuten."],
+        (Unknown, _) => &[
+            "Das habe ich leider nicht verstanden. \
+            Bitte sage zum Beispiel: Füge Milch hinzu.",
+            "Entschuldige, das habe ich nicht verstanden. \
+            Sage zum Beispiel: Ich brauche Eier.",
+            "Hm, das habe ich nicht verstanden. \
+            Versuch es zum Beispiel mit: Setze Butter auf die Liste.",
+        ],
+        (Reprompt, _) => &[
+            "Was möchtest du hinzufügen?",
+            "Welchen Artikel soll ich auf die Liste setzen?",
+            "Was darf auf die Einkaufsliste?",
+        ],
+        (Acknowledgement, _) => &["", "Alles klar. ", "Okay. ", "Erledigt. "],
+        (BriefConfirmation, _) => &["Okay, {item}."],
+        (AddMore, _) => &["Noch etwas?"],
+        (ItemAdded, _) => &["{item} wurde zur Einkaufsliste hinzugefügt."],
+        (ItemsAdded, Plurality::One) => &["{items} wurde zur Einkaufsliste hinzugefügt."],
+        (ItemsAdded, Plurality::Other) => &["{items} wurden zur Einkaufsliste hinzugefügt."],
+        (InvalidItemName, _) => &["Der Artikelname ist ungültig: {reason}"],
+        (ItemNotOnList, _) => &["{item} steht nicht auf deiner Einkaufsliste."],
+        (ItemRemoved, _) => &["{item} wurde von der Einkaufsliste entfernt."],
+        (ItemCheckedOff, _) => &["{item} wurde abgehakt."],
+        (AuthFailed, _) => &["Die Anmeldung bei Cookidoo ist fehlgeschlagen. \
+            Bitte überprüfe deine Zugangsdaten."],
+        (AddUnavailable, _) => {
+            &["Der Artikel konnte nicht hinzugefügt werden. Bitte versuche es später erneut."]
+        }
+        (CookidooMaintenance, _) => &["Cookidoo ist gerade wegen Wartungsarbeiten \
+            nicht erreichbar. Bitte versuche es später erneut."],
+        (RateLimited, _) => &["Cookidoo bekommt gerade zu viele Anfragen. \
+            Bitte versuche es in einer Minute erneut."],
+        (ListFull, _) => &["Deine Einkaufsliste ist voll. \
+            Bitte entferne zuerst ein paar Artikel in der Cookidoo App."],
+        (PermissionDenied, _) => &["Cookidoo verweigert den Zugriff auf deine \
+            Einkaufsliste. Bitte prüfe in der Cookidoo App, ob dein Abo aktiv ist."],
+        (PermissionRequired, _) => {
+            &["Dafür fehlt mir eine Berechtigung. Bitte erteile sie in der Alexa App."]
+        }
+        (Unreachable, _) => {
+            &["Cookidoo antwortet gerade nicht. Bitte versuche es gleich noch einmal."]
+        }
+        (InternalError, _) => {
+            &["Da ist bei mir etwas schiefgelaufen. Bitte versuche es gleich noch einmal."]
+        }
+        (ErrorCardTitle, _) => &["Cookidoo Einkaufsliste: Fehler"],
+        (ErrorCardContent, _) => &["{message}\n\nFehlercode: {code}"],
+        (ListCardTitle, _) => &["Cookidoo Einkaufsliste"],
+        (ListCardContent, _) => &["{message}\n\nZuletzt auf der Liste:\n{items}"],
+        (ItemCount, Plurality::One) => &["Du hast einen Artikel auf der Liste"],
+        (ItemCount, Plurality::Other) => &["Du hast {count} Artikel auf der Liste"],
+        (CheckedOffCount, Plurality::One) => &[", davon einer bereits abgehakt"],
+        (CheckedOffCount, Plurality::Other) => &[", davon {count} bereits abgehakt"],
+        (AllCheckedOff, Plurality::One) => &[", und er ist bereits abgehakt"],
+        (AllCheckedOff, Plurality::Other) => &[", alle bereits abgehakt"],
+        (OpenCategories, _) => &[" Offen sind vor allem {categories}."],
+        (ShoppingListLabel, _) => &["Einkaufsliste"],
+        (ListEmpty, _) => &["Deine Einkaufsliste ist leer. \
+            Du kannst zum Beispiel sagen: Füge Milch hinzu."],
+        (ListEmptyReprompt, _) => &[
+            "Was möchtest du hinzufügen?",
+            "Soll ich etwas auf die Liste setzen?",
+        ],
+        (NamedListEmpty, _) => &["Deine {list} ist leer. \
+            Du kannst zum Beispiel sagen: Setz Kerzen auf die {list}."],
+        (ListEntries, Plurality::One) => &["Auf deiner {list} steht: {items}."],
+        (ListEntries, Plurality::Other) => &["Auf deiner {list} stehen: {items}."],
+        (ListOverview, _) => &["Auf deiner Einkaufsliste stehen {count} Artikel, zuerst: {items}."],
+        (OpenEntries, Plurality::One) => &["Es fehlt noch: {items}."],
+        (OpenEntries, Plurality::Other) => &["Es fehlen noch: {items}."],
+        (OpenOverview, _) => &["Es fehlen noch {count} Artikel, zuerst: {items}."],
+        (NothingOpen, _) => &["Es fehlt nichts mehr, auf deiner Einkaufsliste ist alles abgehakt."],
+        (ReadOnward, _) => &["Weiter geht's: {items}."],
+        (ContinueReading, _) => &["{text} Soll ich weiterlesen?"],
+        (ContinueReadingReprompt, _) => &["Soll ich weiterlesen?"],
+        (NothingLeft, _) => &["Das waren alle Artikel auf deiner Einkaufsliste."],
+        (HistoryEmpty, _) => &["{period} hast du noch nichts hinzugefügt."],
+        (History, _) => &["{period} hast du {items} hinzugefügt."],
+        (AddWhat, _) => &["Was möchtest du hinzufügen?"],
+        (RequestInvalid, _) => &["Fehler beim Verarbeiten der Anfrage."],
+        (ReadFailed, _) => {
+            &["Die Einkaufsliste konnte nicht gelesen werden. Bitte versuche es später erneut."]
+        }
+        (RemoveFailed, _) => {
+            &["Der Artikel konnte nicht entfernt werden. Bitte versuche es später erneut."]
+        }
+        (NoItemAtPosition, _) => &["An dieser Stelle steht nichts auf deiner Einkaufsliste."],
+        (ItemGone, _) => &["Dieser Artikel steht nicht mehr auf deiner Einkaufsliste."],
+        (OccasionItemAdded, _) => &["{item} steht jetzt auf deiner {list}."],
+        (OccasionItemRemoved, _) => &["{item} wurde von deiner {list} entfernt."],
+        (OccasionItemNotOnList, _) => &["{item} steht nicht auf deiner {list}."],
+        (OccasionListUnavailable, _) => {
+            &["Deine {list} ist gerade nicht erreichbar. Bitte versuche es später erneut."]
+        }
+        (TransferEmpty, _) => &["Deine {list} ist leer."],
+        (TransferOnlyStaples, _) => &["Auf deiner {list} steht nur, was du immer da hast: \
+            {items}. Ich habe nichts auf die Einkaufsliste übertragen."],
+        (TransferFailed, _) => {
+            &["Die Einkaufsliste ist gerade nicht erreichbar. Deine {list} bleibt unverändert."]
+        }
+        (TransferredItem, _) => &["Ich habe {item} von deiner {list} auf die Einkaufsliste \
+            übertragen."],
+        (TransferredAll, _) => &["Ich habe {count} Artikel von deiner {list} auf die \
+            Einkaufsliste übertragen."],
+        (TransferredSome, _) => &[
+            "Ich habe {count} von {total} Artikeln auf die Einkaufsliste \
+            übertragen. Der Rest steht noch auf deiner {list}.",
+        ],
+        (StaplesSkipped, Plurality::One) => {
+            &[" {items} habe ich weggelassen, das hast du immer da."]
+        }
+        (StaplesSkipped, Plurality::Other) => {
+            &[" {items} habe ich weggelassen, die hast du immer da."]
+        }
+        (HistoryUnavailable, _) => {
+            &["Dein Verlauf ist gerade nicht erreichbar. Bitte versuche es später erneut."]
+        }
+        (ReminderText, _) => &["{item} kaufen"],
+        (ReminderPermission, _) => &["{added} Damit ich dich erinnern kann, erlaube bitte \
+            Erinnerungen in der Alexa App. Ich habe dir dort eine Karte geschickt."],
+        (ReminderConsent, _) => &["Damit ich dich an {item} erinnern kann, erlaube bitte \
+            Erinnerungen in der Alexa App. Ich habe dir dort eine Karte geschickt. \
+            Danach frag mich einfach noch einmal."],
+        (ReminderSet, _) => &["{added} {reminder}"],
+        (ReminderScheduled, _) => &["Ich erinnere dich in {offset} daran."],
+        (ReminderFailed, _) => &["Die Erinnerung konnte ich leider nicht erstellen."],
+        (Days, Plurality::One) => &["einem Tag"],
+        (Days, Plurality::Other) => &["{count} Tagen"],
+        (Hours, Plurality::One) => &["einer Stunde"],
+        (Hours, Plurality::Other) => &["{count} Stunden"],
+        (Minutes, Plurality::One) => &["einer Minute"],
+        (Minutes, Plurality::Other) => &["{count} Minuten"],
+        (NoReminders, _) => &["{added} Erinnerungen kann ich hier leider nicht einrichten."],
+        (NoUserList, _) => &["Diese Liste ist nur mit einem Alexa-Konto verfügbar. \
+            Bitte versuche es später erneut."],
+        (NoUserHistory, _) => &["Der Verlauf ist nur mit einem Alexa-Konto verfügbar. \
+            Bitte versuche es später erneut."],
+        (NoUserSettings, _) => &["Einstellungen sind nur mit einem Alexa-Konto verfügbar. \
+            Bitte versuche es später erneut."],
+        (SpeechStyleBrief, _) => &["Alles klar, ich antworte ab jetzt kurz."],
+        (SpeechStyleFull, _) => &["Alles klar, ich antworte ab jetzt ausführlich."],
+        (DefaultListSet, _) => &["Alles klar, neue Einträge landen ab jetzt auf deiner {list}."],
+        (DefaultListCleared, _) => {
+            &["Alles klar, neue Einträge landen wieder auf der Einkaufsliste."]
+        }
+        (LocaleSet, _) => &["Alles klar, ich verwende ab jetzt die Einstellungen für {region}."],
+        (RegionGermany, _) => &["Deutschland"],
+        (RegionAustria, _) => &["Österreich"],
+        (RegionSwitzerland, _) => &["die Schweiz"],
+        (AccountSet, _) => &["Alles klar, ich benutze ab jetzt die Liste von {name}."],
+        (HouseholdAccountSet, _) => {
+            &["Alles klar, ich benutze ab jetzt wieder die gemeinsame Liste."]
+        }
+        (SettingSaveFailed, _) => &["Die Einstellung konnte gerade nicht gespeichert werden. \
+            Bitte versuche es später erneut."],
+        (NoUserBundle, _) => &["Eigene Sets sind nur mit einem Alexa-Konto verfügbar. \
+            Bitte versuche es später erneut."],
+        (NoUserData, _) => &["Ohne Alexa-Konto sind keine Daten von dir gespeichert."],
+        (NoLinkedAccountList, _) => {
+            &["Diese Liste ist nur mit einem angemeldeten Konto verfügbar."]
+        }
+        (NoLinkedAccountBundle, _) => &["Eigene Sets gibt es nur mit einem angemeldeten Konto."],
+        (AlexaOnly, _) => &["Das geht bisher nur mit Alexa."],
+        (UnknownAccount, _) => &["Ich kenne keine Liste von {name}."],
+        (UnrecognizedSpeaker, _) => &["Die Liste wechseln kann nur, wen ich an der Stimme \
+            erkenne. Richte dafür eine Stimmerkennung in der Alexa-App ein."],
+        (UnknownBundle, _) => &["Ich kenne kein Set namens {name}."],
+        (BundleCreated, _) => &["Ich habe dein {bundle} mit {item} angelegt."],
+        (BundleExtended, _) => &["{item} steht jetzt in deinem {bundle}."],
+        (AlreadyInBundle, _) => &["{item} steht schon in deinem {bundle}."],
+        (BundleDeleted, _) => &["Ich habe dein {bundle} gelöscht."],
+        (BundleConfigured, _) => {
+            &["Das {bundle} ist fest eingerichtet und lässt sich nicht per Sprache löschen."]
+        }
+        (BundleItems, Plurality::One) => &["In deinem {bundle} steht: {items}."],
+        (BundleItems, Plurality::Other) => &["In deinem {bundle} stehen: {items}."],
+        (BundleNames, Plurality::One) => &["Du hast ein Set: {names}."],
+        (BundleNames, Plurality::Other) => &["Du hast diese Sets: {names}."],
+        (NoBundles, _) => &["Du hast noch keine Sets. Sag zum Beispiel: \
+            füge Butter zu meinem Frühstücks-Set hinzu."],
+        (BundlesUnavailable, _) => {
+            &["Deine Sets sind gerade nicht erreichbar. Bitte versuche es später erneut."]
+        }
+        (DeleteDataQuestion, _) => &["Soll ich deine Einstellungen, deinen Verlauf und \
+            deine eigenen Listen löschen? Deine Cookidoo Einkaufsliste bleibt erhalten."],
+        (DeleteDataReprompt, _) => &["Soll ich deine gespeicherten Daten löschen?"],
+        (DataDeleted, _) => &["Deine Daten wurden gelöscht."],
+        (DeleteDataFailed, _) => &["Deine Daten konnten nicht gelöscht werden. \
+            Bitte versuche es später erneut."],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fills_placeholders() {
+        let text = Speech::new(MessageId::ItemAdded)
+            .with("item", "Milch")
+            .render_default();
+        assert_eq!(text, "Milch wurde zur Einkaufsliste hinzugefügt.");
+    }
+
+    #[test]
+    fn keeps_braces_in_values_and_unknown_placeholders() {
+        assert_eq!(
+            fill("{item} und {other}", &[("item", "{other}".to_string())]),
+            "{other} und {other}"
+        );
+    }
+
+    #[test]
+    fn words_by_plurality() {
+        let one = Speech::new(MessageId::ItemCount).counting(1);
+        let many = Speech::new(MessageId::ItemCount).counting(3);
+        assert_eq!(one.render_default(), "Du hast einen Artikel auf der Liste");
+        assert_eq!(many.render_default(), "Du hast 3 Artikel auf der Liste");
+    }
+
+    #[test]
+    fn varies_between_sessions() {
+        let variants: std::collections::HashSet<_> = (0..50)
+            .map(|i| {
+                Speech::new(MessageId::Acknowledgement)
+                    .render(&PhraseSelector::for_session(Some(&format!("session-{i}"))))
+            })
+            .collect();
+        assert!(variants.len() > 1);
+    }
+
+    #[test]
+    fn every_locale_falls_back_to_german() {
+        for locale in ["de-DE", "de-AT", "en-US", ""] {
+            assert_eq!(Language::from_locale(locale), Language::German);
+        }
+    }
+}
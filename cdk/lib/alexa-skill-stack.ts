@@ -1,6 +1,8 @@
 import * as cdk from "aws-cdk-lib";
 import * as logs from "aws-cdk-lib/aws-logs";
 import * as lambda from "aws-cdk-lib/aws-lambda";
+import * as events from "aws-cdk-lib/aws-events";
+import * as targets from "aws-cdk-lib/aws-events-targets";
 import { RustFunction } from "cargo-lambda-cdk";
 import { Construct } from "constructs";
 import * as path from "node:path";
@@ -20,7 +22,7 @@ export class AlexaSkillStack extends cdk.Stack {
 
     // Create the Rust Lambda function using cargo-lambda-cdk
     this.lambdaFunction = new RustFunction(this, "AlexaCookidooSkillFunction", {
-      manifestPath: path.join(__dirname, "..", "..", "skill", "Cargo.toml"),
+      manifestPath: path.join(__dirname, "..", "..", "skill-lambda", "Cargo.toml"),
       binaryName: "bootstrap",
       architecture: lambda.Architecture.ARM_64,
       memorySize: 256,
@@ -44,6 +46,17 @@ export class AlexaSkillStack extends cdk.Stack {
       sourceAccount: undefined, // Alexa doesn't provide source account
     });
 
+    // Keep an instance warm with a fresh Cookidoo token, so user requests
+    // rarely hit a cold start or a token refresh
+    new events.Rule(this, "WarmUpRule", {
+      schedule: events.Schedule.rate(cdk.Duration.minutes(5)),
+      targets: [
+        new targets.LambdaFunction(this.lambdaFunction, {
+          event: events.RuleTargetInput.fromObject({ warmer: true }),
+        }),
+      ],
+    });
+
     // Stack Outputs
     new cdk.CfnOutput(this, "LambdaFunctionArn", {
       value: this.lambdaFunction.functionArn,
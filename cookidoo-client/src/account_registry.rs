@@ -0,0 +1,160 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use super::error::CookidooError;
+use super::token_provider::{ProvidedToken, TokenProvider};
+
+tokio::task_local! {
+    static REQUEST_ACCOUNT: Option<String>;
+}
+
+/// Household account chosen for the request currently being handled.
+///
+/// The account follows the speaker's voice or their spoken choice, so it is
+/// scoped to the request's task like the [`RequestToken`]; calls outside a
+/// scope use the configured account.
+///
+/// [`RequestToken`]: super::RequestToken
+pub struct RequestAccount;
+
+impl RequestAccount {
+    /// Runs `future` with `account` as the request account.
+    pub async fn scope<F: Future>(account: Option<String>, future: F) -> F::Output {
+        REQUEST_ACCOUNT.scope(account, future).await
+    }
+
+    /// Returns the account of the current request, if one was chosen.
+    pub fn current() -> Option<String> {
+        REQUEST_ACCOUNT
+            .try_with(|account| account.clone())
+            .ok()
+            .flatten()
+    }
+}
+
+/// Token provider choosing between the household's Cookidoo accounts.
+///
+/// Each account keeps its own provider, and with it its own token cache;
+/// the [`RequestAccount`] picks one, and requests without a known account
+/// use the configured one.
+pub struct AccountRegistry {
+    default: Arc<dyn TokenProvider>,
+    accounts: HashMap<String, Arc<dyn TokenProvider>>,
+}
+
+impl AccountRegistry {
+    /// Creates a registry with only the configured account.
+    pub fn new(default: Arc<dyn TokenProvider>) -> Self {
+        Self {
+            default,
+            accounts: HashMap::new(),
+        }
+    }
+
+    /// Registers the provider of the account `name`.
+    pub fn with_account(mut self, name: &str, provider: Arc<dyn TokenProvider>) -> Self {
+        self.accounts.insert(name.to_lowercase(), provider);
+        self
+    }
+
+    /// Returns the provider of the current request's account.
+    fn selected(&self) -> &Arc<dyn TokenProvider> {
+        RequestAccount::current()
+            .and_then(|name| self.accounts.get(&name.to_lowercase()))
+            .unwrap_or(&self.default)
+    }
+}
+
+#[async_trait]
+impl TokenProvider for AccountRegistry {
+    async fn token(&self) -> Result<ProvidedToken, CookidooError> {
+        self.selected().token().await
+    }
+
+    fn invalidate(&self) {
+        self.selected().invalidate();
+    }
+
+    fn refresh_in_background(self: Arc<Self>) {
+        Arc::clone(&self.default).refresh_in_background();
+        for provider in self.accounts.values() {
+            Arc::clone(provider).refresh_in_background();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TokenSource;
+    use shopping_list_domain::models::Secret;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    struct FixedToken {
+        token: &'static str,
+        invalidations: AtomicUsize,
+    }
+
+    impl FixedToken {
+        fn new(token: &'static str) -> Arc<Self> {
+            Arc::new(Self {
+                token,
+                invalidations: AtomicUsize::new(0),
+            })
+        }
+    }
+
+    #[async_trait]
+    impl TokenProvider for FixedToken {
+        async fn token(&self) -> Result<ProvidedToken, CookidooError> {
+            Ok(ProvidedToken {
+                access_token: Secret::new(self.token),
+                source: TokenSource::Cache,
+            })
+        }
+
+        fn invalidate(&self) {
+            self.invalidations.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    fn registry(papa: Arc<FixedToken>) -> AccountRegistry {
+        AccountRegistry::new(FixedToken::new("household")).with_account("Papa", papa)
+    }
+
+    #[tokio::test]
+    async fn uses_configured_account_outside_scope() {
+        let token = registry(FixedToken::new("papa")).token().await.unwrap();
+        assert_eq!(token.access_token.expose(), "household");
+    }
+
+    #[tokio::test]
+    async fn uses_chosen_account() {
+        let registry = registry(FixedToken::new("papa"));
+        let token = RequestAccount::scope(Some("papa".to_string()), registry.token())
+            .await
+            .unwrap();
+        assert_eq!(token.access_token.expose(), "papa");
+    }
+
+    #[tokio::test]
+    async fn falls_back_for_unknown_account() {
+        let registry = registry(FixedToken::new("papa"));
+        let token = RequestAccount::scope(Some("Mama".to_string()), registry.token())
+            .await
+            .unwrap();
+        assert_eq!(token.access_token.expose(), "household");
+    }
+
+    #[tokio::test]
+    async fn invalidates_only_chosen_account() {
+        let papa = FixedToken::new("papa");
+        let registry = registry(Arc::clone(&papa));
+        RequestAccount::scope(Some("Papa".to_string()), async { registry.invalidate() }).await;
+        registry.invalidate();
+        assert_eq!(papa.invalidations.load(Ordering::SeqCst), 1);
+    }
+}
@@ -0,0 +1,731 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use async_trait::async_trait;
+use reqwest::Response;
+use tokio::task::JoinHandle;
+use tracing::{debug, error, info, warn};
+
+use shopping_list_domain::models::{AuthToken, CookidooCredentials, DomainError, Secret};
+use shopping_list_domain::ports::{AuthenticationService, CredentialStore, RefreshTokenStore};
+
+use super::auth_header::AuthHeader;
+use super::client::CookidooClient;
+use super::error::CookidooError;
+use super::models::{CookidooAuthResponse, CookidooErrorResponse};
+use super::pkce::Pkce;
+use super::token_cache::TokenCache;
+use super::token_provider::{ProvidedToken, RequestToken, TokenProvider, TokenSource};
+
+/// OAuth token endpoint path unless set with
+/// [`CookidooAuthAdapter::with_token_path`].
+const TOKEN_ENDPOINT: &str = "/ciam/auth/token";
+
+/// OAuth authorization endpoint path, opened in the user's browser.
+const AUTHORIZE_ENDPOINT: &str = "/ciam/auth/authorize";
+
+/// How long a background refresh may take before it is abandoned and a
+/// later request may start another.
+const BACKGROUND_REFRESH_DEADLINE: Duration = Duration::from_secs(10);
+
+/// Cookidoo authentication adapter implementing the AuthenticationService port.
+///
+/// Tokens come from a provisioned refresh token (authorization-code flow)
+/// or, unless disabled, from the password grant with the configured
+/// credentials. With a credential store, rejected credentials are read
+/// again from the store before the password grant gives up. With a refresh
+/// token store, every refresh token Cookidoo rotates is written back to it.
+pub struct CookidooAuthAdapter {
+    client: CookidooClient,
+    cache: Arc<TokenCache>,
+    credentials: RwLock<CookidooCredentials>,
+    credential_store: Option<Arc<dyn CredentialStore>>,
+    credentials_version: RwLock<Option<String>>,
+    auth_header: AuthHeader,
+    refresh_token: RwLock<Option<Secret>>,
+    refresh_token_store: Option<Arc<dyn RefreshTokenStore>>,
+    password_grant: bool,
+    token_path: String,
+    /// Unix time in seconds the running background refresh started at,
+    /// or 0 if none is running.
+    refresh_started: Arc<AtomicU64>,
+}
+
+impl CookidooAuthAdapter {
+    /// Creates a new CookidooAuthAdapter.
+    ///
+    /// The `client_id` and `client_secret` are combined and base64-encoded
+    /// to create the Basic authorization header for OAuth requests.
+    pub fn new(
+        client: CookidooClient,
+        credentials: CookidooCredentials,
+        client_id: &str,
+        client_secret: &str,
+    ) -> Self {
+        Self::with_cache(
+            client,
+            credentials,
+            client_id,
+            client_secret,
+            Arc::new(TokenCache::new()),
+        )
+    }
+
+    /// Creates a new CookidooAuthAdapter with a shared token cache.
+    ///
+    /// The `client_id` and `client_secret` are combined and base64-encoded
+    /// to create the Basic authorization header for OAuth requests.
+    pub fn with_cache(
+        client: CookidooClient,
+        credentials: CookidooCredentials,
+        client_id: &str,
+        client_secret: &str,
+        cache: Arc<TokenCache>,
+    ) -> Self {
+        Self::with_auth_header(
+            client,
+            credentials,
+            AuthHeader::encode(client_id, client_secret),
+            cache,
+        )
+    }
+
+    /// Creates a new CookidooAuthAdapter from a prebuilt authorization
+    /// header, e.g. the skill's `AppConfig::cookidoo_auth_header`, with a
+    /// shared token cache.
+    pub fn with_auth_header(
+        client: CookidooClient,
+        credentials: CookidooCredentials,
+        auth_header: AuthHeader,
+        cache: Arc<TokenCache>,
+    ) -> Self {
+        Self {
+            client,
+            cache,
+            credentials: RwLock::new(credentials),
+            credential_store: None,
+            credentials_version: RwLock::new(None),
+            auth_header,
+            refresh_token: RwLock::new(None),
+            refresh_token_store: None,
+            password_grant: true,
+            token_path: TOKEN_ENDPOINT.to_string(),
+            refresh_started: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    /// Uses a refresh token provisioned via the authorization-code flow
+    /// whenever no cached token is available.
+    pub fn with_refresh_token(mut self, refresh_token: impl Into<String>) -> Self {
+        self.refresh_token = RwLock::new(Some(Secret::new(refresh_token)));
+        self
+    }
+
+    /// Writes refresh tokens Cookidoo rotates to `store`, so the next cold
+    /// start reads the current one instead of the revoked one it was
+    /// provisioned with.
+    pub fn with_refresh_token_store(mut self, store: Arc<dyn RefreshTokenStore>) -> Self {
+        self.refresh_token_store = Some(store);
+        self
+    }
+
+    /// Enables or disables the password grant (enabled by default).
+    ///
+    /// With a refresh token configured, the password grant is only the
+    /// fallback for a rejected refresh token.
+    pub fn with_password_grant(mut self, enabled: bool) -> Self {
+        self.password_grant = enabled;
+        self
+    }
+
+    /// Reads the credentials from `store` once Cookidoo rejects the ones in
+    /// use, e.g. after the password was rotated.
+    ///
+    /// Call [`Self::reload_credentials`] to replace the credentials passed
+    /// on construction right away.
+    pub fn with_credential_store(mut self, store: Arc<dyn CredentialStore>) -> Self {
+        self.credential_store = Some(store);
+        self
+    }
+
+    /// Replaces the credentials with those in the credential store if their
+    /// version differs from the one in use.
+    ///
+    /// # Returns
+    /// True if the credentials changed; false without a store or with an
+    /// unchanged version.
+    ///
+    /// # Errors
+    /// Returns the error of the credential store if it cannot be read; the
+    /// credentials in use are kept.
+    pub async fn reload_credentials(&self) -> Result<bool, DomainError> {
+        let Some(store) = &self.credential_store else {
+            return Ok(false);
+        };
+        let stored = store.current().await?;
+
+        let mut version = self
+            .credentials_version
+            .write()
+            .unwrap_or_else(|e| e.into_inner());
+        if version.as_deref() == Some(stored.version()) {
+            debug!("Stored Cookidoo credentials unchanged");
+            return Ok(false);
+        }
+        *self.credentials.write().unwrap_or_else(|e| e.into_inner()) = stored.credentials().clone();
+        *version = Some(stored.version().to_string());
+        info!(version = %stored.version(), "Loaded Cookidoo credentials from store");
+        Ok(true)
+    }
+
+    /// Returns the credentials currently used for the password grant.
+    pub fn current_credentials(&self) -> CookidooCredentials {
+        self.credentials
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    /// Returns true if Cookidoo rejected the credentials themselves, as
+    /// opposed to the request failing.
+    fn rejects_credentials(error: &CookidooError) -> bool {
+        match error {
+            CookidooError::AuthenticationError(_) => true,
+            CookidooError::BadRequest(body) => CookidooErrorResponse::parse(body)
+                .and_then(|response| response.code)
+                .is_some_and(|code| code == "invalid_grant"),
+            _ => false,
+        }
+    }
+
+    /// Replaces the OAuth token endpoint path, e.g. for a staging proxy.
+    pub fn with_token_path(mut self, token_path: impl Into<String>) -> Self {
+        self.token_path = token_path.into();
+        self
+    }
+
+    /// Returns a reference to the token cache.
+    pub fn cache(&self) -> &Arc<TokenCache> {
+        &self.cache
+    }
+
+    /// Gets a valid access token, refreshing or re-authenticating as needed.
+    pub async fn get_valid_token(&self) -> Result<String, CookidooError> {
+        self.provide_token()
+            .await
+            .map(|token| token.access_token.expose().to_string())
+    }
+
+    /// Spawns a task refreshing the cached token if it is about to expire,
+    /// so the next request does not wait for the refresh.
+    ///
+    /// At most one refresh runs at a time. On Lambda the task may be frozen
+    /// with the execution environment once the response is sent; it then
+    /// resumes with the next invocation, which meanwhile keeps using the
+    /// still-valid cached token. A refresh is abandoned after
+    /// [`BACKGROUND_REFRESH_DEADLINE`], measured in wall-clock time so a
+    /// frozen one counts as overdue, and a later call may then start
+    /// another. Whichever finishes first replaces the cached token; a
+    /// result arriving after the cache moved on is discarded.
+    ///
+    /// # Returns
+    /// The refresh task, or `None` if no refresh was needed or one is
+    /// already running.
+    pub fn spawn_refresh(self: &Arc<Self>) -> Option<JoinHandle<()>> {
+        let token = self.cache.get()?;
+        if !token.needs_refresh() {
+            return None;
+        }
+        let started = unix_seconds();
+        let running = self.refresh_started.load(Ordering::Acquire);
+        if running != 0 && started.saturating_sub(running) < BACKGROUND_REFRESH_DEADLINE.as_secs() {
+            return None;
+        }
+        self.refresh_started
+            .compare_exchange(running, started, Ordering::AcqRel, Ordering::Acquire)
+            .ok()?;
+
+        let adapter = Arc::clone(self);
+        Some(tokio::spawn(async move {
+            debug!("Refreshing token in background");
+            let refreshed = tokio::time::timeout(
+                BACKGROUND_REFRESH_DEADLINE,
+                adapter.refresh_token_internal(token.refresh_token()),
+            )
+            .await;
+            match refreshed {
+                Ok(Ok(new_token)) => {
+                    let unchanged = adapter
+                        .cache
+                        .get()
+                        .is_some_and(|cached| cached.access_token() == token.access_token());
+                    if unchanged {
+                        adapter
+                            .keep_rotated_refresh_token(token.refresh_token(), &new_token)
+                            .await;
+                        adapter.cache.set(new_token);
+                        info!("Refreshed token in background");
+                    } else {
+                        debug!("Discarded background refresh overtaken by another");
+                    }
+                }
+                // The next request refreshes or re-authenticates on demand
+                Ok(Err(e)) => warn!(error = %e, "Background token refresh failed"),
+                Err(_) => warn!(
+                    deadline_s = BACKGROUND_REFRESH_DEADLINE.as_secs(),
+                    "Background token refresh timed out"
+                ),
+            }
+            // Release the claim unless a later refresh took it over
+            let _ = adapter.refresh_started.compare_exchange(
+                started,
+                0,
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            );
+        }))
+    }
+
+    /// Returns the URL where the user signs in to authorize this client.
+    ///
+    /// After sign-in Cookidoo redirects to `redirect_uri` with `code` and
+    /// `state` query parameters; the code is then passed to
+    /// [`exchange_authorization_code`](Self::exchange_authorization_code).
+    pub fn authorization_url(&self, redirect_uri: &str, pkce: &Pkce, state: &str) -> String {
+        let params = [
+            ("response_type", "code"),
+            ("client_id", self.auth_header.client_id()),
+            ("redirect_uri", redirect_uri),
+            ("code_challenge", pkce.challenge()),
+            ("code_challenge_method", "S256"),
+            ("state", state),
+        ];
+        reqwest::Url::parse_with_params(&self.client.url(AUTHORIZE_ENDPOINT), &params)
+            .map(String::from)
+            .unwrap_or_else(|_| self.client.url(AUTHORIZE_ENDPOINT))
+    }
+
+    /// Extracts the authorization code from the URL Cookidoo redirected to.
+    ///
+    /// Accepts a full URL or just its path and query, as in the request
+    /// line received by a local callback server.
+    ///
+    /// # Errors
+    /// Returns `CookidooError::AuthenticationError` if Cookidoo reported an
+    /// error, the state does not match or no code is present.
+    pub fn authorization_code_from_redirect(
+        redirect: &str,
+        expected_state: &str,
+    ) -> Result<String, CookidooError> {
+        let url = reqwest::Url::parse(redirect.trim())
+            .or_else(|_| reqwest::Url::parse(&format!("http://localhost{}", redirect.trim())))
+            .map_err(|e| CookidooError::AuthenticationError(format!("Invalid redirect: {}", e)))?;
+        let param = |name: &str| {
+            url.query_pairs()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value.into_owned())
+        };
+
+        if let Some(error) = param("error") {
+            return Err(CookidooError::AuthenticationError(format!(
+                "Authorization denied: {}",
+                error
+            )));
+        }
+        if param("state").as_deref() != Some(expected_state) {
+            return Err(CookidooError::AuthenticationError(
+                "State of the redirect does not match".to_string(),
+            ));
+        }
+        param("code")
+            .filter(|code| !code.is_empty())
+            .ok_or_else(|| {
+                CookidooError::AuthenticationError("Redirect carries no code".to_string())
+            })
+    }
+
+    /// Exchanges an authorization code for tokens.
+    ///
+    /// # Errors
+    /// Returns `CookidooError::AuthenticationError` if Cookidoo rejects the
+    /// code or verifier.
+    pub async fn exchange_authorization_code(
+        &self,
+        code: &str,
+        redirect_uri: &str,
+        pkce: &Pkce,
+    ) -> Result<AuthToken, CookidooError> {
+        let params = [
+            ("grant_type", "authorization_code"),
+            ("code", code),
+            ("redirect_uri", redirect_uri),
+            ("code_verifier", pkce.verifier()),
+        ];
+
+        let response = self.post_token_request(&params).await?;
+        let status = response.status();
+
+        if status.is_success() {
+            Self::parse_token(response).await
+        } else {
+            let body = response.text().await.unwrap_or_default();
+            error!(status = %status, "Authorization code exchange failed");
+            Err(CookidooError::AuthenticationError(format!(
+                "Code exchange failed with status {}: {}",
+                status, body
+            )))
+        }
+    }
+
+    /// Sources a token from the request, the cache, the provisioned refresh
+    /// token or the password grant, in that order.
+    async fn provide_token(&self) -> Result<ProvidedToken, CookidooError> {
+        // A token passed with the request replaces the configured account
+        if let Some(access_token) = RequestToken::current() {
+            debug!("Using token from request");
+            return Ok(ProvidedToken {
+                access_token: Secret::new(access_token),
+                source: TokenSource::Request,
+            });
+        }
+
+        // Check if we have a valid cached token
+        if let Some(token) = self.cache.get() {
+            // A background refresh is under way; the token is still good
+            let refreshing =
+                self.refresh_started.load(Ordering::Acquire) != 0 && !token.is_expired();
+            if !token.needs_refresh() || refreshing {
+                debug!("Using cached token");
+                return Ok(ProvidedToken {
+                    access_token: Secret::new(token.access_token()),
+                    source: TokenSource::Cache,
+                });
+            }
+
+            // Try to refresh the token
+            debug!("Token needs refresh, attempting refresh");
+            match self.refresh_token_internal(token.refresh_token()).await {
+                Ok(new_token) => {
+                    self.keep_rotated_refresh_token(token.refresh_token(), &new_token)
+                        .await;
+                    let access_token = Secret::new(new_token.access_token());
+                    self.cache.set(new_token);
+                    return Ok(ProvidedToken {
+                        access_token,
+                        source: TokenSource::Cache,
+                    });
+                }
+                Err(e) => {
+                    debug!(error = %e, "Token refresh failed, will re-authenticate");
+                    self.cache.clear();
+                }
+            }
+        }
+
+        // No cached token, use the provisioned refresh token
+        let provisioned = self
+            .refresh_token
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone();
+        if let Some(refresh_token) = provisioned {
+            debug!("Refreshing with provisioned refresh token");
+            match self.refresh_token_internal(refresh_token.expose()).await {
+                Ok(token) => {
+                    self.keep_rotated_refresh_token(refresh_token.expose(), &token)
+                        .await;
+                    let access_token = Secret::new(token.access_token());
+                    self.cache.set(token);
+                    return Ok(ProvidedToken {
+                        access_token,
+                        source: TokenSource::RefreshToken,
+                    });
+                }
+                Err(e) if self.password_grant => {
+                    warn!(error = %e, "Provisioned refresh token failed, falling back to password grant");
+                }
+                Err(e) => {
+                    error!(error = %e, "Provisioned refresh token was rejected");
+                    return Err(CookidooError::AuthenticationError(
+                        "Provisioned refresh token was rejected".to_string(),
+                    ));
+                }
+            }
+        }
+
+        if !self.password_grant {
+            return Err(CookidooError::AuthenticationError(
+                "No refresh token available and password grant is disabled".to_string(),
+            ));
+        }
+
+        // No valid token, perform full authentication; rejected credentials
+        // may have been rotated in the credential store
+        debug!("Performing full authentication");
+        let token = match self
+            .authenticate_internal(&self.current_credentials())
+            .await
+        {
+            Err(e)
+                if Self::rejects_credentials(&e)
+                    && self.reload_credentials().await.unwrap_or_else(|e| {
+                        error!(error = %e, "Failed to read Cookidoo credentials from store");
+                        false
+                    }) =>
+            {
+                info!("Retrying authentication with rotated credentials");
+                self.authenticate_internal(&self.current_credentials())
+                    .await?
+            }
+            result => result?,
+        };
+        let access_token = Secret::new(token.access_token());
+        self.cache.set(token);
+        Ok(ProvidedToken {
+            access_token,
+            source: TokenSource::PasswordGrant,
+        })
+    }
+
+    /// Replaces the provisioned refresh token with the one `token` carries
+    /// if Cookidoo rotated it on refreshing with `used`, and writes it to
+    /// the refresh token store.
+    ///
+    /// A failed write is logged only: the new token works until the next
+    /// cold start, which then redeems the revoked one.
+    async fn keep_rotated_refresh_token(&self, used: &str, token: &AuthToken) {
+        let rotated = token.refresh_token();
+        if rotated.is_empty() || rotated == used {
+            return;
+        }
+
+        {
+            let mut provisioned = self
+                .refresh_token
+                .write()
+                .unwrap_or_else(|e| e.into_inner());
+            if provisioned.is_some() {
+                *provisioned = Some(Secret::new(rotated));
+            }
+        }
+
+        let Some(store) = &self.refresh_token_store else {
+            return;
+        };
+        match store.save(rotated).await {
+            Ok(()) => info!("Stored rotated Cookidoo refresh token"),
+            Err(e) => error!(error = %e, "Failed to store rotated Cookidoo refresh token"),
+        }
+    }
+
+    async fn authenticate_internal(
+        &self,
+        credentials: &CookidooCredentials,
+    ) -> Result<AuthToken, CookidooError> {
+        let params = [
+            ("grant_type", "password"),
+            ("username", credentials.email()),
+            ("password", credentials.password()),
+        ];
+
+        let response = self.post_token_request(&params).await?;
+        let status = response.status();
+
+        if status.is_success() {
+            Self::parse_token(response).await
+        } else if status.as_u16() == 401 {
+            error!("Authentication failed: invalid credentials");
+            Err(CookidooError::AuthenticationError(
+                "Invalid credentials".to_string(),
+            ))
+        } else if status.as_u16() == 400 {
+            let body = response.text().await.unwrap_or_default();
+            error!(status = %status, body = %body, "Bad request during authentication");
+            Err(CookidooError::BadRequest(body))
+        } else {
+            let body = response.text().await.unwrap_or_default();
+            error!(status = %status, body = %body, "HTTP error during authentication");
+            Err(CookidooError::from_response(status.as_u16(), &body))
+        }
+    }
+
+    async fn refresh_token_internal(
+        &self,
+        refresh_token: &str,
+    ) -> Result<AuthToken, CookidooError> {
+        let params = [
+            ("grant_type", "refresh_token"),
+            ("refresh_token", refresh_token),
+        ];
+
+        let response = self.post_token_request(&params).await?;
+        let status = response.status();
+
+        if status.is_success() {
+            Self::parse_token(response).await
+        } else {
+            let body = response.text().await.unwrap_or_default();
+            error!(status = %status, "Token refresh failed");
+            Err(CookidooError::TokenExpired(format!(
+                "Refresh failed with status {}: {}",
+                status, body
+            )))
+        }
+    }
+
+    /// Posts a form to the token endpoint with the client's Basic auth.
+    async fn post_token_request(&self, params: &[(&str, &str)]) -> Result<Response, CookidooError> {
+        let url = self.client.url(&self.token_path);
+        self.client
+            .send(
+                self.client
+                    .inner()?
+                    .post(&url)
+                    .header("Authorization", self.auth_header.expose())
+                    .header("Content-Type", "application/x-www-form-urlencoded")
+                    .form(params),
+            )
+            .await
+    }
+
+    /// Parses a successful token endpoint response.
+    async fn parse_token(response: Response) -> Result<AuthToken, CookidooError> {
+        let auth_response: CookidooAuthResponse = response
+            .json()
+            .await
+            .map_err(|e| CookidooError::ParseError(e.to_string()))?;
+
+        Ok(AuthToken::new(
+            auth_response.access_token.expose(),
+            auth_response.refresh_token.expose(),
+            Duration::from_secs(auth_response.expires_in),
+        ))
+    }
+}
+
+#[async_trait]
+impl TokenProvider for CookidooAuthAdapter {
+    async fn token(&self) -> Result<ProvidedToken, CookidooError> {
+        self.provide_token().await
+    }
+
+    fn invalidate(&self) {
+        self.cache.clear();
+    }
+
+    fn refresh_in_background(self: Arc<Self>) {
+        self.spawn_refresh();
+    }
+}
+
+#[async_trait]
+impl AuthenticationService for CookidooAuthAdapter {
+    async fn authenticate(
+        &self,
+        credentials: &CookidooCredentials,
+    ) -> Result<AuthToken, DomainError> {
+        self.authenticate_internal(credentials)
+            .await
+            .map_err(|e| e.into())
+    }
+
+    async fn refresh_token(&self, refresh_token: &str) -> Result<AuthToken, DomainError> {
+        self.refresh_token_internal(refresh_token)
+            .await
+            .map_err(|e| e.into())
+    }
+}
+
+/// Returns the current Unix time in seconds.
+fn unix_seconds() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn adapter_with_expiring_token() -> Arc<CookidooAuthAdapter> {
+        let cache = Arc::new(TokenCache::new());
+        cache.set(AuthToken::new(
+            "expiring",
+            "refresh",
+            Duration::from_secs(60),
+        ));
+        Arc::new(CookidooAuthAdapter::with_cache(
+            CookidooClient::with_base_url("http://127.0.0.1:9"),
+            CookidooCredentials::new("", ""),
+            "client-id",
+            "client-secret",
+            cache,
+        ))
+    }
+
+    #[tokio::test]
+    async fn running_refresh_blocks_another() {
+        let adapter = adapter_with_expiring_token();
+        adapter
+            .refresh_started
+            .store(unix_seconds(), Ordering::Release);
+        assert!(adapter.spawn_refresh().is_none());
+    }
+
+    #[tokio::test]
+    async fn overdue_refresh_is_replaced() {
+        // As left by a refresh frozen with the Lambda environment
+        let adapter = adapter_with_expiring_token();
+        let overdue = unix_seconds() - BACKGROUND_REFRESH_DEADLINE.as_secs() - 1;
+        adapter.refresh_started.store(overdue, Ordering::Release);
+
+        let refresh = adapter
+            .spawn_refresh()
+            .expect("overdue refresh is replaced");
+        refresh.await.unwrap();
+        assert_eq!(adapter.refresh_started.load(Ordering::Acquire), 0);
+    }
+
+    #[test]
+    fn extracts_code_from_redirect_url() {
+        let code = CookidooAuthAdapter::authorization_code_from_redirect(
+            "http://localhost:8765/callback?code=abc%2F123&state=xyz",
+            "xyz",
+        )
+        .unwrap();
+        assert_eq!(code, "abc/123");
+    }
+
+    #[test]
+    fn extracts_code_from_request_target() {
+        let code = CookidooAuthAdapter::authorization_code_from_redirect(
+            "/callback?state=xyz&code=abc",
+            "xyz",
+        )
+        .unwrap();
+        assert_eq!(code, "abc");
+    }
+
+    #[test]
+    fn rejects_mismatched_state() {
+        let result = CookidooAuthAdapter::authorization_code_from_redirect(
+            "/callback?code=abc&state=other",
+            "xyz",
+        );
+        assert!(matches!(result, Err(CookidooError::AuthenticationError(_))));
+    }
+
+    #[test]
+    fn reports_authorization_error() {
+        let result = CookidooAuthAdapter::authorization_code_from_redirect(
+            "/callback?error=access_denied&state=xyz",
+            "xyz",
+        );
+        let Err(CookidooError::AuthenticationError(message)) = result else {
+            panic!("expected authentication error");
+        };
+        assert!(message.contains("access_denied"));
+    }
+}
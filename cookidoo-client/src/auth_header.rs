@@ -0,0 +1,137 @@
+use base64::prelude::*;
+use thiserror::Error;
+
+use shopping_list_domain::models::Secret;
+
+/// Basic authorization header for the Cookidoo OAuth client.
+///
+/// Built once from the client ID and secret (`Basic base64(id:secret)`) and
+/// kept for every token request, instead of encoding the credentials again
+/// each time.
+#[derive(Debug, Clone)]
+pub struct AuthHeader {
+    client_id: String,
+    value: Secret,
+}
+
+/// Why client credentials cannot form a Basic authorization header.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum AuthHeaderError {
+    #[error("client ID must not be blank")]
+    BlankClientId,
+
+    #[error("client secret must not be blank")]
+    BlankClientSecret,
+
+    /// RFC 7617 separates ID and secret by the first colon, so the ID
+    /// cannot contain one.
+    #[error("client ID must not contain ':'")]
+    ColonInClientId,
+
+    #[error("client credentials must not contain control characters")]
+    ControlCharacter,
+}
+
+impl AuthHeader {
+    /// Builds the header from validated client credentials.
+    ///
+    /// # Errors
+    /// Returns an error if the ID or secret is blank, the ID contains a
+    /// colon, or either contains a control character.
+    pub fn new(client_id: &str, client_secret: &str) -> Result<Self, AuthHeaderError> {
+        if client_id.trim().is_empty() {
+            return Err(AuthHeaderError::BlankClientId);
+        }
+        if client_secret.trim().is_empty() {
+            return Err(AuthHeaderError::BlankClientSecret);
+        }
+        if client_id.contains(':') {
+            return Err(AuthHeaderError::ColonInClientId);
+        }
+        if client_id
+            .chars()
+            .chain(client_secret.chars())
+            .any(char::is_control)
+        {
+            return Err(AuthHeaderError::ControlCharacter);
+        }
+
+        Ok(Self::encode(client_id, client_secret))
+    }
+
+    /// Builds the header without validating the credentials, e.g. to keep
+    /// going with credentials [`AuthHeader::new`] rejected.
+    pub fn encode(client_id: &str, client_secret: &str) -> Self {
+        let credentials = Secret::new(format!("{}:{}", client_id, client_secret));
+        let encoded = Secret::new(BASE64_STANDARD.encode(credentials.expose().as_bytes()));
+        Self {
+            client_id: client_id.to_string(),
+            value: Secret::new(format!("Basic {}", encoded.expose())),
+        }
+    }
+
+    /// Returns the client ID the header was built from.
+    pub fn client_id(&self) -> &str {
+        &self.client_id
+    }
+
+    /// Returns the header value, for the one place that sends it.
+    pub fn expose(&self) -> &str {
+        self.value.expose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encodes_basic_header() {
+        let header = AuthHeader::new("client_id", "client_secret").unwrap();
+
+        assert_eq!(header.expose(), "Basic Y2xpZW50X2lkOmNsaWVudF9zZWNyZXQ=");
+        assert_eq!(header.client_id(), "client_id");
+    }
+
+    #[test]
+    fn allows_colon_in_secret() {
+        let header = AuthHeader::new("id", "se:cret").unwrap();
+
+        assert_eq!(header.expose(), "Basic aWQ6c2U6Y3JldA==");
+    }
+
+    #[test]
+    fn rejects_blank_credentials() {
+        assert_eq!(
+            AuthHeader::new(" ", "secret").unwrap_err(),
+            AuthHeaderError::BlankClientId
+        );
+        assert_eq!(
+            AuthHeader::new("id", "").unwrap_err(),
+            AuthHeaderError::BlankClientSecret
+        );
+    }
+
+    #[test]
+    fn rejects_colon_in_client_id() {
+        assert_eq!(
+            AuthHeader::new("my:id", "secret").unwrap_err(),
+            AuthHeaderError::ColonInClientId
+        );
+    }
+
+    #[test]
+    fn rejects_control_characters() {
+        assert_eq!(
+            AuthHeader::new("id", "secret\n").unwrap_err(),
+            AuthHeaderError::ControlCharacter
+        );
+    }
+
+    #[test]
+    fn debug_output_redacts_header() {
+        let header = AuthHeader::new("client_id", "client_secret").unwrap();
+
+        assert!(!format!("{:?}", header).contains("Y2xpZW50"));
+    }
+}
@@ -0,0 +1,413 @@
+use std::num::NonZeroU32;
+use std::sync::Arc;
+use std::time::Duration;
+
+use reqwest::header::{
+    HeaderMap, HeaderName, HeaderValue, ACCEPT_LANGUAGE, RETRY_AFTER, USER_AGENT,
+};
+use reqwest::{Client, Method, Request, RequestBuilder, Response, StatusCode};
+use tokio::time::Instant;
+use tracing::warn;
+
+use shopping_list_domain::models::Market;
+
+use super::error::{is_retryable_status, CookidooError};
+use super::fixture_recorder::{FixtureMode, FixtureRecorder};
+use super::lazy_client::LazyClient;
+use super::proxy::ProxyConfig;
+use super::rate_limiter::RateLimiter;
+use super::request_market::RequestMarket;
+
+/// User agent sent unless replaced with [`CookidooClient::with_user_agent`].
+const DEFAULT_USER_AGENT: &str = "AlexaCookidooSkill/1.0";
+
+/// Default timeout for HTTP requests.
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Default base URL for the Cookidoo API (Germany).
+const DEFAULT_BASE_URL: &str = "https://de.tmmobile.vorwerk-digital.com";
+
+/// Host suffix of the per-market Cookidoo APIs, prefixed with the
+/// lower-case country code.
+const MARKET_HOST_SUFFIX: &str = ".tmmobile.vorwerk-digital.com";
+
+/// Default burst size of the request rate limiter.
+const DEFAULT_BURST: NonZeroU32 = NonZeroU32::new(10).unwrap();
+
+/// Default sustained request rate (requests per second).
+const DEFAULT_REQUESTS_PER_SECOND: f64 = 5.0;
+
+/// Maximum time spent waiting on `Retry-After` within one request, so the
+/// skill still answers before Alexa's response deadline.
+const RATE_LIMIT_BUDGET: Duration = Duration::from_secs(4);
+
+/// Backoff used when a 429 response carries no usable `Retry-After` header.
+const DEFAULT_RETRY_AFTER: Duration = Duration::from_secs(1);
+
+/// Attempts per request for transient failures (server errors, timeouts,
+/// connection failures); rate limiting has its own budget.
+const MAX_ATTEMPTS: u32 = 3;
+
+/// Backoff before the first retry of a transient failure, doubled for
+/// each further retry.
+const INITIAL_BACKOFF: Duration = Duration::from_millis(200);
+
+/// HTTP client wrapper for Cookidoo API requests.
+#[derive(Clone)]
+pub struct CookidooClient {
+    client: LazyClient,
+    base_url: String,
+    market_hosts: bool,
+    rate_limiter: Arc<RateLimiter>,
+    fixtures: Option<Arc<FixtureRecorder>>,
+    headers: HeaderMap,
+    market_language: bool,
+}
+
+impl CookidooClient {
+    /// Creates a new CookidooClient with default settings.
+    ///
+    /// Market-specific calls go to the host of their market.
+    pub fn new() -> Self {
+        Self {
+            market_hosts: true,
+            ..Self::with_base_url(DEFAULT_BASE_URL)
+        }
+    }
+
+    /// Creates a new CookidooClient with a custom base URL.
+    ///
+    /// All calls go to this URL, whatever their market.
+    pub fn with_base_url(base_url: impl Into<String>) -> Self {
+        Self {
+            client: Self::http_client(None),
+            base_url: base_url.into(),
+            market_hosts: false,
+            rate_limiter: Arc::new(RateLimiter::new(DEFAULT_BURST, DEFAULT_REQUESTS_PER_SECOND)),
+            fixtures: None,
+            headers: HeaderMap::new(),
+            market_language: false,
+        }
+    }
+
+    /// Sends all requests through the given proxy instead of the one set in
+    /// the environment.
+    ///
+    /// # Errors
+    /// Returns an error if the proxy URL is invalid.
+    pub fn with_proxy(mut self, proxy: &ProxyConfig) -> Result<Self, CookidooError> {
+        let proxy = proxy.build()?;
+        self.client = Self::http_client(Some(proxy));
+        Ok(self)
+    }
+
+    /// Creates the HTTP client, built on the first request so the TLS setup
+    /// does not add to the cold start.
+    fn http_client(proxy: Option<reqwest::Proxy>) -> LazyClient {
+        LazyClient::new(move || {
+            let mut builder = Client::builder()
+                .timeout(DEFAULT_TIMEOUT)
+                .user_agent(DEFAULT_USER_AGENT);
+            if let Some(proxy) = proxy.clone() {
+                builder = builder.proxy(proxy);
+            }
+            builder.build()
+        })
+    }
+
+    /// Replaces the request rate limit (burst size and requests per second).
+    ///
+    /// # Panics
+    /// Panics if `requests_per_second` is not a positive number.
+    pub fn with_rate_limit(mut self, burst: NonZeroU32, requests_per_second: f64) -> Self {
+        self.rate_limiter = Arc::new(RateLimiter::new(burst, requests_per_second));
+        self
+    }
+
+    /// Records responses to, or replays them from, fixtures.
+    pub fn with_fixture_recorder(mut self, recorder: FixtureRecorder) -> Self {
+        self.fixtures = Some(Arc::new(recorder));
+        self
+    }
+
+    /// Replaces the `User-Agent` sent with every request.
+    pub fn with_user_agent(self, user_agent: HeaderValue) -> Self {
+        self.with_header(USER_AGENT, user_agent)
+    }
+
+    /// Sends `name: value` with every request that does not set `name`
+    /// itself.
+    pub fn with_header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.headers.insert(name, value);
+        self
+    }
+
+    /// Sends the language of the request's market (e.g. `de-AT`) as
+    /// `Accept-Language`, unless a request or [`CookidooClient::with_header`]
+    /// sets one.
+    pub fn with_market_language(mut self) -> Self {
+        self.market_language = true;
+        self
+    }
+
+    /// Returns the underlying reqwest client.
+    ///
+    /// # Errors
+    /// Returns `CookidooError::RequestError` if the client cannot be built.
+    pub fn inner(&self) -> Result<&Client, CookidooError> {
+        Ok(self.client.get()?)
+    }
+
+    /// Returns the base URL.
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Builds a full URL from a path.
+    pub fn url(&self, path: &str) -> String {
+        format!("{}{}", self.base_url, path)
+    }
+
+    /// Builds a full URL from a path on the host of `market`.
+    pub fn market_url(&self, market: Market, path: &str) -> String {
+        if self.market_hosts {
+            format!(
+                "https://{}{}{}",
+                market.country_code().to_lowercase(),
+                MARKET_HOST_SUFFIX,
+                path
+            )
+        } else {
+            self.url(path)
+        }
+    }
+
+    /// Sends a request through the rate limiter, backing off on HTTP 429
+    /// and retrying transient failures.
+    ///
+    /// A 429 response is retried after its `Retry-After` delay as long as the
+    /// total wait stays within the rate limit budget; otherwise the 429
+    /// response is returned for the caller to classify.
+    ///
+    /// Server errors and timeouts are retried with exponential backoff, up
+    /// to [`MAX_ATTEMPTS`], but only for idempotent methods: a POST that
+    /// failed on the server may still have added an item. Connection
+    /// failures are retried for every method, as nothing was sent. Client
+    /// errors (4xx) are returned at once.
+    ///
+    /// Retries only start while the backoff still ends within the budget,
+    /// and each retry is cut off when the budget runs out.
+    pub async fn send(&self, request: RequestBuilder) -> Result<Response, CookidooError> {
+        let deadline = Instant::now() + RATE_LIMIT_BUDGET;
+        let mut attempts = 0;
+
+        loop {
+            let attempt = request.try_clone().ok_or_else(|| {
+                CookidooError::RequestError("Request body cannot be retried".to_string())
+            })?;
+            let idempotent = attempt
+                .try_clone()
+                .and_then(|attempt| attempt.build().ok())
+                .is_some_and(|request| is_idempotent(request.method()));
+            let attempt = if attempts > 0 {
+                attempt.timeout(deadline.saturating_duration_since(Instant::now()))
+            } else {
+                attempt
+            };
+            attempts += 1;
+            let backoff = INITIAL_BACKOFF * 2u32.pow(attempts - 1);
+            // Checked after the attempt, which may have used up the budget
+            let may_retry = || attempts < MAX_ATTEMPTS && Instant::now() + backoff <= deadline;
+
+            self.rate_limiter.acquire().await;
+            let response = match self.execute(attempt).await {
+                Ok(response) => response,
+                Err(e @ CookidooError::Connection(_)) if may_retry() => {
+                    warn!(error = %e, attempt = attempts, "Connection failed, retrying");
+                    tokio::time::sleep(backoff).await;
+                    continue;
+                }
+                Err(e @ CookidooError::Timeout(_)) if idempotent && may_retry() => {
+                    warn!(error = %e, attempt = attempts, "Request timed out, retrying");
+                    tokio::time::sleep(backoff).await;
+                    continue;
+                }
+                Err(e) => return Err(e),
+            };
+
+            let status = response.status();
+            if status != StatusCode::TOO_MANY_REQUESTS {
+                if idempotent && is_retryable_status(status.as_u16()) && may_retry() {
+                    warn!(
+                        status = status.as_u16(),
+                        attempt = attempts,
+                        "Server error, retrying"
+                    );
+                    tokio::time::sleep(backoff).await;
+                    continue;
+                }
+                return Ok(response);
+            }
+
+            let wait = retry_after(&response).unwrap_or(DEFAULT_RETRY_AFTER);
+            if Instant::now() + wait > deadline {
+                warn!(
+                    retry_after_ms = wait.as_millis() as u64,
+                    "Rate limited, giving up"
+                );
+                return Ok(response);
+            }
+
+            warn!(
+                retry_after_ms = wait.as_millis() as u64,
+                "Rate limited, backing off"
+            );
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// Executes a request over the network or the fixture recorder.
+    async fn execute(&self, request: RequestBuilder) -> Result<Response, CookidooError> {
+        let mut request = request.build()?;
+        self.add_headers(&mut request);
+
+        match &self.fixtures {
+            Some(fixtures) if fixtures.mode() == FixtureMode::Replay => fixtures.replay(&request),
+            Some(fixtures) => {
+                let recorded = request.try_clone();
+                let response = self.client.get()?.execute(request).await?;
+                match recorded {
+                    Some(recorded) => fixtures.record(&recorded, response).await,
+                    None => Ok(response),
+                }
+            }
+            None => Ok(self.client.get()?.execute(request).await?),
+        }
+    }
+
+    /// Adds the configured headers the request does not set itself.
+    fn add_headers(&self, request: &mut Request) {
+        let headers = request.headers_mut();
+        for (name, value) in &self.headers {
+            headers.entry(name).or_insert_with(|| value.clone());
+        }
+        if self.market_language {
+            headers
+                .entry(ACCEPT_LANGUAGE)
+                .or_insert_with(|| HeaderValue::from_static(RequestMarket::current().language()));
+        }
+    }
+}
+
+impl Default for CookidooClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns true for methods that can be repeated without changing the
+/// result (RFC 9110), so a failed attempt is safe to retry.
+fn is_idempotent(method: &Method) -> bool {
+    matches!(
+        *method,
+        Method::GET | Method::HEAD | Method::OPTIONS | Method::PUT | Method::DELETE
+    )
+}
+
+/// Parses the `Retry-After` header given in seconds.
+///
+/// The HTTP-date form is not used by Cookidoo and falls back to the default.
+fn retry_after(response: &Response) -> Option<Duration> {
+    response
+        .headers()
+        .get(RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse::<u64>()
+        .ok()
+        .map(Duration::from_secs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn creates_client_with_default_base_url() {
+        let client = CookidooClient::new();
+        assert_eq!(client.base_url(), DEFAULT_BASE_URL);
+    }
+
+    #[test]
+    fn creates_client_with_custom_base_url() {
+        let client = CookidooClient::with_base_url("https://example.com");
+        assert_eq!(client.base_url(), "https://example.com");
+    }
+
+    #[test]
+    fn builds_full_url() {
+        let client = CookidooClient::with_base_url("https://example.com");
+        assert_eq!(client.url("/api/test"), "https://example.com/api/test");
+    }
+
+    fn request(client: &CookidooClient) -> Request {
+        client
+            .inner()
+            .unwrap()
+            .get("https://example.com/api")
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn adds_configured_headers() {
+        let client = CookidooClient::with_base_url("https://example.com")
+            .with_user_agent(HeaderValue::from_static("Thermomix/2.0"))
+            .with_header(
+                HeaderName::from_static("x-client"),
+                HeaderValue::from_static("alexa"),
+            );
+        let mut request = request(&client);
+        request
+            .headers_mut()
+            .insert("x-client", HeaderValue::from_static("own"));
+
+        client.add_headers(&mut request);
+
+        assert_eq!(request.headers()[USER_AGENT], "Thermomix/2.0");
+        assert_eq!(request.headers()["x-client"], "own");
+        assert!(request.headers().get(ACCEPT_LANGUAGE).is_none());
+    }
+
+    #[tokio::test]
+    async fn sends_language_of_request_market() {
+        let client = CookidooClient::with_base_url("https://example.com").with_market_language();
+        let austria = Market::from_country_code("AT").unwrap();
+
+        let mut request = request(&client);
+        RequestMarket::scope(austria, async {
+            client.add_headers(&mut request);
+        })
+        .await;
+
+        assert_eq!(request.headers()[ACCEPT_LANGUAGE], austria.language());
+    }
+
+    #[test]
+    fn builds_market_url() {
+        let austria = Market::from_country_code("AT").unwrap();
+        assert_eq!(
+            CookidooClient::new().market_url(austria, "/api/test"),
+            "https://at.tmmobile.vorwerk-digital.com/api/test"
+        );
+        assert_eq!(
+            CookidooClient::new().market_url(Market::GERMANY, ""),
+            DEFAULT_BASE_URL
+        );
+        assert_eq!(
+            CookidooClient::with_base_url("https://example.com").market_url(austria, "/api/test"),
+            "https://example.com/api/test"
+        );
+    }
+}
@@ -0,0 +1,260 @@
+use thiserror::Error;
+use tracing::warn;
+
+use shopping_list_domain::models::DomainError;
+
+use super::models::CookidooErrorResponse;
+
+/// Error codes reported by Cookidoo that map to dedicated variants.
+mod error_codes {
+    pub const RATE_LIMITED: &[&str] = &["rate_limited", "too_many_requests"];
+    pub const LIST_FULL: &[&str] = &["list_full", "shopping_list_full", "max_items_exceeded"];
+    /// Only Cookidoo's own maintenance code: a bare 503 or a generic
+    /// "service_unavailable" may come from a single overloaded node.
+    pub const MAINTENANCE: &[&str] = &["maintenance"];
+}
+
+/// Errors specific to the Cookidoo API adapter.
+#[derive(Debug, Error)]
+pub enum CookidooError {
+    /// Network or HTTP request failed
+    #[error("Request failed: {0}")]
+    RequestError(String),
+
+    /// The request timed out; Cookidoo may or may not have processed it
+    #[error("Request timed out: {0}")]
+    Timeout(String),
+
+    /// No connection to Cookidoo could be established; nothing was sent
+    #[error("Connection failed: {0}")]
+    Connection(String),
+
+    /// Authentication failed (401, invalid credentials)
+    #[error("Authentication failed: {0}")]
+    AuthenticationError(String),
+
+    /// Bad request format (400)
+    #[error("Bad request: {0}")]
+    BadRequest(String),
+
+    /// Failed to parse JSON response
+    #[error("Failed to parse response: {0}")]
+    ParseError(String),
+
+    /// HTTP error with status code
+    #[error("HTTP error {status}: {message}")]
+    HttpError { status: u16, message: String },
+
+    /// Token has expired and refresh failed
+    #[error("Token expired and refresh failed: {0}")]
+    TokenExpired(String),
+
+    /// Too many requests (429 or rate-limit error code)
+    #[error("Rate limited: {0}")]
+    RateLimited(String),
+
+    /// The shopping list has reached its maximum size
+    #[error("Shopping list is full: {0}")]
+    ListFull(String),
+
+    /// Cookidoo is down for maintenance (maintenance error code)
+    #[error("Service in maintenance: {0}")]
+    Maintenance(String),
+}
+
+impl CookidooError {
+    /// Returns true for transient failures worth retrying later: timeouts,
+    /// connection failures, rate limits, maintenance and server errors.
+    ///
+    /// Client errors (4xx), rejected credentials, full lists and
+    /// unparseable responses are permanent; retrying them only repeats
+    /// the failure.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            CookidooError::Timeout(_)
+            | CookidooError::Connection(_)
+            | CookidooError::RateLimited(_)
+            | CookidooError::Maintenance(_) => true,
+            CookidooError::HttpError { status, .. } => is_retryable_status(*status),
+            CookidooError::RequestError(_)
+            | CookidooError::AuthenticationError(_)
+            | CookidooError::BadRequest(_)
+            | CookidooError::ParseError(_)
+            | CookidooError::TokenExpired(_)
+            | CookidooError::ListFull(_) => false,
+        }
+    }
+
+    /// Classifies a non-success API response by its error code and status.
+    ///
+    /// Known error codes take precedence over the HTTP status; unknown
+    /// errors become `HttpError` carrying the error description if present.
+    pub fn from_response(status: u16, body: &str) -> Self {
+        let parsed = CookidooErrorResponse::parse(body).unwrap_or_default();
+        let code = parsed.code.as_deref().unwrap_or_default().to_lowercase();
+        let message = parsed
+            .description
+            .clone()
+            .unwrap_or_else(|| body.to_string());
+
+        if let Some(trace_id) = &parsed.trace_id {
+            warn!(status = status, code = %code, trace_id = %trace_id, "Cookidoo API error");
+        }
+
+        if error_codes::RATE_LIMITED.contains(&code.as_str()) || status == 429 {
+            CookidooError::RateLimited(message)
+        } else if error_codes::LIST_FULL.contains(&code.as_str()) {
+            CookidooError::ListFull(message)
+        } else if error_codes::MAINTENANCE.contains(&code.as_str()) {
+            CookidooError::Maintenance(message)
+        } else {
+            CookidooError::HttpError { status, message }
+        }
+    }
+}
+
+/// Returns true for HTTP statuses signalling a transient server-side
+/// problem: request timeout, rate limit and any 5xx.
+pub(crate) fn is_retryable_status(status: u16) -> bool {
+    status == 408 || status == 429 || status >= 500
+}
+
+impl From<reqwest::Error> for CookidooError {
+    fn from(err: reqwest::Error) -> Self {
+        if err.is_timeout() {
+            CookidooError::Timeout(err.to_string())
+        } else if err.is_connect() {
+            CookidooError::Connection(err.to_string())
+        } else {
+            CookidooError::RequestError(err.to_string())
+        }
+    }
+}
+
+impl From<CookidooError> for DomainError {
+    fn from(err: CookidooError) -> Self {
+        match err {
+            CookidooError::AuthenticationError(msg) => DomainError::AuthenticationFailed(msg),
+            CookidooError::TokenExpired(msg) => DomainError::AuthenticationFailed(msg),
+            CookidooError::RateLimited(msg) => DomainError::RateLimited(msg),
+            CookidooError::ListFull(msg) => DomainError::ListFull(msg),
+            CookidooError::Maintenance(msg) => DomainError::ServiceUnavailable(msg),
+            CookidooError::HttpError {
+                status: 403,
+                message,
+            } => DomainError::PermissionDenied(message),
+            other if other.is_retryable() => DomainError::Unreachable(other.to_string()),
+            other => DomainError::RepositoryError(other.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_not_found_and_forbidden_to_domain_errors() {
+        let not_found: DomainError = CookidooError::from_response(404, "gone").into();
+        let forbidden: DomainError = CookidooError::from_response(403, "no plan").into();
+
+        // A missing endpoint or list is a repository failure; items the
+        // user named but that are not on the list are found by the domain
+        assert!(matches!(not_found, DomainError::RepositoryError(_)));
+        assert!(matches!(forbidden, DomainError::PermissionDenied(_)));
+    }
+
+    #[test]
+    fn server_errors_and_network_failures_are_retryable() {
+        assert!(CookidooError::from_response(500, "").is_retryable());
+        assert!(CookidooError::from_response(503, "").is_retryable());
+        assert!(CookidooError::from_response(429, "").is_retryable());
+        assert!(CookidooError::Timeout("30s".to_string()).is_retryable());
+        assert!(CookidooError::Connection("refused".to_string()).is_retryable());
+    }
+
+    #[test]
+    fn client_errors_are_permanent() {
+        assert!(!CookidooError::from_response(400, "").is_retryable());
+        assert!(!CookidooError::from_response(404, "").is_retryable());
+        assert!(!CookidooError::AuthenticationError("401".to_string()).is_retryable());
+        assert!(!CookidooError::ParseError("eof".to_string()).is_retryable());
+        assert!(!CookidooError::from_response(400, r#"{"code": "list_full"}"#).is_retryable());
+    }
+
+    #[test]
+    fn transient_failures_map_to_unreachable() {
+        let timeout: DomainError = CookidooError::Timeout("30s".to_string()).into();
+        let server_error: DomainError = CookidooError::from_response(502, "").into();
+        let client_error: DomainError = CookidooError::from_response(400, "").into();
+
+        assert!(matches!(timeout, DomainError::Unreachable(_)));
+        assert!(matches!(server_error, DomainError::Unreachable(_)));
+        assert!(matches!(client_error, DomainError::RepositoryError(_)));
+    }
+
+    #[test]
+    fn classifies_rate_limit_by_status() {
+        let err = CookidooError::from_response(429, "");
+        assert!(matches!(err, CookidooError::RateLimited(_)));
+    }
+
+    #[test]
+    fn classifies_list_full_by_code() {
+        let err = CookidooError::from_response(
+            400,
+            r#"{"code": "LIST_FULL", "message": "Too many items"}"#,
+        );
+        assert!(matches!(err, CookidooError::ListFull(msg) if msg == "Too many items"));
+    }
+
+    #[test]
+    fn classifies_maintenance_by_code_only() {
+        let maintenance =
+            CookidooError::from_response(503, r#"{"code": "maintenance", "message": "Wartung"}"#);
+        let unavailable = CookidooError::from_response(503, "<html>Wartung</html>");
+
+        assert!(matches!(maintenance, CookidooError::Maintenance(msg) if msg == "Wartung"));
+        assert!(matches!(
+            unavailable,
+            CookidooError::HttpError { status: 503, .. }
+        ));
+    }
+
+    #[test]
+    fn unknown_error_uses_description_as_message() {
+        let err = CookidooError::from_response(
+            500,
+            r#"{"code": "internal", "message": "Boom", "traceId": "t-1"}"#,
+        );
+        assert!(matches!(
+            err,
+            CookidooError::HttpError { status: 500, message } if message == "Boom"
+        ));
+    }
+
+    #[test]
+    fn unparseable_body_is_kept_as_message() {
+        let err = CookidooError::from_response(500, "Internal Server Error");
+        assert!(matches!(
+            err,
+            CookidooError::HttpError { message, .. } if message == "Internal Server Error"
+        ));
+    }
+
+    #[test]
+    fn maps_known_errors_to_domain_errors() {
+        assert!(matches!(
+            DomainError::from(CookidooError::RateLimited(String::new())),
+            DomainError::RateLimited(_)
+        ));
+        assert!(matches!(
+            DomainError::from(CookidooError::ListFull(String::new())),
+            DomainError::ListFull(_)
+        ));
+        assert!(matches!(
+            DomainError::from(CookidooError::Maintenance(String::new())),
+            DomainError::ServiceUnavailable(_)
+        ));
+    }
+}
@@ -0,0 +1,309 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use reqwest::header::CONTENT_TYPE;
+use reqwest::{Request, Response};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tracing::info;
+
+use super::error::CookidooError;
+
+/// JSON keys whose string values are replaced before a fixture is written.
+const SENSITIVE_KEYS: &[&str] = &[
+    "access_token",
+    "refresh_token",
+    "id_token",
+    "password",
+    "username",
+    "email",
+    "client_secret",
+    "sub",
+    "jti",
+];
+
+/// Replacement for sensitive values in recorded fixtures.
+const REDACTED: &str = "REDACTED";
+
+/// Response headers kept in recorded fixtures.
+const RECORDED_HEADERS: &[&str] = &["content-type", "retry-after"];
+
+/// Whether Cookidoo traffic is recorded to or replayed from fixtures.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FixtureMode {
+    /// Send requests to Cookidoo and write each response to a fixture.
+    Record,
+    /// Answer requests from fixtures without touching the network.
+    Replay,
+}
+
+impl FixtureMode {
+    /// Parses `record` or `replay` (case-insensitive).
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "record" => Some(Self::Record),
+            "replay" => Some(Self::Replay),
+            _ => None,
+        }
+    }
+}
+
+/// A recorded request/response pair.
+#[derive(Debug, Serialize, Deserialize)]
+struct Fixture {
+    request: RecordedRequest,
+    response: RecordedResponse,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct RecordedRequest {
+    method: String,
+    path: String,
+    /// Sanitized JSON body; form and other bodies are not recorded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    body: Option<Value>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct RecordedResponse {
+    status: u16,
+    #[serde(default)]
+    headers: HashMap<String, String>,
+    /// JSON body, or a string for non-JSON bodies.
+    body: Value,
+}
+
+/// Transport decorator that records Cookidoo responses to sanitized JSON
+/// fixtures or replays them.
+///
+/// Fixtures are named after method and path plus a per-endpoint sequence
+/// number (`post_ciam_auth_token.1.json`), so a replayed session sees the
+/// responses in the order they were recorded. Tokens, credentials and
+/// e-mail addresses are redacted before anything is written.
+pub struct FixtureRecorder {
+    mode: FixtureMode,
+    dir: PathBuf,
+    counters: Mutex<HashMap<String, usize>>,
+}
+
+impl FixtureRecorder {
+    /// Creates a recorder for the given mode and fixture directory.
+    pub fn new(mode: FixtureMode, dir: impl Into<PathBuf>) -> Self {
+        Self {
+            mode,
+            dir: dir.into(),
+            counters: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Returns the fixture mode.
+    pub fn mode(&self) -> FixtureMode {
+        self.mode
+    }
+
+    /// Answers a request from its fixture.
+    pub fn replay(&self, request: &Request) -> Result<Response, CookidooError> {
+        let path = self.next_fixture_path(request);
+        let content = fs::read_to_string(&path).map_err(|e| {
+            CookidooError::RequestError(format!(
+                "No recorded fixture {} for {} {}: {}",
+                path.display(),
+                request.method(),
+                request.url().path(),
+                e
+            ))
+        })?;
+        let fixture: Fixture = serde_json::from_str(&content)
+            .map_err(|e| CookidooError::ParseError(format!("{}: {}", path.display(), e)))?;
+
+        let body = match fixture.response.body {
+            Value::String(text) => text.into_bytes(),
+            json => json.to_string().into_bytes(),
+        };
+        to_response(fixture.response.status, &fixture.response.headers, body)
+    }
+
+    /// Writes a sanitized fixture for the request and its response, returning
+    /// an equivalent response for the caller.
+    pub async fn record(
+        &self,
+        request: &Request,
+        response: Response,
+    ) -> Result<Response, CookidooError> {
+        let status = response.status().as_u16();
+        let headers: HashMap<String, String> = RECORDED_HEADERS
+            .iter()
+            .filter_map(|name| {
+                let value = response.headers().get(*name)?.to_str().ok()?;
+                Some((name.to_string(), value.to_string()))
+            })
+            .collect();
+        let bytes = response.bytes().await?.to_vec();
+
+        let is_json_request = request
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .is_some_and(|value| value.starts_with("application/json"));
+        let request_body = request
+            .body()
+            .and_then(|body| body.as_bytes())
+            .filter(|_| is_json_request)
+            .and_then(|bytes| serde_json::from_slice(bytes).ok())
+            .map(sanitize);
+        let response_body = match serde_json::from_slice(&bytes) {
+            Ok(json) => sanitize(json),
+            Err(_) => Value::String(String::from_utf8_lossy(&bytes).into_owned()),
+        };
+
+        let fixture = Fixture {
+            request: RecordedRequest {
+                method: request.method().to_string(),
+                path: request.url().path().to_string(),
+                body: request_body,
+            },
+            response: RecordedResponse {
+                status,
+                headers: headers.clone(),
+                body: response_body,
+            },
+        };
+
+        let path = self.next_fixture_path(request);
+        let content = serde_json::to_string_pretty(&fixture)
+            .map_err(|e| CookidooError::ParseError(e.to_string()))?;
+        fs::create_dir_all(&self.dir)
+            .and_then(|_| fs::write(&path, content + "\n"))
+            .map_err(|e| {
+                CookidooError::RequestError(format!("Failed to write {}: {}", path.display(), e))
+            })?;
+        info!(fixture = %path.display(), "Recorded Cookidoo response");
+
+        to_response(status, &headers, bytes)
+    }
+
+    fn next_fixture_path(&self, request: &Request) -> PathBuf {
+        let key = fixture_key(request.method().as_str(), request.url().path());
+        let mut counters = self.counters.lock().unwrap_or_else(|e| e.into_inner());
+        let count = counters.entry(key.clone()).or_insert(0);
+        *count += 1;
+        self.dir.join(format!("{}.{}.json", key, count))
+    }
+}
+
+/// Builds a file name stem such as `post_ciam_auth_token`.
+fn fixture_key(method: &str, path: &str) -> String {
+    let path: String = path
+        .trim_matches('/')
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    format!("{}_{}", method.to_ascii_lowercase(), path)
+}
+
+/// Replaces the values of sensitive keys, recursively.
+fn sanitize(value: Value) -> Value {
+    match value {
+        Value::Object(map) => Value::Object(
+            map.into_iter()
+                .map(|(key, value)| {
+                    let value = if SENSITIVE_KEYS.contains(&key.to_ascii_lowercase().as_str()) {
+                        Value::String(REDACTED.to_string())
+                    } else {
+                        sanitize(value)
+                    };
+                    (key, value)
+                })
+                .collect(),
+        ),
+        Value::Array(values) => Value::Array(values.into_iter().map(sanitize).collect()),
+        other => other,
+    }
+}
+
+fn to_response(
+    status: u16,
+    headers: &HashMap<String, String>,
+    body: Vec<u8>,
+) -> Result<Response, CookidooError> {
+    let mut builder = http::Response::builder().status(status);
+    for (name, value) in headers {
+        builder = builder.header(name.as_str(), value.as_str());
+    }
+    if !headers.contains_key(CONTENT_TYPE.as_str()) {
+        builder = builder.header(CONTENT_TYPE, "application/json");
+    }
+    builder
+        .body(body)
+        .map(Response::from)
+        .map_err(|e| CookidooError::ParseError(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_modes() {
+        assert_eq!(FixtureMode::parse("Record"), Some(FixtureMode::Record));
+        assert_eq!(FixtureMode::parse(" replay "), Some(FixtureMode::Replay));
+        assert_eq!(FixtureMode::parse("live"), None);
+    }
+
+    #[test]
+    fn builds_fixture_keys_from_method_and_path() {
+        assert_eq!(
+            fixture_key("POST", "/shopping/de-DE/additional-items/add"),
+            "post_shopping_de_de_additional_items_add"
+        );
+        assert_eq!(fixture_key("GET", "/"), "get_");
+    }
+
+    #[test]
+    fn redacts_sensitive_values_recursively() {
+        let sanitized = sanitize(serde_json::json!({
+            "access_token": "secret",
+            "expires_in": 3600,
+            "user": {"Email": "me@example.com", "name": "Me", "sub": "account-id"},
+            "jti": "token-id",
+            "items": [{"refresh_token": "secret"}]
+        }));
+
+        assert_eq!(
+            sanitized,
+            serde_json::json!({
+                "access_token": "REDACTED",
+                "expires_in": 3600,
+                "user": {"Email": "REDACTED", "name": "Me", "sub": "REDACTED"},
+                "jti": "REDACTED",
+                "items": [{"refresh_token": "REDACTED"}]
+            })
+        );
+    }
+
+    #[test]
+    fn numbers_repeated_requests() {
+        let recorder = FixtureRecorder::new(FixtureMode::Replay, "fixtures");
+        let request = Request::new(
+            reqwest::Method::GET,
+            "https://example.com/items".parse().unwrap(),
+        );
+
+        assert_eq!(
+            recorder.next_fixture_path(&request),
+            PathBuf::from("fixtures/get_items.1.json")
+        );
+        assert_eq!(
+            recorder.next_fixture_path(&request),
+            PathBuf::from("fixtures/get_items.2.json")
+        );
+    }
+}
@@ -0,0 +1,86 @@
+use std::fmt;
+use std::sync::{Arc, OnceLock};
+
+use reqwest::Client;
+
+/// TLS backend the HTTP clients use, chosen at compile time by the
+/// `rustls` and `native-tls` features (reqwest prefers native-tls when both
+/// are enabled).
+pub const TLS_BACKEND: &str = if cfg!(feature = "native-tls") {
+    "native-tls"
+} else if cfg!(feature = "rustls") {
+    "rustls"
+} else {
+    "none"
+};
+
+/// Builds the HTTP client of a [`LazyClient`].
+type BuildClient = dyn Fn() -> reqwest::Result<Client> + Send + Sync;
+
+/// HTTP client built on first use.
+///
+/// Building a reqwest client loads the TLS root certificates, which takes
+/// milliseconds per client; adapters created at cold start but not needed
+/// by the first request (reminders, device address) thus add nothing to
+/// it. Clones share the client.
+#[derive(Clone)]
+pub struct LazyClient {
+    client: Arc<OnceLock<Client>>,
+    build: Arc<BuildClient>,
+}
+
+impl LazyClient {
+    /// Creates a client built with `build` when first used.
+    pub fn new(build: impl Fn() -> reqwest::Result<Client> + Send + Sync + 'static) -> Self {
+        Self {
+            client: Arc::new(OnceLock::new()),
+            build: Arc::new(build),
+        }
+    }
+
+    /// Returns the client, building it on the first call.
+    ///
+    /// # Errors
+    /// Returns the build error if the client cannot be built, e.g. when no
+    /// TLS backend is available; the next call tries again.
+    pub fn get(&self) -> reqwest::Result<&Client> {
+        if let Some(client) = self.client.get() {
+            return Ok(client);
+        }
+        let client = (self.build)()?;
+        Ok(self.client.get_or_init(|| client))
+    }
+}
+
+impl fmt::Debug for LazyClient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LazyClient")
+            .field("built", &self.client.get().is_some())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn builds_once_on_first_use() {
+        let builds = Arc::new(AtomicUsize::new(0));
+        let client = LazyClient::new({
+            let builds = builds.clone();
+            move || {
+                builds.fetch_add(1, Ordering::SeqCst);
+                Client::builder().build()
+            }
+        });
+        let clone = client.clone();
+        assert_eq!(builds.load(Ordering::SeqCst), 0);
+
+        client.get().unwrap();
+        clone.get().unwrap();
+
+        assert_eq!(builds.load(Ordering::SeqCst), 1);
+    }
+}
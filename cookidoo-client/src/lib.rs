@@ -0,0 +1,74 @@
+//! Client for the Cookidoo API: sign-in, token refresh and the shopping
+//! list, independent of Alexa and AWS.
+//!
+//! [`CookidooShoppingListAdapter`] implements the
+//! [`ShoppingListRepository`](shopping_list_domain::ports::ShoppingListRepository)
+//! port of the `shopping-list-domain` crate on a [`CookidooAuthAdapter`],
+//! which signs in and keeps the token fresh:
+//!
+//! ```no_run
+//! use std::sync::Arc;
+//!
+//! use cookidoo_client::{CookidooAuthAdapter, CookidooClient, CookidooShoppingListAdapter};
+//! use shopping_list_domain::models::{CookidooCredentials, ShoppingListItem};
+//! use shopping_list_domain::ports::ShoppingListRepository;
+//!
+//! # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = CookidooClient::new();
+//! let auth = CookidooAuthAdapter::new(
+//!     client.clone(),
+//!     CookidooCredentials::new("me@example.com", "password"),
+//!     "client-id",
+//!     "client-secret",
+//! );
+//! let list = CookidooShoppingListAdapter::new(client, Arc::new(auth));
+//!
+//! list.add_item(&ShoppingListItem::new("Milch")?).await?;
+//! for entry in list.list_items().await? {
+//!     println!("{}", entry.name());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Requests go to the German market unless run inside
+//! [`RequestMarket::scope`].
+//!
+//! HTTPS goes through rustls by default; build with
+//! `default-features = false, features = ["native-tls"]` to link the
+//! platform's OpenSSL instead. [`TLS_BACKEND`] names the backend built in.
+
+mod account_registry;
+mod auth;
+mod auth_header;
+mod client;
+mod error;
+mod fixture_recorder;
+mod lazy_client;
+mod list_cache;
+mod maintenance_window;
+mod models;
+mod pkce;
+mod proxy;
+mod rate_limiter;
+mod request_market;
+mod shopping_list;
+mod token_cache;
+mod token_provider;
+
+pub use account_registry::{AccountRegistry, RequestAccount};
+pub use auth::CookidooAuthAdapter;
+pub use auth_header::{AuthHeader, AuthHeaderError};
+pub use client::CookidooClient;
+pub use error::CookidooError;
+pub use fixture_recorder::{FixtureMode, FixtureRecorder};
+pub use lazy_client::{LazyClient, TLS_BACKEND};
+pub use list_cache::ListCache;
+pub use maintenance_window::MaintenanceWindow;
+pub use pkce::Pkce;
+pub use proxy::ProxyConfig;
+pub use rate_limiter::RateLimiter;
+pub use request_market::RequestMarket;
+pub use shopping_list::CookidooShoppingListAdapter;
+pub use token_cache::TokenCache;
+pub use token_provider::{ProvidedToken, RequestToken, TokenProvider, TokenSource};
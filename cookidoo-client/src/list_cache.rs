@@ -0,0 +1,343 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use tokio::sync::OnceCell;
+use tokio::time::Instant;
+use tracing::debug;
+
+use shopping_list_domain::models::{DomainError, ShoppingListEntry};
+
+/// Result of a shopping list fetch shared between coalesced callers.
+type ListResult = Result<Vec<ShoppingListEntry>, DomainError>;
+
+/// Time a fetched list is reused unless set with [`ListCache::with_ttl`].
+const DEFAULT_TTL: Duration = Duration::from_secs(30);
+
+/// A fetch in flight, shared by the callers waiting for it.
+type InFlight = Arc<OnceCell<ListResult>>;
+
+/// The last list fetched, and for which account.
+struct Snapshot {
+    key: String,
+    fetched_at: Instant,
+    entries: Vec<ShoppingListEntry>,
+}
+
+/// Caches the shopping list briefly and coalesces concurrent fetches.
+///
+/// A session that reads, checks and removes items in several turns reuses
+/// the list fetched in the first turn for the TTL instead of fetching it
+/// from Cookidoo each time; every write invalidates it. When several
+/// requests need the list at the same time (e.g. a family talking to
+/// multiple devices), only the first caller performs the fetch; all callers
+/// that arrive while it is in flight receive the same result.
+///
+/// Lists are cached per key, so the lists of different accounts (e.g.
+/// linked accounts in one warm Lambda) never mix, and fetches of different
+/// accounts run side by side. Only the latest list is kept; errors are not
+/// cached.
+pub struct ListCache {
+    ttl: Duration,
+    in_flight: Mutex<HashMap<String, InFlight>>,
+    snapshot: Mutex<Option<Snapshot>>,
+}
+
+impl ListCache {
+    /// Creates an empty list cache reusing lists for 30 seconds.
+    pub fn new() -> Self {
+        Self {
+            ttl: DEFAULT_TTL,
+            in_flight: Mutex::new(HashMap::new()),
+            snapshot: Mutex::new(None),
+        }
+    }
+
+    /// Replaces the time a fetched list is reused; zero only coalesces
+    /// concurrent fetches.
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Returns the list for `key`: the cached one if it is fresh, else the
+    /// result of an in-flight fetch for the same key, else a new fetch.
+    ///
+    /// `fetch` is only called when neither is available. If the leading
+    /// caller is cancelled, a waiting caller takes over the fetch.
+    pub async fn get_or_fetch<F, Fut>(&self, key: &str, fetch: F) -> ListResult
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = ListResult>,
+    {
+        if let Some(entries) = self.fresh(key) {
+            debug!("Using cached shopping list");
+            return Ok(entries);
+        }
+
+        let cell = {
+            let mut guard = self.in_flight.lock().unwrap_or_else(|e| e.into_inner());
+            match guard.get(key) {
+                Some(cell) => {
+                    debug!("Joining in-flight shopping list fetch");
+                    cell.clone()
+                }
+                None => {
+                    let cell = Arc::new(OnceCell::new());
+                    guard.insert(key.to_string(), cell.clone());
+                    cell
+                }
+            }
+        };
+
+        let result = cell.get_or_init(fetch).await.clone();
+        self.release(key, &cell, &result);
+        result
+    }
+
+    /// Drops the cached list and detaches all in-flight fetches, so the
+    /// next caller starts a new one.
+    ///
+    /// Called after the list was modified, since a list fetched before the
+    /// modification does not reflect it.
+    pub fn invalidate(&self) {
+        self.in_flight
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clear();
+        *self.snapshot.lock().unwrap_or_else(|e| e.into_inner()) = None;
+    }
+
+    /// Returns the cached list for `key` if it is younger than the TTL.
+    fn fresh(&self, key: &str) -> Option<Vec<ShoppingListEntry>> {
+        let snapshot = self.snapshot.lock().unwrap_or_else(|e| e.into_inner());
+        snapshot
+            .as_ref()
+            .filter(|snapshot| snapshot.key == key && snapshot.fetched_at.elapsed() < self.ttl)
+            .map(|snapshot| snapshot.entries.clone())
+    }
+
+    /// Clears the key's in-flight slot if it still holds the given fetch,
+    /// keeping its list if it succeeded.
+    fn release(&self, key: &str, cell: &InFlight, result: &ListResult) {
+        let mut guard = self.in_flight.lock().unwrap_or_else(|e| e.into_inner());
+        if !guard
+            .get(key)
+            .is_some_and(|current| Arc::ptr_eq(current, cell))
+        {
+            // Invalidated while in flight: the result may be outdated
+            return;
+        }
+        guard.remove(key);
+
+        if let Ok(entries) = result {
+            *self.snapshot.lock().unwrap_or_else(|e| e.into_inner()) = Some(Snapshot {
+                key: key.to_string(),
+                fetched_at: Instant::now(),
+                entries: entries.clone(),
+            });
+        }
+    }
+}
+
+impl Default for ListCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    const KEY: &str = "account";
+
+    fn entries() -> Vec<ShoppingListEntry> {
+        vec![ShoppingListEntry::new("id-1", "Milch", false)]
+    }
+
+    async fn slow_fetch(calls: &AtomicUsize) -> ListResult {
+        calls.fetch_add(1, Ordering::SeqCst);
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        Ok(entries())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn coalesces_concurrent_fetches() {
+        let cache = ListCache::new();
+        let calls = AtomicUsize::new(0);
+
+        let (a, b, c) = tokio::join!(
+            cache.get_or_fetch(KEY, || slow_fetch(&calls)),
+            cache.get_or_fetch(KEY, || slow_fetch(&calls)),
+            cache.get_or_fetch(KEY, || slow_fetch(&calls)),
+        );
+
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert_eq!(a.unwrap(), entries());
+        assert_eq!(b.unwrap(), entries());
+        assert_eq!(c.unwrap(), entries());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn reuses_list_within_ttl() {
+        let cache = ListCache::new().with_ttl(Duration::from_secs(30));
+        let calls = AtomicUsize::new(0);
+
+        cache
+            .get_or_fetch(KEY, || slow_fetch(&calls))
+            .await
+            .unwrap();
+        tokio::time::advance(Duration::from_secs(20)).await;
+        let second = cache.get_or_fetch(KEY, || slow_fetch(&calls)).await;
+
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert_eq!(second.unwrap(), entries());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn fetches_again_after_ttl() {
+        let cache = ListCache::new().with_ttl(Duration::from_secs(30));
+        let calls = AtomicUsize::new(0);
+
+        cache
+            .get_or_fetch(KEY, || slow_fetch(&calls))
+            .await
+            .unwrap();
+        tokio::time::advance(Duration::from_secs(30)).await;
+        cache
+            .get_or_fetch(KEY, || slow_fetch(&calls))
+            .await
+            .unwrap();
+
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn zero_ttl_only_coalesces() {
+        let cache = ListCache::new().with_ttl(Duration::ZERO);
+        let calls = AtomicUsize::new(0);
+
+        cache
+            .get_or_fetch(KEY, || slow_fetch(&calls))
+            .await
+            .unwrap();
+        cache
+            .get_or_fetch(KEY, || slow_fetch(&calls))
+            .await
+            .unwrap();
+
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn keeps_lists_of_accounts_apart() {
+        let cache = ListCache::new();
+        let calls = AtomicUsize::new(0);
+
+        cache
+            .get_or_fetch("a", || slow_fetch(&calls))
+            .await
+            .unwrap();
+        let other = cache
+            .get_or_fetch("b", || async {
+                calls.fetch_add(1, Ordering::SeqCst);
+                Ok(Vec::new())
+            })
+            .await;
+
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+        assert!(other.unwrap().is_empty());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn coalesces_fetches_of_accounts_in_parallel() {
+        let cache = ListCache::new();
+        let calls = AtomicUsize::new(0);
+
+        let (a1, b, a2) = tokio::join!(
+            cache.get_or_fetch("a", || slow_fetch(&calls)),
+            cache.get_or_fetch("b", || slow_fetch(&calls)),
+            cache.get_or_fetch("a", || slow_fetch(&calls)),
+        );
+
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+        assert!(a1.is_ok() && a2.is_ok() && b.is_ok());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn does_not_cache_errors() {
+        let cache = ListCache::new();
+        let calls = AtomicUsize::new(0);
+
+        let failed = cache
+            .get_or_fetch(KEY, || async {
+                calls.fetch_add(1, Ordering::SeqCst);
+                Err(DomainError::RepositoryError("boom".to_string()))
+            })
+            .await;
+        let retried = cache.get_or_fetch(KEY, || slow_fetch(&calls)).await;
+
+        assert!(failed.is_err());
+        assert!(retried.is_ok());
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn invalidate_drops_cached_list() {
+        let cache = ListCache::new();
+        let calls = AtomicUsize::new(0);
+
+        cache
+            .get_or_fetch(KEY, || slow_fetch(&calls))
+            .await
+            .unwrap();
+        cache.invalidate();
+        cache
+            .get_or_fetch(KEY, || slow_fetch(&calls))
+            .await
+            .unwrap();
+
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn shares_errors_with_joined_callers() {
+        let cache = ListCache::new();
+        let calls = AtomicUsize::new(0);
+        let failing = || async {
+            calls.fetch_add(1, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            Err(DomainError::RepositoryError("boom".to_string()))
+        };
+
+        let (a, b) = tokio::join!(
+            cache.get_or_fetch(KEY, failing),
+            cache.get_or_fetch(KEY, failing)
+        );
+
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert!(matches!(a, Err(DomainError::RepositoryError(_))));
+        assert!(matches!(b, Err(DomainError::RepositoryError(_))));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn invalidate_starts_a_new_fetch() {
+        let cache = ListCache::new();
+        let calls = AtomicUsize::new(0);
+
+        let first = cache.get_or_fetch(KEY, || slow_fetch(&calls));
+        let second = async {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            cache.invalidate();
+            cache.get_or_fetch(KEY, || slow_fetch(&calls)).await
+        };
+        let (a, b) = tokio::join!(first, second);
+
+        assert!(a.is_ok());
+        assert!(b.is_ok());
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+}
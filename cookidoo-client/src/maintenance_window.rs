@@ -0,0 +1,117 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use shopping_list_domain::models::Market;
+use tokio::time::Instant;
+
+/// Time Cookidoo is assumed to stay in maintenance after it reported it.
+const DEFAULT_DURATION: Duration = Duration::from_secs(60);
+
+/// Remembers that Cookidoo reported maintenance in a market, until when.
+///
+/// A request during Cookidoo maintenance waits for the maintenance response
+/// (or its timeout); while the window is open, further requests to that
+/// market are answered at once instead. Markets run on their own hosts, so
+/// each has its own window. The window is short, so the skill notices soon
+/// when Cookidoo is back.
+#[derive(Debug)]
+pub struct MaintenanceWindow {
+    duration: Duration,
+    until: Mutex<HashMap<&'static str, Instant>>,
+}
+
+impl MaintenanceWindow {
+    /// Creates windows, closed in every market, that open for 60 seconds
+    /// at a time.
+    pub fn new() -> Self {
+        Self {
+            duration: DEFAULT_DURATION,
+            until: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Replaces the time the window stays open.
+    pub fn with_duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Opens the window of `market`, or extends it if it is already open.
+    pub fn open(&self, market: Market) {
+        self.until
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(market.country_code(), Instant::now() + self.duration);
+    }
+
+    /// Returns the time until the window of `market` closes, or `None` if
+    /// it is closed.
+    pub fn remaining(&self, market: Market) -> Option<Duration> {
+        let until = *self
+            .until
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(market.country_code())?;
+        until
+            .checked_duration_since(Instant::now())
+            .filter(|remaining| !remaining.is_zero())
+    }
+}
+
+impl Default for MaintenanceWindow {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn starts_closed() {
+        assert_eq!(MaintenanceWindow::new().remaining(Market::GERMANY), None);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn stays_open_for_its_duration() {
+        let window = MaintenanceWindow::new().with_duration(Duration::from_secs(30));
+
+        window.open(Market::GERMANY);
+        tokio::time::advance(Duration::from_secs(20)).await;
+        assert_eq!(
+            window.remaining(Market::GERMANY),
+            Some(Duration::from_secs(10))
+        );
+
+        tokio::time::advance(Duration::from_secs(10)).await;
+        assert_eq!(window.remaining(Market::GERMANY), None);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn reopening_extends_window() {
+        let window = MaintenanceWindow::new().with_duration(Duration::from_secs(30));
+
+        window.open(Market::GERMANY);
+        tokio::time::advance(Duration::from_secs(20)).await;
+        window.open(Market::GERMANY);
+
+        assert_eq!(
+            window.remaining(Market::GERMANY),
+            Some(Duration::from_secs(30))
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn markets_have_their_own_window() {
+        let window = MaintenanceWindow::new();
+
+        let austria = Market::from_country_code("AT").unwrap();
+
+        window.open(Market::GERMANY);
+
+        assert!(window.remaining(Market::GERMANY).is_some());
+        assert_eq!(window.remaining(austria), None);
+    }
+}
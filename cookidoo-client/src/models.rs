@@ -0,0 +1,265 @@
+use serde::{Deserialize, Serialize};
+
+use shopping_list_domain::models::Secret;
+
+/// Response from the Cookidoo OAuth token endpoint.
+#[derive(Debug, Deserialize)]
+pub struct CookidooAuthResponse {
+    pub access_token: Secret,
+    pub refresh_token: Secret,
+    /// Token lifetime in seconds
+    pub expires_in: u64,
+}
+
+/// Error body returned by the Cookidoo API.
+///
+/// The OAuth endpoints use `error`/`error_description`, the shopping list
+/// API uses `code`/`message`; both shapes are accepted.
+#[derive(Debug, Default, Deserialize)]
+pub struct CookidooErrorResponse {
+    #[serde(alias = "error")]
+    pub code: Option<String>,
+    #[serde(alias = "error_description", alias = "message")]
+    pub description: Option<String>,
+    #[serde(alias = "traceId", alias = "trace_id")]
+    pub trace_id: Option<String>,
+}
+
+impl CookidooErrorResponse {
+    /// Parses an error body, returning `None` if it is not a JSON error object.
+    pub fn parse(body: &str) -> Option<Self> {
+        serde_json::from_str(body).ok()
+    }
+}
+
+/// Request body for adding items to the shopping list.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddItemRequest {
+    pub items_value: Vec<String>,
+    /// Category (aisle) for the items; Cookidoo uses "Sonstiges" if absent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+}
+
+impl AddItemRequest {
+    /// Creates a request adding the given items with one call.
+    pub fn for_items<I, S>(names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            items_value: names.into_iter().map(Into::into).collect(),
+            category: None,
+        }
+    }
+
+    pub fn with_category(mut self, category: impl Into<String>) -> Self {
+        self.category = Some(category.into());
+        self
+    }
+}
+
+/// Request body for removing items from the shopping list.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RemoveItemsRequest {
+    pub additional_item_ids: Vec<String>,
+}
+
+impl RemoveItemsRequest {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            additional_item_ids: vec![id.into()],
+        }
+    }
+}
+
+/// Request body for checking items off (or back on) the shopping list.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OwnershipEditRequest {
+    pub additional_items: Vec<OwnershipChange>,
+}
+
+/// New ownership state of a single item.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OwnershipChange {
+    pub id: String,
+    pub is_owned: bool,
+    pub ownership_changed: bool,
+}
+
+impl OwnershipEditRequest {
+    /// Marks the item with the given ID as bought.
+    pub fn owned(id: impl Into<String>) -> Self {
+        Self {
+            additional_items: vec![OwnershipChange {
+                id: id.into(),
+                is_owned: true,
+                ownership_changed: true,
+            }],
+        }
+    }
+}
+
+/// Response from the add additional items endpoint.
+#[derive(Debug, Deserialize)]
+pub struct AddItemResponse {
+    #[serde(default)]
+    pub data: Vec<CookidooItem>,
+}
+
+/// Response from the additional items list endpoint.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdditionalItemsResponse {
+    #[serde(default)]
+    pub additional_items: Vec<CookidooItem>,
+    /// Page to request next, if Cookidoo split the list into pages; not
+    /// documented by Cookidoo, see `list_items_internal` for how it is used.
+    #[serde(default)]
+    pub next_page: Option<u32>,
+}
+
+/// An additional (non-recipe) item on the Cookidoo shopping list.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CookidooItem {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub is_owned: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserializes_auth_response() {
+        let json = r#"{
+            "access_token": "abc123",
+            "refresh_token": "xyz789",
+            "expires_in": 3600,
+            "token_type": "Bearer"
+        }"#;
+
+        let response: CookidooAuthResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(response.access_token.expose(), "abc123");
+        assert_eq!(response.refresh_token.expose(), "xyz789");
+        assert_eq!(response.expires_in, 3600);
+    }
+
+    #[test]
+    fn deserializes_oauth_error_response() {
+        let json = r#"{
+            "error": "invalid_grant",
+            "error_description": "Invalid credentials"
+        }"#;
+
+        let response = CookidooErrorResponse::parse(json).unwrap();
+        assert_eq!(response.code.as_deref(), Some("invalid_grant"));
+        assert_eq!(response.description.as_deref(), Some("Invalid credentials"));
+        assert!(response.trace_id.is_none());
+    }
+
+    #[test]
+    fn deserializes_api_error_response() {
+        let json = r#"{
+            "code": "LIST_FULL",
+            "message": "Too many items",
+            "traceId": "abc-123"
+        }"#;
+
+        let response = CookidooErrorResponse::parse(json).unwrap();
+        assert_eq!(response.code.as_deref(), Some("LIST_FULL"));
+        assert_eq!(response.description.as_deref(), Some("Too many items"));
+        assert_eq!(response.trace_id.as_deref(), Some("abc-123"));
+    }
+
+    #[test]
+    fn error_response_parse_rejects_non_json() {
+        assert!(CookidooErrorResponse::parse("Internal Server Error").is_none());
+    }
+
+    #[test]
+    fn serializes_add_item_request_with_category() {
+        let request = AddItemRequest::for_items(["Milch"]).with_category("Milchprodukte");
+        let json = serde_json::to_string(&request).unwrap();
+        assert_eq!(
+            json,
+            r#"{"itemsValue":["Milch"],"category":"Milchprodukte"}"#
+        );
+    }
+
+    #[test]
+    fn serializes_request_for_several_items() {
+        let request = AddItemRequest::for_items(["Brötchen", "Butter"]);
+        let json = serde_json::to_string(&request).unwrap();
+        assert_eq!(json, r#"{"itemsValue":["Brötchen","Butter"]}"#);
+    }
+
+    #[test]
+    fn serializes_remove_items_request() {
+        let request = RemoveItemsRequest::new("id-1");
+        let json = serde_json::to_string(&request).unwrap();
+        assert_eq!(json, r#"{"additionalItemIds":["id-1"]}"#);
+    }
+
+    #[test]
+    fn serializes_ownership_edit_request() {
+        let request = OwnershipEditRequest::owned("id-1");
+        let json = serde_json::to_string(&request).unwrap();
+        assert_eq!(
+            json,
+            r#"{"additionalItems":[{"id":"id-1","isOwned":true,"ownershipChanged":true}]}"#
+        );
+    }
+
+    #[test]
+    fn deserializes_add_item_response() {
+        let json = r#"{
+            "data": [{"id": "id-1", "name": "Milch", "isOwned": false}]
+        }"#;
+
+        let response: AddItemResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(response.data.len(), 1);
+        assert_eq!(response.data[0].id, "id-1");
+        assert_eq!(response.data[0].name, "Milch");
+    }
+
+    #[test]
+    fn deserializes_additional_items_response() {
+        let json = r#"{
+            "additionalItems": [
+                {"id": "id-1", "name": "Milch", "isOwned": false},
+                {"id": "id-2", "name": "Eier", "isOwned": true}
+            ]
+        }"#;
+
+        let response: AdditionalItemsResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(response.additional_items.len(), 2);
+        assert_eq!(response.additional_items[0].name, "Milch");
+        assert!(response.additional_items[1].is_owned);
+        assert_eq!(response.next_page, None);
+    }
+
+    #[test]
+    fn deserializes_next_page() {
+        let json = r#"{"additionalItems": [], "nextPage": 2}"#;
+
+        let response: AdditionalItemsResponse = serde_json::from_str(json).unwrap();
+
+        assert_eq!(response.next_page, Some(2));
+    }
+
+    #[test]
+    fn serializes_add_item_request() {
+        let request = AddItemRequest::for_items(["Milk"]);
+        let json = serde_json::to_string(&request).unwrap();
+        assert_eq!(json, r#"{"itemsValue":["Milk"]}"#);
+    }
+}
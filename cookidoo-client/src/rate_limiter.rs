@@ -0,0 +1,124 @@
+use std::num::NonZeroU32;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use tokio::time::Instant;
+
+/// Token-bucket rate limiter for outgoing Cookidoo requests.
+///
+/// Allows short bursts up to `capacity` requests and refills at a steady
+/// rate, so batches (e.g. all ingredients of a recipe) are spread out
+/// instead of tripping Cookidoo's own rate limits.
+pub struct RateLimiter {
+    capacity: f64,
+    refill_per_second: f64,
+    state: Mutex<BucketState>,
+}
+
+struct BucketState {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl RateLimiter {
+    /// Creates a full bucket with the given burst capacity and refill rate.
+    ///
+    /// # Panics
+    /// Panics if `refill_per_second` is not a positive number, since the
+    /// bucket would never refill.
+    pub fn new(capacity: NonZeroU32, refill_per_second: f64) -> Self {
+        assert!(
+            refill_per_second.is_finite() && refill_per_second > 0.0,
+            "refill rate must be positive, got {refill_per_second}"
+        );
+        let capacity = capacity.get();
+        Self {
+            capacity: f64::from(capacity),
+            refill_per_second,
+            state: Mutex::new(BucketState {
+                tokens: f64::from(capacity),
+                last_refill: Instant::now(),
+            }),
+        }
+    }
+
+    /// Waits until a request may be sent and consumes one token.
+    pub async fn acquire(&self) {
+        loop {
+            let wait = self.try_acquire();
+            match wait {
+                None => return,
+                Some(wait) => tokio::time::sleep(wait).await,
+            }
+        }
+    }
+
+    /// Takes a token if available, otherwise returns the time until one is.
+    fn try_acquire(&self) -> Option<Duration> {
+        let Ok(mut state) = self.state.lock() else {
+            // A poisoned lock must not block requests entirely.
+            return None;
+        };
+
+        let now = Instant::now();
+        let elapsed = now.duration_since(state.last_refill).as_secs_f64();
+        state.tokens = (state.tokens + elapsed * self.refill_per_second).min(self.capacity);
+        state.last_refill = now;
+
+        if state.tokens >= 1.0 {
+            state.tokens -= 1.0;
+            None
+        } else {
+            let missing = 1.0 - state.tokens;
+            Some(Duration::from_secs_f64(missing / self.refill_per_second))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test(start_paused = true)]
+    async fn allows_burst_up_to_capacity() {
+        let limiter = RateLimiter::new(NonZeroU32::new(3).unwrap(), 1.0);
+        let start = Instant::now();
+
+        for _ in 0..3 {
+            limiter.acquire().await;
+        }
+
+        assert_eq!(start.elapsed(), Duration::ZERO);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn waits_for_refill_when_empty() {
+        let limiter = RateLimiter::new(NonZeroU32::MIN, 2.0);
+        let start = Instant::now();
+
+        limiter.acquire().await;
+        limiter.acquire().await;
+
+        assert!(start.elapsed() >= Duration::from_millis(500));
+        assert!(start.elapsed() < Duration::from_millis(600));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn refill_does_not_exceed_capacity() {
+        let limiter = RateLimiter::new(NonZeroU32::new(2).unwrap(), 10.0);
+        tokio::time::advance(Duration::from_secs(60)).await;
+        let start = Instant::now();
+
+        for _ in 0..3 {
+            limiter.acquire().await;
+        }
+
+        assert!(start.elapsed() >= Duration::from_millis(100));
+    }
+
+    #[test]
+    #[should_panic(expected = "refill rate must be positive")]
+    fn rejects_zero_refill_rate() {
+        RateLimiter::new(NonZeroU32::MIN, 0.0);
+    }
+}
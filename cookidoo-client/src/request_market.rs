@@ -0,0 +1,47 @@
+use std::future::Future;
+
+use shopping_list_domain::models::Market;
+
+tokio::task_local! {
+    static REQUEST_MARKET: Market;
+}
+
+/// Cookidoo market of the request currently being handled.
+///
+/// The market is detected per request (e.g. from the device address), so
+/// it is scoped to the request's task like the [`RequestToken`]; calls
+/// outside a scope use the default market.
+///
+/// [`RequestToken`]: super::RequestToken
+pub struct RequestMarket;
+
+impl RequestMarket {
+    /// Runs `future` with `market` as the request market.
+    pub async fn scope<F: Future>(market: Market, future: F) -> F::Output {
+        REQUEST_MARKET.scope(market, future).await
+    }
+
+    /// Returns the market of the current request.
+    pub fn current() -> Market {
+        REQUEST_MARKET
+            .try_with(|market| *market)
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn defaults_outside_scope() {
+        assert_eq!(RequestMarket::current(), Market::GERMANY);
+    }
+
+    #[tokio::test]
+    async fn returns_market_inside_scope() {
+        let austria = Market::from_country_code("AT").unwrap();
+        let market = RequestMarket::scope(austria, async { RequestMarket::current() }).await;
+        assert_eq!(market, austria);
+    }
+}
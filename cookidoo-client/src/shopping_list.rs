@@ -0,0 +1,511 @@
+use std::collections::HashSet;
+use std::future::Future;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use base64::prelude::*;
+use reqwest::{Client, RequestBuilder, Response};
+use sha2::{Digest, Sha256};
+use tracing::{debug, error, info, warn};
+
+use shopping_list_domain::models::{AddedItem, DomainError, ShoppingListEntry, ShoppingListItem};
+use shopping_list_domain::ports::ShoppingListRepository;
+
+use super::account_registry::RequestAccount;
+use super::client::CookidooClient;
+use super::error::CookidooError;
+use super::list_cache::ListCache;
+use super::maintenance_window::MaintenanceWindow;
+use super::models::{
+    AddItemRequest, AddItemResponse, AdditionalItemsResponse, OwnershipEditRequest,
+    RemoveItemsRequest,
+};
+use super::request_market::RequestMarket;
+use super::token_provider::{RequestToken, TokenProvider, TokenSource};
+
+/// Path of the additional items API unless set with
+/// [`CookidooShoppingListAdapter::with_shopping_path`]; `{language}` is
+/// replaced with the language of the request's market.
+const ADDITIONAL_ITEMS_PATH: &str = "/shopping/{language}/additional-items";
+
+/// Shopping list API endpoint for adding additional items, relative to the
+/// additional items path of the market.
+const SHOPPING_LIST_ENDPOINT: &str = "/add";
+
+/// Shopping list API endpoint for listing additional items.
+const LIST_ITEMS_ENDPOINT: &str = "";
+
+/// Most pages fetched for one read of the list, a guard against a list
+/// that keeps announcing new pages; Cookidoo caps the list size well
+/// below this.
+const MAX_LIST_PAGES: usize = 50;
+
+/// Shopping list API endpoint for removing additional items.
+const REMOVE_ITEMS_ENDPOINT: &str = "/remove";
+
+/// Shopping list API endpoint for checking additional items off.
+const OWNERSHIP_ENDPOINT: &str = "/ownership/edit";
+
+/// Cookidoo shopping list adapter implementing the ShoppingListRepository port.
+pub struct CookidooShoppingListAdapter {
+    client: CookidooClient,
+    auth: Arc<dyn TokenProvider>,
+    list_cache: ListCache,
+    shopping_path: String,
+    maintenance: Arc<MaintenanceWindow>,
+}
+
+impl CookidooShoppingListAdapter {
+    /// Creates a new CookidooShoppingListAdapter.
+    ///
+    /// `auth` is usually a [`CookidooAuthAdapter`](super::CookidooAuthAdapter).
+    pub fn new(client: CookidooClient, auth: Arc<dyn TokenProvider>) -> Self {
+        Self {
+            client,
+            auth,
+            list_cache: ListCache::new(),
+            shopping_path: ADDITIONAL_ITEMS_PATH.to_string(),
+            maintenance: Arc::new(MaintenanceWindow::new()),
+        }
+    }
+
+    /// Replaces the list cache, e.g. to change how long a fetched list is
+    /// reused.
+    pub fn with_list_cache(mut self, list_cache: ListCache) -> Self {
+        self.list_cache = list_cache;
+        self
+    }
+
+    /// Replaces the window remembering Cookidoo maintenance, e.g. to share
+    /// it with other components or to change its duration.
+    pub fn with_maintenance_window(mut self, maintenance: Arc<MaintenanceWindow>) -> Self {
+        self.maintenance = maintenance;
+        self
+    }
+
+    /// Replaces the path of the additional items API, e.g. for a staging
+    /// proxy. A `{language}` placeholder is replaced with the language of
+    /// the request's market.
+    pub fn with_shopping_path(mut self, shopping_path: impl Into<String>) -> Self {
+        self.shopping_path = shopping_path.into();
+        self
+    }
+
+    /// Returns the key the current request's list is cached under: its
+    /// market and account, the latter as a hash of the request token so the
+    /// token itself is not kept, or else the chosen household account.
+    fn list_cache_key() -> String {
+        let account = match (RequestToken::current(), RequestAccount::current()) {
+            (Some(token), _) => BASE64_URL_SAFE_NO_PAD.encode(Sha256::digest(token.as_bytes())),
+            (None, Some(name)) => format!("account:{}", name.to_lowercase()),
+            (None, None) => "configured".to_string(),
+        };
+        format!("{}:{}", RequestMarket::current().country_code(), account)
+    }
+
+    /// Builds the URL of an additional items endpoint in the market of the
+    /// current request.
+    fn endpoint_url(&self, endpoint: &str) -> String {
+        let market = RequestMarket::current();
+        let path = self.shopping_path.replace("{language}", market.language());
+        self.client
+            .market_url(market, &format!("{}{}", path, endpoint))
+    }
+
+    /// Runs a Cookidoo operation unless Cookidoo recently reported
+    /// maintenance in the request's market, and opens the market's
+    /// maintenance window if it reports it now.
+    async fn unless_in_maintenance<T, F>(&self, operation: F) -> Result<T, CookidooError>
+    where
+        F: Future<Output = Result<T, CookidooError>>,
+    {
+        let market = RequestMarket::current();
+        if let Some(remaining) = self.maintenance.remaining(market) {
+            debug!(
+                remaining_secs = remaining.as_secs(),
+                "Cookidoo in maintenance, skipping request"
+            );
+            return Err(CookidooError::Maintenance(
+                "Cookidoo recently reported maintenance".to_string(),
+            ));
+        }
+
+        let result = operation.await;
+        if let Err(CookidooError::Maintenance(message)) = &result {
+            warn!(message = %message, market = market.country_code(), "Cookidoo reported maintenance");
+            self.maintenance.open(market);
+        }
+        result
+    }
+
+    /// Sends an authorized request, retrying once with a fresh token on 401.
+    ///
+    /// `build` receives the HTTP client and the bearer token and creates
+    /// the request; it is called again for the retry. Tokens passed with
+    /// the request are not retried, as there is no other token for that
+    /// user.
+    async fn send_authorized<F>(&self, build: F) -> Result<Response, CookidooError>
+    where
+        F: Fn(&Client, &str) -> RequestBuilder,
+    {
+        let client = self.client.inner()?;
+        let token = self.auth.token().await?;
+        let response = self
+            .client
+            .send(build(client, token.access_token.expose()))
+            .await?;
+
+        if response.status().as_u16() != 401 {
+            return Ok(response);
+        }
+
+        if token.source == TokenSource::Request {
+            error!("Token from request was rejected");
+            return Err(CookidooError::AuthenticationError(
+                "Token from request was rejected".to_string(),
+            ));
+        }
+
+        // Token might have expired between fetching it and now
+        // Clear cache and retry once
+        error!("Received 401, clearing token cache");
+        self.auth.invalidate();
+
+        let new_token = self.auth.token().await?;
+        let retry_response = self
+            .client
+            .send(build(client, new_token.access_token.expose()))
+            .await?;
+
+        if retry_response.status().as_u16() == 401 {
+            let body = retry_response.text().await.unwrap_or_default();
+            error!(body = %body, "Request still unauthorized after retry");
+            return Err(CookidooError::AuthenticationError(
+                "Authentication failed after retry".to_string(),
+            ));
+        }
+
+        Ok(retry_response)
+    }
+
+    async fn add_item_internal(
+        &self,
+        item: &ShoppingListItem,
+    ) -> Result<Vec<AddedItem>, CookidooError> {
+        debug!(item_name = %item.name(), category = ?item.category(), "Adding item to shopping list");
+        self.post_items(&[item]).await
+    }
+
+    /// Adds items with one request per category, since Cookidoo takes one
+    /// category for all items of a request.
+    ///
+    /// Returns one result per item, so the items of groups posted before a
+    /// failed one are reported as added. Groups after the failure are not
+    /// sent and report the same error.
+    async fn add_items_internal(&self, items: &[ShoppingListItem]) -> Vec<Result<(), DomainError>> {
+        let mut groups: Vec<Vec<usize>> = Vec::new();
+        for (index, item) in items.iter().enumerate() {
+            match groups
+                .iter_mut()
+                .find(|group| items[group[0]].category() == item.category())
+            {
+                Some(group) => group.push(index),
+                None => groups.push(vec![index]),
+            }
+        }
+
+        debug!(
+            count = items.len(),
+            requests = groups.len(),
+            "Adding items to shopping list"
+        );
+        let mut results: Vec<Option<Result<(), DomainError>>> = vec![None; items.len()];
+        for group in groups {
+            let group_items: Vec<&ShoppingListItem> =
+                group.iter().map(|&index| &items[index]).collect();
+            let result = self
+                .unless_in_maintenance(self.post_items(&group_items))
+                .await
+                .map(|_| ())
+                .map_err(DomainError::from);
+            for &index in &group {
+                results[index] = Some(result.clone());
+            }
+            if let Err(e) = result {
+                warn!(error = %e, "Adding items stopped after a failed request");
+                return results
+                    .into_iter()
+                    .map(|result| result.unwrap_or_else(|| Err(e.clone())))
+                    .collect();
+            }
+        }
+        results.into_iter().flatten().collect()
+    }
+
+    /// Posts items of the same category to the add endpoint.
+    async fn post_items(
+        &self,
+        items: &[&ShoppingListItem],
+    ) -> Result<Vec<AddedItem>, CookidooError> {
+        let url = self.endpoint_url(SHOPPING_LIST_ENDPOINT);
+        let request_body = AddItemRequest::for_items(items.iter().map(|item| item.label()));
+        let request_body = match items.first().and_then(|item| item.category()) {
+            Some(category) => request_body.with_category(category.label()),
+            None => request_body,
+        };
+
+        let response = self
+            .send_authorized(|client, token| {
+                client
+                    .post(&url)
+                    .header("Authorization", format!("Bearer {}", token))
+                    .json(&request_body)
+            })
+            .await?;
+
+        let status = response.status();
+
+        if status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            let added = parse_added_items(&body);
+            let item_names: Vec<&str> = items.iter().map(|item| item.name()).collect();
+            let item_ids: Vec<&str> = added.iter().map(AddedItem::id).collect();
+            info!(item_names = ?item_names, item_ids = ?item_ids, "Items added successfully");
+            Ok(added)
+        } else {
+            let body = response.text().await.unwrap_or_default();
+            error!(status = %status, body = %body, "Failed to add item");
+            Err(CookidooError::from_response(status.as_u16(), &body))
+        }
+    }
+
+    /// Fetches the list, following Cookidoo's pages.
+    ///
+    /// Cookidoo does not document paging; the list endpoint has been seen
+    /// to return the whole list at once. If a response names a `nextPage`,
+    /// it is requested with `?page=`, and fetching stops at the first
+    /// response without one. A page that was already requested, or that
+    /// brings no item not seen before, does not advance the list and also
+    /// ends it, as does [`MAX_LIST_PAGES`]. Items are never dropped, so
+    /// everything read can also be removed.
+    async fn list_items_internal(&self) -> Result<Vec<ShoppingListEntry>, CookidooError> {
+        let url = self.endpoint_url(LIST_ITEMS_ENDPOINT);
+        let mut entries = Vec::new();
+        let mut ids = HashSet::new();
+        let mut requested = HashSet::new();
+        let mut page = None;
+
+        loop {
+            requested.insert(page);
+            let list = self.list_page(&url, page).await?;
+            let fetched = entries.len();
+            entries.extend(
+                list.additional_items
+                    .into_iter()
+                    .filter(|item| ids.insert(item.id.clone()))
+                    .map(|item| ShoppingListEntry::new(item.id, item.name, item.is_owned)),
+            );
+
+            page = match list.next_page {
+                None => break,
+                Some(next) if requested.contains(&Some(next)) || entries.len() == fetched => {
+                    warn!(page = next, "Shopping list page does not advance, stopping");
+                    break;
+                }
+                Some(_) if requested.len() == MAX_LIST_PAGES => {
+                    warn!(
+                        pages = MAX_LIST_PAGES,
+                        count = entries.len(),
+                        "Shopping list has more pages than fetched"
+                    );
+                    break;
+                }
+                next => next,
+            };
+        }
+
+        info!(count = entries.len(), "Shopping list fetched");
+        Ok(entries)
+    }
+
+    /// Fetches one page of the list; `None` is the first page.
+    async fn list_page(
+        &self,
+        url: &str,
+        page: Option<u32>,
+    ) -> Result<AdditionalItemsResponse, CookidooError> {
+        debug!(page = ?page, "Fetching shopping list");
+
+        let url = match page {
+            Some(page) => format!("{}?page={}", url, page),
+            None => url.to_string(),
+        };
+        let response = self
+            .send_authorized(|client, token| {
+                client
+                    .get(&url)
+                    .header("Authorization", format!("Bearer {}", token))
+            })
+            .await?;
+
+        let status = response.status();
+
+        if status.is_success() {
+            response
+                .json()
+                .await
+                .map_err(|e| CookidooError::ParseError(e.to_string()))
+        } else {
+            let body = response.text().await.unwrap_or_default();
+            error!(status = %status, body = %body, "Failed to fetch shopping list");
+            Err(CookidooError::from_response(status.as_u16(), &body))
+        }
+    }
+
+    async fn remove_item_internal(&self, id: &str) -> Result<(), CookidooError> {
+        let url = self.endpoint_url(REMOVE_ITEMS_ENDPOINT);
+        let request_body = RemoveItemsRequest::new(id);
+
+        debug!(item_id = %id, "Removing item from shopping list");
+
+        let response = self
+            .send_authorized(|client, token| {
+                client
+                    .post(&url)
+                    .header("Authorization", format!("Bearer {}", token))
+                    .json(&request_body)
+            })
+            .await?;
+
+        let status = response.status();
+
+        if status.is_success() {
+            info!(item_id = %id, "Item removed successfully");
+            Ok(())
+        } else {
+            let body = response.text().await.unwrap_or_default();
+            error!(status = %status, body = %body, "Failed to remove item");
+            Err(CookidooError::from_response(status.as_u16(), &body))
+        }
+    }
+
+    async fn mark_owned_internal(&self, id: &str) -> Result<(), CookidooError> {
+        let url = self.endpoint_url(OWNERSHIP_ENDPOINT);
+        let request_body = OwnershipEditRequest::owned(id);
+
+        debug!(item_id = %id, "Checking off item");
+
+        let response = self
+            .send_authorized(|client, token| {
+                client
+                    .post(&url)
+                    .header("Authorization", format!("Bearer {}", token))
+                    .json(&request_body)
+            })
+            .await?;
+
+        let status = response.status();
+
+        if status.is_success() {
+            info!(item_id = %id, "Item checked off successfully");
+            Ok(())
+        } else {
+            let body = response.text().await.unwrap_or_default();
+            error!(status = %status, body = %body, "Failed to check off item");
+            Err(CookidooError::from_response(status.as_u16(), &body))
+        }
+    }
+}
+
+/// Extracts the created items from an add response body.
+///
+/// The item is already on the list at this point, so an unexpected body is
+/// logged and yields no IDs rather than failing the add.
+fn parse_added_items(body: &str) -> Vec<AddedItem> {
+    match serde_json::from_str::<AddItemResponse>(body) {
+        Ok(response) => response
+            .data
+            .into_iter()
+            .map(|item| AddedItem::new(item.id, item.name))
+            .collect(),
+        Err(e) => {
+            warn!(error = %e, "Could not parse add response, item IDs unknown");
+            Vec::new()
+        }
+    }
+}
+
+#[async_trait]
+impl ShoppingListRepository for CookidooShoppingListAdapter {
+    async fn add_item(&self, item: &ShoppingListItem) -> Result<Vec<AddedItem>, DomainError> {
+        let result = self
+            .unless_in_maintenance(self.add_item_internal(item))
+            .await
+            .map_err(|e| e.into());
+        self.list_cache.invalidate();
+        result
+    }
+
+    async fn add_items(&self, items: &[ShoppingListItem]) -> Vec<Result<(), DomainError>> {
+        let results = self.add_items_internal(items).await;
+        self.list_cache.invalidate();
+        results
+    }
+
+    async fn list_items(&self) -> Result<Vec<ShoppingListEntry>, DomainError> {
+        self.list_cache
+            .get_or_fetch(&Self::list_cache_key(), || async {
+                self.unless_in_maintenance(self.list_items_internal())
+                    .await
+                    .map_err(|e| e.into())
+            })
+            .await
+    }
+
+    async fn remove_item(&self, id: &str) -> Result<(), DomainError> {
+        let result = self
+            .unless_in_maintenance(self.remove_item_internal(id))
+            .await
+            .map_err(|e| e.into());
+        self.list_cache.invalidate();
+        result
+    }
+
+    async fn mark_owned(&self, id: &str) -> Result<(), DomainError> {
+        let result = self
+            .unless_in_maintenance(self.mark_owned_internal(id))
+            .await
+            .map_err(|e| e.into());
+        self.list_cache.invalidate();
+        result
+    }
+
+    /// Fetches a token for the configured account, refreshing it if it is
+    /// about to expire; the token request also opens a pooled connection
+    /// to Cookidoo. The sign-in does not use the shopping list API, so it
+    /// is tried even while a market is in maintenance.
+    async fn warm_up(&self) -> Result<(), DomainError> {
+        self.auth.token().await.map(|_| ()).map_err(|e| e.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_added_items_reads_ids() {
+        let body = r#"{"data": [{"id": "id-1", "name": "Milch", "isOwned": false}]}"#;
+
+        assert_eq!(
+            parse_added_items(body),
+            vec![AddedItem::new("id-1", "Milch")]
+        );
+    }
+
+    #[test]
+    fn parse_added_items_tolerates_unexpected_body() {
+        assert!(parse_added_items("").is_empty());
+        assert!(parse_added_items("not json").is_empty());
+    }
+}
@@ -1,6 +1,6 @@
 use std::sync::RwLock;
 
-use crate::domain::models::AuthToken;
+use shopping_list_domain::models::AuthToken;
 
 /// Thread-safe in-memory token cache.
 ///
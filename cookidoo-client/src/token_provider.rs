@@ -0,0 +1,97 @@
+use std::future::Future;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use shopping_list_domain::models::Secret;
+
+use super::error::CookidooError;
+
+tokio::task_local! {
+    static REQUEST_TOKEN: Option<String>;
+}
+
+/// Where an access token for a Cookidoo call came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenSource {
+    /// Passed with the current request, e.g. as the account-linking token.
+    Request,
+    /// Taken from the token cache, refreshed if it was about to expire.
+    Cache,
+    /// Obtained with the refresh token provisioned via the
+    /// authorization-code flow.
+    RefreshToken,
+    /// Obtained with the configured e-mail and password.
+    PasswordGrant,
+}
+
+/// An access token together with its source.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProvidedToken {
+    pub access_token: Secret,
+    pub source: TokenSource,
+}
+
+/// Port for sourcing Cookidoo bearer tokens.
+#[async_trait]
+pub trait TokenProvider: Send + Sync {
+    /// Returns a token for the next Cookidoo call.
+    ///
+    /// # Errors
+    /// Returns an error if no token source could supply a token.
+    async fn token(&self) -> Result<ProvidedToken, CookidooError>;
+
+    /// Discards cached tokens after Cookidoo rejected one.
+    fn invalidate(&self);
+
+    /// Refreshes a cached token that is about to expire in a background
+    /// task, so the next request finds a fresh one. Does nothing by default.
+    fn refresh_in_background(self: Arc<Self>) {}
+}
+
+/// Bearer token passed with the request currently being handled.
+///
+/// Users who authenticate with Cookidoo elsewhere hand their token to the
+/// skill via account linking; it is scoped to the request's task so warm
+/// invocations for other users never see it.
+pub struct RequestToken;
+
+impl RequestToken {
+    /// Runs `future` with `token` as the request token.
+    pub async fn scope<F: Future>(token: Option<String>, future: F) -> F::Output {
+        REQUEST_TOKEN.scope(token, future).await
+    }
+
+    /// Returns the token of the current request, if one was passed.
+    pub fn current() -> Option<String> {
+        REQUEST_TOKEN
+            .try_with(|token| token.clone())
+            .ok()
+            .flatten()
+            .filter(|token| !token.is_empty())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn no_token_outside_scope() {
+        assert_eq!(RequestToken::current(), None);
+    }
+
+    #[tokio::test]
+    async fn returns_token_inside_scope() {
+        let token =
+            RequestToken::scope(Some("abc".to_string()), async { RequestToken::current() }).await;
+        assert_eq!(token.as_deref(), Some("abc"));
+    }
+
+    #[tokio::test]
+    async fn ignores_empty_token() {
+        let token =
+            RequestToken::scope(Some(String::new()), async { RequestToken::current() }).await;
+        assert_eq!(token, None);
+    }
+}
@@ -0,0 +1,7 @@
+//! Shopping list domain: the models, the ports (traits such as
+//! [`ShoppingListRepository`](ports::ShoppingListRepository)) implemented
+//! by the adapters, and the services using them.
+
+pub mod models;
+pub mod ports;
+pub mod services;
@@ -0,0 +1,53 @@
+mod add_item_outcome;
+mod added_item;
+mod auth;
+mod bundle_outcome;
+mod calendar;
+mod category;
+mod error;
+mod history;
+mod household_accounts;
+mod item_bundle;
+mod list_name;
+mod list_position;
+mod list_summary;
+mod market;
+mod pantry_staples;
+mod parsed_intent;
+mod permissions;
+mod quantity_style;
+mod reminder;
+mod secret;
+mod shopping_list_entry;
+mod shopping_list_item;
+mod speech_style;
+mod transfer_outcome;
+mod user_preferences;
+mod voice_request;
+
+pub use add_item_outcome::AddItemOutcome;
+pub use added_item::AddedItem;
+pub use auth::{AuthToken, CookidooCredentials, StoredCredentials};
+pub use bundle_outcome::{BundleError, BundleOutcome};
+pub use calendar::days_from_civil;
+pub use category::Category;
+pub use error::{DomainError, ErrorCode};
+pub use history::{HistoryEntry, HistoryPeriod};
+pub use household_accounts::{AccountChoice, HouseholdAccount, HouseholdAccounts};
+pub use item_bundle::{ItemBundle, ItemBundles};
+pub use list_name::ListName;
+pub use list_position::ListPosition;
+pub use list_summary::ListSummary;
+pub use market::Market;
+pub use pantry_staples::PantryStaples;
+pub use parsed_intent::ParsedIntent;
+pub use permissions::SkillPermissions;
+pub use quantity_style::QuantityStyle;
+pub use reminder::Reminder;
+pub use secret::Secret;
+pub use shopping_list_entry::ShoppingListEntry;
+pub use shopping_list_item::ShoppingListItem;
+pub use speech_style::SpeechStyle;
+pub use transfer_outcome::TransferOutcome;
+pub use user_preferences::{SettingChange, UserPreferences};
+pub use voice_request::VoiceRequest;
@@ -0,0 +1,114 @@
+use super::error::ErrorCode;
+
+/// Result of adding an item to the shopping list.
+///
+/// Services report what happened; turning an outcome into speech or an
+/// HTTP response is up to the adapter that received the request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AddItemOutcome {
+    /// The item was added.
+    Added { item: String },
+    /// The request was already handled; the item was not added again.
+    Duplicate { item: String },
+    /// Authentication with the backend failed.
+    AuthFailed,
+    /// The backend could not be reached or rejected the add.
+    Unavailable,
+    /// The backend is temporarily down for maintenance.
+    Maintenance,
+    /// Too many requests were sent to the backend.
+    RateLimited,
+    /// The shopping list has reached its maximum size.
+    ListFull,
+    /// The spoken item name is not a valid item.
+    InvalidName { reason: String },
+}
+
+impl AddItemOutcome {
+    /// Returns true if the item is on the list after this outcome.
+    pub fn is_success(&self) -> bool {
+        matches!(self, Self::Added { .. } | Self::Duplicate { .. })
+    }
+
+    /// Returns the support code for a failed add, `None` on success.
+    pub fn error_code(&self) -> Option<ErrorCode> {
+        match self {
+            Self::Added { .. } | Self::Duplicate { .. } => None,
+            Self::AuthFailed => Some(ErrorCode::AuthenticationFailed),
+            Self::Unavailable => Some(ErrorCode::Repository),
+            Self::Maintenance => Some(ErrorCode::ServiceUnavailable),
+            Self::RateLimited => Some(ErrorCode::RateLimited),
+            Self::ListFull => Some(ErrorCode::ListFull),
+            Self::InvalidName { .. } => Some(ErrorCode::InvalidItemName),
+        }
+    }
+
+    /// Returns true for failures every further add would run into as well,
+    /// so adding several items stops at the first of them.
+    pub fn stops_batch(&self) -> bool {
+        matches!(self, Self::AuthFailed | Self::Maintenance)
+    }
+
+    /// Returns the name of the added item, if the add succeeded.
+    pub fn item(&self) -> Option<&str> {
+        match self {
+            Self::Added { item } | Self::Duplicate { item } => Some(item),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_sign_in_and_maintenance_stop_a_batch() {
+        assert!(AddItemOutcome::AuthFailed.stops_batch());
+        assert!(AddItemOutcome::Maintenance.stops_batch());
+        assert!(!AddItemOutcome::Unavailable.stops_batch());
+        assert!(!AddItemOutcome::InvalidName {
+            reason: "empty".to_string()
+        }
+        .stops_batch());
+    }
+
+    #[test]
+    fn added_and_duplicate_are_successes() {
+        let added = AddItemOutcome::Added {
+            item: "Milch".to_string(),
+        };
+        let duplicate = AddItemOutcome::Duplicate {
+            item: "Milch".to_string(),
+        };
+
+        assert!(added.is_success());
+        assert!(duplicate.is_success());
+        assert_eq!(added.item(), Some("Milch"));
+        assert_eq!(duplicate.item(), Some("Milch"));
+    }
+
+    #[test]
+    fn failures_have_no_item() {
+        let outcome = AddItemOutcome::InvalidName {
+            reason: "empty".to_string(),
+        };
+
+        assert!(!outcome.is_success());
+        assert_eq!(outcome.item(), None);
+        assert!(!AddItemOutcome::AuthFailed.is_success());
+    }
+
+    #[test]
+    fn only_failures_have_error_code() {
+        let added = AddItemOutcome::Added {
+            item: "Milch".to_string(),
+        };
+
+        assert_eq!(added.error_code(), None);
+        assert_eq!(
+            AddItemOutcome::Maintenance.error_code(),
+            Some(ErrorCode::ServiceUnavailable)
+        );
+    }
+}
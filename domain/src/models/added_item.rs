@@ -0,0 +1,41 @@
+/// An item that was just created on the shopping list.
+///
+/// Carries the backend ID so follow-up operations (undo, check-off) can
+/// target exactly the item that was added.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AddedItem {
+    id: String,
+    name: String,
+}
+
+impl AddedItem {
+    /// Creates a new added item.
+    pub fn new(id: impl Into<String>, name: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            name: name.into(),
+        }
+    }
+
+    /// Returns the backend ID of the created item.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Returns the item name as stored by the backend.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accessors_return_values() {
+        let item = AddedItem::new("id-1", "Milch");
+        assert_eq!(item.id(), "id-1");
+        assert_eq!(item.name(), "Milch");
+    }
+}
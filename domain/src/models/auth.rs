@@ -1,17 +1,21 @@
 use std::time::{Duration, Instant};
 
+use super::secret::Secret;
+
 /// Credentials for authenticating with the Cookidoo API.
+///
+/// The password is a [`Secret`], so debug output shows only the e-mail.
 #[derive(Debug, Clone)]
 pub struct CookidooCredentials {
     email: String,
-    password: String,
+    password: Secret,
 }
 
 impl CookidooCredentials {
     pub fn new(email: impl Into<String>, password: impl Into<String>) -> Self {
         Self {
             email: email.into(),
-            password: password.into(),
+            password: Secret::new(password),
         }
     }
 
@@ -20,7 +24,34 @@ impl CookidooCredentials {
     }
 
     pub fn password(&self) -> &str {
-        &self.password
+        self.password.expose()
+    }
+}
+
+/// Cookidoo credentials read from a credential store, with the version
+/// they are stored under.
+#[derive(Debug, Clone)]
+pub struct StoredCredentials {
+    credentials: CookidooCredentials,
+    version: String,
+}
+
+impl StoredCredentials {
+    pub fn new(credentials: CookidooCredentials, version: impl Into<String>) -> Self {
+        Self {
+            credentials,
+            version: version.into(),
+        }
+    }
+
+    pub fn credentials(&self) -> &CookidooCredentials {
+        &self.credentials
+    }
+
+    /// Returns the store's version of the credentials, which changes with
+    /// every rotation.
+    pub fn version(&self) -> &str {
+        &self.version
     }
 }
 
@@ -30,8 +61,8 @@ const REFRESH_BUFFER: Duration = Duration::from_secs(5 * 60);
 /// Authentication token received from the Cookidoo API.
 #[derive(Debug, Clone)]
 pub struct AuthToken {
-    access_token: String,
-    refresh_token: String,
+    access_token: Secret,
+    refresh_token: Secret,
     expires_at: Instant,
 }
 
@@ -48,20 +79,20 @@ impl AuthToken {
         expires_in: Duration,
     ) -> Self {
         Self {
-            access_token: access_token.into(),
-            refresh_token: refresh_token.into(),
+            access_token: Secret::new(access_token),
+            refresh_token: Secret::new(refresh_token),
             expires_at: Instant::now() + expires_in,
         }
     }
 
     /// Returns the access token string.
     pub fn access_token(&self) -> &str {
-        &self.access_token
+        self.access_token.expose()
     }
 
     /// Returns the refresh token string.
     pub fn refresh_token(&self) -> &str {
-        &self.refresh_token
+        self.refresh_token.expose()
     }
 
     /// Returns true if the token has expired.
@@ -86,6 +117,19 @@ mod tests {
         assert_eq!(creds.password(), "secret123");
     }
 
+    #[test]
+    fn debug_output_hides_secrets() {
+        let creds = CookidooCredentials::new("test@example.com", "secret123");
+        let token = AuthToken::new("my_access", "my_refresh", Duration::from_secs(3600));
+
+        let debug = format!("{:?} {:?}", creds, token);
+
+        assert!(debug.contains("test@example.com"));
+        assert!(!debug.contains("secret123"));
+        assert!(!debug.contains("my_access"));
+        assert!(!debug.contains("my_refresh"));
+    }
+
     #[test]
     fn token_is_not_expired_when_fresh() {
         let token = AuthToken::new("access", "refresh", Duration::from_secs(3600));
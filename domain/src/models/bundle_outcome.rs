@@ -0,0 +1,32 @@
+/// Result of managing a user's item bundles.
+///
+/// Like [`super::AddItemOutcome`], it reports what happened; adapters word
+/// it for the user.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BundleOutcome {
+    /// A new bundle was created holding `item`.
+    Created { bundle: String, item: String },
+    /// `item` was added to the bundle.
+    Extended { bundle: String, item: String },
+    /// The bundle already held `item`; nothing was changed.
+    AlreadyInBundle { bundle: String, item: String },
+    /// The user's bundle was deleted.
+    Deleted { bundle: String },
+    /// The items of the requested bundle.
+    Items { bundle: String, items: Vec<String> },
+    /// The names of the user's own and the configured bundles, possibly
+    /// none.
+    Names { names: Vec<String> },
+}
+
+/// Why a bundle request failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BundleError {
+    /// No bundle has the spoken name.
+    Unknown { name: String },
+    /// The bundle is configured for every user and cannot be deleted by
+    /// voice.
+    Configured { bundle: String },
+    /// The preferences store holding the bundles failed.
+    Unavailable,
+}
@@ -0,0 +1,135 @@
+/// Seconds in a day.
+const DAY_SECONDS: i64 = 86_400;
+
+/// Days since 1970-01-01 of a proleptic Gregorian date.
+pub fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Proleptic Gregorian date of a day count since 1970-01-01.
+pub(crate) fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// Parses an ISO 8601 UTC timestamp such as "2024-01-27T10:00:00Z" into
+/// seconds since 1970-01-01; a bare date counts as its midnight.
+pub(crate) fn parse_timestamp(timestamp: &str) -> Option<i64> {
+    let date = timestamp.get(..10)?;
+    let mut parts = date.split('-');
+    let year: i64 = parts.next()?.parse().ok()?;
+    let month: i64 = parts.next()?.parse().ok()?;
+    let day: i64 = parts.next()?.parse().ok()?;
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+
+    let seconds = match timestamp.get(11..19) {
+        Some(time) => {
+            let mut parts = time.split(':');
+            let hour: i64 = parts.next()?.parse().ok()?;
+            let minute: i64 = parts.next()?.parse().ok()?;
+            let second: i64 = parts.next()?.parse().ok()?;
+            hour * 3_600 + minute * 60 + second
+        }
+        None => 0,
+    };
+    Some(days_from_civil(year, month, day) * DAY_SECONDS + seconds)
+}
+
+/// Formats seconds since 1970-01-01 as an ISO 8601 UTC timestamp.
+pub(crate) fn format_timestamp(seconds: i64) -> String {
+    let (year, month, day) = civil_from_days(seconds.div_euclid(DAY_SECONDS));
+    let time = seconds.rem_euclid(DAY_SECONDS);
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+        time / 3_600,
+        time / 60 % 60,
+        time % 60
+    )
+}
+
+/// Offset of Europe/Berlin from UTC in seconds at the given instant.
+///
+/// Summer time runs from the last Sunday of March to the last Sunday of
+/// October, switching at 01:00 UTC, as everywhere in the EU.
+pub(crate) fn berlin_offset(seconds: i64) -> i64 {
+    let (year, _, _) = civil_from_days(seconds.div_euclid(DAY_SECONDS));
+    let switch = |month| {
+        let last = days_from_civil(year, month, 31);
+        // 1970-01-01 was a Thursday, four days after a Sunday
+        (last - (last + 4).rem_euclid(7)) * DAY_SECONDS + 3_600
+    };
+    if (switch(3)..switch(10)).contains(&seconds) {
+        7_200
+    } else {
+        3_600
+    }
+}
+
+/// Days since 1970-01-01 of the Europe/Berlin date at the given instant.
+pub(crate) fn berlin_day(seconds: i64) -> i64 {
+    (seconds + berlin_offset(seconds)).div_euclid(DAY_SECONDS)
+}
+
+/// Instant of Europe/Berlin midnight starting the given day.
+///
+/// Clocks change at 02:00 or 03:00 local time, so midnight always has the
+/// offset of the evening before.
+pub(crate) fn berlin_midnight(days: i64) -> i64 {
+    let midnight = days * DAY_SECONDS;
+    midnight - berlin_offset(midnight - 3 * 3_600)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_dates() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(days_from_civil(2024, 2, 29), 19_782);
+        assert_eq!(civil_from_days(19_782), (2024, 2, 29));
+    }
+
+    #[test]
+    fn berlin_switches_to_summer_time_in_march() {
+        // 2024-03-31 is the last Sunday in March
+        let before = parse_timestamp("2024-03-31T00:59:59Z").unwrap();
+        let after = parse_timestamp("2024-03-31T01:00:00Z").unwrap();
+        assert_eq!(berlin_offset(before), 3_600);
+        assert_eq!(berlin_offset(after), 7_200);
+        assert_eq!(
+            berlin_offset(parse_timestamp("2024-10-27T01:00:00Z").unwrap()),
+            3_600
+        );
+    }
+
+    #[test]
+    fn berlin_day_starts_before_utc_midnight() {
+        let late = parse_timestamp("2024-01-26T23:30:00Z").unwrap();
+        assert_eq!(civil_from_days(berlin_day(late)), (2024, 1, 27));
+        assert_eq!(
+            format_timestamp(berlin_midnight(days_from_civil(2024, 7, 1))),
+            "2024-06-30T22:00:00Z"
+        );
+    }
+}
@@ -0,0 +1,102 @@
+use std::fmt;
+use std::str::FromStr;
+
+use super::error::DomainError;
+
+/// Shopping list category (aisle) an item is sorted into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Category {
+    FruitAndVegetables,
+    Dairy,
+    MeatAndFish,
+    Bakery,
+    Beverages,
+    Frozen,
+    Pantry,
+    SpicesAndSauces,
+    Sweets,
+    Household,
+}
+
+impl Category {
+    /// All categories in display order.
+    pub const ALL: [Category; 10] = [
+        Category::FruitAndVegetables,
+        Category::Dairy,
+        Category::MeatAndFish,
+        Category::Bakery,
+        Category::Beverages,
+        Category::Frozen,
+        Category::Pantry,
+        Category::SpicesAndSauces,
+        Category::Sweets,
+        Category::Household,
+    ];
+
+    /// Returns the category name as shown in Cookidoo.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Category::FruitAndVegetables => "Obst & Gemüse",
+            Category::Dairy => "Milchprodukte",
+            Category::MeatAndFish => "Fleisch & Fisch",
+            Category::Bakery => "Backwaren",
+            Category::Beverages => "Getränke",
+            Category::Frozen => "Tiefkühlprodukte",
+            Category::Pantry => "Vorrat",
+            Category::SpicesAndSauces => "Gewürze & Saucen",
+            Category::Sweets => "Süßwaren & Snacks",
+            Category::Household => "Haushalt",
+        }
+    }
+}
+
+impl fmt::Display for Category {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.label())
+    }
+}
+
+impl FromStr for Category {
+    type Err = DomainError;
+
+    /// Parses a category from its label, ignoring case.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let needle = value.trim().to_lowercase();
+        Category::ALL
+            .into_iter()
+            .find(|category| category.label().to_lowercase() == needle)
+            .ok_or_else(|| DomainError::InvalidCategory(value.trim().to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_label_ignoring_case() {
+        assert_eq!(
+            "obst & gemüse".parse::<Category>().unwrap(),
+            Category::FruitAndVegetables
+        );
+        assert_eq!(
+            " Milchprodukte ".parse::<Category>().unwrap(),
+            Category::Dairy
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_label() {
+        assert!(matches!(
+            "Sonstiges".parse::<Category>(),
+            Err(DomainError::InvalidCategory(_))
+        ));
+    }
+
+    #[test]
+    fn labels_round_trip() {
+        for category in Category::ALL {
+            assert_eq!(category.label().parse::<Category>().unwrap(), category);
+        }
+    }
+}
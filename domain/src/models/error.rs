@@ -0,0 +1,159 @@
+use std::fmt;
+
+use thiserror::Error;
+
+/// Domain-level errors that can occur in the application.
+/// These errors are technology-agnostic and represent business logic failures.
+#[derive(Debug, Clone, Error)]
+pub enum DomainError {
+    /// The item name is invalid (empty or exceeds maximum length)
+    #[error("Invalid item name: {0}")]
+    InvalidItemName(String),
+
+    /// The category name does not match any known category
+    #[error("Invalid category: {0}")]
+    InvalidCategory(String),
+
+    /// Authentication with the external service failed
+    #[error("Authentication failed: {0}")]
+    AuthenticationFailed(String),
+
+    /// A generic repository operation failed
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+
+    /// The external service rejected the request due to rate limiting
+    #[error("Rate limited: {0}")]
+    RateLimited(String),
+
+    /// The shopping list cannot hold any more items
+    #[error("Shopping list is full: {0}")]
+    ListFull(String),
+
+    /// The external service is temporarily unavailable (e.g. maintenance)
+    #[error("Service unavailable: {0}")]
+    ServiceUnavailable(String),
+
+    /// The user has not granted a permission the operation needs
+    #[error("Permission required: {0}")]
+    PermissionRequired(String),
+
+    /// The named item is not on the list (or was removed meanwhile)
+    #[error("Item not found: {0}")]
+    ItemNotFound(String),
+
+    /// The external service refused access to the account's data
+    #[error("Permission denied: {0}")]
+    PermissionDenied(String),
+
+    /// The external service did not answer in time or failed on its side
+    #[error("Service unreachable: {0}")]
+    Unreachable(String),
+}
+
+impl DomainError {
+    /// Returns true if the same request may succeed later, so it is worth
+    /// retrying or queueing instead of failing for good.
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            Self::RateLimited(_) | Self::ServiceUnavailable(_) | Self::Unreachable(_)
+        )
+    }
+
+    /// Returns the short code identifying this kind of error for support.
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Self::InvalidItemName(_) => ErrorCode::InvalidItemName,
+            Self::InvalidCategory(_) => ErrorCode::InvalidCategory,
+            Self::ItemNotFound(_) => ErrorCode::ItemNotFound,
+            Self::ListFull(_) => ErrorCode::ListFull,
+            Self::AuthenticationFailed(_) => ErrorCode::AuthenticationFailed,
+            Self::PermissionRequired(_) => ErrorCode::PermissionRequired,
+            Self::PermissionDenied(_) => ErrorCode::PermissionDenied,
+            Self::RateLimited(_) => ErrorCode::RateLimited,
+            Self::ServiceUnavailable(_) => ErrorCode::ServiceUnavailable,
+            Self::Unreachable(_) => ErrorCode::Unreachable,
+            Self::RepositoryError(_) => ErrorCode::Repository,
+        }
+    }
+}
+
+/// Short, stable error code shown to users (e.g. in the Alexa app card),
+/// so support can tell failures apart without access to the logs.
+///
+/// Codes group by cause: `E1xx` for requests the skill cannot fulfil,
+/// `E2xx` for missing authorization, `E3xx` for temporary backend
+/// problems and `E5xx` for unexpected failures.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorCode {
+    InvalidItemName,
+    InvalidCategory,
+    ItemNotFound,
+    ListFull,
+    AuthenticationFailed,
+    PermissionRequired,
+    PermissionDenied,
+    RateLimited,
+    ServiceUnavailable,
+    Unreachable,
+    Repository,
+}
+
+impl ErrorCode {
+    /// Returns the code as shown to users, e.g. "E301".
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::InvalidItemName => "E101",
+            Self::InvalidCategory => "E102",
+            Self::ItemNotFound => "E104",
+            Self::ListFull => "E109",
+            Self::AuthenticationFailed => "E201",
+            Self::PermissionRequired => "E202",
+            Self::PermissionDenied => "E203",
+            Self::RateLimited => "E301",
+            Self::ServiceUnavailable => "E302",
+            Self::Unreachable => "E303",
+            Self::Repository => "E500",
+        }
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_transient_errors_are_retryable() {
+        assert!(DomainError::Unreachable("timeout".to_string()).is_retryable());
+        assert!(DomainError::RateLimited("429".to_string()).is_retryable());
+        assert!(!DomainError::RepositoryError("400".to_string()).is_retryable());
+        assert!(!DomainError::AuthenticationFailed("401".to_string()).is_retryable());
+    }
+
+    #[test]
+    fn maps_errors_to_codes() {
+        assert_eq!(
+            DomainError::ServiceUnavailable("503".to_string()).code(),
+            ErrorCode::ServiceUnavailable
+        );
+        assert_eq!(
+            DomainError::ItemNotFound("Milch".to_string())
+                .code()
+                .as_str(),
+            "E104"
+        );
+        assert_eq!(
+            DomainError::RepositoryError("boom".to_string())
+                .code()
+                .to_string(),
+            "E500"
+        );
+    }
+}
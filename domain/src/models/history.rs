@@ -0,0 +1,152 @@
+use super::calendar;
+
+/// An item added to the shopping list, as recorded in the user's history.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HistoryEntry {
+    item_name: String,
+    added_at: String,
+    person_id: Option<String>,
+}
+
+impl HistoryEntry {
+    /// Creates an entry; `added_at` is the ISO 8601 UTC timestamp of the
+    /// request that added the item.
+    pub fn new(item_name: impl Into<String>, added_at: impl Into<String>) -> Self {
+        Self {
+            item_name: item_name.into(),
+            added_at: added_at.into(),
+            person_id: None,
+        }
+    }
+
+    /// Sets the recognized speaker who added the item.
+    pub fn with_person_id(mut self, person_id: impl Into<String>) -> Self {
+        self.person_id = Some(person_id.into());
+        self
+    }
+
+    /// Returns the name of the added item.
+    pub fn item_name(&self) -> &str {
+        &self.item_name
+    }
+
+    /// Returns when the item was added.
+    pub fn added_at(&self) -> &str {
+        &self.added_at
+    }
+
+    /// Returns the ID of the recognized speaker who added the item, if the
+    /// voice was recognized.
+    pub fn person_id(&self) -> Option<&str> {
+        self.person_id.as_deref()
+    }
+}
+
+/// Time span the user asks about ("heute", "diese Woche").
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HistoryPeriod {
+    #[default]
+    Today,
+    /// Since Monday of the current week.
+    ThisWeek,
+}
+
+impl HistoryPeriod {
+    /// Parses a spoken period such as "heute" or "diese Woche".
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "heute" => Some(Self::Today),
+            "diese woche" | "dieser woche" | "in dieser woche" | "seit montag" => {
+                Some(Self::ThisWeek)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the period for the start of a sentence, e.g. "Diese Woche".
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Today => "Heute",
+            Self::ThisWeek => "Diese Woche",
+        }
+    }
+
+    /// Returns the instant the period containing `now` starts, as an
+    /// ISO 8601 UTC timestamp, or `None` if `now` is not one.
+    ///
+    /// Days are Europe/Berlin days, as the skill's users are in the German
+    /// time zone while Alexa request timestamps are UTC. Timestamps at or
+    /// after the returned one belong to the period.
+    pub fn start(&self, now: &str) -> Option<String> {
+        let days = calendar::berlin_day(calendar::parse_timestamp(now)?);
+        let start = match self {
+            Self::Today => days,
+            // 1970-01-01 was a Thursday, three days after a Monday
+            Self::ThisWeek => days - (days + 3).rem_euclid(7),
+        };
+        Some(calendar::format_timestamp(calendar::berlin_midnight(start)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn today_starts_at_midnight() {
+        assert_eq!(
+            HistoryPeriod::Today
+                .start("2024-01-27T10:00:00Z")
+                .as_deref(),
+            Some("2024-01-26T23:00:00Z")
+        );
+        // Summer time
+        assert_eq!(
+            HistoryPeriod::Today
+                .start("2024-07-01T10:00:00Z")
+                .as_deref(),
+            Some("2024-06-30T22:00:00Z")
+        );
+    }
+
+    #[test]
+    fn week_starts_on_monday() {
+        // Saturday
+        assert_eq!(
+            HistoryPeriod::ThisWeek
+                .start("2024-01-27T10:00:00Z")
+                .as_deref(),
+            Some("2024-01-21T23:00:00Z")
+        );
+        // Sunday, across the leap day
+        assert_eq!(
+            HistoryPeriod::ThisWeek
+                .start("2024-03-03T22:59:59Z")
+                .as_deref(),
+            Some("2024-02-25T23:00:00Z")
+        );
+        // Monday in Berlin, still Sunday in UTC
+        assert_eq!(
+            HistoryPeriod::ThisWeek
+                .start("2023-12-31T23:30:00Z")
+                .as_deref(),
+            Some("2023-12-31T23:00:00Z")
+        );
+    }
+
+    #[test]
+    fn invalid_timestamp_has_no_start() {
+        assert_eq!(HistoryPeriod::Today.start("gestern"), None);
+        assert_eq!(HistoryPeriod::Today.start("2024-13-01T00:00:00Z"), None);
+    }
+
+    #[test]
+    fn parses_spoken_periods() {
+        assert_eq!(HistoryPeriod::parse("Heute"), Some(HistoryPeriod::Today));
+        assert_eq!(
+            HistoryPeriod::parse("diese Woche"),
+            Some(HistoryPeriod::ThisWeek)
+        );
+        assert_eq!(HistoryPeriod::parse("gestern"), None);
+    }
+}
@@ -0,0 +1,215 @@
+use super::CookidooCredentials;
+
+/// A further Cookidoo account of the household, e.g. "Papa".
+///
+/// The configured account stays the household's; a further account is used
+/// for the recognized voices in `person_ids` and after the user chose it
+/// with "benutze Papas Liste".
+#[derive(Debug, Clone)]
+pub struct HouseholdAccount {
+    name: String,
+    credentials: CookidooCredentials,
+    credentials_secret: Option<String>,
+    person_ids: Vec<String>,
+}
+
+impl HouseholdAccount {
+    /// Creates an account with the name it is chosen by.
+    pub fn new(name: impl Into<String>, credentials: CookidooCredentials) -> Self {
+        Self {
+            name: name.into().trim().to_string(),
+            credentials,
+            credentials_secret: None,
+            person_ids: Vec::new(),
+        }
+    }
+
+    /// Sets the Secrets Manager secret holding the account's credentials;
+    /// they replace the ones passed on construction once read.
+    pub fn with_credentials_secret(mut self, secret_id: impl Into<String>) -> Self {
+        self.credentials_secret = Some(secret_id.into());
+        self
+    }
+
+    /// Sets the Alexa person IDs whose voices use this account.
+    pub fn with_person_ids(mut self, person_ids: impl IntoIterator<Item = String>) -> Self {
+        self.person_ids = person_ids.into_iter().collect();
+        self
+    }
+
+    /// Returns the name the account is chosen by.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the Cookidoo credentials of the account.
+    pub fn credentials(&self) -> &CookidooCredentials {
+        &self.credentials
+    }
+
+    /// Returns the Secrets Manager secret holding the credentials, if any.
+    pub fn credentials_secret(&self) -> Option<&str> {
+        self.credentials_secret.as_deref()
+    }
+
+    /// Returns the Alexa person IDs whose voices use this account.
+    pub fn person_ids(&self) -> &[String] {
+        &self.person_ids
+    }
+
+    /// Returns true if `spoken` names this account, ignoring case and a
+    /// genitive "s" ("Papas Liste").
+    pub fn is_named(&self, spoken: &str) -> bool {
+        let spoken = spoken.trim().to_lowercase();
+        let name = self.name.to_lowercase();
+        spoken == name || spoken.strip_suffix('s') == Some(name.as_str())
+    }
+}
+
+/// The account a user chose by voice.
+///
+/// Choosing the shared list is kept as a choice of its own, so it also wins
+/// over a further account assigned to the speaker's voice.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AccountChoice {
+    /// The configured account the household shares.
+    Household,
+    /// The further account of this name.
+    Named(String),
+}
+
+impl AccountChoice {
+    /// Stored form of [`AccountChoice::Household`]; no account name is blank.
+    const HOUSEHOLD: &'static str = "";
+
+    /// Returns the stored form of the choice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Household => Self::HOUSEHOLD,
+            Self::Named(name) => name,
+        }
+    }
+
+    /// Parses the stored form of a choice.
+    pub fn parse(value: &str) -> Self {
+        match value.trim() {
+            Self::HOUSEHOLD => Self::Household,
+            name => Self::Named(name.to_string()),
+        }
+    }
+}
+
+/// The further Cookidoo accounts of a household.
+#[derive(Debug, Clone, Default)]
+pub struct HouseholdAccounts {
+    accounts: Vec<HouseholdAccount>,
+}
+
+impl HouseholdAccounts {
+    /// Creates a household with only the configured account.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a further account.
+    pub fn with_account(mut self, account: HouseholdAccount) -> Self {
+        self.accounts.push(account);
+        self
+    }
+
+    /// Returns true if the household has no further accounts.
+    pub fn is_empty(&self) -> bool {
+        self.accounts.is_empty()
+    }
+
+    /// Returns the further accounts.
+    pub fn accounts(&self) -> &[HouseholdAccount] {
+        &self.accounts
+    }
+
+    /// Returns the account a spoken name refers to, if any.
+    pub fn find(&self, spoken: &str) -> Option<&HouseholdAccount> {
+        // An exact name wins over one that only matches without the "s"
+        let exact = spoken.trim().to_lowercase();
+        self.accounts
+            .iter()
+            .find(|account| account.name.to_lowercase() == exact)
+            .or_else(|| {
+                self.accounts
+                    .iter()
+                    .find(|account| account.is_named(spoken))
+            })
+    }
+
+    /// Returns the account the voice `person_id` uses, if any.
+    pub fn for_person(&self, person_id: &str) -> Option<&HouseholdAccount> {
+        self.accounts
+            .iter()
+            .find(|account| account.person_ids.iter().any(|id| id == person_id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn household() -> HouseholdAccounts {
+        HouseholdAccounts::new()
+            .with_account(
+                HouseholdAccount::new("Papa", CookidooCredentials::new("papa@example.de", "pw"))
+                    .with_person_ids(["amzn1.ask.person.PAPA".to_string()]),
+            )
+            .with_account(HouseholdAccount::new(
+                "Klaus",
+                CookidooCredentials::new("klaus@example.de", "pw"),
+            ))
+    }
+
+    #[test]
+    fn finds_account_by_spoken_name() {
+        let household = household();
+        assert_eq!(
+            household.find("papa").map(HouseholdAccount::name),
+            Some("Papa")
+        );
+        assert_eq!(
+            household.find("Papas").map(HouseholdAccount::name),
+            Some("Papa")
+        );
+        assert!(household.find("Mama").is_none());
+    }
+
+    #[test]
+    fn exact_name_wins_over_genitive() {
+        let household = household().with_account(HouseholdAccount::new(
+            "Klau",
+            CookidooCredentials::new("klau@example.de", "pw"),
+        ));
+        assert_eq!(
+            household.find("Klaus").map(HouseholdAccount::name),
+            Some("Klaus")
+        );
+    }
+
+    #[test]
+    fn parses_stored_choice() {
+        for choice in [
+            AccountChoice::Household,
+            AccountChoice::Named("Papa".to_string()),
+        ] {
+            assert_eq!(AccountChoice::parse(choice.as_str()), choice);
+        }
+    }
+
+    #[test]
+    fn finds_account_by_person() {
+        let household = household();
+        assert_eq!(
+            household
+                .for_person("amzn1.ask.person.PAPA")
+                .map(HouseholdAccount::name),
+            Some("Papa")
+        );
+        assert!(household.for_person("amzn1.ask.person.OTHER").is_none());
+    }
+}
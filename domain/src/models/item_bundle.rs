@@ -0,0 +1,193 @@
+/// A named set of items added with one request, e.g. "Frühstücks-Set"
+/// with "Brötchen", "Butter" and "Marmelade".
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ItemBundle {
+    name: String,
+    items: Vec<String>,
+}
+
+impl ItemBundle {
+    /// Creates a bundle with the name it is chosen by; blank items are
+    /// dropped.
+    pub fn new<I, S>(name: impl Into<String>, items: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        Self {
+            name: name.into().trim().to_string(),
+            items: items
+                .into_iter()
+                .map(|item| item.as_ref().trim().to_string())
+                .filter(|item| !item.is_empty())
+                .collect(),
+        }
+    }
+
+    /// Returns the name the bundle is chosen by.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the items of the bundle.
+    pub fn items(&self) -> &[String] {
+        &self.items
+    }
+
+    /// Returns the bundle with `item` added at the end, unless it already
+    /// holds it (ignoring case).
+    pub fn with_item(mut self, item: &str) -> Self {
+        let item = item.trim();
+        if !item.is_empty() && !self.contains(item) {
+            self.items.push(item.to_string());
+        }
+        self
+    }
+
+    /// Returns true if the bundle holds `item`, ignoring case.
+    pub fn contains(&self, item: &str) -> bool {
+        let item = item.trim().to_lowercase();
+        self.items
+            .iter()
+            .any(|existing| existing.to_lowercase() == item)
+    }
+
+    /// Returns true if `spoken` names this bundle, ignoring case, hyphens,
+    /// spaces, a leading "mein" and a trailing "Set" ("mein Frühstücksset"
+    /// and "Frühstücks" both name "Frühstücks-Set").
+    pub fn is_named(&self, spoken: &str) -> bool {
+        let spoken = key(spoken);
+        let name = key(&self.name);
+        spoken == name || Some(spoken.as_str()) == name.strip_suffix("set")
+    }
+}
+
+/// Lowercases a spoken bundle name and drops what varies between
+/// utterances of it.
+fn key(spoken: &str) -> String {
+    let spoken = spoken.trim().to_lowercase();
+    let spoken = ["mein ", "meine ", "das ", "die "]
+        .iter()
+        .find_map(|article| spoken.strip_prefix(article))
+        .unwrap_or(&spoken);
+    spoken
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != '-')
+        .collect()
+}
+
+/// The item bundles a user can add by name.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ItemBundles {
+    bundles: Vec<ItemBundle>,
+}
+
+impl ItemBundles {
+    /// Creates an empty set of bundles.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a bundle.
+    pub fn with_bundle(mut self, bundle: ItemBundle) -> Self {
+        self.bundles.push(bundle);
+        self
+    }
+
+    /// Returns true if there is no bundle.
+    pub fn is_empty(&self) -> bool {
+        self.bundles.is_empty()
+    }
+
+    /// Returns the bundles.
+    pub fn bundles(&self) -> &[ItemBundle] {
+        &self.bundles
+    }
+
+    /// Returns the bundle a spoken name refers to, if any.
+    pub fn find(&self, spoken: &str) -> Option<&ItemBundle> {
+        self.bundles.iter().find(|bundle| bundle.is_named(spoken))
+    }
+
+    /// Stores `bundle`, replacing the one of the same name.
+    pub fn put(&mut self, bundle: ItemBundle) {
+        match self
+            .bundles
+            .iter_mut()
+            .find(|existing| existing.is_named(bundle.name()))
+        {
+            Some(existing) => *existing = bundle,
+            None => self.bundles.push(bundle),
+        }
+    }
+
+    /// Removes and returns the bundle a spoken name refers to, if any.
+    pub fn remove(&mut self, spoken: &str) -> Option<ItemBundle> {
+        let index = self
+            .bundles
+            .iter()
+            .position(|bundle| bundle.is_named(spoken))?;
+        Some(self.bundles.remove(index))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn breakfast() -> ItemBundle {
+        ItemBundle::new("Frühstücks-Set", ["Brötchen", " Butter ", ""])
+    }
+
+    #[test]
+    fn drops_blank_items() {
+        assert_eq!(breakfast().items(), ["Brötchen", "Butter"]);
+    }
+
+    #[test]
+    fn matches_spoken_variants_of_name() {
+        let bundle = breakfast();
+        for spoken in [
+            "Frühstücks-Set",
+            "mein frühstücksset",
+            "Frühstücks Set",
+            "Frühstücks",
+        ] {
+            assert!(bundle.is_named(spoken), "{spoken}");
+        }
+        assert!(!bundle.is_named("Grill-Set"));
+        assert!(!bundle.is_named("Set"));
+    }
+
+    #[test]
+    fn adds_items_once() {
+        let bundle = breakfast().with_item("Marmelade").with_item("butter");
+        assert_eq!(bundle.items(), ["Brötchen", "Butter", "Marmelade"]);
+    }
+
+    #[test]
+    fn replaces_and_removes_bundles_by_name() {
+        let mut bundles = ItemBundles::new().with_bundle(breakfast());
+
+        bundles.put(ItemBundle::new("Frühstücksset", ["Kaffee"]));
+        assert_eq!(bundles.bundles().len(), 1);
+        assert_eq!(bundles.find("Frühstücks-Set").unwrap().items(), ["Kaffee"]);
+
+        assert!(bundles.remove("mein Frühstücksset").is_some());
+        assert!(bundles.is_empty());
+        assert!(bundles.remove("Frühstücksset").is_none());
+    }
+
+    #[test]
+    fn finds_bundle_by_spoken_name() {
+        let bundles = ItemBundles::new()
+            .with_bundle(breakfast())
+            .with_bundle(ItemBundle::new("Grillabend", ["Würstchen"]));
+
+        assert_eq!(
+            bundles.find("mein Grillabend").map(ItemBundle::name),
+            Some("Grillabend")
+        );
+        assert!(bundles.find("Kaffee").is_none());
+    }
+}
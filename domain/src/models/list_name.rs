@@ -0,0 +1,124 @@
+/// Name of a secondary (gift/occasion) list kept by the skill.
+///
+/// Spoken list names vary in casing and spacing, so lists are identified by
+/// a normalized key while the spoken form is kept for responses.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ListName {
+    key: String,
+    label: String,
+}
+
+impl ListName {
+    /// Creates a list name from a spoken value, or `None` if it is blank.
+    ///
+    /// A hyphenated name such as "Party-Liste" is the same list as "Party".
+    pub fn new(raw: &str) -> Option<Self> {
+        let label = raw.split_whitespace().collect::<Vec<_>>().join(" ");
+        if label.is_empty() {
+            return None;
+        }
+
+        let key = label.to_lowercase();
+        const SUFFIX: &str = "-liste";
+        if key.len() > SUFFIX.len() && key.ends_with(SUFFIX) {
+            let base = &label[..label.len() - SUFFIX.len()];
+            return Some(Self {
+                key: base.to_lowercase(),
+                label: format!("{}-Liste", capitalize(base)),
+            });
+        }
+
+        let label = if key.ends_with("liste") {
+            capitalize(&label)
+        } else {
+            format!("Liste {}", capitalize(&label))
+        };
+
+        Some(Self { key, label })
+    }
+
+    /// Returns the normalized key used for storage.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Returns the key a hyphenated name such as "Party-Liste" was stored
+    /// under before it became the same list as "Party", or `None` if the
+    /// key did not change.
+    ///
+    /// Stores look lists up under both keys, so lists kept before still
+    /// answer.
+    pub fn legacy_key(&self) -> Option<String> {
+        self.label
+            .ends_with("-Liste")
+            .then(|| self.label.to_lowercase())
+            .filter(|legacy| *legacy != self.key)
+    }
+
+    /// Returns the name for use in speech, e.g. "Geburtstagsliste".
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Returns true if the name refers to the main shopping list itself.
+    pub fn is_shopping_list(&self) -> bool {
+        matches!(
+            self.key.as_str(),
+            "liste" | "einkaufsliste" | "cookidoo liste"
+        )
+    }
+}
+
+fn capitalize(value: &str) -> String {
+    let mut chars = value.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn blank_name_is_rejected() {
+        assert_eq!(ListName::new(""), None);
+        assert_eq!(ListName::new("   "), None);
+    }
+
+    #[test]
+    fn normalizes_key() {
+        let name = ListName::new("  Geburtstags  Liste ").unwrap();
+        assert_eq!(name.key(), "geburtstags liste");
+    }
+
+    #[test]
+    fn keeps_list_names_as_label() {
+        let name = ListName::new("geburtstagsliste").unwrap();
+        assert_eq!(name.label(), "Geburtstagsliste");
+    }
+
+    #[test]
+    fn prefixes_other_names_with_liste() {
+        let name = ListName::new("Weihnachten").unwrap();
+        assert_eq!(name.label(), "Liste Weihnachten");
+    }
+
+    #[test]
+    fn hyphenated_name_is_same_list_as_base_name() {
+        let hyphenated = ListName::new("party-liste").unwrap();
+        assert_eq!(hyphenated.key(), ListName::new("Party").unwrap().key());
+        assert_eq!(hyphenated.label(), "Party-Liste");
+        assert_eq!(hyphenated.legacy_key().as_deref(), Some("party-liste"));
+        assert_eq!(ListName::new("Party").unwrap().legacy_key(), None);
+    }
+
+    #[test]
+    fn recognizes_shopping_list() {
+        assert!(ListName::new("Einkaufsliste").unwrap().is_shopping_list());
+        assert!(!ListName::new("Geburtstagsliste")
+            .unwrap()
+            .is_shopping_list());
+    }
+}
@@ -0,0 +1,122 @@
+/// Ordinal words, "erste" to "zehnte", by their stem.
+const ORDINALS: &[&str] = &[
+    "erst", "zweit", "dritt", "viert", "fünft", "sechst", "siebt", "acht", "neunt", "zehnt",
+];
+
+/// Numbers after "Nummer", "eins" to "zehn".
+const NUMBERS: &[&str] = &[
+    "eins", "zwei", "drei", "vier", "fünf", "sechs", "sieben", "acht", "neun", "zehn",
+];
+
+/// Position of an entry on the list as it is read aloud, e.g. "den
+/// letzten" or "Nummer drei".
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ListPosition {
+    /// The n-th entry, counted from 1.
+    Number(usize),
+    /// The last entry.
+    Last,
+}
+
+impl ListPosition {
+    /// Parses a spoken position such as "den letzten Artikel", "den
+    /// zweiten", "Nummer drei" or "3.".
+    pub fn parse(value: &str) -> Option<Self> {
+        let value = value.trim().to_lowercase();
+        let mut words: Vec<&str> = value.split_whitespace().collect();
+        if let Some(&("den" | "die" | "das")) = words.first() {
+            words.remove(0);
+        }
+        if let Some(&("artikel" | "eintrag" | "punkt")) = words.last() {
+            words.pop();
+        }
+
+        match words.as_slice() {
+            ["nummer", number] => Self::number(number),
+            [word] => Self::ordinal(word),
+            _ => None,
+        }
+    }
+
+    /// Returns the index of the position in a list of `len` entries, or
+    /// `None` if the list is shorter.
+    pub fn index(&self, len: usize) -> Option<usize> {
+        match *self {
+            Self::Number(number) => (1..=len).contains(&number).then(|| number - 1),
+            Self::Last => len.checked_sub(1),
+        }
+    }
+
+    /// "3" or "drei", as said after "Nummer".
+    fn number(word: &str) -> Option<Self> {
+        let number = match word.parse() {
+            Ok(number) => number,
+            Err(_) => NUMBERS.iter().position(|number| *number == word)? + 1,
+        };
+        (number > 0).then_some(Self::Number(number))
+    }
+
+    /// "letzten", "zweite" or "3.".
+    fn ordinal(word: &str) -> Option<Self> {
+        if let Some(digits) = word.strip_suffix('.') {
+            return Self::number(digits);
+        }
+        let stem = ["en", "es", "e"]
+            .iter()
+            .find_map(|ending| word.strip_suffix(ending))?;
+        if stem == "letzt" {
+            return Some(Self::Last);
+        }
+        ORDINALS
+            .iter()
+            .position(|ordinal| *ordinal == stem)
+            .map(|index| Self::Number(index + 1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_positions() {
+        assert_eq!(
+            ListPosition::parse("den letzten Artikel"),
+            Some(ListPosition::Last)
+        );
+        assert_eq!(ListPosition::parse("letzte"), Some(ListPosition::Last));
+        assert_eq!(
+            ListPosition::parse("den ersten"),
+            Some(ListPosition::Number(1))
+        );
+        assert_eq!(
+            ListPosition::parse("das fünfte"),
+            Some(ListPosition::Number(5))
+        );
+        assert_eq!(
+            ListPosition::parse("Nummer drei"),
+            Some(ListPosition::Number(3))
+        );
+        assert_eq!(
+            ListPosition::parse("Nummer 12"),
+            Some(ListPosition::Number(12))
+        );
+        assert_eq!(ListPosition::parse("2."), Some(ListPosition::Number(2)));
+    }
+
+    #[test]
+    fn rejects_item_names() {
+        for value in ["Milch", "die Eier", "Nummer null", "Nummer", "0.", ""] {
+            assert_eq!(ListPosition::parse(value), None, "{value}");
+        }
+    }
+
+    #[test]
+    fn resolves_index_in_list() {
+        assert_eq!(ListPosition::Number(1).index(3), Some(0));
+        assert_eq!(ListPosition::Number(3).index(3), Some(2));
+        assert_eq!(ListPosition::Number(4).index(3), None);
+        assert_eq!(ListPosition::Last.index(3), Some(2));
+        assert_eq!(ListPosition::Last.index(0), None);
+    }
+}
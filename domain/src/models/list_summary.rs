@@ -0,0 +1,71 @@
+use super::Category;
+
+/// Counts describing the shopping list as a whole.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ListSummary {
+    total: usize,
+    checked_off: usize,
+    open_categories: Vec<(Category, usize)>,
+}
+
+impl ListSummary {
+    /// Creates a summary; categories are ordered by descending count, ties
+    /// in category display order.
+    pub fn new(
+        total: usize,
+        checked_off: usize,
+        mut open_categories: Vec<(Category, usize)>,
+    ) -> Self {
+        open_categories.sort_by_key(|(category, count)| {
+            let position = Category::ALL.iter().position(|c| c == category);
+            (std::cmp::Reverse(*count), position)
+        });
+        Self {
+            total,
+            checked_off,
+            open_categories,
+        }
+    }
+
+    /// Returns the number of entries on the list.
+    pub fn total(&self) -> usize {
+        self.total
+    }
+
+    /// Returns the number of entries already checked off.
+    pub fn checked_off(&self) -> usize {
+        self.checked_off
+    }
+
+    /// Returns the categories of the open entries with their counts, most
+    /// frequent first; unclassified entries are not counted.
+    pub fn open_categories(&self) -> &[(Category, usize)] {
+        &self.open_categories
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn orders_categories_by_count() {
+        let summary = ListSummary::new(
+            5,
+            0,
+            vec![
+                (Category::Household, 1),
+                (Category::Dairy, 2),
+                (Category::FruitAndVegetables, 2),
+            ],
+        );
+        assert_eq!(
+            summary.open_categories(),
+            &[
+                (Category::FruitAndVegetables, 2),
+                (Category::Dairy, 2),
+                (Category::Household, 1),
+            ]
+        );
+    }
+}
@@ -0,0 +1,85 @@
+/// Cookidoo market a shopping list belongs to.
+///
+/// Each market has its own API host and language; a Cookidoo account only
+/// sees its list in the market it was created in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Market {
+    country_code: &'static str,
+    language: &'static str,
+}
+
+/// Supported markets as (ISO 3166 country code, language).
+const MARKETS: &[(&str, &str)] = &[
+    ("DE", "de-DE"),
+    ("AT", "de-AT"),
+    ("CH", "de-CH"),
+    ("LU", "de-LU"),
+    ("FR", "fr-FR"),
+    ("IT", "it-IT"),
+    ("ES", "es-ES"),
+    ("PT", "pt-PT"),
+    ("NL", "nl-NL"),
+    ("PL", "pl-PL"),
+    ("GB", "en-GB"),
+    ("US", "en-US"),
+    ("AU", "en-AU"),
+];
+
+impl Market {
+    /// Market used when none is configured or detected.
+    pub const GERMANY: Market = Market {
+        country_code: "DE",
+        language: "de-DE",
+    };
+
+    /// Returns the market for an ISO 3166 country code (e.g. "AT"), or
+    /// `None` if Cookidoo is not available there.
+    pub fn from_country_code(country_code: &str) -> Option<Self> {
+        let country_code = country_code.trim();
+        MARKETS
+            .iter()
+            .find(|(code, _)| code.eq_ignore_ascii_case(country_code))
+            .map(|&(country_code, language)| Self {
+                country_code,
+                language,
+            })
+    }
+
+    /// Returns the upper-case ISO 3166 country code.
+    pub fn country_code(&self) -> &'static str {
+        self.country_code
+    }
+
+    /// Returns the language tag used in Cookidoo API paths (e.g. "de-AT").
+    pub fn language(&self) -> &'static str {
+        self.language
+    }
+}
+
+impl Default for Market {
+    fn default() -> Self {
+        Self::GERMANY
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_market_by_country_code() {
+        let market = Market::from_country_code(" at ").unwrap();
+        assert_eq!(market.country_code(), "AT");
+        assert_eq!(market.language(), "de-AT");
+    }
+
+    #[test]
+    fn rejects_unsupported_country() {
+        assert_eq!(Market::from_country_code("JP"), None);
+    }
+
+    #[test]
+    fn defaults_to_germany() {
+        assert_eq!(Market::default(), Market::from_country_code("DE").unwrap());
+    }
+}
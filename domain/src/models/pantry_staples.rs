@@ -0,0 +1,72 @@
+/// Items a household always has at home, such as "Salz" or "Pfeffer".
+///
+/// When a whole list is put onto the shopping list, these are skipped
+/// instead of being added every time. Names match case-insensitively.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PantryStaples {
+    names: Vec<String>,
+}
+
+impl PantryStaples {
+    /// Creates a pantry from item names; blank and repeated names are
+    /// dropped.
+    pub fn new<I, S>(names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut pantry = Self::default();
+        for name in names {
+            let name = name.as_ref().trim();
+            if !name.is_empty() && !pantry.contains(name) {
+                pantry.names.push(name.to_string());
+            }
+        }
+        pantry
+    }
+
+    /// Parses a comma-separated list such as "Salz, Pfeffer, Öl".
+    pub fn parse(value: &str) -> Self {
+        Self::new(value.split(','))
+    }
+
+    /// Returns true if `name` is a staple.
+    pub fn contains(&self, name: &str) -> bool {
+        let name = name.trim().to_lowercase();
+        self.names
+            .iter()
+            .any(|staple| staple.to_lowercase() == name)
+    }
+
+    /// Returns the staples in the order given.
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    /// Returns true if no staple is set.
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_comma_separated_names() {
+        let pantry = PantryStaples::parse("Salz, Pfeffer,, salz ,Öl");
+
+        assert_eq!(pantry.names(), ["Salz", "Pfeffer", "Öl"]);
+        assert!(PantryStaples::parse(" ").is_empty());
+    }
+
+    #[test]
+    fn matches_names_case_insensitively() {
+        let pantry = PantryStaples::new(["Salz", "Öl"]);
+
+        assert!(pantry.contains("salz"));
+        assert!(pantry.contains(" ÖL "));
+        assert!(!pantry.contains("Olivenöl"));
+    }
+}
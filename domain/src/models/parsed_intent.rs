@@ -0,0 +1,166 @@
+use std::time::Duration;
+
+use super::{HistoryPeriod, ListName, ListPosition, SettingChange};
+
+/// A user intent recognized by a voice platform.
+///
+/// Each platform adapter parses its requests into these intents, so the
+/// domain services are driven the same way regardless of the assistant.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParsedIntent {
+    /// User wants to add an item to the shopping list, or to the named
+    /// occasion list if a list context was spoken. A spoken note such as
+    /// "fettarm" in "füge Milch hinzu, fettarm" is kept next to the name.
+    AddItem {
+        item_name: String,
+        note: Option<String>,
+        list: Option<ListName>,
+    },
+    /// User named just an item, e.g. "Eier" after the skill asked "Noch
+    /// etwas?". Only an answer to such a question adds the item.
+    FollowUpItem { item_name: String },
+    /// User named several items in free-form speech, e.g. "wir haben keine
+    /// Milch und Eier mehr"; each is added to the shopping list.
+    AddItems { item_names: Vec<String> },
+    /// User wants every item of a named bundle added to the shopping list,
+    /// e.g. "füge mein Frühstücks-Set hinzu"; `name` is the spoken name.
+    AddBundle { name: String },
+    /// User wants an item added to one of their bundles, creating the
+    /// bundle if needed, e.g. "füge Butter zu meinem Frühstücks-Set hinzu".
+    ExtendBundle { name: String, item_name: String },
+    /// User wants one of their bundles deleted.
+    DeleteBundle { name: String },
+    /// User wants to hear the items of the named bundle, or the names of
+    /// all bundles if `name` is `None`.
+    ReadBundle { name: Option<String> },
+    /// User wants to hear the shopping list or the named occasion list.
+    ReadList { list: Option<ListName> },
+    /// User wants to hear only the entries not yet checked off, e.g. "was
+    /// fehlt noch?".
+    ReadOpenItems,
+    /// User wants to know how many items are on the shopping list.
+    CountItems,
+    /// User wants to move everything on the named occasion list onto the
+    /// shopping list, e.g. "übertrage die Party-Liste auf die Einkaufsliste".
+    TransferList { list: ListName },
+    /// User wants to hear what they added in the given period.
+    ReadHistory { period: HistoryPeriod },
+    /// User wants to remove an item from the shopping list or the named
+    /// occasion list.
+    RemoveItem {
+        item_name: String,
+        list: Option<ListName>,
+    },
+    /// User wants to remove the entry at a position of the shopping list as
+    /// it is read aloud, e.g. "entferne den letzten Artikel".
+    RemoveItemAt { position: ListPosition },
+    /// User wants to add an item and be reminded to buy it after `offset`
+    /// (or the default delay if no duration was spoken).
+    Remind {
+        item_name: String,
+        offset: Option<Duration>,
+    },
+    /// User wants to change a setting, e.g. "stelle kurze Antworten ein".
+    ChangeSetting { change: SettingChange },
+    /// User wants everything the skill stored about them deleted.
+    DeleteMyData,
+    /// User checked off the item with the given backend ID, e.g. by
+    /// tapping it on a screen.
+    CheckOffItem { item_id: String },
+    /// User requested help.
+    Help,
+    /// User wants to cancel.
+    Cancel,
+    /// User wants to stop.
+    Stop,
+    /// User wants to hear the last response again.
+    Repeat,
+    /// User wants to start over, dropping any pending conversation state.
+    StartOver,
+    /// User agreed to what the skill asked, e.g. "Soll ich weiterlesen?".
+    Yes,
+    /// User declined what the skill asked.
+    No,
+    /// User launched the skill without a specific intent.
+    Launch,
+    /// User granted or changed skill permissions; carries all accepted scopes.
+    PermissionsChanged {
+        user_id: String,
+        scopes: Vec<String>,
+    },
+    /// User changed proactive event subscriptions; carries all current events.
+    SubscriptionsChanged {
+        user_id: String,
+        events: Vec<String>,
+    },
+    /// User enabled the skill.
+    SkillEnabled { user_id: String },
+    /// User disabled the skill; `keeps_user_data` is true if Alexa keeps
+    /// the user ID for a later re-enable.
+    SkillDisabled {
+        user_id: String,
+        keeps_user_data: bool,
+    },
+    /// User linked their account to the skill.
+    AccountLinked { user_id: String },
+    /// Unknown or unsupported intent.
+    Unknown,
+}
+
+impl ParsedIntent {
+    /// Names the intent without its values, e.g. for logs that must not
+    /// carry what the user said.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::AddItem { .. } => "AddItem",
+            Self::FollowUpItem { .. } => "FollowUpItem",
+            Self::AddItems { .. } => "AddItems",
+            Self::AddBundle { .. } => "AddBundle",
+            Self::ExtendBundle { .. } => "ExtendBundle",
+            Self::DeleteBundle { .. } => "DeleteBundle",
+            Self::ReadBundle { .. } => "ReadBundle",
+            Self::ReadList { .. } => "ReadList",
+            Self::ReadOpenItems => "ReadOpenItems",
+            Self::CountItems => "CountItems",
+            Self::TransferList { .. } => "TransferList",
+            Self::ReadHistory { .. } => "ReadHistory",
+            Self::RemoveItem { .. } => "RemoveItem",
+            Self::RemoveItemAt { .. } => "RemoveItemAt",
+            Self::Remind { .. } => "Remind",
+            Self::ChangeSetting { .. } => "ChangeSetting",
+            Self::DeleteMyData => "DeleteMyData",
+            Self::CheckOffItem { .. } => "CheckOffItem",
+            Self::Help => "Help",
+            Self::Cancel => "Cancel",
+            Self::Stop => "Stop",
+            Self::Repeat => "Repeat",
+            Self::StartOver => "StartOver",
+            Self::Yes => "Yes",
+            Self::No => "No",
+            Self::Launch => "Launch",
+            Self::PermissionsChanged { .. } => "PermissionsChanged",
+            Self::SubscriptionsChanged { .. } => "SubscriptionsChanged",
+            Self::SkillEnabled { .. } => "SkillEnabled",
+            Self::SkillDisabled { .. } => "SkillDisabled",
+            Self::AccountLinked { .. } => "AccountLinked",
+            Self::Unknown => "Unknown",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn kind_leaves_out_values() {
+        let intent = ParsedIntent::AddItem {
+            item_name: "Milch".to_string(),
+            note: Some("fettarm".to_string()),
+            list: None,
+        };
+
+        assert_eq!(intent.kind(), "AddItem");
+        assert_eq!(ParsedIntent::ReadOpenItems.kind(), "ReadOpenItems");
+    }
+}
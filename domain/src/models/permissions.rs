@@ -0,0 +1,86 @@
+use std::collections::BTreeSet;
+
+/// Permission scopes and proactive event subscriptions a user has granted
+/// to the skill, as reported by Alexa skill lifecycle events.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SkillPermissions {
+    scopes: BTreeSet<String>,
+    subscriptions: BTreeSet<String>,
+}
+
+impl SkillPermissions {
+    /// Creates an empty permission set (nothing granted).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replaces the granted scopes (e.g. `alexa::alerts:reminders:skill:readwrite`).
+    pub fn set_scopes<I, S>(&mut self, scopes: I)
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.scopes = scopes.into_iter().map(Into::into).collect();
+    }
+
+    /// Replaces the subscribed proactive event names.
+    pub fn set_subscriptions<I, S>(&mut self, events: I)
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.subscriptions = events.into_iter().map(Into::into).collect();
+    }
+
+    /// Returns true if the given permission scope has been granted.
+    pub fn has_scope(&self, scope: &str) -> bool {
+        self.scopes.contains(scope)
+    }
+
+    /// Returns true if the user is subscribed to the given proactive event.
+    pub fn is_subscribed(&self, event_name: &str) -> bool {
+        self.subscriptions.contains(event_name)
+    }
+
+    /// Returns the granted scopes in sorted order.
+    pub fn scopes(&self) -> impl Iterator<Item = &str> {
+        self.scopes.iter().map(String::as_str)
+    }
+
+    /// Returns the subscribed proactive events in sorted order.
+    pub fn subscriptions(&self) -> impl Iterator<Item = &str> {
+        self.subscriptions.iter().map(String::as_str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_permissions_grant_nothing() {
+        let permissions = SkillPermissions::new();
+        assert!(!permissions.has_scope("alexa::household:lists:read"));
+        assert!(!permissions.is_subscribed("AMAZON.MessageAlert.Activated"));
+    }
+
+    #[test]
+    fn set_scopes_replaces_previous_scopes() {
+        let mut permissions = SkillPermissions::new();
+        permissions.set_scopes(["scope-a", "scope-b"]);
+        permissions.set_scopes(["scope-b"]);
+
+        assert!(!permissions.has_scope("scope-a"));
+        assert!(permissions.has_scope("scope-b"));
+    }
+
+    #[test]
+    fn set_subscriptions_replaces_previous_subscriptions() {
+        let mut permissions = SkillPermissions::new();
+        permissions.set_subscriptions(["event-a"]);
+        permissions.set_subscriptions(Vec::<String>::new());
+
+        assert!(!permissions.is_subscribed("event-a"));
+        assert_eq!(permissions.subscriptions().count(), 0);
+    }
+}
@@ -0,0 +1,32 @@
+/// How a spoken count such as "drei Zitronen" is written to the list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum QuantityStyle {
+    /// One entry with the count in front: "3x Zitronen".
+    #[default]
+    Label,
+    /// The entry once per piece, e.g. three times "Zitronen".
+    Repeat,
+}
+
+impl QuantityStyle {
+    /// Parses `label` or `repeat` (case-insensitive).
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "label" => Some(Self::Label),
+            "repeat" => Some(Self::Repeat),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_styles() {
+        assert_eq!(QuantityStyle::parse("Repeat"), Some(QuantityStyle::Repeat));
+        assert_eq!(QuantityStyle::parse(" label "), Some(QuantityStyle::Label));
+        assert_eq!(QuantityStyle::parse("3x"), None);
+    }
+}
@@ -0,0 +1,69 @@
+use std::time::Duration;
+
+/// A reminder to be scheduled on the user's Alexa devices.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reminder {
+    text: String,
+    offset: Duration,
+    locale: String,
+    request_time: String,
+}
+
+impl Reminder {
+    /// Creates a reminder that fires `offset` after `request_time`.
+    ///
+    /// `request_time` is the ISO 8601 timestamp of the originating request;
+    /// `locale` selects the language the reminder is spoken in.
+    pub fn new(
+        text: impl Into<String>,
+        offset: Duration,
+        locale: impl Into<String>,
+        request_time: impl Into<String>,
+    ) -> Self {
+        Self {
+            text: text.into(),
+            offset,
+            locale: locale.into(),
+            request_time: request_time.into(),
+        }
+    }
+
+    /// Returns the text Alexa speaks when the reminder fires.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns the delay until the reminder fires.
+    pub fn offset(&self) -> Duration {
+        self.offset
+    }
+
+    /// Returns the locale of the reminder text.
+    pub fn locale(&self) -> &str {
+        &self.locale
+    }
+
+    /// Returns the timestamp of the request that created the reminder.
+    pub fn request_time(&self) -> &str {
+        &self.request_time
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accessors_return_values() {
+        let reminder = Reminder::new(
+            "Milch kaufen",
+            Duration::from_secs(3600),
+            "de-DE",
+            "2024-01-27T10:00:00Z",
+        );
+        assert_eq!(reminder.text(), "Milch kaufen");
+        assert_eq!(reminder.offset(), Duration::from_secs(3600));
+        assert_eq!(reminder.locale(), "de-DE");
+        assert_eq!(reminder.request_time(), "2024-01-27T10:00:00Z");
+    }
+}
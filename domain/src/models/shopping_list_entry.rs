@@ -0,0 +1,101 @@
+/// An item as currently stored on the shopping list.
+///
+/// Unlike [`super::ShoppingListItem`], which is validated user input for
+/// adding, an entry is read back from the backend and carries its ID and
+/// whether it was already checked off.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShoppingListEntry {
+    id: String,
+    name: String,
+    owned: bool,
+}
+
+impl ShoppingListEntry {
+    /// Creates a new entry.
+    pub fn new(id: impl Into<String>, name: impl Into<String>, owned: bool) -> Self {
+        Self {
+            id: id.into(),
+            name: name.into(),
+            owned,
+        }
+    }
+
+    /// Returns the backend ID of the entry.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Returns the entry name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns true if the entry was already checked off.
+    pub fn is_owned(&self) -> bool {
+        self.owned
+    }
+
+    /// Returns the name without a note written after it in parentheses:
+    /// "Milch" for "Milch (fettarm)".
+    pub fn item_name(&self) -> &str {
+        let name = self.name.trim();
+        match name
+            .strip_suffix(')')
+            .and_then(|rest| rest.rsplit_once(" ("))
+        {
+            Some((item_name, _)) if !item_name.trim().is_empty() => item_name.trim(),
+            _ => name,
+        }
+    }
+
+    /// Returns true if the entry has the given name, with or without its
+    /// note, ignoring case and surrounding whitespace.
+    pub fn matches_name(&self, name: &str) -> bool {
+        let name = name.trim().to_lowercase();
+        self.name.trim().to_lowercase() == name || self.item_name().to_lowercase() == name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accessors_return_values() {
+        let entry = ShoppingListEntry::new("id-1", "Milch", true);
+        assert_eq!(entry.id(), "id-1");
+        assert_eq!(entry.name(), "Milch");
+        assert!(entry.is_owned());
+    }
+
+    #[test]
+    fn matches_name_ignores_case_and_whitespace() {
+        let entry = ShoppingListEntry::new("id-1", "Milch", false);
+        assert!(entry.matches_name(" milch "));
+        assert!(!entry.matches_name("Milchreis"));
+    }
+
+    #[test]
+    fn item_name_drops_note() {
+        assert_eq!(
+            ShoppingListEntry::new("id-1", "Milch (fettarm)", false).item_name(),
+            "Milch"
+        );
+        assert_eq!(
+            ShoppingListEntry::new("id-1", "Milch", false).item_name(),
+            "Milch"
+        );
+        assert_eq!(
+            ShoppingListEntry::new("id-1", "(bio)", false).item_name(),
+            "(bio)"
+        );
+    }
+
+    #[test]
+    fn matches_name_with_or_without_note() {
+        let entry = ShoppingListEntry::new("id-1", "Milch (fettarm)", false);
+        assert!(entry.matches_name("milch"));
+        assert!(entry.matches_name("Milch (fettarm)"));
+        assert!(!entry.matches_name("fettarm"));
+    }
+}
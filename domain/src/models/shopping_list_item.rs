@@ -0,0 +1,383 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+use super::category::Category;
+use super::error::DomainError;
+
+/// Maximum allowed length for an item name, in user-perceived characters.
+const MAX_ITEM_NAME_LENGTH: usize = 200;
+
+/// A validated shopping list item.
+///
+/// An optional note qualifies the item, e.g. the amount or a variety
+/// ("Milch, fettarm"). Cookidoo has no field for it, so it is written to
+/// the list as part of the [`label`](Self::label): "Milch (fettarm)". A
+/// count of more than one is written in front of it: "3x Zitronen".
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShoppingListItem {
+    name: String,
+    note: Option<String>,
+    quantity: Option<u32>,
+    category: Option<Category>,
+}
+
+impl ShoppingListItem {
+    /// Creates a new ShoppingListItem from a raw name string.
+    ///
+    /// The name is cleaned up with [`sanitize`](Self::sanitize) and
+    /// validated:
+    /// - Must not be empty after trimming
+    /// - Must not exceed 200 characters, counted as displayed: an emoji
+    ///   with skin tone or a letter with a combining accent counts once
+    ///
+    /// # Errors
+    /// Returns `DomainError::InvalidItemName` if validation fails.
+    pub fn new(name: impl Into<String>) -> Result<Self, DomainError> {
+        let name = Self::sanitize(&name.into());
+
+        if name.is_empty() {
+            return Err(DomainError::InvalidItemName(
+                "Item name cannot be empty".to_string(),
+            ));
+        }
+
+        if grapheme_count(&name) > MAX_ITEM_NAME_LENGTH {
+            return Err(DomainError::InvalidItemName(format!(
+                "Item name exceeds maximum length of {} characters",
+                MAX_ITEM_NAME_LENGTH
+            )));
+        }
+
+        Ok(Self {
+            name,
+            note: None,
+            quantity: None,
+            category: None,
+        })
+    }
+
+    /// Attaches a note to the item.
+    ///
+    /// The note is sanitized like the name; a note that is empty afterwards
+    /// is ignored.
+    ///
+    /// # Errors
+    /// Returns `DomainError::InvalidItemName` if name and note together
+    /// exceed the maximum length.
+    pub fn with_note(mut self, note: &str) -> Result<Self, DomainError> {
+        let note = Self::sanitize(note);
+        if note.is_empty() {
+            return Ok(self);
+        }
+
+        if grapheme_count(&Self::labeled(&self.name, Some(&note))) > MAX_ITEM_NAME_LENGTH {
+            return Err(DomainError::InvalidItemName(format!(
+                "Item name with note exceeds maximum length of {} characters",
+                MAX_ITEM_NAME_LENGTH
+            )));
+        }
+
+        self.note = Some(note);
+        Ok(self)
+    }
+
+    /// Sets how many of the item are needed; a count of one or none clears
+    /// it.
+    ///
+    /// # Errors
+    /// Returns `DomainError::InvalidItemName` if the label with the count
+    /// exceeds the maximum length.
+    pub fn with_quantity(mut self, quantity: u32) -> Result<Self, DomainError> {
+        self.quantity = (quantity > 1).then_some(quantity);
+        if grapheme_count(&self.label()) > MAX_ITEM_NAME_LENGTH {
+            return Err(DomainError::InvalidItemName(format!(
+                "Item name with quantity exceeds maximum length of {} characters",
+                MAX_ITEM_NAME_LENGTH
+            )));
+        }
+        Ok(self)
+    }
+
+    /// Formats a name and an optional note the way they are written to the
+    /// list: "Milch (fettarm)", or just the name without a note.
+    pub fn labeled(name: &str, note: Option<&str>) -> String {
+        match note {
+            Some(note) => format!("{} ({})", name, note),
+            None => name.to_string(),
+        }
+    }
+
+    /// Removes what speech recognition or a client may smuggle into a name
+    /// but should never reach the list: control characters become spaces,
+    /// invisible formatting characters (zero-width spaces, byte order
+    /// marks, direction overrides, soft hyphens) are dropped, and runs of
+    /// whitespace collapse to a single space.
+    ///
+    /// Zero-width joiners stay, as they hold emoji sequences together.
+    pub fn sanitize(name: &str) -> String {
+        name.chars()
+            .filter(|c| !is_invisible(*c))
+            .map(|c| if c.is_control() { ' ' } else { c })
+            .collect::<String>()
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Sets the category the item should be sorted into.
+    pub fn with_category(mut self, category: Category) -> Self {
+        self.category = Some(category);
+        self
+    }
+
+    /// Returns the item name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the note, if one was attached.
+    pub fn note(&self) -> Option<&str> {
+        self.note.as_deref()
+    }
+
+    /// Returns the count, if more than one is needed.
+    pub fn quantity(&self) -> Option<u32> {
+        self.quantity
+    }
+
+    /// Returns the text written to the list: the name with the note in
+    /// parentheses, if there is one, after the count, if there is one.
+    pub fn label(&self) -> String {
+        let label = Self::labeled(&self.name, self.note());
+        match self.quantity {
+            Some(quantity) => format!("{}x {}", quantity, label),
+            None => label,
+        }
+    }
+
+    /// Returns the category, if the item was classified.
+    pub fn category(&self) -> Option<Category> {
+        self.category
+    }
+}
+
+/// Counts user-perceived characters (extended grapheme clusters), so a
+/// letter with combining accents, an emoji with a skin tone or a joined
+/// emoji sequence counts once.
+fn grapheme_count(name: &str) -> usize {
+    name.graphemes(true).count()
+}
+
+/// Returns true for formatting characters that are invisible in a name.
+fn is_invisible(c: char) -> bool {
+    matches!(
+        c,
+        '\u{00ad}' // soft hyphen
+        | '\u{200b}' // zero-width space
+        | '\u{200e}' | '\u{200f}' // direction marks
+        | '\u{202a}'..='\u{202e}' // direction embeddings and overrides
+        | '\u{2060}'..='\u{2064}' // word joiner and invisible operators
+        | '\u{2066}'..='\u{2069}' // direction isolates
+        | '\u{feff}' // byte order mark
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn creates_item_with_valid_name() {
+        let item = ShoppingListItem::new("Milk").unwrap();
+        assert_eq!(item.name(), "Milk");
+    }
+
+    #[test]
+    fn new_item_has_no_category() {
+        let item = ShoppingListItem::new("Milk").unwrap();
+        assert_eq!(item.category(), None);
+    }
+
+    #[test]
+    fn with_category_sets_category() {
+        let item = ShoppingListItem::new("Milch")
+            .unwrap()
+            .with_category(Category::Dairy);
+        assert_eq!(item.category(), Some(Category::Dairy));
+    }
+
+    #[test]
+    fn trims_whitespace_from_name() {
+        let item = ShoppingListItem::new("  Bread  ").unwrap();
+        assert_eq!(item.name(), "Bread");
+    }
+
+    #[test]
+    fn rejects_empty_name() {
+        let result = ShoppingListItem::new("");
+        assert!(matches!(result, Err(DomainError::InvalidItemName(_))));
+    }
+
+    #[test]
+    fn rejects_whitespace_only_name() {
+        let result = ShoppingListItem::new("   ");
+        assert!(matches!(result, Err(DomainError::InvalidItemName(_))));
+    }
+
+    #[test]
+    fn rejects_name_exceeding_max_length() {
+        let long_name = "a".repeat(201);
+        let result = ShoppingListItem::new(long_name);
+        assert!(matches!(result, Err(DomainError::InvalidItemName(_))));
+    }
+
+    #[test]
+    fn replaces_control_characters_with_spaces() {
+        let item = ShoppingListItem::new("Milch\nEier\t\u{0}Butter\u{1b}[31m").unwrap();
+        assert_eq!(item.name(), "Milch Eier Butter [31m");
+    }
+
+    #[test]
+    fn drops_invisible_characters() {
+        let item = ShoppingListItem::new("\u{feff}Ha\u{200b}fer\u{00ad}milch\u{202e}").unwrap();
+        assert_eq!(item.name(), "Hafermilch");
+    }
+
+    #[test]
+    fn collapses_inner_whitespace() {
+        let item = ShoppingListItem::new("Brot  mit \u{a0} Körnern").unwrap();
+        assert_eq!(item.name(), "Brot mit Körnern");
+    }
+
+    #[test]
+    fn keeps_emoji_joiners() {
+        let family = "👨\u{200d}👩\u{200d}👧";
+        assert_eq!(ShoppingListItem::new(family).unwrap().name(), family);
+    }
+
+    #[test]
+    fn rejects_name_of_only_invisible_characters() {
+        let result = ShoppingListItem::new("\u{200b}\u{0}\u{feff}");
+        assert!(matches!(result, Err(DomainError::InvalidItemName(_))));
+    }
+
+    #[test]
+    fn counts_characters_not_bytes() {
+        assert!(ShoppingListItem::new("ä".repeat(200)).is_ok());
+        assert!(ShoppingListItem::new("ä".repeat(201)).is_err());
+    }
+
+    #[test]
+    fn counts_combining_characters_once() {
+        // "ä" as "a" followed by a combining diaeresis
+        let decomposed = "a\u{0308}";
+        assert_eq!(grapheme_count(decomposed), 1);
+        assert!(ShoppingListItem::new(decomposed.repeat(200)).is_ok());
+        assert!(ShoppingListItem::new(decomposed.repeat(201)).is_err());
+    }
+
+    #[test]
+    fn counts_emoji_sequences_once() {
+        assert_eq!(grapheme_count("🥛"), 1);
+        assert_eq!(grapheme_count("👍🏽"), 1, "skin tone modifier");
+        assert_eq!(grapheme_count("❤\u{fe0f}"), 1, "variation selector");
+        assert_eq!(grapheme_count("👨\u{200d}👩\u{200d}👧"), 1, "joined family");
+        assert_eq!(grapheme_count("🇩🇪🇦🇹"), 2, "two flags");
+        assert_eq!(grapheme_count("Milch 🥛"), 7);
+        assert!(ShoppingListItem::new("👍🏽".repeat(200)).is_ok());
+    }
+
+    #[test]
+    fn leading_combining_mark_counts_as_character() {
+        assert_eq!(grapheme_count("\u{0308}a"), 2);
+    }
+
+    #[test]
+    fn accepts_long_german_compound_words() {
+        let compound = "Grundstücksverkehrsgenehmigungszuständigkeitsübertragungsverordnung";
+        assert!(ShoppingListItem::new(compound).is_ok());
+
+        // 199 characters, but more bytes than the limit due to the umlauts
+        let name = [
+            compound,
+            compound,
+            "Rindfleischetikettierungsüberwachungsaufgabenübertragungsgesetz",
+        ]
+        .join(" ");
+        assert!(
+            name.len() > MAX_ITEM_NAME_LENGTH,
+            "more bytes than the limit"
+        );
+        assert!(ShoppingListItem::new(name).is_ok());
+    }
+
+    #[test]
+    fn new_item_has_no_note() {
+        let item = ShoppingListItem::new("Milch").unwrap();
+        assert_eq!(item.note(), None);
+        assert_eq!(item.label(), "Milch");
+    }
+
+    #[test]
+    fn with_note_formats_label() {
+        let item = ShoppingListItem::new("Milch")
+            .unwrap()
+            .with_note("  fettarm ")
+            .unwrap();
+        assert_eq!(item.name(), "Milch");
+        assert_eq!(item.note(), Some("fettarm"));
+        assert_eq!(item.label(), "Milch (fettarm)");
+    }
+
+    #[test]
+    fn ignores_blank_note() {
+        let item = ShoppingListItem::new("Milch")
+            .unwrap()
+            .with_note("\u{200b} ")
+            .unwrap();
+        assert_eq!(item.note(), None);
+        assert_eq!(item.label(), "Milch");
+    }
+
+    #[test]
+    fn rejects_note_exceeding_max_length() {
+        let item = ShoppingListItem::new("a".repeat(190)).unwrap();
+        assert!(item.clone().with_note(&"b".repeat(7)).is_ok());
+        assert!(matches!(
+            item.with_note(&"b".repeat(8)),
+            Err(DomainError::InvalidItemName(_))
+        ));
+    }
+
+    #[test]
+    fn with_quantity_formats_label() {
+        let item = ShoppingListItem::new("Milch")
+            .unwrap()
+            .with_note("fettarm")
+            .unwrap()
+            .with_quantity(3)
+            .unwrap();
+        assert_eq!(item.quantity(), Some(3));
+        assert_eq!(item.label(), "3x Milch (fettarm)");
+
+        let single = item.with_quantity(1).unwrap();
+        assert_eq!(single.quantity(), None);
+        assert_eq!(single.label(), "Milch (fettarm)");
+    }
+
+    #[test]
+    fn rejects_quantity_exceeding_max_length() {
+        let item = ShoppingListItem::new("a".repeat(197)).unwrap();
+        assert!(item.clone().with_quantity(2).is_ok());
+        assert!(matches!(
+            item.with_quantity(10),
+            Err(DomainError::InvalidItemName(_))
+        ));
+    }
+
+    #[test]
+    fn accepts_name_at_max_length() {
+        let max_name = "a".repeat(200);
+        let item = ShoppingListItem::new(max_name.clone()).unwrap();
+        assert_eq!(item.name(), max_name);
+    }
+}
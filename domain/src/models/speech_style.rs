@@ -0,0 +1,45 @@
+/// How much the skill says in its answers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SpeechStyle {
+    /// Full sentences, e.g. "Alles klar. Milch wurde zur Einkaufsliste
+    /// hinzugefügt."
+    #[default]
+    Full,
+    /// Short confirmations and a trimmed welcome, e.g. "Okay, Milch."
+    Brief,
+}
+
+impl SpeechStyle {
+    /// Parses `full` or `brief` (case-insensitive).
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "full" => Some(Self::Full),
+            "brief" => Some(Self::Brief),
+            _ => None,
+        }
+    }
+
+    /// Returns the value understood by [`Self::parse`].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Full => "full",
+            Self::Brief => "brief",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_styles() {
+        assert_eq!(SpeechStyle::parse("Brief"), Some(SpeechStyle::Brief));
+        assert_eq!(SpeechStyle::parse(" full "), Some(SpeechStyle::Full));
+        assert_eq!(SpeechStyle::parse("kurz"), None);
+        assert_eq!(
+            SpeechStyle::parse(SpeechStyle::Brief.as_str()),
+            Some(SpeechStyle::Brief)
+        );
+    }
+}
@@ -0,0 +1,51 @@
+/// Result of moving a named list onto the shopping list.
+///
+/// Like [`super::AddItemOutcome`], it reports what happened; adapters word
+/// it for the user.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TransferOutcome {
+    transferred: Vec<String>,
+    failed: Vec<String>,
+    skipped: Vec<String>,
+}
+
+impl TransferOutcome {
+    /// Creates an outcome from the items added to the shopping list, the
+    /// items left on the named list and the pantry staples taken off it
+    /// without being added.
+    pub fn new(transferred: Vec<String>, failed: Vec<String>, skipped: Vec<String>) -> Self {
+        Self {
+            transferred,
+            failed,
+            skipped,
+        }
+    }
+
+    /// Returns the items now on the shopping list, taken off the named list.
+    pub fn transferred(&self) -> &[String] {
+        &self.transferred
+    }
+
+    /// Returns the items that stay on the named list, because the shopping
+    /// list rejected their names or failed before them.
+    pub fn failed(&self) -> &[String] {
+        &self.failed
+    }
+
+    /// Returns the pantry staples taken off the named list without being
+    /// added.
+    pub fn skipped(&self) -> &[String] {
+        &self.skipped
+    }
+
+    /// Returns true if the named list held no items.
+    pub fn is_empty(&self) -> bool {
+        self.transferred.is_empty() && self.failed.is_empty() && self.skipped.is_empty()
+    }
+
+    /// Returns true if items were to be transferred but none got through,
+    /// leaving the named list unchanged.
+    pub fn is_failure(&self) -> bool {
+        self.transferred.is_empty() && !self.failed.is_empty()
+    }
+}
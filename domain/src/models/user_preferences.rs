@@ -0,0 +1,183 @@
+use super::{AccountChoice, ItemBundles, ListName, PantryStaples, SpeechStyle};
+
+/// Settings a user has chosen for the skill.
+///
+/// Unset preferences fall back to the skill-wide configuration, so a user
+/// only overrides what they changed.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UserPreferences {
+    speech_style: Option<SpeechStyle>,
+    locale: Option<String>,
+    default_list: Option<ListName>,
+    account: Option<AccountChoice>,
+    pantry: Option<PantryStaples>,
+    bundles: ItemBundles,
+}
+
+impl UserPreferences {
+    /// Creates preferences with nothing set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the chosen speech style, if any.
+    pub fn speech_style(&self) -> Option<SpeechStyle> {
+        self.speech_style
+    }
+
+    /// Returns the locale overriding the device locale, e.g. "de-AT".
+    pub fn locale(&self) -> Option<&str> {
+        self.locale.as_deref()
+    }
+
+    /// Returns the occasion list used when no list is spoken; `None` means
+    /// the Cookidoo shopping list.
+    pub fn default_list(&self) -> Option<&ListName> {
+        self.default_list.as_ref()
+    }
+
+    /// Returns the household account chosen by voice, if any.
+    pub fn account(&self) -> Option<&AccountChoice> {
+        self.account.as_ref()
+    }
+
+    /// Returns the items the user always has at home, if set.
+    pub fn pantry(&self) -> Option<&PantryStaples> {
+        self.pantry.as_ref()
+    }
+
+    /// Returns the item bundles the user set up by voice.
+    pub fn bundles(&self) -> &ItemBundles {
+        &self.bundles
+    }
+
+    /// Sets or clears the speech style.
+    pub fn set_speech_style(&mut self, speech_style: Option<SpeechStyle>) {
+        self.speech_style = speech_style;
+    }
+
+    /// Sets or clears the locale override.
+    pub fn set_locale(&mut self, locale: Option<String>) {
+        self.locale = locale.filter(|locale| !locale.trim().is_empty());
+    }
+
+    /// Sets or clears the default list.
+    pub fn set_default_list(&mut self, default_list: Option<ListName>) {
+        self.default_list = default_list;
+    }
+
+    /// Sets or clears the chosen household account.
+    pub fn set_account(&mut self, account: Option<AccountChoice>) {
+        self.account = account;
+    }
+
+    /// Sets or clears the pantry staples; an empty pantry counts as unset.
+    pub fn set_pantry(&mut self, pantry: Option<PantryStaples>) {
+        self.pantry = pantry.filter(|pantry| !pantry.is_empty());
+    }
+
+    /// Replaces the item bundles.
+    pub fn set_bundles(&mut self, bundles: ItemBundles) {
+        self.bundles = bundles;
+    }
+
+    /// Returns these preferences with unset ones taken from `fallback`.
+    ///
+    /// Used to layer a recognized speaker's own settings over those of the
+    /// household account.
+    pub fn or(self, fallback: UserPreferences) -> Self {
+        Self {
+            speech_style: self.speech_style.or(fallback.speech_style),
+            locale: self.locale.or(fallback.locale),
+            default_list: self.default_list.or(fallback.default_list),
+            account: self.account.or(fallback.account),
+            pantry: self.pantry.or(fallback.pantry),
+            bundles: if self.bundles.is_empty() {
+                fallback.bundles
+            } else {
+                self.bundles
+            },
+        }
+    }
+
+    /// Applies a change requested by the user.
+    pub fn apply(&mut self, change: &SettingChange) {
+        match change {
+            SettingChange::SpeechStyle(style) => self.set_speech_style(Some(*style)),
+            SettingChange::DefaultList(list) => self.set_default_list(list.clone()),
+            SettingChange::Locale(locale) => self.set_locale(Some(locale.clone())),
+            SettingChange::Account(account) => self.set_account(Some(account.clone())),
+        }
+    }
+}
+
+/// A preference the user changes by voice.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SettingChange {
+    /// Answer briefly or in full sentences.
+    SpeechStyle(SpeechStyle),
+    /// Use the given occasion list when no list is spoken, or the shopping
+    /// list again if `None`.
+    DefaultList(Option<ListName>),
+    /// Use the given locale instead of the device's, e.g. "de-AT".
+    Locale(String),
+    /// Use the list of the chosen household account.
+    Account(AccountChoice),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn applies_changes() {
+        let mut preferences = UserPreferences::new();
+        let list = ListName::new("Geburtstagsliste").unwrap();
+
+        preferences.apply(&SettingChange::SpeechStyle(SpeechStyle::Brief));
+        preferences.apply(&SettingChange::DefaultList(Some(list.clone())));
+
+        assert_eq!(preferences.speech_style(), Some(SpeechStyle::Brief));
+        assert_eq!(preferences.default_list(), Some(&list));
+
+        preferences.apply(&SettingChange::DefaultList(None));
+        assert_eq!(preferences.default_list(), None);
+
+        preferences.apply(&SettingChange::Account(AccountChoice::Household));
+        assert_eq!(preferences.account(), Some(&AccountChoice::Household));
+
+        preferences.apply(&SettingChange::Locale("de-AT".to_string()));
+        assert_eq!(preferences.locale(), Some("de-AT"));
+    }
+
+    #[test]
+    fn layers_over_fallback() {
+        let mut household = UserPreferences::new();
+        household.set_speech_style(Some(SpeechStyle::Brief));
+        household.set_locale(Some("de-AT".to_string()));
+        let mut person = UserPreferences::new();
+        person.set_speech_style(Some(SpeechStyle::Full));
+
+        let preferences = person.or(household);
+
+        assert_eq!(preferences.speech_style(), Some(SpeechStyle::Full));
+        assert_eq!(preferences.locale(), Some("de-AT"));
+    }
+
+    #[test]
+    fn blank_locale_is_unset() {
+        let mut preferences = UserPreferences::new();
+        preferences.set_locale(Some(" ".to_string()));
+        assert_eq!(preferences.locale(), None);
+    }
+
+    #[test]
+    fn empty_pantry_is_unset() {
+        let mut preferences = UserPreferences::new();
+        preferences.set_pantry(Some(PantryStaples::parse(",")));
+        assert_eq!(preferences.pantry(), None);
+
+        preferences.set_pantry(Some(PantryStaples::parse("Salz")));
+        assert!(preferences.pantry().unwrap().contains("salz"));
+    }
+}
@@ -0,0 +1,85 @@
+use super::ParsedIntent;
+
+/// A voice request independent of the assistant platform it came from.
+///
+/// Carries the parsed intent plus the request metadata the domain services
+/// need: an ID for duplicate detection, the locale, and the user for
+/// per-user data such as occasion lists.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VoiceRequest {
+    request_id: String,
+    locale: String,
+    user_id: Option<String>,
+    intent: ParsedIntent,
+}
+
+impl VoiceRequest {
+    /// Creates a request without a known user.
+    pub fn new(
+        request_id: impl Into<String>,
+        locale: impl Into<String>,
+        intent: ParsedIntent,
+    ) -> Self {
+        Self {
+            request_id: request_id.into(),
+            locale: locale.into(),
+            user_id: None,
+            intent,
+        }
+    }
+
+    /// Sets the platform user the request belongs to.
+    pub fn with_user_id(mut self, user_id: impl Into<String>) -> Self {
+        self.user_id = Some(user_id.into());
+        self
+    }
+
+    /// Returns the platform-specific request ID.
+    pub fn request_id(&self) -> &str {
+        &self.request_id
+    }
+
+    /// Returns the locale, e.g. "de-DE".
+    pub fn locale(&self) -> &str {
+        &self.locale
+    }
+
+    /// Returns the platform user ID, if known.
+    pub fn user_id(&self) -> Option<&str> {
+        self.user_id.as_deref()
+    }
+
+    /// Returns the parsed intent.
+    pub fn intent(&self) -> &ParsedIntent {
+        &self.intent
+    }
+
+    /// Consumes the request, returning the parsed intent.
+    pub fn into_intent(self) -> ParsedIntent {
+        self.intent
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn creates_request_without_user() {
+        let request = VoiceRequest::new("req-1", "de-DE", ParsedIntent::Help);
+
+        assert_eq!(request.request_id(), "req-1");
+        assert_eq!(request.locale(), "de-DE");
+        assert_eq!(request.user_id(), None);
+        assert_eq!(request.intent(), &ParsedIntent::Help);
+    }
+
+    #[test]
+    fn sets_user_id() {
+        let request =
+            VoiceRequest::new("req-1", "de-DE", ParsedIntent::Launch).with_user_id("user-1");
+
+        assert_eq!(request.user_id(), Some("user-1"));
+        assert_eq!(request.into_intent(), ParsedIntent::Launch);
+    }
+}
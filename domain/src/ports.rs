@@ -0,0 +1,29 @@
+mod authentication_service;
+mod credential_store;
+mod customer_profile;
+mod device_address_lookup;
+mod history_repository;
+mod idempotency_store;
+mod item_dictionary;
+mod maintenance_flag;
+mod occasion_list_store;
+mod permission_store;
+mod preferences_repository;
+mod refresh_token_store;
+mod reminder_scheduler;
+mod shopping_list_repository;
+
+pub use authentication_service::AuthenticationService;
+pub use credential_store::CredentialStore;
+pub use customer_profile::CustomerProfile;
+pub use device_address_lookup::DeviceAddressLookup;
+pub use history_repository::HistoryRepository;
+pub use idempotency_store::{IdempotencyStore, CLAIM_LEASE};
+pub use item_dictionary::ItemDictionary;
+pub use maintenance_flag::MaintenanceFlag;
+pub use occasion_list_store::OccasionListStore;
+pub use permission_store::PermissionStore;
+pub use preferences_repository::PreferencesRepository;
+pub use refresh_token_store::RefreshTokenStore;
+pub use reminder_scheduler::ReminderScheduler;
+pub use shopping_list_repository::ShoppingListRepository;
@@ -1,6 +1,6 @@
 use async_trait::async_trait;
 
-use crate::domain::models::{AuthToken, CookidooCredentials, DomainError};
+use crate::models::{AuthToken, CookidooCredentials, DomainError};
 
 /// Port for authentication operations.
 ///
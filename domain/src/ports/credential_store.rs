@@ -0,0 +1,18 @@
+use async_trait::async_trait;
+
+use crate::models::{DomainError, StoredCredentials};
+
+/// Port for the Cookidoo credentials kept outside the deployment.
+///
+/// Rotating the password then only means updating the store; the skill
+/// reads it again when Cookidoo rejects the credentials it has.
+#[async_trait]
+pub trait CredentialStore: Send + Sync {
+    /// Returns the current credentials with the version they are stored
+    /// under.
+    ///
+    /// # Errors
+    /// Returns `DomainError::RepositoryError` if the store cannot be read
+    /// or holds no usable credentials.
+    async fn current(&self) -> Result<StoredCredentials, DomainError>;
+}
@@ -0,0 +1,33 @@
+use async_trait::async_trait;
+
+use crate::models::DomainError;
+
+/// Port for reading the user's profile from the voice platform.
+///
+/// Profiles are read on behalf of the user, so every call carries the API
+/// endpoint and an access token of the current request.
+#[async_trait]
+pub trait CustomerProfile: Send + Sync {
+    /// Returns the given name of the account holder, or `None` if unset.
+    ///
+    /// # Errors
+    /// Returns `DomainError::PermissionRequired` if the user has not granted
+    /// access to the name, `DomainError::RepositoryError` otherwise.
+    async fn account_given_name(
+        &self,
+        api_endpoint: &str,
+        access_token: &str,
+    ) -> Result<Option<String>, DomainError>;
+
+    /// Returns the given name of the speaker Alexa recognized for the
+    /// request the access token belongs to, or `None` if unset.
+    ///
+    /// # Errors
+    /// Returns `DomainError::PermissionRequired` if the speaker has not
+    /// granted access to the name, `DomainError::RepositoryError` otherwise.
+    async fn person_given_name(
+        &self,
+        api_endpoint: &str,
+        access_token: &str,
+    ) -> Result<Option<String>, DomainError>;
+}
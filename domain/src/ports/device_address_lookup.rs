@@ -0,0 +1,23 @@
+use async_trait::async_trait;
+
+use crate::models::DomainError;
+
+/// Port for looking up where the user's device is located.
+///
+/// Like reminders, the address is read on behalf of the user, so every call
+/// carries the API endpoint and the access token of the current request.
+#[async_trait]
+pub trait DeviceAddressLookup: Send + Sync {
+    /// Returns the ISO 3166 country code of the device's address, or `None`
+    /// if the user has not set an address.
+    ///
+    /// # Errors
+    /// Returns `DomainError::PermissionRequired` if the user has not granted
+    /// access to the address, `DomainError::RepositoryError` otherwise.
+    async fn country_code(
+        &self,
+        api_endpoint: &str,
+        access_token: &str,
+        device_id: &str,
+    ) -> Result<Option<String>, DomainError>;
+}
@@ -0,0 +1,30 @@
+use async_trait::async_trait;
+
+use crate::models::{DomainError, HistoryEntry};
+
+/// Port for the per-user history of added items, keyed by Alexa user ID.
+#[async_trait]
+pub trait HistoryRepository: Send + Sync {
+    /// Records an added item.
+    ///
+    /// # Errors
+    /// Returns `DomainError::RepositoryError` if the store cannot be written.
+    async fn record(&self, user_id: &str, entry: &HistoryEntry) -> Result<(), DomainError>;
+
+    /// Returns the user's entries added at or after `since` (an ISO 8601
+    /// timestamp or date), oldest first.
+    ///
+    /// # Errors
+    /// Returns `DomainError::RepositoryError` if the store cannot be read.
+    async fn entries_since(
+        &self,
+        user_id: &str,
+        since: &str,
+    ) -> Result<Vec<HistoryEntry>, DomainError>;
+
+    /// Removes all of a user's entries; unknown users are not an error.
+    ///
+    /// # Errors
+    /// Returns `DomainError::RepositoryError` if the store cannot be written.
+    async fn delete(&self, user_id: &str) -> Result<(), DomainError>;
+}
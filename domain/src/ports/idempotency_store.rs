@@ -0,0 +1,49 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+
+use crate::models::DomainError;
+
+/// How long a claim blocks re-deliveries before it may be taken again.
+///
+/// Covers the Lambda timeout (30 s) with a margin: an invocation that timed
+/// out or panicked never settles its claim, and Alexa's retry must still be
+/// able to add the item once the lease has run out.
+pub const CLAIM_LEASE: Duration = Duration::from_secs(60);
+
+/// Port for remembering already processed requests.
+///
+/// Alexa occasionally re-delivers a request; storing the outcome under the
+/// request's unique key lets a retried invocation answer without repeating
+/// its side effects. A key is claimed atomically before the side effect
+/// runs, so two concurrent deliveries of the same request cannot both act.
+#[async_trait]
+pub trait IdempotencyStore: Send + Sync {
+    /// Returns the recorded outcome if the key was already processed.
+    ///
+    /// # Errors
+    /// Returns `DomainError::RepositoryError` if the store cannot be read.
+    async fn get(&self, key: &str) -> Result<Option<String>, DomainError>;
+
+    /// Claims a key for processing.
+    ///
+    /// Returns `false` if the key is already recorded, or claimed less than
+    /// [`CLAIM_LEASE`] ago. An unsettled claim older than the lease is taken
+    /// over. Checking and claiming is a single atomic step of the store.
+    ///
+    /// # Errors
+    /// Returns `DomainError::RepositoryError` if the store cannot be written.
+    async fn claim(&self, key: &str) -> Result<bool, DomainError>;
+
+    /// Releases a claimed key whose processing failed, so a retry runs again.
+    ///
+    /// # Errors
+    /// Returns `DomainError::RepositoryError` if the store cannot be written.
+    async fn release(&self, key: &str) -> Result<(), DomainError>;
+
+    /// Records the outcome of a processed key, replacing its claim.
+    ///
+    /// # Errors
+    /// Returns `DomainError::RepositoryError` if the store cannot be written.
+    async fn put(&self, key: &str, outcome: &str) -> Result<(), DomainError>;
+}
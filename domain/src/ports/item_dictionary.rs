@@ -0,0 +1,15 @@
+/// Port for mapping spoken item names to the names kept on the list.
+///
+/// Regional words ("Paradeiser") and brand names ("Tempo") are stored under
+/// one canonical name, so the same item is not added twice under different
+/// names and can be removed by any of them.
+pub trait ItemDictionary: Send + Sync {
+    /// Returns the canonical name for an alias, or `None` if the name is
+    /// not an alias.
+    fn canonical(&self, item_name: &str) -> Option<&str>;
+
+    /// Returns the canonical name for an alias, or the name unchanged.
+    fn resolve(&self, item_name: &str) -> String {
+        self.canonical(item_name).unwrap_or(item_name).to_string()
+    }
+}
@@ -0,0 +1,16 @@
+use async_trait::async_trait;
+
+use crate::models::DomainError;
+
+/// Port for the "skill is being updated" switch.
+///
+/// While the flag is active, requests are answered with a short maintenance
+/// notice instead of touching Cookidoo.
+#[async_trait]
+pub trait MaintenanceFlag: Send + Sync {
+    /// Returns true while the skill is in maintenance.
+    ///
+    /// # Errors
+    /// Returns `DomainError::RepositoryError` if the flag cannot be read.
+    async fn is_active(&self) -> Result<bool, DomainError>;
+}
@@ -0,0 +1,50 @@
+use async_trait::async_trait;
+
+use crate::models::{DomainError, ListName, ShoppingListEntry, ShoppingListItem};
+
+/// Port for secondary lists (gifts, occasions) kept by the skill itself.
+///
+/// Cookidoo only offers a single shopping list in most markets, so extra
+/// lists such as "Geburtstagsliste" are stored per user outside Cookidoo.
+#[async_trait]
+pub trait OccasionListStore: Send + Sync {
+    /// Appends an item to the user's list, creating the list if needed.
+    ///
+    /// # Errors
+    /// Returns `DomainError::RepositoryError` if the store cannot be written.
+    async fn add(
+        &self,
+        user_id: &str,
+        list: &ListName,
+        item: &ShoppingListItem,
+    ) -> Result<(), DomainError>;
+
+    /// Returns the items on the user's list, or an empty list if unknown.
+    ///
+    /// # Errors
+    /// Returns `DomainError::RepositoryError` if the store cannot be read.
+    async fn items(
+        &self,
+        user_id: &str,
+        list: &ListName,
+    ) -> Result<Vec<ShoppingListEntry>, DomainError>;
+
+    /// Removes the first item matching `item_name` from the user's list.
+    ///
+    /// Returns `false` if no such item was on the list.
+    ///
+    /// # Errors
+    /// Returns `DomainError::RepositoryError` if the store cannot be written.
+    async fn remove(
+        &self,
+        user_id: &str,
+        list: &ListName,
+        item_name: &str,
+    ) -> Result<bool, DomainError>;
+
+    /// Removes all of the user's lists; unknown users are not an error.
+    ///
+    /// # Errors
+    /// Returns `DomainError::RepositoryError` if the store cannot be written.
+    async fn delete(&self, user_id: &str) -> Result<(), DomainError>;
+}
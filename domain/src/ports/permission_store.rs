@@ -0,0 +1,29 @@
+use async_trait::async_trait;
+
+use crate::models::{DomainError, SkillPermissions};
+
+/// Port for persisting the permissions a user has granted to the skill.
+///
+/// Implementations store the state reported by Alexa lifecycle events so
+/// features can check availability without probing Alexa APIs.
+#[async_trait]
+pub trait PermissionStore: Send + Sync {
+    /// Returns the stored permissions for a user, or an empty set if unknown.
+    ///
+    /// # Errors
+    /// Returns `DomainError::RepositoryError` if the store cannot be read.
+    async fn get(&self, user_id: &str) -> Result<SkillPermissions, DomainError>;
+
+    /// Stores the permissions for a user, replacing any previous state.
+    ///
+    /// # Errors
+    /// Returns `DomainError::RepositoryError` if the store cannot be written.
+    async fn save(&self, user_id: &str, permissions: &SkillPermissions) -> Result<(), DomainError>;
+
+    /// Removes the stored permissions for a user; unknown users are not an
+    /// error.
+    ///
+    /// # Errors
+    /// Returns `DomainError::RepositoryError` if the store cannot be written.
+    async fn delete(&self, user_id: &str) -> Result<(), DomainError>;
+}
@@ -0,0 +1,34 @@
+use async_trait::async_trait;
+
+use crate::models::{DomainError, UserPreferences};
+
+/// Port for persisting per-user preferences, keyed by the Alexa user ID.
+#[async_trait]
+pub trait PreferencesRepository: Send + Sync {
+    /// Returns the stored preferences for a user, or defaults if unknown.
+    ///
+    /// # Errors
+    /// Returns `DomainError::RepositoryError` if the store cannot be read.
+    async fn get(&self, user_id: &str) -> Result<UserPreferences, DomainError>;
+
+    /// Stores the preferences for a user, replacing any previous ones.
+    ///
+    /// # Errors
+    /// Returns `DomainError::RepositoryError` if the store cannot be written.
+    async fn save(&self, user_id: &str, preferences: &UserPreferences) -> Result<(), DomainError>;
+
+    /// Removes the stored preferences for a user; unknown users are not an
+    /// error.
+    ///
+    /// # Errors
+    /// Returns `DomainError::RepositoryError` if the store cannot be written.
+    async fn delete(&self, user_id: &str) -> Result<(), DomainError>;
+
+    /// Removes the stored preferences for a user together with those of
+    /// each recognized speaker, which are keyed `{user_id}#{person_id}`.
+    ///
+    /// # Errors
+    /// Returns `DomainError::RepositoryError` if the store cannot be read or
+    /// written.
+    async fn delete_all(&self, user_id: &str) -> Result<(), DomainError>;
+}
@@ -0,0 +1,23 @@
+use async_trait::async_trait;
+
+use crate::models::DomainError;
+
+/// Port for the Cookidoo refresh token kept between cold starts.
+///
+/// Cookidoo may hand out a new refresh token on every refresh and revoke
+/// the old one; the new token is written back here so the next cold start
+/// does not redeem a revoked one.
+#[async_trait]
+pub trait RefreshTokenStore: Send + Sync {
+    /// Returns the stored refresh token, or `None` if none was stored yet.
+    ///
+    /// # Errors
+    /// Returns `DomainError::RepositoryError` if the store cannot be read.
+    async fn load(&self) -> Result<Option<String>, DomainError>;
+
+    /// Replaces the stored refresh token.
+    ///
+    /// # Errors
+    /// Returns `DomainError::RepositoryError` if the token cannot be stored.
+    async fn save(&self, refresh_token: &str) -> Result<(), DomainError>;
+}
@@ -0,0 +1,22 @@
+use async_trait::async_trait;
+
+use crate::models::{DomainError, Reminder};
+
+/// Port for scheduling reminders on the user's devices.
+///
+/// Reminders are created on behalf of the user, so every call carries the
+/// API endpoint and the short-lived access token of the current request.
+#[async_trait]
+pub trait ReminderScheduler: Send + Sync {
+    /// Schedules a reminder.
+    ///
+    /// # Errors
+    /// Returns `DomainError::PermissionRequired` if the user has not granted
+    /// the reminders permission, `DomainError::RepositoryError` otherwise.
+    async fn schedule(
+        &self,
+        api_endpoint: &str,
+        access_token: &str,
+        reminder: &Reminder,
+    ) -> Result<(), DomainError>;
+}
@@ -0,0 +1,72 @@
+use async_trait::async_trait;
+
+use crate::models::{AddedItem, DomainError, ShoppingListEntry, ShoppingListItem};
+
+/// Port for shopping list operations.
+///
+/// Implementations of this trait handle the actual persistence
+/// or API calls to manage shopping list items.
+#[async_trait]
+pub trait ShoppingListRepository: Send + Sync {
+    /// Adds an item to the shopping list.
+    ///
+    /// Returns the items created by the backend with their IDs. The list may
+    /// be empty if the backend does not report what it created.
+    ///
+    /// # Errors
+    /// Returns `DomainError::RepositoryError` if the operation fails.
+    async fn add_item(&self, item: &ShoppingListItem) -> Result<Vec<AddedItem>, DomainError>;
+
+    /// Adds several items at once, e.g. a bundle such as "Frühstücks-Set".
+    ///
+    /// Adds them one by one by default; backends that accept several items
+    /// per request send fewer requests. Returns one result per item, in the
+    /// order given, so items added before a failure are reported as added.
+    /// After a failure, the remaining items are not tried and report the
+    /// same error.
+    async fn add_items(&self, items: &[ShoppingListItem]) -> Vec<Result<(), DomainError>> {
+        let mut results = Vec::with_capacity(items.len());
+        for item in items {
+            match self.add_item(item).await {
+                Ok(_) => results.push(Ok(())),
+                Err(e) => {
+                    results.resize(items.len(), Err(e));
+                    break;
+                }
+            }
+        }
+        results
+    }
+
+    /// Returns all items currently on the shopping list.
+    ///
+    /// # Errors
+    /// Returns `DomainError::RepositoryError` if the list cannot be read.
+    async fn list_items(&self) -> Result<Vec<ShoppingListEntry>, DomainError>;
+
+    /// Removes the item with the given backend ID from the shopping list.
+    ///
+    /// # Errors
+    /// Returns `DomainError::RepositoryError` if the operation fails.
+    async fn remove_item(&self, id: &str) -> Result<(), DomainError>;
+
+    /// Marks the item with the given backend ID as bought, keeping it on
+    /// the list as checked off.
+    ///
+    /// Backends without checked-off items remove it by default.
+    ///
+    /// # Errors
+    /// Returns `DomainError::RepositoryError` if the operation fails.
+    async fn mark_owned(&self, id: &str) -> Result<(), DomainError> {
+        self.remove_item(id).await
+    }
+
+    /// Prepares the backend for the next requests, e.g. by refreshing
+    /// credentials and opening connections. Does nothing by default.
+    ///
+    /// # Errors
+    /// Returns an error if the backend cannot be reached or authenticated.
+    async fn warm_up(&self) -> Result<(), DomainError> {
+        Ok(())
+    }
+}
@@ -0,0 +1,41 @@
+mod add_item_service;
+mod alias_dictionary;
+mod bundle_service;
+mod category_classifier;
+mod german_singularizer;
+mod history_service;
+mod item_extractor;
+mod item_name_normalizer;
+mod item_quantity;
+mod list_summary_service;
+mod list_transfer_service;
+mod market_service;
+mod occasion_list_service;
+mod preferences_service;
+mod profile_service;
+mod read_list_service;
+mod reminder_service;
+mod remove_item_service;
+mod skill_event_service;
+mod user_data_service;
+
+pub use add_item_service::AddItemService;
+pub use alias_dictionary::{AliasDictionary, DEFAULT_ALIASES};
+pub use bundle_service::BundleService;
+pub use category_classifier::CategoryClassifier;
+pub use german_singularizer::{GermanSingularizer, DEFAULT_PLURAL_EXCEPTIONS};
+pub use history_service::HistoryService;
+pub use item_extractor::ItemExtractor;
+pub use item_name_normalizer::ItemNameNormalizer;
+pub use item_quantity::ItemQuantity;
+pub use list_summary_service::ListSummaryService;
+pub use list_transfer_service::ListTransferService;
+pub use market_service::MarketService;
+pub use occasion_list_service::OccasionListService;
+pub use preferences_service::PreferencesService;
+pub use profile_service::ProfileService;
+pub use read_list_service::ReadListService;
+pub use reminder_service::{ReminderError, ReminderService, DEFAULT_REMINDER_OFFSET};
+pub use remove_item_service::RemoveItemService;
+pub use skill_event_service::SkillEventService;
+pub use user_data_service::UserDataService;
@@ -0,0 +1,958 @@
+use std::sync::Arc;
+
+use tracing::{debug, error, info, warn};
+
+use crate::models::{AddItemOutcome, AddedItem, DomainError, QuantityStyle, ShoppingListItem};
+use crate::ports::{IdempotencyStore, ItemDictionary, ShoppingListRepository};
+
+use super::{AliasDictionary, CategoryClassifier, ItemQuantity};
+
+/// Largest count added as separate entries with [`QuantityStyle::Repeat`];
+/// larger counts are written as a label, sparing the list a flood of
+/// entries and Cookidoo the requests.
+const MAX_REPEATED: u32 = 10;
+
+/// Service for adding items to the shopping list.
+///
+/// This is the core use case that orchestrates the validation
+/// and persistence of shopping list items.
+pub struct AddItemService {
+    repository: Arc<dyn ShoppingListRepository>,
+    idempotency_store: Option<Arc<dyn IdempotencyStore>>,
+    classifier: Arc<CategoryClassifier>,
+    dictionary: Arc<dyn ItemDictionary>,
+    quantity_style: QuantityStyle,
+}
+
+impl AddItemService {
+    /// Creates a new AddItemService with the given repository.
+    ///
+    /// Items are categorized with the built-in keywords unless another
+    /// classifier is set with [`Self::with_classifier`]. Aliases are resolved
+    /// with the built-in dictionary unless another is set with
+    /// [`Self::with_dictionary`].
+    pub fn new(repository: Arc<dyn ShoppingListRepository>) -> Self {
+        Self {
+            repository,
+            idempotency_store: None,
+            classifier: Arc::new(CategoryClassifier::new()),
+            dictionary: Arc::new(AliasDictionary::new()),
+            quantity_style: QuantityStyle::default(),
+        }
+    }
+
+    /// Sets the classifier that picks the category of added items.
+    pub fn with_classifier(mut self, classifier: Arc<CategoryClassifier>) -> Self {
+        self.classifier = classifier;
+        self
+    }
+
+    /// Sets the dictionary that maps aliases to the name added to the list.
+    pub fn with_dictionary(mut self, dictionary: Arc<dyn ItemDictionary>) -> Self {
+        self.dictionary = dictionary;
+        self
+    }
+
+    /// Sets how a spoken count ("drei Zitronen") is written to the list.
+    pub fn with_quantity_style(mut self, quantity_style: QuantityStyle) -> Self {
+        self.quantity_style = quantity_style;
+        self
+    }
+
+    /// Enables duplicate detection for [`Self::execute_once`].
+    pub fn with_idempotency_store(mut self, store: Arc<dyn IdempotencyStore>) -> Self {
+        self.idempotency_store = Some(store);
+        self
+    }
+
+    /// Adds an item unless the request with the given key was already handled.
+    ///
+    /// The key is claimed before the add, so a re-delivered request returns
+    /// [`AddItemOutcome::Duplicate`] without adding the item a second time,
+    /// even while the first delivery is still in flight. A failed add
+    /// releases the claim, so a retry is attempted again, unless some pieces
+    /// of a counted item were added before the failure. A claim that is never
+    /// settled, because the delivery timed out or panicked, is taken over by
+    /// a retry once [`CLAIM_LEASE`](crate::ports::CLAIM_LEASE) has passed. Store failures are logged and
+    /// never block the add.
+    pub async fn execute_once(
+        &self,
+        idempotency_key: &str,
+        item_name: &str,
+        note: Option<&str>,
+    ) -> AddItemOutcome {
+        let Some(store) = &self.idempotency_store else {
+            return self.execute_with_note(item_name, note).await;
+        };
+
+        let claimed = match claim(store.as_ref(), idempotency_key, item_name).await {
+            Ok(claimed) => claimed,
+            Err(duplicate) => return duplicate,
+        };
+        let (outcome, added) = self.add(item_name, note).await;
+        settle(store.as_ref(), idempotency_key, claimed, added.as_deref()).await;
+        outcome
+    }
+
+    /// Adds an item to the shopping list.
+    ///
+    /// # Arguments
+    /// * `item_name` - The raw item name from user input
+    ///
+    /// # Returns
+    /// The outcome of the add; failures are reported as outcomes, not errors.
+    pub async fn execute(&self, item_name: &str) -> AddItemOutcome {
+        self.execute_with_note(item_name, None).await
+    }
+
+    /// Adds an item with an optional note, e.g. "fettarm" for "Milch".
+    ///
+    /// The alias and category are resolved from the name alone; the note is
+    /// written to the list next to it (see [`ShoppingListItem::label`]). A
+    /// count in front of the name ("drei Zitronen", see [`ItemQuantity`]) is
+    /// written as "3x Zitronen", or the entry is added once per piece with
+    /// [`QuantityStyle::Repeat`]; a failure part way leaves the pieces
+    /// already added on the list.
+    pub async fn execute_with_note(&self, item_name: &str, note: Option<&str>) -> AddItemOutcome {
+        self.add(item_name, note).await.0
+    }
+
+    /// Adds an item like [`Self::execute_with_note`].
+    ///
+    /// Also returns the label of what is on the list afterwards, which
+    /// after a failure part way is the pieces added before it.
+    async fn add(&self, item_name: &str, note: Option<&str>) -> (AddItemOutcome, Option<String>) {
+        let item = match self.prepare(item_name, note) {
+            Ok(item) => item,
+            Err(outcome) => return (outcome, None),
+        };
+
+        let added = match (self.quantity_style, item.quantity()) {
+            (QuantityStyle::Repeat, Some(quantity)) if quantity <= MAX_REPEATED => {
+                self.add_repeated(&item, quantity).await
+            }
+            _ => self.repository.add_item(&item).await.map_err(|e| (0, e)),
+        };
+        match added {
+            Ok(added) => {
+                let item_ids: Vec<&str> = added.iter().map(AddedItem::id).collect();
+                info!(item_name = %item.name(), note = ?item.note(), item_ids = ?item_ids, "Item added to shopping list");
+                let label = item.label();
+                (
+                    AddItemOutcome::Added {
+                        item: label.clone(),
+                    },
+                    Some(label),
+                )
+            }
+            Err((0, e)) => (failure(e), None),
+            Err((pieces, e)) => {
+                warn!(item_name = %item.name(), pieces = pieces, "Adding stopped part way");
+                let label = item
+                    .clone()
+                    .with_quantity(pieces)
+                    .map_or_else(|_| item.name().to_string(), |item| item.label());
+                (failure(e), Some(label))
+            }
+        }
+    }
+
+    /// Adds several items with one call to the repository, e.g. the items
+    /// of a bundle.
+    ///
+    /// Names are resolved like in [`Self::execute_with_note`], except that
+    /// a count is always written as a label. Invalid names are reported
+    /// and skipped; items the repository added before a failure are
+    /// reported as added, the others with the failure.
+    ///
+    /// # Returns
+    /// The outcome for each name, in the order given.
+    pub async fn execute_all(&self, item_names: &[String]) -> Vec<AddItemOutcome> {
+        let prepared: Vec<Result<ShoppingListItem, AddItemOutcome>> = item_names
+            .iter()
+            .map(|item_name| self.prepare(item_name, None))
+            .collect();
+        let items: Vec<ShoppingListItem> = prepared
+            .iter()
+            .filter_map(|item| item.as_ref().ok().cloned())
+            .collect();
+        if items.is_empty() {
+            return prepared.into_iter().map(|item| item.unwrap_err()).collect();
+        }
+
+        let mut results = self.repository.add_items(&items).await.into_iter();
+        let outcomes: Vec<AddItemOutcome> = prepared
+            .into_iter()
+            .map(|item| match item {
+                Ok(item) => match results.next() {
+                    Some(Ok(())) => AddItemOutcome::Added { item: item.label() },
+                    Some(Err(e)) => failure(e),
+                    None => {
+                        error!(item_name = %item.name(), "Repository reported no result for item");
+                        AddItemOutcome::Unavailable
+                    }
+                },
+                Err(outcome) => outcome,
+            })
+            .collect();
+        let added = outcomes
+            .iter()
+            .filter(|outcome| outcome.is_success())
+            .count();
+        info!(
+            count = items.len(),
+            added = added,
+            "Items added to shopping list"
+        );
+        outcomes
+    }
+
+    /// Adds several items like [`Self::execute_all`], unless the request
+    /// with the given key was already handled.
+    ///
+    /// Each item is claimed under `"{idempotency_key}#{index}"`, the key
+    /// [`Self::execute_once`] is called with for the items of a spoken
+    /// list, so a re-delivered request reports the items added by the first
+    /// delivery as [`AddItemOutcome::Duplicate`] and only adds the rest.
+    pub async fn execute_all_once(
+        &self,
+        idempotency_key: &str,
+        item_names: &[String],
+    ) -> Vec<AddItemOutcome> {
+        let Some(store) = &self.idempotency_store else {
+            return self.execute_all(item_names).await;
+        };
+
+        let keys: Vec<String> = (0..item_names.len())
+            .map(|index| format!("{}#{}", idempotency_key, index))
+            .collect();
+        let mut claims = Vec::with_capacity(item_names.len());
+        for (key, item_name) in keys.iter().zip(item_names) {
+            claims.push(claim(store.as_ref(), key, item_name).await);
+        }
+
+        let pending: Vec<String> = item_names
+            .iter()
+            .zip(&claims)
+            .filter(|(_, claim)| claim.is_ok())
+            .map(|(item_name, _)| item_name.clone())
+            .collect();
+        let mut outcomes = if pending.is_empty() {
+            Vec::new().into_iter()
+        } else {
+            self.execute_all(&pending).await.into_iter()
+        };
+
+        let mut results = Vec::with_capacity(item_names.len());
+        for (key, claim) in keys.iter().zip(claims) {
+            let outcome = match claim {
+                Ok(claimed) => {
+                    let outcome = outcomes.next().unwrap_or(AddItemOutcome::Unavailable);
+                    let added = match &outcome {
+                        AddItemOutcome::Added { item } => Some(item.as_str()),
+                        _ => None,
+                    };
+                    settle(store.as_ref(), key, claimed, added).await;
+                    outcome
+                }
+                Err(duplicate) => duplicate,
+            };
+            results.push(outcome);
+        }
+        results
+    }
+
+    /// Builds the item to add from a spoken name, resolving its count,
+    /// alias and category.
+    fn prepare(
+        &self,
+        item_name: &str,
+        note: Option<&str>,
+    ) -> Result<ShoppingListItem, AddItemOutcome> {
+        let (quantity, item_name) = ItemQuantity::split(item_name);
+        let item_name = match self.dictionary.canonical(item_name) {
+            Some(canonical) => {
+                debug!(alias = %item_name, item_name = %canonical, "Item alias resolved");
+                canonical.to_string()
+            }
+            None => item_name.to_string(),
+        };
+
+        let item = ShoppingListItem::new(&item_name)
+            .and_then(|item| item.with_note(note.unwrap_or_default()))
+            .and_then(|item| item.with_quantity(quantity.unwrap_or(1)));
+        let item = match item {
+            Ok(item) => item,
+            Err(DomainError::InvalidItemName(reason)) => {
+                error!(error = %reason, "Invalid item name provided");
+                return Err(AddItemOutcome::InvalidName { reason });
+            }
+            Err(e) => {
+                error!(error = %e, "Unexpected error creating item");
+                return Err(AddItemOutcome::Unavailable);
+            }
+        };
+
+        Ok(match self.classifier.classify(item.name()) {
+            Some(category) => {
+                debug!(item_name = %item.name(), category = %category, "Item categorized");
+                item.with_category(category)
+            }
+            None => item,
+        })
+    }
+
+    /// Adds `item` without its count, `quantity` times.
+    ///
+    /// A failure comes with the number of pieces added before it.
+    async fn add_repeated(
+        &self,
+        item: &ShoppingListItem,
+        quantity: u32,
+    ) -> Result<Vec<AddedItem>, (u32, DomainError)> {
+        let piece = item.clone().with_quantity(1).map_err(|e| (0, e))?;
+        let mut added = Vec::new();
+        for pieces in 0..quantity {
+            match self.repository.add_item(&piece).await {
+                Ok(items) => added.extend(items),
+                Err(e) => return Err((pieces, e)),
+            }
+        }
+        Ok(added)
+    }
+}
+
+/// Claims `idempotency_key` before adding `item_name`.
+///
+/// Returns whether the key was claimed; store failures are logged and the
+/// add goes ahead unclaimed. A key claimed before is reported as
+/// [`AddItemOutcome::Duplicate`] with the item recorded for it.
+async fn claim(
+    store: &dyn IdempotencyStore,
+    idempotency_key: &str,
+    item_name: &str,
+) -> Result<bool, AddItemOutcome> {
+    match store.claim(idempotency_key).await {
+        Ok(true) => Ok(true),
+        Ok(false) => {
+            info!(idempotency_key = %idempotency_key, "Duplicate request, skipping add");
+            let item = match store.get(idempotency_key).await {
+                Ok(Some(item)) => item,
+                // Still in flight on another invocation
+                _ => item_name.trim().to_string(),
+            };
+            Err(AddItemOutcome::Duplicate { item })
+        }
+        Err(e) => {
+            warn!(error = %e, "Idempotency claim failed, adding anyway");
+            Ok(false)
+        }
+    }
+}
+
+/// Records what was added under `idempotency_key`, or releases the claim
+/// when nothing was added so a retry is attempted again.
+async fn settle(
+    store: &dyn IdempotencyStore,
+    idempotency_key: &str,
+    claimed: bool,
+    added: Option<&str>,
+) {
+    match added {
+        Some(item) => {
+            if let Err(e) = store.put(idempotency_key, item).await {
+                warn!(error = %e, "Failed to record idempotency key");
+            }
+        }
+        _ if claimed => {
+            if let Err(e) = store.release(idempotency_key).await {
+                warn!(error = %e, "Failed to release idempotency key");
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Returns the outcome of an add the repository failed.
+fn failure(error: DomainError) -> AddItemOutcome {
+    match error {
+        DomainError::AuthenticationFailed(msg) => {
+            error!(error = %msg, "Authentication failed while adding item");
+            AddItemOutcome::AuthFailed
+        }
+        DomainError::RateLimited(msg) => {
+            error!(error = %msg, "Rate limited while adding item");
+            AddItemOutcome::RateLimited
+        }
+        DomainError::ListFull(msg) => {
+            error!(error = %msg, "Shopping list full while adding item");
+            AddItemOutcome::ListFull
+        }
+        DomainError::ServiceUnavailable(msg) => {
+            error!(error = %msg, "Service unavailable while adding item");
+            AddItemOutcome::Maintenance
+        }
+        e => {
+            error!(error = %e, "Repository error while adding item");
+            AddItemOutcome::Unavailable
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Category, ShoppingListEntry};
+    use async_trait::async_trait;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    struct MockRepository {
+        should_fail: AtomicBool,
+        fail_with_auth: AtomicBool,
+        fail_with: Option<fn() -> DomainError>,
+    }
+
+    impl MockRepository {
+        fn new() -> Self {
+            Self {
+                should_fail: AtomicBool::new(false),
+                fail_with_auth: AtomicBool::new(false),
+                fail_with: None,
+            }
+        }
+
+        fn failing() -> Self {
+            Self {
+                should_fail: AtomicBool::new(true),
+                fail_with_auth: AtomicBool::new(false),
+                fail_with: None,
+            }
+        }
+
+        fn failing_auth() -> Self {
+            Self {
+                should_fail: AtomicBool::new(true),
+                fail_with_auth: AtomicBool::new(true),
+                fail_with: None,
+            }
+        }
+
+        fn failing_with(error: fn() -> DomainError) -> Self {
+            Self {
+                should_fail: AtomicBool::new(true),
+                fail_with_auth: AtomicBool::new(false),
+                fail_with: Some(error),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl ShoppingListRepository for MockRepository {
+        async fn add_item(&self, _item: &ShoppingListItem) -> Result<Vec<AddedItem>, DomainError> {
+            if self.should_fail.load(Ordering::SeqCst) {
+                if let Some(error) = self.fail_with {
+                    Err(error())
+                } else if self.fail_with_auth.load(Ordering::SeqCst) {
+                    Err(DomainError::AuthenticationFailed(
+                        "Invalid token".to_string(),
+                    ))
+                } else {
+                    Err(DomainError::RepositoryError(
+                        "Connection failed".to_string(),
+                    ))
+                }
+            } else {
+                Ok(Vec::new())
+            }
+        }
+
+        async fn list_items(&self) -> Result<Vec<ShoppingListEntry>, DomainError> {
+            Ok(Vec::new())
+        }
+
+        async fn remove_item(&self, _id: &str) -> Result<(), DomainError> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn execute_adds_valid_item() {
+        let repo = Arc::new(MockRepository::new());
+        let service = AddItemService::new(repo);
+
+        let outcome = service.execute("Milk").await;
+
+        assert_eq!(
+            outcome,
+            AddItemOutcome::Added {
+                item: "Milk".to_string()
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn execute_returns_error_for_empty_item() {
+        let repo = Arc::new(MockRepository::new());
+        let service = AddItemService::new(repo);
+
+        let outcome = service.execute("").await;
+
+        assert!(matches!(outcome, AddItemOutcome::InvalidName { .. }));
+    }
+
+    #[tokio::test]
+    async fn execute_adds_canonical_name_for_alias() {
+        let repo = Arc::new(MockRepository::new());
+        let dictionary = AliasDictionary::empty()
+            .with_aliases([("Haferdrink".to_string(), "Hafermilch".to_string())]);
+        let service = AddItemService::new(repo).with_dictionary(Arc::new(dictionary));
+
+        let outcome = service.execute("haferdrink").await;
+
+        assert_eq!(
+            outcome,
+            AddItemOutcome::Added {
+                item: "Hafermilch".to_string()
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn execute_returns_error_on_repository_failure() {
+        let repo = Arc::new(MockRepository::failing());
+        let service = AddItemService::new(repo);
+
+        let outcome = service.execute("Milk").await;
+
+        assert_eq!(outcome, AddItemOutcome::Unavailable);
+    }
+
+    #[tokio::test]
+    async fn execute_returns_auth_error_message() {
+        let repo = Arc::new(MockRepository::failing_auth());
+        let service = AddItemService::new(repo);
+
+        let outcome = service.execute("Milk").await;
+
+        assert_eq!(outcome, AddItemOutcome::AuthFailed);
+    }
+
+    #[tokio::test]
+    async fn execute_returns_rate_limit_message() {
+        let repo = Arc::new(MockRepository::failing_with(|| {
+            DomainError::RateLimited("429".to_string())
+        }));
+        let service = AddItemService::new(repo);
+
+        let outcome = service.execute("Milk").await;
+
+        assert_eq!(outcome, AddItemOutcome::RateLimited);
+    }
+
+    #[tokio::test]
+    async fn execute_returns_list_full_message() {
+        let repo = Arc::new(MockRepository::failing_with(|| {
+            DomainError::ListFull("full".to_string())
+        }));
+        let service = AddItemService::new(repo);
+
+        let outcome = service.execute("Milk").await;
+
+        assert_eq!(outcome, AddItemOutcome::ListFull);
+    }
+
+    #[tokio::test]
+    async fn execute_returns_maintenance_message() {
+        let repo = Arc::new(MockRepository::failing_with(|| {
+            DomainError::ServiceUnavailable("maintenance".to_string())
+        }));
+        let service = AddItemService::new(repo);
+
+        let outcome = service.execute("Milk").await;
+
+        assert_eq!(outcome, AddItemOutcome::Maintenance);
+    }
+
+    #[tokio::test]
+    async fn execute_once_skips_duplicate_request() {
+        let repo = Arc::new(CountingRepository::default());
+        let store = Arc::new(MockIdempotencyStore::default());
+        let service = AddItemService::new(repo.clone()).with_idempotency_store(store);
+
+        let first = service.execute_once("req-1", "Milk", None).await;
+        let second = service.execute_once("req-1", "Milk", None).await;
+
+        assert!(matches!(first, AddItemOutcome::Added { .. }));
+        assert_eq!(
+            second,
+            AddItemOutcome::Duplicate {
+                item: "Milk".to_string()
+            }
+        );
+        assert_eq!(repo.calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn execute_once_adds_distinct_requests() {
+        let repo = Arc::new(CountingRepository::default());
+        let store = Arc::new(MockIdempotencyStore::default());
+        let service = AddItemService::new(repo.clone()).with_idempotency_store(store);
+
+        service.execute_once("req-1", "Milk", None).await;
+        service.execute_once("req-2", "Milk", None).await;
+
+        assert_eq!(repo.calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn execute_once_does_not_record_failures() {
+        let repo = Arc::new(MockRepository::failing());
+        let store = Arc::new(MockIdempotencyStore::default());
+        let service = AddItemService::new(repo).with_idempotency_store(store.clone());
+
+        let outcome = service.execute_once("req-1", "Milk", None).await;
+
+        assert!(!outcome.is_success());
+        assert!(store.outcomes.lock().unwrap().is_empty());
+        assert!(store.claims.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn execute_once_skips_request_in_flight() {
+        let repo = Arc::new(CountingRepository::default());
+        let store = Arc::new(MockIdempotencyStore::default());
+        let service = AddItemService::new(repo.clone()).with_idempotency_store(store.clone());
+        store.claim("req-1").await.unwrap();
+
+        let outcome = service.execute_once("req-1", "Milk", None).await;
+
+        assert_eq!(
+            outcome,
+            AddItemOutcome::Duplicate {
+                item: "Milk".to_string()
+            }
+        );
+        assert_eq!(repo.calls.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn execute_categorizes_item() {
+        let repo = Arc::new(CountingRepository::default());
+        let service = AddItemService::new(repo.clone());
+
+        service.execute("Vollmilch").await;
+
+        let items = repo.items.lock().unwrap();
+        assert_eq!(items[0].category(), Some(Category::Dairy));
+    }
+
+    #[tokio::test]
+    async fn execute_uses_configured_classifier() {
+        let repo = Arc::new(CountingRepository::default());
+        let classifier = CategoryClassifier::new()
+            .with_overrides([("Vollmilch".to_string(), Category::Beverages)]);
+        let service = AddItemService::new(repo.clone()).with_classifier(Arc::new(classifier));
+
+        service.execute("Vollmilch").await;
+
+        let items = repo.items.lock().unwrap();
+        assert_eq!(items[0].category(), Some(Category::Beverages));
+    }
+
+    #[tokio::test]
+    async fn execute_with_note_adds_labeled_item() {
+        let repo = Arc::new(CountingRepository::default());
+        let service = AddItemService::new(repo.clone());
+
+        let outcome = service.execute_with_note("Milch", Some("fettarm")).await;
+
+        assert_eq!(
+            outcome,
+            AddItemOutcome::Added {
+                item: "Milch (fettarm)".to_string()
+            }
+        );
+        let items = repo.items.lock().unwrap();
+        assert_eq!(items[0].note(), Some("fettarm"));
+        assert_eq!(items[0].category(), Some(Category::Dairy));
+    }
+
+    #[tokio::test]
+    async fn execute_labels_spoken_count() {
+        let repo = Arc::new(CountingRepository::default());
+        let service = AddItemService::new(repo.clone());
+
+        let outcome = service.execute("drei Zitronen").await;
+
+        assert_eq!(
+            outcome,
+            AddItemOutcome::Added {
+                item: "3x Zitronen".to_string()
+            }
+        );
+        let items = repo.items.lock().unwrap();
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].name(), "Zitronen");
+        assert_eq!(items[0].quantity(), Some(3));
+        assert_eq!(items[0].category(), Some(Category::FruitAndVegetables));
+    }
+
+    #[tokio::test]
+    async fn execute_repeats_counted_item() {
+        let repo = Arc::new(CountingRepository::default());
+        let service = AddItemService::new(repo.clone()).with_quantity_style(QuantityStyle::Repeat);
+
+        let outcome = service.execute("3 Zitronen").await;
+        service.execute("20 Zitronen").await;
+
+        assert_eq!(
+            outcome,
+            AddItemOutcome::Added {
+                item: "3x Zitronen".to_string()
+            }
+        );
+        let labels: Vec<String> = repo
+            .items
+            .lock()
+            .unwrap()
+            .iter()
+            .map(ShoppingListItem::label)
+            .collect();
+        assert_eq!(labels, ["Zitronen", "Zitronen", "Zitronen", "20x Zitronen"]);
+    }
+
+    #[tokio::test]
+    async fn execute_once_keeps_claim_after_partial_repeat() {
+        let repo = Arc::new(CountingRepository {
+            max_adds: Some(2),
+            ..CountingRepository::default()
+        });
+        let store = Arc::new(MockIdempotencyStore::default());
+        let service = AddItemService::new(repo.clone())
+            .with_quantity_style(QuantityStyle::Repeat)
+            .with_idempotency_store(store.clone());
+
+        let first = service.execute_once("req-1", "3 Zitronen", None).await;
+        let second = service.execute_once("req-1", "3 Zitronen", None).await;
+
+        assert_eq!(first, AddItemOutcome::Unavailable);
+        assert_eq!(
+            second,
+            AddItemOutcome::Duplicate {
+                item: "2x Zitronen".to_string()
+            }
+        );
+        assert_eq!(repo.items.lock().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn execute_all_adds_items_in_one_batch() {
+        let repo = Arc::new(CountingRepository::default());
+        let service = AddItemService::new(repo.clone());
+        let names = ["Brötchen", "", "2 Butter"].map(String::from);
+
+        let outcomes = service.execute_all(&names).await;
+
+        assert_eq!(
+            outcomes[0],
+            AddItemOutcome::Added {
+                item: "Brötchen".to_string()
+            }
+        );
+        assert!(matches!(outcomes[1], AddItemOutcome::InvalidName { .. }));
+        assert_eq!(
+            outcomes[2],
+            AddItemOutcome::Added {
+                item: "2x Butter".to_string()
+            }
+        );
+        assert_eq!(repo.batches.load(Ordering::SeqCst), 1);
+        assert_eq!(repo.calls.load(Ordering::SeqCst), 0);
+        assert_eq!(
+            repo.items.lock().unwrap()[1].category(),
+            Some(Category::Dairy)
+        );
+    }
+
+    #[tokio::test]
+    async fn execute_all_reports_failed_batch_for_every_item() {
+        let service = AddItemService::new(Arc::new(MockRepository::failing()));
+        let names = ["Brötchen", "Butter"].map(String::from);
+
+        let outcomes = service.execute_all(&names).await;
+
+        assert_eq!(
+            outcomes,
+            [AddItemOutcome::Unavailable, AddItemOutcome::Unavailable]
+        );
+    }
+
+    #[tokio::test]
+    async fn execute_all_reports_items_added_before_a_failure() {
+        let repo = Arc::new(CountingRepository {
+            fail_after: Some("Butter"),
+            ..CountingRepository::default()
+        });
+        let service = AddItemService::new(repo);
+        let names = ["Brötchen", "Butter"].map(String::from);
+
+        let outcomes = service.execute_all(&names).await;
+
+        assert_eq!(
+            outcomes,
+            [
+                AddItemOutcome::Added {
+                    item: "Brötchen".to_string()
+                },
+                AddItemOutcome::Unavailable
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn execute_all_once_skips_items_added_by_earlier_delivery() {
+        let repo = Arc::new(CountingRepository::default());
+        let store = Arc::new(MockIdempotencyStore::default());
+        let service = AddItemService::new(repo.clone()).with_idempotency_store(store.clone());
+        let names = ["Brötchen", "Butter"].map(String::from);
+
+        service.execute_all_once("req-1", &names).await;
+        let outcomes = service.execute_all_once("req-1", &names).await;
+
+        assert_eq!(
+            outcomes,
+            [
+                AddItemOutcome::Duplicate {
+                    item: "Brötchen".to_string()
+                },
+                AddItemOutcome::Duplicate {
+                    item: "Butter".to_string()
+                }
+            ]
+        );
+        assert_eq!(repo.batches.load(Ordering::SeqCst), 1);
+        assert!(store.claims.lock().unwrap().contains("req-1#1"));
+    }
+
+    #[tokio::test]
+    async fn execute_all_once_retries_only_failed_items() {
+        let store = Arc::new(MockIdempotencyStore::default());
+        let failing = AddItemService::new(Arc::new(CountingRepository {
+            fail_after: Some("Butter"),
+            ..CountingRepository::default()
+        }))
+        .with_idempotency_store(store.clone());
+        let names = ["Brötchen", "Butter"].map(String::from);
+        failing.execute_all_once("req-1", &names).await;
+
+        let repo = Arc::new(CountingRepository::default());
+        let service = AddItemService::new(repo.clone()).with_idempotency_store(store);
+        let outcomes = service.execute_all_once("req-1", &names).await;
+
+        assert_eq!(
+            outcomes,
+            [
+                AddItemOutcome::Duplicate {
+                    item: "Brötchen".to_string()
+                },
+                AddItemOutcome::Added {
+                    item: "Butter".to_string()
+                }
+            ]
+        );
+        let labels: Vec<String> = repo
+            .items
+            .lock()
+            .unwrap()
+            .iter()
+            .map(ShoppingListItem::label)
+            .collect();
+        assert_eq!(labels, ["Butter"]);
+    }
+
+    #[tokio::test]
+    async fn execute_leaves_unknown_items_uncategorized() {
+        let repo = Arc::new(CountingRepository::default());
+        let service = AddItemService::new(repo.clone());
+
+        service.execute("Geschenkpapier").await;
+
+        let items = repo.items.lock().unwrap();
+        assert_eq!(items[0].category(), None);
+    }
+
+    #[derive(Default)]
+    struct CountingRepository {
+        calls: std::sync::atomic::AtomicUsize,
+        batches: std::sync::atomic::AtomicUsize,
+        items: std::sync::Mutex<Vec<ShoppingListItem>>,
+        fail_after: Option<&'static str>,
+        max_adds: Option<usize>,
+    }
+
+    #[async_trait]
+    impl ShoppingListRepository for CountingRepository {
+        async fn add_item(&self, item: &ShoppingListItem) -> Result<Vec<AddedItem>, DomainError> {
+            let calls = self.calls.fetch_add(1, Ordering::SeqCst);
+            if self.max_adds.is_some_and(|max_adds| calls >= max_adds) {
+                return Err(DomainError::RepositoryError(
+                    "Connection failed".to_string(),
+                ));
+            }
+            self.items.lock().unwrap().push(item.clone());
+            Ok(Vec::new())
+        }
+
+        async fn add_items(&self, items: &[ShoppingListItem]) -> Vec<Result<(), DomainError>> {
+            self.batches.fetch_add(1, Ordering::SeqCst);
+            self.items.lock().unwrap().extend_from_slice(items);
+            items
+                .iter()
+                .map(|item| match self.fail_after {
+                    Some(name) if item.name() == name => Err(DomainError::RepositoryError(
+                        "Connection failed".to_string(),
+                    )),
+                    _ => Ok(()),
+                })
+                .collect()
+        }
+
+        async fn list_items(&self) -> Result<Vec<ShoppingListEntry>, DomainError> {
+            Ok(Vec::new())
+        }
+
+        async fn remove_item(&self, _id: &str) -> Result<(), DomainError> {
+            Ok(())
+        }
+    }
+
+    #[derive(Default)]
+    struct MockIdempotencyStore {
+        claims: std::sync::Mutex<std::collections::HashSet<String>>,
+        outcomes: std::sync::Mutex<std::collections::HashMap<String, String>>,
+    }
+
+    #[async_trait]
+    impl IdempotencyStore for MockIdempotencyStore {
+        async fn get(&self, key: &str) -> Result<Option<String>, DomainError> {
+            Ok(self.outcomes.lock().unwrap().get(key).cloned())
+        }
+
+        async fn claim(&self, key: &str) -> Result<bool, DomainError> {
+            Ok(self.claims.lock().unwrap().insert(key.to_string()))
+        }
+
+        async fn release(&self, key: &str) -> Result<(), DomainError> {
+            self.claims.lock().unwrap().remove(key);
+            Ok(())
+        }
+
+        async fn put(&self, key: &str, outcome: &str) -> Result<(), DomainError> {
+            self.outcomes
+                .lock()
+                .unwrap()
+                .insert(key.to_string(), outcome.to_string());
+            Ok(())
+        }
+    }
+}
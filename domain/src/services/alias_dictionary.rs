@@ -0,0 +1,119 @@
+use std::collections::HashMap;
+
+use crate::ports::ItemDictionary;
+
+/// Built-in aliases as (canonical name, aliases).
+///
+/// Regional words for common groceries and brand names used for generic
+/// items. The Alexa interaction model offers the same words as synonyms.
+pub const DEFAULT_ALIASES: &[(&str, &[&str])] = &[
+    ("Brötchen", &["Semmel", "Schrippe", "Weckle", "Wecken"]),
+    ("Kartoffeln", &["Erdäpfel", "Grumbeere"]),
+    ("Karotten", &["Möhren", "Mohrrüben", "gelbe Rüben"]),
+    ("Sahne", &["Rahm", "Schlagsahne", "Schlagobers"]),
+    ("Quark", &["Topfen"]),
+    ("Tomaten", &["Paradeiser"]),
+    ("Hackfleisch", &["Gehacktes", "Faschiertes", "Hack"]),
+    ("Pfannkuchen", &["Eierkuchen", "Palatschinken"]),
+    ("Aprikosen", &["Marillen"]),
+    ("Blumenkohl", &["Karfiol"]),
+    ("Pilze", &["Schwammerl"]),
+    ("Eier", &["Hühnereier"]),
+    ("Milch", &["Frischmilch"]),
+    ("Taschentücher", &["Tempo", "Tempos"]),
+    ("Küchenrolle", &["Zewa"]),
+    ("Klebeband", &["Tesa", "Tesafilm"]),
+];
+
+/// Item dictionary built from the default aliases plus configured ones.
+///
+/// Only whole names are matched (ignoring case and surrounding whitespace),
+/// so "Semmelknödel" stays as spoken. Configured aliases take precedence
+/// over the built-in ones.
+pub struct AliasDictionary {
+    aliases: HashMap<String, String>,
+}
+
+impl AliasDictionary {
+    /// Creates a dictionary with the built-in aliases.
+    pub fn new() -> Self {
+        let aliases = DEFAULT_ALIASES
+            .iter()
+            .flat_map(|(canonical, aliases)| {
+                aliases
+                    .iter()
+                    .map(|alias| (normalize(alias), canonical.to_string()))
+            })
+            .collect();
+        Self { aliases }
+    }
+
+    /// Creates a dictionary without any aliases.
+    pub fn empty() -> Self {
+        Self {
+            aliases: HashMap::new(),
+        }
+    }
+
+    /// Adds aliases as (alias, canonical name) pairs, replacing built-in
+    /// ones for the same alias.
+    pub fn with_aliases(mut self, aliases: impl IntoIterator<Item = (String, String)>) -> Self {
+        for (alias, canonical) in aliases {
+            let alias = normalize(&alias);
+            let canonical = canonical.trim();
+            if !alias.is_empty() && !canonical.is_empty() {
+                self.aliases.insert(alias, canonical.to_string());
+            }
+        }
+        self
+    }
+}
+
+impl Default for AliasDictionary {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ItemDictionary for AliasDictionary {
+    fn canonical(&self, item_name: &str) -> Option<&str> {
+        self.aliases.get(&normalize(item_name)).map(String::as_str)
+    }
+}
+
+fn normalize(name: &str) -> String {
+    name.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_regional_words_and_brands() {
+        let dictionary = AliasDictionary::new();
+        assert_eq!(dictionary.resolve("Paradeiser"), "Tomaten");
+        assert_eq!(dictionary.resolve(" tempo "), "Taschentücher");
+        assert_eq!(dictionary.resolve("gelbe  Rüben"), "Karotten");
+    }
+
+    #[test]
+    fn keeps_unknown_names_and_compounds() {
+        let dictionary = AliasDictionary::new();
+        assert_eq!(dictionary.canonical("Milch"), None);
+        assert_eq!(dictionary.resolve("Semmelknödel"), "Semmelknödel");
+    }
+
+    #[test]
+    fn configured_aliases_take_precedence() {
+        let dictionary = AliasDictionary::new().with_aliases([
+            ("Hack".to_string(), "Rinderhack".to_string()),
+            ("Haferdrink".to_string(), "Hafermilch".to_string()),
+        ]);
+        assert_eq!(dictionary.resolve("hack"), "Rinderhack");
+        assert_eq!(dictionary.resolve("Haferdrink"), "Hafermilch");
+    }
+}
@@ -0,0 +1,438 @@
+use std::sync::Arc;
+
+use tracing::{error, info};
+
+use crate::models::{BundleError, BundleOutcome, ItemBundle, ItemBundles, UserPreferences};
+use crate::ports::PreferencesRepository;
+
+/// Service for the item bundles a user sets up by voice, e.g. "füge Butter
+/// zu meinem Frühstücks-Set hinzu".
+///
+/// A user's bundles are stored with the household's preferences and take
+/// precedence over configured bundles of the same name. Extending a
+/// configured bundle stores the user's own copy of it; configured bundles
+/// themselves cannot be deleted by voice.
+pub struct BundleService {
+    repository: Arc<dyn PreferencesRepository>,
+    configured: Arc<ItemBundles>,
+}
+
+impl BundleService {
+    /// Creates a new BundleService storing bundles in `repository`.
+    ///
+    /// No bundles are configured unless set with [`Self::with_bundles`].
+    pub fn new(repository: Arc<dyn PreferencesRepository>) -> Self {
+        Self {
+            repository,
+            configured: Arc::new(ItemBundles::new()),
+        }
+    }
+
+    /// Sets the bundles configured for every user.
+    pub fn with_bundles(mut self, configured: Arc<ItemBundles>) -> Self {
+        self.configured = configured;
+        self
+    }
+
+    /// Returns the bundle a spoken name refers to, looking in the user's
+    /// `own` bundles first.
+    pub fn find<'a>(&'a self, own: &'a ItemBundles, spoken: &str) -> Option<&'a ItemBundle> {
+        own.find(spoken).or_else(|| self.configured.find(spoken))
+    }
+
+    /// Returns the items of the bundle a spoken name refers to, looking in
+    /// the user's own bundles first; without a user only configured bundles
+    /// are known.
+    ///
+    /// # Errors
+    /// Returns `BundleError::Unknown` if there is no such bundle, or
+    /// `BundleError::Unavailable` if the user's bundles cannot be read.
+    pub async fn items(
+        &self,
+        user_id: Option<&str>,
+        spoken: &str,
+    ) -> Result<Vec<String>, BundleError> {
+        let own = match user_id {
+            Some(user_id) => self.read(user_id).await?.bundles().clone(),
+            None => ItemBundles::new(),
+        };
+        match self.find(&own, spoken) {
+            Some(bundle) => Ok(bundle.items().to_vec()),
+            None => Err(unknown(spoken)),
+        }
+    }
+
+    /// Adds an item to the user's bundle, creating the bundle if needed.
+    ///
+    /// # Returns
+    /// `Created`, `Extended`, or `AlreadyInBundle` if the bundle already
+    /// holds the item.
+    ///
+    /// # Errors
+    /// Returns `BundleError::Unavailable` if the store fails.
+    pub async fn extend(
+        &self,
+        user_id: &str,
+        spoken: &str,
+        item_name: &str,
+    ) -> Result<BundleOutcome, BundleError> {
+        let mut preferences = self.read(user_id).await?;
+        let bundle = match self.find(preferences.bundles(), spoken) {
+            Some(bundle) if bundle.contains(item_name) => {
+                return Ok(BundleOutcome::AlreadyInBundle {
+                    bundle: bundle.name().to_string(),
+                    item: item_name.to_string(),
+                });
+            }
+            Some(bundle) => bundle.clone().with_item(item_name),
+            None => ItemBundle::new(capitalize(spoken.trim()), [item_name]),
+        };
+        let created = self.find(preferences.bundles(), spoken).is_none();
+
+        let mut bundles = preferences.bundles().clone();
+        bundles.put(bundle.clone());
+        preferences.set_bundles(bundles);
+        self.save(user_id, &preferences).await?;
+
+        info!(bundle = %bundle.name(), item_name = %item_name, created, "Bundle extended");
+        let (bundle, item) = (bundle.name().to_string(), item_name.to_string());
+        Ok(if created {
+            BundleOutcome::Created { bundle, item }
+        } else {
+            BundleOutcome::Extended { bundle, item }
+        })
+    }
+
+    /// Deletes the user's bundle.
+    ///
+    /// # Returns
+    /// `Deleted` with the name of the deleted bundle.
+    ///
+    /// # Errors
+    /// Returns `BundleError::Configured` for a configured bundle,
+    /// `BundleError::Unknown` if there is no such bundle, or
+    /// `BundleError::Unavailable` if the store fails.
+    pub async fn delete(&self, user_id: &str, spoken: &str) -> Result<BundleOutcome, BundleError> {
+        let mut preferences = self.read(user_id).await?;
+        let mut bundles = preferences.bundles().clone();
+        let Some(bundle) = bundles.remove(spoken) else {
+            return Err(match self.configured.find(spoken) {
+                Some(bundle) => BundleError::Configured {
+                    bundle: bundle.name().to_string(),
+                },
+                None => unknown(spoken),
+            });
+        };
+        preferences.set_bundles(bundles);
+        self.save(user_id, &preferences).await?;
+
+        info!(bundle = %bundle.name(), "Bundle deleted");
+        Ok(BundleOutcome::Deleted {
+            bundle: bundle.name().to_string(),
+        })
+    }
+
+    /// Looks up the items of the named bundle, or the names of all bundles
+    /// if `spoken` is `None`.
+    ///
+    /// # Returns
+    /// `Items` of the named bundle, or `Names` of the user's own bundles
+    /// followed by the configured ones.
+    ///
+    /// # Errors
+    /// Returns `BundleError::Unknown` if there is no such bundle, or
+    /// `BundleError::Unavailable` if the store fails.
+    pub async fn describe(
+        &self,
+        user_id: &str,
+        spoken: Option<&str>,
+    ) -> Result<BundleOutcome, BundleError> {
+        let preferences = self.read(user_id).await?;
+        let own = preferences.bundles();
+
+        let Some(spoken) = spoken else {
+            let mut names: Vec<String> = own
+                .bundles()
+                .iter()
+                .map(|bundle| bundle.name().to_string())
+                .collect();
+            for bundle in self.configured.bundles() {
+                if own.find(bundle.name()).is_none() {
+                    names.push(bundle.name().to_string());
+                }
+            }
+            return Ok(BundleOutcome::Names { names });
+        };
+
+        match self.find(own, spoken) {
+            Some(bundle) => Ok(BundleOutcome::Items {
+                bundle: bundle.name().to_string(),
+                items: bundle.items().to_vec(),
+            }),
+            None => Err(unknown(spoken)),
+        }
+    }
+
+    async fn read(&self, user_id: &str) -> Result<UserPreferences, BundleError> {
+        self.repository.get(user_id).await.map_err(|e| {
+            error!(error = %e, "Failed to read bundles");
+            BundleError::Unavailable
+        })
+    }
+
+    async fn save(&self, user_id: &str, preferences: &UserPreferences) -> Result<(), BundleError> {
+        self.repository
+            .save(user_id, preferences)
+            .await
+            .map_err(|e| {
+                error!(error = %e, "Failed to save bundles");
+                BundleError::Unavailable
+            })
+    }
+}
+
+fn unknown(spoken: &str) -> BundleError {
+    BundleError::Unknown {
+        name: spoken.trim().to_string(),
+    }
+}
+
+fn capitalize(value: &str) -> String {
+    let mut chars = value.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    use async_trait::async_trait;
+
+    use crate::models::DomainError;
+
+    #[derive(Default)]
+    struct MockRepository {
+        preferences: Mutex<HashMap<String, UserPreferences>>,
+        fail: bool,
+    }
+
+    #[async_trait]
+    impl PreferencesRepository for MockRepository {
+        async fn get(&self, user_id: &str) -> Result<UserPreferences, DomainError> {
+            if self.fail {
+                return Err(DomainError::RepositoryError("down".to_string()));
+            }
+            Ok(self
+                .preferences
+                .lock()
+                .unwrap()
+                .get(user_id)
+                .cloned()
+                .unwrap_or_default())
+        }
+
+        async fn save(
+            &self,
+            user_id: &str,
+            preferences: &UserPreferences,
+        ) -> Result<(), DomainError> {
+            self.preferences
+                .lock()
+                .unwrap()
+                .insert(user_id.to_string(), preferences.clone());
+            Ok(())
+        }
+
+        async fn delete(&self, user_id: &str) -> Result<(), DomainError> {
+            self.preferences.lock().unwrap().remove(user_id);
+            Ok(())
+        }
+
+        async fn delete_all(&self, user_id: &str) -> Result<(), DomainError> {
+            self.delete(user_id).await
+        }
+    }
+
+    fn service() -> BundleService {
+        BundleService::new(Arc::new(MockRepository::default())).with_bundles(Arc::new(
+            ItemBundles::new().with_bundle(ItemBundle::new("Grill-Set", ["Würstchen", "Senf"])),
+        ))
+    }
+
+    #[tokio::test]
+    async fn creates_and_extends_bundle() {
+        let service = service();
+
+        let created = service
+            .extend("user-1", "frühstücks-Set", "Butter")
+            .await
+            .unwrap();
+        let extended = service
+            .extend("user-1", "Frühstücksset", "Brötchen")
+            .await
+            .unwrap();
+        let again = service
+            .extend("user-1", "Frühstücks-Set", "butter")
+            .await
+            .unwrap();
+
+        assert_eq!(
+            created,
+            BundleOutcome::Created {
+                bundle: "Frühstücks-Set".to_string(),
+                item: "Butter".to_string()
+            }
+        );
+        assert_eq!(
+            extended,
+            BundleOutcome::Extended {
+                bundle: "Frühstücks-Set".to_string(),
+                item: "Brötchen".to_string()
+            }
+        );
+        assert_eq!(
+            again,
+            BundleOutcome::AlreadyInBundle {
+                bundle: "Frühstücks-Set".to_string(),
+                item: "butter".to_string()
+            }
+        );
+        assert_eq!(
+            service.describe("user-1", Some("Frühstücks-Set")).await,
+            Ok(items("Frühstücks-Set", &["Butter", "Brötchen"]))
+        );
+        assert_eq!(
+            service.describe("user-2", Some("Frühstücks-Set")).await,
+            Err(BundleError::Unknown {
+                name: "Frühstücks-Set".to_string()
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn extending_configured_bundle_stores_own_copy() {
+        let service = service();
+
+        service
+            .extend("user-1", "Grill-Set", "Ketchup")
+            .await
+            .unwrap();
+
+        assert_eq!(
+            service.describe("user-1", Some("Grill-Set")).await,
+            Ok(items("Grill-Set", &["Würstchen", "Senf", "Ketchup"]))
+        );
+        assert_eq!(
+            service.describe("user-2", Some("Grill-Set")).await,
+            Ok(items("Grill-Set", &["Würstchen", "Senf"]))
+        );
+    }
+
+    #[tokio::test]
+    async fn deletes_own_bundles_only() {
+        let service = service();
+        service
+            .extend("user-1", "Kaffee-Set", "Milch")
+            .await
+            .unwrap();
+
+        assert_eq!(
+            service.delete("user-1", "Kaffeeset").await,
+            Ok(BundleOutcome::Deleted {
+                bundle: "Kaffee-Set".to_string()
+            })
+        );
+        assert_eq!(
+            service.delete("user-1", "Kaffee-Set").await,
+            Err(BundleError::Unknown {
+                name: "Kaffee-Set".to_string()
+            })
+        );
+        assert_eq!(
+            service.delete("user-1", "Grill-Set").await,
+            Err(BundleError::Configured {
+                bundle: "Grill-Set".to_string()
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn lists_bundle_names() {
+        let service = service();
+        assert_eq!(
+            service.describe("user-1", None).await,
+            Ok(names(&["Grill-Set"]))
+        );
+
+        service
+            .extend("user-1", "Kaffee-Set", "Milch")
+            .await
+            .unwrap();
+
+        assert_eq!(
+            service.describe("user-1", None).await,
+            Ok(names(&["Kaffee-Set", "Grill-Set"]))
+        );
+        assert_eq!(
+            BundleService::new(Arc::new(MockRepository::default()))
+                .describe("user-1", None)
+                .await,
+            Ok(names(&[]))
+        );
+    }
+
+    #[tokio::test]
+    async fn returns_items_of_own_or_configured_bundle() {
+        let service = service();
+        service
+            .extend("user-1", "Kaffee-Set", "Milch")
+            .await
+            .unwrap();
+
+        assert_eq!(
+            service.items(Some("user-1"), "Kaffeeset").await,
+            Ok(vec!["Milch".to_string()])
+        );
+        assert_eq!(
+            service.items(None, "Grill-Set").await,
+            Ok(vec!["Würstchen".to_string(), "Senf".to_string()])
+        );
+        assert_eq!(
+            service.items(None, "Kaffee-Set").await,
+            Err(BundleError::Unknown {
+                name: "Kaffee-Set".to_string()
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn unavailable_store_is_reported() {
+        let service = BundleService::new(Arc::new(MockRepository {
+            fail: true,
+            ..Default::default()
+        }));
+
+        assert_eq!(
+            service.extend("user-1", "Kaffee-Set", "Milch").await,
+            Err(BundleError::Unavailable)
+        );
+    }
+
+    fn items(bundle: &str, items: &[&str]) -> BundleOutcome {
+        BundleOutcome::Items {
+            bundle: bundle.to_string(),
+            items: items.iter().map(|item| item.to_string()).collect(),
+        }
+    }
+
+    fn names(names: &[&str]) -> BundleOutcome {
+        BundleOutcome::Names {
+            names: names.iter().map(|name| name.to_string()).collect(),
+        }
+    }
+}
@@ -0,0 +1,251 @@
+use crate::models::Category;
+
+/// Built-in keywords per category.
+///
+/// Keywords are matched in lowercase at the start of a word or of a compound
+/// part, so German compounds such as "Vollmilch" or "Cherrytomaten" are
+/// covered by their base word (see [`matches_word`]).
+const DEFAULT_KEYWORDS: &[(&str, Category)] = &[
+    ("apfel", Category::FruitAndVegetables),
+    ("äpfel", Category::FruitAndVegetables),
+    ("banane", Category::FruitAndVegetables),
+    ("birne", Category::FruitAndVegetables),
+    ("zitrone", Category::FruitAndVegetables),
+    ("orange", Category::FruitAndVegetables),
+    ("beere", Category::FruitAndVegetables),
+    ("tomate", Category::FruitAndVegetables),
+    ("gurke", Category::FruitAndVegetables),
+    ("paprika", Category::FruitAndVegetables),
+    ("zwiebel", Category::FruitAndVegetables),
+    ("knoblauch", Category::FruitAndVegetables),
+    ("kartoffel", Category::FruitAndVegetables),
+    ("karotte", Category::FruitAndVegetables),
+    ("möhre", Category::FruitAndVegetables),
+    ("salat", Category::FruitAndVegetables),
+    ("spinat", Category::FruitAndVegetables),
+    ("zucchini", Category::FruitAndVegetables),
+    ("brokkoli", Category::FruitAndVegetables),
+    ("pilz", Category::FruitAndVegetables),
+    ("champignon", Category::FruitAndVegetables),
+    ("milch", Category::Dairy),
+    ("joghurt", Category::Dairy),
+    ("quark", Category::Dairy),
+    ("käse", Category::Dairy),
+    ("butter", Category::Dairy),
+    ("sahne", Category::Dairy),
+    ("schmand", Category::Dairy),
+    ("mozzarella", Category::Dairy),
+    ("ei", Category::Dairy),
+    ("eier", Category::Dairy),
+    ("fleisch", Category::MeatAndFish),
+    ("hähnchen", Category::MeatAndFish),
+    ("huhn", Category::MeatAndFish),
+    ("hack", Category::MeatAndFish),
+    ("wurst", Category::MeatAndFish),
+    ("schinken", Category::MeatAndFish),
+    ("schwein", Category::MeatAndFish),
+    ("speck", Category::MeatAndFish),
+    ("lachs", Category::MeatAndFish),
+    ("fisch", Category::MeatAndFish),
+    ("garnele", Category::MeatAndFish),
+    ("brot", Category::Bakery),
+    ("brötchen", Category::Bakery),
+    ("toast", Category::Bakery),
+    ("croissant", Category::Bakery),
+    ("wasser", Category::Beverages),
+    ("saft", Category::Beverages),
+    ("bier", Category::Beverages),
+    ("wein", Category::Beverages),
+    ("kaffee", Category::Beverages),
+    ("tee", Category::Beverages),
+    ("limonade", Category::Beverages),
+    ("tiefkühl", Category::Frozen),
+    ("eis", Category::Frozen),
+    ("pizza", Category::Frozen),
+    ("mehl", Category::Pantry),
+    ("zucker", Category::Pantry),
+    ("nudel", Category::Pantry),
+    ("spaghetti", Category::Pantry),
+    ("reis", Category::Pantry),
+    ("haferflocken", Category::Pantry),
+    ("linsen", Category::Pantry),
+    ("bohnen", Category::Pantry),
+    ("öl", Category::Pantry),
+    ("olivenöl", Category::Pantry),
+    ("rapsöl", Category::Pantry),
+    ("sonnenblumenöl", Category::Pantry),
+    ("hefe", Category::Pantry),
+    ("salz", Category::SpicesAndSauces),
+    ("pfeffer", Category::SpicesAndSauces),
+    ("gewürz", Category::SpicesAndSauces),
+    ("senf", Category::SpicesAndSauces),
+    ("ketchup", Category::SpicesAndSauces),
+    ("essig", Category::SpicesAndSauces),
+    ("brühe", Category::SpicesAndSauces),
+    ("schokolade", Category::Sweets),
+    ("keks", Category::Sweets),
+    ("chips", Category::Sweets),
+    ("gummibärchen", Category::Sweets),
+    ("spülmittel", Category::Household),
+    ("waschmittel", Category::Household),
+    ("toilettenpapier", Category::Household),
+    ("küchenrolle", Category::Household),
+    ("müllbeutel", Category::Household),
+];
+
+/// Sorts items into shopping list categories by keyword.
+///
+/// The longest matching keyword wins, so "Milchreis" can be mapped
+/// differently from "Milch" by an override. Overrides are checked before
+/// the built-in keywords; items matching nothing stay uncategorized and end
+/// up in Cookidoo's default category.
+pub struct CategoryClassifier {
+    overrides: Vec<(String, Category)>,
+}
+
+impl CategoryClassifier {
+    /// Creates a classifier using only the built-in keywords.
+    pub fn new() -> Self {
+        Self {
+            overrides: Vec::new(),
+        }
+    }
+
+    /// Adds keywords that take precedence over the built-in ones.
+    pub fn with_overrides(
+        mut self,
+        overrides: impl IntoIterator<Item = (String, Category)>,
+    ) -> Self {
+        self.overrides.extend(
+            overrides
+                .into_iter()
+                .map(|(keyword, category)| (keyword.trim().to_lowercase(), category))
+                .filter(|(keyword, _)| !keyword.is_empty()),
+        );
+        self
+    }
+
+    /// Returns the category for an item name, if any keyword matches.
+    pub fn classify(&self, item_name: &str) -> Option<Category> {
+        let name = item_name.to_lowercase();
+
+        let overridden = self
+            .overrides
+            .iter()
+            .map(|(keyword, category)| (keyword.as_str(), *category));
+        if let Some(category) = longest_match(&name, overridden) {
+            return Some(category);
+        }
+
+        longest_match(&name, DEFAULT_KEYWORDS.iter().copied())
+    }
+}
+
+impl Default for CategoryClassifier {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Finds the category of the longest keyword matching a word of `name`.
+fn longest_match<'a>(
+    name: &str,
+    keywords: impl Iterator<Item = (&'a str, Category)>,
+) -> Option<Category> {
+    keywords
+        .filter(|(keyword, _)| {
+            name.split(|c: char| !c.is_alphanumeric())
+                .any(|word| matches_word(word, keyword))
+        })
+        .max_by_key(|(keyword, _)| keyword.len())
+        .map(|(_, category)| category)
+}
+
+/// Returns whether `keyword` starts `word` or one of its compound parts.
+///
+/// A compound part starts after a modifier that is a syllable of its own,
+/// i.e. contains a vowel: "Voll|milch" and "Basmati|reis" match, while
+/// "Schw|ein" ("wein") and "Pr|eis" ("eis") do not. Two-letter keywords
+/// ("ei", "öl") only match whole words to avoid hits inside unrelated words
+/// such as "Brei" or "Brötchen".
+fn matches_word(word: &str, keyword: &str) -> bool {
+    if keyword.chars().count() <= 2 {
+        return word == keyword;
+    }
+    word.match_indices(keyword)
+        .any(|(start, _)| start == 0 || word[..start].chars().any(is_vowel))
+}
+
+fn is_vowel(c: char) -> bool {
+    matches!(c, 'a' | 'e' | 'i' | 'o' | 'u' | 'ä' | 'ö' | 'ü' | 'y')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classifies_by_keyword() {
+        let classifier = CategoryClassifier::new();
+        assert_eq!(classifier.classify("Milch"), Some(Category::Dairy));
+        assert_eq!(
+            classifier.classify("Bananen"),
+            Some(Category::FruitAndVegetables)
+        );
+        assert_eq!(classifier.classify("Vollkornbrot"), Some(Category::Bakery));
+    }
+
+    #[test]
+    fn matches_compound_words() {
+        let classifier = CategoryClassifier::new();
+        assert_eq!(
+            classifier.classify("Cherrytomaten"),
+            Some(Category::FruitAndVegetables)
+        );
+        assert_eq!(classifier.classify("Hafermilch"), Some(Category::Dairy));
+    }
+
+    #[test]
+    fn short_keywords_only_match_whole_words() {
+        let classifier = CategoryClassifier::new();
+        assert_eq!(classifier.classify("Brei"), None);
+        assert_eq!(classifier.classify("Ei"), Some(Category::Dairy));
+        assert_eq!(classifier.classify("Eier"), Some(Category::Dairy));
+    }
+
+    #[test]
+    fn keywords_match_on_word_boundaries() {
+        let classifier = CategoryClassifier::new();
+        assert_eq!(classifier.classify("Schwein"), Some(Category::MeatAndFish));
+        assert_eq!(
+            classifier.classify("Preiselbeeren"),
+            Some(Category::FruitAndVegetables)
+        );
+        assert_eq!(classifier.classify("Rotwein"), Some(Category::Beverages));
+        assert_eq!(classifier.classify("Kreide"), None);
+    }
+
+    #[test]
+    fn prefers_longest_keyword() {
+        let classifier = CategoryClassifier::new();
+        // "eis" and "reis" both match; the longer keyword wins
+        assert_eq!(classifier.classify("Basmatireis"), Some(Category::Pantry));
+    }
+
+    #[test]
+    fn unknown_item_is_uncategorized() {
+        let classifier = CategoryClassifier::new();
+        assert_eq!(classifier.classify("Geschenkpapier"), None);
+    }
+
+    #[test]
+    fn overrides_take_precedence() {
+        let classifier = CategoryClassifier::new()
+            .with_overrides([("Hafermilch".to_string(), Category::Beverages)]);
+        assert_eq!(
+            classifier.classify("Bio Hafermilch"),
+            Some(Category::Beverages)
+        );
+        assert_eq!(classifier.classify("Milch"), Some(Category::Dairy));
+    }
+}
@@ -0,0 +1,158 @@
+use std::collections::HashMap;
+
+/// Built-in exceptions as (singular, plural forms).
+///
+/// Words the suffix rules would get wrong: "Eier" is too short to reduce to
+/// "Ei", and "Eis", "Reis" and "Mais" would lose their final "s".
+pub const DEFAULT_PLURAL_EXCEPTIONS: &[(&str, &[&str])] = &[
+    ("Ei", &["Eier"]),
+    ("Eis", &[]),
+    ("Reis", &[]),
+    ("Mais", &[]),
+];
+
+/// Plural endings removed from each word, longest first.
+const PLURAL_SUFFIXES: &[&str] = &["en", "er", "e", "n", "s"];
+
+/// Shortest stem a suffix may be removed down to.
+const MIN_STEM_LENGTH: usize = 3;
+
+/// Matches German item names regardless of singular and plural.
+///
+/// Every word is reduced to a stem: umlauts are replaced by their base
+/// vowel ("Äpfel" → "apfel") and plural endings are removed ("Tomaten" →
+/// "tomat"), so "Tomate" and "Tomaten" or "Apfel" and "Äpfel" compare equal.
+/// The stem is only a comparison key and never shown. Words the rules get
+/// wrong are listed as exceptions; configured exceptions take precedence
+/// over the built-in ones.
+pub struct GermanSingularizer {
+    exceptions: HashMap<String, String>,
+}
+
+impl GermanSingularizer {
+    /// Creates a singularizer with the built-in exceptions.
+    pub fn new() -> Self {
+        let exceptions = DEFAULT_PLURAL_EXCEPTIONS
+            .iter()
+            .flat_map(|(singular, plurals)| {
+                let key = singular.to_lowercase();
+                std::iter::once(*singular)
+                    .chain(plurals.iter().copied())
+                    .map(move |form| (form.to_lowercase(), key.clone()))
+            })
+            .collect();
+        Self { exceptions }
+    }
+
+    /// Adds exceptions as (plural, singular) pairs. Both forms then match
+    /// each other and nothing else.
+    pub fn with_exceptions(
+        mut self,
+        exceptions: impl IntoIterator<Item = (String, String)>,
+    ) -> Self {
+        for (plural, singular) in exceptions {
+            let plural = plural.trim().to_lowercase();
+            let singular = singular.trim().to_lowercase();
+            if !plural.is_empty() && !singular.is_empty() {
+                self.exceptions.insert(plural, singular.clone());
+                self.exceptions.insert(singular.clone(), singular);
+            }
+        }
+        self
+    }
+
+    /// Returns the comparison key of a name, ignoring case, surrounding
+    /// whitespace and the number of each word.
+    pub fn key(&self, name: &str) -> String {
+        name.split_whitespace()
+            .map(|word| self.word_key(&word.to_lowercase()))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Returns true if both names denote the same item.
+    pub fn same_item(&self, a: &str, b: &str) -> bool {
+        self.key(a) == self.key(b)
+    }
+
+    fn word_key(&self, word: &str) -> String {
+        if let Some(key) = self.exceptions.get(word) {
+            return key.clone();
+        }
+
+        let mut stem: String = word
+            .chars()
+            .map(|c| match c {
+                'ä' => 'a',
+                'ö' => 'o',
+                'ü' => 'u',
+                c => c,
+            })
+            .collect();
+
+        // Endings are removed repeatedly ("Tees" → "tee"), so a singular and
+        // its plural always end up at the same stem
+        while let Some(shorter) = PLURAL_SUFFIXES
+            .iter()
+            .filter_map(|suffix| stem.strip_suffix(suffix))
+            .find(|shorter| shorter.chars().count() >= MIN_STEM_LENGTH)
+        {
+            stem = shorter.to_string();
+        }
+        stem
+    }
+}
+
+impl Default for GermanSingularizer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_regular_plurals() {
+        let singularizer = GermanSingularizer::new();
+        assert!(singularizer.same_item("Tomate", "Tomaten"));
+        assert!(singularizer.same_item("Banane", "bananen"));
+        assert!(singularizer.same_item("Nudel", "Nudeln"));
+        assert!(singularizer.same_item("Joghurt", "Joghurts"));
+        assert!(singularizer.same_item("Tee", "Tees"));
+    }
+
+    #[test]
+    fn matches_umlaut_plurals() {
+        let singularizer = GermanSingularizer::new();
+        assert!(singularizer.same_item("Apfel", "Äpfel"));
+        assert!(singularizer.same_item("Saft", "Säfte"));
+        assert!(singularizer.same_item("Nuss", "Nüsse"));
+    }
+
+    #[test]
+    fn matches_compounds_and_phrases() {
+        let singularizer = GermanSingularizer::new();
+        assert!(singularizer.same_item("Kirschtomate", "Kirschtomaten"));
+        assert!(singularizer.same_item(" frische  Zwiebel", "frische Zwiebeln"));
+        assert!(!singularizer.same_item("Tomaten", "Tomatenmark"));
+    }
+
+    #[test]
+    fn keeps_exceptions_apart() {
+        let singularizer = GermanSingularizer::new();
+        assert!(singularizer.same_item("Ei", "Eier"));
+        assert!(!singularizer.same_item("Eis", "Ei"));
+        assert!(!singularizer.same_item("Reis", "Rei"));
+    }
+
+    #[test]
+    fn configured_exceptions_take_precedence() {
+        assert!(!GermanSingularizer::new().same_item("Pizza", "Pizzen"));
+
+        let singularizer = GermanSingularizer::new()
+            .with_exceptions([("Pizzen".to_string(), "Pizza".to_string())]);
+        assert!(singularizer.same_item("Pizza", "Pizzen"));
+    }
+}
@@ -0,0 +1,218 @@
+use std::sync::Arc;
+
+use tracing::{debug, error, warn};
+
+use crate::models::{DomainError, HistoryEntry, HistoryPeriod};
+use crate::ports::HistoryRepository;
+
+/// Service for the history of items a user added.
+///
+/// Answers "what did I add today?" and shows in the logs and the table
+/// which adds a user actually made when they report duplicates.
+pub struct HistoryService {
+    repository: Arc<dyn HistoryRepository>,
+}
+
+impl HistoryService {
+    /// Creates a new HistoryService with the given repository.
+    pub fn new(repository: Arc<dyn HistoryRepository>) -> Self {
+        Self { repository }
+    }
+
+    /// Records an added item, together with the recognized speaker if any.
+    ///
+    /// Failures are only logged: a missing history entry must not turn a
+    /// successful add into an error.
+    pub async fn record(
+        &self,
+        user_id: &str,
+        person_id: Option<&str>,
+        item_name: &str,
+        added_at: &str,
+    ) {
+        let entry = HistoryEntry::new(item_name, added_at);
+        let entry = match person_id {
+            Some(person_id) => entry.with_person_id(person_id),
+            None => entry,
+        };
+        match self.repository.record(user_id, &entry).await {
+            Ok(()) => debug!(item_name = %item_name, "Recorded add in history"),
+            Err(e) => warn!(error = %e, "Failed to record add in history"),
+        }
+    }
+
+    /// Returns the items the user added in the period containing `now`,
+    /// oldest first.
+    ///
+    /// A recognized speaker only hears their own adds, so household members
+    /// sharing an account can tell who added what.
+    ///
+    /// # Returns
+    /// The entries, possibly none.
+    ///
+    /// # Errors
+    /// Returns `DomainError::RepositoryError` if the period cannot be
+    /// determined from `now`, or the repository error.
+    pub async fn added_in(
+        &self,
+        user_id: &str,
+        person_id: Option<&str>,
+        period: HistoryPeriod,
+        now: &str,
+    ) -> Result<Vec<HistoryEntry>, DomainError> {
+        let Some(since) = period.start(now) else {
+            error!(now = %now, "Cannot determine history period from timestamp");
+            return Err(DomainError::RepositoryError(format!(
+                "invalid request timestamp: {}",
+                now
+            )));
+        };
+
+        let entries = self
+            .repository
+            .entries_since(user_id, &since)
+            .await
+            .inspect_err(|e| error!(error = %e, "Failed to read history"))?;
+
+        Ok(match person_id {
+            Some(person_id) => entries
+                .into_iter()
+                .filter(|entry| entry.person_id() == Some(person_id))
+                .collect(),
+            None => entries,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    use async_trait::async_trait;
+
+    #[derive(Default)]
+    struct MockRepository {
+        entries: Mutex<Vec<(String, HistoryEntry)>>,
+        fail: bool,
+    }
+
+    #[async_trait]
+    impl HistoryRepository for MockRepository {
+        async fn record(&self, user_id: &str, entry: &HistoryEntry) -> Result<(), DomainError> {
+            if self.fail {
+                return Err(DomainError::RepositoryError("down".to_string()));
+            }
+            self.entries
+                .lock()
+                .unwrap()
+                .push((user_id.to_string(), entry.clone()));
+            Ok(())
+        }
+
+        async fn entries_since(
+            &self,
+            user_id: &str,
+            since: &str,
+        ) -> Result<Vec<HistoryEntry>, DomainError> {
+            if self.fail {
+                return Err(DomainError::RepositoryError("down".to_string()));
+            }
+            Ok(self
+                .entries
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|(user, entry)| user == user_id && entry.added_at() >= since)
+                .map(|(_, entry)| entry.clone())
+                .collect())
+        }
+
+        async fn delete(&self, user_id: &str) -> Result<(), DomainError> {
+            self.entries
+                .lock()
+                .unwrap()
+                .retain(|(user, _)| user != user_id);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn returns_items_added_today() {
+        let service = HistoryService::new(Arc::new(MockRepository::default()));
+        service
+            .record("user-1", None, "Milch", "2024-01-26T18:00:00Z")
+            .await;
+        service
+            .record("user-1", None, "Eier", "2024-01-27T09:00:00Z")
+            .await;
+
+        let today = service
+            .added_in("user-1", None, HistoryPeriod::Today, "2024-01-27T10:00:00Z")
+            .await
+            .unwrap();
+        let week = service
+            .added_in(
+                "user-1",
+                None,
+                HistoryPeriod::ThisWeek,
+                "2024-01-27T10:00:00Z",
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            today,
+            vec![HistoryEntry::new("Eier", "2024-01-27T09:00:00Z")]
+        );
+        assert_eq!(week.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn recognized_speaker_hears_own_adds() {
+        let service = HistoryService::new(Arc::new(MockRepository::default()));
+        service
+            .record("user-1", Some("person-a"), "Milch", "2024-01-27T08:00:00Z")
+            .await;
+        service
+            .record("user-1", Some("person-b"), "Eier", "2024-01-27T09:00:00Z")
+            .await;
+
+        let own = service
+            .added_in(
+                "user-1",
+                Some("person-a"),
+                HistoryPeriod::Today,
+                "2024-01-27T10:00:00Z",
+            )
+            .await
+            .unwrap();
+        let household = service
+            .added_in("user-1", None, HistoryPeriod::Today, "2024-01-27T10:00:00Z")
+            .await
+            .unwrap();
+
+        assert_eq!(
+            own,
+            vec![HistoryEntry::new("Milch", "2024-01-27T08:00:00Z").with_person_id("person-a")]
+        );
+        assert_eq!(household.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn store_failure_returns_store_error() {
+        let service = HistoryService::new(Arc::new(MockRepository {
+            fail: true,
+            ..Default::default()
+        }));
+
+        service
+            .record("user-1", None, "Milch", "2024-01-27T09:00:00Z")
+            .await;
+        let result = service
+            .added_in("user-1", None, HistoryPeriod::Today, "2024-01-27T10:00:00Z")
+            .await;
+
+        assert!(matches!(result, Err(DomainError::RepositoryError(_))));
+    }
+}
@@ -0,0 +1,180 @@
+/// Words that introduce or pad a free-form German shopping request
+/// ("ich brauch noch so Zeug für ...") and are dropped before the first item.
+const LEADING_FILLERS: &[&str] = &[
+    "ich",
+    "wir",
+    "man",
+    "brauch",
+    "brauche",
+    "brauchen",
+    "bräuchte",
+    "bräuchten",
+    "noch",
+    "mal",
+    "bitte",
+    "so",
+    "etwas",
+    "was",
+    "zeug",
+    "sachen",
+    "kram",
+    "zutaten",
+    "kauf",
+    "kaufe",
+    "kaufen",
+    "besorg",
+    "besorge",
+    "besorgen",
+    "hol",
+    "hole",
+    "holen",
+    "muss",
+    "müssen",
+    "sollte",
+    "sollten",
+    "will",
+    "wollen",
+    "gern",
+    "gerne",
+    "auch",
+    "dann",
+    "also",
+    "äh",
+    "ähm",
+    "haben",
+    "hab",
+    "habe",
+    "keine",
+    "kein",
+    "keinen",
+    "fehlt",
+    "fehlen",
+    "uns",
+    "mir",
+    "es",
+    "für",
+    "zum",
+    "zur",
+];
+
+/// Words that pad the end of a free-form request ("... mehr", "... kaufen").
+const TRAILING_FILLERS: &[&str] = &[
+    "mehr",
+    "bitte",
+    "noch",
+    "kaufen",
+    "besorgen",
+    "holen",
+    "mitbringen",
+    "da",
+    "hier",
+    "oder",
+    "so",
+    "und",
+];
+
+/// Words that separate several items ("Milch und Eier").
+const SEPARATORS: &[&str] = &["und", "sowie", "plus"];
+
+/// Extracts likely item names from free-form German speech.
+///
+/// Alexa passes utterances that match no add sample verbatim, e.g. "ich
+/// brauch noch so Zeug für Lasagne" or "wir haben keine Milch und Eier
+/// mehr". The query is split into items at commas and "und", and the
+/// filler words around each item are dropped, leaving "Lasagne" or "Milch"
+/// and "Eier". Casing is left to [`super::ItemNameNormalizer`].
+pub struct ItemExtractor;
+
+impl ItemExtractor {
+    /// Returns the item names found in `query`, without duplicates and in
+    /// spoken order; empty if nothing but filler words was said.
+    pub fn extract(query: &str) -> Vec<String> {
+        let mut items: Vec<String> = Vec::new();
+        for segment in segments(query) {
+            let Some(item) = strip_fillers(&segment) else {
+                continue;
+            };
+            if !items.iter().any(|known| known.eq_ignore_ascii_case(&item)) {
+                items.push(item);
+            }
+        }
+        items
+    }
+}
+
+/// Splits the query into word lists at commas and separator words.
+fn segments(query: &str) -> Vec<Vec<&str>> {
+    let mut segments = Vec::new();
+    for part in query.split([',', ';']) {
+        let mut segment = Vec::new();
+        for word in part.split_whitespace() {
+            let word = word.trim_matches(|c: char| matches!(c, '.' | '!' | '?'));
+            if word.is_empty() {
+                continue;
+            }
+            if SEPARATORS.contains(&word.to_lowercase().as_str()) {
+                segments.push(std::mem::take(&mut segment));
+            } else {
+                segment.push(word);
+            }
+        }
+        segments.push(segment);
+    }
+    segments
+}
+
+/// Drops filler words from both ends of a segment.
+fn strip_fillers(words: &[&str]) -> Option<String> {
+    let is_filler = |fillers: &[&str], word: &&str| fillers.contains(&word.to_lowercase().as_str());
+    let start = words
+        .iter()
+        .position(|word| !is_filler(LEADING_FILLERS, word))?;
+    let end = words
+        .iter()
+        .rposition(|word| !is_filler(TRAILING_FILLERS, word))?;
+    (start <= end).then(|| words[start..=end].join(" "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extracts_item_from_padded_request() {
+        assert_eq!(
+            ItemExtractor::extract("ich brauch noch so Zeug für Lasagne"),
+            vec!["Lasagne"]
+        );
+    }
+
+    #[test]
+    fn splits_several_items() {
+        assert_eq!(
+            ItemExtractor::extract("wir haben keine Milch und Eier mehr"),
+            vec!["Milch", "Eier"]
+        );
+        assert_eq!(
+            ItemExtractor::extract("Butter, Mehl sowie Zucker"),
+            vec!["Butter", "Mehl", "Zucker"]
+        );
+    }
+
+    #[test]
+    fn keeps_words_inside_an_item() {
+        assert_eq!(
+            ItemExtractor::extract("besorg mal Brot mit Körnern"),
+            vec!["Brot mit Körnern"]
+        );
+    }
+
+    #[test]
+    fn drops_duplicates() {
+        assert_eq!(ItemExtractor::extract("Milch und milch"), vec!["Milch"]);
+    }
+
+    #[test]
+    fn returns_nothing_for_fillers_only() {
+        assert!(ItemExtractor::extract("ich brauch noch so Zeug").is_empty());
+        assert!(ItemExtractor::extract("").is_empty());
+    }
+}
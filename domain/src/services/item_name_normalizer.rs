@@ -0,0 +1,279 @@
+use crate::models::ShoppingListItem;
+
+/// German articles dropped from the start of a name ("die Milch").
+const GERMAN_ARTICLES: &[&str] = &[
+    "der", "die", "das", "den", "dem", "des", "ein", "eine", "einen", "einem", "einer", "eines",
+];
+
+/// German words that stay lowercase between nouns ("Brot mit Körnern").
+const GERMAN_CONNECTORS: &[&str] = &[
+    "und", "oder", "mit", "ohne", "für", "von", "vom", "aus", "in", "im", "zum", "zur", "am", "an",
+    "auf", "nach",
+];
+
+/// German adjective stems that stay lowercase before a noun
+/// ("frische Eier", "geriebener Käse").
+const GERMAN_ADJECTIVES: &[&str] = &[
+    "frisch",
+    "rot",
+    "grün",
+    "gelb",
+    "weiß",
+    "schwarz",
+    "braun",
+    "klein",
+    "groß",
+    "fein",
+    "grob",
+    "ganz",
+    "halb",
+    "hell",
+    "dunkel",
+    "mild",
+    "scharf",
+    "süß",
+    "sauer",
+    "jung",
+    "alt",
+    "fettarm",
+    "vegan",
+    "laktosefrei",
+    "glutenfrei",
+    "gemahlen",
+    "gehackt",
+    "gerieben",
+    "geräuchert",
+    "getrocknet",
+    "geschält",
+    "passiert",
+    "tiefgekühlt",
+];
+
+/// Acronyms kept in capitals ("bbq Sauce" -> "BBQ sauce"); other words
+/// in capitals are treated as shouted and cased like any other word.
+const ACRONYMS: &[&str] = &[
+    "AA", "AAA", "BBQ", "ESL", "LED", "TK", "UHT", "USB", "WC", "XL", "XXL",
+];
+
+/// German inflection endings accepted after an adjective stem.
+const GERMAN_ENDINGS: &[&str] = &["", "e", "en", "er", "es", "em"];
+
+/// Applies language-specific casing to spoken item names.
+///
+/// Alexa transcribes item names inconsistently ("milch", "Milch",
+/// "frische Eier"), so names are normalized before they reach the list:
+/// German nouns are capitalized while adjectives and connectors stay
+/// lowercase, and a leading article is dropped; English names are
+/// lowercased. Other languages only get their whitespace collapsed.
+///
+/// Control and invisible characters are removed first (see
+/// [`ShoppingListItem::sanitize`]).
+pub struct ItemNameNormalizer;
+
+impl ItemNameNormalizer {
+    /// Normalizes an item name for the given Alexa locale (e.g. "de-DE").
+    pub fn normalize(name: &str, locale: &str) -> String {
+        let name = ShoppingListItem::sanitize(name);
+        let words: Vec<&str> = name.split_whitespace().collect();
+        let language = locale
+            .split(['-', '_'])
+            .next()
+            .unwrap_or_default()
+            .to_lowercase();
+
+        match language.as_str() {
+            "de" => normalize_german(&words),
+            "en" => words
+                .iter()
+                .map(|word| {
+                    if is_acronym(word) {
+                        word.to_uppercase()
+                    } else {
+                        word.to_lowercase()
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join(" "),
+            _ => words.join(" "),
+        }
+    }
+}
+
+fn normalize_german(words: &[&str]) -> String {
+    // "die Milch" is Milch; a lone "Das" stays, it might be the item
+    let words = match words {
+        [article, rest @ ..]
+            if !rest.is_empty() && GERMAN_ARTICLES.contains(&article.to_lowercase().as_str()) =>
+        {
+            rest
+        }
+        _ => words,
+    };
+
+    words
+        .iter()
+        .enumerate()
+        .map(|(index, word)| {
+            let lower = word.to_lowercase();
+            let next_is_noun = words
+                .get(index + 1)
+                .is_some_and(|next| !GERMAN_CONNECTORS.contains(&next.to_lowercase().as_str()));
+
+            if is_acronym(word) {
+                word.to_uppercase()
+            } else if word.chars().any(|c| c.is_ascii_digit()) {
+                word.to_string()
+            } else if GERMAN_CONNECTORS.contains(&lower.as_str())
+                || (next_is_noun && is_german_adjective(&lower))
+            {
+                lower
+            } else {
+                capitalize_parts(&lower)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn is_german_adjective(word: &str) -> bool {
+    GERMAN_ADJECTIVES.iter().any(|stem| {
+        word.strip_prefix(stem)
+            .is_some_and(|ending| GERMAN_ENDINGS.contains(&ending))
+    })
+}
+
+/// Capitalizes each hyphen-separated part ("h-milch" -> "H-Milch").
+fn capitalize_parts(word: &str) -> String {
+    word.split('-')
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+/// Returns true for known acronyms such as "BBQ" or "UHT", in any case.
+fn is_acronym(word: &str) -> bool {
+    let letters: String = word
+        .chars()
+        .filter(|c| c.is_alphabetic())
+        .flat_map(char::to_uppercase)
+        .collect();
+    ACRONYMS.contains(&letters.as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn capitalizes_german_nouns() {
+        assert_eq!(ItemNameNormalizer::normalize("milch", "de-DE"), "Milch");
+        assert_eq!(
+            ItemNameNormalizer::normalize("MEHL", "de-DE"),
+            "Mehl",
+            "shouted words are not acronyms"
+        );
+        assert_eq!(
+            ItemNameNormalizer::normalize("uht Milch", "de-DE"),
+            "UHT Milch"
+        );
+        assert_eq!(ItemNameNormalizer::normalize("kÄse", "de-AT"), "Käse");
+    }
+
+    #[test]
+    fn keeps_german_adjectives_lowercase() {
+        assert_eq!(
+            ItemNameNormalizer::normalize("Frische eier", "de-DE"),
+            "frische Eier"
+        );
+        assert_eq!(
+            ItemNameNormalizer::normalize("geriebener käse", "de-DE"),
+            "geriebener Käse"
+        );
+    }
+
+    #[test]
+    fn adjective_without_following_noun_is_capitalized() {
+        assert_eq!(ItemNameNormalizer::normalize("rot", "de-DE"), "Rot");
+    }
+
+    #[test]
+    fn keeps_german_connectors_lowercase() {
+        assert_eq!(
+            ItemNameNormalizer::normalize("Brot Mit körnern", "de-DE"),
+            "Brot mit Körnern"
+        );
+        assert_eq!(
+            ItemNameNormalizer::normalize("salz und pfeffer", "de-DE"),
+            "Salz und Pfeffer"
+        );
+    }
+
+    #[test]
+    fn capitalizes_hyphenated_parts_and_keeps_numbers() {
+        assert_eq!(ItemNameNormalizer::normalize("h-milch", "de-DE"), "H-Milch");
+        assert_eq!(
+            ItemNameNormalizer::normalize("2 liter milch", "de-DE"),
+            "2 Liter Milch"
+        );
+    }
+
+    #[test]
+    fn drops_leading_german_article() {
+        assert_eq!(ItemNameNormalizer::normalize("die milch", "de-DE"), "Milch");
+        assert_eq!(
+            ItemNameNormalizer::normalize("Einen frischen Salat", "de-DE"),
+            "frischen Salat"
+        );
+        assert_eq!(ItemNameNormalizer::normalize("das", "de-DE"), "Das");
+    }
+
+    #[test]
+    fn drops_every_inflected_german_article() {
+        for article in ["dem", "des", "einem", "einer", "eines"] {
+            assert_eq!(
+                ItemNameNormalizer::normalize(&format!("{} käse", article), "de-DE"),
+                "Käse",
+                "{}",
+                article
+            );
+        }
+    }
+
+    #[test]
+    fn removes_control_and_invisible_characters() {
+        assert_eq!(
+            ItemNameNormalizer::normalize("hafer\u{200b}milch\n\u{0}eier", "de-DE"),
+            "Hafermilch Eier"
+        );
+        assert_eq!(
+            ItemNameNormalizer::normalize("\u{feff}pain\u{7}de mie", "fr-FR"),
+            "pain de mie"
+        );
+    }
+
+    #[test]
+    fn lowercases_english_names() {
+        assert_eq!(
+            ItemNameNormalizer::normalize("Fresh Eggs", "en-GB"),
+            "fresh eggs"
+        );
+        assert_eq!(
+            ItemNameNormalizer::normalize("BBQ Sauce", "en-US"),
+            "BBQ sauce"
+        );
+    }
+
+    #[test]
+    fn other_languages_only_collapse_whitespace() {
+        assert_eq!(
+            ItemNameNormalizer::normalize("  Pain   de mie ", "fr-FR"),
+            "Pain de mie"
+        );
+    }
+}
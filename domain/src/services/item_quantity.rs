@@ -0,0 +1,108 @@
+/// German numerals from "zwei" to "zwölf", for 2 to 12.
+const GERMAN_NUMERALS: &[&str] = &[
+    "zwei", "drei", "vier", "fünf", "sechs", "sieben", "acht", "neun", "zehn", "elf", "zwölf",
+];
+
+/// Units that make a leading number part of the name: "zwei Liter Milch"
+/// is one entry, not two of "Liter Milch".
+const UNITS: &[&str] = &[
+    "liter",
+    "l",
+    "milliliter",
+    "ml",
+    "kilo",
+    "kilogramm",
+    "kg",
+    "gramm",
+    "g",
+    "pfund",
+    "packung",
+    "packungen",
+    "flasche",
+    "flaschen",
+    "dose",
+    "dosen",
+    "glas",
+    "gläser",
+    "becher",
+    "tüte",
+    "tüten",
+    "bund",
+    "stück",
+    "scheiben",
+];
+
+/// Largest count split off a name; larger numbers stay part of it.
+const MAX_QUANTITY: u32 = 99;
+
+/// Splits a spoken count off an item name: "drei Zitronen" are three of
+/// "Zitronen".
+pub struct ItemQuantity;
+
+impl ItemQuantity {
+    /// Returns the count at the start of `name` and the rest of the name.
+    ///
+    /// Digits ("3 Zitronen") and German numerals up to "zwölf" count; a
+    /// count of one, a number followed by a unit ("500 Gramm Mehl") or a
+    /// number alone leave the name as it is.
+    pub fn split(name: &str) -> (Option<u32>, &str) {
+        let name = name.trim();
+        let Some((first, rest)) = name.split_once(char::is_whitespace) else {
+            return (None, name);
+        };
+        let rest = rest.trim_start();
+        let next = rest.split_whitespace().next().unwrap_or_default();
+        if UNITS.contains(&next.to_lowercase().as_str()) {
+            return (None, name);
+        }
+
+        match Self::count(first) {
+            Some(count) if count > 1 => (Some(count), rest),
+            _ => (None, name),
+        }
+    }
+
+    fn count(word: &str) -> Option<u32> {
+        let word = word.to_lowercase();
+        let count = match word.parse::<u32>() {
+            Ok(count) => count,
+            Err(_) => {
+                GERMAN_NUMERALS
+                    .iter()
+                    .position(|numeral| *numeral == word)? as u32
+                    + 2
+            }
+        };
+        (count <= MAX_QUANTITY).then_some(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_digits_and_numerals() {
+        assert_eq!(ItemQuantity::split("3 Zitronen"), (Some(3), "Zitronen"));
+        assert_eq!(ItemQuantity::split("drei Zitronen"), (Some(3), "Zitronen"));
+        assert_eq!(
+            ItemQuantity::split("Zwölf frische Eier"),
+            (Some(12), "frische Eier")
+        );
+    }
+
+    #[test]
+    fn keeps_names_without_count() {
+        for name in [
+            "Zitronen",
+            "1 Zitrone",
+            "7",
+            "zwei Liter Milch",
+            "500 Gramm Mehl",
+            "100 Servietten",
+            "Dreikornbrot",
+        ] {
+            assert_eq!(ItemQuantity::split(name), (None, name), "{name}");
+        }
+    }
+}
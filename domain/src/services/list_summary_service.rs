@@ -0,0 +1,116 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use tracing::info;
+
+use crate::models::{DomainError, ListSummary, ShoppingListEntry};
+
+use super::{CategoryClassifier, ReadListService};
+
+/// Service summarizing the shopping list ("7 Artikel, davon 3 abgehakt").
+pub struct ListSummaryService {
+    read_list_service: Arc<ReadListService>,
+    classifier: Arc<CategoryClassifier>,
+}
+
+impl ListSummaryService {
+    /// Creates a new ListSummaryService reading through the given service.
+    ///
+    /// Open entries are sorted into the built-in categories unless another
+    /// classifier is set with [`Self::with_classifier`].
+    pub fn new(read_list_service: Arc<ReadListService>) -> Self {
+        Self {
+            read_list_service,
+            classifier: Arc::new(CategoryClassifier::new()),
+        }
+    }
+
+    /// Sets the classifier that picks the category of open entries.
+    pub fn with_classifier(mut self, classifier: Arc<CategoryClassifier>) -> Self {
+        self.classifier = classifier;
+        self
+    }
+
+    /// Reads the list and counts its entries.
+    ///
+    /// # Errors
+    /// Returns the error of reading the list.
+    pub async fn execute(&self) -> Result<ListSummary, DomainError> {
+        let entries = self.read_list_service.execute().await?;
+        let summary = self.summarize(&entries);
+        info!(
+            total = summary.total(),
+            checked_off = summary.checked_off(),
+            "Shopping list summarized"
+        );
+        Ok(summary)
+    }
+
+    fn summarize(&self, entries: &[ShoppingListEntry]) -> ListSummary {
+        let checked_off = entries.iter().filter(|entry| entry.is_owned()).count();
+
+        let mut categories = HashMap::new();
+        for entry in entries.iter().filter(|entry| !entry.is_owned()) {
+            if let Some(category) = self.classifier.classify(entry.name()) {
+                *categories.entry(category).or_insert(0) += 1;
+            }
+        }
+
+        ListSummary::new(entries.len(), checked_off, categories.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{AddedItem, Category, DomainError, ShoppingListItem};
+    use crate::ports::ShoppingListRepository;
+    use async_trait::async_trait;
+
+    struct MockRepository {
+        entries: Vec<ShoppingListEntry>,
+    }
+
+    #[async_trait]
+    impl ShoppingListRepository for MockRepository {
+        async fn add_item(&self, _item: &ShoppingListItem) -> Result<Vec<AddedItem>, DomainError> {
+            Ok(Vec::new())
+        }
+
+        async fn list_items(&self) -> Result<Vec<ShoppingListEntry>, DomainError> {
+            Ok(self.entries.clone())
+        }
+
+        async fn remove_item(&self, _id: &str) -> Result<(), DomainError> {
+            Ok(())
+        }
+    }
+
+    fn service(entries: Vec<ShoppingListEntry>) -> ListSummaryService {
+        let repository = Arc::new(MockRepository { entries });
+        ListSummaryService::new(Arc::new(ReadListService::new(repository)))
+    }
+
+    #[tokio::test]
+    async fn counts_entries_and_open_categories() {
+        let service = service(vec![
+            ShoppingListEntry::new("1", "Milch", false),
+            ShoppingListEntry::new("2", "Äpfel", false),
+            ShoppingListEntry::new("3", "Joghurt", false),
+            ShoppingListEntry::new("4", "Käse", true),
+            ShoppingListEntry::new("5", "Spülmittel", true),
+        ]);
+
+        let summary = service.execute().await.unwrap();
+
+        assert_eq!(summary.total(), 5);
+        assert_eq!(summary.checked_off(), 2);
+        assert_eq!(summary.open_categories()[0], (Category::Dairy, 2));
+    }
+
+    #[tokio::test]
+    async fn empty_list_has_empty_summary() {
+        let summary = service(Vec::new()).execute().await.unwrap();
+        assert_eq!(summary, ListSummary::default());
+    }
+}
@@ -0,0 +1,386 @@
+use std::sync::Arc;
+
+use tracing::{info, warn};
+
+use crate::models::{AddItemOutcome, DomainError, ListName, PantryStaples, TransferOutcome};
+
+use super::{AddItemService, OccasionListService};
+
+/// Service that moves a named list onto the Cookidoo shopping list.
+///
+/// Named lists such as "Party-Liste" are collected in the skill's own store
+/// ahead of time; on demand their items are added to the shopping list and
+/// taken off the named list. Pantry staples set with
+/// [`Self::with_pantry`] are taken off without being added.
+pub struct ListTransferService {
+    occasion_lists: Arc<OccasionListService>,
+    add_item_service: Arc<AddItemService>,
+    pantry: PantryStaples,
+}
+
+impl ListTransferService {
+    /// Creates a new ListTransferService reading from `occasion_lists` and
+    /// adding through `add_item_service`.
+    pub fn new(
+        occasion_lists: Arc<OccasionListService>,
+        add_item_service: Arc<AddItemService>,
+    ) -> Self {
+        Self {
+            occasion_lists,
+            add_item_service,
+            pantry: PantryStaples::default(),
+        }
+    }
+
+    /// Sets the items the user always has at home, which are skipped.
+    pub fn with_pantry(mut self, pantry: PantryStaples) -> Self {
+        self.pantry = pantry;
+        self
+    }
+
+    /// Adds every item of the user's list to the shopping list.
+    ///
+    /// Items are removed from the named list only once they are on the
+    /// shopping list, so a failed transfer can simply be asked for again.
+    /// The transfer stops at the first backend failure; items with names the
+    /// shopping list rejects stay on the named list. Pantry staples are
+    /// not added; once the transfer got through they are taken off the
+    /// named list as well and reported as skipped.
+    ///
+    /// # Errors
+    /// Returns the store error if the named list cannot be read.
+    pub async fn execute(
+        &self,
+        user_id: &str,
+        list: &ListName,
+    ) -> Result<TransferOutcome, DomainError> {
+        let entries = self.occasion_lists.read(user_id, list).await?;
+
+        let (staples, entries): (Vec<_>, Vec<_>) = entries
+            .into_iter()
+            .partition(|entry| self.pantry.contains(entry.name()));
+        let skipped: Vec<String> = staples
+            .iter()
+            .map(|entry| entry.name().to_string())
+            .collect();
+
+        let mut transferred = Vec::new();
+        let mut failed = Vec::new();
+        let mut stopped = false;
+        for entry in &entries {
+            if stopped {
+                failed.push(entry.name().to_string());
+                continue;
+            }
+            match self.add_item_service.execute(entry.name()).await {
+                outcome if outcome.is_success() => {
+                    if let Err(message) = self
+                        .occasion_lists
+                        .remove(user_id, list, entry.name())
+                        .await
+                    {
+                        warn!(error = %message, list = %list.key(), "Transferred item stays on occasion list");
+                    }
+                    transferred.push(entry.name().to_string());
+                }
+                AddItemOutcome::InvalidName { reason } => {
+                    warn!(item_name = %entry.name(), reason = %reason, "Skipping item that cannot be transferred");
+                    failed.push(entry.name().to_string());
+                }
+                outcome => {
+                    warn!(outcome = ?outcome, list = %list.key(), "Stopping list transfer");
+                    failed.push(entry.name().to_string());
+                    stopped = true;
+                }
+            }
+        }
+
+        info!(
+            list = %list.key(),
+            transferred = transferred.len(),
+            total = entries.len(),
+            skipped = skipped.len(),
+            "Occasion list transferred"
+        );
+
+        if transferred.is_empty() && !failed.is_empty() {
+            // Nothing got through, so the staples stay with the rest
+            return Ok(TransferOutcome::new(transferred, failed, Vec::new()));
+        }
+        self.remove_staples(user_id, list, &skipped).await;
+        Ok(TransferOutcome::new(transferred, failed, skipped))
+    }
+
+    /// Takes the skipped pantry staples off the named list.
+    async fn remove_staples(&self, user_id: &str, list: &ListName, staples: &[String]) {
+        for staple in staples {
+            if let Err(message) = self.occasion_lists.remove(user_id, list, staple).await {
+                warn!(error = %message, list = %list.key(), "Pantry staple stays on occasion list");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Mutex;
+
+    use async_trait::async_trait;
+
+    use crate::models::{AddedItem, DomainError, ShoppingListEntry, ShoppingListItem};
+    use crate::ports::{OccasionListStore, ShoppingListRepository};
+
+    #[derive(Default)]
+    struct MockStore {
+        lists: Mutex<HashMap<String, Vec<String>>>,
+    }
+
+    impl MockStore {
+        fn with_items(list: &ListName, names: &[&str]) -> Self {
+            let store = Self::default();
+            store.lists.lock().unwrap().insert(
+                list.key().to_string(),
+                names.iter().map(|name| name.to_string()).collect(),
+            );
+            store
+        }
+
+        fn names(&self, list: &ListName) -> Vec<String> {
+            self.lists
+                .lock()
+                .unwrap()
+                .get(list.key())
+                .cloned()
+                .unwrap_or_default()
+        }
+    }
+
+    #[async_trait]
+    impl OccasionListStore for MockStore {
+        async fn add(
+            &self,
+            _user_id: &str,
+            list: &ListName,
+            item: &ShoppingListItem,
+        ) -> Result<(), DomainError> {
+            self.lists
+                .lock()
+                .unwrap()
+                .entry(list.key().to_string())
+                .or_default()
+                .push(item.name().to_string());
+            Ok(())
+        }
+
+        async fn items(
+            &self,
+            _user_id: &str,
+            list: &ListName,
+        ) -> Result<Vec<ShoppingListEntry>, DomainError> {
+            Ok(self
+                .names(list)
+                .iter()
+                .enumerate()
+                .map(|(i, name)| ShoppingListEntry::new(i.to_string(), name, false))
+                .collect())
+        }
+
+        async fn remove(
+            &self,
+            _user_id: &str,
+            list: &ListName,
+            item_name: &str,
+        ) -> Result<bool, DomainError> {
+            let mut lists = self.lists.lock().unwrap();
+            let Some(names) = lists.get_mut(list.key()) else {
+                return Ok(false);
+            };
+            match names.iter().position(|name| name == item_name) {
+                Some(index) => {
+                    names.remove(index);
+                    Ok(true)
+                }
+                None => Ok(false),
+            }
+        }
+
+        async fn delete(&self, _user_id: &str) -> Result<(), DomainError> {
+            self.lists.lock().unwrap().clear();
+            Ok(())
+        }
+    }
+
+    /// Repository accepting the first `capacity` adds and failing afterwards.
+    struct MockRepository {
+        added: Mutex<Vec<String>>,
+        capacity: usize,
+        calls: AtomicUsize,
+    }
+
+    impl MockRepository {
+        fn new(capacity: usize) -> Self {
+            Self {
+                added: Mutex::new(Vec::new()),
+                capacity,
+                calls: AtomicUsize::new(0),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl ShoppingListRepository for MockRepository {
+        async fn add_item(&self, item: &ShoppingListItem) -> Result<Vec<AddedItem>, DomainError> {
+            if self.calls.fetch_add(1, Ordering::SeqCst) >= self.capacity {
+                return Err(DomainError::RepositoryError("down".to_string()));
+            }
+            self.added.lock().unwrap().push(item.name().to_string());
+            Ok(Vec::new())
+        }
+
+        async fn list_items(&self) -> Result<Vec<ShoppingListEntry>, DomainError> {
+            Ok(Vec::new())
+        }
+
+        async fn remove_item(&self, _id: &str) -> Result<(), DomainError> {
+            Ok(())
+        }
+    }
+
+    fn party() -> ListName {
+        ListName::new("Party-Liste").unwrap()
+    }
+
+    fn service(store: Arc<MockStore>, repository: Arc<MockRepository>) -> ListTransferService {
+        ListTransferService::new(
+            Arc::new(OccasionListService::new(store)),
+            Arc::new(AddItemService::new(repository)),
+        )
+    }
+
+    fn names(items: &[&str]) -> Vec<String> {
+        items.iter().map(|item| item.to_string()).collect()
+    }
+
+    #[tokio::test]
+    async fn moves_all_items_to_shopping_list() {
+        let store = Arc::new(MockStore::with_items(&party(), &["Chips", "Cola"]));
+        let repository = Arc::new(MockRepository::new(usize::MAX));
+
+        let outcome = service(store.clone(), repository.clone())
+            .execute("user-1", &party())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            outcome,
+            TransferOutcome::new(names(&["Chips", "Cola"]), Vec::new(), Vec::new())
+        );
+        assert_eq!(*repository.added.lock().unwrap(), ["Chips", "Cola"]);
+        assert!(store.names(&party()).is_empty());
+    }
+
+    #[tokio::test]
+    async fn empty_list_is_reported() {
+        let outcome = service(
+            Arc::new(MockStore::default()),
+            Arc::new(MockRepository::new(usize::MAX)),
+        )
+        .execute("user-1", &party())
+        .await
+        .unwrap();
+
+        assert!(outcome.is_empty());
+    }
+
+    #[tokio::test]
+    async fn keeps_items_that_were_not_transferred() {
+        let store = Arc::new(MockStore::with_items(&party(), &["Chips", "Cola", "Salsa"]));
+
+        let outcome = service(store.clone(), Arc::new(MockRepository::new(1)))
+            .execute("user-1", &party())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            outcome,
+            TransferOutcome::new(names(&["Chips"]), names(&["Cola", "Salsa"]), Vec::new())
+        );
+        assert_eq!(store.names(&party()), ["Cola", "Salsa"]);
+    }
+
+    #[tokio::test]
+    async fn unreachable_shopping_list_leaves_list_unchanged() {
+        let store = Arc::new(MockStore::with_items(&party(), &["Chips"]));
+
+        let outcome = service(store.clone(), Arc::new(MockRepository::new(0)))
+            .execute("user-1", &party())
+            .await
+            .unwrap();
+
+        assert!(outcome.is_failure());
+        assert_eq!(store.names(&party()), ["Chips"]);
+    }
+
+    #[tokio::test]
+    async fn skips_pantry_staples() {
+        let store = Arc::new(MockStore::with_items(
+            &party(),
+            &["Chips", "Salz", "Cola", "pfeffer"],
+        ));
+        let repository = Arc::new(MockRepository::new(usize::MAX));
+
+        let outcome = service(store.clone(), repository.clone())
+            .with_pantry(PantryStaples::parse("Salz, Pfeffer, Öl"))
+            .execute("user-1", &party())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            outcome,
+            TransferOutcome::new(
+                names(&["Chips", "Cola"]),
+                Vec::new(),
+                names(&["Salz", "pfeffer"])
+            )
+        );
+        assert_eq!(*repository.added.lock().unwrap(), ["Chips", "Cola"]);
+        assert!(store.names(&party()).is_empty());
+    }
+
+    #[tokio::test]
+    async fn list_of_only_pantry_staples_adds_nothing() {
+        let store = Arc::new(MockStore::with_items(&party(), &["Salz"]));
+        let repository = Arc::new(MockRepository::new(usize::MAX));
+
+        let outcome = service(store.clone(), repository.clone())
+            .with_pantry(PantryStaples::parse("Salz"))
+            .execute("user-1", &party())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            outcome,
+            TransferOutcome::new(Vec::new(), Vec::new(), names(&["Salz"]))
+        );
+        assert!(repository.added.lock().unwrap().is_empty());
+        assert!(store.names(&party()).is_empty());
+    }
+
+    #[tokio::test]
+    async fn failed_transfer_keeps_pantry_staples() {
+        let store = Arc::new(MockStore::with_items(&party(), &["Chips", "Salz"]));
+
+        let outcome = service(store.clone(), Arc::new(MockRepository::new(0)))
+            .with_pantry(PantryStaples::parse("Salz"))
+            .execute("user-1", &party())
+            .await
+            .unwrap();
+
+        assert!(outcome.is_failure());
+        assert!(outcome.skipped().is_empty());
+        assert_eq!(store.names(&party()), ["Chips", "Salz"]);
+    }
+}
@@ -0,0 +1,147 @@
+use std::sync::Arc;
+
+use tracing::{debug, info, warn};
+
+use crate::models::{DomainError, Market};
+use crate::ports::DeviceAddressLookup;
+
+/// Service for choosing the Cookidoo market of a request.
+///
+/// A configured market always wins. Otherwise the market is derived from
+/// the country of the device's address, falling back to Germany when the
+/// address is unavailable or Cookidoo is not offered in that country.
+pub struct MarketService {
+    lookup: Arc<dyn DeviceAddressLookup>,
+    configured: Option<Market>,
+}
+
+impl MarketService {
+    /// Creates a new MarketService reading device addresses from `lookup`.
+    pub fn new(lookup: Arc<dyn DeviceAddressLookup>) -> Self {
+        Self {
+            lookup,
+            configured: None,
+        }
+    }
+
+    /// Uses `market` for every request instead of detecting it.
+    pub fn with_market(mut self, market: Market) -> Self {
+        self.configured = Some(market);
+        self
+    }
+
+    /// Returns the market for a request from `device_id`.
+    ///
+    /// `api_access` is the request's API endpoint and access token; pass
+    /// `None` when the user has not granted access to the device address.
+    pub async fn market(
+        &self,
+        api_access: Option<(&str, &str)>,
+        device_id: Option<&str>,
+    ) -> Market {
+        if let Some(market) = self.configured {
+            return market;
+        }
+
+        let (Some((api_endpoint, access_token)), Some(device_id)) = (api_access, device_id) else {
+            return Market::default();
+        };
+
+        match self
+            .lookup
+            .country_code(api_endpoint, access_token, device_id)
+            .await
+        {
+            Ok(Some(country_code)) => match Market::from_country_code(&country_code) {
+                Some(market) => {
+                    debug!(country_code = %country_code, "Market detected from device address");
+                    market
+                }
+                None => {
+                    info!(country_code = %country_code, "No Cookidoo market for device country");
+                    Market::default()
+                }
+            },
+            Ok(None) => Market::default(),
+            Err(DomainError::PermissionRequired(scope)) => {
+                info!(scope = %scope, "Device address permission missing");
+                Market::default()
+            }
+            Err(e) => {
+                warn!(error = %e, "Device address lookup failed, using default market");
+                Market::default()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+
+    struct MockLookup {
+        result: Result<Option<String>, DomainError>,
+    }
+
+    impl MockLookup {
+        fn returning(result: Result<Option<String>, DomainError>) -> Arc<Self> {
+            Arc::new(Self { result })
+        }
+    }
+
+    #[async_trait]
+    impl DeviceAddressLookup for MockLookup {
+        async fn country_code(
+            &self,
+            _api_endpoint: &str,
+            _access_token: &str,
+            _device_id: &str,
+        ) -> Result<Option<String>, DomainError> {
+            self.result.clone()
+        }
+    }
+
+    const ACCESS: Option<(&str, &str)> = Some(("https://api.amazonalexa.com", "token"));
+
+    #[tokio::test]
+    async fn detects_market_from_device_country() {
+        let service = MarketService::new(MockLookup::returning(Ok(Some("AT".to_string()))));
+
+        let market = service.market(ACCESS, Some("device-1")).await;
+
+        assert_eq!(market.language(), "de-AT");
+    }
+
+    #[tokio::test]
+    async fn configured_market_wins() {
+        let service = MarketService::new(MockLookup::returning(Ok(Some("AT".to_string()))))
+            .with_market(Market::from_country_code("CH").unwrap());
+
+        let market = service.market(ACCESS, Some("device-1")).await;
+
+        assert_eq!(market.country_code(), "CH");
+    }
+
+    #[tokio::test]
+    async fn falls_back_to_germany() {
+        let unsupported = MarketService::new(MockLookup::returning(Ok(Some("JP".to_string()))));
+        let denied = MarketService::new(MockLookup::returning(Err(
+            DomainError::PermissionRequired("scope".to_string()),
+        )));
+        let no_access = MarketService::new(MockLookup::returning(Ok(Some("AT".to_string()))));
+
+        assert_eq!(
+            unsupported.market(ACCESS, Some("device-1")).await,
+            Market::GERMANY
+        );
+        assert_eq!(
+            denied.market(ACCESS, Some("device-1")).await,
+            Market::GERMANY
+        );
+        assert_eq!(
+            no_access.market(None, Some("device-1")).await,
+            Market::GERMANY
+        );
+    }
+}
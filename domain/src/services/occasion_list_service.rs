@@ -0,0 +1,326 @@
+use std::sync::Arc;
+
+use tracing::{debug, error, info};
+
+use super::AliasDictionary;
+use crate::models::{DomainError, ListName, ShoppingListEntry, ShoppingListItem};
+use crate::ports::{ItemDictionary, OccasionListStore};
+
+/// Service for secondary lists such as a birthday or gift list.
+///
+/// These lists live in the skill's own store, next to the Cookidoo shopping
+/// list, and are addressed by the list name the user speaks.
+pub struct OccasionListService {
+    store: Arc<dyn OccasionListStore>,
+    dictionary: Arc<dyn ItemDictionary>,
+}
+
+impl OccasionListService {
+    /// Creates a new OccasionListService with the given store.
+    ///
+    /// Aliases are resolved with the built-in dictionary unless another is
+    /// set with [`Self::with_dictionary`].
+    pub fn new(store: Arc<dyn OccasionListStore>) -> Self {
+        Self {
+            store,
+            dictionary: Arc::new(AliasDictionary::new()),
+        }
+    }
+
+    /// Sets the dictionary that maps aliases to the name added to the list.
+    pub fn with_dictionary(mut self, dictionary: Arc<dyn ItemDictionary>) -> Self {
+        self.dictionary = dictionary;
+        self
+    }
+
+    /// Adds an item to the user's list under its canonical name, like the
+    /// shopping list, with the note written after it: "Kerzen (blau)".
+    ///
+    /// # Returns
+    /// The label the item was stored under.
+    ///
+    /// # Errors
+    /// Returns `DomainError::InvalidItemName` if the name or note is not
+    /// valid, or the store error.
+    pub async fn add(
+        &self,
+        user_id: &str,
+        list: &ListName,
+        item_name: &str,
+        note: Option<&str>,
+    ) -> Result<String, DomainError> {
+        let item_name = match self.dictionary.canonical(item_name.trim()) {
+            Some(canonical) => {
+                debug!(alias = %item_name, item_name = %canonical, "Item alias resolved");
+                canonical
+            }
+            None => item_name,
+        };
+        let item = ShoppingListItem::new(item_name)
+            .and_then(|item| item.with_note(note.unwrap_or_default()))
+            .inspect_err(|e| error!(error = %e, "Invalid item name provided"))?;
+
+        match self.store.add(user_id, list, &item).await {
+            Ok(()) => {
+                info!(list = %list.key(), item_name = %item.label(), "Item added to occasion list");
+                Ok(item.label())
+            }
+            Err(e) => {
+                error!(error = %e, list = %list.key(), "Failed to add item to occasion list");
+                Err(e)
+            }
+        }
+    }
+
+    /// Returns all entries on the user's list.
+    ///
+    /// # Returns
+    /// The entries, possibly none.
+    ///
+    /// # Errors
+    /// Returns the store error.
+    pub async fn read(
+        &self,
+        user_id: &str,
+        list: &ListName,
+    ) -> Result<Vec<ShoppingListEntry>, DomainError> {
+        match self.store.items(user_id, list).await {
+            Ok(entries) => {
+                info!(list = %list.key(), count = entries.len(), "Occasion list read");
+                Ok(entries)
+            }
+            Err(e) => {
+                error!(error = %e, list = %list.key(), "Failed to read occasion list");
+                Err(e)
+            }
+        }
+    }
+
+    /// Removes an item from the user's list, found by the spoken name or,
+    /// for an alias, by its canonical name.
+    ///
+    /// # Returns
+    /// The spoken name of the removed item.
+    ///
+    /// # Errors
+    /// Returns `DomainError::ItemNotFound` with the spoken name if the list
+    /// has no such item, or the store error.
+    pub async fn remove(
+        &self,
+        user_id: &str,
+        list: &ListName,
+        item_name: &str,
+    ) -> Result<String, DomainError> {
+        let item_name = item_name.trim();
+
+        let removed = match self.store.remove(user_id, list, item_name).await {
+            Ok(false) => match self.dictionary.canonical(item_name) {
+                Some(canonical) => self.store.remove(user_id, list, canonical).await,
+                None => Ok(false),
+            },
+            result => result,
+        };
+        match removed {
+            Ok(true) => {
+                info!(list = %list.key(), item_name = %item_name, "Item removed from occasion list");
+                Ok(item_name.to_string())
+            }
+            Ok(false) => {
+                info!(list = %list.key(), item_name = %item_name, "Item to remove not on occasion list");
+                Err(DomainError::ItemNotFound(item_name.to_string()))
+            }
+            Err(e) => {
+                error!(error = %e, list = %list.key(), "Failed to remove item from occasion list");
+                Err(e)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    use async_trait::async_trait;
+
+    #[derive(Default)]
+    struct MockStore {
+        lists: Mutex<HashMap<(String, String), Vec<String>>>,
+        fail: bool,
+    }
+
+    #[async_trait]
+    impl OccasionListStore for MockStore {
+        async fn add(
+            &self,
+            user_id: &str,
+            list: &ListName,
+            item: &ShoppingListItem,
+        ) -> Result<(), DomainError> {
+            if self.fail {
+                return Err(DomainError::RepositoryError("down".to_string()));
+            }
+            self.lists
+                .lock()
+                .unwrap()
+                .entry((user_id.to_string(), list.key().to_string()))
+                .or_default()
+                .push(item.label());
+            Ok(())
+        }
+
+        async fn items(
+            &self,
+            user_id: &str,
+            list: &ListName,
+        ) -> Result<Vec<ShoppingListEntry>, DomainError> {
+            if self.fail {
+                return Err(DomainError::RepositoryError("down".to_string()));
+            }
+            let lists = self.lists.lock().unwrap();
+            Ok(lists
+                .get(&(user_id.to_string(), list.key().to_string()))
+                .map(|names| {
+                    names
+                        .iter()
+                        .enumerate()
+                        .map(|(i, name)| ShoppingListEntry::new(i.to_string(), name, false))
+                        .collect()
+                })
+                .unwrap_or_default())
+        }
+
+        async fn remove(
+            &self,
+            user_id: &str,
+            list: &ListName,
+            item_name: &str,
+        ) -> Result<bool, DomainError> {
+            let mut lists = self.lists.lock().unwrap();
+            let Some(names) = lists.get_mut(&(user_id.to_string(), list.key().to_string())) else {
+                return Ok(false);
+            };
+            match names.iter().position(|name| name == item_name) {
+                Some(index) => {
+                    names.remove(index);
+                    Ok(true)
+                }
+                None => Ok(false),
+            }
+        }
+
+        async fn delete(&self, user_id: &str) -> Result<(), DomainError> {
+            self.lists
+                .lock()
+                .unwrap()
+                .retain(|(user, _), _| user != user_id);
+            Ok(())
+        }
+    }
+
+    fn birthday() -> ListName {
+        ListName::new("Geburtstagsliste").unwrap()
+    }
+
+    #[tokio::test]
+    async fn add_confirms_with_list_label() {
+        let service = OccasionListService::new(Arc::new(MockStore::default()));
+
+        let label = service
+            .add("user-1", &birthday(), "Kerzen", None)
+            .await
+            .unwrap();
+
+        assert_eq!(label, "Kerzen");
+    }
+
+    #[tokio::test]
+    async fn add_and_remove_resolve_aliases() {
+        let service = OccasionListService::new(Arc::new(MockStore::default()));
+
+        let label = service
+            .add("user-1", &birthday(), "Paradeiser", None)
+            .await
+            .unwrap();
+        assert_eq!(label, "Tomaten");
+
+        service
+            .remove("user-1", &birthday(), "Paradeiser")
+            .await
+            .unwrap();
+        assert!(service
+            .read("user-1", &birthday())
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn add_cleans_up_name_and_note() {
+        let service = OccasionListService::new(Arc::new(MockStore::default()));
+
+        let label = service
+            .add("user-1", &birthday(), "Ker\u{200b}zen", Some(" blau\n"))
+            .await
+            .unwrap();
+        let too_long = service
+            .add("user-1", &birthday(), "Kerzen", Some(&"x".repeat(200)))
+            .await;
+
+        assert_eq!(label, "Kerzen (blau)");
+        assert!(matches!(too_long, Err(DomainError::InvalidItemName(_))));
+    }
+
+    #[tokio::test]
+    async fn lists_are_kept_per_user() {
+        let service = OccasionListService::new(Arc::new(MockStore::default()));
+        service
+            .add("user-1", &birthday(), "Kerzen", None)
+            .await
+            .unwrap();
+
+        let own = service.read("user-1", &birthday()).await.unwrap();
+        let other = service.read("user-2", &birthday()).await.unwrap();
+
+        assert_eq!(own.len(), 1);
+        assert!(other.is_empty());
+    }
+
+    #[tokio::test]
+    async fn add_rejects_invalid_item() {
+        let service = OccasionListService::new(Arc::new(MockStore::default()));
+
+        let result = service.add("user-1", &birthday(), "   ", None).await;
+
+        assert!(matches!(result, Err(DomainError::InvalidItemName(_))));
+    }
+
+    #[tokio::test]
+    async fn remove_reports_removed_and_missing_items() {
+        let service = OccasionListService::new(Arc::new(MockStore::default()));
+        service
+            .add("user-1", &birthday(), "Kerzen", None)
+            .await
+            .unwrap();
+
+        let removed = service.remove("user-1", &birthday(), "Kerzen").await;
+        let missing = service.remove("user-1", &birthday(), "Kerzen").await;
+
+        assert_eq!(removed.unwrap(), "Kerzen");
+        assert!(matches!(missing, Err(DomainError::ItemNotFound(name)) if name == "Kerzen"));
+    }
+
+    #[tokio::test]
+    async fn store_failure_returns_store_error() {
+        let service = OccasionListService::new(Arc::new(MockStore {
+            fail: true,
+            ..Default::default()
+        }));
+
+        let result = service.read("user-1", &birthday()).await;
+
+        assert!(matches!(result, Err(DomainError::RepositoryError(_))));
+    }
+}
@@ -0,0 +1,221 @@
+use std::sync::Arc;
+
+use tracing::{error, info, warn};
+
+use crate::models::{DomainError, SettingChange, UserPreferences};
+use crate::ports::PreferencesRepository;
+
+/// Service for the settings a user changes by voice.
+///
+/// Settings changed by a recognized speaker are stored for that person
+/// (under `<user ID>#<person ID>`) and layered over the household's, so
+/// each voice in a household can, for example, use its own default list.
+pub struct PreferencesService {
+    repository: Arc<dyn PreferencesRepository>,
+}
+
+impl PreferencesService {
+    /// Creates a new PreferencesService with the given repository.
+    pub fn new(repository: Arc<dyn PreferencesRepository>) -> Self {
+        Self { repository }
+    }
+
+    /// Returns the preferences of the user, or of the recognized speaker
+    /// `person_id` with unset ones taken from the household.
+    ///
+    /// An unreadable store yields the defaults, so a preferences outage
+    /// only costs personalization, not the request.
+    pub async fn preferences(&self, user_id: &str, person_id: Option<&str>) -> UserPreferences {
+        let household = self.read(user_id).await;
+        match person_id {
+            Some(person_id) => self
+                .read(&person_key(user_id, person_id))
+                .await
+                .or(household),
+            None => household,
+        }
+    }
+
+    async fn read(&self, key: &str) -> UserPreferences {
+        self.repository.get(key).await.unwrap_or_else(|e| {
+            warn!(error = %e, "Failed to read preferences, using defaults");
+            UserPreferences::default()
+        })
+    }
+
+    /// Applies a setting change and stores it, for the recognized speaker
+    /// `person_id` only if given.
+    ///
+    /// # Returns
+    /// The change that was applied, for the adapter to confirm.
+    ///
+    /// # Errors
+    /// Returns the repository error if the preferences cannot be read or
+    /// saved.
+    pub async fn change(
+        &self,
+        user_id: &str,
+        person_id: Option<&str>,
+        change: &SettingChange,
+    ) -> Result<SettingChange, DomainError> {
+        let key = match person_id {
+            Some(person_id) => person_key(user_id, person_id),
+            None => user_id.to_string(),
+        };
+        let mut preferences = self.repository.get(&key).await.map_err(|e| {
+            error!(error = %e, "Failed to read preferences");
+            e
+        })?;
+        preferences.apply(change);
+
+        self.repository
+            .save(&key, &preferences)
+            .await
+            .map_err(|e| {
+                error!(error = %e, "Failed to save preferences");
+                e
+            })?;
+        info!(change = ?change, "Preferences changed");
+        Ok(change.clone())
+    }
+}
+
+/// Returns the repository key of a recognized speaker's preferences.
+fn person_key(user_id: &str, person_id: &str) -> String {
+    format!("{}#{}", user_id, person_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    use async_trait::async_trait;
+
+    use crate::models::{ListName, SpeechStyle};
+
+    #[derive(Default)]
+    struct MockRepository {
+        preferences: Mutex<HashMap<String, UserPreferences>>,
+        fail: bool,
+    }
+
+    #[async_trait]
+    impl PreferencesRepository for MockRepository {
+        async fn get(&self, user_id: &str) -> Result<UserPreferences, DomainError> {
+            if self.fail {
+                return Err(DomainError::RepositoryError("down".to_string()));
+            }
+            Ok(self
+                .preferences
+                .lock()
+                .unwrap()
+                .get(user_id)
+                .cloned()
+                .unwrap_or_default())
+        }
+
+        async fn save(
+            &self,
+            user_id: &str,
+            preferences: &UserPreferences,
+        ) -> Result<(), DomainError> {
+            if self.fail {
+                return Err(DomainError::RepositoryError("down".to_string()));
+            }
+            self.preferences
+                .lock()
+                .unwrap()
+                .insert(user_id.to_string(), preferences.clone());
+            Ok(())
+        }
+
+        async fn delete(&self, user_id: &str) -> Result<(), DomainError> {
+            self.preferences.lock().unwrap().remove(user_id);
+            Ok(())
+        }
+
+        async fn delete_all(&self, user_id: &str) -> Result<(), DomainError> {
+            self.delete(user_id).await
+        }
+    }
+
+    #[tokio::test]
+    async fn stores_changes_per_user() {
+        let service = PreferencesService::new(Arc::new(MockRepository::default()));
+
+        let applied = service
+            .change(
+                "user-1",
+                None,
+                &SettingChange::SpeechStyle(SpeechStyle::Brief),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(applied, SettingChange::SpeechStyle(SpeechStyle::Brief));
+        assert_eq!(
+            service.preferences("user-1", None).await.speech_style(),
+            Some(SpeechStyle::Brief)
+        );
+        assert_eq!(
+            service.preferences("user-2", None).await.speech_style(),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn stores_changes_per_recognized_speaker() {
+        let service = PreferencesService::new(Arc::new(MockRepository::default()));
+        let list = ListName::new("geburtstagsliste").unwrap();
+        service
+            .change(
+                "user-1",
+                None,
+                &SettingChange::SpeechStyle(SpeechStyle::Brief),
+            )
+            .await
+            .unwrap();
+        service
+            .change(
+                "user-1",
+                Some("person-a"),
+                &SettingChange::DefaultList(Some(list.clone())),
+            )
+            .await
+            .unwrap();
+
+        let person_a = service.preferences("user-1", Some("person-a")).await;
+        let person_b = service.preferences("user-1", Some("person-b")).await;
+
+        assert_eq!(person_a.default_list(), Some(&list));
+        assert_eq!(person_a.speech_style(), Some(SpeechStyle::Brief));
+        assert_eq!(person_b.default_list(), None);
+        assert_eq!(
+            service.preferences("user-1", None).await.default_list(),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn store_failure_falls_back_to_defaults() {
+        let service = PreferencesService::new(Arc::new(MockRepository {
+            fail: true,
+            ..Default::default()
+        }));
+
+        assert_eq!(
+            service.preferences("user-1", Some("person-a")).await,
+            UserPreferences::new()
+        );
+        let result = service
+            .change(
+                "user-1",
+                None,
+                &SettingChange::SpeechStyle(SpeechStyle::Full),
+            )
+            .await;
+        assert!(matches!(result, Err(DomainError::RepositoryError(_))));
+    }
+}
@@ -0,0 +1,145 @@
+use std::sync::Arc;
+
+use tracing::{debug, info, warn};
+
+use crate::models::DomainError;
+use crate::ports::CustomerProfile;
+
+/// Service for addressing the user by name.
+///
+/// The name is only a courtesy: any failure yields `None` and the skill
+/// answers without it.
+pub struct ProfileService {
+    profile: Arc<dyn CustomerProfile>,
+}
+
+impl ProfileService {
+    /// Creates a new ProfileService reading names from `profile`.
+    pub fn new(profile: Arc<dyn CustomerProfile>) -> Self {
+        Self { profile }
+    }
+
+    /// Returns the given name to greet the user with.
+    ///
+    /// A recognized speaker is greeted with their own name; otherwise the
+    /// account holder's name is used. Pass `None` as `api_access` when the
+    /// user has not granted access to the name.
+    pub async fn given_name(
+        &self,
+        api_access: Option<(&str, &str)>,
+        person_recognized: bool,
+    ) -> Option<String> {
+        let (api_endpoint, access_token) = api_access?;
+
+        if person_recognized {
+            let result = self
+                .profile
+                .person_given_name(api_endpoint, access_token)
+                .await;
+            if let Some(name) = Self::name_from(result) {
+                debug!("Greeting recognized speaker by name");
+                return Some(name);
+            }
+        }
+
+        let result = self
+            .profile
+            .account_given_name(api_endpoint, access_token)
+            .await;
+        Self::name_from(result)
+    }
+
+    fn name_from(result: Result<Option<String>, DomainError>) -> Option<String> {
+        match result {
+            Ok(name) => name
+                .map(|name| name.trim().to_string())
+                .filter(|name| !name.is_empty()),
+            Err(DomainError::PermissionRequired(scope)) => {
+                info!(scope = %scope, "Given name permission missing");
+                None
+            }
+            Err(e) => {
+                warn!(error = %e, "Failed to read given name");
+                None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+
+    struct MockProfile {
+        account: Result<Option<String>, DomainError>,
+        person: Result<Option<String>, DomainError>,
+    }
+
+    #[async_trait]
+    impl CustomerProfile for MockProfile {
+        async fn account_given_name(
+            &self,
+            _api_endpoint: &str,
+            _access_token: &str,
+        ) -> Result<Option<String>, DomainError> {
+            self.account.clone()
+        }
+
+        async fn person_given_name(
+            &self,
+            _api_endpoint: &str,
+            _access_token: &str,
+        ) -> Result<Option<String>, DomainError> {
+            self.person.clone()
+        }
+    }
+
+    const ACCESS: Option<(&str, &str)> = Some(("https://api.amazonalexa.com", "token"));
+
+    fn service(
+        account: Result<Option<String>, DomainError>,
+        person: Result<Option<String>, DomainError>,
+    ) -> ProfileService {
+        ProfileService::new(Arc::new(MockProfile { account, person }))
+    }
+
+    #[tokio::test]
+    async fn prefers_recognized_speaker() {
+        let service = service(Ok(Some("Anna".to_string())), Ok(Some("Ben".to_string())));
+
+        assert_eq!(
+            service.given_name(ACCESS, true).await,
+            Some("Ben".to_string())
+        );
+        assert_eq!(
+            service.given_name(ACCESS, false).await,
+            Some("Anna".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn falls_back_to_account_holder() {
+        let service = service(
+            Ok(Some("Anna".to_string())),
+            Err(DomainError::PermissionRequired("scope".to_string())),
+        );
+
+        assert_eq!(
+            service.given_name(ACCESS, true).await,
+            Some("Anna".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn returns_none_without_access_or_name() {
+        let unnamed = service(Ok(Some("Anna".to_string())), Ok(None));
+        let failing = service(
+            Err(DomainError::RepositoryError("down".to_string())),
+            Ok(None),
+        );
+
+        assert_eq!(unnamed.given_name(None, false).await, None);
+        assert_eq!(failing.given_name(ACCESS, false).await, None);
+    }
+}
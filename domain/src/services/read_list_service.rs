@@ -0,0 +1,280 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use tracing::{debug, error, info, warn};
+
+use crate::models::{DomainError, ShoppingListEntry};
+use crate::ports::ShoppingListRepository;
+
+/// Service for reading the current shopping list.
+pub struct ReadListService {
+    repository: Arc<dyn ShoppingListRepository>,
+    /// Whether the last warm-up failed, so a setup that cannot warm up,
+    /// e.g. one signing in only through account linking, warns once
+    /// rather than on every scheduled event.
+    warm_up_failing: AtomicBool,
+}
+
+impl ReadListService {
+    /// Creates a new ReadListService with the given repository.
+    pub fn new(repository: Arc<dyn ShoppingListRepository>) -> Self {
+        Self {
+            repository,
+            warm_up_failing: AtomicBool::new(false),
+        }
+    }
+
+    /// Returns all entries on the shopping list.
+    ///
+    /// # Errors
+    /// Returns the repository error, logged; turning it into speech is up
+    /// to the adapter that received the request.
+    pub async fn execute(&self) -> Result<Vec<ShoppingListEntry>, DomainError> {
+        match self.repository.list_items().await {
+            Ok(entries) => {
+                info!(count = entries.len(), "Shopping list read");
+                Ok(entries)
+            }
+            Err(e) => {
+                error!(error = %e, code = %e.code(), "Failed to read shopping list");
+                Err(e)
+            }
+        }
+    }
+
+    /// Returns the entries not yet checked off, in list order.
+    ///
+    /// # Errors
+    /// Returns the repository error, logged like [`Self::execute`].
+    pub async fn open_items(&self) -> Result<Vec<ShoppingListEntry>, DomainError> {
+        let entries = self.execute().await?;
+        Ok(entries
+            .into_iter()
+            .filter(|entry| !entry.is_owned())
+            .collect())
+    }
+
+    /// Returns up to `limit` open entries, most recently added first, or
+    /// `None` if the list cannot be read within `timeout`.
+    ///
+    /// Meant for extras such as a card next to an add confirmation, which
+    /// must not hold up the answer; failures are only logged.
+    pub async fn recent(&self, limit: usize, timeout: Duration) -> Option<Vec<ShoppingListEntry>> {
+        match tokio::time::timeout(timeout, self.repository.list_items()).await {
+            Ok(Ok(entries)) => Some(
+                entries
+                    .into_iter()
+                    .rev()
+                    .filter(|entry| !entry.is_owned())
+                    .take(limit)
+                    .collect(),
+            ),
+            Ok(Err(e)) => {
+                warn!(error = %e, "Failed to read recent shopping list entries");
+                None
+            }
+            Err(_) => {
+                warn!(
+                    timeout_ms = timeout.as_millis() as u64,
+                    "Reading recent shopping list entries timed out"
+                );
+                None
+            }
+        }
+    }
+
+    /// Prepares the repository for the next reads and writes.
+    ///
+    /// # Returns
+    /// True if the repository is ready. A failure is logged as a warning
+    /// once; repeated ones are logged at debug level until a warm-up
+    /// succeeds again.
+    pub async fn warm_up(&self) -> bool {
+        match self.repository.warm_up().await {
+            Ok(()) => {
+                self.warm_up_failing.store(false, Ordering::Relaxed);
+                info!("Shopping list backend warmed up");
+                true
+            }
+            Err(e) if self.warm_up_failing.swap(true, Ordering::Relaxed) => {
+                debug!(error = %e, "Failed to warm up shopping list backend again");
+                false
+            }
+            Err(e) => {
+                warn!(error = %e, "Failed to warm up shopping list backend");
+                false
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{AddedItem, ShoppingListItem};
+    use async_trait::async_trait;
+
+    struct MockRepository {
+        entries: Result<Vec<ShoppingListEntry>, fn() -> DomainError>,
+        delay: Duration,
+    }
+
+    #[async_trait]
+    impl ShoppingListRepository for MockRepository {
+        async fn add_item(&self, _item: &ShoppingListItem) -> Result<Vec<AddedItem>, DomainError> {
+            Ok(Vec::new())
+        }
+
+        async fn list_items(&self) -> Result<Vec<ShoppingListEntry>, DomainError> {
+            tokio::time::sleep(self.delay).await;
+            self.entries.clone().map_err(|error| error())
+        }
+
+        async fn remove_item(&self, _id: &str) -> Result<(), DomainError> {
+            Ok(())
+        }
+    }
+
+    /// Repository that cannot sign in, as without Cookidoo credentials.
+    struct FailingWarmUp;
+
+    #[async_trait]
+    impl ShoppingListRepository for FailingWarmUp {
+        async fn add_item(&self, _item: &ShoppingListItem) -> Result<Vec<AddedItem>, DomainError> {
+            Ok(Vec::new())
+        }
+
+        async fn list_items(&self) -> Result<Vec<ShoppingListEntry>, DomainError> {
+            Ok(Vec::new())
+        }
+
+        async fn remove_item(&self, _id: &str) -> Result<(), DomainError> {
+            Ok(())
+        }
+
+        async fn warm_up(&self) -> Result<(), DomainError> {
+            Err(DomainError::AuthenticationFailed(
+                "no credentials".to_string(),
+            ))
+        }
+    }
+
+    #[tokio::test]
+    async fn execute_returns_entries() {
+        let repo = Arc::new(MockRepository {
+            entries: Ok(vec![ShoppingListEntry::new("1", "Milch", false)]),
+            delay: Duration::ZERO,
+        });
+        let service = ReadListService::new(repo);
+
+        let entries = service.execute().await.unwrap();
+
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].name(), "Milch");
+    }
+
+    #[tokio::test]
+    async fn open_items_skips_checked_off_entries() {
+        let repo = Arc::new(MockRepository {
+            entries: Ok(vec![
+                ShoppingListEntry::new("1", "Milch", false),
+                ShoppingListEntry::new("2", "Eier", true),
+                ShoppingListEntry::new("3", "Butter", false),
+            ]),
+            delay: Duration::ZERO,
+        });
+        let service = ReadListService::new(repo);
+
+        let entries = service.open_items().await.unwrap();
+
+        let names: Vec<_> = entries.iter().map(ShoppingListEntry::name).collect();
+        assert_eq!(names, ["Milch", "Butter"]);
+    }
+
+    #[tokio::test]
+    async fn recent_returns_newest_open_entries_first() {
+        let repo = Arc::new(MockRepository {
+            entries: Ok(vec![
+                ShoppingListEntry::new("1", "Milch", false),
+                ShoppingListEntry::new("2", "Eier", true),
+                ShoppingListEntry::new("3", "Butter", false),
+                ShoppingListEntry::new("4", "Mehl", false),
+            ]),
+            delay: Duration::ZERO,
+        });
+        let service = ReadListService::new(repo);
+
+        let entries = service.recent(2, Duration::from_secs(1)).await.unwrap();
+
+        let names: Vec<_> = entries.iter().map(ShoppingListEntry::name).collect();
+        assert_eq!(names, ["Mehl", "Butter"]);
+    }
+
+    #[tokio::test]
+    async fn recent_gives_up_on_failure() {
+        let repo = Arc::new(MockRepository {
+            entries: Err(|| DomainError::Unreachable("timeout".to_string())),
+            delay: Duration::ZERO,
+        });
+        let service = ReadListService::new(repo);
+
+        assert!(service.recent(5, Duration::from_secs(1)).await.is_none());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn recent_gives_up_on_slow_list() {
+        let repo = Arc::new(MockRepository {
+            entries: Ok(vec![ShoppingListEntry::new("1", "Milch", false)]),
+            delay: Duration::from_secs(5),
+        });
+        let service = ReadListService::new(repo);
+
+        assert!(service
+            .recent(5, Duration::from_millis(300))
+            .await
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn warm_up_failure_is_remembered_until_success() {
+        let failing = ReadListService::new(Arc::new(FailingWarmUp));
+        assert!(!failing.warm_up().await);
+        assert!(!failing.warm_up().await);
+        assert!(failing.warm_up_failing.load(Ordering::Relaxed));
+
+        let service = ReadListService::new(Arc::new(MockRepository {
+            entries: Ok(Vec::new()),
+            delay: Duration::ZERO,
+        }));
+        service.warm_up_failing.store(true, Ordering::Relaxed);
+        assert!(service.warm_up().await);
+        assert!(!service.warm_up_failing.load(Ordering::Relaxed));
+    }
+
+    #[tokio::test]
+    async fn execute_returns_auth_error() {
+        let repo = Arc::new(MockRepository {
+            entries: Err(|| DomainError::AuthenticationFailed("401".to_string())),
+            delay: Duration::ZERO,
+        });
+        let service = ReadListService::new(repo);
+
+        let result = service.execute().await;
+
+        assert!(matches!(result, Err(DomainError::AuthenticationFailed(_))));
+    }
+
+    #[tokio::test]
+    async fn execute_returns_repository_error_on_failure() {
+        let repo = Arc::new(MockRepository {
+            entries: Err(|| DomainError::RepositoryError("boom".to_string())),
+            delay: Duration::ZERO,
+        });
+        let service = ReadListService::new(repo);
+
+        let result = service.execute().await;
+
+        assert!(matches!(result, Err(DomainError::RepositoryError(_))));
+    }
+}
@@ -0,0 +1,167 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use tracing::{error, info, warn};
+
+use crate::models::{DomainError, Reminder};
+use crate::ports::ReminderScheduler;
+
+/// Delay used when the user does not say when to be reminded.
+pub const DEFAULT_REMINDER_OFFSET: Duration = Duration::from_secs(60 * 60);
+
+/// Why a reminder could not be scheduled.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReminderError {
+    /// The user has not granted the reminders permission yet.
+    PermissionRequired,
+    /// The request carries no API access or scheduling failed.
+    Failed,
+}
+
+/// Service for reminding the user to buy an item.
+pub struct ReminderService {
+    scheduler: Arc<dyn ReminderScheduler>,
+}
+
+impl ReminderService {
+    /// Creates a new ReminderService with the given scheduler.
+    pub fn new(scheduler: Arc<dyn ReminderScheduler>) -> Self {
+        Self { scheduler }
+    }
+
+    /// Schedules a reminder speaking `text`, such as "Milch kaufen", after
+    /// `offset` or [`DEFAULT_REMINDER_OFFSET`].
+    ///
+    /// # Returns
+    /// The offset the reminder was scheduled with.
+    ///
+    /// # Errors
+    /// Returns why the reminder was not created.
+    pub async fn remind(
+        &self,
+        api_access: Option<(&str, &str)>,
+        text: &str,
+        offset: Option<Duration>,
+        locale: &str,
+        request_time: &str,
+    ) -> Result<Duration, ReminderError> {
+        let Some((api_endpoint, access_token)) = api_access else {
+            warn!("Request carries no API access, cannot create reminder");
+            return Err(ReminderError::Failed);
+        };
+
+        let offset = offset.unwrap_or(DEFAULT_REMINDER_OFFSET);
+        let reminder = Reminder::new(text, offset, locale, request_time);
+
+        match self
+            .scheduler
+            .schedule(api_endpoint, access_token, &reminder)
+            .await
+        {
+            Ok(()) => {
+                info!(offset_secs = offset.as_secs(), "Reminder scheduled");
+                Ok(offset)
+            }
+            Err(DomainError::PermissionRequired(msg)) => {
+                info!(reason = %msg, "Reminder permission missing");
+                Err(ReminderError::PermissionRequired)
+            }
+            Err(e) => {
+                error!(error = %e, "Failed to schedule reminder");
+                Err(ReminderError::Failed)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    use async_trait::async_trait;
+
+    struct MockScheduler {
+        result: fn() -> Result<(), DomainError>,
+        scheduled: Mutex<Vec<Reminder>>,
+    }
+
+    impl MockScheduler {
+        fn returning(result: fn() -> Result<(), DomainError>) -> Self {
+            Self {
+                result,
+                scheduled: Mutex::new(Vec::new()),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl ReminderScheduler for MockScheduler {
+        async fn schedule(
+            &self,
+            _api_endpoint: &str,
+            _access_token: &str,
+            reminder: &Reminder,
+        ) -> Result<(), DomainError> {
+            self.scheduled.lock().unwrap().push(reminder.clone());
+            (self.result)()
+        }
+    }
+
+    const ACCESS: Option<(&str, &str)> = Some(("https://api.example.com", "token"));
+
+    #[tokio::test]
+    async fn schedules_reminder_with_default_offset() {
+        let scheduler = Arc::new(MockScheduler::returning(|| Ok(())));
+        let service = ReminderService::new(scheduler.clone());
+
+        let offset = service
+            .remind(
+                ACCESS,
+                "Milch kaufen",
+                None,
+                "de-DE",
+                "2024-01-27T10:00:00Z",
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(offset, DEFAULT_REMINDER_OFFSET);
+        let scheduled = scheduler.scheduled.lock().unwrap();
+        assert_eq!(scheduled[0].text(), "Milch kaufen");
+        assert_eq!(scheduled[0].offset(), DEFAULT_REMINDER_OFFSET);
+    }
+
+    #[tokio::test]
+    async fn reports_missing_permission() {
+        let scheduler = Arc::new(MockScheduler::returning(|| {
+            Err(DomainError::PermissionRequired("403".to_string()))
+        }));
+        let service = ReminderService::new(scheduler);
+
+        let result = service
+            .remind(
+                ACCESS,
+                "Milch kaufen",
+                None,
+                "de-DE",
+                "2024-01-27T10:00:00Z",
+            )
+            .await;
+
+        assert_eq!(result, Err(ReminderError::PermissionRequired));
+    }
+
+    #[tokio::test]
+    async fn fails_without_api_access() {
+        let scheduler = Arc::new(MockScheduler::returning(|| Ok(())));
+        let service = ReminderService::new(scheduler.clone());
+
+        let result = service
+            .remind(None, "Milch kaufen", None, "de-DE", "2024-01-27T10:00:00Z")
+            .await;
+
+        assert_eq!(result, Err(ReminderError::Failed));
+        assert!(scheduler.scheduled.lock().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,440 @@
+use std::sync::Arc;
+
+use tracing::{error, info};
+
+use crate::models::{DomainError, ListPosition, ShoppingListEntry};
+use crate::ports::{ItemDictionary, ShoppingListRepository};
+
+use super::{AliasDictionary, GermanSingularizer};
+
+/// Service for removing items from the shopping list by name.
+pub struct RemoveItemService {
+    repository: Arc<dyn ShoppingListRepository>,
+    dictionary: Arc<dyn ItemDictionary>,
+    singularizer: Arc<GermanSingularizer>,
+}
+
+impl RemoveItemService {
+    /// Creates a new RemoveItemService with the given repository.
+    pub fn new(repository: Arc<dyn ShoppingListRepository>) -> Self {
+        Self {
+            repository,
+            dictionary: Arc::new(AliasDictionary::new()),
+            singularizer: Arc::new(GermanSingularizer::new()),
+        }
+    }
+
+    /// Sets the dictionary used to match aliases against list entries.
+    pub fn with_dictionary(mut self, dictionary: Arc<dyn ItemDictionary>) -> Self {
+        self.dictionary = dictionary;
+        self
+    }
+
+    /// Sets the singularizer that matches singular and plural names.
+    pub fn with_singularizer(mut self, singularizer: Arc<GermanSingularizer>) -> Self {
+        self.singularizer = singularizer;
+        self
+    }
+
+    /// Removes the first entry matching `item_name`.
+    ///
+    /// Entries that are not yet checked off are preferred, so removing
+    /// "Milch" targets the one still to be bought. An alias such as
+    /// "Paradeiser" also matches entries stored under the canonical name,
+    /// and "Tomate" matches "Tomaten"; an entry with exactly the spoken name
+    /// is preferred over one that only matches in the other number. A note
+    /// need not be spoken: "Milch" also removes "Milch (fettarm)".
+    ///
+    /// # Returns
+    /// The name of the removed entry.
+    ///
+    /// # Errors
+    /// Returns `DomainError::ItemNotFound` with the spoken name if no entry
+    /// matches, or the repository error.
+    pub async fn execute(&self, item_name: &str) -> Result<String, DomainError> {
+        let entries = self.repository.list_items().await.map_err(Self::failure)?;
+
+        let names: Vec<&str> = std::iter::once(item_name)
+            .chain(self.dictionary.canonical(item_name))
+            .collect();
+        let keys: Vec<String> = names
+            .iter()
+            .map(|name| self.singularizer.key(name))
+            .collect();
+        let Some(entry) = entries
+            .iter()
+            .filter(|entry| {
+                keys.contains(&self.singularizer.key(entry.name()))
+                    || keys.contains(&self.singularizer.key(entry.item_name()))
+            })
+            .min_by_key(|entry| {
+                let exact = names.iter().any(|name| entry.matches_name(name));
+                (!exact, entry.is_owned())
+            })
+        else {
+            info!(item_name = %item_name, "Item to remove not on shopping list");
+            return Err(DomainError::ItemNotFound(item_name.trim().to_string()));
+        };
+
+        self.repository
+            .remove_item(entry.id())
+            .await
+            .map_err(Self::failure)?;
+
+        info!(item_name = %entry.name(), item_id = %entry.id(), "Item removed from shopping list");
+        Ok(entry.name().to_string())
+    }
+
+    /// Removes the entry at `position` of the list as it is read aloud.
+    ///
+    /// The list is read again for this, so an entry added since the user
+    /// heard the list shifts "den letzten" onto the new one.
+    ///
+    /// # Returns
+    /// The name of the removed entry, `None` if the list has no entry at
+    /// this position.
+    ///
+    /// # Errors
+    /// Returns the repository error.
+    pub async fn remove_at(&self, position: ListPosition) -> Result<Option<String>, DomainError> {
+        let entries = self.repository.list_items().await.map_err(Self::failure)?;
+        self.remove_among(&entries, position).await
+    }
+
+    /// Removes the entry at `position` among the entries not yet checked
+    /// off, as they are read aloud for "was fehlt noch?".
+    ///
+    /// # Returns
+    /// The name of the removed entry, `None` if there is no open entry at
+    /// this position.
+    ///
+    /// # Errors
+    /// Returns the repository error.
+    pub async fn remove_open_at(
+        &self,
+        position: ListPosition,
+    ) -> Result<Option<String>, DomainError> {
+        let entries: Vec<ShoppingListEntry> = self
+            .repository
+            .list_items()
+            .await
+            .map_err(Self::failure)?
+            .into_iter()
+            .filter(|entry| !entry.is_owned())
+            .collect();
+        self.remove_among(&entries, position).await
+    }
+
+    /// Removes the entry at `position` of `entries`.
+    async fn remove_among(
+        &self,
+        entries: &[ShoppingListEntry],
+        position: ListPosition,
+    ) -> Result<Option<String>, DomainError> {
+        let Some(entry) = position.index(entries.len()).map(|index| &entries[index]) else {
+            info!(position = ?position, count = entries.len(), "No item at position to remove");
+            return Ok(None);
+        };
+
+        self.repository
+            .remove_item(entry.id())
+            .await
+            .map_err(Self::failure)?;
+
+        info!(item_name = %entry.name(), item_id = %entry.id(), position = ?position, "Item removed from shopping list");
+        Ok(Some(entry.name().to_string()))
+    }
+
+    /// Removes the entry with the given ID.
+    ///
+    /// # Returns
+    /// The name of the removed entry, `None` if no entry has this ID.
+    ///
+    /// # Errors
+    /// Returns the repository error.
+    pub async fn remove_by_id(&self, id: &str) -> Result<Option<String>, DomainError> {
+        let entries = self.repository.list_items().await.map_err(Self::failure)?;
+
+        let Some(entry) = entries.iter().find(|entry| entry.id() == id) else {
+            info!(item_id = %id, "Item to remove not on shopping list");
+            return Ok(None);
+        };
+
+        self.repository
+            .remove_item(entry.id())
+            .await
+            .map_err(Self::failure)?;
+
+        info!(item_name = %entry.name(), item_id = %entry.id(), "Item removed from shopping list");
+        Ok(Some(entry.name().to_string()))
+    }
+
+    /// Checks off the entry with the given ID, keeping it on the list as
+    /// bought.
+    ///
+    /// # Returns
+    /// The name of the checked-off entry, `None` if no entry has this ID.
+    ///
+    /// # Errors
+    /// Returns the repository error.
+    pub async fn check_off_by_id(&self, id: &str) -> Result<Option<String>, DomainError> {
+        let entries = self
+            .repository
+            .list_items()
+            .await
+            .map_err(Self::check_off_failure)?;
+
+        let Some(entry) = entries.iter().find(|entry| entry.id() == id) else {
+            info!(item_id = %id, "Item to check off not on shopping list");
+            return Ok(None);
+        };
+
+        self.repository
+            .mark_owned(entry.id())
+            .await
+            .map_err(Self::check_off_failure)?;
+
+        info!(item_name = %entry.name(), item_id = %entry.id(), "Item checked off");
+        Ok(Some(entry.name().to_string()))
+    }
+
+    fn failure(error: DomainError) -> DomainError {
+        error!(error = %error, code = %error.code(), "Failed to remove item");
+        error
+    }
+
+    fn check_off_failure(error: DomainError) -> DomainError {
+        error!(error = %error, code = %error.code(), "Failed to check off item");
+        error
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    use async_trait::async_trait;
+
+    use crate::models::{AddedItem, ShoppingListEntry, ShoppingListItem};
+
+    struct MockRepository {
+        entries: Vec<ShoppingListEntry>,
+        removed: Mutex<Vec<String>>,
+        owned: Mutex<Vec<String>>,
+        fail: bool,
+    }
+
+    impl MockRepository {
+        fn with_entries(entries: Vec<ShoppingListEntry>) -> Self {
+            Self {
+                entries,
+                removed: Mutex::new(Vec::new()),
+                owned: Mutex::new(Vec::new()),
+                fail: false,
+            }
+        }
+    }
+
+    #[async_trait]
+    impl ShoppingListRepository for MockRepository {
+        async fn add_item(&self, _item: &ShoppingListItem) -> Result<Vec<AddedItem>, DomainError> {
+            Ok(Vec::new())
+        }
+
+        async fn list_items(&self) -> Result<Vec<ShoppingListEntry>, DomainError> {
+            if self.fail {
+                Err(DomainError::RepositoryError("down".to_string()))
+            } else {
+                Ok(self.entries.clone())
+            }
+        }
+
+        async fn remove_item(&self, id: &str) -> Result<(), DomainError> {
+            self.removed.lock().unwrap().push(id.to_string());
+            Ok(())
+        }
+
+        async fn mark_owned(&self, id: &str) -> Result<(), DomainError> {
+            self.owned.lock().unwrap().push(id.to_string());
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn removes_matching_item() {
+        let repo = Arc::new(MockRepository::with_entries(vec![
+            ShoppingListEntry::new("id-1", "Eier", false),
+            ShoppingListEntry::new("id-2", "Milch", false),
+        ]));
+        let service = RemoveItemService::new(repo.clone());
+
+        let removed = service.execute("milch").await.unwrap();
+
+        assert_eq!(removed, "Milch");
+        assert_eq!(*repo.removed.lock().unwrap(), vec!["id-2"]);
+    }
+
+    #[tokio::test]
+    async fn removes_entry_with_note_by_name() {
+        let repo = Arc::new(MockRepository::with_entries(vec![
+            ShoppingListEntry::new("id-1", "Eier", false),
+            ShoppingListEntry::new("id-2", "Milch (fettarm)", false),
+        ]));
+        let service = RemoveItemService::new(repo.clone());
+
+        let removed = service.execute("Milch").await.unwrap();
+
+        assert_eq!(removed, "Milch (fettarm)");
+        assert_eq!(*repo.removed.lock().unwrap(), vec!["id-2"]);
+    }
+
+    #[tokio::test]
+    async fn prefers_unchecked_entries() {
+        let repo = Arc::new(MockRepository::with_entries(vec![
+            ShoppingListEntry::new("id-1", "Milch", true),
+            ShoppingListEntry::new("id-2", "Milch", false),
+        ]));
+        let service = RemoveItemService::new(repo.clone());
+
+        service.execute("Milch").await.unwrap();
+
+        assert_eq!(*repo.removed.lock().unwrap(), vec!["id-2"]);
+    }
+
+    #[tokio::test]
+    async fn removes_entry_stored_under_canonical_name() {
+        let repo = Arc::new(MockRepository::with_entries(vec![
+            ShoppingListEntry::new("id-1", "Eier", false),
+            ShoppingListEntry::new("id-2", "Tomaten", false),
+        ]));
+        let service = RemoveItemService::new(repo.clone());
+
+        let removed = service.execute("Paradeiser").await.unwrap();
+
+        assert_eq!(removed, "Tomaten");
+        assert_eq!(*repo.removed.lock().unwrap(), vec!["id-2"]);
+    }
+
+    #[tokio::test]
+    async fn removes_entry_in_other_number() {
+        let repo = Arc::new(MockRepository::with_entries(vec![
+            ShoppingListEntry::new("id-1", "Tomatenmark", false),
+            ShoppingListEntry::new("id-2", "Äpfel", false),
+            ShoppingListEntry::new("id-3", "Tomaten", false),
+        ]));
+        let service = RemoveItemService::new(repo.clone());
+
+        service.execute("Tomate").await.unwrap();
+        service.execute("Apfel").await.unwrap();
+
+        assert_eq!(*repo.removed.lock().unwrap(), vec!["id-3", "id-2"]);
+    }
+
+    #[tokio::test]
+    async fn prefers_exact_name_over_other_number() {
+        let repo = Arc::new(MockRepository::with_entries(vec![
+            ShoppingListEntry::new("id-1", "Tomaten", false),
+            ShoppingListEntry::new("id-2", "Tomate", true),
+        ]));
+        let service = RemoveItemService::new(repo.clone());
+
+        service.execute("Tomate").await.unwrap();
+
+        assert_eq!(*repo.removed.lock().unwrap(), vec!["id-2"]);
+    }
+
+    #[tokio::test]
+    async fn reports_missing_item() {
+        let repo = Arc::new(MockRepository::with_entries(Vec::new()));
+        let service = RemoveItemService::new(repo.clone());
+
+        let result = service.execute(" Milch ").await;
+
+        assert!(matches!(result, Err(DomainError::ItemNotFound(name)) if name == "Milch"));
+        assert!(repo.removed.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn removes_item_by_id() {
+        let repo = Arc::new(MockRepository::with_entries(vec![
+            ShoppingListEntry::new("id-1", "Eier", false),
+            ShoppingListEntry::new("id-2", "Milch", false),
+        ]));
+        let service = RemoveItemService::new(repo.clone());
+
+        let removed = service.remove_by_id("id-1").await.unwrap();
+        let missing = service.remove_by_id("id-9").await.unwrap();
+
+        assert_eq!(removed.as_deref(), Some("Eier"));
+        assert_eq!(missing, None);
+        assert_eq!(*repo.removed.lock().unwrap(), vec!["id-1"]);
+    }
+
+    #[tokio::test]
+    async fn removes_item_at_position() {
+        let repo = Arc::new(MockRepository::with_entries(vec![
+            ShoppingListEntry::new("id-1", "Eier", false),
+            ShoppingListEntry::new("id-2", "Milch", false),
+            ShoppingListEntry::new("id-3", "Brot", false),
+        ]));
+        let service = RemoveItemService::new(repo.clone());
+
+        let last = service.remove_at(ListPosition::Last).await.unwrap();
+        let second = service.remove_at(ListPosition::Number(2)).await.unwrap();
+        let missing = service.remove_at(ListPosition::Number(4)).await.unwrap();
+
+        assert_eq!(last.as_deref(), Some("Brot"));
+        assert_eq!(second.as_deref(), Some("Milch"));
+        assert_eq!(missing, None);
+        assert_eq!(*repo.removed.lock().unwrap(), vec!["id-3", "id-2"]);
+    }
+
+    #[tokio::test]
+    async fn removes_open_item_at_position() {
+        let repo = Arc::new(MockRepository::with_entries(vec![
+            ShoppingListEntry::new("id-1", "Eier", true),
+            ShoppingListEntry::new("id-2", "Milch", false),
+            ShoppingListEntry::new("id-3", "Brot", false),
+        ]));
+        let service = RemoveItemService::new(repo.clone());
+
+        let first = service
+            .remove_open_at(ListPosition::Number(1))
+            .await
+            .unwrap();
+
+        assert_eq!(first.as_deref(), Some("Milch"));
+        assert_eq!(*repo.removed.lock().unwrap(), vec!["id-2"]);
+    }
+
+    #[tokio::test]
+    async fn checks_off_item_by_id() {
+        let repo = Arc::new(MockRepository::with_entries(vec![
+            ShoppingListEntry::new("id-1", "Eier", false),
+            ShoppingListEntry::new("id-2", "Milch", false),
+        ]));
+        let service = RemoveItemService::new(repo.clone());
+
+        let checked_off = service.check_off_by_id("id-2").await.unwrap();
+        let missing = service.check_off_by_id("id-9").await.unwrap();
+
+        assert_eq!(checked_off.as_deref(), Some("Milch"));
+        assert_eq!(missing, None);
+        assert_eq!(*repo.owned.lock().unwrap(), vec!["id-2"]);
+        assert!(repo.removed.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn returns_error_when_list_unavailable() {
+        let repo = Arc::new(MockRepository {
+            fail: true,
+            ..MockRepository::with_entries(Vec::new())
+        });
+        let service = RemoveItemService::new(repo);
+
+        let result = service.execute("Milch").await;
+
+        assert!(matches!(result, Err(DomainError::RepositoryError(_))));
+    }
+}
@@ -0,0 +1,297 @@
+use std::sync::Arc;
+
+use tracing::{error, info};
+
+use crate::models::{DomainError, SkillPermissions};
+use crate::ports::PermissionStore;
+
+use super::UserDataService;
+
+/// Service tracking permission and subscription changes reported by Alexa.
+///
+/// Keeps the stored state in sync with lifecycle events so that
+/// permission-dependent features know whether they are available, and
+/// removes a user's records when they disable the skill.
+pub struct SkillEventService {
+    store: Arc<dyn PermissionStore>,
+    user_data: Option<Arc<UserDataService>>,
+}
+
+impl SkillEventService {
+    /// Creates a new SkillEventService with the given store.
+    pub fn new(store: Arc<dyn PermissionStore>) -> Self {
+        Self {
+            store,
+            user_data: None,
+        }
+    }
+
+    /// Sets the service deleting the user's other data when the skill is
+    /// disabled.
+    pub fn with_user_data_service(mut self, user_data: Arc<UserDataService>) -> Self {
+        self.user_data = Some(user_data);
+        self
+    }
+
+    /// Starts a user's permission state afresh when they enable the skill.
+    ///
+    /// Alexa reports permissions granted from then on, so state left from
+    /// an earlier enablement is stale.
+    ///
+    /// # Errors
+    /// Returns a `DomainError` if the permission store fails.
+    pub async fn skill_enabled(&self, user_id: &str) -> Result<(), DomainError> {
+        self.store.save(user_id, &SkillPermissions::new()).await?;
+
+        info!("Skill enabled");
+        Ok(())
+    }
+
+    /// Cleans up after a user disabled the skill.
+    ///
+    /// If Alexa keeps the user ID, the records are kept for a re-enable.
+    /// Otherwise the user's permissions and all data of the
+    /// [`UserDataService`] are deleted, even if one of them fails.
+    ///
+    /// # Errors
+    /// Returns the first `DomainError` of a store that failed.
+    pub async fn skill_disabled(
+        &self,
+        user_id: &str,
+        keeps_user_data: bool,
+    ) -> Result<(), DomainError> {
+        if keeps_user_data {
+            info!("Skill disabled, user data kept for re-enabling");
+            return Ok(());
+        }
+
+        let permissions = self.store.delete(user_id).await;
+        let user_data = match &self.user_data {
+            Some(user_data) => user_data.delete(user_id).await,
+            None => Ok(()),
+        };
+
+        permissions.and(user_data)?;
+
+        info!("Skill disabled, user data deleted");
+        Ok(())
+    }
+
+    /// Records the full set of permission scopes the user currently grants.
+    ///
+    /// # Errors
+    /// Returns a `DomainError` if the permission store fails.
+    pub async fn permissions_changed(
+        &self,
+        user_id: &str,
+        scopes: &[String],
+    ) -> Result<(), DomainError> {
+        let mut permissions = self.store.get(user_id).await?;
+        permissions.set_scopes(scopes.iter().cloned());
+        self.store.save(user_id, &permissions).await?;
+
+        info!(scope_count = scopes.len(), "Skill permissions updated");
+        Ok(())
+    }
+
+    /// Records the full set of proactive events the user is subscribed to.
+    ///
+    /// # Errors
+    /// Returns a `DomainError` if the permission store fails.
+    pub async fn subscriptions_changed(
+        &self,
+        user_id: &str,
+        events: &[String],
+    ) -> Result<(), DomainError> {
+        let mut permissions = self.store.get(user_id).await?;
+        permissions.set_subscriptions(events.iter().cloned());
+        self.store.save(user_id, &permissions).await?;
+
+        info!(
+            subscription_count = events.len(),
+            "Proactive subscriptions updated"
+        );
+        Ok(())
+    }
+
+    /// Returns the known permissions for a user.
+    ///
+    /// Store failures are logged and treated as "nothing granted", so callers
+    /// fall back to the guidance for missing permissions.
+    pub async fn permissions(&self, user_id: &str) -> SkillPermissions {
+        match self.store.get(user_id).await {
+            Ok(permissions) => permissions,
+            Err(e) => {
+                error!(error = %e, "Failed to load skill permissions");
+                SkillPermissions::new()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::UserPreferences;
+    use crate::ports::PreferencesRepository;
+    use async_trait::async_trait;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    struct MockStore {
+        permissions: Mutex<HashMap<String, SkillPermissions>>,
+    }
+
+    #[async_trait]
+    impl PermissionStore for MockStore {
+        async fn get(&self, user_id: &str) -> Result<SkillPermissions, DomainError> {
+            Ok(self
+                .permissions
+                .lock()
+                .unwrap()
+                .get(user_id)
+                .cloned()
+                .unwrap_or_default())
+        }
+
+        async fn save(
+            &self,
+            user_id: &str,
+            permissions: &SkillPermissions,
+        ) -> Result<(), DomainError> {
+            self.permissions
+                .lock()
+                .unwrap()
+                .insert(user_id.to_string(), permissions.clone());
+            Ok(())
+        }
+
+        async fn delete(&self, user_id: &str) -> Result<(), DomainError> {
+            self.permissions.lock().unwrap().remove(user_id);
+            Ok(())
+        }
+    }
+
+    /// Preferences repository recording which users were deleted.
+    #[derive(Default)]
+    struct DeletionLog {
+        deleted: Mutex<Vec<String>>,
+    }
+
+    #[async_trait]
+    impl PreferencesRepository for DeletionLog {
+        async fn get(&self, _user_id: &str) -> Result<UserPreferences, DomainError> {
+            Ok(UserPreferences::default())
+        }
+
+        async fn save(
+            &self,
+            _user_id: &str,
+            _preferences: &UserPreferences,
+        ) -> Result<(), DomainError> {
+            Ok(())
+        }
+
+        async fn delete(&self, _user_id: &str) -> Result<(), DomainError> {
+            Ok(())
+        }
+
+        async fn delete_all(&self, user_id: &str) -> Result<(), DomainError> {
+            self.deleted.lock().unwrap().push(user_id.to_string());
+            Ok(())
+        }
+    }
+
+    fn with_user_data(service: SkillEventService, log: &Arc<DeletionLog>) -> SkillEventService {
+        service.with_user_data_service(Arc::new(
+            UserDataService::new().with_preferences_repository(log.clone()),
+        ))
+    }
+
+    fn make_service() -> SkillEventService {
+        SkillEventService::new(Arc::new(MockStore {
+            permissions: Mutex::new(HashMap::new()),
+        }))
+    }
+
+    #[tokio::test]
+    async fn records_accepted_permissions() {
+        let service = make_service();
+
+        service
+            .permissions_changed("user-1", &["alexa::profile:given_name:read".to_string()])
+            .await
+            .unwrap();
+
+        let permissions = service.permissions("user-1").await;
+        assert!(permissions.has_scope("alexa::profile:given_name:read"));
+    }
+
+    #[tokio::test]
+    async fn subscription_change_keeps_scopes() {
+        let service = make_service();
+        service
+            .permissions_changed("user-1", &["scope".to_string()])
+            .await
+            .unwrap();
+
+        service
+            .subscriptions_changed("user-1", &["AMAZON.MessageAlert.Activated".to_string()])
+            .await
+            .unwrap();
+
+        let permissions = service.permissions("user-1").await;
+        assert!(permissions.has_scope("scope"));
+        assert!(permissions.is_subscribed("AMAZON.MessageAlert.Activated"));
+    }
+
+    #[tokio::test]
+    async fn enabling_resets_permissions() {
+        let service = make_service();
+        service
+            .permissions_changed("user-1", &["scope".to_string()])
+            .await
+            .unwrap();
+
+        service.skill_enabled("user-1").await.unwrap();
+
+        assert!(!service.permissions("user-1").await.has_scope("scope"));
+    }
+
+    #[tokio::test]
+    async fn disabling_deletes_user_data() {
+        let log = Arc::new(DeletionLog::default());
+        let service = with_user_data(make_service(), &log);
+        service
+            .permissions_changed("user-1", &["scope".to_string()])
+            .await
+            .unwrap();
+
+        service.skill_disabled("user-1", false).await.unwrap();
+
+        assert!(!service.permissions("user-1").await.has_scope("scope"));
+        assert_eq!(*log.deleted.lock().unwrap(), ["user-1"]);
+    }
+
+    #[tokio::test]
+    async fn disabling_keeps_persisted_user_data() {
+        let log = Arc::new(DeletionLog::default());
+        let service = with_user_data(make_service(), &log);
+        service
+            .permissions_changed("user-1", &["scope".to_string()])
+            .await
+            .unwrap();
+
+        service.skill_disabled("user-1", true).await.unwrap();
+
+        assert!(service.permissions("user-1").await.has_scope("scope"));
+        assert!(log.deleted.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn unknown_user_has_no_permissions() {
+        let service = make_service();
+        let permissions = service.permissions("unknown").await;
+        assert_eq!(permissions, SkillPermissions::new());
+    }
+}
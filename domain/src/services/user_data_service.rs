@@ -0,0 +1,270 @@
+use std::sync::Arc;
+
+use tracing::{info, warn};
+
+use crate::models::DomainError;
+use crate::ports::{HistoryRepository, OccasionListStore, PermissionStore, PreferencesRepository};
+
+/// Service deleting everything the skill stored about a user.
+///
+/// Used when a user asks for their data to be deleted, when they disable
+/// the skill, and by the `delete-user-data` admin tool. Stores that are not
+/// configured are skipped.
+pub struct UserDataService {
+    preferences: Option<Arc<dyn PreferencesRepository>>,
+    history: Option<Arc<dyn HistoryRepository>>,
+    occasion_lists: Option<Arc<dyn OccasionListStore>>,
+    permissions: Option<Arc<dyn PermissionStore>>,
+}
+
+impl UserDataService {
+    /// Creates a service without stores; it deletes nothing until stores
+    /// are added.
+    pub fn new() -> Self {
+        Self {
+            preferences: None,
+            history: None,
+            occasion_lists: None,
+            permissions: None,
+        }
+    }
+
+    /// Sets the preferences repository to delete from.
+    pub fn with_preferences_repository(
+        mut self,
+        preferences: Arc<dyn PreferencesRepository>,
+    ) -> Self {
+        self.preferences = Some(preferences);
+        self
+    }
+
+    /// Sets the history repository to delete from.
+    pub fn with_history_repository(mut self, history: Arc<dyn HistoryRepository>) -> Self {
+        self.history = Some(history);
+        self
+    }
+
+    /// Sets the occasion list store to delete from.
+    pub fn with_occasion_list_store(mut self, occasion_lists: Arc<dyn OccasionListStore>) -> Self {
+        self.occasion_lists = Some(occasion_lists);
+        self
+    }
+
+    /// Sets the permission store to delete from.
+    pub fn with_permission_store(mut self, permissions: Arc<dyn PermissionStore>) -> Self {
+        self.permissions = Some(permissions);
+        self
+    }
+
+    /// Deletes the user's preferences (including those of each recognized
+    /// speaker), add history, occasion lists and granted permissions.
+    ///
+    /// Every store is tried even if an earlier one fails, so a single outage
+    /// does not leave the other stores untouched.
+    ///
+    /// # Errors
+    /// Returns the first `DomainError` of a store that failed.
+    pub async fn delete(&self, user_id: &str) -> Result<(), DomainError> {
+        let mut results = Vec::new();
+        if let Some(preferences) = &self.preferences {
+            results.push(("preferences", preferences.delete_all(user_id).await));
+        }
+        if let Some(history) = &self.history {
+            results.push(("history", history.delete(user_id).await));
+        }
+        if let Some(occasion_lists) = &self.occasion_lists {
+            results.push(("occasion_lists", occasion_lists.delete(user_id).await));
+        }
+        if let Some(permissions) = &self.permissions {
+            results.push(("permissions", permissions.delete(user_id).await));
+        }
+
+        let mut first_error = None;
+        for (store, result) in results {
+            if let Err(e) = result {
+                warn!(store, error = %e, "Failed to delete user data");
+                first_error.get_or_insert(e);
+            }
+        }
+        if let Some(e) = first_error {
+            return Err(e);
+        }
+
+        info!("User data deleted");
+        Ok(())
+    }
+}
+
+impl Default for UserDataService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{
+        HistoryEntry, ListName, ShoppingListEntry, ShoppingListItem, SkillPermissions,
+        UserPreferences,
+    };
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+
+    /// Records which stores deleted which user.
+    #[derive(Default)]
+    struct DeletionLog {
+        deleted: Mutex<Vec<String>>,
+        fail: bool,
+    }
+
+    impl DeletionLog {
+        fn log(&self, store: &str, user_id: &str) -> Result<(), DomainError> {
+            self.deleted
+                .lock()
+                .unwrap()
+                .push(format!("{store}:{user_id}"));
+            if self.fail {
+                return Err(DomainError::RepositoryError("down".to_string()));
+            }
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl PreferencesRepository for DeletionLog {
+        async fn get(&self, _user_id: &str) -> Result<UserPreferences, DomainError> {
+            Ok(UserPreferences::default())
+        }
+
+        async fn save(
+            &self,
+            _user_id: &str,
+            _preferences: &UserPreferences,
+        ) -> Result<(), DomainError> {
+            Ok(())
+        }
+
+        async fn delete(&self, _user_id: &str) -> Result<(), DomainError> {
+            Ok(())
+        }
+
+        async fn delete_all(&self, user_id: &str) -> Result<(), DomainError> {
+            self.log("preferences", user_id)
+        }
+    }
+
+    #[async_trait]
+    impl HistoryRepository for DeletionLog {
+        async fn record(&self, _user_id: &str, _entry: &HistoryEntry) -> Result<(), DomainError> {
+            Ok(())
+        }
+
+        async fn entries_since(
+            &self,
+            _user_id: &str,
+            _since: &str,
+        ) -> Result<Vec<HistoryEntry>, DomainError> {
+            Ok(Vec::new())
+        }
+
+        async fn delete(&self, user_id: &str) -> Result<(), DomainError> {
+            self.log("history", user_id)
+        }
+    }
+
+    #[async_trait]
+    impl OccasionListStore for DeletionLog {
+        async fn add(
+            &self,
+            _user_id: &str,
+            _list: &ListName,
+            _item: &ShoppingListItem,
+        ) -> Result<(), DomainError> {
+            Ok(())
+        }
+
+        async fn items(
+            &self,
+            _user_id: &str,
+            _list: &ListName,
+        ) -> Result<Vec<ShoppingListEntry>, DomainError> {
+            Ok(Vec::new())
+        }
+
+        async fn remove(
+            &self,
+            _user_id: &str,
+            _list: &ListName,
+            _item_name: &str,
+        ) -> Result<bool, DomainError> {
+            Ok(false)
+        }
+
+        async fn delete(&self, user_id: &str) -> Result<(), DomainError> {
+            self.log("occasion_lists", user_id)
+        }
+    }
+
+    #[async_trait]
+    impl PermissionStore for DeletionLog {
+        async fn get(&self, _user_id: &str) -> Result<SkillPermissions, DomainError> {
+            Ok(SkillPermissions::new())
+        }
+
+        async fn save(
+            &self,
+            _user_id: &str,
+            _permissions: &SkillPermissions,
+        ) -> Result<(), DomainError> {
+            Ok(())
+        }
+
+        async fn delete(&self, user_id: &str) -> Result<(), DomainError> {
+            self.log("permissions", user_id)
+        }
+    }
+
+    fn make_service(log: &Arc<DeletionLog>) -> UserDataService {
+        UserDataService::new()
+            .with_preferences_repository(log.clone())
+            .with_history_repository(log.clone())
+            .with_occasion_list_store(log.clone())
+            .with_permission_store(log.clone())
+    }
+
+    #[tokio::test]
+    async fn deletes_from_every_store() {
+        let log = Arc::new(DeletionLog::default());
+
+        make_service(&log).delete("user-1").await.unwrap();
+
+        assert_eq!(
+            *log.deleted.lock().unwrap(),
+            [
+                "preferences:user-1",
+                "history:user-1",
+                "occasion_lists:user-1",
+                "permissions:user-1"
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn tries_every_store_before_failing() {
+        let log = Arc::new(DeletionLog {
+            fail: true,
+            ..DeletionLog::default()
+        });
+
+        let result = make_service(&log).delete("user-1").await;
+
+        assert!(matches!(result, Err(DomainError::RepositoryError(_))));
+        assert_eq!(log.deleted.lock().unwrap().len(), 4);
+    }
+
+    #[tokio::test]
+    async fn without_stores_deletes_nothing() {
+        assert!(UserDataService::new().delete("user-1").await.is_ok());
+    }
+}
@@ -0,0 +1,130 @@
+//! Lambda entry point of the skill: loads the configuration, wires the
+//! container once at cold start and answers each event with
+//! [`handle_event`].
+
+mod telemetry;
+
+use std::sync::Arc;
+use std::time::Instant;
+
+use lambda_runtime::layers::TracingLayer;
+use lambda_runtime::{service_fn, Error, LambdaEvent, Runtime};
+use serde_json::Value;
+use tracing::{error, info};
+
+use alexa_cookidoo_skill::adapters::cookidoo::TLS_BACKEND;
+use alexa_cookidoo_skill::adapters::logging::{self, LogScrubber, Metric, RequestCapture};
+use alexa_cookidoo_skill::adapters::memory::InMemoryShoppingListRepository;
+use alexa_cookidoo_skill::application::{
+    handle_event, handle_payload, AppConfig, Container, SkillEvent,
+};
+
+use telemetry::TelemetryLayer;
+
+/// Metric recording how long the cold start took until the runtime polls
+/// for the first event.
+const INIT_DURATION_METRIC: &str = "InitDuration";
+
+// Lambda hands the function one event at a time, so a single thread saves
+// starting a worker pool at cold start
+#[tokio::main(flavor = "current_thread")]
+async fn main() -> Result<(), Error> {
+    let started = Instant::now();
+
+    // `bootstrap --smoke-test <request.json>` checks a build on its target
+    // architecture without the Lambda runtime
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if let [flag, fixture] = args.as_slice() {
+        if flag == "--smoke-test" {
+            return smoke_test(fixture).await;
+        }
+    }
+
+    // Load .env file if present (for local development)
+    let _ = dotenvy::from_filename("../.env");
+
+    // Load configuration, then initialize logging with its scrubber, or
+    // the default one to report why it failed
+    let config = AppConfig::from_env();
+    match &config {
+        Ok(config) => logging::init(config.log_scrubber().clone(), config.request_capture()),
+        Err(_) => logging::init(LogScrubber::new(), RequestCapture::default()),
+    }
+
+    info!(
+        arch = std::env::consts::ARCH,
+        tls = TLS_BACKEND,
+        "Lambda cold start - initializing"
+    );
+
+    let config = match config {
+        Ok(config) => config,
+        Err(e) => {
+            error!(error = %e, "Failed to load configuration");
+            return Err(e.into());
+        }
+    };
+    info!(config = %config.summary(), "Configuration loaded");
+    if let Err(e) = config.validate() {
+        error!(error = %e, "Invalid configuration");
+        return Err(e.into());
+    }
+
+    let config_loaded = started.elapsed();
+
+    // Wire dependencies (done once at cold start)
+    let container = match Container::new(config).await {
+        Ok(container) => container,
+        Err(e) => {
+            error!(error = %e, "Failed to initialize");
+            return Err(e.into());
+        }
+    };
+
+    let init = started.elapsed();
+    info!(
+        init_ms = init.as_millis() as u64,
+        config_ms = config_loaded.as_millis() as u64,
+        container_ms = (init - config_loaded).as_millis() as u64,
+        "Initialization complete, starting Lambda runtime"
+    );
+    Metric::milliseconds(INIT_DURATION_METRIC, init).emit();
+
+    // Run the Lambda runtime; metrics are written after each response is
+    // sent, so they never add to the user's wait
+    // Unparseable requests are answered with an error response, so the
+    // handler never fails the invocation
+    Runtime::new(service_fn(|event: LambdaEvent<SkillEvent>| async {
+        Ok::<Value, Error>(handle_event(event.payload, container.handler()).await)
+    }))
+    .layer(TracingLayer::new())
+    .layer(TelemetryLayer::enable())
+    .run()
+    .await
+}
+
+/// Starts the skill on an in-memory list and answers the Alexa request in
+/// `fixture`, printing the response: the TLS backend must initialize, the
+/// configuration (without Cookidoo credentials) load and the request parse.
+async fn smoke_test(fixture: &str) -> Result<(), Error> {
+    let payload: Value = serde_json::from_str(&std::fs::read_to_string(fixture)?)?;
+    if let SkillEvent::Invalid(e) = SkillEvent::from_value(payload.clone()) {
+        return Err(format!("{} is no valid Alexa request: {}", fixture, e).into());
+    }
+
+    reqwest::Client::builder().build()?;
+    let config = AppConfig::from_env_without_credentials()?;
+    let container =
+        Container::with_repository(config, Arc::new(InMemoryShoppingListRepository::new())).await;
+
+    let response = handle_payload(payload, container.handler()).await;
+    println!(
+        "{}",
+        serde_json::json!({
+            "arch": std::env::consts::ARCH,
+            "tls": TLS_BACKEND,
+            "response": response,
+        })
+    );
+    Ok(())
+}
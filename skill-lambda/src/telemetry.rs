@@ -0,0 +1,53 @@
+use std::task::{Context, Poll};
+
+use lambda_runtime::tower::{Layer, Service};
+use lambda_runtime::LambdaInvocation;
+
+use alexa_cookidoo_skill::adapters::logging::{Telemetry, TelemetryFuture};
+
+/// Runtime layer flushing [`Telemetry`] after each invocation.
+///
+/// Added as the outermost layer of the Lambda runtime, it wraps the
+/// service that posts the response to the Runtime API, so the flush runs
+/// after Alexa already has its answer and before the next event is polled.
+#[derive(Debug, Clone, Copy)]
+pub struct TelemetryLayer;
+
+impl TelemetryLayer {
+    /// Enables buffering of [`Telemetry`] for the whole process and returns
+    /// the layer flushing it.
+    pub fn enable() -> Self {
+        Telemetry::enable_buffering();
+        Self
+    }
+}
+
+impl<S> Layer<S> for TelemetryLayer {
+    type Service = TelemetryService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        TelemetryService { inner }
+    }
+}
+
+/// Service created by [`TelemetryLayer`].
+pub struct TelemetryService<S> {
+    inner: S,
+}
+
+impl<S> Service<LambdaInvocation> for TelemetryService<S>
+where
+    S: Service<LambdaInvocation, Response = ()>,
+{
+    type Response = ();
+    type Error = S::Error;
+    type Future = TelemetryFuture<S::Future>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, invocation: LambdaInvocation) -> Self::Future {
+        TelemetryFuture::new(self.inner.call(invocation))
+    }
+}
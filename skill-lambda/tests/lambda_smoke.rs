@@ -0,0 +1,46 @@
+//! Smoke tests of the Lambda `bootstrap` binary.
+//!
+//! Runs the built binary with `--smoke-test` on a request fixture, so a
+//! build for another architecture (e.g. arm64 on Graviton) is checked to
+//! start, initialize its TLS backend and answer a request:
+//!
+//! ```text
+//! cargo test --release -p skill-lambda --test lambda_smoke
+//! ```
+
+use std::process::{Command, Output};
+
+use serde_json::Value;
+
+fn smoke_test(fixture: &str) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_bootstrap"))
+        .args(["--smoke-test", fixture])
+        .env_remove("RUST_LOG")
+        .output()
+        .expect("bootstrap binary runs")
+}
+
+#[test]
+fn answers_fixture_request() {
+    let output = smoke_test("../skill/tests/fixtures/launch_request.json");
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    // Metrics go to stdout before the report line
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let report: Value = serde_json::from_str(stdout.lines().last().unwrap()).unwrap();
+    assert_eq!(report["arch"], std::env::consts::ARCH);
+    assert_ne!(report["tls"], "none");
+    assert!(report["response"]["response"]["outputSpeech"].is_object());
+}
+
+#[test]
+fn fails_on_invalid_fixture() {
+    let output = smoke_test("../skill/tests/fixtures/cookidoo_auth_error.json");
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("no valid Alexa request"));
+}
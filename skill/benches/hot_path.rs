@@ -0,0 +1,131 @@
+//! Criterion benchmarks for the request path: request deserialization,
+//! intent parsing, handling and response serialization, plus the
+//! cold-start wiring of the container.
+//!
+//! Run with `cargo bench --bench hot_path`; pass a name filter after `--`
+//! to run a subset, e.g. `cargo bench --bench hot_path -- parse`. Save a
+//! baseline with `-- --save-baseline main` and compare a change against it
+//! with `-- --baseline main`; criterion reports regressions beyond noise.
+
+use std::hint::black_box;
+use std::sync::Arc;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use serde_json::Value;
+use tokio::runtime::Runtime;
+
+use alexa_cookidoo_skill::adapters::alexa::{parse_intent, AlexaRequest, AlexaSkillHandler};
+use alexa_cookidoo_skill::adapters::cookidoo::CookidooClient;
+use alexa_cookidoo_skill::adapters::memory::InMemoryShoppingListRepository;
+use alexa_cookidoo_skill::application::{handle_payload, AppConfig, Container};
+use alexa_cookidoo_skill::domain::models::ShoppingListItem;
+use alexa_cookidoo_skill::domain::ports::ShoppingListRepository;
+use alexa_cookidoo_skill::domain::services::{AddItemService, ReadListService, RemoveItemService};
+
+fn deserialize_benches(c: &mut Criterion) {
+    let small = fixture("read_list_request.json");
+    let large = fixture("large_read_list_request.json");
+
+    let mut group = c.benchmark_group("deserialize");
+    group.bench_function("small_request", |b| {
+        b.iter(|| deserialize(black_box(&small)))
+    });
+    group.bench_function("large_request", |b| {
+        b.iter(|| deserialize(black_box(&large)))
+    });
+    group.finish();
+}
+
+fn parse_benches(c: &mut Criterion) {
+    let add_item = deserialize(&fixture("add_item_request.json"));
+    let large = deserialize(&fixture("large_read_list_request.json"));
+
+    let mut group = c.benchmark_group("parse_intent");
+    group.bench_function("add_item", |b| {
+        b.iter(|| parse_intent(black_box(&add_item)))
+    });
+    group.bench_function("large_request", |b| {
+        b.iter(|| parse_intent(black_box(&large)))
+    });
+    group.finish();
+}
+
+fn serialize_benches(c: &mut Criterion) {
+    let runtime = runtime();
+    let handler = runtime.block_on(handler_with_items(150));
+    let response =
+        runtime.block_on(handler.handle(deserialize(&fixture("large_read_list_request.json"))));
+
+    c.bench_function("serialize/read_list_response", |b| {
+        b.iter(|| serde_json::to_vec(black_box(&response)).unwrap())
+    });
+}
+
+fn handle_benches(c: &mut Criterion) {
+    let runtime = runtime();
+    let handler = runtime.block_on(handler_with_items(150));
+    let large = fixture("large_read_list_request.json");
+
+    c.bench_function("handle_payload/read_list_150_items", |b| {
+        b.iter(|| {
+            let payload: Value = serde_json::from_str(&large).unwrap();
+            runtime.block_on(handle_payload(payload, &handler))
+        })
+    });
+}
+
+fn cold_start_benches(c: &mut Criterion) {
+    let runtime = runtime();
+
+    let mut group = c.benchmark_group("cold_start");
+    group.bench_function("cookidoo_client", |b| b.iter(CookidooClient::new));
+    group.bench_function("container", |b| {
+        b.iter(|| {
+            let config = AppConfig::from_env_without_credentials().unwrap();
+            let repository = Arc::new(InMemoryShoppingListRepository::new());
+            runtime.block_on(Container::with_repository(config, repository))
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    deserialize_benches,
+    parse_benches,
+    serialize_benches,
+    handle_benches,
+    cold_start_benches
+);
+criterion_main!(benches);
+
+fn runtime() -> Runtime {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .expect("Failed to build runtime")
+}
+
+fn fixture(name: &str) -> String {
+    let path = format!("{}/tests/fixtures/{}", env!("CARGO_MANIFEST_DIR"), name);
+    std::fs::read_to_string(&path).unwrap_or_else(|_| panic!("Failed to read fixture: {}", path))
+}
+
+fn deserialize(json: &str) -> AlexaRequest {
+    serde_json::from_str(json).unwrap()
+}
+
+/// Creates a handler backed by an in-memory list with `count` items.
+async fn handler_with_items(count: usize) -> AlexaSkillHandler {
+    let repository = Arc::new(InMemoryShoppingListRepository::new());
+    for i in 0..count {
+        let item = ShoppingListItem::new(format!("Artikel {}", i)).unwrap();
+        repository.add_item(&item).await.unwrap();
+    }
+
+    AlexaSkillHandler::new(
+        Arc::new(AddItemService::new(repository.clone())),
+        Arc::new(ReadListService::new(repository.clone())),
+        Arc::new(RemoveItemService::new(repository)),
+    )
+}
@@ -1,3 +1,18 @@
 pub mod alexa;
-pub mod cookidoo;
+pub use cookidoo_client as cookidoo;
+#[cfg(feature = "dynamodb")]
+pub mod dynamodb;
+pub mod google;
+#[cfg(feature = "http-server")]
+pub mod http_server;
 pub mod logging;
+pub mod maintenance;
+pub mod memory;
+pub mod parameters;
+pub(crate) mod replies;
+#[cfg(feature = "rest-api")]
+pub mod rest;
+pub mod shadow;
+pub mod simulator;
+#[cfg(any(feature = "rest-api", feature = "http-server"))]
+pub mod web;
@@ -1,9 +1,34 @@
+mod customer_profile_client;
+mod device_address_client;
 mod handler;
 mod intent_parser;
-mod models;
+mod interaction_model;
+mod list_presenter;
+mod reminders_client;
 mod response_builder;
+mod response_validator;
 
+use alexa_skill_kit::{models, permission_checker, phrases, session_state, speech};
+
+pub use crate::domain::models::ParsedIntent;
+pub use customer_profile_client::{AlexaCustomerProfileClient, GIVEN_NAME_SCOPE};
+pub use device_address_client::{AlexaDeviceAddressClient, DEVICE_ADDRESS_SCOPE};
 pub use handler::AlexaSkillHandler;
-pub use intent_parser::ParsedIntent;
-pub use models::{AlexaRequest, AlexaResponse};
+pub(crate) use intent_parser::items_intent;
+pub use intent_parser::parse as parse_intent;
+pub use intent_parser::{intent_names, slot_names};
+pub use interaction_model::{InteractionModel, INVOCATION_NAME};
+pub use list_presenter::ListPresenter;
+pub(crate) use list_presenter::{entries_speech, join_names, summary_speech};
+pub(crate) use models::is_known_request_type;
+pub use models::{
+    interfaces, AlexaRequest, AlexaResponse, Card, DialogState, Directive, OnCompletion,
+    UpdatedIntent,
+};
+pub use permission_checker::PermissionChecker;
+pub use phrases::{PhraseSelector, PhraseVariation};
+pub use reminders_client::{AlexaRemindersClient, REMINDERS_SCOPE};
 pub use response_builder::ResponseBuilder;
+pub use response_validator::{Repair, ResponseValidator};
+pub use session_state::{LastResponse, PendingAction, SessionState};
+pub use speech::{Language, MessageId, Plurality, Speech};
@@ -0,0 +1,114 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use reqwest::{Client, StatusCode};
+use tracing::{debug, error};
+
+use crate::adapters::cookidoo::LazyClient;
+use crate::domain::models::DomainError;
+use crate::domain::ports::CustomerProfile;
+
+/// Permission scope needed to read the given name of the account holder
+/// or of a recognized speaker.
+pub const GIVEN_NAME_SCOPE: &str = "alexa::profile:given_name:read";
+
+/// Customer Profile API path of the account holder's given name.
+const ACCOUNT_GIVEN_NAME_PATH: &str = "/v2/accounts/~current/settings/Profile.givenName";
+
+/// Person Profile API path of the recognized speaker's given name.
+const PERSON_GIVEN_NAME_PATH: &str = "/v2/persons/~current/profile/givenName";
+
+/// Timeout for profile requests, kept short so the skill still answers
+/// before Alexa's response deadline.
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Client for the Alexa Customer and Person Profile APIs implementing the
+/// CustomerProfile port.
+pub struct AlexaCustomerProfileClient {
+    client: LazyClient,
+}
+
+impl AlexaCustomerProfileClient {
+    /// Creates a new AlexaCustomerProfileClient.
+    pub fn new() -> Self {
+        let client = LazyClient::new(|| {
+            Client::builder()
+                .timeout(DEFAULT_TIMEOUT)
+                .user_agent("AlexaCookidooSkill/1.0")
+                .build()
+        });
+
+        Self { client }
+    }
+
+    /// Reads a given name; both APIs answer with a bare JSON string.
+    async fn given_name(
+        &self,
+        api_endpoint: &str,
+        access_token: &str,
+        path: &str,
+    ) -> Result<Option<String>, DomainError> {
+        let url = format!("{}{}", api_endpoint.trim_end_matches('/'), path);
+        debug!(url = %url, "Reading given name");
+
+        let client = self
+            .client
+            .get()
+            .map_err(|e| DomainError::RepositoryError(format!("HTTP client unavailable: {}", e)))?;
+        let response = client
+            .get(&url)
+            .bearer_auth(access_token)
+            .send()
+            .await
+            .map_err(|e| DomainError::RepositoryError(format!("Profile request failed: {}", e)))?;
+
+        let status = response.status();
+        match status {
+            StatusCode::NO_CONTENT => Ok(None),
+            s if s.is_success() => {
+                let name: Option<String> = response.json().await.map_err(|e| {
+                    DomainError::RepositoryError(format!("Invalid profile response: {}", e))
+                })?;
+                Ok(name)
+            }
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => Err(
+                DomainError::PermissionRequired(GIVEN_NAME_SCOPE.to_string()),
+            ),
+            _ => {
+                let body = response.text().await.unwrap_or_default();
+                error!(status = %status, body = %body, "Profile API returned an error");
+                Err(DomainError::RepositoryError(format!(
+                    "Profile API returned {}",
+                    status
+                )))
+            }
+        }
+    }
+}
+
+impl Default for AlexaCustomerProfileClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl CustomerProfile for AlexaCustomerProfileClient {
+    async fn account_given_name(
+        &self,
+        api_endpoint: &str,
+        access_token: &str,
+    ) -> Result<Option<String>, DomainError> {
+        self.given_name(api_endpoint, access_token, ACCOUNT_GIVEN_NAME_PATH)
+            .await
+    }
+
+    async fn person_given_name(
+        &self,
+        api_endpoint: &str,
+        access_token: &str,
+    ) -> Result<Option<String>, DomainError> {
+        self.given_name(api_endpoint, access_token, PERSON_GIVEN_NAME_PATH)
+            .await
+    }
+}
@@ -0,0 +1,138 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use reqwest::{Client, StatusCode};
+use serde::Deserialize;
+use tracing::{debug, error};
+
+use crate::adapters::cookidoo::LazyClient;
+use crate::domain::models::DomainError;
+use crate::domain::ports::DeviceAddressLookup;
+
+/// Permission scope needed to read the device's country and postal code.
+pub const DEVICE_ADDRESS_SCOPE: &str = "read::alexa:device:all:address:country_and_postal_code";
+
+/// Timeout for address requests, kept short so the skill still answers
+/// before Alexa's response deadline.
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Client for the Alexa Device Address API implementing the
+/// DeviceAddressLookup port.
+///
+/// Only the country is read. Answers are cached per device for the lifetime
+/// of the container, since a device rarely moves between countries and the
+/// lookup would otherwise add a round trip to every request.
+pub struct AlexaDeviceAddressClient {
+    client: LazyClient,
+    countries: Mutex<HashMap<String, Option<String>>>,
+}
+
+impl AlexaDeviceAddressClient {
+    /// Creates a new AlexaDeviceAddressClient.
+    pub fn new() -> Self {
+        let client = LazyClient::new(|| {
+            Client::builder()
+                .timeout(DEFAULT_TIMEOUT)
+                .user_agent("AlexaCookidooSkill/1.0")
+                .build()
+        });
+
+        Self {
+            client,
+            countries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    async fn fetch(
+        &self,
+        api_endpoint: &str,
+        access_token: &str,
+        device_id: &str,
+    ) -> Result<Option<String>, DomainError> {
+        let url = format!(
+            "{}/v1/devices/{}/settings/address/countryAndPostalCode",
+            api_endpoint.trim_end_matches('/'),
+            device_id
+        );
+        debug!("Reading device country");
+
+        let client = self
+            .client
+            .get()
+            .map_err(|e| DomainError::RepositoryError(format!("HTTP client unavailable: {}", e)))?;
+        let response = client
+            .get(&url)
+            .bearer_auth(access_token)
+            .send()
+            .await
+            .map_err(|e| {
+                DomainError::RepositoryError(format!("Device address request failed: {}", e))
+            })?;
+
+        let status = response.status();
+        match status {
+            StatusCode::NO_CONTENT => Ok(None),
+            s if s.is_success() => {
+                let address: CountryAndPostalCode = response.json().await.map_err(|e| {
+                    DomainError::RepositoryError(format!("Invalid device address: {}", e))
+                })?;
+                Ok(address.country_code.filter(|code| !code.trim().is_empty()))
+            }
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => Err(
+                DomainError::PermissionRequired(DEVICE_ADDRESS_SCOPE.to_string()),
+            ),
+            _ => {
+                let body = response.text().await.unwrap_or_default();
+                error!(status = %status, body = %body, "Device Address API returned an error");
+                Err(DomainError::RepositoryError(format!(
+                    "Device Address API returned {}",
+                    status
+                )))
+            }
+        }
+    }
+}
+
+impl Default for AlexaDeviceAddressClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl DeviceAddressLookup for AlexaDeviceAddressClient {
+    async fn country_code(
+        &self,
+        api_endpoint: &str,
+        access_token: &str,
+        device_id: &str,
+    ) -> Result<Option<String>, DomainError> {
+        let cached = self
+            .countries
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(device_id)
+            .cloned();
+        if let Some(country_code) = cached {
+            return Ok(country_code);
+        }
+
+        // Failures are not cached, so a granted permission takes effect on
+        // the next request
+        let country_code = self.fetch(api_endpoint, access_token, device_id).await?;
+        self.countries
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(device_id.to_string(), country_code.clone());
+        Ok(country_code)
+    }
+}
+
+/// Response body of the country and postal code endpoint.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CountryAndPostalCode {
+    country_code: Option<String>,
+}
@@ -1,205 +1,3074 @@
 use std::sync::Arc;
+use std::time::Duration;
 
-use tracing::info;
+use tracing::{error, info, warn};
 
-use crate::domain::ports::ShoppingListRepository;
-use crate::domain::services::AddItemService;
+use crate::adapters::cookidoo::TokenProvider;
+use crate::adapters::logging::Metric;
+use crate::adapters::maintenance::StaticMaintenanceFlag;
+use crate::adapters::memory::{
+    InMemoryHistoryRepository, InMemoryOccasionListStore, InMemoryPermissionStore,
+    InMemoryPreferencesRepository,
+};
+use crate::adapters::replies::{
+    add_item_speech, bundle_failure_speech, bundle_speech, occasion_failure_speech, offset_speech,
+    setting_speech, transfer_speech,
+};
+use crate::domain::models::{
+    AccountChoice, AddItemOutcome, HistoryPeriod, HouseholdAccounts, ListName, ListPosition,
+    Market, PantryStaples, ParsedIntent, SettingChange, ShoppingListEntry, SpeechStyle,
+    UserPreferences,
+};
+use crate::domain::ports::MaintenanceFlag;
+use crate::domain::services::{
+    AddItemService, BundleService, HistoryService, ListSummaryService, ListTransferService,
+    MarketService, OccasionListService, PreferencesService, ProfileService, ReadListService,
+    ReminderError, ReminderService, RemoveItemService, SkillEventService, UserDataService,
+};
 
-use super::intent_parser::{self, ParsedIntent};
-use super::models::{AlexaRequest, AlexaResponse};
+use super::customer_profile_client::{AlexaCustomerProfileClient, GIVEN_NAME_SCOPE};
+use super::device_address_client::{AlexaDeviceAddressClient, DEVICE_ADDRESS_SCOPE};
+use super::intent_parser;
+use super::list_presenter::ListPresenter;
+use super::models::{AlexaRequest, AlexaResponse, DialogState, Request, SessionEndedRequest};
+use super::permission_checker::PermissionChecker;
+use super::phrases::{PhraseSelector, PhraseVariation};
+use super::reminders_client::{AlexaRemindersClient, REMINDERS_SCOPE};
 use super::response_builder::ResponseBuilder;
+use super::session_state::{Greeting, PendingAction, SessionState};
+use super::speech::{MessageId, Speech};
+
+/// Metric counting sessions ended by Alexa, by reason and error type.
+const SESSION_ENDED_METRIC: &str = "SessionEnded";
+
+/// Session end reason when the user did not answer the reprompts.
+const EXCEEDED_MAX_REPROMPTS: &str = "EXCEEDED_MAX_REPROMPTS";
+
+/// Most recent entries shown on the card after an add.
+const LIST_CARD_ENTRIES: usize = 5;
+
+/// How long an add waits for the list shown on its card before answering
+/// without it.
+const LIST_CARD_TIMEOUT: Duration = Duration::from_millis(300);
+
+/// Locale assumed for reminders when the request carries none.
+const DEFAULT_LOCALE: &str = "de-DE";
+
+/// List name that addresses the shopping list itself.
+const SHOPPING_LIST_NAME: &str = "Einkaufsliste";
 
 /// Main Alexa skill handler.
-pub struct AlexaSkillHandler<R: ShoppingListRepository> {
-    add_item_service: Arc<AddItemService<R>>,
+pub struct AlexaSkillHandler {
+    add_item_service: Arc<AddItemService>,
+    read_list_service: Arc<ReadListService>,
+    list_summary_service: Arc<ListSummaryService>,
+    remove_item_service: Arc<RemoveItemService>,
+    occasion_list_service: Arc<OccasionListService>,
+    skill_event_service: Arc<SkillEventService>,
+    reminder_service: Arc<ReminderService>,
+    maintenance_flag: Arc<dyn MaintenanceFlag>,
+    preferences_service: Arc<PreferencesService>,
+    history_service: Arc<HistoryService>,
+    user_data_service: Arc<UserDataService>,
+    market_service: Arc<MarketService>,
+    profile_service: Arc<ProfileService>,
+    household_accounts: Arc<HouseholdAccounts>,
+    bundle_service: Arc<BundleService>,
+    speech_style: SpeechStyle,
+    add_more: bool,
+    phrase_variation: PhraseVariation,
+    pantry: PantryStaples,
+    token_provider: Option<Arc<dyn TokenProvider>>,
 }
 
-impl<R: ShoppingListRepository> AlexaSkillHandler<R> {
-    /// Creates a new AlexaSkillHandler with the given service.
-    pub fn new(add_item_service: Arc<AddItemService<R>>) -> Self {
-        Self { add_item_service }
+impl AlexaSkillHandler {
+    /// Creates a new AlexaSkillHandler with the given services.
+    ///
+    /// The list summary reads through `read_list_service` with the built-in
+    /// categories unless set with [`Self::with_list_summary_service`].
+    /// Occasion lists, skill events, user preferences and the add history
+    /// are kept in in-memory stores unless other services are set with
+    /// [`Self::with_occasion_list_service`],
+    /// [`Self::with_skill_event_service`],
+    /// [`Self::with_preferences_service`] and
+    /// [`Self::with_history_service`]; "lösche meine Daten" only reaches
+    /// the stores of a service set with [`Self::with_user_data_service`].
+    /// Reminders go to the Alexa
+    /// Reminders API unless replaced with [`Self::with_reminder_service`].
+    /// The Cookidoo market is detected from the device address unless
+    /// replaced with [`Self::with_market_service`], and the welcome names
+    /// the user via the Alexa profile APIs unless replaced with
+    /// [`Self::with_profile_service`]. Cookidoo is used with the configured
+    /// account unless further ones are set with
+    /// [`Self::with_household_accounts`]. Only the bundles the user creates
+    /// by voice are known unless others are configured on a service set
+    /// with [`Self::with_bundle_service`]; the default service stores them
+    /// in the same in-memory store as the preferences.
+    /// Maintenance mode is off unless a flag is set with
+    /// [`Self::with_maintenance_flag`]. Answers use full sentences unless
+    /// changed with [`Self::with_speech_style`] or by the user's preferences.
+    /// Adds end the session unless [`Self::with_add_more`] asks for more.
+    /// Phrasings vary randomly unless fixed with
+    /// [`Self::with_phrase_variation`]. Transfers skip no pantry staples
+    /// unless set with [`Self::with_pantry`] or by the user's preferences.
+    /// No token is refreshed after a request unless a provider is set with
+    /// [`Self::with_token_refresh`].
+    pub fn new(
+        add_item_service: Arc<AddItemService>,
+        read_list_service: Arc<ReadListService>,
+        remove_item_service: Arc<RemoveItemService>,
+    ) -> Self {
+        let preferences = Arc::new(InMemoryPreferencesRepository::new());
+        Self {
+            add_item_service,
+            list_summary_service: Arc::new(ListSummaryService::new(read_list_service.clone())),
+            read_list_service,
+            remove_item_service,
+            occasion_list_service: Arc::new(OccasionListService::new(Arc::new(
+                InMemoryOccasionListStore::new(),
+            ))),
+            skill_event_service: Arc::new(SkillEventService::new(Arc::new(
+                InMemoryPermissionStore::new(),
+            ))),
+            reminder_service: Arc::new(ReminderService::new(Arc::new(AlexaRemindersClient::new()))),
+            maintenance_flag: Arc::new(StaticMaintenanceFlag::new(false)),
+            preferences_service: Arc::new(PreferencesService::new(preferences.clone())),
+            history_service: Arc::new(HistoryService::new(Arc::new(
+                InMemoryHistoryRepository::new(),
+            ))),
+            user_data_service: Arc::new(UserDataService::new()),
+            market_service: Arc::new(MarketService::new(
+                Arc::new(AlexaDeviceAddressClient::new()),
+            )),
+            profile_service: Arc::new(ProfileService::new(Arc::new(
+                AlexaCustomerProfileClient::new(),
+            ))),
+            household_accounts: Arc::new(HouseholdAccounts::new()),
+            bundle_service: Arc::new(BundleService::new(preferences)),
+            speech_style: SpeechStyle::default(),
+            add_more: false,
+            phrase_variation: PhraseVariation::default(),
+            pantry: PantryStaples::default(),
+            token_provider: None,
+        }
+    }
+
+    /// Sets the service used to summarize the shopping list.
+    pub fn with_list_summary_service(
+        mut self,
+        list_summary_service: Arc<ListSummaryService>,
+    ) -> Self {
+        self.list_summary_service = list_summary_service;
+        self
+    }
+
+    /// Sets the service used for gift/occasion lists.
+    pub fn with_occasion_list_service(
+        mut self,
+        occasion_list_service: Arc<OccasionListService>,
+    ) -> Self {
+        self.occasion_list_service = occasion_list_service;
+        self
+    }
+
+    /// Sets the service used to record permission and subscription events.
+    pub fn with_skill_event_service(mut self, skill_event_service: Arc<SkillEventService>) -> Self {
+        self.skill_event_service = skill_event_service;
+        self
+    }
+
+    /// Sets the service used to schedule reminders.
+    pub fn with_reminder_service(mut self, reminder_service: Arc<ReminderService>) -> Self {
+        self.reminder_service = reminder_service;
+        self
+    }
+
+    /// Sets the Cookidoo token provider refreshed in the background after
+    /// each request.
+    pub fn with_token_refresh(mut self, token_provider: Arc<dyn TokenProvider>) -> Self {
+        self.token_provider = Some(token_provider);
+        self
+    }
+
+    /// Sets the flag that switches the skill into maintenance mode.
+    pub fn with_maintenance_flag(mut self, maintenance_flag: Arc<dyn MaintenanceFlag>) -> Self {
+        self.maintenance_flag = maintenance_flag;
+        self
+    }
+
+    /// Sets the service used to read and change user preferences.
+    pub fn with_preferences_service(
+        mut self,
+        preferences_service: Arc<PreferencesService>,
+    ) -> Self {
+        self.preferences_service = preferences_service;
+        self
+    }
+
+    /// Sets the service recording and reading the history of added items.
+    pub fn with_history_service(mut self, history_service: Arc<HistoryService>) -> Self {
+        self.history_service = history_service;
+        self
+    }
+
+    /// Sets the service deleting a user's stored data on request.
+    pub fn with_user_data_service(mut self, user_data_service: Arc<UserDataService>) -> Self {
+        self.user_data_service = user_data_service;
+        self
+    }
+
+    /// Sets the service choosing the Cookidoo market of a request.
+    pub fn with_market_service(mut self, market_service: Arc<MarketService>) -> Self {
+        self.market_service = market_service;
+        self
+    }
+
+    /// Sets the service looking up the name the welcome addresses.
+    pub fn with_profile_service(mut self, profile_service: Arc<ProfileService>) -> Self {
+        self.profile_service = profile_service;
+        self
+    }
+
+    /// Sets the further Cookidoo accounts of the household, chosen by voice
+    /// or with "benutze Papas Liste".
+    pub fn with_household_accounts(mut self, household_accounts: Arc<HouseholdAccounts>) -> Self {
+        self.household_accounts = household_accounts;
+        self
+    }
+
+    /// Sets the service for item bundles, e.g. "füge mein Frühstücks-Set
+    /// hinzu". It should store bundles with the preferences of the service
+    /// set with [`Self::with_preferences_service`].
+    pub fn with_bundle_service(mut self, bundle_service: Arc<BundleService>) -> Self {
+        self.bundle_service = bundle_service;
+        self
+    }
+
+    /// Sets how verbose confirmations and the welcome are for users who
+    /// have not chosen a style themselves.
+    pub fn with_speech_style(mut self, speech_style: SpeechStyle) -> Self {
+        self.speech_style = speech_style;
+        self
+    }
+
+    /// Sets the pantry staples skipped when a list is transferred, for
+    /// users who have not set their own.
+    pub fn with_pantry(mut self, pantry: PantryStaples) -> Self {
+        self.pantry = pantry;
+        self
+    }
+
+    /// Sets whether a successful add asks "Noch etwas?" and keeps the
+    /// session open for the next item.
+    pub fn with_add_more(mut self, add_more: bool) -> Self {
+        self.add_more = add_more;
+        self
+    }
+
+    /// Sets how alternative phrasings are chosen, e.g. a seed so tests can
+    /// assert exact speech.
+    pub fn with_phrase_variation(mut self, phrase_variation: PhraseVariation) -> Self {
+        self.phrase_variation = phrase_variation;
+        self
+    }
+
+    /// Prepares the shopping list backend for the next requests, e.g. on a
+    /// scheduled warm-up event; returns true if it is ready.
+    pub async fn warm_up(&self) -> bool {
+        self.read_list_service.warm_up().await
+    }
+
+    /// Starts refreshing an expiring Cookidoo token in the background after
+    /// a request has been handled, so the next one does not wait for it.
+    pub fn refresh_in_background(&self) {
+        if let Some(token_provider) = &self.token_provider {
+            Arc::clone(token_provider).refresh_in_background();
+        }
+    }
+
+    /// Returns the Cookidoo market to use for `request`.
+    ///
+    /// The device address is only read once the user granted access to it;
+    /// until then the configured or default market is used.
+    pub async fn market(&self, request: &AlexaRequest) -> Market {
+        let api_access = request
+            .api_access()
+            .filter(|_| PermissionChecker::is_granted(request, DEVICE_ADDRESS_SCOPE));
+        self.market_service
+            .market(api_access, request.device_id())
+            .await
+    }
+
+    /// Returns the household account whose Cookidoo list `request` uses, or
+    /// `None` for the configured one.
+    ///
+    /// An account chosen by voice wins over the one assigned to the
+    /// recognized speaker; a chosen account that is no longer configured
+    /// counts as unset.
+    pub async fn account(&self, request: &AlexaRequest) -> Option<String> {
+        if self.household_accounts.is_empty() {
+            return None;
+        }
+        // Only recognized speakers choose an account, so a choice never
+        // applies to guests or the whole household
+        let chosen = match (request.user_id(), request.person_id()) {
+            (Some(user_id), Some(_)) => self
+                .preferences_service
+                .preferences(user_id, request.person_id())
+                .await
+                .account()
+                .cloned(),
+            _ => None,
+        };
+        let account = match chosen {
+            Some(AccountChoice::Household) => return None,
+            Some(AccountChoice::Named(name)) => self.household_accounts.find(&name),
+            None => None,
+        };
+        account
+            .or_else(|| {
+                request
+                    .person_id()
+                    .and_then(|person_id| self.household_accounts.for_person(person_id))
+            })
+            .map(|account| account.name().to_string())
     }
 
     /// Handles an Alexa request and returns an appropriate response.
+    ///
+    /// Responses that keep the session open are remembered in the session
+    /// attributes, so `AMAZON.RepeatIntent` can speak them again, and carry
+    /// the greeting read at launch for the rest of the session.
     pub async fn handle(&self, request: AlexaRequest) -> AlexaResponse {
-        let intent = intent_parser::parse(&request);
+        let mut response = self.respond(&request).await;
+
+        if !response.response.should_end_session {
+            let mut state = SessionState::from_attributes(&response.session_attributes);
+            if let Some(reprompt) = &response.response.reprompt {
+                state = state.with_last_response(
+                    &response.response.output_speech.text,
+                    &reprompt.output_speech.text,
+                );
+            }
+            if state.greeting().is_none() {
+                if let Some(greeting) = SessionState::from_request(&request).greeting() {
+                    state = state.with_greeting(greeting.clone());
+                }
+            }
+            response.session_attributes = state.to_attributes();
+        }
+
+        response
+    }
+
+    async fn respond(&self, request: &AlexaRequest) -> AlexaResponse {
+        // Alexa ignores any speech in the answer to a session end
+        if let Request::SessionEnded(ended) = &request.request {
+            Self::record_session_end(ended);
+            return ResponseBuilder::acknowledge();
+        }
+
+        // Request types added by Alexa after this skill was built
+        if let Request::Unknown(unknown) = &request.request {
+            warn!(request_type = %unknown.request_type, "Ignoring unsupported request type");
+            return ResponseBuilder::acknowledge();
+        }
+
+        if Self::lacks_item(request) {
+            info!("Delegating add without item to the dialog");
+            return ResponseBuilder::delegate(None);
+        }
 
-        info!(intent = ?intent, "Processing Alexa request");
+        let intent = Self::answering_pending(intent_parser::parse(request), request);
+        let phrases = self
+            .phrase_variation
+            .selector(
+                request
+                    .session
+                    .as_ref()
+                    .map(|session| session.session_id.as_str()),
+            )
+            .with_locale(request.request.locale());
+
+        info!(intent = intent.kind(), "Processing Alexa request");
+
+        if Self::needs_service(&intent) && self.in_maintenance().await {
+            info!("Skill in maintenance, skipping request");
+            return ResponseBuilder::maintenance();
+        }
+
+        let preferences = match request.user_id() {
+            Some(user_id) if Self::needs_service(&intent) => {
+                self.preferences_service
+                    .preferences(user_id, request.person_id())
+                    .await
+            }
+            _ => UserPreferences::default(),
+        };
+        let style = preferences.speech_style().unwrap_or(self.speech_style);
+        let intent = Self::with_default_list(intent, preferences.default_list());
 
         match intent {
             ParsedIntent::Launch => {
                 info!("Handling launch request");
-                ResponseBuilder::launch()
+                let greeting = self.greeting(request).await;
+                let response = match &greeting.name {
+                    Some(name) => ResponseBuilder::personal_launch(name, &phrases, style),
+                    None => ResponseBuilder::launch(&phrases, style),
+                };
+                let state = SessionState::from_attributes(&response.session_attributes)
+                    .with_greeting(greeting);
+                response.with_session_attributes(state.to_attributes())
+            }
+
+            ParsedIntent::AddItem {
+                item_name,
+                note,
+                list: Some(list),
+            } => {
+                self.add_to_occasion_list(request, &list, &item_name, note.as_deref(), &phrases)
+                    .await
+            }
+
+            ParsedIntent::AddItem {
+                item_name,
+                note,
+                list: None,
+            } => {
+                self.add_item(request, &item_name, note.as_deref(), &phrases, style)
+                    .await
+            }
+
+            ParsedIntent::AddItems { item_names } => {
+                self.add_items(request, &item_names, &phrases, style).await
+            }
+
+            ParsedIntent::AddBundle { name } => {
+                self.add_bundle(request, &name, &preferences, &phrases, style)
+                    .await
+            }
+
+            ParsedIntent::ExtendBundle { name, item_name } => {
+                self.extend_bundle(request, &name, &item_name, &phrases)
+                    .await
+            }
+
+            ParsedIntent::DeleteBundle { name } => {
+                self.delete_bundle(request, &name, &phrases).await
+            }
+
+            ParsedIntent::ReadBundle { name } => {
+                self.read_bundle(request, name.as_deref(), &phrases).await
+            }
+
+            ParsedIntent::ReadList { list: Some(list) } => {
+                self.read_occasion_list(request, &list, &phrases).await
+            }
+
+            ParsedIntent::ReadList { list: None } => {
+                info!("Handling read list request");
+                match self.read_list_service.execute().await {
+                    Ok(entries) => ListPresenter::readout(&entries, &phrases),
+                    Err(e) => ResponseBuilder::failure(&e, MessageId::ReadFailed),
+                }
+            }
+
+            ParsedIntent::ReadOpenItems => {
+                info!("Handling read open items request");
+                match self.read_list_service.open_items().await {
+                    Ok(entries) => ListPresenter::readout_open(&entries, &phrases),
+                    Err(e) => ResponseBuilder::failure(&e, MessageId::ReadFailed),
+                }
+            }
+
+            ParsedIntent::ReadHistory { period } => {
+                self.read_history(request, period, &phrases).await
+            }
+
+            ParsedIntent::CountItems => {
+                info!("Handling list summary request");
+                match self.list_summary_service.execute().await {
+                    Ok(summary) => ListPresenter::summary(&summary, &phrases),
+                    Err(e) => ResponseBuilder::failure(&e, MessageId::ReadFailed),
+                }
             }
 
-            ParsedIntent::AddItem { item_name } => {
-                info!(item_name = %item_name, "Handling add item request");
-                match self.add_item_service.execute(&item_name).await {
-                    Ok(message) => ResponseBuilder::success(message),
-                    Err(message) => ResponseBuilder::error(message),
+            ParsedIntent::TransferList { list } => {
+                self.transfer_list(request, &list, &preferences, &phrases)
+                    .await
+            }
+
+            ParsedIntent::RemoveItem {
+                item_name,
+                list: Some(list),
+            } => {
+                self.remove_from_occasion_list(request, &list, &item_name, &phrases)
+                    .await
+            }
+
+            ParsedIntent::RemoveItem {
+                item_name,
+                list: None,
+            } => {
+                info!(item_name = %item_name, "Handling remove item request");
+                match self.remove_item_service.execute(&item_name).await {
+                    Ok(item) => ResponseBuilder::success(
+                        Speech::new(MessageId::ItemRemoved)
+                            .with("item", item)
+                            .render(&phrases),
+                    ),
+                    Err(e) => ResponseBuilder::failure(&e, MessageId::RemoveFailed),
+                }
+            }
+
+            ParsedIntent::RemoveItemAt { position } => {
+                self.remove_item_at(request, position, &phrases).await
+            }
+
+            ParsedIntent::CheckOffItem { item_id } => {
+                info!(item_id = %item_id, "Handling check off from touch event");
+                match self.remove_item_service.check_off_by_id(&item_id).await {
+                    Ok(Some(item)) => ResponseBuilder::success(
+                        Speech::new(MessageId::ItemCheckedOff)
+                            .with("item", item)
+                            .render(&phrases),
+                    ),
+                    Ok(None) => {
+                        ResponseBuilder::success(Speech::new(MessageId::ItemGone).render(&phrases))
+                    }
+                    Err(e) => ResponseBuilder::failure(&e, MessageId::RemoveFailed),
+                }
+            }
+
+            ParsedIntent::Remind { item_name, offset } => {
+                self.remind(request, &item_name, offset, &preferences, &phrases)
+                    .await
+            }
+
+            ParsedIntent::ChangeSetting { change } => {
+                self.change_setting(request, change, &phrases).await
+            }
+
+            ParsedIntent::DeleteMyData => {
+                info!("Handling delete data request");
+                if request.user_id().is_none() {
+                    return ResponseBuilder::success(
+                        Speech::new(MessageId::NoUserData).render(&phrases),
+                    );
                 }
+                ResponseBuilder::question(
+                    Speech::new(MessageId::DeleteDataQuestion).render(&phrases),
+                    Speech::new(MessageId::DeleteDataReprompt).render(&phrases),
+                )
+                .with_session_attributes(
+                    SessionState::pending(PendingAction::DeleteMyData).to_attributes(),
+                )
             }
 
             ParsedIntent::Help => {
                 info!("Handling help request");
-                ResponseBuilder::help()
+                ResponseBuilder::help(&phrases)
             }
 
-            ParsedIntent::Cancel | ParsedIntent::Stop => {
-                info!("Handling cancel/stop request");
-                ResponseBuilder::goodbye()
+            ParsedIntent::Yes => self.answer_yes(request, &phrases).await,
+
+            ParsedIntent::No => match SessionState::from_request(request).pending_action() {
+                Some(PendingAction::AddItem) => {
+                    info!("Declined offer to add an item");
+                    ResponseBuilder::cancelled(&phrases)
+                }
+                Some(PendingAction::DeleteMyData) => {
+                    info!("Declined data deletion");
+                    ResponseBuilder::cancelled(&phrases)
+                }
+                Some(PendingAction::AddMore { .. } | PendingAction::ContinueReading { .. })
+                | None => {
+                    info!("Handling no");
+                    ResponseBuilder::goodbye(&phrases)
+                }
+            },
+
+            ParsedIntent::Cancel => match SessionState::from_request(request).pending_action() {
+                Some(action) if !matches!(action, PendingAction::AddMore { .. }) => {
+                    info!(action = ?action, "Cancelling pending action");
+                    ResponseBuilder::cancelled(&phrases)
+                }
+                _ => {
+                    info!("Handling cancel request");
+                    ResponseBuilder::goodbye(&phrases)
+                }
+            },
+
+            // Responses carry only the state they set, so the fresh welcome
+            // drops anything pending from earlier turns.
+            ParsedIntent::StartOver => {
+                info!("Handling start over request");
+                ResponseBuilder::launch(&phrases, style)
             }
 
-            ParsedIntent::Unknown => {
-                info!("Handling unknown request");
-                ResponseBuilder::unknown()
+            ParsedIntent::Repeat => {
+                let state = SessionState::from_request(request);
+                match state.last_response() {
+                    Some(last) => {
+                        info!("Repeating last response");
+                        ResponseBuilder::question(&last.speech, &last.reprompt)
+                            .with_session_attributes(state.to_attributes())
+                    }
+                    None => {
+                        info!("Nothing to repeat");
+                        ResponseBuilder::nothing_to_repeat(&phrases)
+                    }
+                }
             }
-        }
-    }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::domain::models::{DomainError, ShoppingListItem};
-    use async_trait::async_trait;
+            ParsedIntent::Stop => {
+                info!("Handling stop request");
+                ResponseBuilder::goodbye(&phrases)
+            }
 
-    struct MockRepository {
-        should_fail: bool,
-    }
+            ParsedIntent::PermissionsChanged { user_id, scopes } => {
+                info!(scopes = ?scopes, "Handling permission change event");
+                if let Err(e) = self
+                    .skill_event_service
+                    .permissions_changed(&user_id, &scopes)
+                    .await
+                {
+                    error!(error = %e, "Failed to record permission change");
+                }
+                ResponseBuilder::acknowledge()
+            }
 
-    impl MockRepository {
-        fn new() -> Self {
-            Self { should_fail: false }
-        }
+            ParsedIntent::SubscriptionsChanged { user_id, events } => {
+                info!(events = ?events, "Handling proactive subscription change event");
+                if let Err(e) = self
+                    .skill_event_service
+                    .subscriptions_changed(&user_id, &events)
+                    .await
+                {
+                    error!(error = %e, "Failed to record subscription change");
+                }
+                ResponseBuilder::acknowledge()
+            }
 
-        fn failing() -> Self {
-            Self { should_fail: true }
+            ParsedIntent::SkillEnabled { user_id } => {
+                info!("Handling skill enabled event");
+                if let Err(e) = self.skill_event_service.skill_enabled(&user_id).await {
+                    error!(error = %e, "Failed to set up enabled skill");
+                }
+                ResponseBuilder::acknowledge()
+            }
+
+            ParsedIntent::SkillDisabled {
+                user_id,
+                keeps_user_data,
+            } => {
+                info!(keeps_user_data, "Handling skill disabled event");
+                if let Err(e) = self
+                    .skill_event_service
+                    .skill_disabled(&user_id, keeps_user_data)
+                    .await
+                {
+                    error!(error = %e, "Failed to clean up disabled skill");
+                }
+                ResponseBuilder::acknowledge()
+            }
+
+            // The linked token arrives with every request, so a linked
+            // account needs no per-user record
+            ParsedIntent::AccountLinked { .. } => {
+                info!("Handling account linked event");
+                ResponseBuilder::acknowledge()
+            }
+
+            // An item named outside "Noch etwas?" (see `answering_pending`)
+            ParsedIntent::FollowUpItem { .. } | ParsedIntent::Unknown => {
+                info!("Handling unknown request");
+                ResponseBuilder::unknown(&phrases)
+            }
         }
     }
 
-    #[async_trait]
-    impl ShoppingListRepository for MockRepository {
-        async fn add_item(&self, _item: &ShoppingListItem) -> Result<(), DomainError> {
-            if self.should_fail {
-                Err(DomainError::RepositoryError("Test error".to_string()))
-            } else {
-                Ok(())
+    /// Adds an item to an occasion list, offering to add more if enabled.
+    async fn add_to_occasion_list(
+        &self,
+        request: &AlexaRequest,
+        list: &ListName,
+        item_name: &str,
+        note: Option<&str>,
+        phrases: &PhraseSelector,
+    ) -> AlexaResponse {
+        info!(item_name = %item_name, list = %list.key(), "Handling add to occasion list");
+        let Some(user_id) = request.user_id() else {
+            return ResponseBuilder::error(Speech::new(MessageId::NoUserList).render(phrases));
+        };
+        match self
+            .occasion_list_service
+            .add(user_id, list, item_name, note)
+            .await
+        {
+            Ok(label) => {
+                let message = Speech::new(MessageId::OccasionItemAdded)
+                    .with("item", label)
+                    .with("list", list.label())
+                    .render(phrases);
+                if self.add_more {
+                    ResponseBuilder::ask_for_more(message).with_session_attributes(
+                        SessionState::pending(PendingAction::AddMore {
+                            list: Some(list.clone()),
+                        })
+                        .to_attributes(),
+                    )
+                } else {
+                    ResponseBuilder::confirmation(message, phrases)
+                }
             }
+            Err(e) => ResponseBuilder::error(occasion_failure_speech(&e, list)),
         }
     }
 
-    fn make_handler(repo: MockRepository) -> AlexaSkillHandler<MockRepository> {
-        let service = Arc::new(AddItemService::new(Arc::new(repo)));
-        AlexaSkillHandler::new(service)
+    /// Adds an item to the shopping list once per request, with the recent
+    /// entries on the card.
+    async fn add_item(
+        &self,
+        request: &AlexaRequest,
+        item_name: &str,
+        note: Option<&str>,
+        phrases: &PhraseSelector,
+        style: SpeechStyle,
+    ) -> AlexaResponse {
+        info!(item_name = %item_name, note = ?note, "Handling add item request");
+        let (outcome, recent) = tokio::join!(
+            self.add_item_service
+                .execute_once(request.request.request_id(), item_name, note),
+            self.recent_entries(),
+        );
+        self.record_history(request, &outcome).await;
+        let response = if self.add_more && outcome.is_success() {
+            ResponseBuilder::add_more(&outcome, phrases, style).with_session_attributes(
+                SessionState::pending(PendingAction::AddMore { list: None }).to_attributes(),
+            )
+        } else {
+            ResponseBuilder::add_item(&outcome, phrases, style)
+        };
+        Self::with_list_card(response, std::slice::from_ref(&outcome), recent)
     }
 
-    fn make_launch_request() -> AlexaRequest {
-        serde_json::from_str(
-            r#"{
-                "version": "1.0",
-                "request": {
-                    "type": "LaunchRequest",
-                    "requestId": "req-123",
-                    "timestamp": "2024-01-27T10:00:00Z",
-                    "locale": "de-DE"
+    /// Adds several items named in one request, each once, stopping at a
+    /// failure that would fail the rest as well.
+    async fn add_items(
+        &self,
+        request: &AlexaRequest,
+        item_names: &[String],
+        phrases: &PhraseSelector,
+        style: SpeechStyle,
+    ) -> AlexaResponse {
+        info!(item_names = ?item_names, "Handling add items request");
+        let add_all = async {
+            let mut outcomes = Vec::with_capacity(item_names.len());
+            for (index, item_name) in item_names.iter().enumerate() {
+                let idempotency_key = format!("{}#{}", request.request.request_id(), index);
+                let outcome = self
+                    .add_item_service
+                    .execute_once(&idempotency_key, item_name, None)
+                    .await;
+                self.record_history(request, &outcome).await;
+                let stops = outcome.stops_batch();
+                outcomes.push(outcome);
+                if stops {
+                    break;
                 }
-            }"#,
-        )
-        .unwrap()
+            }
+            outcomes
+        };
+        let (outcomes, recent) = tokio::join!(add_all, self.recent_entries());
+        let response = if self.add_more && outcomes.iter().all(AddItemOutcome::is_success) {
+            ResponseBuilder::add_more_items(&outcomes, phrases, style).with_session_attributes(
+                SessionState::pending(PendingAction::AddMore { list: None }).to_attributes(),
+            )
+        } else {
+            ResponseBuilder::add_items(&outcomes, phrases, style)
+        };
+        Self::with_list_card(response, &outcomes, recent)
     }
 
-    fn make_add_item_request(item: &str) -> AlexaRequest {
-        let json = format!(
-            r#"{{
-                "version": "1.0",
-                "request": {{
-                    "type": "IntentRequest",
-                    "requestId": "req-123",
-                    "timestamp": "2024-01-27T10:00:00Z",
-                    "locale": "de-DE",
-                    "intent": {{
-                        "name": "AddItemIntent",
-                        "slots": {{
-                            "Item": {{"name": "Item", "value": "{item}"}}
-                        }}
-                    }}
-                }}
-            }}"#
+    /// Adds every item of a bundle, each once per request.
+    async fn add_bundle(
+        &self,
+        request: &AlexaRequest,
+        name: &str,
+        preferences: &UserPreferences,
+        phrases: &PhraseSelector,
+        style: SpeechStyle,
+    ) -> AlexaResponse {
+        info!(bundle = %name, "Handling add bundle request");
+        let Some(bundle) = self.bundle_service.find(preferences.bundles(), name) else {
+            return ResponseBuilder::success(
+                Speech::new(MessageId::UnknownBundle)
+                    .with("name", name)
+                    .render(phrases),
+            );
+        };
+        let (outcomes, recent) = tokio::join!(
+            self.add_item_service
+                .execute_all_once(request.request.request_id(), bundle.items()),
+            self.recent_entries(),
         );
-        serde_json::from_str(&json).unwrap()
+        for outcome in &outcomes {
+            self.record_history(request, outcome).await;
+        }
+        let response = ResponseBuilder::add_items(&outcomes, phrases, style);
+        Self::with_list_card(response, &outcomes, recent)
     }
 
-    fn make_help_request() -> AlexaRequest {
-        serde_json::from_str(
-            r#"{
-                "version": "1.0",
-                "request": {
-                    "type": "IntentRequest",
-                    "requestId": "req-123",
-                    "timestamp": "2024-01-27T10:00:00Z",
-                    "locale": "de-DE",
-                    "intent": {"name": "AMAZON.HelpIntent", "slots": {}}
-                }
-            }"#,
-        )
-        .unwrap()
+    /// Adds an item to one of the user's bundles, creating it if needed.
+    async fn extend_bundle(
+        &self,
+        request: &AlexaRequest,
+        name: &str,
+        item_name: &str,
+        phrases: &PhraseSelector,
+    ) -> AlexaResponse {
+        info!(bundle = %name, item_name = %item_name, "Handling extend bundle request");
+        let Some(user_id) = request.user_id() else {
+            return ResponseBuilder::error(Speech::new(MessageId::NoUserBundle).render(phrases));
+        };
+        match self.bundle_service.extend(user_id, name, item_name).await {
+            Ok(outcome) => ResponseBuilder::success(bundle_speech(&outcome)),
+            Err(e) => ResponseBuilder::error(bundle_failure_speech(&e)),
+        }
     }
 
-    fn make_stop_request() -> AlexaRequest {
-        serde_json::from_str(
-            r#"{
-                "version": "1.0",
-                "request": {
-                    "type": "IntentRequest",
-                    "requestId": "req-123",
-                    "timestamp": "2024-01-27T10:00:00Z",
-                    "locale": "de-DE",
-                    "intent": {"name": "AMAZON.StopIntent", "slots": {}}
-                }
-            }"#,
-        )
-        .unwrap()
+    /// Deletes one of the user's bundles.
+    async fn delete_bundle(
+        &self,
+        request: &AlexaRequest,
+        name: &str,
+        phrases: &PhraseSelector,
+    ) -> AlexaResponse {
+        info!(bundle = %name, "Handling delete bundle request");
+        let Some(user_id) = request.user_id() else {
+            return ResponseBuilder::error(Speech::new(MessageId::NoUserBundle).render(phrases));
+        };
+        match self.bundle_service.delete(user_id, name).await {
+            Ok(outcome) => ResponseBuilder::success(bundle_speech(&outcome)),
+            Err(e) => ResponseBuilder::error(bundle_failure_speech(&e)),
+        }
     }
 
-    #[tokio::test]
-    async fn handles_launch_request() {
-        let handler = make_handler(MockRepository::new());
-        let response = handler.handle(make_launch_request()).await;
+    /// Reads the items of a bundle, or the names of all bundles.
+    async fn read_bundle(
+        &self,
+        request: &AlexaRequest,
+        name: Option<&str>,
+        phrases: &PhraseSelector,
+    ) -> AlexaResponse {
+        info!(bundle = ?name, "Handling read bundle request");
+        let Some(user_id) = request.user_id() else {
+            return ResponseBuilder::error(Speech::new(MessageId::NoUserBundle).render(phrases));
+        };
+        match self.bundle_service.describe(user_id, name).await {
+            Ok(outcome) => ResponseBuilder::success(bundle_speech(&outcome)),
+            Err(e) => ResponseBuilder::error(bundle_failure_speech(&e)),
+        }
+    }
 
-        assert!(!response.response.should_end_session);
-        assert!(response.response.output_speech.text.contains("Willkommen"));
+    /// Reads an occasion list aloud.
+    async fn read_occasion_list(
+        &self,
+        request: &AlexaRequest,
+        list: &ListName,
+        phrases: &PhraseSelector,
+    ) -> AlexaResponse {
+        info!(list = %list.key(), "Handling read occasion list request");
+        let Some(user_id) = request.user_id() else {
+            return ResponseBuilder::error(Speech::new(MessageId::NoUserList).render(phrases));
+        };
+        match self.occasion_list_service.read(user_id, list).await {
+            Ok(entries) => ListPresenter::readout_named(list, &entries, phrases),
+            Err(e) => ResponseBuilder::error(occasion_failure_speech(&e, list)),
+        }
     }
 
-    #[tokio::test]
-    async fn handles_add_item_success() {
-        let handler = make_handler(MockRepository::new());
-        let response = handler.handle(make_add_item_request("Milch")).await;
+    /// Moves the items of an occasion list to the shopping list, leaving
+    /// pantry staples behind.
+    async fn transfer_list(
+        &self,
+        request: &AlexaRequest,
+        list: &ListName,
+        preferences: &UserPreferences,
+        phrases: &PhraseSelector,
+    ) -> AlexaResponse {
+        info!(list = %list.key(), "Handling occasion list transfer");
+        let Some(user_id) = request.user_id() else {
+            return ResponseBuilder::error(Speech::new(MessageId::NoUserList).render(phrases));
+        };
+        let pantry = preferences.pantry().unwrap_or(&self.pantry);
+        let transfer = ListTransferService::new(
+            self.occasion_list_service.clone(),
+            self.add_item_service.clone(),
+        )
+        .with_pantry(pantry.clone());
+        match transfer.execute(user_id, list).await {
+            Ok(outcome) if outcome.is_failure() => {
+                ResponseBuilder::error(transfer_speech(&outcome, list))
+            }
+            Ok(outcome) => ResponseBuilder::success(transfer_speech(&outcome, list)),
+            Err(e) => ResponseBuilder::error(occasion_failure_speech(&e, list)),
+        }
+    }
 
-        assert!(response.response.should_end_session);
-        assert!(response.response.output_speech.text.contains("Milch"));
-        assert!(response.response.output_speech.text.contains("hinzugefügt"));
+    /// Removes an item from an occasion list.
+    async fn remove_from_occasion_list(
+        &self,
+        request: &AlexaRequest,
+        list: &ListName,
+        item_name: &str,
+        phrases: &PhraseSelector,
+    ) -> AlexaResponse {
+        info!(item_name = %item_name, list = %list.key(), "Handling remove from occasion list");
+        let Some(user_id) = request.user_id() else {
+            return ResponseBuilder::error(Speech::new(MessageId::NoUserList).render(phrases));
+        };
+        match self
+            .occasion_list_service
+            .remove(user_id, list, item_name)
+            .await
+        {
+            Ok(item) => ResponseBuilder::success(
+                Speech::new(MessageId::OccasionItemRemoved)
+                    .with("item", item)
+                    .with("list", list.label())
+                    .render(phrases),
+            ),
+            Err(e) => ResponseBuilder::error(occasion_failure_speech(&e, list)),
+        }
     }
 
-    #[tokio::test]
-    async fn handles_add_item_failure() {
-        let handler = make_handler(MockRepository::failing());
-        let response = handler.handle(make_add_item_request("Milch")).await;
+    /// Adds an item and schedules a reminder for it, asking for the
+    /// reminders permission first if it is missing.
+    async fn remind(
+        &self,
+        request: &AlexaRequest,
+        item_name: &str,
+        offset: Option<Duration>,
+        preferences: &UserPreferences,
+        phrases: &PhraseSelector,
+    ) -> AlexaResponse {
+        info!(item_name = %item_name, offset = ?offset, "Handling reminder request");
+        if !PermissionChecker::is_granted(request, REMINDERS_SCOPE) {
+            info!("Reminders permission not granted, sending consent card");
+            return ResponseBuilder::permission_request(
+                Speech::new(MessageId::ReminderConsent)
+                    .with("item", item_name.trim())
+                    .render(phrases),
+                &[REMINDERS_SCOPE],
+            );
+        }
 
-        assert!(response.response.should_end_session);
-        assert!(response
-            .response
-            .output_speech
-            .text
-            .contains("nicht hinzugefügt"));
+        let outcome = self
+            .add_item_service
+            .execute_once(request.request.request_id(), item_name, None)
+            .await;
+        self.record_history(request, &outcome).await;
+        let added = add_item_speech(&outcome);
+        if !outcome.is_success() {
+            return ResponseBuilder::error(added);
+        }
+
+        let text = Speech::new(MessageId::ReminderText)
+            .with("item", item_name.trim())
+            .render(phrases);
+        match self
+            .reminder_service
+            .remind(
+                request.api_access(),
+                &text,
+                offset,
+                preferences
+                    .locale()
+                    .or(request.request.locale())
+                    .unwrap_or(DEFAULT_LOCALE),
+                request.request.timestamp(),
+            )
+            .await
+        {
+            Ok(offset) => ResponseBuilder::confirmation(
+                Speech::new(MessageId::ReminderSet)
+                    .with("added", added)
+                    .with(
+                        "reminder",
+                        Speech::new(MessageId::ReminderScheduled)
+                            .with("offset", offset_speech(offset))
+                            .render(phrases),
+                    )
+                    .render(phrases),
+                phrases,
+            ),
+            Err(ReminderError::PermissionRequired) => ResponseBuilder::permission_request(
+                Speech::new(MessageId::ReminderPermission)
+                    .with("added", added)
+                    .render(phrases),
+                &[REMINDERS_SCOPE],
+            ),
+            Err(ReminderError::Failed) => ResponseBuilder::success(
+                Speech::new(MessageId::ReminderSet)
+                    .with("added", added)
+                    .with(
+                        "reminder",
+                        Speech::new(MessageId::ReminderFailed).render(phrases),
+                    )
+                    .render(phrases),
+            ),
+        }
+    }
+
+    /// Changes a setting of the user, or of the recognized speaker.
+    async fn change_setting(
+        &self,
+        request: &AlexaRequest,
+        change: SettingChange,
+        phrases: &PhraseSelector,
+    ) -> AlexaResponse {
+        info!(change = ?change, "Handling settings request");
+        let Some(user_id) = request.user_id() else {
+            return ResponseBuilder::error(Speech::new(MessageId::NoUserSettings).render(phrases));
+        };
+        if matches!(change, SettingChange::Account(_)) && request.person_id().is_none() {
+            return ResponseBuilder::success(
+                Speech::new(MessageId::UnrecognizedSpeaker).render(phrases),
+            );
+        }
+        // Stored under the configured spelling, so "Papas" finds "Papa"
+        let change = match change {
+            SettingChange::Account(AccountChoice::Named(spoken)) => {
+                match self.household_accounts.find(&spoken) {
+                    Some(account) => {
+                        SettingChange::Account(AccountChoice::Named(account.name().to_string()))
+                    }
+                    None => {
+                        return ResponseBuilder::success(
+                            Speech::new(MessageId::UnknownAccount)
+                                .with("name", spoken)
+                                .render(phrases),
+                        )
+                    }
+                }
+            }
+            change => change,
+        };
+        match self
+            .preferences_service
+            .change(user_id, request.person_id(), &change)
+            .await
+        {
+            Ok(change) => ResponseBuilder::success(setting_speech(&change)),
+            Err(e) => ResponseBuilder::failure(&e, MessageId::SettingSaveFailed),
+        }
+    }
+
+    /// Carries out the pending action the user agreed to.
+    async fn answer_yes(&self, request: &AlexaRequest, phrases: &PhraseSelector) -> AlexaResponse {
+        match SessionState::from_request(request).pending_action() {
+            Some(PendingAction::ContinueReading { offset, open_only }) => {
+                info!(offset, open_only, "Continuing to read the list");
+                let entries = if open_only {
+                    self.read_list_service.open_items().await
+                } else {
+                    self.read_list_service.execute().await
+                };
+                match entries {
+                    Ok(entries) => {
+                        ListPresenter::readout_from(&entries, offset, open_only, phrases)
+                    }
+                    Err(e) => ResponseBuilder::failure(&e, MessageId::ReadFailed),
+                }
+            }
+            Some(PendingAction::DeleteMyData) => {
+                info!("Confirmed data deletion");
+                let Some(user_id) = request.user_id() else {
+                    return ResponseBuilder::success(
+                        Speech::new(MessageId::NoUserData).render(phrases),
+                    );
+                };
+                match self.user_data_service.delete(user_id).await {
+                    Ok(()) => ResponseBuilder::success(
+                        Speech::new(MessageId::DataDeleted).render(phrases),
+                    ),
+                    Err(e) => {
+                        error!(error = %e, "Failed to delete user data");
+                        ResponseBuilder::error(
+                            Speech::new(MessageId::DeleteDataFailed).render(phrases),
+                        )
+                    }
+                }
+            }
+            Some(PendingAction::AddItem | PendingAction::AddMore { .. }) => {
+                info!("Accepted offer to add an item");
+                ResponseBuilder::question(
+                    Speech::new(MessageId::AddWhat).render(phrases),
+                    Speech::new(MessageId::AddWhat).render(phrases),
+                )
+                .with_session_attributes(
+                    SessionState::pending(PendingAction::AddItem).to_attributes(),
+                )
+            }
+            None => {
+                info!("Nothing to agree to");
+                ResponseBuilder::unknown(phrases)
+            }
+        }
+    }
+
+    /// Reads back the items the user added in a period.
+    async fn read_history(
+        &self,
+        request: &AlexaRequest,
+        period: HistoryPeriod,
+        phrases: &PhraseSelector,
+    ) -> AlexaResponse {
+        info!(period = ?period, "Handling history request");
+        let Some(user_id) = request.user_id() else {
+            return ResponseBuilder::error(Speech::new(MessageId::NoUserHistory).render(phrases));
+        };
+        match self
+            .history_service
+            .added_in(
+                user_id,
+                request.person_id(),
+                period,
+                request.request.timestamp(),
+            )
+            .await
+        {
+            Ok(entries) => ListPresenter::history(period, &entries),
+            Err(_) => {
+                ResponseBuilder::error(Speech::new(MessageId::HistoryUnavailable).render(phrases))
+            }
+        }
+    }
+
+    /// Removes the item at a position of the list last read out.
+    async fn remove_item_at(
+        &self,
+        request: &AlexaRequest,
+        position: ListPosition,
+        phrases: &PhraseSelector,
+    ) -> AlexaResponse {
+        // Positions count along the list last read out, which may
+        // have been the open entries only
+        let open_only = matches!(
+            SessionState::from_request(request).pending_action(),
+            Some(PendingAction::ContinueReading {
+                open_only: true,
+                ..
+            })
+        );
+        info!(position = ?position, open_only, "Handling remove by position request");
+        let removed = if open_only {
+            self.remove_item_service.remove_open_at(position).await
+        } else {
+            self.remove_item_service.remove_at(position).await
+        };
+        match removed {
+            Ok(Some(item)) => ResponseBuilder::success(
+                Speech::new(MessageId::ItemRemoved)
+                    .with("item", item)
+                    .render(phrases),
+            ),
+            Ok(None) => {
+                ResponseBuilder::success(Speech::new(MessageId::NoItemAtPosition).render(phrases))
+            }
+            Err(e) => ResponseBuilder::failure(&e, MessageId::RemoveFailed),
+        }
+    }
+
+    /// Logs why Alexa ended the session and counts it as a metric, so
+    /// sessions killed by invalid responses show up in CloudWatch.
+    fn record_session_end(ended: &SessionEndedRequest) {
+        let mut metric =
+            Metric::count(SESSION_ENDED_METRIC).with_dimension("Reason", &ended.reason);
+
+        match &ended.error {
+            Some(session_error) => {
+                error!(
+                    reason = %ended.reason,
+                    error_type = %session_error.error_type,
+                    error_message = session_error.message.as_deref().unwrap_or_default(),
+                    "Alexa ended the session with an error"
+                );
+                metric = metric.with_dimension("ErrorType", &session_error.error_type);
+            }
+            None if ended.reason == EXCEEDED_MAX_REPROMPTS => {
+                warn!(reason = %ended.reason, "Alexa ended the session after unanswered reprompts");
+            }
+            None => info!(reason = %ended.reason, "Session ended"),
+        }
+
+        metric.emit();
+    }
+
+    /// Returns the name to greet the user with, once they granted access to
+    /// their given name.
+    ///
+    /// The name is read from the Alexa profile once per session and speaker;
+    /// a repeated launch in the same session reuses it.
+    async fn greeting(&self, request: &AlexaRequest) -> Greeting {
+        let person_id = request.person_id().map(str::to_string);
+        if let Some(greeting) = SessionState::from_request(request)
+            .greeting()
+            .filter(|greeting| greeting.person_id == person_id)
+        {
+            return greeting.clone();
+        }
+
+        let api_access = request
+            .api_access()
+            .filter(|_| PermissionChecker::is_granted(request, GIVEN_NAME_SCOPE));
+        let name = self
+            .profile_service
+            .given_name(api_access, person_id.is_some())
+            .await;
+        Greeting { person_id, name }
+    }
+
+    /// Reads the most recent list entries for the card after an add, or
+    /// `None` if the list cannot be read quickly.
+    ///
+    /// Runs concurrently with the add, so the card costs the answer no
+    /// time of its own.
+    async fn recent_entries(&self) -> Option<Vec<ShoppingListEntry>> {
+        self.read_list_service
+            .recent(LIST_CARD_ENTRIES, LIST_CARD_TIMEOUT)
+            .await
+    }
+
+    /// Attaches the items just added followed by the `recent` entries as a
+    /// card if anything was added; without `recent` entries, because the
+    /// read was slow or failed, `response` stays as it is.
+    ///
+    /// The read ran alongside the add, so it may or may not contain the
+    /// added items; they are listed once either way.
+    fn with_list_card(
+        response: AlexaResponse,
+        outcomes: &[AddItemOutcome],
+        recent: Option<Vec<ShoppingListEntry>>,
+    ) -> AlexaResponse {
+        let Some(recent) = recent else {
+            return response;
+        };
+        let mut names: Vec<&str> = outcomes
+            .iter()
+            .rev()
+            .filter_map(AddItemOutcome::item)
+            .collect();
+        if names.is_empty() {
+            return response;
+        }
+        for entry in &recent {
+            let name = entry.name();
+            if !names
+                .iter()
+                .any(|known| known.to_lowercase() == name.to_lowercase())
+            {
+                names.push(name);
+            }
+        }
+        names.truncate(LIST_CARD_ENTRIES);
+        ResponseBuilder::with_list_card(response, &names)
+    }
+
+    /// Records a fresh add in the user's history; re-delivered requests were
+    /// recorded the first time.
+    async fn record_history(&self, request: &AlexaRequest, outcome: &AddItemOutcome) {
+        if let (AddItemOutcome::Added { item }, Some(user_id)) = (outcome, request.user_id()) {
+            self.history_service
+                .record(
+                    user_id,
+                    request.person_id(),
+                    item,
+                    request.request.timestamp(),
+                )
+                .await;
+        }
+    }
+
+    /// Returns true for an add in an open dialog that names no item; Alexa
+    /// asks for it as defined in the interaction model's dialog.
+    fn lacks_item(request: &AlexaRequest) -> bool {
+        let Request::Intent(intent_req) = &request.request else {
+            return false;
+        };
+        intent_req.intent.name == intent_parser::intent_names::ADD_ITEM
+            && matches!(
+                intent_req.dialog_state,
+                Some(DialogState::Started | DialogState::InProgress)
+            )
+            && intent_req
+                .intent
+                .slots
+                .get(intent_parser::slot_names::ITEM)
+                .and_then(|slot| slot.spoken_value())
+                .is_none_or(|value| value.trim().is_empty())
+    }
+
+    /// Treats a bare item name as an add when the skill waits for an item,
+    /// after "Noch etwas?" or an offer to add something. After "Noch
+    /// etwas?" the item goes to the list the previous one went to.
+    fn answering_pending(intent: ParsedIntent, request: &AlexaRequest) -> ParsedIntent {
+        let ParsedIntent::FollowUpItem { item_name } = intent else {
+            return intent;
+        };
+        let list = match SessionState::from_request(request).pending_action() {
+            Some(PendingAction::AddItem) => None,
+            // Named explicitly, so the default list does not apply
+            Some(PendingAction::AddMore { list }) => {
+                list.or_else(|| ListName::new(SHOPPING_LIST_NAME))
+            }
+            _ => return ParsedIntent::FollowUpItem { item_name },
+        };
+        ParsedIntent::AddItem {
+            item_name,
+            note: None,
+            list,
+        }
+    }
+
+    /// Routes requests without a spoken list to the user's default list,
+    /// and requests naming the shopping list itself to the shopping list.
+    fn with_default_list(intent: ParsedIntent, default_list: Option<&ListName>) -> ParsedIntent {
+        let resolve = |list: Option<ListName>| match list {
+            Some(list) if list.is_shopping_list() => None,
+            Some(list) => Some(list),
+            None => default_list.cloned(),
+        };
+        match intent {
+            ParsedIntent::AddItem {
+                item_name,
+                note,
+                list,
+            } => ParsedIntent::AddItem {
+                item_name,
+                note,
+                list: resolve(list),
+            },
+            ParsedIntent::ReadList { list } => ParsedIntent::ReadList {
+                list: resolve(list),
+            },
+            ParsedIntent::RemoveItem { item_name, list } => ParsedIntent::RemoveItem {
+                item_name,
+                list: resolve(list),
+            },
+            intent => intent,
+        }
+    }
+
+    /// Returns true for requests the user expects an answer to; stopping
+    /// and skill events are still handled during maintenance.
+    fn needs_service(intent: &ParsedIntent) -> bool {
+        !matches!(
+            intent,
+            ParsedIntent::Cancel
+                | ParsedIntent::Stop
+                | ParsedIntent::No
+                | ParsedIntent::PermissionsChanged { .. }
+                | ParsedIntent::SubscriptionsChanged { .. }
+                | ParsedIntent::SkillEnabled { .. }
+                | ParsedIntent::SkillDisabled { .. }
+                | ParsedIntent::AccountLinked { .. }
+        )
+    }
+
+    /// Reads the maintenance flag; an unreadable flag counts as inactive so
+    /// a flag outage does not take the skill down.
+    async fn in_maintenance(&self) -> bool {
+        self.maintenance_flag.is_active().await.unwrap_or_else(|e| {
+            warn!(error = %e, "Failed to read maintenance flag");
+            false
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::alexa::{Card, Directive, PendingAction};
+    use crate::domain::models::{
+        AddedItem, CookidooCredentials, DomainError, HouseholdAccount, ItemBundle, ItemBundles,
+        Reminder, ShoppingListEntry, ShoppingListItem, SkillPermissions,
+    };
+    use crate::domain::ports::ShoppingListRepository;
+    use crate::domain::ports::{
+        CustomerProfile, HistoryRepository, PermissionStore, PreferencesRepository,
+        ReminderScheduler,
+    };
+    use async_trait::async_trait;
+    use std::collections::HashMap;
+
+    struct MockRepository {
+        should_fail: bool,
+        auth_fails: bool,
+        adds: Arc<std::sync::atomic::AtomicUsize>,
+        entries: Vec<ShoppingListEntry>,
+    }
+
+    impl MockRepository {
+        fn new() -> Self {
+            Self {
+                should_fail: false,
+                auth_fails: false,
+                adds: Arc::default(),
+                entries: Vec::new(),
+            }
+        }
+
+        fn failing() -> Self {
+            Self {
+                should_fail: true,
+                auth_fails: false,
+                adds: Arc::default(),
+                entries: Vec::new(),
+            }
+        }
+
+        fn auth_failing() -> Self {
+            Self {
+                auth_fails: true,
+                ..Self::new()
+            }
+        }
+
+        fn with_entries(names: &[&str]) -> Self {
+            Self {
+                should_fail: false,
+                auth_fails: false,
+                adds: Arc::default(),
+                entries: names
+                    .iter()
+                    .enumerate()
+                    .map(|(i, name)| ShoppingListEntry::new(i.to_string(), *name, false))
+                    .collect(),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl ShoppingListRepository for MockRepository {
+        async fn add_item(&self, _item: &ShoppingListItem) -> Result<Vec<AddedItem>, DomainError> {
+            self.adds.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            if self.auth_fails {
+                return Err(DomainError::AuthenticationFailed("rejected".to_string()));
+            }
+            if self.should_fail {
+                Err(DomainError::RepositoryError("Test error".to_string()))
+            } else {
+                Ok(Vec::new())
+            }
+        }
+
+        async fn list_items(&self) -> Result<Vec<ShoppingListEntry>, DomainError> {
+            if self.should_fail {
+                Err(DomainError::RepositoryError("Test error".to_string()))
+            } else {
+                Ok(self.entries.clone())
+            }
+        }
+
+        async fn remove_item(&self, _id: &str) -> Result<(), DomainError> {
+            if self.should_fail {
+                Err(DomainError::RepositoryError("Test error".to_string()))
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    struct MockScheduler {
+        permission_granted: bool,
+    }
+
+    #[async_trait]
+    impl ReminderScheduler for MockScheduler {
+        async fn schedule(
+            &self,
+            _api_endpoint: &str,
+            _access_token: &str,
+            _reminder: &Reminder,
+        ) -> Result<(), DomainError> {
+            if self.permission_granted {
+                Ok(())
+            } else {
+                Err(DomainError::PermissionRequired(REMINDERS_SCOPE.to_string()))
+            }
+        }
+    }
+
+    fn make_reminder_handler(permission_granted: bool) -> AlexaSkillHandler {
+        make_handler(MockRepository::new()).with_reminder_service(Arc::new(ReminderService::new(
+            Arc::new(MockScheduler { permission_granted }),
+        )))
+    }
+
+    fn make_reminder_request() -> AlexaRequest {
+        serde_json::from_str(
+            r#"{
+                "version": "1.0",
+                "context": {
+                    "System": {
+                        "user": {
+                            "userId": "user-123",
+                            "permissions": {
+                                "scopes": {
+                                    "alexa::alerts:reminders:skill:readwrite": {"status": "GRANTED"}
+                                }
+                            }
+                        },
+                        "apiEndpoint": "https://api.eu.amazonalexa.com",
+                        "apiAccessToken": "token-123"
+                    }
+                },
+                "request": {
+                    "type": "IntentRequest",
+                    "requestId": "req-123",
+                    "timestamp": "2024-01-27T10:00:00Z",
+                    "locale": "de-DE",
+                    "intent": {
+                        "name": "ReminderIntent",
+                        "slots": {
+                            "Item": {"name": "Item", "value": "Milch"},
+                            "Duration": {"name": "Duration", "value": "PT2H"}
+                        }
+                    }
+                }
+            }"#,
+        )
+        .unwrap()
+    }
+
+    fn make_handler(repo: MockRepository) -> AlexaSkillHandler {
+        let repo = Arc::new(repo);
+        AlexaSkillHandler::new(
+            Arc::new(AddItemService::new(repo.clone())),
+            Arc::new(ReadListService::new(repo.clone())),
+            Arc::new(RemoveItemService::new(repo)),
+        )
+    }
+
+    fn make_user_intent_request(intent_name: &str, slots_json: &str) -> AlexaRequest {
+        let json = format!(
+            r#"{{
+                "version": "1.0",
+                "session": {{
+                    "new": false,
+                    "sessionId": "session-123",
+                    "application": {{"applicationId": "skill-123"}},
+                    "user": {{"userId": "user-123"}}
+                }},
+                "request": {{
+                    "type": "IntentRequest",
+                    "requestId": "req-123",
+                    "timestamp": "2024-01-27T10:00:00Z",
+                    "locale": "de-DE",
+                    "intent": {{"name": "{intent_name}", "slots": {slots_json}}}
+                }}
+            }}"#
+        );
+        serde_json::from_str(&json).unwrap()
+    }
+
+    fn make_intent_request(intent_name: &str) -> AlexaRequest {
+        let json = format!(
+            r#"{{
+                "version": "1.0",
+                "request": {{
+                    "type": "IntentRequest",
+                    "requestId": "req-123",
+                    "timestamp": "2024-01-27T10:00:00Z",
+                    "locale": "de-DE",
+                    "intent": {{"name": "{intent_name}", "slots": {{}}}}
+                }}
+            }}"#
+        );
+        serde_json::from_str(&json).unwrap()
+    }
+
+    struct CountingProfile {
+        reads: std::sync::atomic::AtomicUsize,
+    }
+
+    #[async_trait]
+    impl CustomerProfile for CountingProfile {
+        async fn account_given_name(
+            &self,
+            _api_endpoint: &str,
+            _access_token: &str,
+        ) -> Result<Option<String>, DomainError> {
+            self.reads.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            Ok(Some("Anna".to_string()))
+        }
+
+        async fn person_given_name(
+            &self,
+            _api_endpoint: &str,
+            _access_token: &str,
+        ) -> Result<Option<String>, DomainError> {
+            Ok(None)
+        }
+    }
+
+    fn make_session_launch_request(
+        attributes: &HashMap<String, serde_json::Value>,
+    ) -> AlexaRequest {
+        let json = format!(
+            r#"{{
+                "version": "1.0",
+                "session": {{
+                    "new": false,
+                    "sessionId": "session-123",
+                    "application": {{"applicationId": "skill-123"}},
+                    "user": {{"userId": "user-123"}},
+                    "attributes": {}
+                }},
+                "context": {{
+                    "System": {{
+                        "user": {{
+                            "userId": "user-123",
+                            "permissions": {{
+                                "scopes": {{"{GIVEN_NAME_SCOPE}": {{"status": "GRANTED"}}}}
+                            }}
+                        }},
+                        "apiEndpoint": "https://api.eu.amazonalexa.com",
+                        "apiAccessToken": "token-123"
+                    }}
+                }},
+                "request": {{
+                    "type": "LaunchRequest",
+                    "requestId": "req-123",
+                    "timestamp": "2024-01-27T10:00:00Z",
+                    "locale": "de-DE"
+                }}
+            }}"#,
+            serde_json::to_string(attributes).unwrap()
+        );
+        serde_json::from_str(&json).unwrap()
+    }
+
+    #[tokio::test]
+    async fn reads_given_name_once_per_session() {
+        let profile = Arc::new(CountingProfile {
+            reads: Default::default(),
+        });
+        let handler = make_handler(MockRepository::new())
+            .with_profile_service(Arc::new(ProfileService::new(profile.clone())));
+
+        let first = handler
+            .handle(make_session_launch_request(&HashMap::new()))
+            .await;
+        let second = handler
+            .handle(make_session_launch_request(&first.session_attributes))
+            .await;
+
+        assert!(second
+            .response
+            .output_speech
+            .text
+            .starts_with("Hallo Anna!"));
+        assert_eq!(profile.reads.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
+
+    fn make_launch_request() -> AlexaRequest {
+        serde_json::from_str(
+            r#"{
+                "version": "1.0",
+                "request": {
+                    "type": "LaunchRequest",
+                    "requestId": "req-123",
+                    "timestamp": "2024-01-27T10:00:00Z",
+                    "locale": "de-DE"
+                }
+            }"#,
+        )
+        .unwrap()
+    }
+
+    fn make_add_item_request(item: &str) -> AlexaRequest {
+        let json = format!(
+            r#"{{
+                "version": "1.0",
+                "request": {{
+                    "type": "IntentRequest",
+                    "requestId": "req-123",
+                    "timestamp": "2024-01-27T10:00:00Z",
+                    "locale": "de-DE",
+                    "intent": {{
+                        "name": "AddItemIntent",
+                        "slots": {{
+                            "Item": {{"name": "Item", "value": "{item}"}}
+                        }}
+                    }}
+                }}
+            }}"#
+        );
+        serde_json::from_str(&json).unwrap()
+    }
+
+    fn make_help_request() -> AlexaRequest {
+        serde_json::from_str(
+            r#"{
+                "version": "1.0",
+                "request": {
+                    "type": "IntentRequest",
+                    "requestId": "req-123",
+                    "timestamp": "2024-01-27T10:00:00Z",
+                    "locale": "de-DE",
+                    "intent": {"name": "AMAZON.HelpIntent", "slots": {}}
+                }
+            }"#,
+        )
+        .unwrap()
+    }
+
+    fn make_stop_request() -> AlexaRequest {
+        serde_json::from_str(
+            r#"{
+                "version": "1.0",
+                "request": {
+                    "type": "IntentRequest",
+                    "requestId": "req-123",
+                    "timestamp": "2024-01-27T10:00:00Z",
+                    "locale": "de-DE",
+                    "intent": {"name": "AMAZON.StopIntent", "slots": {}}
+                }
+            }"#,
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn handles_launch_request() {
+        let handler = make_handler(MockRepository::new());
+        let response = handler.handle(make_launch_request()).await;
+
+        assert!(!response.response.should_end_session);
+        assert!(response.response.output_speech.text.contains("Willkommen"));
+    }
+
+    #[tokio::test]
+    async fn handles_add_item_success() {
+        let handler = make_handler(MockRepository::new());
+        let response = handler.handle(make_add_item_request("Milch")).await;
+
+        assert!(response.response.should_end_session);
+        assert!(response.response.output_speech.text.contains("Milch"));
+        assert!(response.response.output_speech.text.contains("hinzugefügt"));
+    }
+
+    #[tokio::test]
+    async fn delegates_add_without_item_to_dialog() {
+        let handler = make_handler(MockRepository::new());
+        let mut request = make_user_intent_request("AddItemIntent", "{}");
+        if let Request::Intent(intent_req) = &mut request.request {
+            intent_req.dialog_state = Some(DialogState::Started);
+        }
+
+        let response = handler.handle(request).await;
+
+        assert!(matches!(
+            response.response.directives.as_slice(),
+            [Directive::DialogDelegate { .. }]
+        ));
+    }
+
+    #[tokio::test]
+    async fn add_shows_recent_items_on_card() {
+        let handler = make_handler(MockRepository::with_entries(&["Eier", "Milch"]));
+        let response = handler.handle(make_add_item_request("Milch")).await;
+
+        let Some(Card::Standard { text, .. }) = &response.response.card else {
+            panic!("expected a standard card");
+        };
+        assert!(text.ends_with("Zuletzt auf der Liste:\n• Milch\n• Eier"));
+    }
+
+    #[tokio::test]
+    async fn failed_add_keeps_error_card() {
+        let handler = make_handler(MockRepository::failing());
+        let response = handler.handle(make_add_item_request("Milch")).await;
+
+        let Some(Card::Simple { content, .. }) = &response.response.card else {
+            panic!("expected a simple card");
+        };
+        assert!(content.contains("Fehlercode"));
+    }
+
+    #[tokio::test]
+    async fn brief_style_shortens_launch_and_confirmation() {
+        let handler = make_handler(MockRepository::new()).with_speech_style(SpeechStyle::Brief);
+
+        let launch = handler.handle(make_launch_request()).await;
+        assert!(!launch.response.should_end_session);
+        assert!(!launch.response.output_speech.text.contains("Willkommen"));
+
+        let response = handler.handle(make_add_item_request("Milch")).await;
+        assert_eq!(response.response.output_speech.text, "Okay, Milch.");
+    }
+
+    #[tokio::test]
+    async fn spoken_setting_changes_style_for_that_user() {
+        let handler = make_handler(MockRepository::new());
+
+        let response = handler
+            .handle(make_user_intent_request(
+                "SettingsIntent",
+                r#"{"SpeechStyle": {"name": "SpeechStyle", "value": "kurze"}}"#,
+            ))
+            .await;
+        assert!(response.response.output_speech.text.contains("kurz"));
+
+        let response = handler
+            .handle(make_user_intent_request(
+                "AddItemIntent",
+                r#"{"Item": {"name": "Item", "value": "Milch"}}"#,
+            ))
+            .await;
+        assert_eq!(response.response.output_speech.text, "Okay, Milch.");
+
+        let response = handler.handle(make_add_item_request("Milch")).await;
+        assert!(response.response.output_speech.text.contains("hinzugefügt"));
+    }
+
+    fn make_household_handler() -> AlexaSkillHandler {
+        make_handler(MockRepository::new()).with_household_accounts(Arc::new(
+            HouseholdAccounts::new().with_account(
+                HouseholdAccount::new("Papa", CookidooCredentials::new("papa@example.de", "pw"))
+                    .with_person_ids(["person-papa".to_string()]),
+            ),
+        ))
+    }
+
+    fn make_person_request(person_id: &str) -> AlexaRequest {
+        serde_json::from_value(serde_json::json!({
+            "version": "1.0",
+            "context": {
+                "System": {
+                    "user": {"userId": "user-123"},
+                    "person": {"personId": person_id}
+                }
+            },
+            "request": {
+                "type": "LaunchRequest",
+                "requestId": "req-123",
+                "timestamp": "2024-01-27T10:00:00Z",
+                "locale": "de-DE"
+            }
+        }))
+        .unwrap()
+    }
+
+    fn make_use_account_request(person_id: Option<&str>, account: Option<&str>) -> AlexaRequest {
+        let mut system = serde_json::json!({"user": {"userId": "user-123"}});
+        if let Some(person_id) = person_id {
+            system["person"] = serde_json::json!({"personId": person_id});
+        }
+        let slots = match account {
+            Some(account) => serde_json::json!({"Account": {"name": "Account", "value": account}}),
+            None => serde_json::json!({}),
+        };
+        serde_json::from_value(serde_json::json!({
+            "version": "1.0",
+            "context": {"System": system},
+            "request": {
+                "type": "IntentRequest",
+                "requestId": "req-123",
+                "timestamp": "2024-01-27T10:00:00Z",
+                "locale": "de-DE",
+                "intent": {"name": "UseAccountIntent", "slots": slots}
+            }
+        }))
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn recognized_voice_uses_its_account() {
+        let handler = make_household_handler();
+
+        assert_eq!(
+            handler.account(&make_person_request("person-papa")).await,
+            Some("Papa".to_string())
+        );
+        assert_eq!(
+            handler.account(&make_person_request("person-other")).await,
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn spoken_account_choice_wins_over_voice() {
+        let handler = make_household_handler();
+
+        let response = handler
+            .handle(make_use_account_request(
+                Some("person-other"),
+                Some("Papas"),
+            ))
+            .await;
+        assert_eq!(
+            response.response.output_speech.text,
+            "Alles klar, ich benutze ab jetzt die Liste von Papa."
+        );
+        assert_eq!(
+            handler.account(&make_person_request("person-other")).await,
+            Some("Papa".to_string())
+        );
+
+        handler
+            .handle(make_use_account_request(Some("person-papa"), None))
+            .await;
+        assert_eq!(
+            handler.account(&make_person_request("person-papa")).await,
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn unrecognized_speaker_cannot_switch_account() {
+        let handler = make_household_handler();
+
+        let response = handler
+            .handle(make_use_account_request(None, Some("Papa")))
+            .await;
+
+        assert_eq!(
+            response.response.output_speech.text,
+            Speech::new(MessageId::UnrecognizedSpeaker).render_default()
+        );
+        assert_eq!(
+            handler.account(&make_person_request("person-other")).await,
+            None
+        );
+        assert_eq!(
+            handler
+                .account(&make_user_intent_request("ReadListIntent", "{}"))
+                .await,
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn unknown_account_is_not_stored() {
+        let handler = make_household_handler();
+
+        let response = handler
+            .handle(make_use_account_request(Some("person-other"), Some("Mama")))
+            .await;
+
+        assert_eq!(
+            response.response.output_speech.text,
+            "Ich kenne keine Liste von Mama."
+        );
+        assert_eq!(
+            handler.account(&make_person_request("person-other")).await,
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn default_list_receives_items_without_list() {
+        let handler = make_handler(MockRepository::failing());
+        handler
+            .handle(make_user_intent_request(
+                "SettingsIntent",
+                r#"{"ListName": {"name": "ListName", "value": "Geburtstagsliste"}}"#,
+            ))
+            .await;
+
+        let response = handler
+            .handle(make_user_intent_request(
+                "AddItemIntent",
+                r#"{"Item": {"name": "Item", "value": "Kerzen"}}"#,
+            ))
+            .await;
+
+        assert!(response
+            .response
+            .output_speech
+            .text
+            .ends_with("Kerzen steht jetzt auf deiner Geburtstagsliste."));
+    }
+
+    #[tokio::test]
+    async fn spoken_shopping_list_overrides_default_list() {
+        let handler = make_handler(MockRepository::new());
+        handler
+            .handle(make_user_intent_request(
+                "SettingsIntent",
+                r#"{"ListName": {"name": "ListName", "value": "Geburtstagsliste"}}"#,
+            ))
+            .await;
+
+        let response = handler
+            .handle(make_user_intent_request(
+                "AddItemIntent",
+                r#"{
+                    "Item": {"name": "Item", "value": "Milch"},
+                    "ListName": {"name": "ListName", "value": "Einkaufsliste"}
+                }"#,
+            ))
+            .await;
+
+        assert!(response
+            .response
+            .output_speech
+            .text
+            .contains("Milch wurde zur Einkaufsliste hinzugefügt."));
+    }
+
+    #[tokio::test]
+    async fn summarizes_list() {
+        let handler = make_handler(MockRepository::with_entries(&["Milch", "Äpfel"]));
+
+        let response = handler
+            .handle(make_user_intent_request("HowManyItemsIntent", "{}"))
+            .await;
+
+        assert!(response
+            .response
+            .output_speech
+            .text
+            .starts_with("Du hast 2 Artikel auf der Liste."));
+    }
+
+    #[tokio::test]
+    async fn reads_back_items_added_today() {
+        let handler = make_handler(MockRepository::new());
+        handler
+            .handle(make_user_intent_request(
+                "AddItemIntent",
+                r#"{"Item": {"name": "Item", "value": "Milch"}}"#,
+            ))
+            .await;
+
+        let response = handler
+            .handle(make_user_intent_request("HistoryIntent", "{}"))
+            .await;
+
+        assert_eq!(
+            response.response.output_speech.text,
+            "Heute hast du Milch hinzugefügt."
+        );
+    }
+
+    #[tokio::test]
+    async fn adds_every_item_of_a_free_form_query() {
+        let handler =
+            make_handler(MockRepository::new()).with_phrase_variation(PhraseVariation::Seeded(0));
+        let response = handler
+            .handle(make_user_intent_request(
+                "FreeFormAddIntent",
+                r#"{"Query": {"name": "Query", "value": "keine Milch und Eier mehr"}}"#,
+            ))
+            .await;
+
+        assert!(response.response.should_end_session);
+        assert_eq!(
+            response.response.output_speech.text,
+            "Milch und Eier wurden zur Einkaufsliste hinzugefügt."
+        );
+    }
+
+    fn make_free_form_add_request() -> AlexaRequest {
+        make_user_intent_request(
+            "FreeFormAddIntent",
+            r#"{"Query": {"name": "Query", "value": "Milch, Eier und Brot"}}"#,
+        )
+    }
+
+    #[tokio::test]
+    async fn brief_style_shortens_confirmation_of_several_items() {
+        let handler = make_handler(MockRepository::new()).with_speech_style(SpeechStyle::Brief);
+        let response = handler.handle(make_free_form_add_request()).await;
+
+        assert_eq!(
+            response.response.output_speech.text,
+            "Okay, Milch, Eier und Brot."
+        );
+    }
+
+    #[tokio::test]
+    async fn asks_for_more_after_several_items() {
+        let handler = make_handler(MockRepository::new()).with_add_more(true);
+        let response = handler.handle(make_free_form_add_request()).await;
+
+        assert!(!response.response.should_end_session);
+        assert!(response
+            .response
+            .output_speech
+            .text
+            .ends_with("Noch etwas?"));
+        assert_eq!(
+            SessionState::from_attributes(&response.session_attributes).pending_action(),
+            Some(PendingAction::AddMore { list: None })
+        );
+    }
+
+    #[tokio::test]
+    async fn stops_adding_items_after_failed_sign_in() {
+        let repo = MockRepository::auth_failing();
+        let adds = repo.adds.clone();
+        let handler = make_handler(repo).with_add_more(true);
+        let response = handler.handle(make_free_form_add_request()).await;
+
+        assert_eq!(adds.load(std::sync::atomic::Ordering::SeqCst), 1);
+        assert!(response.response.should_end_session);
+        assert!(response.response.output_speech.text.contains("Anmeldung"));
+    }
+
+    #[tokio::test]
+    async fn adds_every_item_of_a_bundle() {
+        let handler = make_handler(MockRepository::new())
+            .with_phrase_variation(PhraseVariation::Seeded(0))
+            .with_bundle_service(Arc::new(
+                BundleService::new(Arc::new(InMemoryPreferencesRepository::new())).with_bundles(
+                    Arc::new(
+                        ItemBundles::new()
+                            .with_bundle(ItemBundle::new("Frühstücks-Set", ["Brötchen", "Butter"])),
+                    ),
+                ),
+            ));
+
+        let added = handler
+            .handle(make_user_intent_request(
+                "AddBundleIntent",
+                r#"{"Bundle": {"name": "Bundle", "value": "Frühstücksset"}}"#,
+            ))
+            .await;
+        let unknown = handler
+            .handle(make_user_intent_request(
+                "AddBundleIntent",
+                r#"{"Bundle": {"name": "Bundle", "value": "Grill-Set"}}"#,
+            ))
+            .await;
+
+        assert_eq!(
+            added.response.output_speech.text,
+            "Brötchen und Butter wurden zur Einkaufsliste hinzugefügt."
+        );
+        assert_eq!(
+            unknown.response.output_speech.text,
+            "Ich kenne kein Set namens Grill-Set."
+        );
+    }
+
+    #[tokio::test]
+    async fn adds_bundle_created_by_voice() {
+        let handler =
+            make_handler(MockRepository::new()).with_phrase_variation(PhraseVariation::Seeded(0));
+
+        let created = handler
+            .handle(make_user_intent_request(
+                "ExtendBundleIntent",
+                r#"{"Bundle": {"name": "Bundle", "value": "Kaffee-Set"},
+                    "Item": {"name": "Item", "value": "Milch"}}"#,
+            ))
+            .await;
+        let read = handler
+            .handle(make_user_intent_request(
+                "ReadBundleIntent",
+                r#"{"Bundle": {"name": "Bundle", "value": "Kaffeeset"}}"#,
+            ))
+            .await;
+        let added = handler
+            .handle(make_user_intent_request(
+                "AddBundleIntent",
+                r#"{"Bundle": {"name": "Bundle", "value": "Kaffee-Set"}}"#,
+            ))
+            .await;
+        let deleted = handler
+            .handle(make_user_intent_request(
+                "DeleteBundleIntent",
+                r#"{"Bundle": {"name": "Bundle", "value": "Kaffee-Set"}}"#,
+            ))
+            .await;
+
+        assert_eq!(
+            created.response.output_speech.text,
+            "Ich habe dein Kaffee-Set mit Milch angelegt."
+        );
+        assert_eq!(
+            read.response.output_speech.text,
+            "In deinem Kaffee-Set steht: Milch."
+        );
+        assert_eq!(
+            added.response.output_speech.text,
+            "Milch wurde zur Einkaufsliste hinzugefügt."
+        );
+        assert_eq!(
+            deleted.response.output_speech.text,
+            "Ich habe dein Kaffee-Set gelöscht."
+        );
+    }
+
+    #[tokio::test]
+    async fn bundle_management_requires_user() {
+        let handler = make_handler(MockRepository::new());
+        let response = handler
+            .handle(make_intent_request("ReadBundleIntent"))
+            .await;
+
+        assert_eq!(
+            response.response.output_speech.text,
+            Speech::new(MessageId::NoUserBundle).render_default()
+        );
+    }
+
+    #[tokio::test]
+    async fn handles_add_item_failure() {
+        let handler = make_handler(MockRepository::failing());
+        let response = handler.handle(make_add_item_request("Milch")).await;
+
+        assert!(response.response.should_end_session);
+        assert!(response
+            .response
+            .output_speech
+            .text
+            .contains("nicht hinzugefügt"));
+    }
+
+    #[tokio::test]
+    async fn handles_help_request() {
+        let handler = make_handler(MockRepository::new());
+        let response = handler.handle(make_help_request()).await;
+
+        assert!(!response.response.should_end_session);
+    }
+
+    #[tokio::test]
+    async fn records_permission_accepted_event() {
+        let service = Arc::new(SkillEventService::new(Arc::new(
+            InMemoryPermissionStore::new(),
+        )));
+        let handler = make_handler(MockRepository::new()).with_skill_event_service(service.clone());
+        let request: AlexaRequest = serde_json::from_str(
+            r#"{
+                "version": "1.0",
+                "context": {"System": {"user": {"userId": "user-123"}}},
+                "request": {
+                    "type": "AlexaSkillEvent.SkillPermissionAccepted",
+                    "requestId": "req-123",
+                    "timestamp": "2024-01-27T10:00:00Z",
+                    "body": {"acceptedPermissions": [{"scope": "scope-a"}]}
+                }
+            }"#,
+        )
+        .unwrap();
+
+        let response = handler.handle(request).await;
+
+        assert!(response.response.should_end_session);
+        assert!(service.permissions("user-123").await.has_scope("scope-a"));
+    }
+
+    #[tokio::test]
+    async fn skill_disabled_event_deletes_user_data() {
+        let service = Arc::new(SkillEventService::new(Arc::new(
+            InMemoryPermissionStore::new(),
+        )));
+        service
+            .permissions_changed("user-123", &["scope-a".to_string()])
+            .await
+            .unwrap();
+        let handler = make_handler(MockRepository::new()).with_skill_event_service(service.clone());
+        let request: AlexaRequest = serde_json::from_str(
+            r#"{
+                "version": "1.0",
+                "context": {"System": {"user": {"userId": "user-123"}}},
+                "request": {
+                    "type": "AlexaSkillEvent.SkillDisabled",
+                    "requestId": "req-123",
+                    "timestamp": "2024-01-27T10:00:00Z",
+                    "body": {"userInformationPersistenceStatus": "NOT_PERSISTED"}
+                }
+            }"#,
+        )
+        .unwrap();
+
+        let response = handler.handle(request).await;
+
+        assert!(response.response.should_end_session);
+        assert!(!service.permissions("user-123").await.has_scope("scope-a"));
+    }
+
+    #[tokio::test]
+    async fn reads_list_entries() {
+        let handler = make_handler(MockRepository::with_entries(&["Milch", "Eier"]));
+        let response = handler.handle(make_intent_request("ReadListIntent")).await;
+
+        assert!(response.response.should_end_session);
+        assert!(response
+            .response
+            .output_speech
+            .text
+            .contains("Milch und Eier"));
+    }
+
+    #[tokio::test]
+    async fn reads_only_open_entries() {
+        let mut repo = MockRepository::with_entries(&["Milch", "Eier", "Brot"]);
+        repo.entries[1] = ShoppingListEntry::new("1", "Eier", true);
+        let handler = make_handler(repo);
+
+        let response = handler
+            .handle(make_intent_request("ReadOpenItemsIntent"))
+            .await;
+
+        assert_eq!(
+            response.response.output_speech.text,
+            "Es fehlen noch: Milch und Brot."
+        );
+    }
+
+    #[tokio::test]
+    async fn read_empty_list_keeps_session_open() {
+        let handler = make_handler(MockRepository::new());
+        let response = handler.handle(make_intent_request("ReadListIntent")).await;
+
+        assert!(!response.response.should_end_session);
+        assert!(response.response.output_speech.text.contains("leer"));
+    }
+
+    #[tokio::test]
+    async fn read_list_failure_returns_error() {
+        let handler = make_handler(MockRepository::failing());
+        let response = handler.handle(make_intent_request("ReadListIntent")).await;
+
+        assert!(response.response.should_end_session);
+        assert!(response
+            .response
+            .output_speech
+            .text
+            .contains("nicht gelesen"));
+    }
+
+    #[tokio::test]
+    async fn removes_item_from_shopping_list() {
+        let handler = make_handler(MockRepository::with_entries(&["Milch"]));
+        let response = handler
+            .handle(make_user_intent_request(
+                "RemoveItemIntent",
+                r#"{"Item": {"name": "Item", "value": "Milch"}}"#,
+            ))
+            .await;
+
+        assert!(response.response.should_end_session);
+        assert_eq!(
+            response.response.output_speech.text,
+            "Milch wurde von der Einkaufsliste entfernt."
+        );
+    }
+
+    #[tokio::test]
+    async fn removes_item_by_position() {
+        let handler = make_handler(MockRepository::with_entries(&["Milch", "Eier"]));
+        let position = |value: &str| {
+            make_user_intent_request(
+                "RemoveItemIntent",
+                &format!(r#"{{"Position": {{"name": "Position", "value": "{value}"}}}}"#),
+            )
+        };
+
+        let last = handler.handle(position("letzten")).await;
+        let missing = handler.handle(position("Nummer fünf")).await;
+
+        assert_eq!(
+            last.response.output_speech.text,
+            "Eier wurde von der Einkaufsliste entfernt."
+        );
+        assert_eq!(
+            missing.response.output_speech.text,
+            Speech::new(MessageId::NoItemAtPosition).render_default()
+        );
+    }
+
+    #[tokio::test]
+    async fn removes_position_among_open_items_read_out() {
+        let handler = make_handler(MockRepository {
+            entries: vec![
+                ShoppingListEntry::new("0", "Milch", false),
+                ShoppingListEntry::new("1", "Eier", false),
+                ShoppingListEntry::new("2", "Brot", true),
+            ],
+            ..MockRepository::new()
+        });
+        let mut request = make_user_intent_request(
+            "RemoveItemIntent",
+            r#"{"Position": {"name": "Position", "value": "letzten"}}"#,
+        );
+        request.session.as_mut().unwrap().attributes =
+            SessionState::pending(PendingAction::ContinueReading {
+                offset: 10,
+                open_only: true,
+            })
+            .to_attributes();
+
+        let response = handler.handle(request).await;
+
+        assert_eq!(
+            response.response.output_speech.text,
+            "Eier wurde von der Einkaufsliste entfernt."
+        );
+    }
+
+    #[tokio::test]
+    async fn routes_list_context_to_occasion_list() {
+        let handler = make_handler(MockRepository::failing());
+        let list_slot = r#""ListName": {"name": "ListName", "value": "Geburtstagsliste"}"#;
+
+        let added = handler
+            .handle(make_user_intent_request(
+                "AddItemIntent",
+                &format!(r#"{{"Item": {{"name": "Item", "value": "Kerzen"}}, {list_slot}}}"#),
+            ))
+            .await;
+        let read = handler
+            .handle(make_user_intent_request(
+                "ReadListIntent",
+                &format!("{{{list_slot}}}"),
+            ))
+            .await;
+
+        // The failing Cookidoo repository is never touched
+        assert!(added
+            .response
+            .output_speech
+            .text
+            .contains("Kerzen steht jetzt auf deiner Geburtstagsliste."));
+        assert_eq!(
+            read.response.output_speech.text,
+            "Auf deiner Geburtstagsliste steht: Kerzen."
+        );
+    }
+
+    #[tokio::test]
+    async fn transfers_occasion_list_to_shopping_list() {
+        let handler = make_handler(MockRepository::new());
+        let list_slot = r#""ListName": {"name": "ListName", "value": "Party-Liste"}"#;
+        for item in ["Chips", "Cola"] {
+            handler
+                .handle(make_user_intent_request(
+                    "AddItemIntent",
+                    &format!(r#"{{"Item": {{"name": "Item", "value": "{item}"}}, {list_slot}}}"#),
+                ))
+                .await;
+        }
+
+        let transferred = handler
+            .handle(make_user_intent_request(
+                "TransferListIntent",
+                &format!("{{{list_slot}}}"),
+            ))
+            .await;
+        let read = handler
+            .handle(make_user_intent_request(
+                "ReadListIntent",
+                &format!("{{{list_slot}}}"),
+            ))
+            .await;
+
+        assert_eq!(
+            transferred.response.output_speech.text,
+            "Ich habe 2 Artikel von deiner Party-Liste auf die Einkaufsliste übertragen."
+        );
+        assert!(read.response.output_speech.text.contains("leer"));
+    }
+
+    #[tokio::test]
+    async fn transfer_skips_pantry_staples() {
+        let handler =
+            make_handler(MockRepository::new()).with_pantry(PantryStaples::parse("Salz, Pfeffer"));
+        let list_slot = r#""ListName": {"name": "ListName", "value": "Party-Liste"}"#;
+        for item in ["Chips", "Salz"] {
+            handler
+                .handle(make_user_intent_request(
+                    "AddItemIntent",
+                    &format!(r#"{{"Item": {{"name": "Item", "value": "{item}"}}, {list_slot}}}"#),
+                ))
+                .await;
+        }
+
+        let transferred = handler
+            .handle(make_user_intent_request(
+                "TransferListIntent",
+                &format!("{{{list_slot}}}"),
+            ))
+            .await;
+
+        assert_eq!(
+            transferred.response.output_speech.text,
+            "Ich habe Chips von deiner Party-Liste auf die Einkaufsliste übertragen. \
+             Salz habe ich weggelassen, das hast du immer da."
+        );
+    }
+
+    #[tokio::test]
+    async fn occasion_list_without_user_returns_error() {
+        let handler = make_handler(MockRepository::new());
+        let request: AlexaRequest = serde_json::from_str(
+            r#"{
+                "version": "1.0",
+                "request": {
+                    "type": "IntentRequest",
+                    "requestId": "req-123",
+                    "timestamp": "2024-01-27T10:00:00Z",
+                    "locale": "de-DE",
+                    "intent": {
+                        "name": "ReadListIntent",
+                        "slots": {"ListName": {"name": "ListName", "value": "Geburtstagsliste"}}
+                    }
+                }
+            }"#,
+        )
+        .unwrap();
+
+        let response = handler.handle(request).await;
+
+        assert_eq!(
+            response.response.output_speech.text,
+            Speech::new(MessageId::NoUserList).render_default()
+        );
     }
 
     #[tokio::test]
-    async fn handles_help_request() {
+    async fn adds_item_and_schedules_reminder() {
+        let handler = make_reminder_handler(true);
+        let response = handler.handle(make_reminder_request()).await;
+
+        assert!(response.response.should_end_session);
+        assert!(response.response.card.is_none());
+        let text = response.response.output_speech.text;
+        assert!(text.contains("Milch"));
+        assert!(text.ends_with("Ich erinnere dich in 2 Stunden daran."));
+    }
+
+    #[tokio::test]
+    async fn reminder_without_permission_sends_consent_card() {
+        let handler = make_reminder_handler(false);
+        let response = handler.handle(make_reminder_request()).await;
+
+        assert!(response.response.should_end_session);
+        assert!(response
+            .response
+            .output_speech
+            .text
+            .ends_with("Ich habe dir dort eine Karte geschickt."));
+        let json = serde_json::to_value(&response).unwrap();
+        assert_eq!(json["response"]["card"]["permissions"][0], REMINDERS_SCOPE);
+    }
+
+    #[tokio::test]
+    async fn reminder_without_granted_scope_skips_api_call() {
+        // The scheduler would succeed, but the request lists no permissions
+        let handler = make_reminder_handler(true);
+        let mut request = make_reminder_request();
+        request.context.as_mut().unwrap().system.user.permissions = None;
+
+        let response = handler.handle(request).await;
+
+        assert!(response.response.card.is_some());
+        assert!(!response
+            .response
+            .output_speech
+            .text
+            .contains("erinnere dich"));
+    }
+
+    #[tokio::test]
+    async fn reminder_without_granted_scope_does_not_add_item() {
+        let repo = MockRepository::new();
+        let adds = repo.adds.clone();
+        let handler = make_handler(repo).with_reminder_service(Arc::new(ReminderService::new(
+            Arc::new(MockScheduler {
+                permission_granted: true,
+            }),
+        )));
+        let mut request = make_reminder_request();
+        request.context.as_mut().unwrap().system.user.permissions = None;
+
+        let response = handler.handle(request).await;
+
+        assert_eq!(adds.load(std::sync::atomic::Ordering::SeqCst), 0);
+        assert!(response
+            .response
+            .output_speech
+            .text
+            .starts_with("Damit ich dich an Milch erinnern kann"));
+    }
+
+    #[tokio::test]
+    async fn reminder_is_not_scheduled_when_adding_fails() {
+        let handler = make_handler(MockRepository::failing()).with_reminder_service(Arc::new(
+            ReminderService::new(Arc::new(MockScheduler {
+                permission_granted: true,
+            })),
+        ));
+        let response = handler.handle(make_reminder_request()).await;
+
+        assert!(!response.response.output_speech.text.contains("erinnere"));
+    }
+
+    #[tokio::test]
+    async fn maintenance_flag_short_circuits_requests() {
+        let handler = make_handler(MockRepository::failing())
+            .with_maintenance_flag(Arc::new(StaticMaintenanceFlag::new(true)));
+
+        let response = handler.handle(make_add_item_request("Milch")).await;
+
+        assert!(response.response.should_end_session);
+        assert!(response
+            .response
+            .output_speech
+            .text
+            .contains("aktualisiert"));
+    }
+
+    #[tokio::test]
+    async fn stop_is_handled_during_maintenance() {
+        let handler = make_handler(MockRepository::new())
+            .with_maintenance_flag(Arc::new(StaticMaintenanceFlag::new(true)));
+
+        let response = handler.handle(make_stop_request()).await;
+
+        assert!(response.response.output_speech.text.contains("Wiedersehen"));
+    }
+
+    #[tokio::test]
+    async fn deletes_user_data_after_confirmation() {
+        let history = Arc::new(InMemoryHistoryRepository::new());
+        let handler = make_handler(MockRepository::new())
+            .with_history_service(Arc::new(HistoryService::new(history.clone())))
+            .with_user_data_service(Arc::new(
+                UserDataService::new().with_history_repository(history.clone()),
+            ));
+        handler
+            .handle(make_user_intent_request(
+                "AddItemIntent",
+                r#"{"Item": {"name": "Item", "value": "Milch"}}"#,
+            ))
+            .await;
+
+        let question = handler
+            .handle(make_user_intent_request("DeleteMyDataIntent", "{}"))
+            .await;
+        assert!(!question.response.should_end_session);
+        assert_eq!(
+            history
+                .entries_since("user-123", "2000")
+                .await
+                .unwrap()
+                .len(),
+            1
+        );
+
+        let mut yes = make_user_intent_request("AMAZON.YesIntent", "{}");
+        yes.session.as_mut().unwrap().attributes = question.session_attributes;
+        let response = handler.handle(yes).await;
+
+        assert_eq!(
+            response.response.output_speech.text,
+            "Deine Daten wurden gelöscht."
+        );
+        assert!(history
+            .entries_since("user-123", "2000")
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn deleting_user_data_removes_speaker_preferences_and_permissions() {
+        let preferences = Arc::new(InMemoryPreferencesRepository::new());
+        let permissions = Arc::new(InMemoryPermissionStore::new());
+        let mut brief = UserPreferences::new();
+        brief.set_speech_style(Some(SpeechStyle::Brief));
+        preferences.save("user-123#person-1", &brief).await.unwrap();
+        let mut granted = SkillPermissions::new();
+        granted.set_scopes(["alexa::alerts:reminders:skill:readwrite"]);
+        permissions.save("user-123", &granted).await.unwrap();
+        let handler = make_handler(MockRepository::new()).with_user_data_service(Arc::new(
+            UserDataService::new()
+                .with_preferences_repository(preferences.clone())
+                .with_permission_store(permissions.clone()),
+        ));
+        let mut yes = make_user_intent_request("AMAZON.YesIntent", "{}");
+        yes.session.as_mut().unwrap().attributes =
+            SessionState::pending(PendingAction::DeleteMyData).to_attributes();
+
+        handler.handle(yes).await;
+
+        assert_eq!(
+            preferences.get("user-123#person-1").await.unwrap(),
+            UserPreferences::new()
+        );
+        assert_eq!(
+            permissions.get("user-123").await.unwrap(),
+            SkillPermissions::new()
+        );
+    }
+
+    #[tokio::test]
+    async fn no_keeps_user_data() {
         let handler = make_handler(MockRepository::new());
-        let response = handler.handle(make_help_request()).await;
+        let mut no = make_user_intent_request("AMAZON.NoIntent", "{}");
+        no.session.as_mut().unwrap().attributes =
+            SessionState::pending(PendingAction::DeleteMyData).to_attributes();
+
+        let response = handler.handle(no).await;
+
+        assert!(response
+            .response
+            .output_speech
+            .text
+            .starts_with("Okay, abgebrochen."));
+    }
+
+    fn make_pending_request(intent_name: &str) -> AlexaRequest {
+        let mut request = make_user_intent_request(intent_name, "{}");
+        request.session.as_mut().unwrap().attributes =
+            SessionState::pending(PendingAction::AddItem).to_attributes();
+        request
+    }
+
+    #[tokio::test]
+    async fn yes_continues_reading_long_list() {
+        let names: Vec<String> = (1..=12).map(|n| format!("Artikel {}", n)).collect();
+        let names: Vec<&str> = names.iter().map(String::as_str).collect();
+        let handler = make_handler(MockRepository::with_entries(&names));
+
+        let first = handler
+            .handle(make_user_intent_request("ReadListIntent", "{}"))
+            .await;
+        assert!(first
+            .response
+            .output_speech
+            .text
+            .ends_with("Soll ich weiterlesen?"));
+
+        let mut yes = make_user_intent_request("AMAZON.YesIntent", "{}");
+        yes.session.as_mut().unwrap().attributes = first.session_attributes;
+        let response = handler.handle(yes).await;
+
+        assert_eq!(
+            response.response.output_speech.text,
+            "Weiter geht's: Artikel 11 und Artikel 12."
+        );
+        assert!(response.response.should_end_session);
+    }
+
+    #[tokio::test]
+    async fn no_ends_reading_long_list() {
+        let handler = make_handler(MockRepository::new());
+        let mut request = make_user_intent_request("AMAZON.NoIntent", "{}");
+        request.session.as_mut().unwrap().attributes =
+            SessionState::pending(PendingAction::ContinueReading {
+                offset: 10,
+                open_only: false,
+            })
+            .to_attributes();
+
+        let response = handler.handle(request).await;
+
+        assert!(response.response.should_end_session);
+    }
+
+    #[tokio::test]
+    async fn yes_to_add_offer_asks_for_the_item() {
+        let handler = make_handler(MockRepository::new());
+
+        let response = handler
+            .handle(make_pending_request("AMAZON.YesIntent"))
+            .await;
+
+        assert_eq!(
+            response.response.output_speech.text,
+            "Was möchtest du hinzufügen?"
+        );
+        assert_eq!(
+            SessionState::from_attributes(&response.session_attributes).pending_action(),
+            Some(PendingAction::AddItem)
+        );
+    }
+
+    #[tokio::test]
+    async fn add_more_keeps_adding_until_no() {
+        let handler = make_handler(MockRepository::new()).with_add_more(true);
+
+        let first = handler
+            .handle(make_user_intent_request(
+                "AddItemIntent",
+                r#"{"Item": {"name": "Item", "value": "Milch"}}"#,
+            ))
+            .await;
+        assert!(!first.response.should_end_session);
+        assert!(first
+            .response
+            .output_speech
+            .text
+            .ends_with("Milch wurde zur Einkaufsliste hinzugefügt. Noch etwas?"));
+
+        let mut follow_up = make_user_intent_request(
+            "FollowUpItemIntent",
+            r#"{"Item": {"name": "Item", "value": "Eier"}}"#,
+        );
+        follow_up.session.as_mut().unwrap().attributes = first.session_attributes;
+        let second = handler.handle(follow_up).await;
+        assert!(second
+            .response
+            .output_speech
+            .text
+            .ends_with("Eier wurde zur Einkaufsliste hinzugefügt. Noch etwas?"));
+
+        let mut no = make_user_intent_request("AMAZON.NoIntent", "{}");
+        no.session.as_mut().unwrap().attributes = second.session_attributes;
+        let response = handler.handle(no).await;
+        assert!(response.response.should_end_session);
+    }
+
+    #[tokio::test]
+    async fn add_more_stays_on_occasion_list() {
+        let handler = make_handler(MockRepository::failing()).with_add_more(true);
+        let list_slot = r#""ListName": {"name": "ListName", "value": "Geburtstagsliste"}"#;
+
+        let first = handler
+            .handle(make_user_intent_request(
+                "AddItemIntent",
+                &format!(r#"{{"Item": {{"name": "Item", "value": "Kerzen"}}, {list_slot}}}"#),
+            ))
+            .await;
+        let mut follow_up = make_user_intent_request(
+            "FollowUpItemIntent",
+            r#"{"Item": {"name": "Item", "value": "Luftballons"}}"#,
+        );
+        follow_up.session.as_mut().unwrap().attributes = first.session_attributes;
+        let second = handler.handle(follow_up).await;
+
+        // The failing Cookidoo repository is never touched
+        assert!(second
+            .response
+            .output_speech
+            .text
+            .contains("Luftballons steht jetzt auf deiner Geburtstagsliste."));
+    }
+
+    #[tokio::test]
+    async fn add_ends_session_without_add_more() {
+        let handler = make_handler(MockRepository::new());
+
+        let response = handler
+            .handle(make_user_intent_request(
+                "AddItemIntent",
+                r#"{"Item": {"name": "Item", "value": "Milch"}}"#,
+            ))
+            .await;
+
+        assert!(response.response.should_end_session);
+    }
+
+    #[tokio::test]
+    async fn item_answers_add_offer() {
+        let handler = make_handler(MockRepository::new());
+        let mut request = make_user_intent_request(
+            "FollowUpItemIntent",
+            r#"{"Item": {"name": "Item", "value": "Eier"}}"#,
+        );
+        request.session.as_mut().unwrap().attributes =
+            SessionState::pending(PendingAction::AddItem).to_attributes();
+
+        let response = handler.handle(request).await;
+
+        assert!(response
+            .response
+            .output_speech
+            .text
+            .contains("Eier wurde zur Einkaufsliste hinzugefügt."));
+    }
+
+    #[tokio::test]
+    async fn bare_item_without_question_is_not_added() {
+        let handler = make_handler(MockRepository::new());
+
+        let response = handler
+            .handle(make_user_intent_request(
+                "FollowUpItemIntent",
+                r#"{"Item": {"name": "Item", "value": "Eier"}}"#,
+            ))
+            .await;
+
+        assert!(!response.response.should_end_session);
+        assert!(!response.response.output_speech.text.contains("hinzugefügt"));
+    }
+
+    #[tokio::test]
+    async fn cancel_aborts_pending_action_and_keeps_session_open() {
+        let handler = make_handler(MockRepository::new());
+        let response = handler
+            .handle(make_pending_request("AMAZON.CancelIntent"))
+            .await;
+
+        assert!(!response.response.should_end_session);
+        assert_eq!(
+            SessionState::from_attributes(&response.session_attributes).pending_action(),
+            None
+        );
+        assert_eq!(
+            response.response.output_speech.text,
+            "Okay, abgebrochen. Was möchtest du sonst tun?"
+        );
+    }
+
+    #[tokio::test]
+    async fn cancel_without_pending_action_says_goodbye() {
+        let handler = make_handler(MockRepository::new());
+        let response = handler
+            .handle(make_user_intent_request("AMAZON.CancelIntent", "{}"))
+            .await;
+
+        assert!(response.response.should_end_session);
+        assert!(response.response.output_speech.text.contains("Wiedersehen"));
+    }
+
+    #[tokio::test]
+    async fn stop_ends_session_despite_pending_action() {
+        let handler = make_handler(MockRepository::new());
+        let response = handler
+            .handle(make_pending_request("AMAZON.StopIntent"))
+            .await;
+
+        assert!(response.response.should_end_session);
+        assert!(response.response.output_speech.text.contains("Wiedersehen"));
+    }
+
+    #[tokio::test]
+    async fn repeat_reissues_last_response_with_its_state() {
+        let handler = make_handler(MockRepository::new());
+        let read = handler
+            .handle(make_user_intent_request("ReadListIntent", "{}"))
+            .await;
+
+        let mut repeat = make_user_intent_request("AMAZON.RepeatIntent", "{}");
+        repeat.session.as_mut().unwrap().attributes = read.session_attributes.clone();
+        let response = handler.handle(repeat).await;
+
+        assert!(!response.response.should_end_session);
+        assert_eq!(
+            response.response.output_speech.text,
+            read.response.output_speech.text
+        );
+        assert_eq!(response.session_attributes, read.session_attributes);
+    }
+
+    #[tokio::test]
+    async fn repeat_without_last_response_keeps_session_open() {
+        let handler = make_handler(MockRepository::new());
+        let response = handler
+            .handle(make_user_intent_request("AMAZON.RepeatIntent", "{}"))
+            .await;
+
+        assert!(!response.response.should_end_session);
+        assert!(response
+            .response
+            .output_speech
+            .text
+            .contains("nichts gesagt"));
+    }
+
+    #[tokio::test]
+    async fn ended_sessions_store_no_attributes() {
+        let handler = make_handler(MockRepository::new());
+        let response = handler.handle(make_add_item_request("Milch")).await;
+
+        assert!(response.session_attributes.is_empty());
+    }
+
+    #[tokio::test]
+    async fn start_over_clears_pending_action() {
+        let handler = make_handler(MockRepository::new());
+        let response = handler
+            .handle(make_pending_request("AMAZON.StartOverIntent"))
+            .await;
 
         assert!(!response.response.should_end_session);
+        assert!(response.response.output_speech.text.contains("Willkommen"));
+        assert_eq!(
+            SessionState::from_attributes(&response.session_attributes).pending_action(),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn navigate_home_ends_session() {
+        let handler = make_handler(MockRepository::new());
+        let response = handler
+            .handle(make_user_intent_request("AMAZON.NavigateHomeIntent", "{}"))
+            .await;
+
+        assert!(response.response.should_end_session);
+    }
+
+    #[tokio::test]
+    async fn acknowledges_unknown_request_type() {
+        let handler = make_handler(MockRepository::new());
+        let request: AlexaRequest = serde_json::from_str(
+            r#"{
+                "version": "1.0",
+                "request": {
+                    "type": "Alexa.Presentation.HTML.Message",
+                    "requestId": "req-123",
+                    "timestamp": "2024-01-27T10:00:00Z",
+                    "locale": "de-DE"
+                }
+            }"#,
+        )
+        .unwrap();
+
+        let response = handler.handle(request).await;
+
+        assert!(response.response.should_end_session);
+        assert!(response.response.output_speech.text.is_empty());
+    }
+
+    fn make_check_off_request(item_id: &str) -> AlexaRequest {
+        let json = format!(
+            r#"{{
+                "version": "1.0",
+                "request": {{
+                    "type": "Alexa.Presentation.APL.UserEvent",
+                    "requestId": "req-123",
+                    "timestamp": "2024-01-27T10:00:00Z",
+                    "locale": "de-DE",
+                    "token": "shoppingList",
+                    "arguments": ["checkOff", "{item_id}"]
+                }}
+            }}"#
+        );
+        serde_json::from_str(&json).unwrap()
+    }
+
+    #[tokio::test]
+    async fn checks_off_tapped_item() {
+        let handler = make_handler(MockRepository::with_entries(&["Milch", "Eier"]));
+        let response = handler.handle(make_check_off_request("1")).await;
+
+        assert!(response.response.should_end_session);
+        assert_eq!(response.response.output_speech.text, "Eier wurde abgehakt.");
+    }
+
+    #[tokio::test]
+    async fn tapped_item_no_longer_on_list() {
+        let handler = make_handler(MockRepository::with_entries(&["Milch"]));
+        let response = handler.handle(make_check_off_request("7")).await;
+
+        assert_eq!(
+            response.response.output_speech.text,
+            Speech::new(MessageId::ItemGone).render_default()
+        );
     }
 
     #[tokio::test]
@@ -1,34 +1,68 @@
-use super::models::{AlexaRequest, Request};
-
-/// Parsed intent from an Alexa request.
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub enum ParsedIntent {
-    /// User wants to add an item to the shopping list.
-    AddItem { item_name: String },
-    /// User requested help.
-    Help,
-    /// User wants to cancel.
-    Cancel,
-    /// User wants to stop.
-    Stop,
-    /// User launched the skill without a specific intent.
-    Launch,
-    /// Unknown or unsupported intent.
-    Unknown,
-}
+use std::sync::LazyLock;
+use std::time::Duration;
+
+use regex::Regex;
+
+use crate::domain::models::{
+    AccountChoice, HistoryPeriod, ListName, ListPosition, ParsedIntent, SettingChange, SpeechStyle,
+};
+use crate::domain::services::{ItemExtractor, ItemNameNormalizer};
+
+use super::models::{AlexaRequest, IntentRequest, Request};
 
 /// Intent names from Alexa.
-mod intent_names {
+pub mod intent_names {
     pub const ADD_ITEM: &str = "AddItemIntent";
+    pub const FOLLOW_UP_ITEM: &str = "FollowUpItemIntent";
+    pub const FREE_FORM_ADD: &str = "FreeFormAddIntent";
+    pub const ADD_BUNDLE: &str = "AddBundleIntent";
+    pub const EXTEND_BUNDLE: &str = "ExtendBundleIntent";
+    pub const DELETE_BUNDLE: &str = "DeleteBundleIntent";
+    pub const READ_BUNDLE: &str = "ReadBundleIntent";
+    pub const READ_LIST: &str = "ReadListIntent";
+    pub const READ_OPEN_ITEMS: &str = "ReadOpenItemsIntent";
+    pub const REMOVE_ITEM: &str = "RemoveItemIntent";
+    pub const HOW_MANY_ITEMS: &str = "HowManyItemsIntent";
+    pub const TRANSFER_LIST: &str = "TransferListIntent";
+    pub const HISTORY: &str = "HistoryIntent";
+    pub const REMIND: &str = "ReminderIntent";
+    pub const SETTINGS: &str = "SettingsIntent";
+    pub const USE_ACCOUNT: &str = "UseAccountIntent";
+    pub const DELETE_MY_DATA: &str = "DeleteMyDataIntent";
     pub const HELP: &str = "AMAZON.HelpIntent";
     pub const CANCEL: &str = "AMAZON.CancelIntent";
     pub const STOP: &str = "AMAZON.StopIntent";
+    pub const REPEAT: &str = "AMAZON.RepeatIntent";
+    pub const START_OVER: &str = "AMAZON.StartOverIntent";
+    pub const YES: &str = "AMAZON.YesIntent";
+    pub const NO: &str = "AMAZON.NoIntent";
+    pub const PAUSE: &str = "AMAZON.PauseIntent";
     pub const FALLBACK: &str = "AMAZON.FallbackIntent";
+    /// Built-in intent Alexa requires in every custom model.
+    pub const NAVIGATE_HOME: &str = "AMAZON.NavigateHomeIntent";
+}
+
+/// First `SendEvent` argument of APL touch events; the remaining arguments
+/// are the command's parameters.
+pub(crate) mod apl_commands {
+    /// Checks off an item: `["checkOff", "<item id>"]`.
+    pub const CHECK_OFF: &str = "checkOff";
 }
 
 /// Slot names for intents.
-mod slot_names {
+pub mod slot_names {
     pub const ITEM: &str = "Item";
+    pub const NOTE: &str = "Note";
+    pub const LIST_NAME: &str = "ListName";
+    pub const DURATION: &str = "Duration";
+    pub const SPEECH_STYLE: &str = "SpeechStyle";
+    pub const PERIOD: &str = "Period";
+    pub const ACCOUNT: &str = "Account";
+    pub const QUERY: &str = "Query";
+    pub const POSITION: &str = "Position";
+    pub const QUANTITY: &str = "Quantity";
+    pub const BUNDLE: &str = "Bundle";
+    pub const REGION: &str = "Region";
 }
 
 /// Parses an Alexa request into a domain-friendly intent.
@@ -40,30 +74,366 @@ pub fn parse(request: &AlexaRequest) -> ParsedIntent {
             let intent_name = intent_req.intent.name.as_str();
 
             match intent_name {
-                intent_names::ADD_ITEM => {
-                    let item_name = intent_req
-                        .intent
-                        .slots
-                        .get(slot_names::ITEM)
-                        .and_then(|slot| slot.value.clone())
-                        .unwrap_or_default();
-
-                    if item_name.is_empty() {
-                        ParsedIntent::Unknown
-                    } else {
-                        ParsedIntent::AddItem { item_name }
+                intent_names::ADD_ITEM => match counted_item_name(intent_req) {
+                    Some(item_name) => ParsedIntent::AddItem {
+                        item_name,
+                        note: slot_value(intent_req, slot_names::NOTE),
+                        list: list_context(intent_req),
+                    },
+                    None => ParsedIntent::Unknown,
+                },
+                intent_names::FOLLOW_UP_ITEM => match item_name(intent_req) {
+                    Some(item_name) => ParsedIntent::FollowUpItem { item_name },
+                    None => ParsedIntent::Unknown,
+                },
+                intent_names::FREE_FORM_ADD => free_form_add(intent_req),
+                intent_names::ADD_BUNDLE => match slot_value(intent_req, slot_names::BUNDLE) {
+                    Some(name) => ParsedIntent::AddBundle { name },
+                    None => ParsedIntent::Unknown,
+                },
+                intent_names::EXTEND_BUNDLE => match (
+                    slot_value(intent_req, slot_names::BUNDLE),
+                    item_name(intent_req),
+                ) {
+                    (Some(name), Some(item_name)) => ParsedIntent::ExtendBundle { name, item_name },
+                    _ => ParsedIntent::Unknown,
+                },
+                intent_names::DELETE_BUNDLE => match slot_value(intent_req, slot_names::BUNDLE) {
+                    Some(name) => ParsedIntent::DeleteBundle { name },
+                    None => ParsedIntent::Unknown,
+                },
+                intent_names::READ_BUNDLE => ParsedIntent::ReadBundle {
+                    name: slot_value(intent_req, slot_names::BUNDLE),
+                },
+                intent_names::READ_LIST => ParsedIntent::ReadList {
+                    list: list_context(intent_req),
+                },
+                intent_names::READ_OPEN_ITEMS => ParsedIntent::ReadOpenItems,
+                intent_names::HOW_MANY_ITEMS => ParsedIntent::CountItems,
+                intent_names::TRANSFER_LIST => {
+                    match list_context(intent_req).filter(|list| !list.is_shopping_list()) {
+                        Some(list) => ParsedIntent::TransferList { list },
+                        None => ParsedIntent::Unknown,
                     }
                 }
+                // Without a spoken period ("was habe ich zuletzt hinzugefügt")
+                // the answer covers today
+                intent_names::HISTORY => ParsedIntent::ReadHistory {
+                    period: slot_value(intent_req, slot_names::PERIOD)
+                        .and_then(|value| HistoryPeriod::parse(&value))
+                        .unwrap_or_default(),
+                },
+                intent_names::REMOVE_ITEM => match list_position(intent_req) {
+                    Some(position) => ParsedIntent::RemoveItemAt { position },
+                    None => match item_name(intent_req) {
+                        Some(item_name) => ParsedIntent::RemoveItem {
+                            item_name,
+                            list: list_context(intent_req),
+                        },
+                        None => ParsedIntent::Unknown,
+                    },
+                },
+                intent_names::REMIND => match item_name(intent_req) {
+                    Some(item_name) => ParsedIntent::Remind {
+                        item_name,
+                        offset: slot_value(intent_req, slot_names::DURATION)
+                            .and_then(|value| parse_duration(&value)),
+                    },
+                    None => ParsedIntent::Unknown,
+                },
+                intent_names::DELETE_MY_DATA => ParsedIntent::DeleteMyData,
+                intent_names::SETTINGS => match setting_change(intent_req) {
+                    Some(change) => ParsedIntent::ChangeSetting { change },
+                    None => ParsedIntent::Unknown,
+                },
+                intent_names::USE_ACCOUNT => ParsedIntent::ChangeSetting {
+                    change: SettingChange::Account(account_choice(intent_req)),
+                },
                 intent_names::HELP => ParsedIntent::Help,
                 intent_names::CANCEL => ParsedIntent::Cancel,
                 intent_names::STOP => ParsedIntent::Stop,
-                intent_names::FALLBACK => ParsedIntent::Unknown,
+                intent_names::REPEAT => ParsedIntent::Repeat,
+                intent_names::START_OVER => ParsedIntent::StartOver,
+                intent_names::YES => ParsedIntent::Yes,
+                intent_names::NO => ParsedIntent::No,
+                // The skill plays no audio, so pausing or leaving for the home
+                // screen simply ends the session.
+                intent_names::PAUSE | intent_names::NAVIGATE_HOME => ParsedIntent::Stop,
+                intent_names::FALLBACK => fallback(intent_req),
                 _ => ParsedIntent::Unknown,
             }
         }
 
         Request::SessionEnded(_) => ParsedIntent::Stop,
+
+        Request::AplUserEvent(event) => match event.arguments.as_slice() {
+            [command, item_id] if command.as_str() == Some(apl_commands::CHECK_OFF) => {
+                match item_id.as_str().filter(|id| !id.is_empty()) {
+                    Some(item_id) => ParsedIntent::CheckOffItem {
+                        item_id: item_id.to_string(),
+                    },
+                    None => ParsedIntent::Unknown,
+                }
+            }
+            _ => ParsedIntent::Unknown,
+        },
+
+        Request::Unknown(_) => ParsedIntent::Unknown,
+
+        Request::PermissionAccepted(event) | Request::PermissionChanged(event) => {
+            match request.user_id() {
+                Some(user_id) => ParsedIntent::PermissionsChanged {
+                    user_id: user_id.to_string(),
+                    scopes: event
+                        .body
+                        .accepted_permissions
+                        .iter()
+                        .map(|permission| permission.scope.clone())
+                        .collect(),
+                },
+                None => ParsedIntent::Unknown,
+            }
+        }
+
+        Request::ProactiveSubscriptionChanged(event) => match request.user_id() {
+            Some(user_id) => ParsedIntent::SubscriptionsChanged {
+                user_id: user_id.to_string(),
+                events: event
+                    .body
+                    .subscriptions
+                    .iter()
+                    .map(|subscription| subscription.event_name.clone())
+                    .collect(),
+            },
+            None => ParsedIntent::Unknown,
+        },
+
+        Request::SkillEnabled(_) => match request.user_id() {
+            Some(user_id) => ParsedIntent::SkillEnabled {
+                user_id: user_id.to_string(),
+            },
+            None => ParsedIntent::Unknown,
+        },
+
+        Request::SkillDisabled(event) => match request.user_id() {
+            Some(user_id) => ParsedIntent::SkillDisabled {
+                user_id: user_id.to_string(),
+                keeps_user_data: event.keeps_user_information(),
+            },
+            None => ParsedIntent::Unknown,
+        },
+
+        Request::AccountLinked(_) => match request.user_id() {
+            Some(user_id) => ParsedIntent::AccountLinked {
+                user_id: user_id.to_string(),
+            },
+            None => ParsedIntent::Unknown,
+        },
+    }
+}
+
+/// Slots of custom types with a fixed set of values, whose entity
+/// resolution names the value meant ("kurz" for "knapp"). Free-form slots
+/// such as the item, note or search query keep the words as recognized;
+/// aliases among item names are resolved by the domain.
+const RESOLVED_SLOTS: &[&str] = &[
+    slot_names::LIST_NAME,
+    slot_names::SPEECH_STYLE,
+    slot_names::REGION,
+    slot_names::PERIOD,
+    slot_names::POSITION,
+    slot_names::BUNDLE,
+];
+
+/// Returns a non-empty slot value, for [`RESOLVED_SLOTS`] preferring the
+/// canonical value entity resolution matched over the recognized words.
+fn slot_value(intent_req: &IntentRequest, slot_name: &str) -> Option<String> {
+    let slot = intent_req.intent.slots.get(slot_name)?;
+    let value = if RESOLVED_SLOTS.contains(&slot_name) {
+        slot.best_value()
+    } else {
+        slot.spoken_value()
+    };
+    value.filter(|value| !value.is_empty()).map(str::to_string)
+}
+
+/// Returns the item slot value with locale-specific casing applied.
+fn item_name(intent_req: &IntentRequest) -> Option<String> {
+    slot_value(intent_req, slot_names::ITEM)
+        .map(|value| ItemNameNormalizer::normalize(&value, &intent_req.locale))
+        .filter(|value| !value.is_empty())
+}
+
+/// Returns the item name with the spoken count in front, e.g. "3 Zitronen"
+/// for "füge drei Zitronen hinzu"; the add splits it off again.
+fn counted_item_name(intent_req: &IntentRequest) -> Option<String> {
+    let item_name = item_name(intent_req)?;
+    match slot_value(intent_req, slot_names::QUANTITY).and_then(|value| value.parse::<u32>().ok()) {
+        Some(quantity) => Some(format!("{} {}", quantity, item_name)),
+        None => Some(item_name),
+    }
+}
+
+/// Returns the spoken list position, e.g. "den letzten"; Alexa may also
+/// put the position into the item slot.
+fn list_position(intent_req: &IntentRequest) -> Option<ListPosition> {
+    [slot_names::POSITION, slot_names::ITEM]
+        .iter()
+        .filter_map(|slot_name| slot_value(intent_req, slot_name))
+        .find_map(|value| ListPosition::parse(&value))
+}
+
+/// Utterances that name items to add even though Alexa matched no intent,
+/// e.g. "füge Rote Bete hinzu" when "Rote Bete" was not recognized as food.
+static ADD_PATTERNS: LazyLock<Vec<Regex>> = LazyLock::new(|| {
+    [
+        concat!(
+            r"(?i)^(?:bitte\s+)?(?:füge|füg|tu|pack|schreib|schreibe|setz|setze)\s+",
+            r"(?P<items>.+?)\s+",
+            r"(?:hinzu|dazu|auf\s+(?:die|meine)\s+(?:einkaufs)?liste|zur\s+(?:einkaufs)?liste(?:\s+hinzu)?)",
+            r"(?:\s+bitte)?$",
+        ),
+        r"(?i)^(?:ich|wir)\s+(?:brauche|brauchen|brauch|bräuchte|bräuchten)\s+(?P<items>.+)$",
+        r"(?i)^(?:ich|wir)\s+(?:haben|hab|habe)\s+(?:keine|keinen|kein)\s+(?P<items>.+?)(?:\s+mehr)?$",
+        r"(?i)^(?P<items>.+?)\s+auf\s+die\s+(?:einkaufs)?liste$",
+    ]
+    .iter()
+    .map(|pattern| Regex::new(pattern).expect("valid regex"))
+    .collect()
+});
+
+/// Looks for items in the utterance of a fallback intent before giving
+/// up, so "füge X hinzu" still adds X when Alexa did not recognize it.
+fn fallback(intent_req: &IntentRequest) -> ParsedIntent {
+    let Some(utterance) = intent_req.utterance() else {
+        return ParsedIntent::Unknown;
+    };
+    let utterance = utterance.trim_end_matches(['.', '!', '?']);
+
+    ADD_PATTERNS
+        .iter()
+        .find_map(|pattern| pattern.captures(utterance))
+        .and_then(|captures| captures.name("items"))
+        .map(|items| items_intent(items.as_str(), &intent_req.locale))
+        .unwrap_or(ParsedIntent::Unknown)
+}
+
+/// Returns the items named in a free-form query.
+fn free_form_add(intent_req: &IntentRequest) -> ParsedIntent {
+    let query = slot_value(intent_req, slot_names::QUERY).unwrap_or_default();
+    items_intent(&query, &intent_req.locale)
+}
+
+/// Extracts the items from free-form speech: a single item is added like a
+/// regular add, several are added together.
+pub(crate) fn items_intent(query: &str, locale: &str) -> ParsedIntent {
+    let mut item_names: Vec<String> = ItemExtractor::extract(query)
+        .iter()
+        .map(|name| ItemNameNormalizer::normalize(name, locale))
+        .filter(|name| !name.is_empty())
+        .collect();
+
+    match item_names.len() {
+        0 => ParsedIntent::Unknown,
+        1 => ParsedIntent::AddItem {
+            item_name: item_names.remove(0),
+            note: None,
+            list: None,
+        },
+        _ => ParsedIntent::AddItems { item_names },
+    }
+}
+
+/// Returns the list named in the list-context slot.
+///
+/// Naming the shopping list itself ("auf die Einkaufsliste") is kept, so it
+/// takes precedence over a default occasion list.
+fn list_context(intent_req: &IntentRequest) -> Option<ListName> {
+    slot_value(intent_req, slot_names::LIST_NAME).and_then(|value| ListName::new(&value))
+}
+
+/// Returns the setting named in a settings intent.
+///
+/// Naming the shopping list as default list ("stelle die Standardliste auf
+/// die Einkaufsliste") switches back to the shopping list.
+fn setting_change(intent_req: &IntentRequest) -> Option<SettingChange> {
+    if let Some(style) =
+        slot_value(intent_req, slot_names::SPEECH_STYLE).and_then(|value| spoken_style(&value))
+    {
+        return Some(SettingChange::SpeechStyle(style));
+    }
+
+    if let Some(locale) =
+        slot_value(intent_req, slot_names::REGION).and_then(|value| spoken_locale(&value))
+    {
+        return Some(SettingChange::Locale(locale.to_string()));
+    }
+
+    let list = ListName::new(&slot_value(intent_req, slot_names::LIST_NAME)?)?;
+    Some(SettingChange::DefaultList(
+        Some(list).filter(|list| !list.is_shopping_list()),
+    ))
+}
+
+/// Returns the household account named in a use-account intent.
+///
+/// The intent's samples without a name ("benutze die gemeinsame Liste")
+/// choose the shared account.
+fn account_choice(intent_req: &IntentRequest) -> AccountChoice {
+    match slot_value(intent_req, slot_names::ACCOUNT) {
+        Some(name) => AccountChoice::Named(name),
+        None => AccountChoice::Household,
+    }
+}
+
+/// Maps a spoken `SpeechStyle` slot value such as "kurze" to a style.
+fn spoken_style(value: &str) -> Option<SpeechStyle> {
+    match value.trim().to_lowercase().as_str() {
+        "kurz" | "kurze" | "knapp" | "knappe" => Some(SpeechStyle::Brief),
+        "ausführlich" | "ausführliche" | "lang" | "lange" => Some(SpeechStyle::Full),
+        _ => None,
+    }
+}
+
+/// Maps a spoken region ("Österreich") to the locale used for it.
+fn spoken_locale(value: &str) -> Option<&'static str> {
+    match value.trim().to_lowercase().as_str() {
+        "deutschland" | "deutsch" | "hochdeutsch" => Some("de-DE"),
+        "österreich" | "österreichisch" => Some("de-AT"),
+        "schweiz" | "die schweiz" | "schweizerdeutsch" => Some("de-CH"),
+        _ => None,
+    }
+}
+
+/// Parses an `AMAZON.DURATION` slot value such as "PT2H" or "P1DT30M".
+///
+/// Only weeks, days, hours, minutes and seconds are supported; years and
+/// months have no fixed length and yield `None`.
+fn parse_duration(value: &str) -> Option<Duration> {
+    let rest = value.strip_prefix('P')?;
+    let (date, time) = rest.split_once('T').unwrap_or((rest, ""));
+
+    let mut seconds = 0;
+    for (part, units) in [
+        (date, &[('W', 7 * 86400), ('D', 86400)][..]),
+        (time, &[('H', 3600), ('M', 60), ('S', 1)][..]),
+    ] {
+        let mut number = String::new();
+        for c in part.chars() {
+            if c.is_ascii_digit() {
+                number.push(c);
+                continue;
+            }
+            let (_, factor) = units.iter().find(|(unit, _)| *unit == c)?;
+            seconds += number.parse::<u64>().ok()? * factor;
+            number.clear();
+        }
+        if !number.is_empty() {
+            return None;
+        }
     }
+
+    (seconds > 0).then(|| Duration::from_secs(seconds))
 }
 
 #[cfg(test)]
@@ -119,11 +489,294 @@ mod tests {
         assert_eq!(
             parse(&request),
             ParsedIntent::AddItem {
-                item_name: "Milch".to_string()
+                item_name: "Milch".to_string(),
+                note: None,
+                list: None,
+            }
+        );
+    }
+
+    #[test]
+    fn parses_free_form_query_with_one_item() {
+        let request = make_intent_request(
+            "FreeFormAddIntent",
+            r#"{"Query": {"name": "Query", "value": "so Zeug für lasagne"}}"#,
+        );
+        assert_eq!(
+            parse(&request),
+            ParsedIntent::AddItem {
+                item_name: "Lasagne".to_string(),
+                note: None,
+                list: None,
+            }
+        );
+    }
+
+    #[test]
+    fn parses_free_form_query_with_several_items() {
+        let request = make_intent_request(
+            "FreeFormAddIntent",
+            r#"{"Query": {"name": "Query", "value": "keine milch und eier mehr"}}"#,
+        );
+        assert_eq!(
+            parse(&request),
+            ParsedIntent::AddItems {
+                item_names: vec!["Milch".to_string(), "Eier".to_string()],
+            }
+        );
+    }
+
+    #[test]
+    fn free_form_query_without_items_is_unknown() {
+        let request = make_intent_request(
+            "FreeFormAddIntent",
+            r#"{"Query": {"name": "Query", "value": "so Zeug"}}"#,
+        );
+        assert_eq!(parse(&request), ParsedIntent::Unknown);
+    }
+
+    #[test]
+    fn normalizes_item_name_casing() {
+        let request = make_intent_request(
+            "AddItemIntent",
+            r#"{"Item": {"name": "Item", "value": "frische eier"}}"#,
+        );
+        assert_eq!(
+            parse(&request),
+            ParsedIntent::AddItem {
+                item_name: "frische Eier".to_string(),
+                note: None,
+                list: None,
             }
         );
     }
 
+    #[test]
+    fn parses_add_item_intent_with_note() {
+        let request = make_intent_request(
+            "AddItemIntent",
+            r#"{
+                "Item": {"name": "Item", "value": "Milch"},
+                "Note": {"name": "Note", "value": "fettarm"}
+            }"#,
+        );
+        assert_eq!(
+            parse(&request),
+            ParsedIntent::AddItem {
+                item_name: "Milch".to_string(),
+                note: Some("fettarm".to_string()),
+                list: None,
+            }
+        );
+    }
+
+    #[test]
+    fn keeps_spoken_item_value_despite_resolution() {
+        let request = make_intent_request(
+            "AddItemIntent",
+            r#"{"Item": {
+                "name": "Item",
+                "value": "schrippen",
+                "resolutions": {"resolutionsPerAuthority": [{
+                    "authority": "amzn1.er-authority.echo-sdk.skill-123.AMAZON.Food",
+                    "status": {"code": "ER_SUCCESS_MATCH"},
+                    "values": [{"value": {"name": "Brötchen", "id": "1"}}]
+                }]}
+            }}"#,
+        );
+        assert_eq!(
+            parse(&request),
+            ParsedIntent::AddItem {
+                item_name: "Schrippen".to_string(),
+                note: None,
+                list: None,
+            }
+        );
+    }
+
+    #[test]
+    fn prefers_resolved_value_of_custom_slot() {
+        let request = make_intent_request(
+            "ReadListIntent",
+            r#"{"ListName": {
+                "name": "ListName",
+                "value": "geburtstag",
+                "resolutions": {"resolutionsPerAuthority": [{
+                    "authority": "amzn1.er-authority.echo-sdk.skill-123.LIST_NAME",
+                    "status": {"code": "ER_SUCCESS_MATCH"},
+                    "values": [{"value": {"name": "Geburtstagsliste", "id": "1"}}]
+                }]}
+            }}"#,
+        );
+        assert_eq!(
+            parse(&request),
+            ParsedIntent::ReadList {
+                list: ListName::new("Geburtstagsliste"),
+            }
+        );
+    }
+
+    #[test]
+    fn parses_follow_up_item() {
+        let request = make_intent_request(
+            "FollowUpItemIntent",
+            r#"{"Item": {"name": "Item", "value": "eier"}}"#,
+        );
+        assert_eq!(
+            parse(&request),
+            ParsedIntent::FollowUpItem {
+                item_name: "Eier".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn parses_add_item_intent_with_list_context() {
+        let request = make_intent_request(
+            "AddItemIntent",
+            r#"{
+                "Item": {"name": "Item", "value": "Kerzen"},
+                "ListName": {"name": "ListName", "value": "Geburtstagsliste"}
+            }"#,
+        );
+        assert_eq!(
+            parse(&request),
+            ParsedIntent::AddItem {
+                item_name: "Kerzen".to_string(),
+                note: None,
+                list: ListName::new("Geburtstagsliste"),
+            }
+        );
+    }
+
+    #[test]
+    fn keeps_shopping_list_context() {
+        let request = make_intent_request(
+            "AddItemIntent",
+            r#"{
+                "Item": {"name": "Item", "value": "Milch"},
+                "ListName": {"name": "ListName", "value": "Einkaufsliste"}
+            }"#,
+        );
+        assert_eq!(
+            parse(&request),
+            ParsedIntent::AddItem {
+                item_name: "Milch".to_string(),
+                note: None,
+                list: ListName::new("Einkaufsliste"),
+            }
+        );
+    }
+
+    #[test]
+    fn parses_add_item_quantity() {
+        let request = make_intent_request(
+            "AddItemIntent",
+            r#"{
+                "Item": {"name": "Item", "value": "zitronen"},
+                "Quantity": {"name": "Quantity", "value": "3"}
+            }"#,
+        );
+        assert_eq!(
+            parse(&request),
+            ParsedIntent::AddItem {
+                item_name: "3 Zitronen".to_string(),
+                note: None,
+                list: None,
+            }
+        );
+    }
+
+    #[test]
+    fn parses_remove_item_intent() {
+        let request = make_intent_request(
+            "RemoveItemIntent",
+            r#"{"Item": {"name": "Item", "value": "Milch"}}"#,
+        );
+        assert_eq!(
+            parse(&request),
+            ParsedIntent::RemoveItem {
+                item_name: "Milch".to_string(),
+                list: None,
+            }
+        );
+    }
+
+    #[test]
+    fn parses_remove_by_position() {
+        let request = make_intent_request(
+            "RemoveItemIntent",
+            r#"{"Position": {"name": "Position", "value": "letzten"}}"#,
+        );
+        assert_eq!(
+            parse(&request),
+            ParsedIntent::RemoveItemAt {
+                position: ListPosition::Last
+            }
+        );
+
+        let request = make_intent_request(
+            "RemoveItemIntent",
+            r#"{"Item": {"name": "Item", "value": "Nummer drei"}}"#,
+        );
+        assert_eq!(
+            parse(&request),
+            ParsedIntent::RemoveItemAt {
+                position: ListPosition::Number(3)
+            }
+        );
+    }
+
+    #[test]
+    fn parses_reminder_intent_with_duration() {
+        let request = make_intent_request(
+            "ReminderIntent",
+            r#"{
+                "Item": {"name": "Item", "value": "milch"},
+                "Duration": {"name": "Duration", "value": "PT2H30M"}
+            }"#,
+        );
+        assert_eq!(
+            parse(&request),
+            ParsedIntent::Remind {
+                item_name: "Milch".to_string(),
+                offset: Some(Duration::from_secs(9000)),
+            }
+        );
+    }
+
+    #[test]
+    fn parses_reminder_intent_without_duration() {
+        let request = make_intent_request(
+            "ReminderIntent",
+            r#"{"Item": {"name": "Item", "value": "Milch"}}"#,
+        );
+        assert_eq!(
+            parse(&request),
+            ParsedIntent::Remind {
+                item_name: "Milch".to_string(),
+                offset: None,
+            }
+        );
+    }
+
+    #[test]
+    fn parses_iso_durations() {
+        assert_eq!(parse_duration("PT15M"), Some(Duration::from_secs(900)));
+        assert_eq!(parse_duration("P1D"), Some(Duration::from_secs(86400)));
+        assert_eq!(parse_duration("P1DT1H"), Some(Duration::from_secs(90000)));
+        assert_eq!(parse_duration("P2W"), Some(Duration::from_secs(1209600)));
+        assert_eq!(parse_duration("P1M"), None, "months are not supported");
+        assert_eq!(parse_duration("PT0S"), None);
+        assert_eq!(parse_duration("2 Stunden"), None);
+    }
+
+    #[test]
+    fn parses_remove_item_intent_without_slot_as_unknown() {
+        let request = make_intent_request("RemoveItemIntent", "{}");
+        assert_eq!(parse(&request), ParsedIntent::Unknown);
+    }
+
     #[test]
     fn parses_add_item_intent_without_slot_as_unknown() {
         let request = make_intent_request("AddItemIntent", "{}");
@@ -139,6 +792,214 @@ mod tests {
         assert_eq!(parse(&request), ParsedIntent::Unknown);
     }
 
+    #[test]
+    fn parses_settings_intent_with_speech_style() {
+        let request = make_intent_request(
+            "SettingsIntent",
+            r#"{"SpeechStyle": {"name": "SpeechStyle", "value": "kurze"}}"#,
+        );
+        assert_eq!(
+            parse(&request),
+            ParsedIntent::ChangeSetting {
+                change: SettingChange::SpeechStyle(SpeechStyle::Brief),
+            }
+        );
+    }
+
+    #[test]
+    fn parses_settings_intent_with_default_list() {
+        let request = make_intent_request(
+            "SettingsIntent",
+            r#"{"ListName": {"name": "ListName", "value": "Geburtstagsliste"}}"#,
+        );
+        assert_eq!(
+            parse(&request),
+            ParsedIntent::ChangeSetting {
+                change: SettingChange::DefaultList(ListName::new("Geburtstagsliste")),
+            }
+        );
+
+        let request = make_intent_request(
+            "SettingsIntent",
+            r#"{"ListName": {"name": "ListName", "value": "Einkaufsliste"}}"#,
+        );
+        assert_eq!(
+            parse(&request),
+            ParsedIntent::ChangeSetting {
+                change: SettingChange::DefaultList(None),
+            }
+        );
+    }
+
+    #[test]
+    fn parses_settings_intent_with_region() {
+        let request = make_intent_request(
+            "SettingsIntent",
+            r#"{"Region": {"name": "Region", "value": "Österreich"}}"#,
+        );
+        assert_eq!(
+            parse(&request),
+            ParsedIntent::ChangeSetting {
+                change: SettingChange::Locale("de-AT".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn parses_use_account_intent() {
+        let request = make_intent_request(
+            "UseAccountIntent",
+            r#"{"Account": {"name": "Account", "value": "Papas"}}"#,
+        );
+        assert_eq!(
+            parse(&request),
+            ParsedIntent::ChangeSetting {
+                change: SettingChange::Account(AccountChoice::Named("Papas".to_string())),
+            }
+        );
+
+        let request = make_intent_request("UseAccountIntent", "{}");
+        assert_eq!(
+            parse(&request),
+            ParsedIntent::ChangeSetting {
+                change: SettingChange::Account(AccountChoice::Household),
+            }
+        );
+    }
+
+    #[test]
+    fn parses_settings_intent_without_setting_as_unknown() {
+        let request = make_intent_request(
+            "SettingsIntent",
+            r#"{"SpeechStyle": {"name": "SpeechStyle", "value": "laut"}}"#,
+        );
+        assert_eq!(parse(&request), ParsedIntent::Unknown);
+    }
+
+    #[test]
+    fn parses_delete_my_data_intent() {
+        let request = make_intent_request("DeleteMyDataIntent", "{}");
+        assert_eq!(parse(&request), ParsedIntent::DeleteMyData);
+    }
+
+    #[test]
+    fn parses_how_many_items_intent() {
+        let request = make_intent_request("HowManyItemsIntent", "{}");
+        assert_eq!(parse(&request), ParsedIntent::CountItems);
+    }
+
+    #[test]
+    fn parses_history_intent() {
+        let request = make_intent_request(
+            "HistoryIntent",
+            r#"{"Period": {"name": "Period", "value": "diese Woche"}}"#,
+        );
+        assert_eq!(
+            parse(&request),
+            ParsedIntent::ReadHistory {
+                period: HistoryPeriod::ThisWeek,
+            }
+        );
+
+        let request = make_intent_request("HistoryIntent", "{}");
+        assert_eq!(
+            parse(&request),
+            ParsedIntent::ReadHistory {
+                period: HistoryPeriod::Today,
+            }
+        );
+    }
+
+    #[test]
+    fn parses_read_list_intent() {
+        let request = make_intent_request("ReadListIntent", "{}");
+        assert_eq!(parse(&request), ParsedIntent::ReadList { list: None });
+    }
+
+    #[test]
+    fn parses_read_list_intent_with_list_context() {
+        let request = make_intent_request(
+            "ReadListIntent",
+            r#"{"ListName": {"name": "ListName", "value": "Geburtstagsliste"}}"#,
+        );
+        assert_eq!(
+            parse(&request),
+            ParsedIntent::ReadList {
+                list: ListName::new("Geburtstagsliste"),
+            }
+        );
+    }
+
+    #[test]
+    fn parses_add_bundle_intent() {
+        let request = make_intent_request(
+            "AddBundleIntent",
+            r#"{"Bundle": {"name": "Bundle", "value": "Frühstücks-Set"}}"#,
+        );
+        assert_eq!(
+            parse(&request),
+            ParsedIntent::AddBundle {
+                name: "Frühstücks-Set".to_string(),
+            }
+        );
+
+        let request = make_intent_request("AddBundleIntent", "{}");
+        assert_eq!(parse(&request), ParsedIntent::Unknown);
+    }
+
+    #[test]
+    fn parses_bundle_management_intents() {
+        let request = make_intent_request(
+            "ExtendBundleIntent",
+            r#"{"Bundle": {"name": "Bundle", "value": "Frühstücks-Set"},
+                "Item": {"name": "Item", "value": "butter"}}"#,
+        );
+        assert_eq!(
+            parse(&request),
+            ParsedIntent::ExtendBundle {
+                name: "Frühstücks-Set".to_string(),
+                item_name: "Butter".to_string(),
+            }
+        );
+
+        let request = make_intent_request(
+            "DeleteBundleIntent",
+            r#"{"Bundle": {"name": "Bundle", "value": "Grill-Set"}}"#,
+        );
+        assert_eq!(
+            parse(&request),
+            ParsedIntent::DeleteBundle {
+                name: "Grill-Set".to_string(),
+            }
+        );
+
+        let request = make_intent_request("ReadBundleIntent", "{}");
+        assert_eq!(parse(&request), ParsedIntent::ReadBundle { name: None });
+    }
+
+    #[test]
+    fn parses_transfer_list_intent() {
+        let request = make_intent_request(
+            "TransferListIntent",
+            r#"{"ListName": {"name": "ListName", "value": "Party-Liste"}}"#,
+        );
+        assert_eq!(
+            parse(&request),
+            ParsedIntent::TransferList {
+                list: ListName::new("Party-Liste").unwrap(),
+            }
+        );
+    }
+
+    #[test]
+    fn transfer_of_shopping_list_is_unknown() {
+        let request = make_intent_request(
+            "TransferListIntent",
+            r#"{"ListName": {"name": "ListName", "value": "Einkaufsliste"}}"#,
+        );
+        assert_eq!(parse(&request), ParsedIntent::Unknown);
+    }
+
     #[test]
     fn parses_help_intent() {
         let request = make_intent_request("AMAZON.HelpIntent", "{}");
@@ -151,6 +1012,35 @@ mod tests {
         assert_eq!(parse(&request), ParsedIntent::Cancel);
     }
 
+    #[test]
+    fn parses_repeat_intent() {
+        let request = make_intent_request("AMAZON.RepeatIntent", "{}");
+        assert_eq!(parse(&request), ParsedIntent::Repeat);
+    }
+
+    #[test]
+    fn parses_start_over_intent() {
+        let request = make_intent_request("AMAZON.StartOverIntent", "{}");
+        assert_eq!(parse(&request), ParsedIntent::StartOver);
+    }
+
+    #[test]
+    fn parses_yes_and_no() {
+        let yes = make_intent_request("AMAZON.YesIntent", "{}");
+        let no = make_intent_request("AMAZON.NoIntent", "{}");
+
+        assert_eq!(parse(&yes), ParsedIntent::Yes);
+        assert_eq!(parse(&no), ParsedIntent::No);
+    }
+
+    #[test]
+    fn pause_and_navigate_home_stop_the_skill() {
+        for name in ["AMAZON.PauseIntent", "AMAZON.NavigateHomeIntent"] {
+            let request = make_intent_request(name, "{}");
+            assert_eq!(parse(&request), ParsedIntent::Stop, "{name}");
+        }
+    }
+
     #[test]
     fn parses_stop_intent() {
         let request = make_intent_request("AMAZON.StopIntent", "{}");
@@ -163,6 +1053,188 @@ mod tests {
         assert_eq!(parse(&request), ParsedIntent::Unknown);
     }
 
+    fn make_fallback_request(utterance: &str) -> AlexaRequest {
+        let json = serde_json::json!({
+            "version": "1.0",
+            "request": {
+                "type": "IntentRequest",
+                "requestId": "req-123",
+                "timestamp": "2024-01-27T10:00:00Z",
+                "locale": "de-DE",
+                "intent": {"name": "AMAZON.FallbackIntent", "slots": {}},
+                "input": {"text": utterance}
+            }
+        });
+        serde_json::from_value(json).unwrap()
+    }
+
+    #[test]
+    fn extracts_item_from_fallback_utterance() {
+        for utterance in [
+            "füge rote Bete hinzu",
+            "Setz rote Bete auf die Einkaufsliste.",
+            "ich bräuchte rote Bete",
+            "rote Bete auf die Liste",
+        ] {
+            assert_eq!(
+                parse(&make_fallback_request(utterance)),
+                ParsedIntent::AddItem {
+                    item_name: "rote Bete".to_string(),
+                    note: None,
+                    list: None,
+                },
+                "{utterance}"
+            );
+        }
+    }
+
+    #[test]
+    fn extracts_several_items_from_fallback_utterance() {
+        assert_eq!(
+            parse(&make_fallback_request(
+                "wir haben keine Milch und Eier mehr"
+            )),
+            ParsedIntent::AddItems {
+                item_names: vec!["Milch".to_string(), "Eier".to_string()],
+            }
+        );
+    }
+
+    #[test]
+    fn fallback_utterance_without_grocery_pattern_is_unknown() {
+        assert_eq!(
+            parse(&make_fallback_request("wie wird das Wetter morgen")),
+            ParsedIntent::Unknown
+        );
+    }
+
+    fn make_event_request(event_type: &str, body_json: &str) -> AlexaRequest {
+        let json = format!(
+            r#"{{
+                "version": "1.0",
+                "context": {{
+                    "System": {{"user": {{"userId": "user-123"}}}}
+                }},
+                "request": {{
+                    "type": "{event_type}",
+                    "requestId": "req-123",
+                    "timestamp": "2024-01-27T10:00:00Z",
+                    "body": {body_json}
+                }}
+            }}"#
+        );
+        serde_json::from_str(&json).unwrap()
+    }
+
+    #[test]
+    fn parses_permission_changed_event() {
+        let request = make_event_request(
+            "AlexaSkillEvent.SkillPermissionChanged",
+            r#"{"acceptedPermissions": [{"scope": "scope-a"}]}"#,
+        );
+        assert_eq!(
+            parse(&request),
+            ParsedIntent::PermissionsChanged {
+                user_id: "user-123".to_string(),
+                scopes: vec!["scope-a".to_string()],
+            }
+        );
+    }
+
+    #[test]
+    fn parses_subscription_changed_event() {
+        let request = make_event_request(
+            "AlexaSkillEvent.ProactiveSubscriptionChanged",
+            r#"{"subscriptions": [{"eventName": "AMAZON.MessageAlert.Activated"}]}"#,
+        );
+        assert_eq!(
+            parse(&request),
+            ParsedIntent::SubscriptionsChanged {
+                user_id: "user-123".to_string(),
+                events: vec!["AMAZON.MessageAlert.Activated".to_string()],
+            }
+        );
+    }
+
+    #[test]
+    fn parses_skill_lifecycle_events() {
+        let enabled = make_event_request("AlexaSkillEvent.SkillEnabled", "{}");
+        assert_eq!(
+            parse(&enabled),
+            ParsedIntent::SkillEnabled {
+                user_id: "user-123".to_string()
+            }
+        );
+
+        let linked = make_event_request(
+            "AlexaSkillEvent.SkillAccountLinked",
+            r#"{"accessToken": "token"}"#,
+        );
+        assert_eq!(
+            parse(&linked),
+            ParsedIntent::AccountLinked {
+                user_id: "user-123".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn parses_skill_disabled_persistence_status() {
+        for (status, keeps_user_data) in [("PERSISTED", true), ("NOT_PERSISTED", false)] {
+            let request = make_event_request(
+                "AlexaSkillEvent.SkillDisabled",
+                &format!(r#"{{"userInformationPersistenceStatus": "{status}"}}"#),
+            );
+            assert_eq!(
+                parse(&request),
+                ParsedIntent::SkillDisabled {
+                    user_id: "user-123".to_string(),
+                    keeps_user_data,
+                }
+            );
+        }
+    }
+
+    fn make_apl_user_event(arguments: &str) -> AlexaRequest {
+        let json = format!(
+            r#"{{
+                "version": "1.0",
+                "request": {{
+                    "type": "Alexa.Presentation.APL.UserEvent",
+                    "requestId": "req-123",
+                    "timestamp": "2024-01-27T10:00:00Z",
+                    "locale": "de-DE",
+                    "arguments": {arguments}
+                }}
+            }}"#
+        );
+        serde_json::from_str(&json).unwrap()
+    }
+
+    #[test]
+    fn parses_apl_check_off_event() {
+        let request = make_apl_user_event(r#"["checkOff", "id-1"]"#);
+        assert_eq!(
+            parse(&request),
+            ParsedIntent::CheckOffItem {
+                item_id: "id-1".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn ignores_unsupported_apl_events() {
+        for arguments in [
+            r#"["checkOff"]"#,
+            r#"["checkOff", ""]"#,
+            r#"["scroll", "id-1"]"#,
+            "[]",
+        ] {
+            let request = make_apl_user_event(arguments);
+            assert_eq!(parse(&request), ParsedIntent::Unknown, "{arguments}");
+        }
+    }
+
     #[test]
     fn parses_unknown_intent() {
         let request = make_intent_request("SomeRandomIntent", "{}");
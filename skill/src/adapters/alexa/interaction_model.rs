@@ -0,0 +1,679 @@
+use serde::Serialize;
+
+use super::intent_parser::{intent_names, slot_names};
+use super::speech::{MessageId, Speech};
+use crate::domain::services::DEFAULT_ALIASES;
+
+/// Spoken name that opens the skill.
+pub const INVOCATION_NAME: &str = "cookidoo einkaufsliste";
+
+/// Custom slot type for occasion list names.
+const LIST_NAME_TYPE: &str = "LIST_NAME";
+
+/// Built-in slot type for item names, extended with the item aliases.
+///
+/// Offering regional words such as "Semmel" or "Topfen" as synonyms helps
+/// their recognition.
+const FOOD_TYPE: &str = "AMAZON.Food";
+
+/// Custom slot type for item notes such as "fettarm".
+///
+/// Custom slot types also catch values that are not listed, so the values
+/// below only guide recognition of the usual notes.
+const NOTE_TYPE: &str = "ITEM_NOTE";
+
+/// Custom slot type for the answer length in settings.
+const SPEECH_STYLE_TYPE: &str = "SPEECH_STYLE";
+
+/// Custom slot type for the region whose conventions the skill follows.
+const REGION_TYPE: &str = "SKILL_REGION";
+
+/// Custom slot type for the history period.
+const PERIOD_TYPE: &str = "HISTORY_PERIOD";
+
+/// Custom slot type for positions on the list, e.g. "letzten".
+const POSITION_TYPE: &str = "LIST_POSITION";
+
+/// Custom slot type for the names of item bundles, e.g. "Frühstücks-Set".
+const BUNDLE_TYPE: &str = "ITEM_BUNDLE";
+
+/// Built-in slot type for the names of household accounts.
+const FIRST_NAME_TYPE: &str = "AMAZON.FirstName";
+
+/// Built-in slot type catching free-form speech; samples need a carrier
+/// phrase around it.
+const SEARCH_QUERY_TYPE: &str = "AMAZON.SearchQuery";
+
+/// Built-in slot type for counts, e.g. "drei" in "füge drei Zitronen hinzu".
+const NUMBER_TYPE: &str = "AMAZON.NUMBER";
+
+/// Built-in slot type for reminder delays (ISO 8601 durations).
+const DURATION_TYPE: &str = "AMAZON.DURATION";
+
+/// Occasion list names offered as LIST_NAME values.
+const LIST_NAMES: &[(&str, &[&str])] = &[
+    ("Geburtstagsliste", &["Geburtstagsgeschenke"]),
+    ("Geschenkeliste", &["Geschenkliste", "Wunschliste"]),
+    ("Weihnachtsliste", &["Weihnachtsgeschenke"]),
+    ("Party-Liste", &["Party"]),
+    ("Wochenend-Liste", &["Wochenend"]),
+];
+
+/// Item notes offered as ITEM_NOTE values.
+const NOTES: &[(&str, &[&str])] = &[
+    ("fettarm", &["fettarme", "mager"]),
+    ("laktosefrei", &["laktosefreie"]),
+    ("glutenfrei", &["glutenfreie"]),
+    ("bio", &["Bioqualität"]),
+    ("vegan", &["vegane"]),
+    ("Vollkorn", &[]),
+    ("groß", &["große", "eine große Packung"]),
+    ("klein", &["kleine", "eine kleine Packung"]),
+    ("ein Kilo", &["ein Kilogramm"]),
+    ("ein Liter", &[]),
+    ("zwei Packungen", &[]),
+];
+
+/// Speech styles offered as SPEECH_STYLE values, matching the words the
+/// parser maps to a style.
+const SPEECH_STYLES: &[(&str, &[&str])] = &[
+    ("kurz", &["kurze", "knapp", "knappe"]),
+    ("ausführlich", &["ausführliche", "lang", "lange"]),
+];
+
+/// Regions offered as SKILL_REGION values, matching the words the parser
+/// maps to a locale.
+const REGIONS: &[(&str, &[&str])] = &[
+    ("Deutschland", &["deutsch", "Hochdeutsch"]),
+    ("Österreich", &["österreichisch"]),
+    ("Schweiz", &["die Schweiz", "Schweizerdeutsch"]),
+];
+
+/// History periods offered as HISTORY_PERIOD values.
+const PERIODS: &[(&str, &[&str])] = &[
+    ("heute", &[]),
+    ("diese Woche", &["in dieser Woche", "seit Montag"]),
+];
+
+/// List positions offered as LIST_POSITION values, matching the words the
+/// parser maps to a position.
+const POSITIONS: &[(&str, &[&str])] = &[
+    ("letzten", &["letzte"]),
+    ("ersten", &["erste"]),
+    ("zweiten", &["zweite"]),
+    ("dritten", &["dritte"]),
+    ("vierten", &["vierte"]),
+    ("fünften", &["fünfte"]),
+    ("Nummer eins", &[]),
+    ("Nummer zwei", &[]),
+    ("Nummer drei", &[]),
+];
+
+/// Bundle names offered as ITEM_BUNDLE values; the configured bundles may
+/// use others.
+const BUNDLE_NAMES: &[(&str, &[&str])] = &[
+    ("Frühstücks-Set", &["Frühstücksset", "Frühstück"]),
+    ("Grill-Set", &["Grillset", "Grillsachen"]),
+    ("Kaffee-Set", &["Kaffeeset"]),
+    ("Wochenend-Set", &["Wochenendeinkauf"]),
+];
+
+/// ID of the prompt eliciting the item of an add.
+const ADD_ITEM_PROMPT_ID: &str = "Elicit.Slot.AddItemIntent.Item";
+
+/// Root of the interaction model JSON accepted by the ASK CLI and console.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InteractionModel {
+    pub interaction_model: Model,
+}
+
+/// Interaction model body.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Model {
+    pub language_model: LanguageModel,
+    pub dialog: Dialog,
+    pub prompts: Vec<Prompt>,
+}
+
+/// Language model of one locale.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LanguageModel {
+    pub invocation_name: String,
+    pub intents: Vec<IntentModel>,
+    pub types: Vec<SlotType>,
+}
+
+/// An intent with its slots and sample utterances.
+#[derive(Debug, Serialize)]
+pub struct IntentModel {
+    pub name: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub slots: Vec<SlotModel>,
+    pub samples: Vec<String>,
+}
+
+/// A slot of an intent.
+#[derive(Debug, Serialize)]
+pub struct SlotModel {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub slot_type: String,
+}
+
+/// Dialog model: required slots Alexa asks for when the user leaves them
+/// out. With the skill response strategy, each turn reaches the skill,
+/// which hands it back with a `Dialog.Delegate` directive.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Dialog {
+    pub intents: Vec<DialogIntent>,
+    pub delegation_strategy: String,
+}
+
+/// Dialog of one intent.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DialogIntent {
+    pub name: String,
+    pub confirmation_required: bool,
+    pub slots: Vec<DialogSlot>,
+}
+
+/// A slot Alexa elicits in a dialog.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DialogSlot {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub slot_type: String,
+    pub confirmation_required: bool,
+    pub elicitation_required: bool,
+    pub prompts: SlotPrompts,
+}
+
+/// Prompts of a dialog slot, by prompt ID.
+#[derive(Debug, Serialize)]
+pub struct SlotPrompts {
+    pub elicitation: String,
+}
+
+/// A prompt Alexa speaks in a dialog.
+#[derive(Debug, Serialize)]
+pub struct Prompt {
+    pub id: String,
+    pub variations: Vec<PromptVariation>,
+}
+
+/// One wording of a prompt.
+#[derive(Debug, Serialize)]
+pub struct PromptVariation {
+    #[serde(rename = "type")]
+    pub prompt_type: String,
+    pub value: String,
+}
+
+/// A custom or extended slot type.
+#[derive(Debug, Serialize)]
+pub struct SlotType {
+    pub name: String,
+    pub values: Vec<SlotTypeValue>,
+}
+
+/// A slot type value with its synonyms.
+#[derive(Debug, Serialize)]
+pub struct SlotTypeValue {
+    pub name: SlotValueName,
+}
+
+/// Canonical value and synonyms of a slot type value.
+#[derive(Debug, Serialize)]
+pub struct SlotValueName {
+    pub value: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub synonyms: Vec<String>,
+}
+
+impl InteractionModel {
+    /// Builds the de-DE interaction model from the intent and slot names the
+    /// parser understands, so the deployed model cannot drift from the code.
+    pub fn german() -> Self {
+        let item = || slot(slot_names::ITEM, FOOD_TYPE);
+        let list_name = || slot(slot_names::LIST_NAME, LIST_NAME_TYPE);
+        let note = || slot(slot_names::NOTE, NOTE_TYPE);
+
+        let intents = vec![
+            intent(
+                intent_names::ADD_ITEM,
+                vec![
+                    item(),
+                    list_name(),
+                    note(),
+                    slot(slot_names::QUANTITY, NUMBER_TYPE),
+                ],
+                &[
+                    "füge {Item} hinzu",
+                    "füge etwas hinzu",
+                    "ich möchte etwas hinzufügen",
+                    "füge {Item} zur Liste hinzu",
+                    "füge {Item} zur Einkaufsliste hinzu",
+                    "schreibe {Item} auf die Liste",
+                    "setz {Item} auf die Liste",
+                    "setze {Item} auf die Liste",
+                    "ich brauche {Item}",
+                    "wir brauchen {Item}",
+                    "notiere {Item}",
+                    "{Item} auf die Einkaufsliste",
+                    "setz {Item} auf die {ListName}",
+                    "setze {Item} auf die {ListName}",
+                    "füge {Item} zur {ListName} hinzu",
+                    "füge {Item} hinzu {Note}",
+                    "füge {Item} {Note} hinzu",
+                    "füge {Item} zur Einkaufsliste hinzu {Note}",
+                    "ich brauche {Item} {Note}",
+                    "notiere {Item} {Note}",
+                    "füge {Quantity} {Item} hinzu",
+                    "setze {Quantity} {Item} auf die Liste",
+                    "ich brauche {Quantity} {Item}",
+                    "wir brauchen {Quantity} {Item}",
+                ],
+            ),
+            intent(
+                intent_names::READ_LIST,
+                vec![list_name()],
+                &[
+                    "was steht auf der Liste",
+                    "was steht auf meiner Einkaufsliste",
+                    "lies die Liste vor",
+                    "lies meine Einkaufsliste vor",
+                    "was steht auf der {ListName}",
+                    "lies die {ListName} vor",
+                ],
+            ),
+            intent(
+                intent_names::READ_OPEN_ITEMS,
+                Vec::new(),
+                &[
+                    "was fehlt noch",
+                    "was muss ich noch kaufen",
+                    "was ist noch offen",
+                    "was steht noch offen",
+                    "lies die offenen Artikel vor",
+                ],
+            ),
+            intent(
+                intent_names::HOW_MANY_ITEMS,
+                Vec::new(),
+                &[
+                    "wie viele Artikel stehen auf der Liste",
+                    "wie viele Artikel stehen auf meiner Einkaufsliste",
+                    "wie viel steht auf der Liste",
+                    "wie lang ist die Liste",
+                    "wie lang ist meine Einkaufsliste",
+                ],
+            ),
+            intent(
+                intent_names::TRANSFER_LIST,
+                vec![list_name()],
+                &[
+                    "übertrage die {ListName} auf die Einkaufsliste",
+                    "übertrage die {ListName} in die Einkaufsliste",
+                    "schiebe die {ListName} auf die Einkaufsliste",
+                    "setze alles von der {ListName} auf die Einkaufsliste",
+                ],
+            ),
+            intent(
+                intent_names::ADD_BUNDLE,
+                vec![slot(slot_names::BUNDLE, BUNDLE_TYPE)],
+                &[
+                    "füge mein {Bundle} hinzu",
+                    "füge das {Bundle} hinzu",
+                    "füge mein {Bundle} zur Einkaufsliste hinzu",
+                    "setz mein {Bundle} auf die Liste",
+                    "setze mein {Bundle} auf die Einkaufsliste",
+                    "ich brauche mein {Bundle}",
+                ],
+            ),
+            intent(
+                intent_names::EXTEND_BUNDLE,
+                vec![slot(slot_names::BUNDLE, BUNDLE_TYPE), item()],
+                &[
+                    "füge {Item} zu meinem {Bundle} hinzu",
+                    "füge {Item} zum {Bundle} hinzu",
+                    "nimm {Item} in mein {Bundle} auf",
+                    "erstelle ein {Bundle} mit {Item}",
+                    "lege ein {Bundle} mit {Item} an",
+                ],
+            ),
+            intent(
+                intent_names::DELETE_BUNDLE,
+                vec![slot(slot_names::BUNDLE, BUNDLE_TYPE)],
+                &["lösche mein {Bundle}", "entferne mein {Bundle}"],
+            ),
+            intent(
+                intent_names::READ_BUNDLE,
+                vec![slot(slot_names::BUNDLE, BUNDLE_TYPE)],
+                &[
+                    "was ist in meinem {Bundle}",
+                    "was steht in meinem {Bundle}",
+                    "lies mein {Bundle} vor",
+                    "welche Sets habe ich",
+                ],
+            ),
+            intent(
+                intent_names::FOLLOW_UP_ITEM,
+                vec![item()],
+                &["{Item}", "noch {Item}", "und {Item}", "außerdem {Item}"],
+            ),
+            intent(
+                intent_names::FREE_FORM_ADD,
+                vec![slot(slot_names::QUERY, SEARCH_QUERY_TYPE)],
+                &[
+                    "ich brauch noch {Query}",
+                    "ich bräuchte noch {Query}",
+                    "wir brauchen noch {Query}",
+                    "wir haben keine {Query}",
+                    "uns fehlt {Query}",
+                    "besorg mal {Query}",
+                    "kauf noch {Query}",
+                    "merk dir {Query}",
+                ],
+            ),
+            intent(
+                intent_names::HISTORY,
+                vec![slot(slot_names::PERIOD, PERIOD_TYPE)],
+                &[
+                    "was habe ich {Period} hinzugefügt",
+                    "was habe ich {Period} auf die Liste gesetzt",
+                    "was wurde {Period} hinzugefügt",
+                    "was habe ich zuletzt hinzugefügt",
+                ],
+            ),
+            intent(
+                intent_names::REMOVE_ITEM,
+                vec![
+                    item(),
+                    list_name(),
+                    slot(slot_names::POSITION, POSITION_TYPE),
+                ],
+                &[
+                    "entferne {Item}",
+                    "lösche {Item}",
+                    "entferne {Item} von der Liste",
+                    "lösche {Item} von der Liste",
+                    "entferne {Item} von der Einkaufsliste",
+                    "entferne {Item} von der {ListName}",
+                    "lösche {Item} von der {ListName}",
+                    "entferne den {Position} Artikel",
+                    "lösche den {Position} Artikel",
+                    "entferne den {Position}",
+                    "lösche den {Position}",
+                    "entferne {Position}",
+                ],
+            ),
+            intent(
+                intent_names::REMIND,
+                vec![item(), slot(slot_names::DURATION, DURATION_TYPE)],
+                &[
+                    "erinnere mich {Item} zu kaufen",
+                    "erinnere mich an {Item}",
+                    "erinnere mich in {Duration} {Item} zu kaufen",
+                    "erinnere mich in {Duration} an {Item}",
+                ],
+            ),
+            intent(
+                intent_names::SETTINGS,
+                vec![
+                    slot(slot_names::SPEECH_STYLE, SPEECH_STYLE_TYPE),
+                    list_name(),
+                    slot(slot_names::REGION, REGION_TYPE),
+                ],
+                &[
+                    "stelle {SpeechStyle} Antworten ein",
+                    "antworte {SpeechStyle}",
+                    "antworte ab jetzt {SpeechStyle}",
+                    "stelle die Standardliste auf {ListName}",
+                    "stelle die Standardliste auf die {ListName}",
+                    "verwende standardmäßig die {ListName}",
+                    "stelle die Region auf {Region}",
+                    "ich wohne in {Region}",
+                ],
+            ),
+            intent(
+                intent_names::USE_ACCOUNT,
+                vec![slot(slot_names::ACCOUNT, FIRST_NAME_TYPE)],
+                &[
+                    "benutze {Account} Liste",
+                    "benutze die Liste von {Account}",
+                    "wechsle zu {Account} Liste",
+                    "wechsle zur Liste von {Account}",
+                    "benutze die gemeinsame Liste",
+                    "wechsle zur gemeinsamen Liste",
+                ],
+            ),
+            intent(
+                intent_names::DELETE_MY_DATA,
+                Vec::new(),
+                &[
+                    "lösche meine Daten",
+                    "lösche meine gespeicherten Daten",
+                    "lösche alle meine Daten",
+                    "vergiss alles über mich",
+                ],
+            ),
+            intent(intent_names::HELP, Vec::new(), &[]),
+            intent(intent_names::CANCEL, Vec::new(), &[]),
+            intent(intent_names::STOP, Vec::new(), &[]),
+            intent(intent_names::REPEAT, Vec::new(), &[]),
+            intent(intent_names::START_OVER, Vec::new(), &[]),
+            intent(intent_names::YES, Vec::new(), &[]),
+            intent(intent_names::NO, Vec::new(), &[]),
+            intent(intent_names::PAUSE, Vec::new(), &[]),
+            intent(intent_names::FALLBACK, Vec::new(), &[]),
+            intent(intent_names::NAVIGATE_HOME, Vec::new(), &[]),
+        ];
+
+        Self {
+            interaction_model: Model {
+                language_model: LanguageModel {
+                    invocation_name: INVOCATION_NAME.to_string(),
+                    intents,
+                    types: vec![
+                        slot_type(FOOD_TYPE, DEFAULT_ALIASES),
+                        slot_type(LIST_NAME_TYPE, LIST_NAMES),
+                        slot_type(NOTE_TYPE, NOTES),
+                        slot_type(SPEECH_STYLE_TYPE, SPEECH_STYLES),
+                        slot_type(REGION_TYPE, REGIONS),
+                        slot_type(PERIOD_TYPE, PERIODS),
+                        slot_type(POSITION_TYPE, POSITIONS),
+                        slot_type(BUNDLE_TYPE, BUNDLE_NAMES),
+                    ],
+                },
+                dialog: Dialog {
+                    intents: vec![DialogIntent {
+                        name: intent_names::ADD_ITEM.to_string(),
+                        confirmation_required: false,
+                        slots: vec![DialogSlot {
+                            name: slot_names::ITEM.to_string(),
+                            slot_type: FOOD_TYPE.to_string(),
+                            confirmation_required: false,
+                            elicitation_required: true,
+                            prompts: SlotPrompts {
+                                elicitation: ADD_ITEM_PROMPT_ID.to_string(),
+                            },
+                        }],
+                    }],
+                    delegation_strategy: "SKILL_RESPONSE".to_string(),
+                },
+                prompts: vec![Prompt {
+                    id: ADD_ITEM_PROMPT_ID.to_string(),
+                    variations: vec![PromptVariation {
+                        prompt_type: "PlainText".to_string(),
+                        value: Speech::new(MessageId::AddWhat).render_default(),
+                    }],
+                }],
+            },
+        }
+    }
+}
+
+fn intent(name: &str, slots: Vec<SlotModel>, samples: &[&str]) -> IntentModel {
+    IntentModel {
+        name: name.to_string(),
+        slots,
+        samples: samples.iter().map(|sample| sample.to_string()).collect(),
+    }
+}
+
+fn slot(name: &str, slot_type: &str) -> SlotModel {
+    SlotModel {
+        name: name.to_string(),
+        slot_type: slot_type.to_string(),
+    }
+}
+
+fn slot_type(name: &str, values: &[(&str, &[&str])]) -> SlotType {
+    SlotType {
+        name: name.to_string(),
+        values: values
+            .iter()
+            .map(|(value, synonyms)| SlotTypeValue {
+                name: SlotValueName {
+                    value: value.to_string(),
+                    synonyms: synonyms.iter().map(|s| s.to_string()).collect(),
+                },
+            })
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn language_model() -> LanguageModel {
+        InteractionModel::german().interaction_model.language_model
+    }
+
+    #[test]
+    fn contains_every_parsed_intent() {
+        let model = language_model();
+        for name in [
+            intent_names::ADD_ITEM,
+            intent_names::FOLLOW_UP_ITEM,
+            intent_names::FREE_FORM_ADD,
+            intent_names::ADD_BUNDLE,
+            intent_names::EXTEND_BUNDLE,
+            intent_names::DELETE_BUNDLE,
+            intent_names::READ_BUNDLE,
+            intent_names::READ_LIST,
+            intent_names::READ_OPEN_ITEMS,
+            intent_names::HOW_MANY_ITEMS,
+            intent_names::TRANSFER_LIST,
+            intent_names::HISTORY,
+            intent_names::REMOVE_ITEM,
+            intent_names::REMIND,
+            intent_names::SETTINGS,
+            intent_names::USE_ACCOUNT,
+            intent_names::DELETE_MY_DATA,
+            intent_names::HELP,
+            intent_names::CANCEL,
+            intent_names::STOP,
+            intent_names::REPEAT,
+            intent_names::START_OVER,
+            intent_names::YES,
+            intent_names::NO,
+            intent_names::PAUSE,
+            intent_names::FALLBACK,
+            intent_names::NAVIGATE_HOME,
+        ] {
+            assert!(
+                model.intents.iter().any(|intent| intent.name == name),
+                "{name} missing from model"
+            );
+        }
+    }
+
+    #[test]
+    fn samples_only_reference_declared_slots() {
+        for intent in language_model().intents {
+            for sample in &intent.samples {
+                for placeholder in sample.split('{').skip(1) {
+                    let name = placeholder.split('}').next().unwrap();
+                    assert!(
+                        intent.slots.iter().any(|slot| slot.name == name),
+                        "{} sample '{}' uses undeclared slot {}",
+                        intent.name,
+                        sample,
+                        name
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn slot_types_are_declared_or_built_in() {
+        let model = language_model();
+        for intent in &model.intents {
+            for slot in &intent.slots {
+                assert!(
+                    slot.slot_type.starts_with("AMAZON.")
+                        || model.types.iter().any(|t| t.name == slot.slot_type),
+                    "slot type {} is not declared",
+                    slot.slot_type
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn samples_are_unique_across_intents() {
+        let model = language_model();
+        let mut samples: Vec<String> = model
+            .intents
+            .iter()
+            .flat_map(|intent| intent.samples.iter().map(|s| s.to_lowercase()))
+            .collect();
+        let total = samples.len();
+        samples.sort();
+        samples.dedup();
+        assert_eq!(samples.len(), total);
+    }
+
+    #[test]
+    fn serializes_in_ask_format() {
+        let json = serde_json::to_value(InteractionModel::german()).unwrap();
+        let language_model = &json["interactionModel"]["languageModel"];
+
+        assert_eq!(language_model["invocationName"], INVOCATION_NAME);
+        assert_eq!(language_model["intents"][0]["name"], "AddItemIntent");
+        assert_eq!(
+            language_model["intents"][0]["slots"][0]["type"],
+            "AMAZON.Food"
+        );
+        assert!(language_model["intents"][2].get("slots").is_none());
+        assert_eq!(
+            language_model["types"][0]["values"][0]["name"]["synonyms"][0],
+            "Semmel"
+        );
+    }
+
+    #[test]
+    fn elicits_missing_item_of_an_add() {
+        let json = serde_json::to_value(InteractionModel::german()).unwrap();
+        let model = &json["interactionModel"];
+        let slot = &model["dialog"]["intents"][0]["slots"][0];
+
+        assert_eq!(model["dialog"]["intents"][0]["name"], "AddItemIntent");
+        assert_eq!(slot["name"], "Item");
+        assert_eq!(slot["elicitationRequired"], true);
+        assert_eq!(model["prompts"][0]["id"], slot["prompts"]["elicitation"]);
+        assert_eq!(
+            model["prompts"][0]["variations"][0]["value"],
+            "Was möchtest du hinzufügen?"
+        );
+    }
+}
@@ -0,0 +1,485 @@
+use crate::domain::models::{
+    HistoryEntry, HistoryPeriod, ListName, ListSummary, ShoppingListEntry,
+};
+
+use super::models::AlexaResponse;
+use super::phrases::PhraseSelector;
+use super::response_builder::ResponseBuilder;
+use super::session_state::{PendingAction, SessionState};
+use super::speech::{MessageId, Speech};
+
+/// Entries read in one response; longer lists are read in parts, each
+/// ending with the offer to go on.
+const READ_CHUNK: usize = 10;
+
+/// Turns shopping list snapshots into spoken responses.
+///
+/// All paths that read the list go through the presenter, so an empty list
+/// is answered the same way everywhere: with a hint how to add something
+/// and the session kept open for the answer.
+pub struct ListPresenter;
+
+impl ListPresenter {
+    /// Reads the entries aloud, or the empty-list hint if there are none.
+    ///
+    /// A long list is read in parts: the first part ends with "Soll ich
+    /// weiterlesen?", and the position to go on from is kept in the
+    /// session (see [`Self::readout_from`]).
+    pub fn readout(entries: &[ShoppingListEntry], phrases: &PhraseSelector) -> AlexaResponse {
+        if entries.is_empty() {
+            return Self::empty(phrases);
+        }
+        if entries.len() <= READ_CHUNK {
+            let label = Speech::new(MessageId::ShoppingListLabel).render(phrases);
+            return Self::enumerate(&label, entries);
+        }
+
+        let names = Self::names(&entries[..READ_CHUNK]);
+        let text = Speech::new(MessageId::ListOverview)
+            .counting(entries.len())
+            .with("items", join_names(&names))
+            .render(phrases);
+        Self::continue_after(text, READ_CHUNK, false, phrases)
+    }
+
+    /// Reads the entries not yet checked off ("was fehlt noch?"), in parts
+    /// like [`Self::readout`].
+    ///
+    /// `entries` are the open entries only; none left means everything was
+    /// bought, which ends the session.
+    pub fn readout_open(entries: &[ShoppingListEntry], phrases: &PhraseSelector) -> AlexaResponse {
+        let names = Self::names(&entries[..entries.len().min(READ_CHUNK)]);
+        let text = match names.len() {
+            0 => {
+                return ResponseBuilder::success(
+                    Speech::new(MessageId::NothingOpen).render(phrases),
+                )
+            }
+            _ if entries.len() <= READ_CHUNK => Speech::new(MessageId::OpenEntries)
+                .counting(names.len())
+                .with("items", join_names(&names)),
+            _ => Speech::new(MessageId::OpenOverview)
+                .counting(entries.len())
+                .with("items", join_names(&names)),
+        }
+        .render(phrases);
+        if entries.len() <= READ_CHUNK {
+            return ResponseBuilder::success(text);
+        }
+        Self::continue_after(text, READ_CHUNK, true, phrases)
+    }
+
+    /// Goes on reading a long list from the entry at `offset`.
+    ///
+    /// The list is read again for this, so entries added or removed in the
+    /// meantime may shift the position; an offset past the end of the list
+    /// says that everything was read. `open_only` carries over that only
+    /// open entries are read (see [`Self::readout_open`]).
+    pub fn readout_from(
+        entries: &[ShoppingListEntry],
+        offset: usize,
+        open_only: bool,
+        phrases: &PhraseSelector,
+    ) -> AlexaResponse {
+        let Some(rest) = entries.get(offset..).filter(|rest| !rest.is_empty()) else {
+            return ResponseBuilder::success(Speech::new(MessageId::NothingLeft).render(phrases));
+        };
+
+        let end = rest.len().min(READ_CHUNK);
+        let text = Speech::new(MessageId::ReadOnward)
+            .with("items", join_names(&Self::names(&rest[..end])))
+            .render(phrases);
+        if end == rest.len() {
+            return ResponseBuilder::success(text);
+        }
+        Self::continue_after(text, offset + end, open_only, phrases)
+    }
+
+    /// Reads an occasion list aloud, or its empty-list hint.
+    pub fn readout_named(
+        list: &ListName,
+        entries: &[ShoppingListEntry],
+        phrases: &PhraseSelector,
+    ) -> AlexaResponse {
+        if entries.is_empty() {
+            return Self::empty_named(list, phrases);
+        }
+        Self::enumerate(list.label(), entries)
+    }
+
+    /// Speaks how many entries are on the list, how many are checked off
+    /// and which categories most open entries belong to.
+    pub fn summary(summary: &ListSummary, phrases: &PhraseSelector) -> AlexaResponse {
+        match summary_speech(summary, phrases) {
+            Some(text) => ResponseBuilder::success(text),
+            None => Self::empty(phrases),
+        }
+    }
+
+    /// Reads the items added in a period, each name once.
+    pub fn history(period: HistoryPeriod, entries: &[HistoryEntry]) -> AlexaResponse {
+        let mut names: Vec<&str> = Vec::new();
+        for entry in entries {
+            let name = entry.item_name();
+            if !names
+                .iter()
+                .any(|known| known.to_lowercase() == name.to_lowercase())
+            {
+                names.push(name);
+            }
+        }
+
+        let speech = if names.is_empty() {
+            Speech::new(MessageId::HistoryEmpty)
+        } else {
+            Speech::new(MessageId::History).with("items", join_names(&names))
+        };
+        ResponseBuilder::success(speech.with("period", period.label()).render_default())
+    }
+
+    /// Responds to an empty list with a suggestion to add something.
+    ///
+    /// The offer is recorded as a pending action, so Cancel can decline it.
+    pub fn empty(phrases: &PhraseSelector) -> AlexaResponse {
+        ResponseBuilder::question(
+            Speech::new(MessageId::ListEmpty).render(phrases),
+            Speech::new(MessageId::ListEmptyReprompt).render(phrases),
+        )
+        .with_session_attributes(SessionState::pending(PendingAction::AddItem).to_attributes())
+    }
+
+    /// Responds to an empty occasion list with a suggestion to add something.
+    pub fn empty_named(list: &ListName, phrases: &PhraseSelector) -> AlexaResponse {
+        ResponseBuilder::question(
+            Speech::new(MessageId::NamedListEmpty)
+                .with("list", list.label())
+                .render(phrases),
+            Speech::new(MessageId::ListEmptyReprompt).render(phrases),
+        )
+        .with_session_attributes(SessionState::pending(PendingAction::AddItem).to_attributes())
+    }
+
+    /// Asks whether to go on reading, remembering where to go on from.
+    fn continue_after(
+        text: String,
+        offset: usize,
+        open_only: bool,
+        phrases: &PhraseSelector,
+    ) -> AlexaResponse {
+        ResponseBuilder::question(
+            Speech::new(MessageId::ContinueReading)
+                .with("text", text)
+                .render(phrases),
+            Speech::new(MessageId::ContinueReadingReprompt).render(phrases),
+        )
+        .with_session_attributes(
+            SessionState::pending(PendingAction::ContinueReading { offset, open_only })
+                .to_attributes(),
+        )
+    }
+
+    fn names(entries: &[ShoppingListEntry]) -> Vec<&str> {
+        entries.iter().map(ShoppingListEntry::name).collect()
+    }
+
+    fn enumerate(label: &str, entries: &[ShoppingListEntry]) -> AlexaResponse {
+        ResponseBuilder::success(entries_speech(label, entries))
+    }
+}
+
+/// Returns how many items are on the list and which categories are still
+/// open, or `None` for an empty list.
+pub(crate) fn summary_speech(summary: &ListSummary, phrases: &PhraseSelector) -> Option<String> {
+    let total = summary.total();
+    if total == 0 {
+        return None;
+    }
+
+    let mut text = Speech::new(MessageId::ItemCount)
+        .counting(total)
+        .render(phrases);
+    match summary.checked_off() {
+        0 => {}
+        n if n == total => text.push_str(
+            &Speech::new(MessageId::AllCheckedOff)
+                .counting(n)
+                .render(phrases),
+        ),
+        n => text.push_str(
+            &Speech::new(MessageId::CheckedOffCount)
+                .counting(n)
+                .render(phrases),
+        ),
+    }
+    text.push('.');
+
+    let top: Vec<&str> = summary
+        .open_categories()
+        .iter()
+        .take(2)
+        .map(|(category, _)| category.label())
+        .collect();
+    if !top.is_empty() {
+        text.push_str(
+            &Speech::new(MessageId::OpenCategories)
+                .with("categories", join_names(&top))
+                .render(phrases),
+        );
+    }
+
+    Some(text)
+}
+
+/// Returns the speech enumerating the entries of the list `label`.
+pub(crate) fn entries_speech(label: &str, entries: &[ShoppingListEntry]) -> String {
+    let names: Vec<&str> = entries.iter().map(ShoppingListEntry::name).collect();
+    Speech::new(MessageId::ListEntries)
+        .counting(names.len())
+        .with("list", label)
+        .with("items", join_names(&names))
+        .render_default()
+}
+
+/// Joins names as a spoken German enumeration ("A, B und C").
+pub(crate) fn join_names(names: &[&str]) -> String {
+    match names {
+        [] => String::new(),
+        [only] => only.to_string(),
+        [init @ .., last] => format!("{} und {}", init.join(", "), last),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::Category;
+
+    fn phrases() -> PhraseSelector {
+        PhraseSelector::for_session(Some("session-123"))
+    }
+
+    #[test]
+    fn empty_list_suggests_adding_and_keeps_session_open() {
+        let response = ListPresenter::readout(&[], &phrases());
+        assert!(!response.response.should_end_session);
+        assert!(response.response.output_speech.text.contains("leer"));
+        assert!(response.response.reprompt.is_some());
+        assert_eq!(
+            response.session_attributes,
+            SessionState::pending(PendingAction::AddItem).to_attributes()
+        );
+    }
+
+    #[test]
+    fn reads_single_entry() {
+        let entries = vec![ShoppingListEntry::new("1", "Milch", false)];
+        let response = ListPresenter::readout(&entries, &phrases());
+        assert_eq!(
+            response.response.output_speech.text,
+            "Auf deiner Einkaufsliste steht: Milch."
+        );
+        assert!(response.response.should_end_session);
+    }
+
+    #[test]
+    fn reads_multiple_entries_as_enumeration() {
+        let entries = vec![
+            ShoppingListEntry::new("1", "Milch", false),
+            ShoppingListEntry::new("2", "Eier", false),
+            ShoppingListEntry::new("3", "Butter", true),
+        ];
+        let response = ListPresenter::readout(&entries, &phrases());
+        assert_eq!(
+            response.response.output_speech.text,
+            "Auf deiner Einkaufsliste stehen: Milch, Eier und Butter."
+        );
+    }
+
+    fn long_list(len: usize) -> Vec<ShoppingListEntry> {
+        (1..=len)
+            .map(|n| ShoppingListEntry::new(n.to_string(), format!("Artikel {}", n), false))
+            .collect()
+    }
+
+    #[test]
+    fn reads_long_list_in_parts() {
+        let response = ListPresenter::readout(&long_list(23), &phrases());
+
+        let text = &response.response.output_speech.text;
+        assert!(text.starts_with("Auf deiner Einkaufsliste stehen 23 Artikel, zuerst: Artikel 1,"));
+        assert!(text.contains("Artikel 10."));
+        assert!(!text.contains("Artikel 11"));
+        assert!(text.ends_with("Soll ich weiterlesen?"));
+        assert!(!response.response.should_end_session);
+        assert_eq!(
+            SessionState::from_attributes(&response.session_attributes).pending_action(),
+            Some(PendingAction::ContinueReading {
+                offset: 10,
+                open_only: false
+            })
+        );
+    }
+
+    #[test]
+    fn continues_reading_from_offset() {
+        let entries = long_list(23);
+
+        let middle = ListPresenter::readout_from(&entries, 10, false, &PhraseSelector::default());
+        assert!(middle
+            .response
+            .output_speech
+            .text
+            .starts_with("Weiter geht's: Artikel 11,"));
+        assert_eq!(
+            SessionState::from_attributes(&middle.session_attributes).pending_action(),
+            Some(PendingAction::ContinueReading {
+                offset: 20,
+                open_only: false
+            })
+        );
+
+        let last = ListPresenter::readout_from(&entries, 20, false, &PhraseSelector::default());
+        assert_eq!(
+            last.response.output_speech.text,
+            "Weiter geht's: Artikel 21, Artikel 22 und Artikel 23."
+        );
+        assert!(last.response.should_end_session);
+    }
+
+    #[test]
+    fn continuing_past_the_end_says_everything_was_read() {
+        let response =
+            ListPresenter::readout_from(&long_list(3), 10, false, &PhraseSelector::default());
+
+        assert_eq!(
+            response.response.output_speech.text,
+            "Das waren alle Artikel auf deiner Einkaufsliste."
+        );
+    }
+
+    #[test]
+    fn reads_open_entries() {
+        let single = ListPresenter::readout_open(&long_list(1), &PhraseSelector::default());
+        let several = ListPresenter::readout_open(&long_list(3), &PhraseSelector::default());
+        let none = ListPresenter::readout_open(&[], &PhraseSelector::default());
+
+        assert_eq!(
+            single.response.output_speech.text,
+            "Es fehlt noch: Artikel 1."
+        );
+        assert_eq!(
+            several.response.output_speech.text,
+            "Es fehlen noch: Artikel 1, Artikel 2 und Artikel 3."
+        );
+        assert_eq!(
+            none.response.output_speech.text,
+            Speech::new(MessageId::NothingOpen).render_default()
+        );
+        assert!(none.response.should_end_session);
+    }
+
+    #[test]
+    fn reads_many_open_entries_in_parts() {
+        let response = ListPresenter::readout_open(&long_list(12), &PhraseSelector::default());
+
+        assert!(response
+            .response
+            .output_speech
+            .text
+            .starts_with("Es fehlen noch 12 Artikel, zuerst: Artikel 1,"));
+        assert_eq!(
+            SessionState::from_attributes(&response.session_attributes).pending_action(),
+            Some(PendingAction::ContinueReading {
+                offset: 10,
+                open_only: true
+            })
+        );
+    }
+
+    #[test]
+    fn reads_occasion_list_with_its_label() {
+        let list = ListName::new("Geburtstagsliste").unwrap();
+        let entries = vec![ShoppingListEntry::new("0", "Kerzen", false)];
+        let response = ListPresenter::readout_named(&list, &entries, &phrases());
+        assert_eq!(
+            response.response.output_speech.text,
+            "Auf deiner Geburtstagsliste steht: Kerzen."
+        );
+    }
+
+    #[test]
+    fn empty_occasion_list_suggests_adding_to_it() {
+        let list = ListName::new("Geburtstagsliste").unwrap();
+        let response = ListPresenter::readout_named(&list, &[], &phrases());
+        assert!(response
+            .response
+            .output_speech
+            .text
+            .starts_with("Deine Geburtstagsliste ist leer."));
+        assert!(!response.response.should_end_session);
+    }
+
+    #[test]
+    fn summarizes_counts_and_categories() {
+        let summary = ListSummary::new(
+            7,
+            3,
+            vec![(Category::Dairy, 2), (Category::FruitAndVegetables, 1)],
+        );
+        let response = ListPresenter::summary(&summary, &phrases());
+        assert_eq!(
+            response.response.output_speech.text,
+            "Du hast 7 Artikel auf der Liste, davon 3 bereits abgehakt. \
+             Offen sind vor allem Milchprodukte und Obst & Gemüse."
+        );
+        assert!(response.response.should_end_session);
+    }
+
+    #[test]
+    fn summarizes_single_checked_off_entry() {
+        let summary = ListSummary::new(1, 1, Vec::new());
+        let response = ListPresenter::summary(&summary, &phrases());
+        assert_eq!(
+            response.response.output_speech.text,
+            "Du hast einen Artikel auf der Liste, und er ist bereits abgehakt."
+        );
+    }
+
+    #[test]
+    fn empty_summary_suggests_adding() {
+        let response = ListPresenter::summary(&ListSummary::default(), &phrases());
+        assert!(response.response.output_speech.text.contains("leer"));
+        assert!(!response.response.should_end_session);
+    }
+
+    #[test]
+    fn reads_history_once_per_item() {
+        let entries = vec![
+            HistoryEntry::new("Milch", "2024-01-27T08:00:00Z"),
+            HistoryEntry::new("Eier", "2024-01-27T09:00:00Z"),
+            HistoryEntry::new("milch", "2024-01-27T09:30:00Z"),
+        ];
+        let response = ListPresenter::history(HistoryPeriod::Today, &entries);
+        assert_eq!(
+            response.response.output_speech.text,
+            "Heute hast du Milch und Eier hinzugefügt."
+        );
+    }
+
+    #[test]
+    fn reads_empty_history() {
+        let response = ListPresenter::history(HistoryPeriod::ThisWeek, &[]);
+        assert_eq!(
+            response.response.output_speech.text,
+            "Diese Woche hast du noch nichts hinzugefügt."
+        );
+    }
+
+    #[test]
+    fn joins_names() {
+        assert_eq!(join_names(&[]), "");
+        assert_eq!(join_names(&["A"]), "A");
+        assert_eq!(join_names(&["A", "B"]), "A und B");
+        assert_eq!(join_names(&["A", "B", "C"]), "A, B und C");
+    }
+}
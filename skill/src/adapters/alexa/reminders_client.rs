@@ -0,0 +1,177 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use reqwest::{Client, StatusCode};
+use serde::Serialize;
+use tracing::{debug, error};
+
+use crate::adapters::cookidoo::LazyClient;
+use crate::domain::models::{DomainError, Reminder};
+use crate::domain::ports::ReminderScheduler;
+
+/// Reminders API path, relative to the request's API endpoint.
+const REMINDERS_PATH: &str = "/v1/alerts/reminders";
+
+/// Timeout for reminder requests, kept short so the skill still answers
+/// before Alexa's response deadline.
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Permission scope needed to create reminders.
+pub const REMINDERS_SCOPE: &str = "alexa::alerts:reminders:skill:readwrite";
+
+/// Client for the Alexa Reminders API implementing the ReminderScheduler port.
+pub struct AlexaRemindersClient {
+    client: LazyClient,
+}
+
+impl AlexaRemindersClient {
+    /// Creates a new AlexaRemindersClient.
+    pub fn new() -> Self {
+        let client = LazyClient::new(|| {
+            Client::builder()
+                .timeout(DEFAULT_TIMEOUT)
+                .user_agent("AlexaCookidooSkill/1.0")
+                .build()
+        });
+
+        Self { client }
+    }
+}
+
+impl Default for AlexaRemindersClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl ReminderScheduler for AlexaRemindersClient {
+    async fn schedule(
+        &self,
+        api_endpoint: &str,
+        access_token: &str,
+        reminder: &Reminder,
+    ) -> Result<(), DomainError> {
+        let url = format!("{}{}", api_endpoint.trim_end_matches('/'), REMINDERS_PATH);
+        debug!(url = %url, "Creating reminder");
+
+        let client = self
+            .client
+            .get()
+            .map_err(|e| DomainError::RepositoryError(format!("HTTP client unavailable: {}", e)))?;
+        let response = client
+            .post(&url)
+            .bearer_auth(access_token)
+            .json(&ReminderRequest::from(reminder))
+            .send()
+            .await
+            .map_err(|e| DomainError::RepositoryError(format!("Reminder request failed: {}", e)))?;
+
+        let status = response.status();
+        match status {
+            s if s.is_success() => Ok(()),
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
+                Err(DomainError::PermissionRequired(REMINDERS_SCOPE.to_string()))
+            }
+            _ => {
+                let body = response.text().await.unwrap_or_default();
+                error!(status = %status, body = %body, "Reminders API returned an error");
+                Err(DomainError::RepositoryError(format!(
+                    "Reminders API returned {}",
+                    status
+                )))
+            }
+        }
+    }
+}
+
+/// Request body of the Reminders API.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ReminderRequest<'a> {
+    request_time: &'a str,
+    trigger: Trigger,
+    alert_info: AlertInfo<'a>,
+    push_notification: PushNotification,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Trigger {
+    #[serde(rename = "type")]
+    trigger_type: &'static str,
+    offset_in_seconds: u64,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AlertInfo<'a> {
+    spoken_info: SpokenInfo<'a>,
+}
+
+#[derive(Debug, Serialize)]
+struct SpokenInfo<'a> {
+    content: Vec<SpokenContent<'a>>,
+}
+
+#[derive(Debug, Serialize)]
+struct SpokenContent<'a> {
+    locale: &'a str,
+    text: &'a str,
+}
+
+#[derive(Debug, Serialize)]
+struct PushNotification {
+    status: &'static str,
+}
+
+impl<'a> From<&'a Reminder> for ReminderRequest<'a> {
+    fn from(reminder: &'a Reminder) -> Self {
+        Self {
+            request_time: reminder.request_time(),
+            trigger: Trigger {
+                trigger_type: "SCHEDULED_RELATIVE",
+                offset_in_seconds: reminder.offset().as_secs(),
+            },
+            alert_info: AlertInfo {
+                spoken_info: SpokenInfo {
+                    content: vec![SpokenContent {
+                        locale: reminder.locale(),
+                        text: reminder.text(),
+                    }],
+                },
+            },
+            push_notification: PushNotification { status: "ENABLED" },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serializes_relative_reminder() {
+        let reminder = Reminder::new(
+            "Milch kaufen",
+            Duration::from_secs(7200),
+            "de-DE",
+            "2024-01-27T10:00:00Z",
+        );
+
+        let json = serde_json::to_value(ReminderRequest::from(&reminder)).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "requestTime": "2024-01-27T10:00:00Z",
+                "trigger": {"type": "SCHEDULED_RELATIVE", "offsetInSeconds": 7200},
+                "alertInfo": {
+                    "spokenInfo": {
+                        "content": [{"locale": "de-DE", "text": "Milch kaufen"}]
+                    }
+                },
+                "pushNotification": {"status": "ENABLED"}
+            })
+        );
+    }
+}
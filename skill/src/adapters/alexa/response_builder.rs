@@ -1,19 +1,21 @@
-use super::models::{AlexaResponse, OutputSpeech, ResponseBody};
+use std::collections::HashMap;
 
-/// German response messages.
-mod messages {
-    pub const WELCOME: &str = "Willkommen bei der Cookidoo Einkaufsliste. \
-        Du kannst Artikel hinzufügen, indem du zum Beispiel sagst: \
-        Füge Milch hinzu.";
+use crate::adapters::replies::{add_item_speech, add_items_speech, failure_speech};
+use crate::domain::models::{AddItemOutcome, DomainError, ErrorCode, SpeechStyle};
 
-    pub const HELP: &str = "Du kannst Artikel zu deiner Cookidoo Einkaufsliste hinzufügen. \
-        Sage zum Beispiel: Füge Milch hinzu, oder: Ich brauche Eier. \
-        Was möchtest du hinzufügen?";
+use super::list_presenter::join_names;
+use super::models::{
+    interfaces, AlexaRequest, AlexaResponse, Card, Directive, OnCompletion, OutputSpeech, Reprompt,
+    ResponseBody, UpdatedIntent,
+};
+use super::phrases::PhraseSelector;
+use super::speech::{MessageId, Speech};
 
-    pub const GOODBYE: &str = "Auf Wiedersehen!";
-
-    pub const UNKNOWN: &str = "Das habe ich leider nicht verstanden. \
-        Bitte sage zum Beispiel: Füge Milch hinzu.";
+fn welcome_message(style: SpeechStyle) -> MessageId {
+    match style {
+        SpeechStyle::Full => MessageId::Welcome,
+        SpeechStyle::Brief => MessageId::WelcomeBrief,
+    }
 }
 
 /// Builder for Alexa responses.
@@ -25,36 +27,348 @@ impl ResponseBuilder {
         Self::build(message, true)
     }
 
+    /// Creates a success response prefixed with a varied acknowledgement,
+    /// ending the session.
+    pub fn confirmation(message: impl Into<String>, phrases: &PhraseSelector) -> AlexaResponse {
+        let text = format!(
+            "{}{}",
+            Speech::new(MessageId::Acknowledgement).render(phrases),
+            message.into()
+        );
+        Self::build(text, true)
+    }
+
+    /// Speaks the outcome of adding an item, ending the session.
+    ///
+    /// Successful adds are acknowledged like [`Self::confirmation`], or
+    /// just named in the brief style.
+    pub fn add_item(
+        outcome: &AddItemOutcome,
+        phrases: &PhraseSelector,
+        style: SpeechStyle,
+    ) -> AlexaResponse {
+        match (style, outcome) {
+            (
+                SpeechStyle::Brief,
+                AddItemOutcome::Added { item } | AddItemOutcome::Duplicate { item },
+            ) => Self::success(
+                Speech::new(MessageId::BriefConfirmation)
+                    .with("item", item.as_str())
+                    .render(phrases),
+            ),
+            _ if outcome.is_success() => Self::confirmation(add_item_speech(outcome), phrases),
+            _ => {
+                let response = Self::error(add_item_speech(outcome));
+                match outcome.error_code() {
+                    Some(code) => Self::with_error_card(response, code),
+                    None => response,
+                }
+            }
+        }
+    }
+
+    /// Speaks the outcomes of adding several items, ending the session.
+    ///
+    /// The added items are confirmed together, briefly in
+    /// [`SpeechStyle::Brief`]; if an add failed, its guidance follows and
+    /// the Alexa app shows its error code.
+    pub fn add_items(
+        outcomes: &[AddItemOutcome],
+        phrases: &PhraseSelector,
+        style: SpeechStyle,
+    ) -> AlexaResponse {
+        match outcomes.iter().find(|outcome| !outcome.is_success()) {
+            None if style == SpeechStyle::Brief => {
+                Self::success(Self::brief_items_speech(outcomes, phrases))
+            }
+            None => Self::confirmation(add_items_speech(outcomes, phrases), phrases),
+            Some(failure) if !outcomes.iter().any(AddItemOutcome::is_success) => {
+                Self::add_item(failure, phrases, style)
+            }
+            Some(failure) => {
+                let response = Self::error(add_items_speech(outcomes, phrases));
+                match failure.error_code() {
+                    Some(code) => Self::with_error_card(response, code),
+                    None => response,
+                }
+            }
+        }
+    }
+
+    /// Confirms several added items and asks "Noch etwas?", keeping the
+    /// session open; if an add failed, the outcomes are spoken like
+    /// [`Self::add_items`].
+    pub fn add_more_items(
+        outcomes: &[AddItemOutcome],
+        phrases: &PhraseSelector,
+        style: SpeechStyle,
+    ) -> AlexaResponse {
+        if !outcomes.iter().all(AddItemOutcome::is_success) {
+            return Self::add_items(outcomes, phrases, style);
+        }
+        match style {
+            SpeechStyle::Brief => Self::ask_for_more(Self::brief_items_speech(outcomes, phrases)),
+            SpeechStyle::Full => Self::ask_for_more(format!(
+                "{}{}",
+                Speech::new(MessageId::Acknowledgement).render(phrases),
+                add_items_speech(outcomes, phrases)
+            )),
+        }
+    }
+
+    fn brief_items_speech(outcomes: &[AddItemOutcome], phrases: &PhraseSelector) -> String {
+        let added: Vec<&str> = outcomes.iter().filter_map(AddItemOutcome::item).collect();
+        Speech::new(MessageId::BriefConfirmation)
+            .with("item", join_names(&added))
+            .render(phrases)
+    }
+
+    /// Confirms a successful add and asks "Noch etwas?", keeping the
+    /// session open; other outcomes are spoken like [`Self::add_item`].
+    pub fn add_more(
+        outcome: &AddItemOutcome,
+        phrases: &PhraseSelector,
+        style: SpeechStyle,
+    ) -> AlexaResponse {
+        match (style, outcome) {
+            (
+                SpeechStyle::Brief,
+                AddItemOutcome::Added { item } | AddItemOutcome::Duplicate { item },
+            ) => Self::ask_for_more(
+                Speech::new(MessageId::BriefConfirmation)
+                    .with("item", item.as_str())
+                    .render(phrases),
+            ),
+            _ if outcome.is_success() => Self::ask_for_more(format!(
+                "{}{}",
+                Speech::new(MessageId::Acknowledgement).render(phrases),
+                add_item_speech(outcome)
+            )),
+            _ => Self::add_item(outcome, phrases, style),
+        }
+    }
+
+    /// Speaks `text` followed by "Noch etwas?", keeping the session open.
+    pub fn ask_for_more(text: impl Into<String>) -> AlexaResponse {
+        let add_more = Speech::new(MessageId::AddMore).render_default();
+        Self::question(format!("{} {}", text.into(), add_more), add_more)
+    }
+
+    /// Speaks tailored guidance for a failed request, ending the session.
+    ///
+    /// Unless the user merely named an item that is not on the list, the
+    /// Alexa app shows the guidance with a short error code to quote to
+    /// support.
+    pub fn failure(error: &DomainError, fallback: MessageId) -> AlexaResponse {
+        let response = Self::error(failure_speech(error, fallback));
+        match error {
+            DomainError::ItemNotFound(_) => response,
+            _ => Self::with_error_card(response, error.code()),
+        }
+    }
+
+    fn with_error_card(mut response: AlexaResponse, code: ErrorCode) -> AlexaResponse {
+        response.response.card = Some(Card::Simple {
+            title: Speech::new(MessageId::ErrorCardTitle).render_default(),
+            content: Speech::new(MessageId::ErrorCardContent)
+                .with("message", response.response.output_speech.text.as_str())
+                .with("code", code.to_string())
+                .render_default(),
+        });
+        response
+    }
+
+    /// Shows the names of the most recent list entries on a standard card
+    /// in the Alexa app below the spoken text, unless `response` already
+    /// carries a card or `names` is empty.
+    pub fn with_list_card(mut response: AlexaResponse, names: &[&str]) -> AlexaResponse {
+        if response.response.card.is_some() || names.is_empty() {
+            return response;
+        }
+        let items: Vec<String> = names.iter().map(|name| format!("• {}", name)).collect();
+        response.response.card = Some(Card::Standard {
+            title: Speech::new(MessageId::ListCardTitle).render_default(),
+            text: Speech::new(MessageId::ListCardContent)
+                .with("message", response.response.output_speech.text.as_str())
+                .with("items", items.join("\n"))
+                .render_default(),
+        });
+        response
+    }
+
     /// Creates an error response with the given message, ending the session.
     pub fn error(message: impl Into<String>) -> AlexaResponse {
         Self::build(message, true)
     }
 
     /// Creates a welcome message response, keeping the session open.
-    pub fn launch() -> AlexaResponse {
-        Self::build(messages::WELCOME, false)
+    pub fn launch(phrases: &PhraseSelector, style: SpeechStyle) -> AlexaResponse {
+        let welcome = Speech::new(welcome_message(style)).render(phrases);
+        Self::with_reprompt(Self::build(welcome, false), phrases)
+    }
+
+    /// Creates a welcome response addressing the user by name.
+    pub fn personal_launch(
+        given_name: &str,
+        phrases: &PhraseSelector,
+        style: SpeechStyle,
+    ) -> AlexaResponse {
+        let greeting = Speech::new(MessageId::Greeting)
+            .with("name", given_name)
+            .render(phrases);
+        let welcome = Speech::new(welcome_message(style)).render(phrases);
+        Self::with_reprompt(
+            Self::build(format!("{} {}", greeting, welcome), false),
+            phrases,
+        )
     }
 
     /// Creates a help response, keeping the session open.
-    pub fn help() -> AlexaResponse {
-        Self::build(messages::HELP, false)
+    pub fn help(phrases: &PhraseSelector) -> AlexaResponse {
+        Self::with_reprompt(
+            Self::build(Speech::new(MessageId::Help).render(phrases), false),
+            phrases,
+        )
+    }
+
+    /// Asks the user a question, keeping the session open with a reprompt.
+    pub fn question(text: impl Into<String>, reprompt: impl Into<String>) -> AlexaResponse {
+        let mut response = Self::build(text, false);
+        response.response.reprompt = Some(Reprompt {
+            output_speech: OutputSpeech::plain_text(reprompt),
+        });
+        response
+    }
+
+    /// Hands the current intent's dialog to Alexa, which elicits and
+    /// confirms slots as defined in the interaction model. The response has
+    /// no speech and keeps the session open.
+    pub fn delegate(updated_intent: Option<UpdatedIntent>) -> AlexaResponse {
+        let mut response = Self::build("", false);
+        response.response.output_speech = OutputSpeech::omitted();
+        response.with_directive(Directive::DialogDelegate { updated_intent })
+    }
+
+    /// Adds an APL document to `response` if the device can show it;
+    /// devices without a screen get the response unchanged.
+    pub fn with_document(
+        response: AlexaResponse,
+        request: &AlexaRequest,
+        token: impl Into<String>,
+        document: serde_json::Value,
+        datasources: Option<serde_json::Value>,
+    ) -> AlexaResponse {
+        if !request.supports_interface(interfaces::APL) {
+            return response;
+        }
+        response.with_directive(Directive::AplRenderDocument {
+            token: token.into(),
+            document,
+            datasources,
+        })
+    }
+
+    /// Hands the user to a task provided by Alexa with `text` as the
+    /// lead-in, e.g. an in-skill purchase. The session ends; the result
+    /// arrives as a new request.
+    pub fn send_request(
+        text: impl Into<String>,
+        name: impl Into<String>,
+        payload: serde_json::Value,
+        token: impl Into<String>,
+    ) -> AlexaResponse {
+        Self::build(text, true).with_directive(Directive::ConnectionsSendRequest {
+            name: name.into(),
+            payload,
+            token: token.into(),
+        })
+    }
+
+    /// Starts a task of another skill or of Alexa with `text` as the
+    /// lead-in, ending the session.
+    pub fn start_connection(
+        text: impl Into<String>,
+        uri: impl Into<String>,
+        input: serde_json::Value,
+        token: impl Into<String>,
+        on_completion: Option<OnCompletion>,
+    ) -> AlexaResponse {
+        Self::build(text, true).with_directive(Directive::ConnectionsStartConnection {
+            uri: uri.into(),
+            input,
+            token: token.into(),
+            on_completion,
+        })
     }
 
     /// Creates a goodbye response, ending the session.
-    pub fn goodbye() -> AlexaResponse {
-        Self::build(messages::GOODBYE, true)
+    pub fn goodbye(phrases: &PhraseSelector) -> AlexaResponse {
+        Self::build(Speech::new(MessageId::Goodbye).render(phrases), true)
+    }
+
+    /// Confirms that a pending action was aborted, keeping the session open.
+    pub fn cancelled(phrases: &PhraseSelector) -> AlexaResponse {
+        Self::question(
+            Speech::new(MessageId::Cancelled).render(phrases),
+            Speech::new(MessageId::CancelledReprompt).render(phrases),
+        )
+    }
+
+    /// Answers a repeat request when there is nothing to repeat, keeping
+    /// the session open.
+    pub fn nothing_to_repeat(phrases: &PhraseSelector) -> AlexaResponse {
+        Self::question(
+            Speech::new(MessageId::NothingToRepeat).render(phrases),
+            Speech::new(MessageId::Reprompt).render(phrases),
+        )
+    }
+
+    /// Tells the user the skill is being updated, ending the session.
+    pub fn maintenance() -> AlexaResponse {
+        Self::build(Speech::new(MessageId::Maintenance).render_default(), true)
     }
 
     /// Creates an unknown intent response, keeping the session open.
-    pub fn unknown() -> AlexaResponse {
-        Self::build(messages::UNKNOWN, false)
+    pub fn unknown(phrases: &PhraseSelector) -> AlexaResponse {
+        Self::with_reprompt(
+            Self::build(Speech::new(MessageId::Unknown).render(phrases), false),
+            phrases,
+        )
+    }
+
+    /// Asks the user to grant permissions in the Alexa app, ending the session.
+    ///
+    /// Alexa shows a consent card for the given scopes in the companion app.
+    pub fn permission_request(text: impl Into<String>, scopes: &[&str]) -> AlexaResponse {
+        let mut response = Self::build(text, true);
+        response.response.card = Some(Card::AskForPermissionsConsent {
+            permissions: scopes.iter().map(|scope| scope.to_string()).collect(),
+        });
+        response
+    }
+
+    /// Creates an empty acknowledgement for skill events, which Alexa
+    /// does not speak.
+    pub fn acknowledge() -> AlexaResponse {
+        Self::build("", true)
+    }
+
+    fn with_reprompt(response: AlexaResponse, phrases: &PhraseSelector) -> AlexaResponse {
+        Self::question(
+            response.response.output_speech.text,
+            Speech::new(MessageId::Reprompt).render(phrases),
+        )
     }
 
     fn build(text: impl Into<String>, end_session: bool) -> AlexaResponse {
         AlexaResponse {
             version: "1.0".to_string(),
+            session_attributes: HashMap::new(),
             response: ResponseBody {
                 output_speech: OutputSpeech::plain_text(text),
+                reprompt: None,
+                card: None,
+                directives: Vec::new(),
                 should_end_session: end_session,
             },
         }
@@ -65,6 +379,10 @@ impl ResponseBuilder {
 mod tests {
     use super::*;
 
+    fn phrases() -> PhraseSelector {
+        PhraseSelector::for_session(Some("session-123"))
+    }
+
     #[test]
     fn success_ends_session() {
         let response = ResponseBuilder::success("Item added");
@@ -72,6 +390,17 @@ mod tests {
         assert_eq!(response.response.output_speech.text, "Item added");
     }
 
+    #[test]
+    fn confirmation_contains_message() {
+        let response = ResponseBuilder::confirmation("Milch wurde hinzugefügt.", &phrases());
+        assert!(response.response.should_end_session);
+        assert!(response
+            .response
+            .output_speech
+            .text
+            .ends_with("Milch wurde hinzugefügt."));
+    }
+
     #[test]
     fn error_ends_session() {
         let response = ResponseBuilder::error("Something went wrong");
@@ -80,28 +409,330 @@ mod tests {
 
     #[test]
     fn launch_keeps_session_open() {
-        let response = ResponseBuilder::launch();
+        let response = ResponseBuilder::launch(&phrases(), SpeechStyle::Full);
         assert!(!response.response.should_end_session);
         assert!(response.response.output_speech.text.contains("Willkommen"));
+        assert!(response.response.reprompt.is_some());
     }
 
     #[test]
     fn help_keeps_session_open() {
-        let response = ResponseBuilder::help();
+        let response = ResponseBuilder::help(&phrases());
         assert!(!response.response.should_end_session);
+        assert!(response.response.reprompt.is_some());
+    }
+
+    #[test]
+    fn question_keeps_session_open_with_reprompt() {
+        let response = ResponseBuilder::question("Noch etwas?", "Was noch?");
+        assert!(!response.response.should_end_session);
+        assert_eq!(
+            response.response.reprompt.unwrap().output_speech.text,
+            "Was noch?"
+        );
     }
 
     #[test]
     fn goodbye_ends_session() {
-        let response = ResponseBuilder::goodbye();
+        let response = ResponseBuilder::goodbye(&PhraseSelector::default());
         assert!(response.response.should_end_session);
         assert!(response.response.output_speech.text.contains("Wiedersehen"));
+        assert!(response.response.reprompt.is_none());
+    }
+
+    #[test]
+    fn maintenance_ends_session() {
+        let response = ResponseBuilder::maintenance();
+        assert!(response.response.should_end_session);
+        assert!(response
+            .response
+            .output_speech
+            .text
+            .contains("aktualisiert"));
     }
 
     #[test]
     fn unknown_keeps_session_open() {
-        let response = ResponseBuilder::unknown();
+        let response = ResponseBuilder::unknown(&phrases());
         assert!(!response.response.should_end_session);
+        assert!(response
+            .response
+            .output_speech
+            .text
+            .contains("nicht verstanden"));
+    }
+
+    #[test]
+    fn reprompt_is_stable_within_session() {
+        let first = ResponseBuilder::help(&phrases()).response.reprompt.unwrap();
+        let second = ResponseBuilder::launch(&phrases(), SpeechStyle::Full)
+            .response
+            .reprompt
+            .unwrap();
+        assert_eq!(first.output_speech.text, second.output_speech.text);
+    }
+
+    #[test]
+    fn permission_request_attaches_consent_card() {
+        let response = ResponseBuilder::permission_request(
+            "Bitte erlaube Erinnerungen.",
+            &["alexa::alerts:reminders:skill:readwrite"],
+        );
+        assert!(response.response.should_end_session);
+        let json = serde_json::to_value(&response).unwrap();
+        assert_eq!(json["response"]["card"]["type"], "AskForPermissionsConsent");
+        assert_eq!(
+            json["response"]["card"]["permissions"][0],
+            "alexa::alerts:reminders:skill:readwrite"
+        );
+    }
+
+    #[test]
+    fn acknowledge_has_no_speech() {
+        let response = ResponseBuilder::acknowledge();
+        assert!(response.response.should_end_session);
+        assert!(response.response.output_speech.text.is_empty());
+    }
+
+    #[test]
+    fn add_item_confirms_added_item() {
+        let outcome = AddItemOutcome::Added {
+            item: "Milch".to_string(),
+        };
+        let response = ResponseBuilder::add_item(&outcome, &phrases(), SpeechStyle::Full);
+        assert!(response.response.should_end_session);
+        assert!(response
+            .response
+            .output_speech
+            .text
+            .ends_with("Milch wurde zur Einkaufsliste hinzugefügt."));
+    }
+
+    #[test]
+    fn brief_add_item_names_only_the_item() {
+        let outcome = AddItemOutcome::Duplicate {
+            item: "Milch".to_string(),
+        };
+        let response = ResponseBuilder::add_item(&outcome, &phrases(), SpeechStyle::Brief);
+        assert_eq!(response.response.output_speech.text, "Okay, Milch.");
+
+        let response =
+            ResponseBuilder::add_item(&AddItemOutcome::ListFull, &phrases(), SpeechStyle::Brief);
+        assert!(response.response.output_speech.text.contains("voll"));
+    }
+
+    #[test]
+    fn add_more_asks_for_another_item() {
+        let outcome = AddItemOutcome::Added {
+            item: "Milch".to_string(),
+        };
+        let response = ResponseBuilder::add_more(&outcome, &phrases(), SpeechStyle::Brief);
+        assert!(!response.response.should_end_session);
+        assert_eq!(
+            response.response.output_speech.text,
+            "Okay, Milch. Noch etwas?"
+        );
+
+        let response =
+            ResponseBuilder::add_more(&AddItemOutcome::ListFull, &phrases(), SpeechStyle::Full);
+        assert!(response.response.should_end_session);
+    }
+
+    #[test]
+    fn add_items_names_added_items_before_failure() {
+        let outcomes = [
+            AddItemOutcome::Added {
+                item: "Milch".to_string(),
+            },
+            AddItemOutcome::ListFull,
+        ];
+        let response =
+            ResponseBuilder::add_items(&outcomes, &PhraseSelector::default(), SpeechStyle::Full);
+
+        assert!(response.response.should_end_session);
+        assert!(response.response.output_speech.text.starts_with(
+            "Milch wurde zur Einkaufsliste hinzugefügt. Deine Einkaufsliste ist voll."
+        ));
+        assert!(response.response.card.is_some());
+    }
+
+    #[test]
+    fn adds_document_only_for_screen_devices() {
+        let request = |interfaces: serde_json::Value| -> AlexaRequest {
+            serde_json::from_value(serde_json::json!({
+                "version": "1.0",
+                "context": {"System": {
+                    "user": {"userId": "user-1"},
+                    "device": {"deviceId": "device-1", "supportedInterfaces": interfaces}
+                }},
+                "request": {
+                    "type": "LaunchRequest",
+                    "requestId": "req-1",
+                    "timestamp": "2024-01-27T10:00:00Z",
+                    "locale": "de-DE"
+                }
+            }))
+            .unwrap()
+        };
+        let document = serde_json::json!({"type": "APL"});
+
+        let screen = ResponseBuilder::with_document(
+            ResponseBuilder::success("Hallo"),
+            &request(serde_json::json!({"Alexa.Presentation.APL": {}})),
+            "list",
+            document.clone(),
+            None,
+        );
+        assert_eq!(screen.response.directives.len(), 1);
+
+        let speaker = ResponseBuilder::with_document(
+            ResponseBuilder::success("Hallo"),
+            &request(serde_json::json!({})),
+            "list",
+            document,
+            None,
+        );
+        assert!(speaker.response.directives.is_empty());
+    }
+
+    #[test]
+    fn connection_ends_session() {
+        let response = ResponseBuilder::send_request(
+            "Gerne.",
+            "Buy",
+            serde_json::json!({"InSkillProduct": {"productId": "amzn1.adg.product.1"}}),
+            "buy",
+        );
+        assert!(response.response.should_end_session);
+        assert!(response.response.directives[0].is_connection());
+    }
+
+    #[test]
+    fn brief_launch_keeps_session_open() {
+        let response = ResponseBuilder::launch(&phrases(), SpeechStyle::Brief);
+        assert!(!response.response.should_end_session);
+        assert_eq!(
+            response.response.output_speech.text,
+            Speech::new(MessageId::WelcomeBrief).render_default()
+        );
+        assert!(response.response.reprompt.is_some());
+    }
+
+    #[test]
+    fn personal_launch_greets_by_name() {
+        let response = ResponseBuilder::personal_launch("Anna", &phrases(), SpeechStyle::Brief);
+        assert!(!response.response.should_end_session);
+        assert_eq!(
+            response.response.output_speech.text,
+            format!(
+                "Hallo Anna! {}",
+                Speech::new(MessageId::WelcomeBrief).render_default()
+            )
+        );
+    }
+
+    #[test]
+    fn add_item_speaks_failure_reasons() {
+        let cases = [
+            (AddItemOutcome::AuthFailed, "Anmeldung"),
+            (AddItemOutcome::Unavailable, "nicht hinzugefügt"),
+            (AddItemOutcome::Maintenance, "Wartungsarbeiten"),
+            (AddItemOutcome::RateLimited, "zu viele Anfragen"),
+            (AddItemOutcome::ListFull, "voll"),
+            (
+                AddItemOutcome::InvalidName {
+                    reason: "zu lang".to_string(),
+                },
+                "ungültig: zu lang",
+            ),
+        ];
+        for (outcome, expected) in cases {
+            let response = ResponseBuilder::add_item(&outcome, &phrases(), SpeechStyle::Full);
+            assert!(
+                response.response.output_speech.text.contains(expected),
+                "{:?}",
+                outcome
+            );
+        }
+    }
+
+    #[test]
+    fn add_item_failure_shows_error_code_card() {
+        let response =
+            ResponseBuilder::add_item(&AddItemOutcome::Maintenance, &phrases(), SpeechStyle::Full);
+
+        let Some(Card::Simple { content, .. }) = &response.response.card else {
+            panic!("expected a simple card");
+        };
+        assert!(content.contains("Wartungsarbeiten"));
+        assert!(content.ends_with("Fehlercode: E302"));
+    }
+
+    #[test]
+    fn failure_speaks_guidance_per_error() {
+        let cases = [
+            (
+                DomainError::ServiceUnavailable("503".to_string()),
+                "Wartungsarbeiten",
+            ),
+            (
+                DomainError::RateLimited("429".to_string()),
+                "zu viele Anfragen",
+            ),
+            (
+                DomainError::PermissionDenied("403".to_string()),
+                "verweigert",
+            ),
+            (
+                DomainError::AuthenticationFailed("401".to_string()),
+                "Anmeldung",
+            ),
+            (
+                DomainError::RepositoryError("boom".to_string()),
+                "nicht gelesen",
+            ),
+        ];
+        for (error, expected) in cases {
+            let response = ResponseBuilder::failure(&error, MessageId::ReadFailed);
+
+            assert!(response.response.should_end_session);
+            assert!(
+                response.response.output_speech.text.contains(expected),
+                "{:?}",
+                error
+            );
+            let Some(Card::Simple { content, .. }) = &response.response.card else {
+                panic!("expected a simple card for {:?}", error);
+            };
+            assert!(content.ends_with(&format!("Fehlercode: {}", error.code())));
+        }
+    }
+
+    #[test]
+    fn missing_item_is_spoken_without_card() {
+        let response = ResponseBuilder::failure(
+            &DomainError::ItemNotFound("Milch".to_string()),
+            MessageId::RemoveFailed,
+        );
+
+        assert_eq!(
+            response.response.output_speech.text,
+            "Milch steht nicht auf deiner Einkaufsliste."
+        );
+        assert!(response.response.card.is_none());
+    }
+
+    #[test]
+    fn delegate_has_no_speech_and_keeps_session_open() {
+        let response = ResponseBuilder::delegate(None);
+        let json = serde_json::to_value(&response).unwrap();
+
+        assert!(json["response"].get("outputSpeech").is_none());
+        assert_eq!(json["response"]["shouldEndSession"], false);
+        assert_eq!(
+            json["response"]["directives"],
+            serde_json::json!([{ "type": "Dialog.Delegate" }])
+        );
     }
 
     #[test]
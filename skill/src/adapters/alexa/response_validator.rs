@@ -0,0 +1,381 @@
+use super::models::{AlexaResponse, Card, OutputSpeech};
+
+/// Maximum characters of output speech or reprompt, plain text or SSML.
+const MAX_SPEECH_CHARS: usize = 8000;
+
+/// Maximum characters of a card's title and content combined.
+const MAX_CARD_CHARS: usize = 8000;
+
+/// A change made to a response so Alexa accepts it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Repair {
+    /// Speech exceeded the length limit and was shortened.
+    TruncatedSpeech { field: &'static str, chars: usize },
+    /// SSML lacked the `<speak>` root or had unescaped `&`.
+    RepairedSsml { field: &'static str },
+    /// SSML was malformed and was replaced by its plain text.
+    SsmlToPlainText { field: &'static str },
+    /// Card text exceeded the length limit and was shortened.
+    TruncatedCard { chars: usize },
+    /// A dialog directive came with an ending session, which Alexa
+    /// rejects; the session was kept open.
+    KeptSessionOpenForDialog,
+    /// A connection directive came with a reprompt, which Alexa rejects;
+    /// the reprompt was dropped.
+    DroppedRepromptForConnection,
+}
+
+/// Checks outgoing responses against Alexa's constraints.
+///
+/// Alexa rejects a response that breaks a limit with a generic error and
+/// the user hears nothing useful. Repairing it (shortening, fixing SSML)
+/// keeps the answer mostly intact instead.
+pub struct ResponseValidator;
+
+impl ResponseValidator {
+    /// Repairs all constraint violations in place and returns what was
+    /// changed; an empty list means the response was valid.
+    pub fn validate(response: &mut AlexaResponse) -> Vec<Repair> {
+        let mut repairs = Vec::new();
+
+        Self::validate_speech(
+            &mut response.response.output_speech,
+            "outputSpeech",
+            &mut repairs,
+        );
+        if let Some(reprompt) = &mut response.response.reprompt {
+            Self::validate_speech(&mut reprompt.output_speech, "reprompt", &mut repairs);
+        }
+        if let Some(
+            Card::Simple { title, content }
+            | Card::Standard {
+                title,
+                text: content,
+            },
+        ) = &mut response.response.card
+        {
+            let chars = title.chars().count() + content.chars().count();
+            if chars > MAX_CARD_CHARS {
+                let available = MAX_CARD_CHARS.saturating_sub(title.chars().count());
+                *content = truncate(content, available);
+                repairs.push(Repair::TruncatedCard { chars });
+            }
+        }
+        let has_dialog = response.response.directives.iter().any(|d| d.is_dialog());
+        if has_dialog && response.response.should_end_session {
+            response.response.should_end_session = false;
+            repairs.push(Repair::KeptSessionOpenForDialog);
+        }
+        let has_connection = response
+            .response
+            .directives
+            .iter()
+            .any(|d| d.is_connection());
+        if has_connection && response.response.reprompt.take().is_some() {
+            repairs.push(Repair::DroppedRepromptForConnection);
+        }
+
+        repairs
+    }
+
+    fn validate_speech(speech: &mut OutputSpeech, field: &'static str, repairs: &mut Vec<Repair>) {
+        if speech.is_ssml() {
+            match repair_ssml(&speech.text) {
+                Some(ssml) => {
+                    if ssml != speech.text {
+                        speech.text = ssml;
+                        repairs.push(Repair::RepairedSsml { field });
+                    }
+                }
+                None => {
+                    *speech = OutputSpeech::plain_text(strip_tags(&speech.text));
+                    repairs.push(Repair::SsmlToPlainText { field });
+                }
+            }
+        }
+
+        let chars = speech.text.chars().count();
+        if chars > MAX_SPEECH_CHARS {
+            // Cutting markup could break it, so long SSML is spoken as text
+            if speech.is_ssml() {
+                *speech = OutputSpeech::plain_text(strip_tags(&speech.text));
+                repairs.push(Repair::SsmlToPlainText { field });
+            }
+            speech.text = truncate(&speech.text, MAX_SPEECH_CHARS);
+            repairs.push(Repair::TruncatedSpeech { field, chars });
+        }
+    }
+}
+
+/// Shortens text to at most `max` characters, preferring to end after a
+/// sentence and otherwise between words.
+fn truncate(text: &str, max: usize) -> String {
+    if text.chars().count() <= max {
+        return text.to_string();
+    }
+
+    let cut: String = text.chars().take(max).collect();
+    let half = cut.len() / 2;
+
+    let sentence_end = cut
+        .char_indices()
+        .filter(|&(i, c)| matches!(c, '.' | '!' | '?') && i >= half)
+        .map(|(i, c)| i + c.len_utf8())
+        .next_back();
+    if let Some(end) = sentence_end {
+        return cut[..end].to_string();
+    }
+
+    match cut.rfind(char::is_whitespace) {
+        Some(end) if end > 0 => cut[..end].trim_end().to_string(),
+        _ => cut,
+    }
+}
+
+/// Makes SSML well-formed where that is safe: wraps it in `<speak>` and
+/// escapes bare `&`. Returns `None` if the tags do not nest properly.
+fn repair_ssml(ssml: &str) -> Option<String> {
+    let trimmed = ssml.trim();
+    let mut repaired = escape_ampersands(trimmed);
+    if !(repaired.starts_with("<speak>") || repaired.starts_with("<speak "))
+        || !repaired.ends_with("</speak>")
+    {
+        repaired = format!("<speak>{}</speak>", repaired);
+    }
+
+    tags_are_balanced(&repaired).then_some(repaired)
+}
+
+/// Escapes `&` that does not start an XML entity.
+fn escape_ampersands(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for (i, c) in text.char_indices() {
+        if c == '&' && !starts_with_entity(&text[i + 1..]) {
+            escaped.push_str("&amp;");
+        } else {
+            escaped.push(c);
+        }
+    }
+    escaped
+}
+
+fn starts_with_entity(rest: &str) -> bool {
+    let Some(end) = rest.find(';') else {
+        return false;
+    };
+    let name = &rest[..end];
+    matches!(name, "amp" | "lt" | "gt" | "quot" | "apos")
+        || name
+            .strip_prefix('#')
+            .is_some_and(|code| !code.is_empty() && code.chars().all(|c| c.is_ascii_alphanumeric()))
+}
+
+/// Checks that every opening tag is closed in the right order.
+fn tags_are_balanced(ssml: &str) -> bool {
+    let mut open: Vec<&str> = Vec::new();
+    let mut rest = ssml;
+
+    while let Some(start) = rest.find('<') {
+        let Some(len) = rest[start..].find('>') else {
+            return false;
+        };
+        let tag = &rest[start + 1..start + len];
+        rest = &rest[start + len + 1..];
+
+        if let Some(name) = tag.strip_prefix('/') {
+            if open.pop() != Some(name.trim()) {
+                return false;
+            }
+        } else if !tag.ends_with('/') {
+            match tag.split_whitespace().next() {
+                Some(name) => open.push(name),
+                None => return false,
+            }
+        }
+    }
+
+    open.is_empty() && !rest.contains('>')
+}
+
+/// Turns SSML into the plain text it would speak.
+fn strip_tags(ssml: &str) -> String {
+    let mut text = String::with_capacity(ssml.len());
+    let mut in_tag = false;
+    for c in ssml.chars() {
+        match c {
+            '<' => {
+                in_tag = true;
+                text.push(' ');
+            }
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::alexa::Directive;
+    use crate::adapters::alexa::ResponseBuilder;
+
+    fn ssml_response(ssml: &str) -> AlexaResponse {
+        let mut response = ResponseBuilder::success("");
+        response.response.output_speech = OutputSpeech::ssml(ssml);
+        response
+    }
+
+    #[test]
+    fn valid_response_is_unchanged() {
+        let mut response = ResponseBuilder::question("Noch etwas?", "Was noch?");
+        assert!(ResponseValidator::validate(&mut response).is_empty());
+        assert_eq!(response.response.output_speech.text, "Noch etwas?");
+    }
+
+    #[test]
+    fn truncates_long_speech_after_a_sentence() {
+        let text = "Milch. ".repeat(2000);
+        let mut response = ResponseBuilder::success(text.trim());
+
+        let repairs = ResponseValidator::validate(&mut response);
+
+        let speech = &response.response.output_speech.text;
+        assert!(speech.chars().count() <= MAX_SPEECH_CHARS);
+        assert!(speech.ends_with("Milch."));
+        assert_eq!(
+            repairs,
+            vec![Repair::TruncatedSpeech {
+                field: "outputSpeech",
+                chars: 13_999
+            }]
+        );
+    }
+
+    #[test]
+    fn truncates_long_reprompt_between_words() {
+        let mut response = ResponseBuilder::question("Noch etwas?", "Eier ".repeat(2000));
+
+        ResponseValidator::validate(&mut response);
+
+        let reprompt = &response.response.reprompt.unwrap().output_speech.text;
+        assert!(reprompt.chars().count() <= MAX_SPEECH_CHARS);
+        assert!(reprompt.ends_with("Eier"));
+    }
+
+    #[test]
+    fn counts_characters_not_bytes() {
+        let text = "ü".repeat(MAX_SPEECH_CHARS);
+        let mut response = ResponseBuilder::success(text.clone());
+
+        assert!(ResponseValidator::validate(&mut response).is_empty());
+        assert_eq!(response.response.output_speech.text, text);
+    }
+
+    #[test]
+    fn wraps_ssml_in_speak_and_escapes_ampersands() {
+        let mut response = ssml_response("Salz & Pfeffer<break time=\"1s\"/>");
+
+        let repairs = ResponseValidator::validate(&mut response);
+
+        assert_eq!(
+            response.response.output_speech.text,
+            "<speak>Salz &amp; Pfeffer<break time=\"1s\"/></speak>"
+        );
+        assert_eq!(
+            repairs,
+            vec![Repair::RepairedSsml {
+                field: "outputSpeech"
+            }]
+        );
+    }
+
+    #[test]
+    fn keeps_escaped_entities() {
+        let mut response = ssml_response("<speak>Salz &amp; Pfeffer &#228;</speak>");
+        assert!(ResponseValidator::validate(&mut response).is_empty());
+    }
+
+    #[test]
+    fn falls_back_to_plain_text_for_malformed_ssml() {
+        let mut response = ssml_response("<speak><emphasis>Milch</speak>");
+
+        let repairs = ResponseValidator::validate(&mut response);
+
+        let speech = &response.response.output_speech;
+        assert!(!speech.is_ssml());
+        assert_eq!(speech.text, "Milch");
+        assert_eq!(
+            repairs,
+            vec![Repair::SsmlToPlainText {
+                field: "outputSpeech"
+            }]
+        );
+    }
+
+    #[test]
+    fn speaks_overlong_ssml_as_plain_text() {
+        let ssml = format!("<speak>{}</speak>", "Brot &amp; Butter. ".repeat(500));
+        let mut response = ssml_response(&ssml);
+
+        ResponseValidator::validate(&mut response);
+
+        let speech = &response.response.output_speech;
+        assert!(!speech.is_ssml());
+        assert!(speech.text.starts_with("Brot & Butter."));
+        assert!(speech.text.chars().count() <= MAX_SPEECH_CHARS);
+    }
+
+    #[test]
+    fn truncates_card_content_to_fit_with_title() {
+        let mut response = ResponseBuilder::success("Erledigt.");
+        response.response.card = Some(Card::Simple {
+            title: "Einkaufsliste".to_string(),
+            content: "Milch ".repeat(2000),
+        });
+
+        let repairs = ResponseValidator::validate(&mut response);
+
+        let Some(Card::Simple { title, content }) = &response.response.card else {
+            panic!("card removed");
+        };
+        assert!(title.chars().count() + content.chars().count() <= MAX_CARD_CHARS);
+        assert_eq!(repairs, vec![Repair::TruncatedCard { chars: 12_013 }]);
+    }
+
+    #[test]
+    fn keeps_session_open_for_dialog_directives() {
+        let mut response = ResponseBuilder::delegate(None);
+        response.response.should_end_session = true;
+
+        let repairs = ResponseValidator::validate(&mut response);
+
+        assert!(!response.response.should_end_session);
+        assert_eq!(repairs, vec![Repair::KeptSessionOpenForDialog]);
+    }
+
+    #[test]
+    fn drops_reprompt_next_to_connection_directive() {
+        let mut response = ResponseBuilder::question("Frage?", "Frage?").with_directive(
+            Directive::ConnectionsSendRequest {
+                name: "Buy".to_string(),
+                payload: serde_json::json!({}),
+                token: "buy".to_string(),
+            },
+        );
+
+        let repairs = ResponseValidator::validate(&mut response);
+
+        assert!(response.response.reprompt.is_none());
+        assert_eq!(repairs, vec![Repair::DroppedRepromptForConnection]);
+    }
+}
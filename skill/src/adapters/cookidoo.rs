@@ -1,12 +0,0 @@
-mod auth;
-mod client;
-mod error;
-mod models;
-mod shopping_list;
-mod token_cache;
-
-pub use auth::CookidooAuthAdapter;
-pub use client::CookidooClient;
-pub use error::CookidooError;
-pub use shopping_list::CookidooShoppingListAdapter;
-pub use token_cache::TokenCache;
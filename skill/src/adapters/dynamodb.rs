@@ -0,0 +1,17 @@
+mod history_repository;
+mod idempotency_store;
+mod item_alias_table;
+mod migration;
+mod occasion_list_store;
+mod permission_store;
+mod preferences_repository;
+mod refresh_token_store;
+
+pub use history_repository::DynamoDbHistoryRepository;
+pub use idempotency_store::DynamoDbIdempotencyStore;
+pub use item_alias_table::DynamoDbItemAliasTable;
+pub use migration::{Item, MigrationError, MigrationStep, Migrations, SCHEMA_VERSION_ATTRIBUTE};
+pub use occasion_list_store::DynamoDbOccasionListStore;
+pub use permission_store::DynamoDbPermissionStore;
+pub use preferences_repository::DynamoDbPreferencesRepository;
+pub use refresh_token_store::DynamoDbRefreshTokenStore;
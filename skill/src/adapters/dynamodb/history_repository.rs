@@ -0,0 +1,244 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use async_trait::async_trait;
+use aws_sdk_dynamodb::types::{AttributeValue, DeleteRequest, WriteRequest};
+use aws_sdk_dynamodb::Client;
+use tracing::error;
+
+use crate::domain::models::{DomainError, HistoryEntry};
+use crate::domain::ports::HistoryRepository;
+
+use super::migration::{Migrations, SCHEMA_VERSION_ATTRIBUTE};
+
+/// Partition key attribute holding the Alexa user ID.
+const USER_ATTRIBUTE: &str = "userId";
+
+/// Sort key attribute: the timestamp followed by `#` and the item name, so
+/// entries sort by time and two items added at once do not collide.
+const ENTRY_KEY_ATTRIBUTE: &str = "entryKey";
+
+/// Attribute holding the item name.
+const ITEM_ATTRIBUTE: &str = "itemName";
+
+/// Attribute holding the ISO 8601 timestamp of the add.
+const ADDED_AT_ATTRIBUTE: &str = "addedAt";
+
+/// Attribute holding the ID of the recognized speaker, if any.
+const PERSON_ATTRIBUTE: &str = "personId";
+
+/// Attribute used as the table's TTL (epoch seconds).
+const EXPIRES_AT_ATTRIBUTE: &str = "expiresAt";
+
+/// How long added items are remembered; the history covers a week at most.
+const RETENTION: Duration = Duration::from_secs(30 * 24 * 60 * 60);
+
+/// Most delete requests DynamoDB accepts in one `BatchWriteItem` call.
+const BATCH_WRITE_LIMIT: usize = 25;
+
+/// How often unprocessed deletes of a batch are sent again before giving up.
+const BATCH_WRITE_ATTEMPTS: usize = 3;
+
+/// Schema migrations of the history table.
+///
+/// Entries expire within a month, so migrated entries are not written back.
+const MIGRATIONS: Migrations = Migrations::new(&[]);
+
+/// DynamoDB-backed history of added items.
+///
+/// The table must use `userId` (string) as partition key and `entryKey`
+/// (string) as sort key, and should enable TTL on `expiresAt`.
+pub struct DynamoDbHistoryRepository {
+    client: Client,
+    table_name: String,
+}
+
+impl DynamoDbHistoryRepository {
+    /// Creates a new repository for the given table.
+    pub fn new(client: Client, table_name: impl Into<String>) -> Self {
+        Self {
+            client,
+            table_name: table_name.into(),
+        }
+    }
+
+    /// Deletes up to [`BATCH_WRITE_LIMIT`] entries of `user_id` in one
+    /// `BatchWriteItem` call, resending the entries DynamoDB left
+    /// unprocessed.
+    async fn delete_batch(
+        &self,
+        user_id: &str,
+        entry_keys: Vec<AttributeValue>,
+    ) -> Result<(), DomainError> {
+        let mut requests = entry_keys
+            .into_iter()
+            .map(|entry_key| {
+                DeleteRequest::builder()
+                    .key(USER_ATTRIBUTE, AttributeValue::S(user_id.to_string()))
+                    .key(ENTRY_KEY_ATTRIBUTE, entry_key)
+                    .build()
+                    .map(|delete| WriteRequest::builder().delete_request(delete).build())
+                    .map_err(|e| DomainError::RepositoryError(e.to_string()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        for _ in 0..BATCH_WRITE_ATTEMPTS {
+            if requests.is_empty() {
+                return Ok(());
+            }
+            let output = self
+                .client
+                .batch_write_item()
+                .request_items(&self.table_name, requests)
+                .send()
+                .await
+                .map_err(|e| {
+                    error!(error = %e, "Failed to delete history entries");
+                    DomainError::RepositoryError(e.to_string())
+                })?;
+            requests = output
+                .unprocessed_items
+                .and_then(|mut unprocessed| unprocessed.remove(&self.table_name))
+                .unwrap_or_default();
+        }
+
+        if requests.is_empty() {
+            Ok(())
+        } else {
+            error!(
+                remaining = requests.len(),
+                "History entries left unprocessed"
+            );
+            Err(DomainError::RepositoryError(format!(
+                "{} history entries could not be deleted",
+                requests.len()
+            )))
+        }
+    }
+}
+
+#[async_trait]
+impl HistoryRepository for DynamoDbHistoryRepository {
+    async fn record(&self, user_id: &str, entry: &HistoryEntry) -> Result<(), DomainError> {
+        let expires_at = (SystemTime::now() + RETENTION)
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+
+        let mut request = self
+            .client
+            .put_item()
+            .table_name(&self.table_name)
+            .item(USER_ATTRIBUTE, AttributeValue::S(user_id.to_string()))
+            .item(
+                ENTRY_KEY_ATTRIBUTE,
+                AttributeValue::S(format!("{}#{}", entry.added_at(), entry.item_name())),
+            )
+            .item(
+                ITEM_ATTRIBUTE,
+                AttributeValue::S(entry.item_name().to_string()),
+            )
+            .item(
+                ADDED_AT_ATTRIBUTE,
+                AttributeValue::S(entry.added_at().to_string()),
+            )
+            .item(
+                EXPIRES_AT_ATTRIBUTE,
+                AttributeValue::N(expires_at.to_string()),
+            )
+            .item(SCHEMA_VERSION_ATTRIBUTE, MIGRATIONS.current_version_value());
+        if let Some(person_id) = entry.person_id() {
+            request = request.item(PERSON_ATTRIBUTE, AttributeValue::S(person_id.to_string()));
+        }
+
+        request.send().await.map(|_| ()).map_err(|e| {
+            error!(error = %e, "Failed to record history entry");
+            DomainError::RepositoryError(e.to_string())
+        })
+    }
+
+    async fn entries_since(
+        &self,
+        user_id: &str,
+        since: &str,
+    ) -> Result<Vec<HistoryEntry>, DomainError> {
+        let mut entries = Vec::new();
+        let mut start_key = None;
+
+        loop {
+            let output = self
+                .client
+                .query()
+                .table_name(&self.table_name)
+                .key_condition_expression("#user = :user AND #entry >= :since")
+                .expression_attribute_names("#user", USER_ATTRIBUTE)
+                .expression_attribute_names("#entry", ENTRY_KEY_ATTRIBUTE)
+                .expression_attribute_values(":user", AttributeValue::S(user_id.to_string()))
+                .expression_attribute_values(":since", AttributeValue::S(since.to_string()))
+                .set_exclusive_start_key(start_key)
+                .send()
+                .await
+                .map_err(|e| {
+                    error!(error = %e, "Failed to read history");
+                    DomainError::RepositoryError(e.to_string())
+                })?;
+
+            for mut item in output.items.unwrap_or_default() {
+                MIGRATIONS.migrate(&mut item)?;
+                let value =
+                    |name: &str| item.get(name).and_then(|value| value.as_s().ok()).cloned();
+                if let (Some(item_name), Some(added_at)) =
+                    (value(ITEM_ATTRIBUTE), value(ADDED_AT_ATTRIBUTE))
+                {
+                    let entry = HistoryEntry::new(item_name, added_at);
+                    entries.push(match value(PERSON_ATTRIBUTE) {
+                        Some(person_id) => entry.with_person_id(person_id),
+                        None => entry,
+                    });
+                }
+            }
+
+            start_key = output.last_evaluated_key;
+            if start_key.is_none() {
+                return Ok(entries);
+            }
+        }
+    }
+
+    async fn delete(&self, user_id: &str) -> Result<(), DomainError> {
+        let mut start_key = None;
+
+        loop {
+            let output = self
+                .client
+                .query()
+                .table_name(&self.table_name)
+                .key_condition_expression("#user = :user")
+                .projection_expression("#entry")
+                .expression_attribute_names("#user", USER_ATTRIBUTE)
+                .expression_attribute_names("#entry", ENTRY_KEY_ATTRIBUTE)
+                .expression_attribute_values(":user", AttributeValue::S(user_id.to_string()))
+                .set_exclusive_start_key(start_key)
+                .send()
+                .await
+                .map_err(|e| {
+                    error!(error = %e, "Failed to read history for deletion");
+                    DomainError::RepositoryError(e.to_string())
+                })?;
+
+            let entry_keys: Vec<_> = output
+                .items
+                .unwrap_or_default()
+                .into_iter()
+                .filter_map(|mut item| item.remove(ENTRY_KEY_ATTRIBUTE))
+                .collect();
+            for chunk in entry_keys.chunks(BATCH_WRITE_LIMIT) {
+                self.delete_batch(user_id, chunk.to_vec()).await?;
+            }
+
+            start_key = output.last_evaluated_key;
+            if start_key.is_none() {
+                return Ok(());
+            }
+        }
+    }
+}
@@ -0,0 +1,175 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use async_trait::async_trait;
+use aws_sdk_dynamodb::types::AttributeValue;
+use aws_sdk_dynamodb::Client;
+use tracing::error;
+
+use crate::domain::models::DomainError;
+use crate::domain::ports::{IdempotencyStore, CLAIM_LEASE};
+
+use super::migration::{Migrations, SCHEMA_VERSION_ATTRIBUTE};
+
+/// Partition key attribute holding the idempotency key.
+const KEY_ATTRIBUTE: &str = "requestId";
+
+/// Attribute holding the recorded outcome.
+const OUTCOME_ATTRIBUTE: &str = "outcome";
+
+/// Attribute holding when an unsettled key was claimed (epoch seconds).
+const CLAIMED_AT_ATTRIBUTE: &str = "claimedAt";
+
+/// Attribute used as the table's TTL (epoch seconds).
+const EXPIRES_AT_ATTRIBUTE: &str = "expiresAt";
+
+/// How long processed requests are remembered; Alexa retries within minutes.
+const RETENTION: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Schema migrations of the idempotency table.
+///
+/// Records expire within a day, so migrated records are not written back.
+const MIGRATIONS: Migrations = Migrations::new(&[]);
+
+/// DynamoDB-backed idempotency store.
+///
+/// Shared by all Lambda instances, so re-deliveries are detected even when
+/// they land on a different execution environment. A claim is a conditional
+/// update that only succeeds on a new key or on an unsettled claim whose
+/// `claimedAt` is older than [`CLAIM_LEASE`], so only one invocation wins it
+/// and a claim left behind by a timed-out invocation does not block retries.
+/// The table must use `requestId` (string) as partition key and should
+/// enable TTL on `expiresAt`.
+pub struct DynamoDbIdempotencyStore {
+    client: Client,
+    table_name: String,
+}
+
+impl DynamoDbIdempotencyStore {
+    /// Creates a new store for the given table.
+    pub fn new(client: Client, table_name: impl Into<String>) -> Self {
+        Self {
+            client,
+            table_name: table_name.into(),
+        }
+    }
+}
+
+fn epoch_seconds(time: SystemTime) -> AttributeValue {
+    let seconds = time
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    AttributeValue::N(seconds.to_string())
+}
+
+fn expires_at() -> AttributeValue {
+    epoch_seconds(SystemTime::now() + RETENTION)
+}
+
+#[async_trait]
+impl IdempotencyStore for DynamoDbIdempotencyStore {
+    async fn get(&self, key: &str) -> Result<Option<String>, DomainError> {
+        let output = self
+            .client
+            .get_item()
+            .table_name(&self.table_name)
+            .key(KEY_ATTRIBUTE, AttributeValue::S(key.to_string()))
+            .consistent_read(true)
+            .send()
+            .await
+            .map_err(|e| {
+                error!(error = %e, "Failed to read idempotency record");
+                DomainError::RepositoryError(e.to_string())
+            })?;
+
+        let Some(mut item) = output.item else {
+            return Ok(None);
+        };
+        MIGRATIONS.migrate(&mut item)?;
+
+        Ok(item
+            .get(OUTCOME_ATTRIBUTE)
+            .and_then(|value| value.as_s().ok().cloned()))
+    }
+
+    async fn claim(&self, key: &str) -> Result<bool, DomainError> {
+        let now = SystemTime::now();
+        let result = self
+            .client
+            .update_item()
+            .table_name(&self.table_name)
+            .key(KEY_ATTRIBUTE, AttributeValue::S(key.to_string()))
+            .update_expression(
+                "SET #claimedAt = :now, #expiresAt = :expiresAt, #version = :version",
+            )
+            .condition_expression(
+                "attribute_not_exists(#key) OR (attribute_not_exists(#outcome) \
+                 AND (attribute_not_exists(#claimedAt) OR #claimedAt < :stale))",
+            )
+            .expression_attribute_names("#key", KEY_ATTRIBUTE)
+            .expression_attribute_names("#outcome", OUTCOME_ATTRIBUTE)
+            .expression_attribute_names("#claimedAt", CLAIMED_AT_ATTRIBUTE)
+            .expression_attribute_names("#expiresAt", EXPIRES_AT_ATTRIBUTE)
+            .expression_attribute_names("#version", SCHEMA_VERSION_ATTRIBUTE)
+            .expression_attribute_values(":now", epoch_seconds(now))
+            .expression_attribute_values(":stale", epoch_seconds(now - CLAIM_LEASE))
+            .expression_attribute_values(":expiresAt", expires_at())
+            .expression_attribute_values(":version", MIGRATIONS.current_version_value())
+            .send()
+            .await;
+
+        match result {
+            Ok(_) => Ok(true),
+            Err(e)
+                if e.as_service_error()
+                    .is_some_and(|e| e.is_conditional_check_failed_exception()) =>
+            {
+                Ok(false)
+            }
+            Err(e) => {
+                error!(error = %e, "Failed to claim idempotency key");
+                Err(DomainError::RepositoryError(e.to_string()))
+            }
+        }
+    }
+
+    async fn release(&self, key: &str) -> Result<(), DomainError> {
+        self.client
+            .delete_item()
+            .table_name(&self.table_name)
+            .key(KEY_ATTRIBUTE, AttributeValue::S(key.to_string()))
+            .condition_expression("attribute_not_exists(#outcome)")
+            .expression_attribute_names("#outcome", OUTCOME_ATTRIBUTE)
+            .send()
+            .await
+            .map(|_| ())
+            .or_else(|e| {
+                if e.as_service_error()
+                    .is_some_and(|e| e.is_conditional_check_failed_exception())
+                {
+                    // Already recorded; keep the outcome
+                    return Ok(());
+                }
+                error!(error = %e, "Failed to release idempotency key");
+                Err(DomainError::RepositoryError(e.to_string()))
+            })
+    }
+
+    async fn put(&self, key: &str, outcome: &str) -> Result<(), DomainError> {
+        self.client
+            .put_item()
+            .table_name(&self.table_name)
+            .item(KEY_ATTRIBUTE, AttributeValue::S(key.to_string()))
+            .item(OUTCOME_ATTRIBUTE, AttributeValue::S(outcome.to_string()))
+            .item(EXPIRES_AT_ATTRIBUTE, expires_at())
+            .item(SCHEMA_VERSION_ATTRIBUTE, MIGRATIONS.current_version_value())
+            .send()
+            .await
+            .map_err(|e| {
+                error!(error = %e, "Failed to write idempotency record");
+                DomainError::RepositoryError(e.to_string())
+            })?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,80 @@
+use aws_sdk_dynamodb::Client;
+use tracing::error;
+
+use crate::domain::models::DomainError;
+
+use super::migration::{Item, Migrations};
+
+/// Partition key attribute holding the alias ("Paradeiser").
+const ALIAS_ATTRIBUTE: &str = "alias";
+
+/// Attribute holding the canonical item name ("Tomaten").
+const CANONICAL_ATTRIBUTE: &str = "canonical";
+
+/// Schema migrations of the alias table.
+///
+/// The table is maintained by hand and only read, so migrated items are
+/// not written back.
+const MIGRATIONS: Migrations = Migrations::new(&[]);
+
+/// DynamoDB table with item aliases overriding the built-in ones.
+///
+/// Each item maps `alias` (partition key, string) to a `canonical` name.
+/// The table is read once at cold start, so it can be edited in the console
+/// without a deployment.
+pub struct DynamoDbItemAliasTable {
+    client: Client,
+    table_name: String,
+}
+
+impl DynamoDbItemAliasTable {
+    /// Creates a reader for the given table.
+    pub fn new(client: Client, table_name: impl Into<String>) -> Self {
+        Self {
+            client,
+            table_name: table_name.into(),
+        }
+    }
+
+    /// Reads all aliases as (alias, canonical name) pairs.
+    ///
+    /// # Errors
+    /// Returns `DomainError::RepositoryError` if the table cannot be read.
+    pub async fn load(&self) -> Result<Vec<(String, String)>, DomainError> {
+        let mut aliases = Vec::new();
+        let mut start_key = None;
+
+        loop {
+            let output = self
+                .client
+                .scan()
+                .table_name(&self.table_name)
+                .set_exclusive_start_key(start_key)
+                .send()
+                .await
+                .map_err(|e| {
+                    error!(error = %e, "Failed to read item aliases");
+                    DomainError::RepositoryError(e.to_string())
+                })?;
+
+            for mut item in output.items.unwrap_or_default() {
+                MIGRATIONS.migrate(&mut item)?;
+                if let (Some(alias), Some(canonical)) = (
+                    string_attribute(&item, ALIAS_ATTRIBUTE),
+                    string_attribute(&item, CANONICAL_ATTRIBUTE),
+                ) {
+                    aliases.push((alias, canonical));
+                }
+            }
+
+            start_key = output.last_evaluated_key;
+            if start_key.is_none() {
+                return Ok(aliases);
+            }
+        }
+    }
+}
+
+fn string_attribute(item: &Item, name: &str) -> Option<String> {
+    item.get(name).and_then(|value| value.as_s().ok()).cloned()
+}
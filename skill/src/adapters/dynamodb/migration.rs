@@ -0,0 +1,234 @@
+use std::collections::HashMap;
+
+use aws_sdk_dynamodb::types::AttributeValue;
+use thiserror::Error;
+
+use crate::domain::models::DomainError;
+
+/// A DynamoDB item as returned by `GetItem`.
+pub type Item = HashMap<String, AttributeValue>;
+
+/// Attribute holding the schema version of an item.
+pub const SCHEMA_VERSION_ATTRIBUTE: &str = "schemaVersion";
+
+/// Version of items written before schema versions were introduced.
+const UNVERSIONED: u32 = 1;
+
+/// Upgrades an item in place from one schema version to the next.
+pub type MigrationStep = fn(&mut Item) -> Result<(), String>;
+
+/// Errors raised while migrating an item.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum MigrationError {
+    /// The item was written by a newer deployment (e.g. during a rollback)
+    #[error("Item has schema version {found}, newest supported is {supported}")]
+    UnsupportedVersion { found: u32, supported: u32 },
+
+    /// The version attribute is not a valid number
+    #[error("Invalid schema version: {0}")]
+    InvalidVersion(String),
+
+    /// A migration step rejected the item
+    #[error("Migration to version {version} failed: {reason}")]
+    StepFailed { version: u32, reason: String },
+}
+
+impl From<MigrationError> for DomainError {
+    fn from(err: MigrationError) -> Self {
+        DomainError::RepositoryError(err.to_string())
+    }
+}
+
+/// Ordered schema migrations of one table.
+///
+/// Items carry their schema version in `schemaVersion`; items without it
+/// are version 1. Step `n` (zero-based) upgrades version `n + 1` to
+/// `n + 2`, so the current version is one more than the number of steps.
+/// Stores migrate lazily: each item is upgraded when it is read and written
+/// back, so existing users' data is never stranded by a schema change.
+pub struct Migrations {
+    steps: &'static [MigrationStep],
+}
+
+impl Migrations {
+    /// Creates the migrations from their ordered steps.
+    pub const fn new(steps: &'static [MigrationStep]) -> Self {
+        Self { steps }
+    }
+
+    /// Returns the schema version new items are written with.
+    pub fn current_version(&self) -> u32 {
+        UNVERSIONED + self.steps.len() as u32
+    }
+
+    /// Returns the current version as an attribute value for writes.
+    pub fn current_version_value(&self) -> AttributeValue {
+        AttributeValue::N(self.current_version().to_string())
+    }
+
+    /// Returns the schema version of an item.
+    pub fn version_of(&self, item: &Item) -> Result<u32, MigrationError> {
+        match item.get(SCHEMA_VERSION_ATTRIBUTE) {
+            None => Ok(UNVERSIONED),
+            Some(value) => value
+                .as_n()
+                .ok()
+                .and_then(|n| n.parse().ok())
+                .ok_or_else(|| MigrationError::InvalidVersion(format!("{:?}", value))),
+        }
+    }
+
+    /// Upgrades an item to the current version.
+    ///
+    /// # Returns
+    /// The version the item had if it was changed (and must be written
+    /// back), or `None` if it was already current.
+    pub fn migrate(&self, item: &mut Item) -> Result<Option<u32>, MigrationError> {
+        let found = self.version_of(item)?;
+        let current = self.current_version();
+
+        if found > current {
+            return Err(MigrationError::UnsupportedVersion {
+                found,
+                supported: current,
+            });
+        }
+        if found == current && item.contains_key(SCHEMA_VERSION_ATTRIBUTE) {
+            return Ok(None);
+        }
+
+        for (index, step) in self
+            .steps
+            .iter()
+            .enumerate()
+            .skip((found - UNVERSIONED) as usize)
+        {
+            let version = UNVERSIONED + index as u32 + 1;
+            step(item).map_err(|reason| MigrationError::StepFailed { version, reason })?;
+        }
+        item.insert(
+            SCHEMA_VERSION_ATTRIBUTE.to_string(),
+            self.current_version_value(),
+        );
+
+        Ok(Some(found))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// v1 -> v2: rename "name" to "title".
+    fn rename_name(item: &mut Item) -> Result<(), String> {
+        let name = item.remove("name").ok_or("missing name")?;
+        item.insert("title".to_string(), name);
+        Ok(())
+    }
+
+    /// v2 -> v3: add a default "count".
+    fn add_count(item: &mut Item) -> Result<(), String> {
+        item.entry("count".to_string())
+            .or_insert(AttributeValue::N("1".to_string()));
+        Ok(())
+    }
+
+    const MIGRATIONS: Migrations = Migrations::new(&[rename_name, add_count]);
+
+    fn item(pairs: &[(&str, AttributeValue)]) -> Item {
+        pairs
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.clone()))
+            .collect()
+    }
+
+    fn version(n: u32) -> (&'static str, AttributeValue) {
+        (SCHEMA_VERSION_ATTRIBUTE, AttributeValue::N(n.to_string()))
+    }
+
+    #[test]
+    fn current_version_counts_steps() {
+        assert_eq!(Migrations::new(&[]).current_version(), 1);
+        assert_eq!(MIGRATIONS.current_version(), 3);
+    }
+
+    #[test]
+    fn migrates_unversioned_item_through_all_steps() {
+        let mut item = item(&[("name", AttributeValue::S("Milch".to_string()))]);
+
+        let from = MIGRATIONS.migrate(&mut item).unwrap();
+
+        assert_eq!(from, Some(1));
+        assert_eq!(item["title"], AttributeValue::S("Milch".to_string()));
+        assert_eq!(item["count"], AttributeValue::N("1".to_string()));
+        assert_eq!(MIGRATIONS.version_of(&item).unwrap(), 3);
+    }
+
+    #[test]
+    fn runs_only_pending_steps() {
+        let mut item = item(&[
+            ("title", AttributeValue::S("Milch".to_string())),
+            version(2),
+        ]);
+
+        assert_eq!(MIGRATIONS.migrate(&mut item).unwrap(), Some(2));
+        assert!(item.contains_key("count"));
+    }
+
+    #[test]
+    fn current_item_is_unchanged() {
+        let mut item = item(&[
+            ("title", AttributeValue::S("Milch".to_string())),
+            version(3),
+        ]);
+        assert_eq!(MIGRATIONS.migrate(&mut item).unwrap(), None);
+    }
+
+    #[test]
+    fn unversioned_item_is_stamped_without_steps() {
+        let migrations = Migrations::new(&[]);
+        let mut item = item(&[("name", AttributeValue::S("Milch".to_string()))]);
+
+        assert_eq!(migrations.migrate(&mut item).unwrap(), Some(1));
+        assert_eq!(
+            item[SCHEMA_VERSION_ATTRIBUTE],
+            AttributeValue::N("1".to_string())
+        );
+    }
+
+    #[test]
+    fn rejects_newer_versions() {
+        let mut item = item(&[version(4)]);
+        assert_eq!(
+            MIGRATIONS.migrate(&mut item),
+            Err(MigrationError::UnsupportedVersion {
+                found: 4,
+                supported: 3
+            })
+        );
+    }
+
+    #[test]
+    fn reports_failing_step() {
+        let mut item = item(&[]);
+        assert_eq!(
+            MIGRATIONS.migrate(&mut item),
+            Err(MigrationError::StepFailed {
+                version: 2,
+                reason: "missing name".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_version_attribute() {
+        let mut item = item(&[(
+            SCHEMA_VERSION_ATTRIBUTE,
+            AttributeValue::S("two".to_string()),
+        )]);
+        assert!(matches!(
+            MIGRATIONS.migrate(&mut item),
+            Err(MigrationError::InvalidVersion(_))
+        ));
+    }
+}
@@ -0,0 +1,325 @@
+use async_trait::async_trait;
+use aws_sdk_dynamodb::error::SdkError;
+use aws_sdk_dynamodb::operation::update_item::UpdateItemError;
+use aws_sdk_dynamodb::types::AttributeValue;
+use aws_sdk_dynamodb::Client;
+use tracing::{error, info, warn};
+
+use crate::domain::models::{DomainError, ListName, ShoppingListEntry, ShoppingListItem};
+use crate::domain::ports::OccasionListStore;
+
+use super::migration::{Item, Migrations, SCHEMA_VERSION_ATTRIBUTE};
+
+/// Partition key attribute holding the Alexa user ID.
+const USER_ATTRIBUTE: &str = "userId";
+
+/// Sort key attribute holding the normalized list name.
+const LIST_ATTRIBUTE: &str = "listName";
+
+/// Attribute holding the item names as a list of strings.
+const ITEMS_ATTRIBUTE: &str = "items";
+
+/// Schema migrations of the occasion list table; version 1 is the layout
+/// described on [`DynamoDbOccasionListStore`].
+const MIGRATIONS: Migrations = Migrations::new(&[]);
+
+/// DynamoDB-backed store for occasion lists.
+///
+/// Each list is one item keyed by `userId` (partition key, string) and
+/// `listName` (sort key, string), with its entries in an `items` list and
+/// its layout version in `schemaVersion`. Outdated lists are migrated when
+/// they are read; writes only apply to lists in the current layout. A list
+/// stored under its [legacy key](ListName::legacy_key) is moved to its
+/// current key on first use.
+pub struct DynamoDbOccasionListStore {
+    client: Client,
+    table_name: String,
+}
+
+impl DynamoDbOccasionListStore {
+    /// Creates a new store for the given table.
+    pub fn new(client: Client, table_name: impl Into<String>) -> Self {
+        Self {
+            client,
+            table_name: table_name.into(),
+        }
+    }
+
+    async fn load(&self, user_id: &str, list: &ListName) -> Result<Vec<String>, DomainError> {
+        self.adopt_legacy(user_id, list).await?;
+        Ok(self
+            .get(user_id, list.key())
+            .await?
+            .map(|item| item_names(&item))
+            .unwrap_or_default())
+    }
+
+    /// Reads the list stored under `key`, migrated to the current layout.
+    async fn get(&self, user_id: &str, key: &str) -> Result<Option<Item>, DomainError> {
+        let output = self
+            .client
+            .get_item()
+            .table_name(&self.table_name)
+            .key(USER_ATTRIBUTE, AttributeValue::S(user_id.to_string()))
+            .key(LIST_ATTRIBUTE, AttributeValue::S(key.to_string()))
+            .consistent_read(true)
+            .send()
+            .await
+            .map_err(|e| {
+                error!(error = %e, "Failed to read occasion list");
+                DomainError::RepositoryError(e.to_string())
+            })?;
+
+        let Some(mut item) = output.item else {
+            return Ok(None);
+        };
+        if let Some(from) = MIGRATIONS.migrate(&mut item)? {
+            self.write_back(&item, from).await;
+        }
+        Ok(Some(item))
+    }
+
+    /// Moves the entries of a list stored under its legacy key to the
+    /// current key, appending them to entries already stored there.
+    async fn adopt_legacy(&self, user_id: &str, list: &ListName) -> Result<(), DomainError> {
+        let Some(legacy_key) = list.legacy_key() else {
+            return Ok(());
+        };
+        let Some(legacy) = self.get(user_id, &legacy_key).await? else {
+            return Ok(());
+        };
+
+        let entries = item_names(&legacy)
+            .into_iter()
+            .map(AttributeValue::S)
+            .collect();
+        self.client
+            .update_item()
+            .table_name(&self.table_name)
+            .key(USER_ATTRIBUTE, AttributeValue::S(user_id.to_string()))
+            .key(LIST_ATTRIBUTE, AttributeValue::S(list.key().to_string()))
+            .update_expression(
+                "SET #items = list_append(if_not_exists(#items, :empty), :legacy), #version = :version",
+            )
+            .condition_expression("attribute_not_exists(#user) OR #version = :version")
+            .expression_attribute_names("#items", ITEMS_ATTRIBUTE)
+            .expression_attribute_names("#user", USER_ATTRIBUTE)
+            .expression_attribute_names("#version", SCHEMA_VERSION_ATTRIBUTE)
+            .expression_attribute_values(":empty", AttributeValue::L(Vec::new()))
+            .expression_attribute_values(":legacy", AttributeValue::L(entries))
+            .expression_attribute_values(":version", MIGRATIONS.current_version_value())
+            .send()
+            .await
+            .map_err(|e| {
+                error!(error = %e, "Failed to move occasion list to its current key");
+                DomainError::RepositoryError(e.to_string())
+            })?;
+
+        self.client
+            .delete_item()
+            .table_name(&self.table_name)
+            .key(USER_ATTRIBUTE, AttributeValue::S(user_id.to_string()))
+            .key(LIST_ATTRIBUTE, AttributeValue::S(legacy_key))
+            .send()
+            .await
+            .map_err(|e| {
+                error!(error = %e, "Failed to delete occasion list under its legacy key");
+                DomainError::RepositoryError(e.to_string())
+            })?;
+
+        info!(list = %list.key(), "Moved occasion list to its current key");
+        Ok(())
+    }
+
+    /// Stores a migrated list unless it changed since it was read.
+    ///
+    /// Failures are only logged: the list is migrated again on the next read.
+    async fn write_back(&self, item: &Item, from_version: u32) {
+        let result = self
+            .client
+            .put_item()
+            .table_name(&self.table_name)
+            .set_item(Some(item.clone()))
+            .condition_expression("attribute_not_exists(#version) OR #version = :from")
+            .expression_attribute_names("#version", SCHEMA_VERSION_ATTRIBUTE)
+            .expression_attribute_values(":from", AttributeValue::N(from_version.to_string()))
+            .send()
+            .await;
+
+        match result {
+            Ok(_) => info!(
+                from_version,
+                to_version = MIGRATIONS.current_version(),
+                "Migrated occasion list"
+            ),
+            Err(e) => warn!(error = %e, "Failed to store migrated occasion list"),
+        }
+    }
+
+    /// Appends an item name, provided the list is new or in the current layout.
+    async fn append(
+        &self,
+        user_id: &str,
+        list: &ListName,
+        item: &ShoppingListItem,
+    ) -> Result<(), SdkError<UpdateItemError>> {
+        self.client
+            .update_item()
+            .table_name(&self.table_name)
+            .key(USER_ATTRIBUTE, AttributeValue::S(user_id.to_string()))
+            .key(LIST_ATTRIBUTE, AttributeValue::S(list.key().to_string()))
+            .update_expression(
+                "SET #items = list_append(if_not_exists(#items, :empty), :new), #version = :version",
+            )
+            .condition_expression("attribute_not_exists(#user) OR #version = :version")
+            .expression_attribute_names("#items", ITEMS_ATTRIBUTE)
+            .expression_attribute_names("#user", USER_ATTRIBUTE)
+            .expression_attribute_names("#version", SCHEMA_VERSION_ATTRIBUTE)
+            .expression_attribute_values(":empty", AttributeValue::L(Vec::new()))
+            .expression_attribute_values(
+                ":new",
+                AttributeValue::L(vec![AttributeValue::S(item.label())]),
+            )
+            .expression_attribute_values(":version", MIGRATIONS.current_version_value())
+            .send()
+            .await
+            .map(|_| ())
+    }
+}
+
+/// Returns the item names of a stored list.
+fn item_names(item: &Item) -> Vec<String> {
+    item.get(ITEMS_ATTRIBUTE)
+        .and_then(|value| value.as_l().ok())
+        .map(|values| {
+            values
+                .iter()
+                .filter_map(|value| value.as_s().ok().cloned())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[async_trait]
+impl OccasionListStore for DynamoDbOccasionListStore {
+    async fn add(
+        &self,
+        user_id: &str,
+        list: &ListName,
+        item: &ShoppingListItem,
+    ) -> Result<(), DomainError> {
+        self.adopt_legacy(user_id, list).await?;
+        let mut result = self.append(user_id, list, item).await;
+
+        let outdated = result.as_ref().is_err_and(|e| {
+            e.as_service_error()
+                .is_some_and(UpdateItemError::is_conditional_check_failed_exception)
+        });
+        if outdated {
+            // Reading migrates the list to the current layout
+            self.load(user_id, list).await?;
+            result = self.append(user_id, list, item).await;
+        }
+
+        result.map_err(|e| {
+            error!(error = %e, "Failed to add item to occasion list");
+            DomainError::RepositoryError(e.to_string())
+        })
+    }
+
+    async fn items(
+        &self,
+        user_id: &str,
+        list: &ListName,
+    ) -> Result<Vec<ShoppingListEntry>, DomainError> {
+        Ok(self
+            .load(user_id, list)
+            .await?
+            .into_iter()
+            .enumerate()
+            .map(|(index, name)| ShoppingListEntry::new(index.to_string(), name, false))
+            .collect())
+    }
+
+    async fn remove(
+        &self,
+        user_id: &str,
+        list: &ListName,
+        item_name: &str,
+    ) -> Result<bool, DomainError> {
+        let names = self.load(user_id, list).await?;
+        let Some(index) = names.iter().position(|name| {
+            ShoppingListEntry::new("", name.as_str(), false).matches_name(item_name)
+        }) else {
+            return Ok(false);
+        };
+
+        // The condition guards against the list changing between read and
+        // write; a concurrent edit surfaces as an error instead of removing
+        // the wrong entry.
+        self.client
+            .update_item()
+            .table_name(&self.table_name)
+            .key(USER_ATTRIBUTE, AttributeValue::S(user_id.to_string()))
+            .key(LIST_ATTRIBUTE, AttributeValue::S(list.key().to_string()))
+            .update_expression(format!("REMOVE #items[{index}]"))
+            .condition_expression(format!("#items[{index}] = :name AND #version = :version"))
+            .expression_attribute_names("#items", ITEMS_ATTRIBUTE)
+            .expression_attribute_names("#version", SCHEMA_VERSION_ATTRIBUTE)
+            .expression_attribute_values(":name", AttributeValue::S(names[index].clone()))
+            .expression_attribute_values(":version", MIGRATIONS.current_version_value())
+            .send()
+            .await
+            .map_err(|e| {
+                error!(error = %e, "Failed to remove item from occasion list");
+                DomainError::RepositoryError(e.to_string())
+            })?;
+
+        Ok(true)
+    }
+
+    async fn delete(&self, user_id: &str) -> Result<(), DomainError> {
+        let mut start_key = None;
+
+        loop {
+            let output = self
+                .client
+                .query()
+                .table_name(&self.table_name)
+                .key_condition_expression("#user = :user")
+                .projection_expression("#list")
+                .expression_attribute_names("#user", USER_ATTRIBUTE)
+                .expression_attribute_names("#list", LIST_ATTRIBUTE)
+                .expression_attribute_values(":user", AttributeValue::S(user_id.to_string()))
+                .set_exclusive_start_key(start_key)
+                .send()
+                .await
+                .map_err(|e| {
+                    error!(error = %e, "Failed to read occasion lists for deletion");
+                    DomainError::RepositoryError(e.to_string())
+                })?;
+
+            for item in output.items.unwrap_or_default() {
+                let Some(list_name) = item.get(LIST_ATTRIBUTE).cloned() else {
+                    continue;
+                };
+                self.client
+                    .delete_item()
+                    .table_name(&self.table_name)
+                    .key(USER_ATTRIBUTE, AttributeValue::S(user_id.to_string()))
+                    .key(LIST_ATTRIBUTE, list_name)
+                    .send()
+                    .await
+                    .map_err(|e| {
+                        error!(error = %e, "Failed to delete occasion list");
+                        DomainError::RepositoryError(e.to_string())
+                    })?;
+            }
+
+            start_key = output.last_evaluated_key;
+            if start_key.is_none() {
+                return Ok(());
+            }
+        }
+    }
+}
@@ -0,0 +1,125 @@
+use async_trait::async_trait;
+use aws_sdk_dynamodb::types::AttributeValue;
+use aws_sdk_dynamodb::Client;
+use tracing::error;
+
+use crate::domain::models::{DomainError, SkillPermissions};
+use crate::domain::ports::PermissionStore;
+
+use super::migration::{Item, Migrations, SCHEMA_VERSION_ATTRIBUTE};
+
+/// Partition key attribute holding the Alexa user ID.
+const USER_ATTRIBUTE: &str = "userId";
+
+/// Attribute holding the granted scopes as a string set.
+const SCOPES_ATTRIBUTE: &str = "scopes";
+
+/// Attribute holding the subscribed proactive events as a string set.
+const SUBSCRIPTIONS_ATTRIBUTE: &str = "subscriptions";
+
+/// Schema migrations of the permissions table; version 1 is the layout
+/// described on [`DynamoDbPermissionStore`].
+const MIGRATIONS: Migrations = Migrations::new(&[]);
+
+/// DynamoDB-backed store of the permissions users granted to the skill.
+///
+/// Each user's permissions are one item keyed by `userId` (partition key,
+/// string). DynamoDB has no empty sets, so nothing granted leaves the
+/// attribute out.
+pub struct DynamoDbPermissionStore {
+    client: Client,
+    table_name: String,
+}
+
+impl DynamoDbPermissionStore {
+    /// Creates a new store for the given table.
+    pub fn new(client: Client, table_name: impl Into<String>) -> Self {
+        Self {
+            client,
+            table_name: table_name.into(),
+        }
+    }
+}
+
+fn string_set(item: &Item, name: &str) -> Vec<String> {
+    item.get(name)
+        .and_then(|value| value.as_ss().ok())
+        .cloned()
+        .unwrap_or_default()
+}
+
+#[async_trait]
+impl PermissionStore for DynamoDbPermissionStore {
+    async fn get(&self, user_id: &str) -> Result<SkillPermissions, DomainError> {
+        let output = self
+            .client
+            .get_item()
+            .table_name(&self.table_name)
+            .key(USER_ATTRIBUTE, AttributeValue::S(user_id.to_string()))
+            .send()
+            .await
+            .map_err(|e| {
+                error!(error = %e, "Failed to read permissions");
+                DomainError::RepositoryError(e.to_string())
+            })?;
+
+        let Some(mut item) = output.item else {
+            return Ok(SkillPermissions::new());
+        };
+        MIGRATIONS.migrate(&mut item)?;
+
+        let mut permissions = SkillPermissions::new();
+        permissions.set_scopes(string_set(&item, SCOPES_ATTRIBUTE));
+        permissions.set_subscriptions(string_set(&item, SUBSCRIPTIONS_ATTRIBUTE));
+        Ok(permissions)
+    }
+
+    async fn save(&self, user_id: &str, permissions: &SkillPermissions) -> Result<(), DomainError> {
+        let mut item = Item::new();
+        item.insert(
+            USER_ATTRIBUTE.to_string(),
+            AttributeValue::S(user_id.to_string()),
+        );
+        item.insert(
+            SCHEMA_VERSION_ATTRIBUTE.to_string(),
+            MIGRATIONS.current_version_value(),
+        );
+        let scopes: Vec<String> = permissions.scopes().map(str::to_string).collect();
+        if !scopes.is_empty() {
+            item.insert(SCOPES_ATTRIBUTE.to_string(), AttributeValue::Ss(scopes));
+        }
+        let subscriptions: Vec<String> = permissions.subscriptions().map(str::to_string).collect();
+        if !subscriptions.is_empty() {
+            item.insert(
+                SUBSCRIPTIONS_ATTRIBUTE.to_string(),
+                AttributeValue::Ss(subscriptions),
+            );
+        }
+
+        self.client
+            .put_item()
+            .table_name(&self.table_name)
+            .set_item(Some(item))
+            .send()
+            .await
+            .map(|_| ())
+            .map_err(|e| {
+                error!(error = %e, "Failed to save permissions");
+                DomainError::RepositoryError(e.to_string())
+            })
+    }
+
+    async fn delete(&self, user_id: &str) -> Result<(), DomainError> {
+        self.client
+            .delete_item()
+            .table_name(&self.table_name)
+            .key(USER_ATTRIBUTE, AttributeValue::S(user_id.to_string()))
+            .send()
+            .await
+            .map(|_| ())
+            .map_err(|e| {
+                error!(error = %e, "Failed to delete permissions");
+                DomainError::RepositoryError(e.to_string())
+            })
+    }
+}
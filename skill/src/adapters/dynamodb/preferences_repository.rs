@@ -0,0 +1,248 @@
+use async_trait::async_trait;
+use aws_sdk_dynamodb::types::AttributeValue;
+use aws_sdk_dynamodb::Client;
+use tracing::error;
+
+use crate::domain::models::{
+    AccountChoice, DomainError, ItemBundle, ItemBundles, ListName, PantryStaples, SpeechStyle,
+    UserPreferences,
+};
+use crate::domain::ports::PreferencesRepository;
+
+use super::migration::{Item, Migrations, SCHEMA_VERSION_ATTRIBUTE};
+
+/// Partition key attribute holding the Alexa user ID.
+const USER_ATTRIBUTE: &str = "userId";
+
+/// Attribute holding the speech style (`full` or `brief`).
+const SPEECH_STYLE_ATTRIBUTE: &str = "speechStyle";
+
+/// Attribute holding the locale override.
+const LOCALE_ATTRIBUTE: &str = "locale";
+
+/// Attribute holding the spoken name of the default list.
+const DEFAULT_LIST_ATTRIBUTE: &str = "defaultList";
+
+/// Attribute holding the chosen household account; blank for the shared
+/// one.
+const ACCOUNT_ATTRIBUTE: &str = "account";
+
+/// Attribute holding the pantry staples as a string set.
+const PANTRY_ATTRIBUTE: &str = "pantry";
+
+/// Attribute holding the item bundles, a map from bundle name to the list
+/// of its items.
+const BUNDLES_ATTRIBUTE: &str = "bundles";
+
+/// Schema migrations of the preferences table; version 1 is the layout
+/// described on [`DynamoDbPreferencesRepository`].
+const MIGRATIONS: Migrations = Migrations::new(&[]);
+
+/// DynamoDB-backed preferences repository.
+///
+/// Each user's preferences are one item keyed by `userId` (partition key,
+/// string), and each recognized speaker's one keyed `{userId}#{personId}`,
+/// with unset preferences left out and the layout version in
+/// `schemaVersion`. Outdated items are migrated when read; since every save
+/// writes the whole item, they are stored in the current layout on the
+/// next change.
+pub struct DynamoDbPreferencesRepository {
+    client: Client,
+    table_name: String,
+}
+
+impl DynamoDbPreferencesRepository {
+    /// Creates a new repository for the given table.
+    pub fn new(client: Client, table_name: impl Into<String>) -> Self {
+        Self {
+            client,
+            table_name: table_name.into(),
+        }
+    }
+}
+
+fn string_attribute(item: &Item, name: &str) -> Option<String> {
+    item.get(name).and_then(|value| value.as_s().ok()).cloned()
+}
+
+/// Reads the bundles map, ordered by name since DynamoDB maps keep no
+/// order.
+fn bundles_attribute(item: &Item) -> ItemBundles {
+    let Some(map) = item
+        .get(BUNDLES_ATTRIBUTE)
+        .and_then(|value| value.as_m().ok())
+    else {
+        return ItemBundles::new();
+    };
+    let mut names: Vec<&String> = map.keys().collect();
+    names.sort();
+    names.into_iter().fold(ItemBundles::new(), |bundles, name| {
+        let items = map[name]
+            .as_l()
+            .map(|items| items.iter().filter_map(|item| item.as_s().ok()).collect())
+            .unwrap_or_else(|_| Vec::new());
+        bundles.with_bundle(ItemBundle::new(name.as_str(), items))
+    })
+}
+
+#[async_trait]
+impl PreferencesRepository for DynamoDbPreferencesRepository {
+    async fn get(&self, user_id: &str) -> Result<UserPreferences, DomainError> {
+        let output = self
+            .client
+            .get_item()
+            .table_name(&self.table_name)
+            .key(USER_ATTRIBUTE, AttributeValue::S(user_id.to_string()))
+            .send()
+            .await
+            .map_err(|e| {
+                error!(error = %e, "Failed to read preferences");
+                DomainError::RepositoryError(e.to_string())
+            })?;
+
+        let Some(mut item) = output.item else {
+            return Ok(UserPreferences::default());
+        };
+        MIGRATIONS.migrate(&mut item)?;
+
+        let mut preferences = UserPreferences::new();
+        preferences.set_speech_style(
+            string_attribute(&item, SPEECH_STYLE_ATTRIBUTE)
+                .and_then(|value| SpeechStyle::parse(&value)),
+        );
+        preferences.set_locale(string_attribute(&item, LOCALE_ATTRIBUTE));
+        preferences.set_default_list(
+            string_attribute(&item, DEFAULT_LIST_ATTRIBUTE).and_then(|value| ListName::new(&value)),
+        );
+        preferences.set_account(
+            string_attribute(&item, ACCOUNT_ATTRIBUTE).map(|value| AccountChoice::parse(&value)),
+        );
+        preferences.set_pantry(
+            item.get(PANTRY_ATTRIBUTE)
+                .and_then(|value| value.as_ss().ok())
+                .map(PantryStaples::new),
+        );
+        preferences.set_bundles(bundles_attribute(&item));
+        Ok(preferences)
+    }
+
+    async fn save(&self, user_id: &str, preferences: &UserPreferences) -> Result<(), DomainError> {
+        let mut item = Item::new();
+        item.insert(
+            USER_ATTRIBUTE.to_string(),
+            AttributeValue::S(user_id.to_string()),
+        );
+        item.insert(
+            SCHEMA_VERSION_ATTRIBUTE.to_string(),
+            MIGRATIONS.current_version_value(),
+        );
+        if let Some(style) = preferences.speech_style() {
+            item.insert(
+                SPEECH_STYLE_ATTRIBUTE.to_string(),
+                AttributeValue::S(style.as_str().to_string()),
+            );
+        }
+        if let Some(locale) = preferences.locale() {
+            item.insert(
+                LOCALE_ATTRIBUTE.to_string(),
+                AttributeValue::S(locale.to_string()),
+            );
+        }
+        if let Some(list) = preferences.default_list() {
+            item.insert(
+                DEFAULT_LIST_ATTRIBUTE.to_string(),
+                AttributeValue::S(list.label().to_string()),
+            );
+        }
+        if let Some(account) = preferences.account() {
+            item.insert(
+                ACCOUNT_ATTRIBUTE.to_string(),
+                AttributeValue::S(account.as_str().to_string()),
+            );
+        }
+        if let Some(pantry) = preferences.pantry() {
+            item.insert(
+                PANTRY_ATTRIBUTE.to_string(),
+                AttributeValue::Ss(pantry.names().to_vec()),
+            );
+        }
+        if !preferences.bundles().is_empty() {
+            let bundles = preferences
+                .bundles()
+                .bundles()
+                .iter()
+                .map(|bundle| {
+                    let items = bundle
+                        .items()
+                        .iter()
+                        .map(|item| AttributeValue::S(item.clone()))
+                        .collect();
+                    (bundle.name().to_string(), AttributeValue::L(items))
+                })
+                .collect();
+            item.insert(BUNDLES_ATTRIBUTE.to_string(), AttributeValue::M(bundles));
+        }
+
+        self.client
+            .put_item()
+            .table_name(&self.table_name)
+            .set_item(Some(item))
+            .send()
+            .await
+            .map(|_| ())
+            .map_err(|e| {
+                error!(error = %e, "Failed to save preferences");
+                DomainError::RepositoryError(e.to_string())
+            })
+    }
+
+    async fn delete(&self, user_id: &str) -> Result<(), DomainError> {
+        self.client
+            .delete_item()
+            .table_name(&self.table_name)
+            .key(USER_ATTRIBUTE, AttributeValue::S(user_id.to_string()))
+            .send()
+            .await
+            .map(|_| ())
+            .map_err(|e| {
+                error!(error = %e, "Failed to delete preferences");
+                DomainError::RepositoryError(e.to_string())
+            })
+    }
+
+    async fn delete_all(&self, user_id: &str) -> Result<(), DomainError> {
+        self.delete(user_id).await?;
+
+        // Speaker items have their own partition key, so they are found by
+        // scanning for the user's prefix rather than by a query
+        let mut start_key = None;
+        loop {
+            let output = self
+                .client
+                .scan()
+                .table_name(&self.table_name)
+                .filter_expression("begins_with(#user, :prefix)")
+                .projection_expression("#user")
+                .expression_attribute_names("#user", USER_ATTRIBUTE)
+                .expression_attribute_values(":prefix", AttributeValue::S(format!("{}#", user_id)))
+                .set_exclusive_start_key(start_key)
+                .send()
+                .await
+                .map_err(|e| {
+                    error!(error = %e, "Failed to read preferences for deletion");
+                    DomainError::RepositoryError(e.to_string())
+                })?;
+
+            for item in output.items.unwrap_or_default() {
+                if let Some(key) = string_attribute(&item, USER_ATTRIBUTE) {
+                    self.delete(&key).await?;
+                }
+            }
+
+            start_key = output.last_evaluated_key;
+            if start_key.is_none() {
+                return Ok(());
+            }
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use async_trait::async_trait;
+use aws_sdk_dynamodb::types::AttributeValue;
+use aws_sdk_dynamodb::Client;
+use tracing::error;
+
+use crate::domain::models::DomainError;
+use crate::domain::ports::RefreshTokenStore;
+
+/// Partition key attribute naming the stored token.
+const ID_ATTRIBUTE: &str = "id";
+
+/// Key of the skill's Cookidoo refresh token.
+const TOKEN_ID: &str = "cookidoo";
+
+/// Attribute holding the refresh token.
+const TOKEN_ATTRIBUTE: &str = "refreshToken";
+
+/// DynamoDB-backed store of the Cookidoo refresh token.
+///
+/// The token is one item keyed by `id` (partition key, string) with the
+/// value `cookidoo`; `cookidoo-login --store dynamodb:<table>` writes the
+/// same item.
+pub struct DynamoDbRefreshTokenStore {
+    client: Client,
+    table_name: String,
+}
+
+impl DynamoDbRefreshTokenStore {
+    /// Creates a new store for the given table.
+    pub fn new(client: Client, table_name: impl Into<String>) -> Self {
+        Self {
+            client,
+            table_name: table_name.into(),
+        }
+    }
+}
+
+#[async_trait]
+impl RefreshTokenStore for DynamoDbRefreshTokenStore {
+    async fn load(&self) -> Result<Option<String>, DomainError> {
+        let output = self
+            .client
+            .get_item()
+            .table_name(&self.table_name)
+            .key(ID_ATTRIBUTE, AttributeValue::S(TOKEN_ID.to_string()))
+            .consistent_read(true)
+            .send()
+            .await
+            .map_err(|e| {
+                error!(error = %e, "Failed to read refresh token");
+                DomainError::RepositoryError(e.to_string())
+            })?;
+
+        Ok(output
+            .item
+            .as_ref()
+            .and_then(|item| item.get(TOKEN_ATTRIBUTE))
+            .and_then(|value| value.as_s().ok())
+            .filter(|token| !token.trim().is_empty())
+            .map(|token| token.trim().to_string()))
+    }
+
+    async fn save(&self, refresh_token: &str) -> Result<(), DomainError> {
+        self.client
+            .put_item()
+            .table_name(&self.table_name)
+            .item(ID_ATTRIBUTE, AttributeValue::S(TOKEN_ID.to_string()))
+            .item(
+                TOKEN_ATTRIBUTE,
+                AttributeValue::S(refresh_token.to_string()),
+            )
+            .send()
+            .await
+            .map(|_| ())
+            .map_err(|e| {
+                error!(error = %e, "Failed to save refresh token");
+                DomainError::RepositoryError(e.to_string())
+            })
+    }
+}
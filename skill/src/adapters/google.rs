@@ -0,0 +1,9 @@
+mod handler;
+mod intent_parser;
+mod models;
+mod response_builder;
+
+pub use handler::DialogflowHandler;
+pub use intent_parser::parse;
+pub use models::{DialogflowRequest, WebhookResponse};
+pub use response_builder::ResponseBuilder;
@@ -0,0 +1,578 @@
+use std::sync::Arc;
+
+use tracing::info;
+
+use crate::adapters::alexa::{
+    entries_speech, join_names, summary_speech, MessageId, PhraseSelector, Speech,
+};
+use crate::adapters::memory::{InMemoryOccasionListStore, InMemoryPreferencesRepository};
+use crate::adapters::replies::{
+    add_item_speech, add_items_speech, bundle_failure_speech, bundle_speech, failure_speech,
+    occasion_failure_speech, transfer_speech,
+};
+use crate::domain::models::{PantryStaples, ParsedIntent, ShoppingListEntry, VoiceRequest};
+use crate::domain::services::{
+    AddItemService, BundleService, ListSummaryService, ListTransferService, OccasionListService,
+    ReadListService, RemoveItemService,
+};
+
+use super::intent_parser;
+use super::models::{DialogflowRequest, WebhookResponse};
+use super::response_builder::ResponseBuilder;
+
+/// Dialogflow webhook handler for Google Assistant.
+///
+/// Drives the same domain services as the Alexa handler from the same
+/// [`ParsedIntent`]s, so both assistants share one shopping list.
+pub struct DialogflowHandler {
+    add_item_service: Arc<AddItemService>,
+    read_list_service: Arc<ReadListService>,
+    remove_item_service: Arc<RemoveItemService>,
+    occasion_list_service: Arc<OccasionListService>,
+    list_summary_service: Arc<ListSummaryService>,
+    bundle_service: Arc<BundleService>,
+    pantry: PantryStaples,
+}
+
+impl DialogflowHandler {
+    /// Creates a new DialogflowHandler with the given services.
+    ///
+    /// Occasion lists and bundles are kept in in-memory stores unless other
+    /// services are set with [`Self::with_occasion_list_service`] and
+    /// [`Self::with_bundle_service`]. The list summary reads through
+    /// `read_list_service` unless set with
+    /// [`Self::with_list_summary_service`]. Transfers skip no pantry staples
+    /// unless set with [`Self::with_pantry`].
+    pub fn new(
+        add_item_service: Arc<AddItemService>,
+        read_list_service: Arc<ReadListService>,
+        remove_item_service: Arc<RemoveItemService>,
+    ) -> Self {
+        Self {
+            add_item_service,
+            list_summary_service: Arc::new(ListSummaryService::new(read_list_service.clone())),
+            read_list_service,
+            remove_item_service,
+            occasion_list_service: Arc::new(OccasionListService::new(Arc::new(
+                InMemoryOccasionListStore::new(),
+            ))),
+            bundle_service: Arc::new(BundleService::new(Arc::new(
+                InMemoryPreferencesRepository::new(),
+            ))),
+            pantry: PantryStaples::default(),
+        }
+    }
+
+    /// Sets the service used for gift/occasion lists.
+    pub fn with_occasion_list_service(
+        mut self,
+        occasion_list_service: Arc<OccasionListService>,
+    ) -> Self {
+        self.occasion_list_service = occasion_list_service;
+        self
+    }
+
+    /// Sets the service used to summarize the shopping list.
+    pub fn with_list_summary_service(
+        mut self,
+        list_summary_service: Arc<ListSummaryService>,
+    ) -> Self {
+        self.list_summary_service = list_summary_service;
+        self
+    }
+
+    /// Sets the service used for item bundles.
+    pub fn with_bundle_service(mut self, bundle_service: Arc<BundleService>) -> Self {
+        self.bundle_service = bundle_service;
+        self
+    }
+
+    /// Sets the pantry staples skipped when transferring an occasion list.
+    pub fn with_pantry(mut self, pantry: PantryStaples) -> Self {
+        self.pantry = pantry;
+        self
+    }
+
+    /// Handles a Dialogflow webhook request.
+    pub async fn handle(&self, request: DialogflowRequest) -> WebhookResponse {
+        self.handle_voice_request(intent_parser::parse(&request))
+            .await
+    }
+
+    /// Handles a platform-agnostic voice request.
+    pub async fn handle_voice_request(&self, request: VoiceRequest) -> WebhookResponse {
+        info!(
+            intent = request.intent().kind(),
+            "Processing Dialogflow request"
+        );
+
+        let user_id = request.user_id().map(str::to_string);
+        let request_id = request.request_id().to_string();
+
+        match request.into_intent() {
+            ParsedIntent::Launch | ParsedIntent::StartOver => {
+                ResponseBuilder::ask(speech(MessageId::Welcome))
+            }
+
+            ParsedIntent::AddItem {
+                item_name,
+                note,
+                list: Some(list),
+            } => {
+                let Some(user_id) = user_id else {
+                    return ResponseBuilder::tell(speech(MessageId::NoLinkedAccountList));
+                };
+                match self
+                    .occasion_list_service
+                    .add(&user_id, &list, &item_name, note.as_deref())
+                    .await
+                {
+                    Ok(label) => ResponseBuilder::tell(
+                        Speech::new(MessageId::OccasionItemAdded)
+                            .with("item", label)
+                            .with("list", list.label())
+                            .render_default(),
+                    ),
+                    Err(e) => ResponseBuilder::tell(occasion_failure_speech(&e, &list)),
+                }
+            }
+
+            ParsedIntent::AddItem {
+                item_name,
+                note,
+                list: None,
+            } => {
+                let outcome = self
+                    .add_item_service
+                    .execute_once(&request_id, &item_name, note.as_deref())
+                    .await;
+                ResponseBuilder::tell(add_item_speech(&outcome))
+            }
+
+            ParsedIntent::AddItems { item_names } => {
+                let mut outcomes = Vec::with_capacity(item_names.len());
+                for (index, item_name) in item_names.iter().enumerate() {
+                    let idempotency_key = format!("{}#{}", request_id, index);
+                    let outcome = self
+                        .add_item_service
+                        .execute_once(&idempotency_key, item_name, None)
+                        .await;
+                    let stops = outcome.stops_batch();
+                    outcomes.push(outcome);
+                    if stops {
+                        break;
+                    }
+                }
+                ResponseBuilder::tell(add_items_speech(&outcomes, &PhraseSelector::default()))
+            }
+
+            ParsedIntent::AddBundle { name } => {
+                let item_names = match self.bundle_service.items(user_id.as_deref(), &name).await {
+                    Ok(item_names) => item_names,
+                    Err(e) => return ResponseBuilder::tell(bundle_failure_speech(&e)),
+                };
+                let outcomes = self
+                    .add_item_service
+                    .execute_all_once(&request_id, &item_names)
+                    .await;
+                ResponseBuilder::tell(add_items_speech(&outcomes, &PhraseSelector::default()))
+            }
+
+            ParsedIntent::ExtendBundle { name, item_name } => {
+                let Some(user_id) = user_id else {
+                    return ResponseBuilder::tell(speech(MessageId::NoLinkedAccountBundle));
+                };
+                match self
+                    .bundle_service
+                    .extend(&user_id, &name, &item_name)
+                    .await
+                {
+                    Ok(outcome) => ResponseBuilder::tell(bundle_speech(&outcome)),
+                    Err(e) => ResponseBuilder::tell(bundle_failure_speech(&e)),
+                }
+            }
+
+            ParsedIntent::DeleteBundle { name } => {
+                let Some(user_id) = user_id else {
+                    return ResponseBuilder::tell(speech(MessageId::NoLinkedAccountBundle));
+                };
+                match self.bundle_service.delete(&user_id, &name).await {
+                    Ok(outcome) => ResponseBuilder::tell(bundle_speech(&outcome)),
+                    Err(e) => ResponseBuilder::tell(bundle_failure_speech(&e)),
+                }
+            }
+
+            ParsedIntent::ReadBundle { name } => {
+                let Some(user_id) = user_id else {
+                    return ResponseBuilder::tell(speech(MessageId::NoLinkedAccountBundle));
+                };
+                match self
+                    .bundle_service
+                    .describe(&user_id, name.as_deref())
+                    .await
+                {
+                    Ok(outcome) => ResponseBuilder::tell(bundle_speech(&outcome)),
+                    Err(e) => ResponseBuilder::tell(bundle_failure_speech(&e)),
+                }
+            }
+
+            ParsedIntent::ReadList { list: Some(list) } => {
+                let Some(user_id) = user_id else {
+                    return ResponseBuilder::tell(speech(MessageId::NoLinkedAccountList));
+                };
+                match self.occasion_list_service.read(&user_id, &list).await {
+                    Ok(entries) if entries.is_empty() => ResponseBuilder::ask(
+                        Speech::new(MessageId::NamedListEmpty)
+                            .with("list", list.label())
+                            .render_default(),
+                    ),
+                    Ok(entries) => ResponseBuilder::tell(entries_speech(list.label(), &entries)),
+                    Err(e) => ResponseBuilder::tell(occasion_failure_speech(&e, &list)),
+                }
+            }
+
+            ParsedIntent::ReadList { list: None } => match self.read_list_service.execute().await {
+                Ok(entries) if entries.is_empty() => {
+                    ResponseBuilder::ask(speech(MessageId::ListEmpty))
+                }
+                Ok(entries) => ResponseBuilder::tell(entries_speech(
+                    &speech(MessageId::ShoppingListLabel),
+                    &entries,
+                )),
+                Err(e) => ResponseBuilder::tell(failure_speech(&e, MessageId::ReadFailed)),
+            },
+
+            ParsedIntent::ReadOpenItems => match self.read_list_service.open_items().await {
+                Ok(entries) => ResponseBuilder::tell(match entries.as_slice() {
+                    [] => speech(MessageId::NothingOpen),
+                    entries => {
+                        let names: Vec<&str> =
+                            entries.iter().map(ShoppingListEntry::name).collect();
+                        Speech::new(MessageId::OpenEntries)
+                            .counting(names.len())
+                            .with("items", join_names(&names))
+                            .render_default()
+                    }
+                }),
+                Err(e) => ResponseBuilder::tell(failure_speech(&e, MessageId::ReadFailed)),
+            },
+
+            ParsedIntent::CountItems => match self.list_summary_service.execute().await {
+                Ok(summary) => match summary_speech(&summary, &PhraseSelector::default()) {
+                    Some(text) => ResponseBuilder::tell(text),
+                    None => ResponseBuilder::ask(speech(MessageId::ListEmpty)),
+                },
+                Err(e) => ResponseBuilder::tell(failure_speech(&e, MessageId::ReadFailed)),
+            },
+
+            ParsedIntent::TransferList { list } => {
+                let Some(user_id) = user_id else {
+                    return ResponseBuilder::tell(speech(MessageId::NoLinkedAccountList));
+                };
+                let transfer = ListTransferService::new(
+                    self.occasion_list_service.clone(),
+                    self.add_item_service.clone(),
+                )
+                .with_pantry(self.pantry.clone());
+                match transfer.execute(&user_id, &list).await {
+                    Ok(outcome) => ResponseBuilder::tell(transfer_speech(&outcome, &list)),
+                    Err(e) => ResponseBuilder::tell(occasion_failure_speech(&e, &list)),
+                }
+            }
+
+            ParsedIntent::RemoveItem {
+                item_name,
+                list: Some(list),
+            } => {
+                let Some(user_id) = user_id else {
+                    return ResponseBuilder::tell(speech(MessageId::NoLinkedAccountList));
+                };
+                match self
+                    .occasion_list_service
+                    .remove(&user_id, &list, &item_name)
+                    .await
+                {
+                    Ok(item) => ResponseBuilder::tell(
+                        Speech::new(MessageId::OccasionItemRemoved)
+                            .with("item", item)
+                            .with("list", list.label())
+                            .render_default(),
+                    ),
+                    Err(e) => ResponseBuilder::tell(occasion_failure_speech(&e, &list)),
+                }
+            }
+
+            ParsedIntent::RemoveItem {
+                item_name,
+                list: None,
+            } => match self.remove_item_service.execute(&item_name).await {
+                Ok(item) => ResponseBuilder::tell(
+                    Speech::new(MessageId::ItemRemoved)
+                        .with("item", item)
+                        .render_default(),
+                ),
+                Err(e) => ResponseBuilder::tell(failure_speech(&e, MessageId::RemoveFailed)),
+            },
+
+            ParsedIntent::RemoveItemAt { position } => {
+                match self.remove_item_service.remove_at(position).await {
+                    Ok(Some(item)) => ResponseBuilder::tell(
+                        Speech::new(MessageId::ItemRemoved)
+                            .with("item", item)
+                            .render_default(),
+                    ),
+                    Ok(None) => ResponseBuilder::tell(speech(MessageId::NoItemAtPosition)),
+                    Err(e) => ResponseBuilder::tell(failure_speech(&e, MessageId::RemoveFailed)),
+                }
+            }
+
+            // Google has no reminders API for webhooks; the item is still added.
+            ParsedIntent::Remind { item_name, .. } => {
+                let outcome = self
+                    .add_item_service
+                    .execute_once(&request_id, &item_name, None)
+                    .await;
+                let speech = add_item_speech(&outcome);
+                if outcome.is_success() {
+                    ResponseBuilder::tell(
+                        Speech::new(MessageId::NoReminders)
+                            .with("added", speech)
+                            .render_default(),
+                    )
+                } else {
+                    ResponseBuilder::tell(speech)
+                }
+            }
+
+            ParsedIntent::Help => ResponseBuilder::ask(speech(MessageId::Help)),
+
+            ParsedIntent::Cancel | ParsedIntent::Stop => {
+                ResponseBuilder::tell(speech(MessageId::Goodbye))
+            }
+
+            // History, settings and data deletion keep per-user state only
+            // the Alexa skill records
+            ParsedIntent::ReadHistory { .. }
+            | ParsedIntent::ChangeSetting { .. }
+            | ParsedIntent::DeleteMyData => ResponseBuilder::tell(speech(MessageId::AlexaOnly)),
+
+            // Follow-up questions are Dialogflow contexts, not webhook state;
+            // lifecycle events and touch check-offs only come from Alexa
+            ParsedIntent::Repeat
+            | ParsedIntent::Yes
+            | ParsedIntent::No
+            | ParsedIntent::FollowUpItem { .. }
+            | ParsedIntent::CheckOffItem { .. }
+            | ParsedIntent::PermissionsChanged { .. }
+            | ParsedIntent::SubscriptionsChanged { .. }
+            | ParsedIntent::SkillEnabled { .. }
+            | ParsedIntent::SkillDisabled { .. }
+            | ParsedIntent::AccountLinked { .. }
+            | ParsedIntent::Unknown => ResponseBuilder::ask(speech(MessageId::Unknown)),
+        }
+    }
+}
+
+/// Renders a message without values in its first variant.
+fn speech(id: MessageId) -> String {
+    Speech::new(id).render_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::memory::InMemoryShoppingListRepository;
+    use crate::domain::models::ListName;
+
+    fn make_handler() -> DialogflowHandler {
+        let repo = Arc::new(InMemoryShoppingListRepository::new());
+        DialogflowHandler::new(
+            Arc::new(AddItemService::new(repo.clone())),
+            Arc::new(ReadListService::new(repo.clone())),
+            Arc::new(RemoveItemService::new(repo)),
+        )
+    }
+
+    fn add(item_name: &str, list: Option<ListName>) -> ParsedIntent {
+        ParsedIntent::AddItem {
+            item_name: item_name.to_string(),
+            note: None,
+            list,
+        }
+    }
+
+    #[tokio::test]
+    async fn adds_and_reads_items() {
+        let handler = make_handler();
+
+        handler
+            .handle_voice_request(VoiceRequest::new("r1", "de-DE", add("Milch", None)))
+            .await;
+        handler
+            .handle_voice_request(VoiceRequest::new("r2", "de-DE", add("Eier", None)))
+            .await;
+        let response = handler
+            .handle_voice_request(VoiceRequest::new(
+                "r3",
+                "de-DE",
+                ParsedIntent::ReadList { list: None },
+            ))
+            .await;
+
+        assert_eq!(
+            response.fulfillment_text,
+            "Auf deiner Einkaufsliste stehen: Milch und Eier."
+        );
+        assert!(!response.payload.google.expect_user_response);
+    }
+
+    #[tokio::test]
+    async fn empty_list_keeps_conversation_open() {
+        let response = make_handler()
+            .handle_voice_request(VoiceRequest::new(
+                "r1",
+                "de-DE",
+                ParsedIntent::ReadList { list: None },
+            ))
+            .await;
+
+        assert_eq!(response.fulfillment_text, speech(MessageId::ListEmpty));
+        assert!(response.payload.google.expect_user_response);
+    }
+
+    #[tokio::test]
+    async fn occasion_list_requires_user() {
+        let list = ListName::new("Geburtstagsliste");
+        let handler = make_handler();
+
+        let anonymous = handler
+            .handle_voice_request(VoiceRequest::new(
+                "r1",
+                "de-DE",
+                add("Kerzen", list.clone()),
+            ))
+            .await;
+        assert_eq!(
+            anonymous.fulfillment_text,
+            speech(MessageId::NoLinkedAccountList)
+        );
+
+        handler
+            .handle_voice_request(
+                VoiceRequest::new("r2", "de-DE", add("Kerzen", list.clone())).with_user_id("u1"),
+            )
+            .await;
+        let read = handler
+            .handle_voice_request(
+                VoiceRequest::new("r3", "de-DE", ParsedIntent::ReadList { list })
+                    .with_user_id("u1"),
+            )
+            .await;
+        assert_eq!(
+            read.fulfillment_text,
+            "Auf deiner Geburtstagsliste steht: Kerzen."
+        );
+    }
+
+    #[tokio::test]
+    async fn reminder_adds_item_without_reminder() {
+        let response = make_handler()
+            .handle_voice_request(VoiceRequest::new(
+                "r1",
+                "de-DE",
+                ParsedIntent::Remind {
+                    item_name: "Milch".to_string(),
+                    offset: None,
+                },
+            ))
+            .await;
+
+        assert!(response.fulfillment_text.contains("Milch"));
+        assert!(response
+            .fulfillment_text
+            .ends_with("Erinnerungen kann ich hier leider nicht einrichten."));
+    }
+
+    #[tokio::test]
+    async fn adds_several_items_and_counts_them() {
+        let handler = make_handler();
+
+        let added = handler
+            .handle_voice_request(VoiceRequest::new(
+                "r1",
+                "de-DE",
+                ParsedIntent::AddItems {
+                    item_names: vec!["Milch".to_string(), "Eier".to_string()],
+                },
+            ))
+            .await;
+        let counted = handler
+            .handle_voice_request(VoiceRequest::new("r2", "de-DE", ParsedIntent::CountItems))
+            .await;
+
+        assert!(added.fulfillment_text.contains("Milch und Eier"));
+        assert!(counted
+            .fulfillment_text
+            .starts_with("Du hast 2 Artikel auf der Liste"));
+    }
+
+    #[tokio::test]
+    async fn manages_and_adds_bundles() {
+        let handler = make_handler();
+        let request = |id: &str, intent: ParsedIntent| {
+            VoiceRequest::new(id, "de-DE", intent).with_user_id("u1")
+        };
+
+        handler
+            .handle_voice_request(request(
+                "r1",
+                ParsedIntent::ExtendBundle {
+                    name: "Kaffee-Set".to_string(),
+                    item_name: "Milch".to_string(),
+                },
+            ))
+            .await;
+        let added = handler
+            .handle_voice_request(request(
+                "r2",
+                ParsedIntent::AddBundle {
+                    name: "Kaffeeset".to_string(),
+                },
+            ))
+            .await;
+        let read = handler
+            .handle_voice_request(request("r3", ParsedIntent::ReadList { list: None }))
+            .await;
+
+        assert!(added.fulfillment_text.contains("Milch"));
+        assert_eq!(
+            read.fulfillment_text,
+            "Auf deiner Einkaufsliste steht: Milch."
+        );
+    }
+
+    #[tokio::test]
+    async fn alexa_only_features_are_named() {
+        let response = make_handler()
+            .handle_voice_request(VoiceRequest::new("r1", "de-DE", ParsedIntent::DeleteMyData))
+            .await;
+
+        assert_eq!(response.fulfillment_text, speech(MessageId::AlexaOnly));
+    }
+
+    #[tokio::test]
+    async fn welcome_and_goodbye() {
+        let handler = make_handler();
+
+        let welcome = handler
+            .handle_voice_request(VoiceRequest::new("r1", "de-DE", ParsedIntent::Launch))
+            .await;
+        assert!(welcome.payload.google.expect_user_response);
+
+        let goodbye = handler
+            .handle_voice_request(VoiceRequest::new("r2", "de-DE", ParsedIntent::Stop))
+            .await;
+        assert_eq!(goodbye.fulfillment_text, speech(MessageId::Goodbye));
+        assert!(!goodbye.payload.google.expect_user_response);
+    }
+}
@@ -0,0 +1,369 @@
+use std::time::Duration;
+
+use serde_json::Value;
+
+use crate::adapters::alexa::{intent_names as custom_intents, items_intent, slot_names};
+use crate::domain::models::{ListName, ListPosition, ParsedIntent, VoiceRequest};
+use crate::domain::services::ItemNameNormalizer;
+
+use super::models::DialogflowRequest;
+
+/// Display names of Dialogflow's default and system intents.
+///
+/// The skill's own intents use the same names as in the Alexa interaction
+/// model (`AddItemIntent`, ...), and parameters the same names as the Alexa
+/// slots (`Item`, `ListName`, `Duration`).
+pub(crate) mod intent_names {
+    pub const WELCOME: &str = "Default Welcome Intent";
+    pub const FALLBACK: &str = "Default Fallback Intent";
+    pub const HELP: &str = "HelpIntent";
+    pub const CANCEL: &str = "CancelIntent";
+    pub const STOP: &str = "StopIntent";
+}
+
+/// Locale assumed when Dialogflow sends no language code.
+const DEFAULT_LOCALE: &str = "de-DE";
+
+/// Parses a Dialogflow webhook request into a platform-agnostic request.
+pub fn parse(request: &DialogflowRequest) -> VoiceRequest {
+    let query = &request.query_result;
+    let locale = if query.language_code.is_empty() {
+        DEFAULT_LOCALE
+    } else {
+        query.language_code.as_str()
+    };
+
+    let item_name = || {
+        string_parameter(request, slot_names::ITEM)
+            .map(|value| ItemNameNormalizer::normalize(&value, locale))
+            .filter(|value| !value.is_empty())
+    };
+    let list = || {
+        string_parameter(request, slot_names::LIST_NAME)
+            .and_then(|value| ListName::new(&value))
+            .filter(|list| !list.is_shopping_list())
+    };
+
+    let display_name = query
+        .intent
+        .as_ref()
+        .map(|intent| intent.display_name.as_str())
+        .unwrap_or_default();
+
+    let intent = match display_name {
+        intent_names::WELCOME => ParsedIntent::Launch,
+        custom_intents::ADD_ITEM => match item_name() {
+            Some(item_name) => ParsedIntent::AddItem {
+                item_name,
+                note: None,
+                list: list(),
+            },
+            None => ParsedIntent::Unknown,
+        },
+        custom_intents::FREE_FORM_ADD => match string_parameter(request, slot_names::QUERY) {
+            Some(query) => items_intent(&query, locale),
+            None => ParsedIntent::Unknown,
+        },
+        custom_intents::ADD_BUNDLE => match string_parameter(request, slot_names::BUNDLE) {
+            Some(name) => ParsedIntent::AddBundle { name },
+            None => ParsedIntent::Unknown,
+        },
+        custom_intents::EXTEND_BUNDLE => {
+            match (string_parameter(request, slot_names::BUNDLE), item_name()) {
+                (Some(name), Some(item_name)) => ParsedIntent::ExtendBundle { name, item_name },
+                _ => ParsedIntent::Unknown,
+            }
+        }
+        custom_intents::DELETE_BUNDLE => match string_parameter(request, slot_names::BUNDLE) {
+            Some(name) => ParsedIntent::DeleteBundle { name },
+            None => ParsedIntent::Unknown,
+        },
+        custom_intents::READ_BUNDLE => ParsedIntent::ReadBundle {
+            name: string_parameter(request, slot_names::BUNDLE),
+        },
+        custom_intents::READ_LIST => ParsedIntent::ReadList { list: list() },
+        custom_intents::READ_OPEN_ITEMS => ParsedIntent::ReadOpenItems,
+        custom_intents::HOW_MANY_ITEMS => ParsedIntent::CountItems,
+        custom_intents::TRANSFER_LIST => match list() {
+            Some(list) => ParsedIntent::TransferList { list },
+            None => ParsedIntent::Unknown,
+        },
+        custom_intents::REMOVE_ITEM => match [slot_names::POSITION, slot_names::ITEM]
+            .iter()
+            .filter_map(|name| string_parameter(request, name))
+            .find_map(|value| ListPosition::parse(&value))
+        {
+            Some(position) => ParsedIntent::RemoveItemAt { position },
+            None => match item_name() {
+                Some(item_name) => ParsedIntent::RemoveItem {
+                    item_name,
+                    list: list(),
+                },
+                None => ParsedIntent::Unknown,
+            },
+        },
+        custom_intents::REMIND => match item_name() {
+            Some(item_name) => ParsedIntent::Remind {
+                item_name,
+                offset: request
+                    .query_result
+                    .parameters
+                    .get(slot_names::DURATION)
+                    .and_then(parse_duration),
+            },
+            None => ParsedIntent::Unknown,
+        },
+        intent_names::HELP => ParsedIntent::Help,
+        intent_names::CANCEL => ParsedIntent::Cancel,
+        intent_names::STOP => ParsedIntent::Stop,
+        intent_names::FALLBACK => ParsedIntent::Unknown,
+        _ => ParsedIntent::Unknown,
+    };
+
+    let voice_request = VoiceRequest::new(&request.response_id, locale, intent);
+    match request.user_id() {
+        Some(user_id) => voice_request.with_user_id(user_id),
+        None => voice_request,
+    }
+}
+
+/// Returns a non-empty string parameter.
+fn string_parameter(request: &DialogflowRequest, name: &str) -> Option<String> {
+    request
+        .query_result
+        .parameters
+        .get(name)
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string)
+}
+
+/// Parses a `@sys.duration` parameter such as `{"amount": 2, "unit": "h"}`.
+///
+/// Months and years have no fixed length and yield `None`.
+fn parse_duration(value: &Value) -> Option<Duration> {
+    let amount = value.get("amount")?.as_f64()?;
+    let factor = match value.get("unit")?.as_str()? {
+        "s" => 1.0,
+        "min" => 60.0,
+        "h" => 3600.0,
+        "day" => 86400.0,
+        "wk" => 7.0 * 86400.0,
+        _ => return None,
+    };
+
+    let seconds = amount * factor;
+    (seconds >= 1.0).then(|| Duration::from_secs(seconds as u64))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_request(display_name: &str, parameters: Value) -> DialogflowRequest {
+        serde_json::from_value(serde_json::json!({
+            "responseId": "response-123",
+            "session": "projects/test/agent/sessions/session-123",
+            "queryResult": {
+                "queryText": "test",
+                "parameters": parameters,
+                "intent": {
+                    "name": "projects/test/agent/intents/1",
+                    "displayName": display_name
+                },
+                "languageCode": "de"
+            }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn parses_welcome_intent_as_launch() {
+        let request = parse(&make_request(
+            "Default Welcome Intent",
+            serde_json::json!({}),
+        ));
+
+        assert_eq!(request.intent(), &ParsedIntent::Launch);
+        assert_eq!(request.request_id(), "response-123");
+        assert_eq!(request.locale(), "de");
+        assert_eq!(request.user_id(), None);
+    }
+
+    #[test]
+    fn parses_add_item_with_normalized_name() {
+        let request = parse(&make_request(
+            "AddItemIntent",
+            serde_json::json!({"Item": "frische eier", "ListName": ""}),
+        ));
+
+        assert_eq!(
+            request.into_intent(),
+            ParsedIntent::AddItem {
+                item_name: "frische Eier".to_string(),
+                note: None,
+                list: None,
+            }
+        );
+    }
+
+    #[test]
+    fn parses_occasion_list_parameter() {
+        let request = parse(&make_request(
+            "RemoveItemIntent",
+            serde_json::json!({"Item": "Kerzen", "ListName": "Geburtstagsliste"}),
+        ));
+
+        assert_eq!(
+            request.into_intent(),
+            ParsedIntent::RemoveItem {
+                item_name: "Kerzen".to_string(),
+                list: ListName::new("Geburtstagsliste"),
+            }
+        );
+    }
+
+    #[test]
+    fn parses_list_position() {
+        let request = parse(&make_request(
+            "RemoveItemIntent",
+            serde_json::json!({"Item": "den letzten Artikel"}),
+        ));
+
+        assert_eq!(
+            request.into_intent(),
+            ParsedIntent::RemoveItemAt {
+                position: ListPosition::Last
+            }
+        );
+    }
+
+    #[test]
+    fn add_item_without_item_is_unknown() {
+        let request = parse(&make_request(
+            "AddItemIntent",
+            serde_json::json!({"Item": ""}),
+        ));
+        assert_eq!(request.into_intent(), ParsedIntent::Unknown);
+    }
+
+    #[test]
+    fn parses_reminder_duration() {
+        let request = parse(&make_request(
+            "ReminderIntent",
+            serde_json::json!({"Item": "Milch", "Duration": {"amount": 2, "unit": "h"}}),
+        ));
+
+        assert_eq!(
+            request.into_intent(),
+            ParsedIntent::Remind {
+                item_name: "Milch".to_string(),
+                offset: Some(Duration::from_secs(7200)),
+            }
+        );
+    }
+
+    #[test]
+    fn parses_bundle_and_list_intents() {
+        for (name, parameters, expected) in [
+            (
+                "AddBundleIntent",
+                serde_json::json!({"Bundle": "Grill-Set"}),
+                ParsedIntent::AddBundle {
+                    name: "Grill-Set".to_string(),
+                },
+            ),
+            (
+                "ExtendBundleIntent",
+                serde_json::json!({"Bundle": "Grill-Set", "Item": "senf"}),
+                ParsedIntent::ExtendBundle {
+                    name: "Grill-Set".to_string(),
+                    item_name: "Senf".to_string(),
+                },
+            ),
+            (
+                "ReadBundleIntent",
+                serde_json::json!({}),
+                ParsedIntent::ReadBundle { name: None },
+            ),
+            (
+                "HowManyItemsIntent",
+                serde_json::json!({}),
+                ParsedIntent::CountItems,
+            ),
+            (
+                "TransferListIntent",
+                serde_json::json!({"ListName": "Grillliste"}),
+                ParsedIntent::TransferList {
+                    list: ListName::new("Grillliste").unwrap(),
+                },
+            ),
+            (
+                "FreeFormAddIntent",
+                serde_json::json!({"Query": "Milch, Eier und Butter"}),
+                ParsedIntent::AddItems {
+                    item_names: vec![
+                        "Milch".to_string(),
+                        "Eier".to_string(),
+                        "Butter".to_string(),
+                    ],
+                },
+            ),
+        ] {
+            assert_eq!(
+                parse(&make_request(name, parameters)).into_intent(),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn ignores_durations_without_fixed_length() {
+        assert_eq!(
+            parse_duration(&serde_json::json!({"amount": 1, "unit": "mo"})),
+            None
+        );
+        assert_eq!(
+            parse_duration(&serde_json::json!({"amount": 30, "unit": "min"})),
+            Some(Duration::from_secs(1800))
+        );
+    }
+
+    #[test]
+    fn parses_built_in_intents() {
+        for (name, expected) in [
+            ("HelpIntent", ParsedIntent::Help),
+            ("CancelIntent", ParsedIntent::Cancel),
+            ("StopIntent", ParsedIntent::Stop),
+            ("Default Fallback Intent", ParsedIntent::Unknown),
+            ("SomethingElse", ParsedIntent::Unknown),
+        ] {
+            assert_eq!(
+                parse(&make_request(name, serde_json::json!({}))).into_intent(),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn reads_user_id_from_original_request() {
+        let request: DialogflowRequest = serde_json::from_value(serde_json::json!({
+            "responseId": "response-123",
+            "session": "projects/test/agent/sessions/session-123",
+            "queryResult": {
+                "intent": {"displayName": "ReadListIntent"}
+            },
+            "originalDetectIntentRequest": {
+                "source": "google",
+                "payload": {"user": {"userId": "google-user-1"}}
+            }
+        }))
+        .unwrap();
+
+        let request = parse(&request);
+
+        assert_eq!(request.user_id(), Some("google-user-1"));
+        assert_eq!(request.locale(), DEFAULT_LOCALE);
+    }
+}
@@ -0,0 +1,86 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Dialogflow ES (v2) webhook request.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DialogflowRequest {
+    pub response_id: String,
+    /// Session path, e.g. `projects/<project>/agent/sessions/<session>`.
+    pub session: String,
+    pub query_result: QueryResult,
+    #[serde(default)]
+    pub original_detect_intent_request: Option<OriginalDetectIntentRequest>,
+}
+
+impl DialogflowRequest {
+    /// Returns the platform user ID from the original request payload, if the
+    /// integration provides one.
+    pub fn user_id(&self) -> Option<&str> {
+        self.original_detect_intent_request
+            .as_ref()?
+            .payload
+            .get("user")?
+            .get("userId")?
+            .as_str()
+            .filter(|user_id| !user_id.is_empty())
+    }
+}
+
+/// Result of Dialogflow's intent matching.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryResult {
+    #[serde(default)]
+    pub query_text: String,
+    /// Parameter values by name; their shape depends on the entity type.
+    #[serde(default)]
+    pub parameters: HashMap<String, Value>,
+    #[serde(default)]
+    pub intent: Option<MatchedIntent>,
+    #[serde(default)]
+    pub language_code: String,
+}
+
+/// The intent Dialogflow matched.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MatchedIntent {
+    /// Intent path, e.g. `projects/<project>/agent/intents/<uuid>`.
+    #[serde(default)]
+    pub name: String,
+    pub display_name: String,
+}
+
+/// Request as received by Dialogflow from the integration (e.g. Google).
+#[derive(Debug, Deserialize)]
+pub struct OriginalDetectIntentRequest {
+    #[serde(default)]
+    pub source: String,
+    #[serde(default)]
+    pub payload: Value,
+}
+
+/// Dialogflow ES (v2) webhook response.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookResponse {
+    pub fulfillment_text: String,
+    pub payload: ResponsePayload,
+}
+
+/// Platform-specific part of a webhook response.
+#[derive(Debug, Serialize)]
+pub struct ResponsePayload {
+    pub google: GooglePayload,
+}
+
+/// Google Assistant response settings.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GooglePayload {
+    /// Keeps the microphone open for an answer if true.
+    pub expect_user_response: bool,
+}
@@ -0,0 +1,46 @@
+use super::models::{GooglePayload, ResponsePayload, WebhookResponse};
+
+/// Builder for Dialogflow webhook responses.
+pub struct ResponseBuilder;
+
+impl ResponseBuilder {
+    /// Creates a response that ends the conversation.
+    pub fn tell(text: impl Into<String>) -> WebhookResponse {
+        Self::build(text.into(), false)
+    }
+
+    /// Creates a response that keeps the conversation open for an answer.
+    pub fn ask(text: impl Into<String>) -> WebhookResponse {
+        Self::build(text.into(), true)
+    }
+
+    fn build(text: String, expect_user_response: bool) -> WebhookResponse {
+        WebhookResponse {
+            fulfillment_text: text,
+            payload: ResponsePayload {
+                google: GooglePayload {
+                    expect_user_response,
+                },
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serializes_in_dialogflow_format() {
+        let json = serde_json::to_value(ResponseBuilder::ask("Was noch?")).unwrap();
+
+        assert_eq!(json["fulfillmentText"], "Was noch?");
+        assert_eq!(json["payload"]["google"]["expectUserResponse"], true);
+    }
+
+    #[test]
+    fn tell_ends_conversation() {
+        let response = ResponseBuilder::tell("Tschüss");
+        assert!(!response.payload.google.expect_user_response);
+    }
+}
@@ -0,0 +1,9 @@
+mod endpoint;
+mod home_assistant;
+mod server;
+mod signature;
+
+pub use endpoint::AlexaEndpoint;
+pub use home_assistant::HomeAssistantApi;
+pub use server::{serve, tls_acceptor};
+pub use signature::{SignatureError, SignatureVerifier, CERT_CHAIN_URL_HEADER, SIGNATURE_HEADER};
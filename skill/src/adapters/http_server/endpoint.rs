@@ -0,0 +1,139 @@
+use std::sync::Arc;
+
+use axum::body::Bytes;
+use axum::extract::State;
+use axum::http::header::CONTENT_TYPE;
+use axum::http::{HeaderMap, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::routing::post;
+use axum::Router;
+use serde_json::Value;
+use tracing::warn;
+
+use crate::adapters::alexa::AlexaSkillHandler;
+use crate::application::handle_payload;
+
+use super::home_assistant::HomeAssistantApi;
+use super::signature::{SignatureVerifier, CERT_CHAIN_URL_HEADER, SIGNATURE_HEADER};
+
+/// HTTPS endpoint for Alexa requests outside AWS Lambda.
+///
+/// Accepts `POST /` and `POST /alexa`, rejects requests for another skill
+/// than the configured one, verifies the request signature and hands the
+/// payload to the same core path as the Lambda runtime. With a Home
+/// Assistant API set, `/api/items` is routed to it.
+pub struct AlexaEndpoint {
+    handler: Arc<AlexaSkillHandler>,
+    verifier: SignatureVerifier,
+    skill_id: String,
+    home_assistant: Option<HomeAssistantApi>,
+}
+
+impl AlexaEndpoint {
+    /// Creates a new endpoint answering requests for the skill with the
+    /// given ID and verifying them with the given verifier.
+    pub fn new(
+        handler: Arc<AlexaSkillHandler>,
+        verifier: SignatureVerifier,
+        skill_id: impl Into<String>,
+    ) -> Self {
+        Self {
+            handler,
+            verifier,
+            skill_id: skill_id.into(),
+            home_assistant: None,
+        }
+    }
+
+    /// Serves the given Home Assistant API under `/api/items`.
+    pub fn with_home_assistant_api(mut self, api: HomeAssistantApi) -> Self {
+        self.home_assistant = Some(api);
+        self
+    }
+
+    /// Returns the router serving the endpoint.
+    pub fn router(self) -> Router {
+        let Self {
+            handler,
+            verifier,
+            skill_id,
+            home_assistant,
+        } = self;
+
+        let alexa = Router::new()
+            .route("/", post(alexa))
+            .route("/alexa", post(alexa))
+            .with_state(Arc::new(Alexa {
+                handler,
+                verifier,
+                skill_id,
+            }));
+        let router = match home_assistant {
+            Some(api) => alexa.merge(Arc::new(api).router()),
+            None => alexa,
+        };
+
+        router
+            .fallback(|| async { (StatusCode::NOT_FOUND, "Not found") })
+            .method_not_allowed_fallback(|| async {
+                (StatusCode::METHOD_NOT_ALLOWED, "Method not allowed")
+            })
+    }
+}
+
+/// State of the Alexa routes.
+struct Alexa {
+    handler: Arc<AlexaSkillHandler>,
+    verifier: SignatureVerifier,
+    skill_id: String,
+}
+
+/// Returns whether the request names the skill with the given ID.
+///
+/// Every skill's requests are signed by Amazon, so a valid signature alone
+/// does not tell that the request is meant for this skill. Requests name
+/// their skill in the session, the context or both; every ID present must
+/// match and at least one must be present.
+fn is_for_skill(payload: &Value, skill_id: &str) -> bool {
+    let ids = [
+        payload.pointer("/session/application/applicationId"),
+        payload.pointer("/context/System/application/applicationId"),
+    ];
+    ids.iter().any(Option::is_some) && ids.iter().flatten().all(|id| id.as_str() == Some(skill_id))
+}
+
+async fn alexa(State(alexa): State<Arc<Alexa>>, headers: HeaderMap, body: Bytes) -> Response {
+    let payload: Value = match serde_json::from_slice(&body) {
+        Ok(payload) => payload,
+        Err(e) => {
+            warn!(error = %e, "Invalid Alexa request");
+            return (StatusCode::BAD_REQUEST, "Invalid Alexa request").into_response();
+        }
+    };
+    // Checked first, so requests for other skills fetch no certificates
+    if !is_for_skill(&payload, &alexa.skill_id) {
+        warn!("Rejected Alexa request for another skill");
+        return (StatusCode::FORBIDDEN, "Unknown skill").into_response();
+    }
+
+    let header = |name: &str| headers.get(name).and_then(|value| value.to_str().ok());
+    if let Err(e) = alexa
+        .verifier
+        .verify(
+            header(CERT_CHAIN_URL_HEADER),
+            header(SIGNATURE_HEADER),
+            &body,
+        )
+        .await
+    {
+        warn!(error = %e, "Rejected Alexa request");
+        return (StatusCode::BAD_REQUEST, "Request verification failed").into_response();
+    }
+
+    let response = handle_payload(payload, &alexa.handler).await;
+    (
+        [(CONTENT_TYPE, "application/json;charset=UTF-8")],
+        response.to_string(),
+    )
+        .into_response()
+}
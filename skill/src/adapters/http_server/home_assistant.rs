@@ -0,0 +1,200 @@
+use std::sync::Arc;
+
+use axum::body::Bytes;
+use axum::extract::State;
+use axum::http::header::{AUTHORIZATION, WWW_AUTHENTICATE};
+use axum::http::{HeaderMap, HeaderValue, StatusCode};
+use axum::response::Response;
+use axum::routing::post;
+use axum::Router;
+use tracing::{info, warn};
+
+use crate::adapters::web::{self, error};
+use crate::domain::models::Secret;
+use crate::domain::services::AddItemService;
+
+/// Bearer-token protected REST endpoint for Home Assistant.
+///
+/// `POST /api/items` with `{"name": "Milch"}` adds an item through the same
+/// service as the voice intents, e.g. from a Home Assistant `rest_command`.
+pub struct HomeAssistantApi {
+    add_item_service: Arc<AddItemService>,
+    token: Secret,
+}
+
+impl HomeAssistantApi {
+    /// Creates a new API accepting requests carrying `Bearer <token>`.
+    ///
+    /// Returns `None` for an empty token, so the endpoint cannot be enabled
+    /// without authentication.
+    pub fn new(add_item_service: Arc<AddItemService>, token: impl Into<Secret>) -> Option<Self> {
+        let token = token.into();
+        if normalize(token.expose()).is_empty() {
+            return None;
+        }
+        Some(Self {
+            add_item_service,
+            token,
+        })
+    }
+
+    /// Returns the router serving `POST /api/items`.
+    pub fn router(self: Arc<Self>) -> Router {
+        Router::new()
+            .route("/api/items", post(add_item))
+            .with_state(self)
+    }
+
+    fn is_authorized(&self, headers: &HeaderMap) -> bool {
+        headers
+            .get(AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .is_some_and(|token| {
+                constant_time_eq(
+                    normalize(token).as_bytes(),
+                    normalize(self.token.expose()).as_bytes(),
+                )
+            })
+    }
+}
+
+/// Strips the whitespace around a token, applied alike to the configured
+/// and the presented one.
+fn normalize(token: &str) -> &str {
+    token.trim()
+}
+
+async fn add_item(
+    State(api): State<Arc<HomeAssistantApi>>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Response {
+    if !api.is_authorized(&headers) {
+        warn!("Rejected Home Assistant request with invalid token");
+        let mut response = error(StatusCode::UNAUTHORIZED, "Invalid bearer token");
+        response
+            .headers_mut()
+            .insert(WWW_AUTHENTICATE, HeaderValue::from_static("Bearer"));
+        return response;
+    }
+
+    info!("Handling Home Assistant add item request");
+    web::add_item(&api.add_item_service, &body).await
+}
+
+/// Compares two byte strings in time independent of where they differ.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |diff, (x, y)| diff | (x ^ y)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::{to_bytes, Body};
+    use axum::http::{Method, Request};
+    use tower::ServiceExt;
+
+    use crate::adapters::memory::InMemoryShoppingListRepository;
+
+    fn make_api() -> (Router, Arc<InMemoryShoppingListRepository>) {
+        let repo = Arc::new(InMemoryShoppingListRepository::new());
+        let api =
+            HomeAssistantApi::new(Arc::new(AddItemService::new(repo.clone())), "secret").unwrap();
+        (Arc::new(api).router(), repo)
+    }
+
+    fn bearer(token: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            AUTHORIZATION,
+            HeaderValue::from_str(&format!("Bearer {}", token)).unwrap(),
+        );
+        headers
+    }
+
+    async fn send(
+        router: &Router,
+        method: Method,
+        headers: HeaderMap,
+        body: &'static [u8],
+    ) -> Response {
+        let mut request = Request::builder()
+            .method(method)
+            .uri("/api/items")
+            .body(Body::from(body))
+            .unwrap();
+        *request.headers_mut() = headers;
+        router.clone().oneshot(request).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn adds_item_with_valid_token() {
+        let (api, repo) = make_api();
+
+        let response = send(
+            &api,
+            Method::POST,
+            bearer("secret"),
+            br#"{"name": "Milch"}"#,
+        )
+        .await;
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert!(body["message"].as_str().unwrap().contains("Milch"));
+        assert_eq!(repo.items()[0].name(), "Milch");
+    }
+
+    #[tokio::test]
+    async fn rejects_missing_or_wrong_token() {
+        let (api, repo) = make_api();
+
+        for headers in [HeaderMap::new(), bearer("wrong"), bearer("secre")] {
+            let response = send(&api, Method::POST, headers, br#"{"name": "Milch"}"#).await;
+            assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+            assert_eq!(response.headers()[WWW_AUTHENTICATE], "Bearer");
+        }
+        assert!(repo.items().is_empty());
+    }
+
+    #[tokio::test]
+    async fn ignores_whitespace_around_configured_token() {
+        let repo = Arc::new(InMemoryShoppingListRepository::new());
+        let api = HomeAssistantApi::new(
+            Arc::new(AddItemService::new(repo.clone())),
+            Secret::new(" secret\n"),
+        )
+        .unwrap();
+        let api = Arc::new(api).router();
+
+        let response = send(
+            &api,
+            Method::POST,
+            bearer("secret"),
+            br#"{"name": "Milch"}"#,
+        )
+        .await;
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+        assert_eq!(repo.items().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn rejects_invalid_body_and_method() {
+        let (api, _) = make_api();
+
+        let invalid = send(&api, Method::POST, bearer("secret"), b"Milch").await;
+        assert_eq!(invalid.status(), StatusCode::BAD_REQUEST);
+
+        let get = send(&api, Method::GET, bearer("secret"), b"").await;
+        assert_eq!(get.status(), StatusCode::METHOD_NOT_ALLOWED);
+    }
+
+    #[test]
+    fn requires_non_empty_token() {
+        let repo = Arc::new(InMemoryShoppingListRepository::new());
+        assert!(HomeAssistantApi::new(Arc::new(AddItemService::new(repo)), " ").is_none());
+    }
+}
@@ -0,0 +1,102 @@
+use std::io;
+use std::net::SocketAddr;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+
+use axum::serve::Listener;
+use axum::Router;
+use rustls_pki_types::pem::PemObject;
+use rustls_pki_types::{CertificateDer, PrivateKeyDer};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::task::JoinSet;
+use tokio_rustls::rustls::{self, ServerConfig};
+use tokio_rustls::server::TlsStream;
+use tokio_rustls::TlsAcceptor;
+use tracing::warn;
+
+use crate::adapters::web;
+
+/// How long a client may take to complete the TLS handshake.
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Builds a TLS acceptor from PEM certificate chain and private key files.
+pub fn tls_acceptor(cert_path: &Path, key_path: &Path) -> io::Result<TlsAcceptor> {
+    let certs = CertificateDer::pem_file_iter(cert_path)
+        .and_then(|certs| certs.collect::<Result<Vec<_>, _>>())
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    let key = PrivateKeyDer::from_pem_file(key_path)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    let config = ServerConfig::builder_with_provider(Arc::new(
+        rustls::crypto::aws_lc_rs::default_provider(),
+    ))
+    .with_safe_default_protocol_versions()
+    .and_then(|builder| builder.with_no_client_auth().with_single_cert(certs, key))
+    .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+    Ok(TlsAcceptor::from(Arc::new(config)))
+}
+
+/// Serves the router on the given listener until it fails, terminating TLS
+/// if an acceptor is given.
+///
+/// Without an acceptor the endpoint speaks plain HTTP, for use behind a
+/// TLS-terminating reverse proxy.
+pub async fn serve(
+    listener: TcpListener,
+    tls: Option<TlsAcceptor>,
+    router: Router,
+) -> io::Result<()> {
+    match tls {
+        Some(acceptor) => {
+            let listener = TlsListener {
+                listener,
+                acceptor,
+                handshakes: JoinSet::new(),
+            };
+            web::serve(listener, router).await
+        }
+        None => web::serve(listener, router).await,
+    }
+}
+
+/// Listener yielding connections whose TLS handshake completed.
+///
+/// Handshakes run concurrently, so a slow client does not hold up the
+/// connections accepted after it.
+struct TlsListener {
+    listener: TcpListener,
+    acceptor: TlsAcceptor,
+    handshakes: JoinSet<(io::Result<TlsStream<TcpStream>>, SocketAddr)>,
+}
+
+impl Listener for TlsListener {
+    type Io = TlsStream<TcpStream>;
+    type Addr = SocketAddr;
+
+    async fn accept(&mut self) -> (Self::Io, Self::Addr) {
+        loop {
+            tokio::select! {
+                (stream, peer) = Listener::accept(&mut self.listener) => {
+                    let acceptor = self.acceptor.clone();
+                    self.handshakes.spawn(async move {
+                        let stream = tokio::time::timeout(HANDSHAKE_TIMEOUT, acceptor.accept(stream))
+                            .await
+                            .unwrap_or_else(|_| Err(io::ErrorKind::TimedOut.into()));
+                        (stream, peer)
+                    });
+                }
+                Some(handshake) = self.handshakes.join_next() => match handshake {
+                    Ok((Ok(stream), peer)) => return (stream, peer),
+                    Ok((Err(e), peer)) => warn!(peer = %peer, error = %e, "TLS handshake failed"),
+                    Err(e) => warn!(error = %e, "TLS handshake task failed"),
+                },
+            }
+        }
+    }
+
+    fn local_addr(&self) -> io::Result<Self::Addr> {
+        self.listener.local_addr()
+    }
+}
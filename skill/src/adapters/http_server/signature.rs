@@ -0,0 +1,391 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use reqwest::Url;
+use rustls_pki_types::pem::PemObject;
+use rustls_pki_types::{CertificateDer, ServerName, UnixTime};
+use thiserror::Error;
+use tracing::info;
+use webpki::{EndEntityCert, KeyUsage};
+
+use crate::domain::models::days_from_civil;
+
+/// Header carrying the URL of the signing certificate chain.
+pub const CERT_CHAIN_URL_HEADER: &str = "SignatureCertChainUrl";
+
+/// Header carrying the base64-encoded SHA-256 signature of the body.
+pub const SIGNATURE_HEADER: &str = "Signature-256";
+
+/// Host every signing certificate chain must be downloaded from.
+const CERT_HOST: &str = "s3.amazonaws.com";
+
+/// Path prefix every signing certificate chain must live under.
+const CERT_PATH_PREFIX: &str = "/echo.api/";
+
+/// Subject alternative name the signing certificate must carry.
+const SIGNING_CERT_SAN: &str = "echo-api.amazon.com";
+
+/// Largest accepted difference between request timestamp and now.
+const MAX_TIMESTAMP_SKEW: Duration = Duration::from_secs(150);
+
+/// Errors when an Alexa request fails signature verification.
+#[derive(Debug, Error)]
+pub enum SignatureError {
+    #[error("Missing header: {0}")]
+    MissingHeader(&'static str),
+
+    #[error("Invalid certificate chain URL: {0}")]
+    InvalidCertificateUrl(String),
+
+    #[error("Failed to download certificate chain: {0}")]
+    CertificateDownload(String),
+
+    #[error("Invalid signing certificate: {0}")]
+    InvalidCertificate(String),
+
+    #[error("Signature does not match the request body")]
+    InvalidSignature,
+
+    #[error("Invalid request timestamp: {0}")]
+    InvalidTimestamp(String),
+}
+
+/// Verifies that requests were sent by Alexa, as required for skills
+/// hosted outside AWS Lambda.
+///
+/// Checks the certificate chain URL, validates the downloaded chain against
+/// the trusted roots, checks the body signature with the signing
+/// certificate and rejects requests older than 150 seconds. Downloaded
+/// chains are cached per URL; they are re-validated on every request.
+pub struct SignatureVerifier {
+    client: reqwest::Client,
+    roots: Vec<CertificateDer<'static>>,
+    cert_endpoint: Option<String>,
+    chains: Mutex<HashMap<String, Vec<CertificateDer<'static>>>>,
+}
+
+impl SignatureVerifier {
+    /// Creates a verifier trusting the Mozilla root certificates.
+    pub fn new() -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            roots: webpki_root_certs::TLS_SERVER_ROOT_CERTS.to_vec(),
+            cert_endpoint: None,
+            chains: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Replaces the trusted root certificates with the given PEM bundle.
+    pub fn with_root_certificates(mut self, pem: &[u8]) -> Result<Self, SignatureError> {
+        self.roots = parse_pem_chain(pem)?;
+        Ok(self)
+    }
+
+    /// Downloads certificate chains from `endpoint` (keeping the validated
+    /// path) instead of Amazon S3 (for testing).
+    pub fn with_cert_endpoint(mut self, endpoint: impl Into<String>) -> Self {
+        self.cert_endpoint = Some(endpoint.into());
+        self
+    }
+
+    /// Verifies a request body against its signature headers.
+    pub async fn verify(
+        &self,
+        cert_chain_url: Option<&str>,
+        signature: Option<&str>,
+        body: &[u8],
+    ) -> Result<(), SignatureError> {
+        self.verify_at(cert_chain_url, signature, body, SystemTime::now())
+            .await
+    }
+
+    /// Verifies a request body as of the given time.
+    pub async fn verify_at(
+        &self,
+        cert_chain_url: Option<&str>,
+        signature: Option<&str>,
+        body: &[u8],
+        now: SystemTime,
+    ) -> Result<(), SignatureError> {
+        let cert_chain_url =
+            cert_chain_url.ok_or(SignatureError::MissingHeader(CERT_CHAIN_URL_HEADER))?;
+        let signature = signature.ok_or(SignatureError::MissingHeader(SIGNATURE_HEADER))?;
+
+        let url = validate_cert_url(cert_chain_url)?;
+        let chain = self.certificate_chain(&url).await?;
+        let (signing_cert, intermediates) = chain
+            .split_first()
+            .ok_or_else(|| SignatureError::InvalidCertificate("empty chain".to_string()))?;
+
+        let signing_cert = EndEntityCert::try_from(signing_cert)
+            .map_err(|e| SignatureError::InvalidCertificate(e.to_string()))?;
+        self.validate_certificate(&signing_cert, intermediates, now)?;
+
+        let signature = STANDARD
+            .decode(signature.trim())
+            .map_err(|_| SignatureError::InvalidSignature)?;
+        signing_cert
+            .verify_signature(
+                webpki::aws_lc_rs::RSA_PKCS1_2048_8192_SHA256,
+                body,
+                &signature,
+            )
+            .map_err(|_| SignatureError::InvalidSignature)?;
+
+        check_timestamp(body, now)
+    }
+
+    fn validate_certificate(
+        &self,
+        signing_cert: &EndEntityCert<'_>,
+        intermediates: &[CertificateDer<'static>],
+        now: SystemTime,
+    ) -> Result<(), SignatureError> {
+        let anchors: Vec<_> = self
+            .roots
+            .iter()
+            .filter_map(|root| webpki::anchor_from_trusted_cert(root).ok())
+            .collect();
+        let time = UnixTime::since_unix_epoch(now.duration_since(UNIX_EPOCH).unwrap_or_default());
+
+        signing_cert
+            .verify_for_usage(
+                webpki::ALL_VERIFICATION_ALGS,
+                &anchors,
+                intermediates,
+                time,
+                KeyUsage::server_auth(),
+                None,
+                None,
+            )
+            .map_err(|e| SignatureError::InvalidCertificate(e.to_string()))?;
+
+        let san = ServerName::try_from(SIGNING_CERT_SAN).expect("valid DNS name");
+        signing_cert
+            .verify_is_valid_for_subject_name(&san)
+            .map_err(|e| SignatureError::InvalidCertificate(e.to_string()))
+    }
+
+    /// Returns the chain at the validated URL, downloading it on first use.
+    async fn certificate_chain(
+        &self,
+        url: &Url,
+    ) -> Result<Vec<CertificateDer<'static>>, SignatureError> {
+        if let Some(chain) = self.lock_chains().get(url.as_str()) {
+            return Ok(chain.clone());
+        }
+
+        let download_url = match &self.cert_endpoint {
+            Some(endpoint) => format!("{}{}", endpoint, url.path()),
+            None => url.to_string(),
+        };
+        info!(url = %url, "Downloading Alexa signing certificate chain");
+
+        let response = self
+            .client
+            .get(&download_url)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| SignatureError::CertificateDownload(e.to_string()))?;
+        let pem = response
+            .bytes()
+            .await
+            .map_err(|e| SignatureError::CertificateDownload(e.to_string()))?;
+
+        let chain = parse_pem_chain(&pem)?;
+        self.lock_chains().insert(url.to_string(), chain.clone());
+        Ok(chain)
+    }
+
+    fn lock_chains(
+        &self,
+    ) -> std::sync::MutexGuard<'_, HashMap<String, Vec<CertificateDer<'static>>>> {
+        self.chains.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Default for SignatureVerifier {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Checks the certificate chain URL as required by Alexa: `https`, host
+/// `s3.amazonaws.com`, port 443 and a normalized path under `/echo.api/`.
+fn validate_cert_url(value: &str) -> Result<Url, SignatureError> {
+    let invalid = || SignatureError::InvalidCertificateUrl(value.to_string());
+    let url = Url::parse(value).map_err(|_| invalid())?;
+
+    let host_matches = url
+        .host_str()
+        .is_some_and(|host| host.eq_ignore_ascii_case(CERT_HOST));
+    if url.scheme() != "https"
+        || !host_matches
+        || url.port_or_known_default() != Some(443)
+        || !url.path().starts_with(CERT_PATH_PREFIX)
+    {
+        return Err(invalid());
+    }
+    Ok(url)
+}
+
+fn parse_pem_chain(pem: &[u8]) -> Result<Vec<CertificateDer<'static>>, SignatureError> {
+    let chain = CertificateDer::pem_slice_iter(pem)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| SignatureError::InvalidCertificate(e.to_string()))?;
+    if chain.is_empty() {
+        return Err(SignatureError::InvalidCertificate(
+            "no certificates found".to_string(),
+        ));
+    }
+    Ok(chain)
+}
+
+/// Rejects requests whose `request.timestamp` is more than 150 seconds away
+/// from `now`, protecting against replayed requests.
+fn check_timestamp(body: &[u8], now: SystemTime) -> Result<(), SignatureError> {
+    let payload: serde_json::Value = serde_json::from_slice(body)
+        .map_err(|e| SignatureError::InvalidTimestamp(e.to_string()))?;
+    let timestamp = payload["request"]["timestamp"]
+        .as_str()
+        .ok_or_else(|| SignatureError::InvalidTimestamp("missing".to_string()))?;
+    let sent = parse_timestamp(timestamp)
+        .ok_or_else(|| SignatureError::InvalidTimestamp(timestamp.to_string()))?;
+
+    let skew = now.duration_since(sent).unwrap_or_else(|e| e.duration());
+    if skew > MAX_TIMESTAMP_SKEW {
+        return Err(SignatureError::InvalidTimestamp(format!(
+            "{} is {}s away from now",
+            timestamp,
+            skew.as_secs()
+        )));
+    }
+    Ok(())
+}
+
+/// Parses an ISO 8601 UTC timestamp such as `2024-01-27T10:00:00Z`
+/// (fractional seconds are ignored).
+fn parse_timestamp(value: &str) -> Option<SystemTime> {
+    let value = value.strip_suffix('Z')?;
+    let (date, time) = value.split_once('T')?;
+    let time = time.split('.').next()?;
+
+    let mut date = date.splitn(3, '-').map(str::parse::<i64>);
+    let (year, month, day) = (date.next()?.ok()?, date.next()?.ok()?, date.next()?.ok()?);
+    let mut time = time.splitn(3, ':').map(str::parse::<u64>);
+    let (hour, minute, second) = (time.next()?.ok()?, time.next()?.ok()?, time.next()?.ok()?);
+
+    if !(1..=12).contains(&month)
+        || !(1..=31).contains(&day)
+        || hour > 23
+        || minute > 59
+        || second > 60
+    {
+        return None;
+    }
+
+    let days = u64::try_from(days_from_civil(year, month, day)).ok()?;
+    let seconds = days * 86_400 + hour * 3_600 + minute * 60 + second;
+    Some(UNIX_EPOCH + Duration::from_secs(seconds))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_valid_cert_urls() {
+        for url in [
+            "https://s3.amazonaws.com/echo.api/echo-api-cert.pem",
+            "https://s3.amazonaws.com:443/echo.api/echo-api-cert.pem",
+            "https://S3.AMAZONAWS.COM/echo.api/echo-api-cert.pem",
+            "https://s3.amazonaws.com/echo.api/../echo.api/echo-api-cert.pem",
+        ] {
+            assert!(validate_cert_url(url).is_ok(), "{url} should be valid");
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_cert_urls() {
+        for url in [
+            "http://s3.amazonaws.com/echo.api/echo-api-cert.pem",
+            "https://notamazon.com/echo.api/echo-api-cert.pem",
+            "https://s3.amazonaws.com/EcHo.aPi/echo-api-cert.pem",
+            "https://s3.amazonaws.com/invalid.path/echo-api-cert.pem",
+            "https://s3.amazonaws.com:563/echo.api/echo-api-cert.pem",
+            "https://s3.amazonaws.com/echo.api/../invalid.path/echo-api-cert.pem",
+            "not a url",
+        ] {
+            assert!(
+                matches!(
+                    validate_cert_url(url),
+                    Err(SignatureError::InvalidCertificateUrl(_))
+                ),
+                "{url} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn parses_timestamps() {
+        assert_eq!(parse_timestamp("1970-01-01T00:00:00Z"), Some(UNIX_EPOCH));
+        assert_eq!(
+            parse_timestamp("2024-01-27T10:00:00Z"),
+            Some(UNIX_EPOCH + Duration::from_secs(1_706_349_600))
+        );
+        assert_eq!(
+            parse_timestamp("2024-02-29T23:59:59.123Z"),
+            Some(UNIX_EPOCH + Duration::from_secs(1_709_251_199))
+        );
+        assert_eq!(parse_timestamp("2024-01-27T10:00:00"), None);
+        assert_eq!(parse_timestamp("2024-13-01T10:00:00Z"), None);
+    }
+
+    #[test]
+    fn accepts_timestamps_within_tolerance() {
+        let body = br#"{"request": {"timestamp": "2024-01-27T10:00:00Z"}}"#;
+        let sent = parse_timestamp("2024-01-27T10:00:00Z").unwrap();
+
+        assert!(check_timestamp(body, sent + Duration::from_secs(150)).is_ok());
+        assert!(check_timestamp(body, sent - Duration::from_secs(150)).is_ok());
+    }
+
+    #[test]
+    fn rejects_stale_or_missing_timestamps() {
+        let body = br#"{"request": {"timestamp": "2024-01-27T10:00:00Z"}}"#;
+        let sent = parse_timestamp("2024-01-27T10:00:00Z").unwrap();
+
+        assert!(matches!(
+            check_timestamp(body, sent + Duration::from_secs(151)),
+            Err(SignatureError::InvalidTimestamp(_))
+        ));
+        assert!(matches!(
+            check_timestamp(br#"{"request": {}}"#, sent),
+            Err(SignatureError::InvalidTimestamp(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn rejects_missing_headers() {
+        let verifier = SignatureVerifier::new();
+
+        assert!(matches!(
+            verifier.verify(None, Some("c2ln"), b"{}").await,
+            Err(SignatureError::MissingHeader(CERT_CHAIN_URL_HEADER))
+        ));
+        assert!(matches!(
+            verifier
+                .verify(
+                    Some("https://s3.amazonaws.com/echo.api/cert.pem"),
+                    None,
+                    b"{}"
+                )
+                .await,
+            Err(SignatureError::MissingHeader(SIGNATURE_HEADER))
+        ));
+    }
+}
@@ -1,3 +1,11 @@
+mod metrics;
+mod request_capture;
+mod scrubber;
 mod setup;
+mod telemetry;
 
+pub use metrics::{Metric, NAMESPACE};
+pub use request_capture::{CaptureMode, RequestCapture, PAYLOAD_FIELD};
+pub use scrubber::{LogScrubber, ScrubbingLayer, DEFAULT_SENSITIVE_SLOTS};
 pub use setup::init;
+pub use telemetry::{Telemetry, TelemetryFuture};
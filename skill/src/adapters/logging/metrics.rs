@@ -0,0 +1,131 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde_json::{json, Map, Value};
+
+use super::telemetry::Telemetry;
+
+/// CloudWatch namespace of all skill metrics.
+pub const NAMESPACE: &str = "CookidooSkill";
+
+/// A metric in CloudWatch Embedded Metric Format (EMF).
+///
+/// Lambda forwards stdout to CloudWatch Logs, which extracts EMF lines into
+/// metrics, so emitting a metric needs no API call or extra permission.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Metric {
+    name: String,
+    value: f64,
+    unit: &'static str,
+    dimensions: Vec<(String, String)>,
+}
+
+impl Metric {
+    /// Creates a metric counting a single occurrence.
+    pub fn count(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            value: 1.0,
+            unit: "Count",
+            dimensions: Vec::new(),
+        }
+    }
+
+    /// Creates a metric recording a duration in milliseconds.
+    pub fn milliseconds(name: impl Into<String>, duration: Duration) -> Self {
+        Self {
+            name: name.into(),
+            value: duration.as_secs_f64() * 1000.0,
+            unit: "Milliseconds",
+            dimensions: Vec::new(),
+        }
+    }
+
+    /// Adds a dimension the metric is broken down by.
+    pub fn with_dimension(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.dimensions.push((name.into(), value.into()));
+        self
+    }
+
+    /// Renders the metric as an EMF document for the given time.
+    pub fn to_emf(&self, timestamp_millis: u128) -> Value {
+        let dimension_names: Vec<&str> = self
+            .dimensions
+            .iter()
+            .map(|(name, _)| name.as_str())
+            .collect();
+
+        let mut document = Map::new();
+        document.insert(
+            "_aws".to_string(),
+            json!({
+                "Timestamp": timestamp_millis,
+                "CloudWatchMetrics": [{
+                    "Namespace": NAMESPACE,
+                    "Dimensions": [dimension_names],
+                    "Metrics": [{"Name": self.name, "Unit": self.unit}]
+                }]
+            }),
+        );
+        for (name, value) in &self.dimensions {
+            document.insert(name.clone(), Value::String(value.clone()));
+        }
+        document.insert(self.name.clone(), json!(self.value));
+
+        Value::Object(document)
+    }
+
+    /// Records the metric as a single EMF log line, written to stdout with
+    /// the other [`Telemetry`].
+    pub fn emit(&self) {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_millis())
+            .unwrap_or_default();
+        Telemetry::record(self.to_emf(now).to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_count_with_dimensions() {
+        let metric = Metric::count("SessionEnded")
+            .with_dimension("Reason", "ERROR")
+            .with_dimension("ErrorType", "INVALID_RESPONSE");
+
+        let emf = metric.to_emf(1_706_349_600_000);
+
+        assert_eq!(emf["_aws"]["Timestamp"], 1_706_349_600_000u64);
+        let directive = &emf["_aws"]["CloudWatchMetrics"][0];
+        assert_eq!(directive["Namespace"], NAMESPACE);
+        assert_eq!(directive["Dimensions"], json!([["Reason", "ErrorType"]]));
+        assert_eq!(directive["Metrics"][0]["Name"], "SessionEnded");
+        assert_eq!(emf["Reason"], "ERROR");
+        assert_eq!(emf["ErrorType"], "INVALID_RESPONSE");
+        assert_eq!(emf["SessionEnded"], 1.0);
+    }
+
+    #[test]
+    fn renders_metric_without_dimensions() {
+        let emf = Metric::count("ColdStart").to_emf(0);
+
+        assert_eq!(
+            emf["_aws"]["CloudWatchMetrics"][0]["Dimensions"],
+            json!([[]])
+        );
+        assert_eq!(emf["ColdStart"], 1.0);
+    }
+
+    #[test]
+    fn renders_duration_in_milliseconds() {
+        let emf = Metric::milliseconds("ResponseTime", Duration::from_micros(12_500)).to_emf(0);
+
+        assert_eq!(
+            emf["_aws"]["CloudWatchMetrics"][0]["Metrics"][0]["Unit"],
+            "Milliseconds"
+        );
+        assert_eq!(emf["ResponseTime"], 12.5);
+    }
+}
@@ -0,0 +1,258 @@
+use std::sync::OnceLock;
+
+use hmac::{Hmac, KeyInit, Mac};
+use serde_json::Value;
+use sha2::Sha256;
+use tracing::info;
+
+use super::scrubber::REDACTED;
+
+/// Log field holding a captured request payload.
+pub const PAYLOAD_FIELD: &str = "payload";
+
+/// Fields identifying a user, device or session; hashed, so requests of
+/// the same user still correlate.
+const ID_FIELDS: &[&str] = &["userId", "personId", "deviceId", "sessionId"];
+
+/// Fields holding credentials or the device's location; always redacted.
+const REDACTED_FIELDS: &[&str] = &[
+    "apiAccessToken",
+    "accessToken",
+    "consentToken",
+    "Geolocation",
+];
+
+/// Slot fields repeating the spoken value, dropped when it is hashed.
+const SLOT_DETAIL_FIELDS: &[&str] = &["resolutions", "slotValue"];
+
+/// Hex digits of the HMAC kept in hashed values.
+const HASH_LENGTH: usize = 12;
+
+/// Capture set by [`super::init`].
+static INSTALLED: OnceLock<RequestCapture> = OnceLock::new();
+
+/// Capture used until one is installed.
+static OFF: RequestCapture = RequestCapture {
+    mode: CaptureMode::Off,
+    key: Vec::new(),
+};
+
+/// Makes `capture` the one [`RequestCapture::global`] returns.
+pub(super) fn install(capture: RequestCapture) {
+    let _ = INSTALLED.set(capture);
+}
+
+/// How much of an incoming request is logged.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CaptureMode {
+    /// Requests are not logged.
+    #[default]
+    Off,
+    /// Requests are logged with identifiers hashed and tokens redacted.
+    On,
+    /// Like [`CaptureMode::On`], with slot values and the transcribed
+    /// utterance hashed as well.
+    HashedSlots,
+}
+
+impl CaptureMode {
+    /// Parses the value of `LOG_CAPTURE_REQUESTS`: `on`, `hashed` or
+    /// `off`.
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "on" | "true" | "1" => Some(Self::On),
+            "hashed" => Some(Self::HashedSlots),
+            "off" | "false" | "0" => Some(Self::Off),
+            _ => None,
+        }
+    }
+}
+
+/// Logs incoming requests for debugging, without personal data.
+///
+/// Meant to be switched on while diagnosing reports such as "it says
+/// nicht verstanden": the sanitized request is logged in the
+/// [`PAYLOAD_FIELD`] of a "Captured Alexa request" line, which the
+/// `replay` binary reads back. The line passes the [`LogScrubber`] like
+/// every other one.
+///
+/// Values are hashed with HMAC-SHA-256 under a key set at deploy time:
+/// a plain hash of a grocery word could be reversed by hashing a
+/// dictionary, while without the key the hashes reveal nothing.
+///
+/// [`LogScrubber`]: super::LogScrubber
+#[derive(Debug, Clone, Default)]
+pub struct RequestCapture {
+    mode: CaptureMode,
+    key: Vec<u8>,
+}
+
+impl RequestCapture {
+    /// Creates a capture with the given mode, hashing with `key`.
+    pub fn new(mode: CaptureMode, key: &str) -> Self {
+        Self {
+            mode,
+            key: key.as_bytes().to_vec(),
+        }
+    }
+
+    /// Returns the capture installed for the process, or one that is off
+    /// before logging is initialized.
+    pub fn global() -> &'static Self {
+        INSTALLED.get().unwrap_or(&OFF)
+    }
+
+    /// Logs `payload` sanitized, unless capture is off.
+    pub fn capture(&self, payload: &Value) {
+        if self.mode == CaptureMode::Off {
+            return;
+        }
+        info!(payload = %self.sanitize(payload), "Captured Alexa request");
+    }
+
+    /// Returns `payload` with identifiers hashed and tokens and the
+    /// location redacted, and with slot values and the utterance hashed in
+    /// [`CaptureMode::HashedSlots`].
+    pub fn sanitize(&self, payload: &Value) -> Value {
+        let mut payload = payload.clone();
+        self.scrub_ids(&mut payload);
+        if self.mode == CaptureMode::HashedSlots {
+            if let Some(slots) = payload
+                .pointer_mut("/request/intent/slots")
+                .and_then(Value::as_object_mut)
+            {
+                slots.values_mut().for_each(|slot| self.hash_slot(slot));
+            }
+            if let Some(text) = payload.pointer_mut("/request/input/text") {
+                self.hash_in_place(text);
+            }
+        }
+        payload
+    }
+
+    fn scrub_ids(&self, value: &mut Value) {
+        match value {
+            Value::Object(fields) => {
+                for (name, field) in fields.iter_mut() {
+                    if REDACTED_FIELDS.contains(&name.as_str()) {
+                        *field = Value::String(REDACTED.to_string());
+                    } else if ID_FIELDS.contains(&name.as_str()) {
+                        self.hash_in_place(field);
+                    } else {
+                        self.scrub_ids(field);
+                    }
+                }
+            }
+            Value::Array(items) => items.iter_mut().for_each(|item| self.scrub_ids(item)),
+            _ => {}
+        }
+    }
+
+    fn hash_slot(&self, slot: &mut Value) {
+        let Some(slot) = slot.as_object_mut() else {
+            return;
+        };
+        if let Some(value) = slot.get_mut("value") {
+            self.hash_in_place(value);
+        }
+        slot.retain(|name, _| !SLOT_DETAIL_FIELDS.contains(&name.as_str()));
+    }
+
+    /// Replaces a string with a short stable keyed hash; other values are
+    /// kept.
+    fn hash_in_place(&self, value: &mut Value) {
+        if let Value::String(text) = value {
+            let mut mac =
+                Hmac::<Sha256>::new_from_slice(&self.key).expect("HMAC accepts keys of any length");
+            mac.update(text.as_bytes());
+            let digest = mac.finalize().into_bytes();
+            let hex: String = digest.iter().map(|byte| format!("{byte:02x}")).collect();
+            *text = format!("hmac:{}", &hex[..HASH_LENGTH]);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn request() -> Value {
+        json!({
+            "version": "1.0",
+            "session": {
+                "sessionId": "session-1",
+                "user": {"userId": "amzn1.ask.account.ABC", "accessToken": "linked-token"}
+            },
+            "context": {
+                "System": {
+                    "user": {"userId": "amzn1.ask.account.ABC"},
+                    "device": {"deviceId": "device-1"},
+                    "apiAccessToken": "eyJ0eXAi"
+                },
+                "Geolocation": {"coordinate": {"latitudeInDegrees": 52.52}}
+            },
+            "request": {
+                "type": "IntentRequest",
+                "requestId": "req-1",
+                "intent": {
+                    "name": "AddItemIntent",
+                    "slots": {"Item": {
+                        "name": "Item",
+                        "value": "Milch",
+                        "resolutions": {"resolutionsPerAuthority": []}
+                    }}
+                },
+                "input": {"text": "füge milch hinzu"}
+            }
+        })
+    }
+
+    #[test]
+    fn hashes_ids_and_redacts_tokens() {
+        let sanitized = RequestCapture::new(CaptureMode::On, "key").sanitize(&request());
+
+        let user_id = &sanitized["context"]["System"]["user"]["userId"];
+        assert!(user_id.as_str().unwrap().starts_with("hmac:"));
+        assert_eq!(sanitized["session"]["user"]["userId"], *user_id);
+        assert_eq!(sanitized["context"]["System"]["apiAccessToken"], REDACTED);
+        assert_eq!(sanitized["session"]["user"]["accessToken"], REDACTED);
+        assert_eq!(sanitized["context"]["Geolocation"], REDACTED);
+        assert_eq!(sanitized["request"]["requestId"], "req-1");
+        assert_eq!(
+            sanitized["request"]["intent"]["slots"]["Item"]["value"],
+            "Milch"
+        );
+    }
+
+    #[test]
+    fn hashes_what_the_user_said() {
+        let sanitized = RequestCapture::new(CaptureMode::HashedSlots, "key").sanitize(&request());
+
+        let slot = &sanitized["request"]["intent"]["slots"]["Item"];
+        assert_eq!(slot["name"], "Item");
+        assert!(slot["value"].as_str().unwrap().starts_with("hmac:"));
+        assert!(slot.get("resolutions").is_none());
+        assert_ne!(sanitized["request"]["input"]["text"], "füge milch hinzu");
+    }
+
+    #[test]
+    fn hashes_depend_on_the_key() {
+        let hashed = |key| {
+            RequestCapture::new(CaptureMode::HashedSlots, key).sanitize(&request())["request"]
+                ["intent"]["slots"]["Item"]["value"]
+                .clone()
+        };
+
+        assert_eq!(hashed("key"), hashed("key"));
+        assert_ne!(hashed("key"), hashed("other key"));
+    }
+
+    #[test]
+    fn parses_modes() {
+        assert_eq!(CaptureMode::parse("ON"), Some(CaptureMode::On));
+        assert_eq!(CaptureMode::parse("hashed"), Some(CaptureMode::HashedSlots));
+        assert_eq!(CaptureMode::parse("off"), Some(CaptureMode::Off));
+        assert_eq!(CaptureMode::parse("verbose"), None);
+    }
+}
@@ -0,0 +1,402 @@
+use std::collections::HashSet;
+use std::fmt;
+use std::io::Write;
+use std::sync::{LazyLock, OnceLock};
+
+use regex::Regex;
+use serde_json::{Map, Value};
+use tracing::field::{Field, Visit};
+use tracing::{Event, Subscriber};
+use tracing_subscriber::fmt::MakeWriter;
+use tracing_subscriber::layer::{Context, Layer};
+
+use crate::adapters::alexa::slot_names;
+
+/// Replacement for redacted values.
+pub(super) const REDACTED: &str = "[REDACTED]";
+
+/// Replacement for e-mail addresses found in log text.
+const EMAIL_REDACTED: &str = "[EMAIL]";
+
+/// Fields whose values are always redacted.
+const DEFAULT_DENY_FIELDS: &[&str] = &[
+    "password",
+    "access_token",
+    "refresh_token",
+    "api_access_token",
+    "consent_token",
+    "authorization",
+    "email",
+];
+
+/// Fields that never contain personal data.
+const DEFAULT_ALLOW_FIELDS: &[&str] = &["level", "target", "timestamp"];
+
+/// Slots whose values are redacted unless `LOG_SCRUB_SENSITIVE_SLOTS` is
+/// set: free-form notes and search queries may hold anything.
+pub const DEFAULT_SENSITIVE_SLOTS: &[&str] = &[slot_names::NOTE, slot_names::QUERY];
+
+/// Log fields carrying each slot's value.
+const SLOT_FIELDS: &[(&str, &[&str])] = &[
+    (slot_names::ITEM, &["item_name", "item_names", "alias"]),
+    (slot_names::NOTE, &["note"]),
+    (slot_names::LIST_NAME, &["list"]),
+    (slot_names::BUNDLE, &["bundle"]),
+    (slot_names::ACCOUNT, &["account"]),
+    (slot_names::QUERY, &["query"]),
+];
+
+static BEARER: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\b(bearer|basic)\s+[A-Za-z0-9\-._~+/]+=*").expect("valid regex")
+});
+
+static JWT: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"\beyJ[A-Za-z0-9_-]+\.[A-Za-z0-9_-]+\.[A-Za-z0-9_-]*").expect("valid regex")
+});
+
+static EMAIL: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"[A-Za-z0-9._%+-]+@[A-Za-z0-9.-]+\.[A-Za-z]{2,}").expect("valid regex")
+});
+
+/// Removes tokens and personal data from JSON log lines.
+///
+/// Values of denied fields are replaced entirely; in all other string
+/// values bearer tokens, JWTs and e-mail addresses are masked. Allowed
+/// fields are logged unchanged, even if denied by default. Slots flagged
+/// as sensitive deny the log fields carrying their values, e.g. `Note`
+/// denies `note`.
+#[derive(Debug, Clone)]
+pub struct LogScrubber {
+    deny_fields: HashSet<String>,
+    allow_fields: HashSet<String>,
+}
+
+impl LogScrubber {
+    /// Creates a scrubber with the default field lists and sensitive slots.
+    pub fn new() -> Self {
+        Self {
+            deny_fields: DEFAULT_DENY_FIELDS.iter().map(|s| s.to_string()).collect(),
+            allow_fields: DEFAULT_ALLOW_FIELDS.iter().map(|s| s.to_string()).collect(),
+        }
+        .with_sensitive_slots(DEFAULT_SENSITIVE_SLOTS.iter().copied())
+    }
+
+    /// Creates a scrubber with the default field lists extended by
+    /// `deny_fields` and `allow_fields`, e.g. `item_name` denied to hide
+    /// spoken items, and the given sensitive slots instead of the default
+    /// ones, e.g. `Item,Note`.
+    ///
+    /// The settings are read from `LOG_SCRUB_DENY_FIELDS`,
+    /// `LOG_SCRUB_ALLOW_FIELDS` and `LOG_SCRUB_SENSITIVE_SLOTS` by the
+    /// application config.
+    pub fn with_settings(
+        sensitive_slots: Option<&[String]>,
+        deny_fields: &[String],
+        allow_fields: &[String],
+    ) -> Self {
+        let scrubber = match sensitive_slots {
+            Some(slots) => Self {
+                deny_fields: DEFAULT_DENY_FIELDS.iter().map(|s| s.to_string()).collect(),
+                allow_fields: DEFAULT_ALLOW_FIELDS.iter().map(|s| s.to_string()).collect(),
+            }
+            .with_sensitive_slots(slots),
+            None => Self::new(),
+        };
+        scrubber
+            .with_deny_fields(deny_fields)
+            .with_allow_fields(allow_fields)
+    }
+
+    /// Redacts the values of the given slots, named as in the interaction
+    /// model, e.g. `Item`; unknown slot names are ignored.
+    pub fn with_sensitive_slots<I, S>(self, slots: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let fields: Vec<&str> = slots
+            .into_iter()
+            .filter_map(|slot| {
+                SLOT_FIELDS
+                    .iter()
+                    .find(|(name, _)| name.eq_ignore_ascii_case(slot.as_ref()))
+            })
+            .flat_map(|(_, fields)| fields.iter().copied())
+            .collect();
+        self.with_deny_fields(fields)
+    }
+
+    /// Redacts the values of the given fields as well.
+    pub fn with_deny_fields<I, S>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.deny_fields.extend(fields.into_iter().map(Into::into));
+        self
+    }
+
+    /// Logs the given fields unchanged.
+    pub fn with_allow_fields<I, S>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.allow_fields.extend(fields.into_iter().map(Into::into));
+        self
+    }
+
+    /// Scrubs one log line; lines that are not JSON are masked as text.
+    pub fn scrub_line(&self, line: &str) -> String {
+        match serde_json::from_str::<Value>(line) {
+            Ok(mut value) => {
+                self.scrub_value(&mut value);
+                value.to_string()
+            }
+            Err(_) => scrub_text(line),
+        }
+    }
+
+    fn scrub_value(&self, value: &mut Value) {
+        match value {
+            Value::Object(fields) => {
+                for (name, field) in fields.iter_mut() {
+                    if self.allow_fields.contains(name) {
+                        continue;
+                    }
+                    if self.deny_fields.contains(name) {
+                        *field = Value::String(REDACTED.to_string());
+                    } else {
+                        self.scrub_value(field);
+                    }
+                }
+            }
+            Value::Array(items) => items.iter_mut().for_each(|item| self.scrub_value(item)),
+            Value::String(text) => *text = scrub_text(text),
+            _ => {}
+        }
+    }
+}
+
+impl Default for LogScrubber {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Masks bearer tokens, JWTs and e-mail addresses in free text.
+fn scrub_text(text: &str) -> String {
+    let text = BEARER.replace_all(text, format!("$1 {}", REDACTED));
+    let text = JWT.replace_all(&text, REDACTED);
+    EMAIL.replace_all(&text, EMAIL_REDACTED).into_owned()
+}
+
+/// Tracing layer writing every event as one scrubbed JSON line.
+///
+/// Replaces the JSON `fmt` layer: an event's fields are collected into a
+/// JSON object (`level`, `message` and the event's own fields, flattened),
+/// scrubbed field by field and written to `make_writer`.
+pub struct ScrubbingLayer<W> {
+    make_writer: W,
+    scrubber: LogScrubber,
+}
+
+impl<W> ScrubbingLayer<W> {
+    pub fn new(make_writer: W, scrubber: LogScrubber) -> Self {
+        Self {
+            make_writer,
+            scrubber,
+        }
+    }
+}
+
+impl<S, W> Layer<S> for ScrubbingLayer<W>
+where
+    S: Subscriber,
+    W: for<'a> MakeWriter<'a> + 'static,
+{
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let mut fields = JsonFields::default();
+        fields.0.insert(
+            "level".to_string(),
+            Value::String(event.metadata().level().to_string()),
+        );
+        event.record(&mut fields);
+
+        let mut value = Value::Object(fields.0);
+        self.scrubber.scrub_value(&mut value);
+        let mut writer = self.make_writer.make_writer_for(event.metadata());
+        let _ = writeln!(writer, "{}", value);
+    }
+}
+
+/// Collects an event's fields as JSON values.
+#[derive(Default)]
+struct JsonFields(Map<String, Value>);
+
+impl JsonFields {
+    fn insert(&mut self, field: &Field, value: Value) {
+        self.0.insert(field.name().to_string(), value);
+    }
+}
+
+impl Visit for JsonFields {
+    fn record_f64(&mut self, field: &Field, value: f64) {
+        self.insert(field, Value::from(value));
+    }
+
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        self.insert(field, Value::from(value));
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.insert(field, Value::from(value));
+    }
+
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        self.insert(field, Value::from(value));
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.insert(field, Value::from(value));
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.insert(field, Value::String(format!("{:?}", value)));
+    }
+}
+
+/// Scrubber for lines written outside of tracing, set by [`super::init`].
+static INSTALLED: OnceLock<LogScrubber> = OnceLock::new();
+
+/// Makes `scrubber` the one [`scrub_telemetry`] uses.
+pub(super) fn install(scrubber: LogScrubber) {
+    let _ = INSTALLED.set(scrubber);
+}
+
+/// Scrubs a line written outside of tracing, such as an EMF metric, with
+/// the installed scrubber (the default one before [`super::init`]).
+pub(super) fn scrub_telemetry(line: &str) -> String {
+    INSTALLED.get_or_init(LogScrubber::new).scrub_line(line)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io;
+    use std::sync::{Arc, Mutex};
+    use tracing_subscriber::layer::SubscriberExt;
+
+    #[test]
+    fn redacts_denied_fields() {
+        let line = r#"{"level":"INFO","message":"Authenticating","password":"hunter2"}"#;
+
+        let scrubbed: Value = serde_json::from_str(&LogScrubber::new().scrub_line(line)).unwrap();
+
+        assert_eq!(scrubbed["password"], REDACTED);
+        assert_eq!(scrubbed["message"], "Authenticating");
+        assert_eq!(scrubbed["level"], "INFO");
+    }
+
+    #[test]
+    fn masks_tokens_and_emails_in_text() {
+        let line =
+            r#"{"message":"Request failed","body":"Bearer abc.def-123 for max@example.com"}"#;
+
+        let scrubbed: Value = serde_json::from_str(&LogScrubber::new().scrub_line(line)).unwrap();
+
+        assert_eq!(scrubbed["body"], "Bearer [REDACTED] for [EMAIL]");
+    }
+
+    #[test]
+    fn masks_jwts() {
+        let text = scrub_text("token eyJhbGciOiJIUzI1NiJ9.eyJzdWIiOiIxIn0.sig_123 expired");
+        assert_eq!(text, "token [REDACTED] expired");
+    }
+
+    #[test]
+    fn configured_fields_are_denied_and_allowed() {
+        let scrubber = LogScrubber::new()
+            .with_deny_fields(["item_name"])
+            .with_allow_fields(["email"]);
+        let line = r#"{"item_name":"Kondome","email":"max@example.com"}"#;
+
+        let scrubbed: Value = serde_json::from_str(&scrubber.scrub_line(line)).unwrap();
+
+        assert_eq!(scrubbed["item_name"], REDACTED);
+        assert_eq!(scrubbed["email"], "max@example.com");
+    }
+
+    #[test]
+    fn scrubs_nested_fields() {
+        let line = r#"{"span":{"authorization":"Basic Zm9vOmJhcg=="},"fields":["a@b.de"]}"#;
+
+        let scrubbed: Value = serde_json::from_str(&LogScrubber::new().scrub_line(line)).unwrap();
+
+        assert_eq!(scrubbed["span"]["authorization"], REDACTED);
+        assert_eq!(scrubbed["fields"][0], EMAIL_REDACTED);
+    }
+
+    #[test]
+    fn masks_plain_text_lines() {
+        assert_eq!(
+            LogScrubber::new().scrub_line("login for max@example.com"),
+            "login for [EMAIL]"
+        );
+    }
+
+    #[test]
+    fn sensitive_slots_redact_their_fields() {
+        let scrubber = LogScrubber::new().with_sensitive_slots(["item"]);
+        let line = r#"{"item_name":"Kondome","note":"für Max","list":"Einkauf"}"#;
+
+        let scrubbed: Value = serde_json::from_str(&scrubber.scrub_line(line)).unwrap();
+
+        assert_eq!(scrubbed["item_name"], REDACTED);
+        assert_eq!(scrubbed["note"], REDACTED);
+        assert_eq!(scrubbed["list"], "Einkauf");
+    }
+
+    #[derive(Clone, Default)]
+    struct Sink(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Sink {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn layer_writes_scrubbed_json_events() {
+        let sink = Sink::default();
+        let writer = sink.clone();
+        let subscriber = tracing_subscriber::registry().with(ScrubbingLayer::new(
+            move || writer.clone(),
+            LogScrubber::new(),
+        ));
+
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info!(
+                email = "max@example.com",
+                body = "Bearer secret-token",
+                note = "für Max",
+                count = 2,
+                "Login"
+            );
+        });
+
+        let output = String::from_utf8(sink.0.lock().unwrap().clone()).unwrap();
+        let event: Value = serde_json::from_str(output.trim()).unwrap();
+        assert_eq!(event["message"], "Login");
+        assert_eq!(event["level"], "INFO");
+        assert_eq!(event["count"], 2);
+        assert_eq!(event["email"], REDACTED);
+        assert_eq!(event["body"], "Bearer [REDACTED]");
+        assert_eq!(event["note"], REDACTED);
+    }
+}
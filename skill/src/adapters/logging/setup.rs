@@ -1,5 +1,10 @@
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
 use tracing_subscriber::EnvFilter;
 
+use super::request_capture::{self, RequestCapture};
+use super::scrubber::{self, LogScrubber, ScrubbingLayer};
+
 /// Initializes structured logging for AWS Lambda.
 ///
 /// Configuration:
@@ -7,25 +12,27 @@ use tracing_subscriber::EnvFilter;
 /// - Reads log level from `RUST_LOG` environment variable (default: `info`)
 /// - Flattens event fields for easier querying
 /// - Excludes verbose target names for cleaner logs
+/// - Scrubs tokens and personal data with `scrubber` in every event and
+///   EMF metric line, so logs are safe to share
+/// - Logs incoming requests as configured in `capture`
 ///
 /// # Panics
 /// Panics if the subscriber cannot be set (e.g., called more than once).
 ///
 /// # Example
 /// ```ignore
-/// alexa_cookidoo_skill::adapters::logging::init();
+/// alexa_cookidoo_skill::adapters::logging::init(
+///     config.log_scrubber().clone(),
+///     config.request_capture(),
+/// );
 /// tracing::info!("Application started");
 /// ```
-pub fn init() {
-    tracing_subscriber::fmt()
-        .with_env_filter(
-            EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info")),
-        )
-        .json()
-        .flatten_event(true)
-        .with_target(false)
-        .with_current_span(false)
-        .without_time() // Lambda adds timestamps
+pub fn init(scrubber: LogScrubber, capture: RequestCapture) {
+    scrubber::install(scrubber.clone());
+    request_capture::install(capture);
+    tracing_subscriber::registry()
+        .with(EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info")))
+        .with(ScrubbingLayer::new(std::io::stdout, scrubber))
         .init();
 }
 
@@ -0,0 +1,181 @@
+use std::future::Future;
+use std::io::{self, Write};
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::task::{ready, Context, Poll};
+
+use super::scrubber::scrub_telemetry;
+
+/// Telemetry lines held back until flushed.
+struct TelemetryBuffer {
+    /// Whether lines are held back instead of written at once.
+    buffering: AtomicBool,
+    /// Lines recorded since the last flush.
+    lines: Mutex<Vec<String>>,
+}
+
+impl TelemetryBuffer {
+    const fn new() -> Self {
+        Self {
+            buffering: AtomicBool::new(false),
+            lines: Mutex::new(Vec::new()),
+        }
+    }
+
+    fn enable(&self) {
+        self.buffering.store(true, Ordering::Release);
+    }
+
+    /// Holds `line` back if buffering is enabled; returns it otherwise.
+    fn hold(&self, line: String) -> Option<String> {
+        if self.buffering.load(Ordering::Acquire) {
+            self.lines().push(line);
+            None
+        } else {
+            Some(line)
+        }
+    }
+
+    /// Writes and clears the held lines to `writer`.
+    fn flush_to(&self, writer: &mut impl Write) -> io::Result<usize> {
+        let lines = std::mem::take(&mut *self.lines());
+        for line in &lines {
+            writeln!(writer, "{}", line)?;
+        }
+        writer.flush()?;
+        Ok(lines.len())
+    }
+
+    fn lines(&self) -> std::sync::MutexGuard<'_, Vec<String>> {
+        self.lines.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// The process's telemetry buffer, enabled by [`Telemetry::enable_buffering`].
+static BUFFER: TelemetryBuffer = TelemetryBuffer::new();
+
+/// Telemetry lines such as EMF metrics, written to stdout.
+///
+/// Once buffering is enabled by [`Telemetry::enable_buffering`], lines are
+/// held in memory and written after the response has been sent, so writing
+/// them never delays the user's answer. Without it (HTTP server, CLI) they
+/// are written at once.
+pub struct Telemetry;
+
+impl Telemetry {
+    /// Holds lines back for the whole process until [`Telemetry::flush`],
+    /// e.g. by a [`TelemetryFuture`] around sending each response.
+    pub fn enable_buffering() {
+        BUFFER.enable();
+    }
+
+    /// Records a line, writing it at once unless buffering is enabled.
+    ///
+    /// The line passes the installed [`LogScrubber`] like every log event.
+    ///
+    /// [`LogScrubber`]: super::LogScrubber
+    pub fn record(line: String) {
+        if let Some(line) = BUFFER.hold(scrub_telemetry(&line)) {
+            println!("{}", line);
+        }
+    }
+
+    /// Writes and clears the buffered lines.
+    ///
+    /// # Returns
+    /// The number of lines written.
+    pub fn flush() -> usize {
+        Self::flush_buffer(&BUFFER)
+    }
+
+    fn flush_buffer(buffer: &TelemetryBuffer) -> usize {
+        let stdout = io::stdout();
+        buffer.flush_to(&mut stdout.lock()).unwrap_or_default()
+    }
+}
+
+/// Future flushing [`Telemetry`] once the wrapped future completes.
+pub struct TelemetryFuture<F> {
+    inner: Pin<Box<F>>,
+    buffer: &'static TelemetryBuffer,
+}
+
+impl<F> TelemetryFuture<F> {
+    /// Wraps `inner`, flushing the process's telemetry when it completes.
+    pub fn new(inner: F) -> Self {
+        Self::with_buffer(inner, &BUFFER)
+    }
+
+    fn with_buffer(inner: F, buffer: &'static TelemetryBuffer) -> Self {
+        Self {
+            inner: Box::pin(inner),
+            buffer,
+        }
+    }
+}
+
+impl<F: Future> Future for TelemetryFuture<F> {
+    type Output = F::Output;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let output = ready!(self.inner.as_mut().poll(cx));
+        Telemetry::flush_buffer(self.buffer);
+        Poll::Ready(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Each test holds its own buffer, so parallel tests do not flush each
+    // other's lines and the process-wide buffer stays disabled
+
+    fn buffered(buffer: &TelemetryBuffer, line: &str) -> bool {
+        buffer.lines().iter().any(|buffered| buffered == line)
+    }
+
+    #[test]
+    fn buffers_until_flushed() {
+        static BUFFER: TelemetryBuffer = TelemetryBuffer::new();
+        BUFFER.enable();
+        assert!(BUFFER.hold("buffers_until_flushed".to_string()).is_none());
+        assert!(buffered(&BUFFER, "buffers_until_flushed"));
+
+        let mut out = Vec::new();
+        assert_eq!(BUFFER.flush_to(&mut out).unwrap(), 1);
+
+        assert_eq!(String::from_utf8(out).unwrap(), "buffers_until_flushed\n");
+        assert!(!buffered(&BUFFER, "buffers_until_flushed"));
+    }
+
+    #[test]
+    fn passes_lines_through_until_enabled() {
+        let buffer = TelemetryBuffer::new();
+        assert_eq!(
+            buffer.hold("written at once".to_string()).as_deref(),
+            Some("written at once")
+        );
+        assert!(buffer.lines().is_empty());
+    }
+
+    #[tokio::test]
+    async fn flushes_after_wrapped_future() {
+        static BUFFER: TelemetryBuffer = TelemetryBuffer::new();
+        BUFFER.enable();
+
+        let output = TelemetryFuture::with_buffer(
+            async {
+                BUFFER.hold("flushes_after_wrapped_future".to_string());
+                assert!(buffered(&BUFFER, "flushes_after_wrapped_future"));
+                7
+            },
+            &BUFFER,
+        )
+        .await;
+
+        assert_eq!(output, 7);
+        assert!(!buffered(&BUFFER, "flushes_after_wrapped_future"));
+    }
+}
@@ -0,0 +1,7 @@
+mod cached_flag;
+mod ssm_flag;
+mod static_flag;
+
+pub use cached_flag::CachedMaintenanceFlag;
+pub use ssm_flag::SsmMaintenanceFlag;
+pub use static_flag::StaticMaintenanceFlag;
@@ -0,0 +1,120 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use tokio::time::Instant;
+
+use crate::domain::models::DomainError;
+use crate::domain::ports::MaintenanceFlag;
+
+/// Default time a flag state is reused before it is read again.
+const DEFAULT_TTL: Duration = Duration::from_secs(30);
+
+/// Caches another maintenance flag for a short time.
+///
+/// The flag is checked on every request; caching keeps that cheap while a
+/// change still reaches all warm Lambda environments within the TTL. Read
+/// errors are cached as well, so a failing backend is not hammered.
+pub struct CachedMaintenanceFlag {
+    inner: Arc<dyn MaintenanceFlag>,
+    ttl: Duration,
+    cached: Mutex<Option<(Instant, Result<bool, DomainError>)>>,
+}
+
+impl CachedMaintenanceFlag {
+    /// Wraps a flag with the default TTL of 30 seconds.
+    pub fn new(inner: Arc<dyn MaintenanceFlag>) -> Self {
+        Self {
+            inner,
+            ttl: DEFAULT_TTL,
+            cached: Mutex::new(None),
+        }
+    }
+
+    /// Replaces the time a flag state is reused.
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+}
+
+#[async_trait]
+impl MaintenanceFlag for CachedMaintenanceFlag {
+    async fn is_active(&self) -> Result<bool, DomainError> {
+        {
+            let cached = self.cached.lock().unwrap_or_else(|e| e.into_inner());
+            if let Some((read_at, result)) = cached.as_ref() {
+                if read_at.elapsed() < self.ttl {
+                    return result.clone();
+                }
+            }
+        }
+
+        let result = self.inner.is_active().await;
+        *self.cached.lock().unwrap_or_else(|e| e.into_inner()) =
+            Some((Instant::now(), result.clone()));
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+    struct CountingFlag {
+        active: AtomicBool,
+        reads: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl MaintenanceFlag for CountingFlag {
+        async fn is_active(&self) -> Result<bool, DomainError> {
+            self.reads.fetch_add(1, Ordering::SeqCst);
+            Ok(self.active.load(Ordering::SeqCst))
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn reuses_state_within_ttl() {
+        let inner = Arc::new(CountingFlag {
+            active: AtomicBool::new(false),
+            reads: AtomicUsize::new(0),
+        });
+        let flag = CachedMaintenanceFlag::new(inner.clone());
+
+        assert!(!flag.is_active().await.unwrap());
+        inner.active.store(true, Ordering::SeqCst);
+        assert!(!flag.is_active().await.unwrap());
+        assert_eq!(inner.reads.load(Ordering::SeqCst), 1);
+
+        tokio::time::advance(DEFAULT_TTL).await;
+
+        assert!(flag.is_active().await.unwrap());
+        assert_eq!(inner.reads.load(Ordering::SeqCst), 2);
+    }
+
+    struct FailingFlag {
+        reads: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl MaintenanceFlag for FailingFlag {
+        async fn is_active(&self) -> Result<bool, DomainError> {
+            self.reads.fetch_add(1, Ordering::SeqCst);
+            Err(DomainError::RepositoryError("unreachable".to_string()))
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn caches_errors() {
+        let inner = Arc::new(FailingFlag {
+            reads: AtomicUsize::new(0),
+        });
+        let flag = CachedMaintenanceFlag::new(inner.clone()).with_ttl(Duration::from_secs(5));
+
+        assert!(flag.is_active().await.is_err());
+        assert!(flag.is_active().await.is_err());
+        assert_eq!(inner.reads.load(Ordering::SeqCst), 1);
+    }
+}
@@ -0,0 +1,55 @@
+use async_trait::async_trait;
+
+use crate::adapters::parameters::ParametersExtensionClient;
+use crate::domain::models::DomainError;
+use crate::domain::ports::MaintenanceFlag;
+
+/// Maintenance flag stored as an SSM parameter.
+///
+/// Reads the parameter through the AWS Parameters and Secrets Lambda
+/// Extension, which must be added to the function as a layer. The flag is
+/// active when the value is `true`, `on`, `yes` or `1`; any other value
+/// disables it. Wrap it in a `CachedMaintenanceFlag`, as every read is an
+/// HTTP request.
+pub struct SsmMaintenanceFlag {
+    client: ParametersExtensionClient,
+    parameter_name: String,
+}
+
+impl SsmMaintenanceFlag {
+    /// Creates a flag reading the given parameter via the Lambda extension.
+    ///
+    /// The extension port and session token are taken from the Lambda
+    /// environment (`PARAMETERS_SECRETS_EXTENSION_HTTP_PORT`,
+    /// `AWS_SESSION_TOKEN`).
+    pub fn new(parameter_name: impl Into<String>) -> Self {
+        Self {
+            client: ParametersExtensionClient::new(),
+            parameter_name: parameter_name.into(),
+        }
+    }
+
+    /// Creates a flag reading from a custom extension endpoint.
+    pub fn with_endpoint(
+        endpoint: impl Into<String>,
+        parameter_name: impl Into<String>,
+        session_token: impl Into<String>,
+    ) -> Self {
+        Self {
+            client: ParametersExtensionClient::with_endpoint(endpoint, session_token),
+            parameter_name: parameter_name.into(),
+        }
+    }
+}
+
+#[async_trait]
+impl MaintenanceFlag for SsmMaintenanceFlag {
+    async fn is_active(&self) -> Result<bool, DomainError> {
+        let value = self.client.get_parameter(&self.parameter_name).await?;
+
+        Ok(matches!(
+            value.trim().to_lowercase().as_str(),
+            "true" | "on" | "yes" | "1"
+        ))
+    }
+}
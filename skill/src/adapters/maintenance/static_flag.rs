@@ -0,0 +1,23 @@
+use async_trait::async_trait;
+
+use crate::domain::models::DomainError;
+use crate::domain::ports::MaintenanceFlag;
+
+/// Maintenance flag fixed at startup, e.g. from an environment variable.
+pub struct StaticMaintenanceFlag {
+    active: bool,
+}
+
+impl StaticMaintenanceFlag {
+    /// Creates a flag with the given state.
+    pub fn new(active: bool) -> Self {
+        Self { active }
+    }
+}
+
+#[async_trait]
+impl MaintenanceFlag for StaticMaintenanceFlag {
+    async fn is_active(&self) -> Result<bool, DomainError> {
+        Ok(self.active)
+    }
+}
@@ -0,0 +1,13 @@
+mod history_repository;
+mod idempotency_store;
+mod occasion_list_store;
+mod permission_store;
+mod preferences_repository;
+mod shopping_list;
+
+pub use history_repository::InMemoryHistoryRepository;
+pub use idempotency_store::InMemoryIdempotencyStore;
+pub use occasion_list_store::InMemoryOccasionListStore;
+pub use permission_store::InMemoryPermissionStore;
+pub use preferences_repository::InMemoryPreferencesRepository;
+pub use shopping_list::InMemoryShoppingListRepository;
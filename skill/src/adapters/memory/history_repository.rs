@@ -0,0 +1,133 @@
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+use async_trait::async_trait;
+
+use crate::domain::models::{DomainError, HistoryEntry};
+use crate::domain::ports::HistoryRepository;
+
+/// In-memory history of added items, keyed by user.
+///
+/// Survives across Lambda warm invocations but is lost on cold start.
+pub struct InMemoryHistoryRepository {
+    entries: RwLock<HashMap<String, Vec<HistoryEntry>>>,
+}
+
+impl InMemoryHistoryRepository {
+    /// Creates a new empty repository.
+    pub fn new() -> Self {
+        Self {
+            entries: RwLock::new(HashMap::new()),
+        }
+    }
+}
+
+impl Default for InMemoryHistoryRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl HistoryRepository for InMemoryHistoryRepository {
+    async fn record(&self, user_id: &str, entry: &HistoryEntry) -> Result<(), DomainError> {
+        let mut entries = self
+            .entries
+            .write()
+            .map_err(|_| DomainError::RepositoryError("History lock poisoned".to_string()))?;
+
+        let user_entries = entries.entry(user_id.to_string()).or_default();
+        user_entries.push(entry.clone());
+        user_entries.sort_by(|a, b| a.added_at().cmp(b.added_at()));
+        Ok(())
+    }
+
+    async fn entries_since(
+        &self,
+        user_id: &str,
+        since: &str,
+    ) -> Result<Vec<HistoryEntry>, DomainError> {
+        let entries = self
+            .entries
+            .read()
+            .map_err(|_| DomainError::RepositoryError("History lock poisoned".to_string()))?;
+
+        Ok(entries
+            .get(user_id)
+            .map(|user_entries| {
+                user_entries
+                    .iter()
+                    .filter(|entry| entry.added_at() >= since)
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+
+    async fn delete(&self, user_id: &str) -> Result<(), DomainError> {
+        let mut entries = self
+            .entries
+            .write()
+            .map_err(|_| DomainError::RepositoryError("History lock poisoned".to_string()))?;
+
+        entries.remove(user_id);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn returns_entries_since_date_oldest_first() {
+        let repository = InMemoryHistoryRepository::new();
+        for (item, added_at) in [
+            ("Eier", "2024-01-27T09:00:00Z"),
+            ("Milch", "2024-01-26T18:00:00Z"),
+            ("Butter", "2024-01-27T08:00:00Z"),
+        ] {
+            repository
+                .record("user-1", &HistoryEntry::new(item, added_at))
+                .await
+                .unwrap();
+        }
+
+        let entries = repository
+            .entries_since("user-1", "2024-01-27")
+            .await
+            .unwrap();
+
+        let names: Vec<&str> = entries.iter().map(HistoryEntry::item_name).collect();
+        assert_eq!(names, ["Butter", "Eier"]);
+        assert!(repository
+            .entries_since("user-2", "2024-01-27")
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn deletes_entries_of_one_user() {
+        let repository = InMemoryHistoryRepository::new();
+        let entry = HistoryEntry::new("Milch", "2024-01-27T09:00:00Z");
+        repository.record("user-1", &entry).await.unwrap();
+        repository.record("user-2", &entry).await.unwrap();
+
+        repository.delete("user-1").await.unwrap();
+
+        assert!(repository
+            .entries_since("user-1", "2024-01-01")
+            .await
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            repository
+                .entries_since("user-2", "2024-01-01")
+                .await
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+}
@@ -0,0 +1,231 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Mutex, MutexGuard};
+
+use async_trait::async_trait;
+use tokio::time::Instant;
+
+use crate::domain::models::DomainError;
+use crate::domain::ports::{IdempotencyStore, CLAIM_LEASE};
+
+/// Default number of request outcomes kept in memory.
+const DEFAULT_CAPACITY: usize = 1000;
+
+/// In-memory idempotency store with least-recently-used eviction.
+///
+/// Covers re-deliveries that hit the same warm Lambda instance. An unsettled
+/// claim older than [`CLAIM_LEASE`] is taken over by the next claim.
+pub struct InMemoryIdempotencyStore {
+    capacity: usize,
+    entries: Mutex<LruEntries>,
+}
+
+#[derive(Default)]
+struct LruEntries {
+    /// Claimed and recorded keys.
+    outcomes: HashMap<String, Record>,
+    /// Keys from least to most recently used.
+    order: VecDeque<String>,
+}
+
+/// State of a key in the store.
+enum Record {
+    /// Claimed at the given instant and still being processed.
+    Claimed(Instant),
+    /// Processed with the recorded outcome.
+    Done(String),
+}
+
+impl LruEntries {
+    fn touch(&mut self, key: &str) {
+        if let Some(position) = self.order.iter().position(|k| k == key) {
+            if let Some(k) = self.order.remove(position) {
+                self.order.push_back(k);
+            }
+        }
+    }
+
+    fn insert(&mut self, key: &str, record: Record, capacity: usize) {
+        if self.outcomes.insert(key.to_string(), record).is_some() {
+            self.touch(key);
+            return;
+        }
+
+        self.order.push_back(key.to_string());
+        while self.order.len() > capacity {
+            if let Some(evicted) = self.order.pop_front() {
+                self.outcomes.remove(&evicted);
+            }
+        }
+    }
+}
+
+impl InMemoryIdempotencyStore {
+    /// Creates a new store with the default capacity.
+    pub fn new() -> Self {
+        Self::with_capacity(DEFAULT_CAPACITY)
+    }
+
+    /// Creates a new store holding at most `capacity` outcomes.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            entries: Mutex::new(LruEntries::default()),
+        }
+    }
+
+    fn lock(&self) -> Result<MutexGuard<'_, LruEntries>, DomainError> {
+        self.entries
+            .lock()
+            .map_err(|_| DomainError::RepositoryError("Idempotency lock poisoned".to_string()))
+    }
+}
+
+impl Default for InMemoryIdempotencyStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl IdempotencyStore for InMemoryIdempotencyStore {
+    async fn get(&self, key: &str) -> Result<Option<String>, DomainError> {
+        let mut entries = self.lock()?;
+
+        let outcome = match entries.outcomes.get(key) {
+            Some(Record::Done(outcome)) => Some(outcome.clone()),
+            _ => None,
+        };
+        if outcome.is_some() {
+            entries.touch(key);
+        }
+        Ok(outcome)
+    }
+
+    async fn claim(&self, key: &str) -> Result<bool, DomainError> {
+        let mut entries = self.lock()?;
+
+        let now = Instant::now();
+        match entries.outcomes.get(key) {
+            Some(Record::Done(_)) => return Ok(false),
+            Some(Record::Claimed(at)) if now.duration_since(*at) < CLAIM_LEASE => return Ok(false),
+            _ => {}
+        }
+        entries.insert(key, Record::Claimed(now), self.capacity);
+        Ok(true)
+    }
+
+    async fn release(&self, key: &str) -> Result<(), DomainError> {
+        let mut entries = self.lock()?;
+
+        if matches!(entries.outcomes.get(key), Some(Record::Claimed(_))) {
+            entries.outcomes.remove(key);
+            entries.order.retain(|k| k != key);
+        }
+        Ok(())
+    }
+
+    async fn put(&self, key: &str, outcome: &str) -> Result<(), DomainError> {
+        let mut entries = self.lock()?;
+        entries.insert(key, Record::Done(outcome.to_string()), self.capacity);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::adapters::memory::InMemoryShoppingListRepository;
+    use crate::domain::models::AddItemOutcome;
+    use crate::domain::services::AddItemService;
+
+    #[tokio::test]
+    async fn returns_none_for_unknown_key() {
+        let store = InMemoryIdempotencyStore::new();
+        assert_eq!(store.get("req-1").await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn returns_recorded_outcome() {
+        let store = InMemoryIdempotencyStore::new();
+        store.put("req-1", "done").await.unwrap();
+        assert_eq!(store.get("req-1").await.unwrap().as_deref(), Some("done"));
+    }
+
+    #[tokio::test]
+    async fn evicts_least_recently_used_entry() {
+        let store = InMemoryIdempotencyStore::with_capacity(2);
+        store.put("req-1", "one").await.unwrap();
+        store.put("req-2", "two").await.unwrap();
+
+        // Touch req-1 so req-2 becomes the eviction candidate
+        store.get("req-1").await.unwrap();
+        store.put("req-3", "three").await.unwrap();
+
+        assert!(store.get("req-1").await.unwrap().is_some());
+        assert!(store.get("req-2").await.unwrap().is_none());
+        assert!(store.get("req-3").await.unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn claims_key_once() {
+        let store = InMemoryIdempotencyStore::new();
+
+        assert!(store.claim("req-1").await.unwrap());
+        assert!(!store.claim("req-1").await.unwrap());
+        assert_eq!(store.get("req-1").await.unwrap(), None);
+
+        store.put("req-1", "done").await.unwrap();
+        assert!(!store.claim("req-1").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn released_claim_can_be_claimed_again() {
+        let store = InMemoryIdempotencyStore::new();
+        store.claim("req-1").await.unwrap();
+
+        store.release("req-1").await.unwrap();
+
+        assert!(store.claim("req-1").await.unwrap());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn stale_claim_can_be_claimed_again() {
+        let store = InMemoryIdempotencyStore::new();
+        store.claim("req-1").await.unwrap();
+
+        tokio::time::advance(CLAIM_LEASE / 2).await;
+        assert!(!store.claim("req-1").await.unwrap());
+
+        tokio::time::advance(CLAIM_LEASE).await;
+        assert!(store.claim("req-1").await.unwrap());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn recorded_outcome_outlives_lease() {
+        let store = InMemoryIdempotencyStore::new();
+        store.claim("req-1").await.unwrap();
+        store.put("req-1", "done").await.unwrap();
+
+        tokio::time::advance(CLAIM_LEASE * 2).await;
+
+        assert!(!store.claim("req-1").await.unwrap());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn retry_adds_item_after_unsettled_claim() {
+        let repo = Arc::new(InMemoryShoppingListRepository::new());
+        let store = Arc::new(InMemoryIdempotencyStore::new());
+        let service = AddItemService::new(repo.clone()).with_idempotency_store(store.clone());
+
+        // First delivery claimed the key, then timed out before settling it
+        store.claim("req-1").await.unwrap();
+        tokio::time::advance(CLAIM_LEASE + std::time::Duration::from_secs(1)).await;
+
+        let outcome = service.execute_once("req-1", "Milch", None).await;
+
+        assert!(matches!(outcome, AddItemOutcome::Added { .. }));
+        assert_eq!(repo.items().len(), 1);
+    }
+}
@@ -0,0 +1,156 @@
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+use async_trait::async_trait;
+
+use crate::domain::models::{DomainError, ListName, ShoppingListEntry, ShoppingListItem};
+use crate::domain::ports::OccasionListStore;
+
+/// In-memory store for occasion lists, keyed by user and list name.
+///
+/// Survives across Lambda warm invocations but is lost on cold start.
+pub struct InMemoryOccasionListStore {
+    lists: RwLock<HashMap<(String, String), Vec<String>>>,
+}
+
+impl InMemoryOccasionListStore {
+    /// Creates a new empty store.
+    pub fn new() -> Self {
+        Self {
+            lists: RwLock::new(HashMap::new()),
+        }
+    }
+}
+
+impl Default for InMemoryOccasionListStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn list_key(user_id: &str, list: &ListName) -> (String, String) {
+    (user_id.to_string(), list.key().to_string())
+}
+
+#[async_trait]
+impl OccasionListStore for InMemoryOccasionListStore {
+    async fn add(
+        &self,
+        user_id: &str,
+        list: &ListName,
+        item: &ShoppingListItem,
+    ) -> Result<(), DomainError> {
+        let mut lists = self
+            .lists
+            .write()
+            .map_err(|_| DomainError::RepositoryError("Occasion list lock poisoned".to_string()))?;
+
+        lists
+            .entry(list_key(user_id, list))
+            .or_default()
+            .push(item.label());
+        Ok(())
+    }
+
+    async fn items(
+        &self,
+        user_id: &str,
+        list: &ListName,
+    ) -> Result<Vec<ShoppingListEntry>, DomainError> {
+        let lists = self
+            .lists
+            .read()
+            .map_err(|_| DomainError::RepositoryError("Occasion list lock poisoned".to_string()))?;
+
+        Ok(lists
+            .get(&list_key(user_id, list))
+            .map(|names| {
+                names
+                    .iter()
+                    .enumerate()
+                    .map(|(index, name)| ShoppingListEntry::new(index.to_string(), name, false))
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+
+    async fn remove(
+        &self,
+        user_id: &str,
+        list: &ListName,
+        item_name: &str,
+    ) -> Result<bool, DomainError> {
+        let mut lists = self
+            .lists
+            .write()
+            .map_err(|_| DomainError::RepositoryError("Occasion list lock poisoned".to_string()))?;
+
+        let Some(names) = lists.get_mut(&list_key(user_id, list)) else {
+            return Ok(false);
+        };
+        match names.iter().position(|name| {
+            ShoppingListEntry::new("", name.as_str(), false).matches_name(item_name)
+        }) {
+            Some(index) => {
+                names.remove(index);
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    async fn delete(&self, user_id: &str) -> Result<(), DomainError> {
+        let mut lists = self
+            .lists
+            .write()
+            .map_err(|_| DomainError::RepositoryError("Occasion list lock poisoned".to_string()))?;
+
+        lists.retain(|(user, _), _| user != user_id);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn birthday() -> ListName {
+        ListName::new("Geburtstagsliste").unwrap()
+    }
+
+    #[tokio::test]
+    async fn unknown_list_is_empty() {
+        let store = InMemoryOccasionListStore::new();
+        assert!(store.items("user-1", &birthday()).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn adds_and_removes_items_ignoring_case() {
+        let store = InMemoryOccasionListStore::new();
+        store
+            .add(
+                "user-1",
+                &birthday(),
+                &ShoppingListItem::new("Kerzen").unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(store.items("user-1", &birthday()).await.unwrap().len(), 1);
+        assert!(store.remove("user-1", &birthday(), "kerzen").await.unwrap());
+        assert!(!store.remove("user-1", &birthday(), "kerzen").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn deletes_lists_of_one_user() {
+        let store = InMemoryOccasionListStore::new();
+        let item = ShoppingListItem::new("Kerzen").unwrap();
+        store.add("user-1", &birthday(), &item).await.unwrap();
+        store.add("user-2", &birthday(), &item).await.unwrap();
+
+        store.delete("user-1").await.unwrap();
+
+        assert!(store.items("user-1", &birthday()).await.unwrap().is_empty());
+        assert_eq!(store.items("user-2", &birthday()).await.unwrap().len(), 1);
+    }
+}
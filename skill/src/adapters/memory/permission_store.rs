@@ -0,0 +1,99 @@
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+use async_trait::async_trait;
+
+use crate::domain::models::{DomainError, SkillPermissions};
+use crate::domain::ports::PermissionStore;
+
+/// In-memory permission store.
+///
+/// Survives across Lambda warm invocations but is lost on cold start.
+pub struct InMemoryPermissionStore {
+    permissions: RwLock<HashMap<String, SkillPermissions>>,
+}
+
+impl InMemoryPermissionStore {
+    /// Creates a new empty permission store.
+    pub fn new() -> Self {
+        Self {
+            permissions: RwLock::new(HashMap::new()),
+        }
+    }
+}
+
+impl Default for InMemoryPermissionStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl PermissionStore for InMemoryPermissionStore {
+    async fn get(&self, user_id: &str) -> Result<SkillPermissions, DomainError> {
+        let permissions = self
+            .permissions
+            .read()
+            .map_err(|_| DomainError::RepositoryError("Permission lock poisoned".to_string()))?;
+
+        Ok(permissions.get(user_id).cloned().unwrap_or_default())
+    }
+
+    async fn save(&self, user_id: &str, permissions: &SkillPermissions) -> Result<(), DomainError> {
+        let mut stored = self
+            .permissions
+            .write()
+            .map_err(|_| DomainError::RepositoryError("Permission lock poisoned".to_string()))?;
+
+        stored.insert(user_id.to_string(), permissions.clone());
+        Ok(())
+    }
+
+    async fn delete(&self, user_id: &str) -> Result<(), DomainError> {
+        let mut stored = self
+            .permissions
+            .write()
+            .map_err(|_| DomainError::RepositoryError("Permission lock poisoned".to_string()))?;
+
+        stored.remove(user_id);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn returns_empty_permissions_for_unknown_user() {
+        let store = InMemoryPermissionStore::new();
+        let permissions = store.get("user-1").await.unwrap();
+        assert_eq!(permissions, SkillPermissions::new());
+    }
+
+    #[tokio::test]
+    async fn saves_and_loads_permissions_per_user() {
+        let store = InMemoryPermissionStore::new();
+        let mut permissions = SkillPermissions::new();
+        permissions.set_scopes(["scope"]);
+
+        store.save("user-1", &permissions).await.unwrap();
+
+        assert!(store.get("user-1").await.unwrap().has_scope("scope"));
+        assert!(!store.get("user-2").await.unwrap().has_scope("scope"));
+    }
+
+    #[tokio::test]
+    async fn deletes_permissions_of_one_user() {
+        let store = InMemoryPermissionStore::new();
+        let mut permissions = SkillPermissions::new();
+        permissions.set_scopes(["scope"]);
+        store.save("user-1", &permissions).await.unwrap();
+        store.save("user-2", &permissions).await.unwrap();
+
+        store.delete("user-1").await.unwrap();
+
+        assert_eq!(store.get("user-1").await.unwrap(), SkillPermissions::new());
+        assert!(store.get("user-2").await.unwrap().has_scope("scope"));
+    }
+}
@@ -0,0 +1,117 @@
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+use async_trait::async_trait;
+
+use crate::domain::models::{DomainError, UserPreferences};
+use crate::domain::ports::PreferencesRepository;
+
+/// In-memory preferences repository.
+///
+/// Survives across Lambda warm invocations but is lost on cold start.
+pub struct InMemoryPreferencesRepository {
+    preferences: RwLock<HashMap<String, UserPreferences>>,
+}
+
+impl InMemoryPreferencesRepository {
+    /// Creates a new empty repository.
+    pub fn new() -> Self {
+        Self {
+            preferences: RwLock::new(HashMap::new()),
+        }
+    }
+}
+
+impl Default for InMemoryPreferencesRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl PreferencesRepository for InMemoryPreferencesRepository {
+    async fn get(&self, user_id: &str) -> Result<UserPreferences, DomainError> {
+        let preferences = self
+            .preferences
+            .read()
+            .map_err(|_| DomainError::RepositoryError("Preferences lock poisoned".to_string()))?;
+
+        Ok(preferences.get(user_id).cloned().unwrap_or_default())
+    }
+
+    async fn save(&self, user_id: &str, preferences: &UserPreferences) -> Result<(), DomainError> {
+        let mut stored = self
+            .preferences
+            .write()
+            .map_err(|_| DomainError::RepositoryError("Preferences lock poisoned".to_string()))?;
+
+        stored.insert(user_id.to_string(), preferences.clone());
+        Ok(())
+    }
+
+    async fn delete(&self, user_id: &str) -> Result<(), DomainError> {
+        let mut stored = self
+            .preferences
+            .write()
+            .map_err(|_| DomainError::RepositoryError("Preferences lock poisoned".to_string()))?;
+
+        stored.remove(user_id);
+        Ok(())
+    }
+
+    async fn delete_all(&self, user_id: &str) -> Result<(), DomainError> {
+        let mut stored = self
+            .preferences
+            .write()
+            .map_err(|_| DomainError::RepositoryError("Preferences lock poisoned".to_string()))?;
+
+        let person_prefix = format!("{}#", user_id);
+        stored.retain(|key, _| key != user_id && !key.starts_with(&person_prefix));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::SpeechStyle;
+
+    #[tokio::test]
+    async fn returns_defaults_for_unknown_user() {
+        let repository = InMemoryPreferencesRepository::new();
+        let preferences = repository.get("user-1").await.unwrap();
+        assert_eq!(preferences, UserPreferences::new());
+    }
+
+    #[tokio::test]
+    async fn saves_and_loads_preferences_per_user() {
+        let repository = InMemoryPreferencesRepository::new();
+        let mut preferences = UserPreferences::new();
+        preferences.set_speech_style(Some(SpeechStyle::Brief));
+
+        repository.save("user-1", &preferences).await.unwrap();
+
+        assert_eq!(
+            repository.get("user-1").await.unwrap().speech_style(),
+            Some(SpeechStyle::Brief)
+        );
+        assert_eq!(repository.get("user-2").await.unwrap().speech_style(), None);
+    }
+
+    #[tokio::test]
+    async fn delete_all_removes_user_and_speakers_only() {
+        let repository = InMemoryPreferencesRepository::new();
+        let mut preferences = UserPreferences::new();
+        preferences.set_speech_style(Some(SpeechStyle::Brief));
+        for key in ["user-1", "user-1#person-1", "user-10", "user-2#person-1"] {
+            repository.save(key, &preferences).await.unwrap();
+        }
+
+        repository.delete_all("user-1").await.unwrap();
+
+        let stored = repository.preferences.read().unwrap();
+        let mut keys: Vec<&str> = stored.keys().map(String::as_str).collect();
+        keys.sort();
+        assert_eq!(keys, ["user-10", "user-2#person-1"]);
+    }
+}
@@ -0,0 +1,197 @@
+use std::sync::RwLock;
+
+use async_trait::async_trait;
+use tracing::info;
+
+use crate::domain::models::{AddedItem, DomainError, ShoppingListEntry, ShoppingListItem};
+use crate::domain::ports::ShoppingListRepository;
+
+/// In-memory shopping list used as a dry-run backend.
+///
+/// Nothing is sent to Cookidoo; items are kept in process memory so the
+/// skill can be exercised locally without credentials.
+pub struct InMemoryShoppingListRepository {
+    list: RwLock<List>,
+}
+
+/// Stored items with IDs that stay stable when other items are removed.
+#[derive(Default)]
+struct List {
+    next_id: u64,
+    items: Vec<Entry>,
+}
+
+/// A stored item and whether it was checked off.
+struct Entry {
+    id: String,
+    item: ShoppingListItem,
+    owned: bool,
+}
+
+impl InMemoryShoppingListRepository {
+    /// Creates a new empty in-memory shopping list.
+    pub fn new() -> Self {
+        Self {
+            list: RwLock::new(List::default()),
+        }
+    }
+
+    /// Returns a snapshot of all items currently on the list.
+    pub fn items(&self) -> Vec<ShoppingListItem> {
+        self.list
+            .read()
+            .map(|list| list.items.iter().map(|entry| entry.item.clone()).collect())
+            .unwrap_or_default()
+    }
+}
+
+impl Default for InMemoryShoppingListRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl ShoppingListRepository for InMemoryShoppingListRepository {
+    async fn add_item(&self, item: &ShoppingListItem) -> Result<Vec<AddedItem>, DomainError> {
+        let mut list = self
+            .list
+            .write()
+            .map_err(|_| DomainError::RepositoryError("List lock poisoned".to_string()))?;
+
+        let id = list.next_id.to_string();
+        list.next_id += 1;
+        list.items.push(Entry {
+            id: id.clone(),
+            item: item.clone(),
+            owned: false,
+        });
+        info!(item_name = %item.name(), item_id = %id, "Item added to in-memory shopping list");
+        Ok(vec![AddedItem::new(id, item.label())])
+    }
+
+    async fn list_items(&self) -> Result<Vec<ShoppingListEntry>, DomainError> {
+        let list = self
+            .list
+            .read()
+            .map_err(|_| DomainError::RepositoryError("List lock poisoned".to_string()))?;
+
+        Ok(list
+            .items
+            .iter()
+            .map(|entry| ShoppingListEntry::new(entry.id.as_str(), entry.item.label(), entry.owned))
+            .collect())
+    }
+
+    async fn remove_item(&self, id: &str) -> Result<(), DomainError> {
+        let mut list = self
+            .list
+            .write()
+            .map_err(|_| DomainError::RepositoryError("List lock poisoned".to_string()))?;
+
+        list.items.retain(|entry| entry.id != id);
+        info!(item_id = %id, "Item removed from in-memory shopping list");
+        Ok(())
+    }
+
+    async fn mark_owned(&self, id: &str) -> Result<(), DomainError> {
+        let mut list = self
+            .list
+            .write()
+            .map_err(|_| DomainError::RepositoryError("List lock poisoned".to_string()))?;
+
+        for entry in list.items.iter_mut().filter(|entry| entry.id == id) {
+            entry.owned = true;
+        }
+        info!(item_id = %id, "Item checked off in in-memory shopping list");
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_list_is_empty() {
+        let repo = InMemoryShoppingListRepository::new();
+        assert!(repo.items().is_empty());
+    }
+
+    #[tokio::test]
+    async fn add_item_stores_item() {
+        let repo = InMemoryShoppingListRepository::new();
+        let item = ShoppingListItem::new("Milch").unwrap();
+
+        let added = repo.add_item(&item).await.unwrap();
+
+        assert_eq!(repo.items(), vec![item]);
+        assert_eq!(added, vec![AddedItem::new("0", "Milch")]);
+    }
+
+    #[tokio::test]
+    async fn add_item_keeps_insertion_order() {
+        let repo = InMemoryShoppingListRepository::new();
+        repo.add_item(&ShoppingListItem::new("Milch").unwrap())
+            .await
+            .unwrap();
+        repo.add_item(&ShoppingListItem::new("Eier").unwrap())
+            .await
+            .unwrap();
+
+        let names: Vec<_> = repo.items().iter().map(|i| i.name().to_string()).collect();
+        assert_eq!(names, vec!["Milch", "Eier"]);
+    }
+
+    #[tokio::test]
+    async fn list_items_returns_added_items() {
+        let repo = InMemoryShoppingListRepository::new();
+        repo.add_item(&ShoppingListItem::new("Milch").unwrap())
+            .await
+            .unwrap();
+
+        let entries = repo.list_items().await.unwrap();
+
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].name(), "Milch");
+        assert!(!entries[0].is_owned());
+    }
+
+    #[tokio::test]
+    async fn remove_item_keeps_other_ids_stable() {
+        let repo = InMemoryShoppingListRepository::new();
+        let milk = repo
+            .add_item(&ShoppingListItem::new("Milch").unwrap())
+            .await
+            .unwrap();
+        repo.add_item(&ShoppingListItem::new("Eier").unwrap())
+            .await
+            .unwrap();
+
+        repo.remove_item(milk[0].id()).await.unwrap();
+        let added = repo
+            .add_item(&ShoppingListItem::new("Butter").unwrap())
+            .await
+            .unwrap();
+
+        let entries = repo.list_items().await.unwrap();
+        assert_eq!(entries[0].id(), "1");
+        assert_eq!(entries[0].name(), "Eier");
+        assert_eq!(added[0].id(), "2");
+    }
+
+    #[tokio::test]
+    async fn mark_owned_checks_off_item() {
+        let repo = InMemoryShoppingListRepository::new();
+        let added = repo
+            .add_item(&ShoppingListItem::new("Milch").unwrap())
+            .await
+            .unwrap();
+
+        repo.mark_owned(added[0].id()).await.unwrap();
+
+        let entries = repo.list_items().await.unwrap();
+        assert_eq!(entries.len(), 1);
+        assert!(entries[0].is_owned());
+    }
+}
@@ -0,0 +1,5 @@
+mod credential_secret;
+mod extension_client;
+
+pub use credential_secret::SecretCredentialStore;
+pub use extension_client::{ParametersExtensionClient, SecretVersion, SECRETS_MANAGER_PREFIX};
@@ -0,0 +1,68 @@
+use async_trait::async_trait;
+use serde::Deserialize;
+
+use crate::domain::models::{CookidooCredentials, DomainError, StoredCredentials};
+use crate::domain::ports::CredentialStore;
+
+use super::extension_client::ParametersExtensionClient;
+
+/// Cookidoo credentials stored as a Secrets Manager secret.
+///
+/// The secret holds `{"email": "...", "password": "..."}` and is read
+/// through the AWS Parameters and Secrets Lambda Extension; its version ID
+/// tells a rotated secret from the one already in use.
+pub struct SecretCredentialStore {
+    client: ParametersExtensionClient,
+    secret_id: String,
+}
+
+impl SecretCredentialStore {
+    /// Creates a store reading the given secret via the Lambda extension.
+    ///
+    /// The extension port and session token are taken from the Lambda
+    /// environment (`PARAMETERS_SECRETS_EXTENSION_HTTP_PORT`,
+    /// `AWS_SESSION_TOKEN`).
+    pub fn new(secret_id: impl Into<String>) -> Self {
+        Self {
+            client: ParametersExtensionClient::new(),
+            secret_id: secret_id.into(),
+        }
+    }
+
+    /// Creates a store reading from a custom extension endpoint.
+    pub fn with_endpoint(
+        endpoint: impl Into<String>,
+        secret_id: impl Into<String>,
+        session_token: impl Into<String>,
+    ) -> Self {
+        Self {
+            client: ParametersExtensionClient::with_endpoint(endpoint, session_token),
+            secret_id: secret_id.into(),
+        }
+    }
+}
+
+/// JSON layout of the credentials secret.
+#[derive(Deserialize)]
+struct CredentialsSecret {
+    email: String,
+    password: String,
+}
+
+#[async_trait]
+impl CredentialStore for SecretCredentialStore {
+    async fn current(&self) -> Result<StoredCredentials, DomainError> {
+        let secret = self.client.get_secret(&self.secret_id).await?;
+        let credentials: CredentialsSecret = serde_json::from_str(&secret.value).map_err(|e| {
+            DomainError::RepositoryError(format!(
+                "Secret {} holds no Cookidoo credentials: {}",
+                self.secret_id, e
+            ))
+        })?;
+
+        Ok(StoredCredentials::new(
+            CookidooCredentials::new(credentials.email, credentials.password),
+            secret.version_id,
+        ))
+    }
+}
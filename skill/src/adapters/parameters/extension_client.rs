@@ -0,0 +1,197 @@
+use std::time::Duration;
+
+use reqwest::{Client, Url};
+use serde::Deserialize;
+use tracing::{debug, error};
+
+use crate::adapters::cookidoo::LazyClient;
+use crate::domain::models::DomainError;
+
+/// Default port of the AWS Parameters and Secrets Lambda Extension.
+const DEFAULT_EXTENSION_PORT: &str = "2773";
+
+/// Path of the extension's parameter endpoint.
+const PARAMETER_PATH: &str = "/systemsmanager/parameters/get";
+
+/// Path of the extension's Secrets Manager endpoint.
+const SECRET_PATH: &str = "/secretsmanager/get";
+
+/// Header authenticating requests to the extension.
+const TOKEN_HEADER: &str = "X-Aws-Parameters-Secrets-Token";
+
+/// Short timeout; the extension runs next to the function.
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Parameter name prefix for reading a Secrets Manager secret through the
+/// parameter endpoint, followed by the secret ID.
+pub const SECRETS_MANAGER_PREFIX: &str = "/aws/reference/secretsmanager/";
+
+/// Reads SSM parameters through the AWS Parameters and Secrets Lambda
+/// Extension, which must be added to the function as a layer.
+///
+/// `SecureString` parameters are decrypted; Secrets Manager secrets are read
+/// with names starting with [`SECRETS_MANAGER_PREFIX`].
+pub struct ParametersExtensionClient {
+    client: LazyClient,
+    endpoint: String,
+    session_token: String,
+}
+
+impl ParametersExtensionClient {
+    /// Creates a client for the extension of the running Lambda.
+    ///
+    /// The extension port and session token are taken from the Lambda
+    /// environment (`PARAMETERS_SECRETS_EXTENSION_HTTP_PORT`,
+    /// `AWS_SESSION_TOKEN`).
+    pub fn new() -> Self {
+        let port = std::env::var("PARAMETERS_SECRETS_EXTENSION_HTTP_PORT")
+            .unwrap_or_else(|_| DEFAULT_EXTENSION_PORT.to_string());
+        Self::with_endpoint(
+            format!("http://localhost:{}", port),
+            std::env::var("AWS_SESSION_TOKEN").unwrap_or_default(),
+        )
+    }
+
+    /// Creates a client for a custom extension endpoint.
+    pub fn with_endpoint(endpoint: impl Into<String>, session_token: impl Into<String>) -> Self {
+        let client = LazyClient::new(|| Client::builder().timeout(DEFAULT_TIMEOUT).build());
+
+        Self {
+            client,
+            endpoint: endpoint.into(),
+            session_token: session_token.into(),
+        }
+    }
+
+    /// Returns the value of the given parameter.
+    ///
+    /// # Errors
+    /// Returns `DomainError::RepositoryError` if the extension cannot be
+    /// reached or the parameter does not exist.
+    pub async fn get_parameter(&self, name: &str) -> Result<String, DomainError> {
+        let url = Url::parse_with_params(
+            &format!("{}{}", self.endpoint, PARAMETER_PATH),
+            [("name", name), ("withDecryption", "true")],
+        )
+        .map_err(|e| DomainError::RepositoryError(format!("Invalid SSM endpoint: {}", e)))?;
+        debug!(parameter = %name, "Reading SSM parameter");
+
+        let client = self
+            .client
+            .get()
+            .map_err(|e| DomainError::RepositoryError(format!("HTTP client unavailable: {}", e)))?;
+        let response = client
+            .get(url)
+            .header(TOKEN_HEADER, &self.session_token)
+            .send()
+            .await
+            .map_err(|e| DomainError::RepositoryError(format!("SSM request failed: {}", e)))?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            error!(status = %status, body = %body, parameter = %name, "Failed to read SSM parameter");
+            return Err(DomainError::RepositoryError(format!(
+                "SSM extension returned {}",
+                status
+            )));
+        }
+
+        let parameter: GetParameterResponse = response
+            .json()
+            .await
+            .map_err(|e| DomainError::RepositoryError(format!("Invalid SSM response: {}", e)))?;
+
+        Ok(parameter.parameter.value)
+    }
+
+    /// Returns the current version of the given Secrets Manager secret.
+    ///
+    /// The extension caches secrets for `SECRETS_MANAGER_TTL` seconds (300
+    /// by default), so a rotation shows up here only after that.
+    ///
+    /// # Errors
+    /// Returns `DomainError::RepositoryError` if the extension cannot be
+    /// reached, the secret does not exist or holds no string.
+    pub async fn get_secret(&self, secret_id: &str) -> Result<SecretVersion, DomainError> {
+        let url = Url::parse_with_params(
+            &format!("{}{}", self.endpoint, SECRET_PATH),
+            [("secretId", secret_id)],
+        )
+        .map_err(|e| {
+            DomainError::RepositoryError(format!("Invalid Secrets Manager endpoint: {}", e))
+        })?;
+        debug!(secret = %secret_id, "Reading Secrets Manager secret");
+
+        let client = self
+            .client
+            .get()
+            .map_err(|e| DomainError::RepositoryError(format!("HTTP client unavailable: {}", e)))?;
+        let response = client
+            .get(url)
+            .header(TOKEN_HEADER, &self.session_token)
+            .send()
+            .await
+            .map_err(|e| {
+                DomainError::RepositoryError(format!("Secrets Manager request failed: {}", e))
+            })?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            error!(status = %status, body = %body, secret = %secret_id, "Failed to read secret");
+            return Err(DomainError::RepositoryError(format!(
+                "Secrets Manager extension returned {}",
+                status
+            )));
+        }
+
+        let secret: GetSecretValueResponse = response.json().await.map_err(|e| {
+            DomainError::RepositoryError(format!("Invalid Secrets Manager response: {}", e))
+        })?;
+        let value = secret.secret_string.ok_or_else(|| {
+            DomainError::RepositoryError(format!("Secret {} holds no string", secret_id))
+        })?;
+
+        Ok(SecretVersion {
+            value,
+            version_id: secret.version_id,
+        })
+    }
+}
+
+/// The value of a Secrets Manager secret in one of its versions.
+#[derive(Debug, Clone)]
+pub struct SecretVersion {
+    pub value: String,
+    pub version_id: String,
+}
+
+impl Default for ParametersExtensionClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Response of the extension's parameter endpoint (SSM `GetParameter`).
+#[derive(Debug, Deserialize)]
+struct GetParameterResponse {
+    #[serde(rename = "Parameter")]
+    parameter: Parameter,
+}
+
+/// Response of the extension's Secrets Manager endpoint
+/// (`GetSecretValue`).
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct GetSecretValueResponse {
+    #[serde(default)]
+    secret_string: Option<String>,
+    version_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct Parameter {
+    #[serde(rename = "Value")]
+    value: String,
+}
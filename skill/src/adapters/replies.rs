@@ -0,0 +1,323 @@
+use std::time::Duration;
+
+use crate::adapters::alexa::{join_names, MessageId, PhraseSelector, Speech};
+use crate::domain::models::{
+    AccountChoice, AddItemOutcome, BundleError, BundleOutcome, DomainError, ListName,
+    SettingChange, SpeechStyle, TransferOutcome,
+};
+
+/// Returns the speech for the outcome of adding an item.
+pub(crate) fn add_item_speech(outcome: &AddItemOutcome) -> String {
+    match outcome {
+        AddItemOutcome::Added { item } | AddItemOutcome::Duplicate { item } => {
+            Speech::new(MessageId::ItemAdded).with("item", item.as_str())
+        }
+        AddItemOutcome::InvalidName { reason } => {
+            Speech::new(MessageId::InvalidItemName).with("reason", reason.as_str())
+        }
+        AddItemOutcome::AuthFailed => Speech::new(MessageId::AuthFailed),
+        AddItemOutcome::Unavailable => Speech::new(MessageId::AddUnavailable),
+        AddItemOutcome::Maintenance => Speech::new(MessageId::CookidooMaintenance),
+        AddItemOutcome::RateLimited => Speech::new(MessageId::RateLimited),
+        AddItemOutcome::ListFull => Speech::new(MessageId::ListFull),
+    }
+    .render_default()
+}
+
+/// Returns the speech for the outcomes of adding several items: the added
+/// items together, followed by the guidance for the first failed add.
+pub(crate) fn add_items_speech(outcomes: &[AddItemOutcome], phrases: &PhraseSelector) -> String {
+    let added: Vec<&str> = outcomes.iter().filter_map(AddItemOutcome::item).collect();
+    let failure = outcomes.iter().find(|outcome| !outcome.is_success());
+    if let (Some(failure), true) = (failure, added.is_empty()) {
+        return add_item_speech(failure);
+    }
+
+    let confirmation = Speech::new(MessageId::ItemsAdded)
+        .counting(added.len())
+        .with("items", join_names(&added))
+        .render(phrases);
+    match failure {
+        Some(failure) => format!("{} {}", confirmation, add_item_speech(failure)),
+        None => confirmation,
+    }
+}
+
+/// Returns the confirmation of a changed setting.
+pub(crate) fn setting_speech(change: &SettingChange) -> String {
+    match change {
+        SettingChange::SpeechStyle(SpeechStyle::Brief) => Speech::new(MessageId::SpeechStyleBrief),
+        SettingChange::SpeechStyle(SpeechStyle::Full) => Speech::new(MessageId::SpeechStyleFull),
+        SettingChange::DefaultList(Some(list)) => {
+            Speech::new(MessageId::DefaultListSet).with("list", list.label())
+        }
+        SettingChange::DefaultList(None) => Speech::new(MessageId::DefaultListCleared),
+        SettingChange::Locale(locale) => {
+            let region = match locale.as_str() {
+                "de-AT" => MessageId::RegionAustria,
+                "de-CH" => MessageId::RegionSwitzerland,
+                _ => MessageId::RegionGermany,
+            };
+            Speech::new(MessageId::LocaleSet).with("region", Speech::new(region).render_default())
+        }
+        SettingChange::Account(AccountChoice::Named(name)) => {
+            Speech::new(MessageId::AccountSet).with("name", name.as_str())
+        }
+        SettingChange::Account(AccountChoice::Household) => {
+            Speech::new(MessageId::HouseholdAccountSet)
+        }
+    }
+    .render_default()
+}
+
+/// Returns guidance for a failed request.
+///
+/// Errors without more specific advice, such as unexpected repository
+/// failures, are answered with `fallback`, which names what failed.
+pub(crate) fn failure_speech(error: &DomainError, fallback: MessageId) -> String {
+    let speech = match error {
+        DomainError::ItemNotFound(item) => {
+            Speech::new(MessageId::ItemNotOnList).with("item", item.as_str())
+        }
+        DomainError::InvalidItemName(reason) => {
+            Speech::new(MessageId::InvalidItemName).with("reason", reason.as_str())
+        }
+        DomainError::AuthenticationFailed(_) => Speech::new(MessageId::AuthFailed),
+        DomainError::PermissionDenied(_) => Speech::new(MessageId::PermissionDenied),
+        DomainError::PermissionRequired(_) => Speech::new(MessageId::PermissionRequired),
+        DomainError::RateLimited(_) => Speech::new(MessageId::RateLimited),
+        DomainError::ServiceUnavailable(_) => Speech::new(MessageId::CookidooMaintenance),
+        DomainError::Unreachable(_) => Speech::new(MessageId::Unreachable),
+        DomainError::ListFull(_) => Speech::new(MessageId::ListFull),
+        DomainError::InvalidCategory(_) | DomainError::RepositoryError(_) => Speech::new(fallback),
+    };
+    speech.render_default()
+}
+
+/// Returns the speech for the outcome of managing an item bundle.
+pub(crate) fn bundle_speech(outcome: &BundleOutcome) -> String {
+    match outcome {
+        BundleOutcome::Created { bundle, item } => Speech::new(MessageId::BundleCreated)
+            .with("bundle", bundle.as_str())
+            .with("item", item.as_str()),
+        BundleOutcome::Extended { bundle, item } => Speech::new(MessageId::BundleExtended)
+            .with("bundle", bundle.as_str())
+            .with("item", item.as_str()),
+        BundleOutcome::AlreadyInBundle { bundle, item } => Speech::new(MessageId::AlreadyInBundle)
+            .with("bundle", bundle.as_str())
+            .with("item", item.as_str()),
+        BundleOutcome::Deleted { bundle } => {
+            Speech::new(MessageId::BundleDeleted).with("bundle", bundle.as_str())
+        }
+        BundleOutcome::Items { bundle, items } => {
+            let items: Vec<&str> = items.iter().map(String::as_str).collect();
+            Speech::new(MessageId::BundleItems)
+                .counting(items.len())
+                .with("bundle", bundle.as_str())
+                .with("items", join_names(&items))
+        }
+        BundleOutcome::Names { names } if names.is_empty() => Speech::new(MessageId::NoBundles),
+        BundleOutcome::Names { names } => {
+            let names: Vec<&str> = names.iter().map(String::as_str).collect();
+            Speech::new(MessageId::BundleNames)
+                .counting(names.len())
+                .with("names", join_names(&names))
+        }
+    }
+    .render_default()
+}
+
+/// Returns the speech for a failed bundle request.
+pub(crate) fn bundle_failure_speech(error: &BundleError) -> String {
+    match error {
+        BundleError::Unknown { name } => {
+            Speech::new(MessageId::UnknownBundle).with("name", name.as_str())
+        }
+        BundleError::Configured { bundle } => {
+            Speech::new(MessageId::BundleConfigured).with("bundle", bundle.as_str())
+        }
+        BundleError::Unavailable => Speech::new(MessageId::BundlesUnavailable),
+    }
+    .render_default()
+}
+
+/// Returns guidance for a failed request on a named list such as the
+/// "Geburtstagsliste".
+pub(crate) fn occasion_failure_speech(error: &DomainError, list: &ListName) -> String {
+    let speech = match error {
+        DomainError::ItemNotFound(item) => {
+            Speech::new(MessageId::OccasionItemNotOnList).with("item", item.as_str())
+        }
+        DomainError::InvalidItemName(reason) => {
+            Speech::new(MessageId::InvalidItemName).with("reason", reason.as_str())
+        }
+        _ => Speech::new(MessageId::OccasionListUnavailable),
+    };
+    speech.with("list", list.label()).render_default()
+}
+
+/// Returns the speech for moving a named list onto the shopping list,
+/// naming the pantry staples left out.
+pub(crate) fn transfer_speech(outcome: &TransferOutcome, list: &ListName) -> String {
+    let transferred = outcome.transferred();
+    let total = transferred.len() + outcome.failed().len();
+    let skipped: Vec<&str> = outcome.skipped().iter().map(String::as_str).collect();
+    let speech = match transferred {
+        _ if outcome.is_empty() => Speech::new(MessageId::TransferEmpty),
+        _ if outcome.is_failure() => Speech::new(MessageId::TransferFailed),
+        [] => {
+            return Speech::new(MessageId::TransferOnlyStaples)
+                .with("list", list.label())
+                .with("items", join_names(&skipped))
+                .render_default()
+        }
+        [item] if total == 1 => Speech::new(MessageId::TransferredItem).with("item", item.as_str()),
+        _ if transferred.len() == total => {
+            Speech::new(MessageId::TransferredAll).counting(transferred.len())
+        }
+        _ => Speech::new(MessageId::TransferredSome)
+            .counting(transferred.len())
+            .with("total", total.to_string()),
+    };
+    let text = speech.with("list", list.label()).render_default();
+    if skipped.is_empty() {
+        return text;
+    }
+    text + &Speech::new(MessageId::StaplesSkipped)
+        .counting(skipped.len())
+        .with("items", join_names(&skipped))
+        .render_default()
+}
+
+/// Describes when a reminder is due, e.g. "2 Stunden und 30 Minuten",
+/// rounded up to whole minutes.
+pub(crate) fn offset_speech(offset: Duration) -> String {
+    let total_minutes = offset.as_secs().div_ceil(60).max(1);
+    let parts: Vec<String> = [
+        (total_minutes / (24 * 60), MessageId::Days),
+        (total_minutes % (24 * 60) / 60, MessageId::Hours),
+        (total_minutes % 60, MessageId::Minutes),
+    ]
+    .into_iter()
+    .filter(|(value, _)| *value > 0)
+    .map(|(value, unit)| Speech::new(unit).counting(value as usize).render_default())
+    .collect();
+    let parts: Vec<&str> = parts.iter().map(String::as_str).collect();
+    join_names(&parts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn describes_offsets() {
+        assert_eq!(offset_speech(Duration::from_secs(30)), "einer Minute");
+        assert_eq!(offset_speech(Duration::from_secs(45 * 60)), "45 Minuten");
+        assert_eq!(
+            offset_speech(Duration::from_secs(2 * 3600 + 30 * 60)),
+            "2 Stunden und 30 Minuten"
+        );
+        assert_eq!(
+            offset_speech(Duration::from_secs(26 * 3600)),
+            "einem Tag und 2 Stunden"
+        );
+        assert_eq!(
+            offset_speech(Duration::from_secs(2 * 86400 + 3600 + 60)),
+            "2 Tagen, einer Stunde und einer Minute"
+        );
+    }
+
+    #[test]
+    fn confirms_setting_changes() {
+        let list = ListName::new("geburtstagsliste").unwrap();
+
+        assert_eq!(
+            setting_speech(&SettingChange::DefaultList(Some(list))),
+            "Alles klar, neue Einträge landen ab jetzt auf deiner Geburtstagsliste."
+        );
+        assert_eq!(
+            setting_speech(&SettingChange::Locale("de-CH".to_string())),
+            "Alles klar, ich verwende ab jetzt die Einstellungen für die Schweiz."
+        );
+    }
+
+    #[test]
+    fn words_transfer_outcomes() {
+        let list = ListName::new("Party-Liste").unwrap();
+        let names = |items: &[&str]| items.iter().map(|item| item.to_string()).collect();
+
+        assert_eq!(
+            transfer_speech(
+                &TransferOutcome::new(names(&["Chips"]), Vec::new(), Vec::new()),
+                &list
+            ),
+            "Ich habe Chips von deiner Party-Liste auf die Einkaufsliste übertragen."
+        );
+        assert_eq!(
+            transfer_speech(
+                &TransferOutcome::new(
+                    names(&["Chips", "Cola"]),
+                    Vec::new(),
+                    names(&["Salz", "pfeffer"])
+                ),
+                &list
+            ),
+            "Ich habe 2 Artikel von deiner Party-Liste auf die Einkaufsliste übertragen. \
+             Salz und pfeffer habe ich weggelassen, die hast du immer da."
+        );
+        assert_eq!(
+            transfer_speech(
+                &TransferOutcome::new(names(&["Chips"]), names(&["Cola", "Salsa"]), Vec::new()),
+                &list
+            ),
+            "Ich habe 1 von 3 Artikeln auf die Einkaufsliste übertragen. \
+             Der Rest steht noch auf deiner Party-Liste."
+        );
+        assert_eq!(
+            transfer_speech(
+                &TransferOutcome::new(Vec::new(), Vec::new(), names(&["Salz"])),
+                &list
+            ),
+            "Auf deiner Party-Liste steht nur, was du immer da hast: Salz. \
+             Ich habe nichts auf die Einkaufsliste übertragen."
+        );
+        assert_eq!(
+            transfer_speech(&TransferOutcome::default(), &list),
+            "Deine Party-Liste ist leer."
+        );
+    }
+
+    #[test]
+    fn words_bundle_outcomes() {
+        let items = BundleOutcome::Items {
+            bundle: "Grill-Set".to_string(),
+            items: vec!["Würstchen".to_string(), "Senf".to_string()],
+        };
+        let names = BundleOutcome::Names {
+            names: vec!["Grill-Set".to_string()],
+        };
+
+        assert_eq!(
+            bundle_speech(&items),
+            "In deinem Grill-Set stehen: Würstchen und Senf."
+        );
+        assert_eq!(bundle_speech(&names), "Du hast ein Set: Grill-Set.");
+        assert!(bundle_speech(&BundleOutcome::Names { names: Vec::new() })
+            .starts_with("Du hast noch keine Sets."));
+    }
+
+    #[test]
+    fn names_the_list_in_occasion_failures() {
+        let list = ListName::new("Geburtstagsliste").unwrap();
+
+        assert_eq!(
+            occasion_failure_speech(&DomainError::ItemNotFound("Kerzen".to_string()), &list),
+            "Kerzen steht nicht auf deiner Geburtstagsliste."
+        );
+        assert!(
+            occasion_failure_speech(&DomainError::RepositoryError("down".to_string()), &list)
+                .starts_with("Deine Geburtstagsliste ist gerade nicht erreichbar.")
+        );
+    }
+}
@@ -0,0 +1,4 @@
+mod api;
+mod models;
+
+pub use api::RestApi;
@@ -0,0 +1,327 @@
+use std::sync::Arc;
+
+use axum::body::Bytes;
+use axum::extract::{Path, Request, State};
+use axum::http::StatusCode;
+use axum::middleware::{self, Next};
+use axum::response::{Html, Response};
+use axum::routing::{delete, get, post};
+use axum::Router;
+use tracing::{info, warn};
+
+use crate::adapters::alexa::{AlexaRequest, AlexaSkillHandler, MessageId, Speech};
+use crate::adapters::google::{DialogflowHandler, DialogflowRequest};
+use crate::adapters::replies::failure_speech;
+use crate::adapters::web::{self, error, json, ErrorDto, MessageDto};
+use crate::domain::models::DomainError;
+use crate::domain::services::{AddItemService, ReadListService, RemoveItemService};
+
+use super::models::ItemDto;
+
+/// Minimal browser page for managing the list from a phone.
+const INDEX_HTML: &str = include_str!("index.html");
+
+/// Plain-HTTP API over the domain services.
+///
+/// Serves `GET/POST /items` and `DELETE /items/{id}` for browsers, and
+/// `POST /alexa` for Alexa request JSON, so voice and browser share the same
+/// services and backend. With a Dialogflow handler set, `POST /dialogflow`
+/// accepts Google Assistant webhook requests as well.
+pub struct RestApi {
+    add_item_service: Arc<AddItemService>,
+    read_list_service: Arc<ReadListService>,
+    remove_item_service: Arc<RemoveItemService>,
+    handler: Arc<AlexaSkillHandler>,
+    dialogflow_handler: Option<Arc<DialogflowHandler>>,
+}
+
+impl RestApi {
+    /// Creates a new RestApi with the given services and skill handler.
+    pub fn new(
+        add_item_service: Arc<AddItemService>,
+        read_list_service: Arc<ReadListService>,
+        remove_item_service: Arc<RemoveItemService>,
+        handler: Arc<AlexaSkillHandler>,
+    ) -> Self {
+        Self {
+            add_item_service,
+            read_list_service,
+            remove_item_service,
+            handler,
+            dialogflow_handler: None,
+        }
+    }
+
+    /// Enables `POST /dialogflow` for Google Assistant webhook requests.
+    pub fn with_dialogflow_handler(mut self, handler: Arc<DialogflowHandler>) -> Self {
+        self.dialogflow_handler = Some(handler);
+        self
+    }
+
+    /// Returns the router serving the API.
+    pub fn router(self: Arc<Self>) -> Router {
+        let mut router = Router::new()
+            .route("/", get(index))
+            .route("/items", get(list_items).post(add_item))
+            .route("/items/{id}", delete(remove_item))
+            .route("/alexa", post(alexa));
+        if self.dialogflow_handler.is_some() {
+            router = router.route("/dialogflow", post(dialogflow));
+        }
+
+        router
+            .fallback(|| async { error(StatusCode::NOT_FOUND, "Not found") })
+            .method_not_allowed_fallback(|| async {
+                error(StatusCode::METHOD_NOT_ALLOWED, "Method not allowed")
+            })
+            .layer(middleware::from_fn(log_request))
+            .with_state(self)
+    }
+}
+
+async fn log_request(request: Request, next: Next) -> Response {
+    info!(
+        method = %request.method(),
+        path = %request.uri().path(),
+        "Handling REST request"
+    );
+    next.run(request).await
+}
+
+async fn index() -> Html<&'static str> {
+    Html(INDEX_HTML)
+}
+
+async fn list_items(State(api): State<Arc<RestApi>>) -> Response {
+    match api.read_list_service.execute().await {
+        Ok(entries) => json(
+            StatusCode::OK,
+            &entries.iter().map(ItemDto::from).collect::<Vec<_>>(),
+        ),
+        Err(e) => domain_error(&e, MessageId::ReadFailed),
+    }
+}
+
+async fn add_item(State(api): State<Arc<RestApi>>, body: Bytes) -> Response {
+    web::add_item(&api.add_item_service, &body).await
+}
+
+async fn remove_item(State(api): State<Arc<RestApi>>, Path(id): Path<String>) -> Response {
+    match api.remove_item_service.remove_by_id(&id).await {
+        Ok(Some(item)) => json(
+            StatusCode::OK,
+            &MessageDto {
+                message: Speech::new(MessageId::ItemRemoved)
+                    .with("item", item)
+                    .render_default(),
+            },
+        ),
+        Ok(None) => error(StatusCode::NOT_FOUND, format!("No item with id {}", id)),
+        Err(e) => domain_error(&e, MessageId::RemoveFailed),
+    }
+}
+
+async fn alexa(State(api): State<Arc<RestApi>>, body: Bytes) -> Response {
+    match serde_json::from_slice::<AlexaRequest>(&body) {
+        Ok(request) => json(StatusCode::OK, &api.handler.handle(request).await),
+        Err(e) => {
+            warn!(error = %e, "Invalid Alexa request");
+            error(StatusCode::BAD_REQUEST, "Invalid Alexa request")
+        }
+    }
+}
+
+async fn dialogflow(State(api): State<Arc<RestApi>>, body: Bytes) -> Response {
+    let Some(handler) = &api.dialogflow_handler else {
+        return error(StatusCode::NOT_FOUND, "Not found");
+    };
+    match serde_json::from_slice::<DialogflowRequest>(&body) {
+        Ok(request) => json(StatusCode::OK, &handler.handle(request).await),
+        Err(e) => {
+            warn!(error = %e, "Invalid Dialogflow request");
+            error(StatusCode::BAD_REQUEST, "Invalid Dialogflow request")
+        }
+    }
+}
+
+/// Answers a failed backend call with a matching status, the spoken
+/// guidance and the support code.
+fn domain_error(e: &DomainError, fallback: MessageId) -> Response {
+    let status = match e {
+        DomainError::ItemNotFound(_) => StatusCode::NOT_FOUND,
+        DomainError::RateLimited(_) => StatusCode::TOO_MANY_REQUESTS,
+        DomainError::ServiceUnavailable(_) => StatusCode::SERVICE_UNAVAILABLE,
+        _ => StatusCode::BAD_GATEWAY,
+    };
+    json(
+        status,
+        &ErrorDto {
+            error: failure_speech(e, fallback),
+            code: Some(e.code().to_string()),
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::{to_bytes, Body};
+    use axum::http::header::CONTENT_TYPE;
+    use axum::http::Method;
+    use tower::ServiceExt;
+
+    use crate::adapters::memory::InMemoryShoppingListRepository;
+
+    fn make_api() -> Router {
+        let repo = Arc::new(InMemoryShoppingListRepository::new());
+        let add = Arc::new(AddItemService::new(repo.clone()));
+        let read = Arc::new(ReadListService::new(repo.clone()));
+        let remove = Arc::new(RemoveItemService::new(repo));
+        let handler = Arc::new(AlexaSkillHandler::new(
+            add.clone(),
+            read.clone(),
+            remove.clone(),
+        ));
+        Arc::new(RestApi::new(add, read, remove, handler)).router()
+    }
+
+    async fn send(router: &Router, method: Method, path: &str, body: &'static [u8]) -> Response {
+        let request = Request::builder()
+            .method(method)
+            .uri(path)
+            .body(Body::from(body))
+            .unwrap();
+        router.clone().oneshot(request).await.unwrap()
+    }
+
+    async fn body_json(response: Response) -> serde_json::Value {
+        let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        serde_json::from_slice(&bytes).unwrap()
+    }
+
+    #[tokio::test]
+    async fn adds_lists_and_removes_items() {
+        let api = make_api();
+
+        let added = send(&api, Method::POST, "/items", br#"{"name": "Milch"}"#).await;
+        assert_eq!(added.status(), StatusCode::CREATED);
+
+        let listed = body_json(send(&api, Method::GET, "/items", b"").await).await;
+        assert_eq!(listed[0]["name"], "Milch");
+        let id = listed[0]["id"].as_str().unwrap().to_string();
+
+        let removed = send(&api, Method::DELETE, &format!("/items/{}", id), b"").await;
+        assert_eq!(removed.status(), StatusCode::OK);
+
+        let listed = body_json(send(&api, Method::GET, "/items", b"").await).await;
+        assert_eq!(listed, serde_json::json!([]));
+    }
+
+    #[tokio::test]
+    async fn rejects_invalid_add_body() {
+        let api = make_api();
+        let response = send(&api, Method::POST, "/items", b"Milch").await;
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn rejects_blank_item_name() {
+        let api = make_api();
+        let response = send(&api, Method::POST, "/items", br#"{"name": "  "}"#).await;
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn removing_unknown_item_is_not_found() {
+        let api = make_api();
+        let response = send(&api, Method::DELETE, "/items/42", b"").await;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn unknown_routes_and_methods() {
+        let api = make_api();
+        assert_eq!(
+            send(&api, Method::GET, "/nope", b"").await.status(),
+            StatusCode::NOT_FOUND
+        );
+        assert_eq!(
+            send(&api, Method::PUT, "/items", b"").await.status(),
+            StatusCode::METHOD_NOT_ALLOWED
+        );
+    }
+
+    #[tokio::test]
+    async fn serves_index_page() {
+        let response = send(&make_api(), Method::GET, "/", b"").await;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[CONTENT_TYPE], "text/html; charset=utf-8");
+    }
+
+    #[tokio::test]
+    async fn forwards_alexa_requests_to_the_skill_handler() {
+        let api = make_api();
+        let request = br#"{
+            "version": "1.0",
+            "request": {
+                "type": "LaunchRequest",
+                "requestId": "req-123",
+                "timestamp": "2024-01-27T10:00:00Z",
+                "locale": "de-DE"
+            }
+        }"#;
+
+        let response = body_json(send(&api, Method::POST, "/alexa", request).await).await;
+
+        assert_eq!(response["version"], "1.0");
+        assert!(response["response"]["outputSpeech"]["text"]
+            .as_str()
+            .unwrap()
+            .contains("Willkommen"));
+    }
+
+    #[tokio::test]
+    async fn dialogflow_route_requires_handler() {
+        let response = send(&make_api(), Method::POST, "/dialogflow", b"{}").await;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn forwards_dialogflow_requests_to_the_same_list() {
+        let repo = Arc::new(InMemoryShoppingListRepository::new());
+        let add = Arc::new(AddItemService::new(repo.clone()));
+        let read = Arc::new(ReadListService::new(repo.clone()));
+        let remove = Arc::new(RemoveItemService::new(repo));
+        let handler = Arc::new(AlexaSkillHandler::new(
+            add.clone(),
+            read.clone(),
+            remove.clone(),
+        ));
+        let dialogflow = Arc::new(DialogflowHandler::new(
+            add.clone(),
+            read.clone(),
+            remove.clone(),
+        ));
+        let api =
+            Arc::new(RestApi::new(add, read, remove, handler).with_dialogflow_handler(dialogflow))
+                .router();
+        let request = br#"{
+            "responseId": "response-123",
+            "session": "projects/test/agent/sessions/session-123",
+            "queryResult": {
+                "parameters": {"Item": "Milch"},
+                "intent": {"displayName": "AddItemIntent"},
+                "languageCode": "de"
+            }
+        }"#;
+
+        let response = body_json(send(&api, Method::POST, "/dialogflow", request).await).await;
+        assert!(response["fulfillmentText"]
+            .as_str()
+            .unwrap()
+            .contains("Milch"));
+
+        let items = body_json(send(&api, Method::GET, "/items", b"").await).await;
+        assert_eq!(items[0]["name"], "Milch");
+    }
+}
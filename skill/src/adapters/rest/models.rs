@@ -0,0 +1,21 @@
+use serde::Serialize;
+
+use crate::domain::models::ShoppingListEntry;
+
+/// A shopping list entry as returned by `GET /items`.
+#[derive(Debug, Serialize)]
+pub struct ItemDto<'a> {
+    pub id: &'a str,
+    pub name: &'a str,
+    pub owned: bool,
+}
+
+impl<'a> From<&'a ShoppingListEntry> for ItemDto<'a> {
+    fn from(entry: &'a ShoppingListEntry) -> Self {
+        Self {
+            id: entry.id(),
+            name: entry.name(),
+            owned: entry.is_owned(),
+        }
+    }
+}
@@ -0,0 +1,3 @@
+mod shopping_list;
+
+pub use shopping_list::ShadowShoppingListRepository;
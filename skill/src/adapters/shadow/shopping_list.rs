@@ -0,0 +1,385 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use tracing::{info, warn};
+
+use crate::adapters::logging::Metric;
+use crate::domain::models::{AddedItem, DomainError, ShoppingListEntry, ShoppingListItem};
+use crate::domain::ports::ShoppingListRepository;
+
+/// Metric counting operations whose shadow result differed, by operation.
+const DIVERGENCE_METRIC: &str = "ShadowDivergence";
+
+/// Metric counting shadow calls abandoned after [`SHADOW_TIMEOUT`], by
+/// operation.
+const TIMEOUT_METRIC: &str = "ShadowTimeout";
+
+/// How long a shadow call may take before it is abandoned unanswered.
+const SHADOW_TIMEOUT: Duration = Duration::from_millis(300);
+
+/// Shopping list that answers from a primary backend and repeats every
+/// operation on a shadow backend, logging where the two disagree.
+///
+/// Lets a rewritten adapter or a new endpoint run against real traffic
+/// before it serves users: only the primary's results are returned, and
+/// shadow failures never reach the caller. Both backends are called
+/// concurrently, and a shadow call still running after [`SHADOW_TIMEOUT`]
+/// is abandoned without comparison, so a slow or unreachable shadow delays
+/// an answer by that much at most.
+///
+/// Item IDs differ between backends; removes and check-offs are passed to
+/// the shadow under the ID it reported for the same item, learned from
+/// adds and list reads. Items the shadow has not reported yet are skipped
+/// there.
+pub struct ShadowShoppingListRepository {
+    primary: Arc<dyn ShoppingListRepository>,
+    shadow: Arc<dyn ShoppingListRepository>,
+    shadow_ids: Mutex<HashMap<String, String>>,
+    timeout: Duration,
+}
+
+impl ShadowShoppingListRepository {
+    /// Creates a repository serving from `primary` and mirroring to `shadow`.
+    pub fn new(
+        primary: Arc<dyn ShoppingListRepository>,
+        shadow: Arc<dyn ShoppingListRepository>,
+    ) -> Self {
+        Self {
+            primary,
+            shadow,
+            shadow_ids: Mutex::new(HashMap::new()),
+            timeout: SHADOW_TIMEOUT,
+        }
+    }
+
+    /// Abandons shadow calls after `timeout` instead of [`SHADOW_TIMEOUT`].
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Runs `shadow` for at most the configured timeout, returning `None`
+    /// if it was abandoned.
+    async fn shadow<T>(
+        &self,
+        operation: &str,
+        shadow: impl Future<Output = Result<T, DomainError>>,
+    ) -> Option<Result<T, DomainError>> {
+        match tokio::time::timeout(self.timeout, shadow).await {
+            Ok(result) => Some(result),
+            Err(_) => {
+                warn!(
+                    operation,
+                    timeout_ms = self.timeout.as_millis() as u64,
+                    "Shadow backend timed out"
+                );
+                Metric::count(TIMEOUT_METRIC)
+                    .with_dimension("Operation", operation)
+                    .emit();
+                None
+            }
+        }
+    }
+
+    fn shadow_id(&self, primary_id: &str) -> Option<String> {
+        self.shadow_ids.lock().ok()?.get(primary_id).cloned()
+    }
+
+    /// Remembers the shadow ID of each primary item with the same name, in
+    /// list order so repeated names pair up one by one.
+    fn learn_ids<'a>(
+        &self,
+        primary: impl IntoIterator<Item = (&'a str, &'a str)>,
+        shadow: impl IntoIterator<Item = (&'a str, &'a str)>,
+    ) {
+        let mut shadow: Vec<(&str, &str)> = shadow.into_iter().collect();
+        let Ok(mut shadow_ids) = self.shadow_ids.lock() else {
+            return;
+        };
+        for (primary_id, name) in primary {
+            if let Some(index) = shadow.iter().position(|(_, other)| *other == name) {
+                let (shadow_id, _) = shadow.remove(index);
+                shadow_ids.insert(primary_id.to_string(), shadow_id.to_string());
+            }
+        }
+    }
+
+    fn forget_id(&self, primary_id: &str) {
+        if let Ok(mut shadow_ids) = self.shadow_ids.lock() {
+            shadow_ids.remove(primary_id);
+        }
+    }
+
+    /// Compares the outcome of an operation without a result worth
+    /// comparing beyond success.
+    fn compare_outcomes<T, U>(
+        operation: &str,
+        primary: &Result<T, DomainError>,
+        shadow: &Option<Result<U, DomainError>>,
+    ) {
+        let Some(shadow) = shadow else {
+            return;
+        };
+        match (primary, shadow) {
+            (Ok(_), Ok(_)) => {}
+            (Err(primary), Err(shadow)) if primary.code() == shadow.code() => {}
+            (Ok(_), Err(shadow)) => {
+                Self::diverged(operation, format!("shadow failed: {shadow}"));
+            }
+            (Err(primary), Ok(_)) => {
+                Self::diverged(operation, format!("only primary failed: {primary}"));
+            }
+            (Err(primary), Err(shadow)) => Self::diverged(
+                operation,
+                format!("primary failed with {primary}, shadow with {shadow}"),
+            ),
+        }
+    }
+
+    fn diverged(operation: &str, detail: String) {
+        warn!(operation, detail = %detail, "Shadow backend diverged from primary");
+        Metric::count(DIVERGENCE_METRIC)
+            .with_dimension("Operation", operation)
+            .emit();
+    }
+}
+
+/// Returns `names` sorted, so lists in different orders compare equal.
+fn sorted_names<'a>(names: impl IntoIterator<Item = &'a str>) -> Vec<&'a str> {
+    let mut names: Vec<&str> = names.into_iter().collect();
+    names.sort_unstable();
+    names
+}
+
+#[async_trait]
+impl ShoppingListRepository for ShadowShoppingListRepository {
+    async fn add_item(&self, item: &ShoppingListItem) -> Result<Vec<AddedItem>, DomainError> {
+        let (primary, shadow) = tokio::join!(
+            self.primary.add_item(item),
+            self.shadow("add_item", self.shadow.add_item(item))
+        );
+
+        if let (Ok(primary), Some(Ok(shadow))) = (&primary, &shadow) {
+            let primary_names = sorted_names(primary.iter().map(AddedItem::name));
+            let shadow_names = sorted_names(shadow.iter().map(AddedItem::name));
+            // Backends that do not report what they created are not compared
+            if !primary.is_empty() && !shadow.is_empty() && primary_names != shadow_names {
+                Self::diverged(
+                    "add_item",
+                    format!("primary added {primary_names:?}, shadow {shadow_names:?}"),
+                );
+            }
+            self.learn_ids(
+                primary.iter().map(|added| (added.id(), added.name())),
+                shadow.iter().map(|added| (added.id(), added.name())),
+            );
+        }
+        Self::compare_outcomes("add_item", &primary, &shadow);
+        primary
+    }
+
+    async fn list_items(&self) -> Result<Vec<ShoppingListEntry>, DomainError> {
+        let (primary, shadow) = tokio::join!(
+            self.primary.list_items(),
+            self.shadow("list_items", self.shadow.list_items())
+        );
+
+        if let (Ok(primary), Some(Ok(shadow))) = (&primary, &shadow) {
+            let primary_names = sorted_names(primary.iter().map(ShoppingListEntry::name));
+            let shadow_names = sorted_names(shadow.iter().map(ShoppingListEntry::name));
+            if primary_names != shadow_names {
+                Self::diverged(
+                    "list_items",
+                    format!(
+                        "primary lists {} items, shadow {}",
+                        primary_names.len(),
+                        shadow_names.len()
+                    ),
+                );
+            }
+            self.learn_ids(
+                primary.iter().map(|entry| (entry.id(), entry.name())),
+                shadow.iter().map(|entry| (entry.id(), entry.name())),
+            );
+        }
+        Self::compare_outcomes("list_items", &primary, &shadow);
+        primary
+    }
+
+    async fn remove_item(&self, id: &str) -> Result<(), DomainError> {
+        let Some(shadow_id) = self.shadow_id(id) else {
+            info!(item_id = %id, "Item unknown to shadow backend, not removing it there");
+            return self.primary.remove_item(id).await;
+        };
+        let (primary, shadow) = tokio::join!(
+            self.primary.remove_item(id),
+            self.shadow("remove_item", self.shadow.remove_item(&shadow_id))
+        );
+        Self::compare_outcomes("remove_item", &primary, &shadow);
+        if primary.is_ok() {
+            self.forget_id(id);
+        }
+        primary
+    }
+
+    async fn mark_owned(&self, id: &str) -> Result<(), DomainError> {
+        let Some(shadow_id) = self.shadow_id(id) else {
+            info!(item_id = %id, "Item unknown to shadow backend, not checking it off there");
+            return self.primary.mark_owned(id).await;
+        };
+        let (primary, shadow) = tokio::join!(
+            self.primary.mark_owned(id),
+            self.shadow("mark_owned", self.shadow.mark_owned(&shadow_id))
+        );
+        Self::compare_outcomes("mark_owned", &primary, &shadow);
+        primary
+    }
+
+    async fn warm_up(&self) -> Result<(), DomainError> {
+        let (primary, shadow) = tokio::join!(
+            self.primary.warm_up(),
+            self.shadow("warm_up", self.shadow.warm_up())
+        );
+        if let Some(Err(e)) = shadow {
+            warn!(error = %e, "Failed to warm up shadow backend");
+        }
+        primary
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::memory::InMemoryShoppingListRepository;
+
+    struct FailingRepository;
+
+    #[async_trait]
+    impl ShoppingListRepository for FailingRepository {
+        async fn add_item(&self, _item: &ShoppingListItem) -> Result<Vec<AddedItem>, DomainError> {
+            Err(DomainError::Unreachable("shadow down".to_string()))
+        }
+
+        async fn list_items(&self) -> Result<Vec<ShoppingListEntry>, DomainError> {
+            Err(DomainError::Unreachable("shadow down".to_string()))
+        }
+
+        async fn remove_item(&self, _id: &str) -> Result<(), DomainError> {
+            Err(DomainError::Unreachable("shadow down".to_string()))
+        }
+
+        async fn mark_owned(&self, _id: &str) -> Result<(), DomainError> {
+            Err(DomainError::Unreachable("shadow down".to_string()))
+        }
+    }
+
+    /// Never answers, like a shadow backend that stopped responding.
+    struct HangingRepository;
+
+    #[async_trait]
+    impl ShoppingListRepository for HangingRepository {
+        async fn add_item(&self, _item: &ShoppingListItem) -> Result<Vec<AddedItem>, DomainError> {
+            std::future::pending().await
+        }
+
+        async fn list_items(&self) -> Result<Vec<ShoppingListEntry>, DomainError> {
+            std::future::pending().await
+        }
+
+        async fn remove_item(&self, _id: &str) -> Result<(), DomainError> {
+            std::future::pending().await
+        }
+
+        async fn mark_owned(&self, _id: &str) -> Result<(), DomainError> {
+            std::future::pending().await
+        }
+    }
+
+    fn item(name: &str) -> ShoppingListItem {
+        ShoppingListItem::new(name).unwrap()
+    }
+
+    #[tokio::test]
+    async fn mirrors_adds_to_shadow() {
+        let primary = Arc::new(InMemoryShoppingListRepository::new());
+        let shadow = Arc::new(InMemoryShoppingListRepository::new());
+        let repository = ShadowShoppingListRepository::new(primary.clone(), shadow.clone());
+
+        let added = repository.add_item(&item("Milch")).await.unwrap();
+
+        assert_eq!(added[0].name(), "Milch");
+        assert_eq!(primary.items(), vec![item("Milch")]);
+        assert_eq!(shadow.items(), vec![item("Milch")]);
+    }
+
+    #[tokio::test]
+    async fn removes_under_the_shadow_id() {
+        let primary = Arc::new(InMemoryShoppingListRepository::new());
+        let shadow = Arc::new(InMemoryShoppingListRepository::new());
+        // Offsets the shadow's IDs from the primary's
+        shadow.add_item(&item("Eier")).await.unwrap();
+        let repository = ShadowShoppingListRepository::new(primary.clone(), shadow.clone());
+
+        let added = repository.add_item(&item("Milch")).await.unwrap();
+        repository.remove_item(added[0].id()).await.unwrap();
+
+        assert!(primary.items().is_empty());
+        assert_eq!(shadow.items(), vec![item("Eier")]);
+    }
+
+    #[tokio::test]
+    async fn learns_shadow_ids_from_list_reads() {
+        let primary = Arc::new(InMemoryShoppingListRepository::new());
+        let shadow = Arc::new(InMemoryShoppingListRepository::new());
+        shadow.add_item(&item("Eier")).await.unwrap();
+        for repository in [&primary, &shadow] {
+            repository.add_item(&item("Milch")).await.unwrap();
+        }
+        let repository = ShadowShoppingListRepository::new(primary.clone(), shadow.clone());
+
+        let entries = repository.list_items().await.unwrap();
+        repository.mark_owned(entries[0].id()).await.unwrap();
+
+        let shadow_entries = shadow.list_items().await.unwrap();
+        assert!(!shadow_entries[0].is_owned());
+        assert!(shadow_entries[1].is_owned());
+    }
+
+    #[tokio::test]
+    async fn shadow_failures_do_not_reach_the_caller() {
+        let primary = Arc::new(InMemoryShoppingListRepository::new());
+        let repository = ShadowShoppingListRepository::new(primary, Arc::new(FailingRepository));
+
+        assert!(repository.add_item(&item("Milch")).await.is_ok());
+        assert_eq!(repository.list_items().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn hanging_shadow_does_not_hold_up_the_answer() {
+        let primary = Arc::new(InMemoryShoppingListRepository::new());
+        let repository = ShadowShoppingListRepository::new(primary, Arc::new(HangingRepository))
+            .with_timeout(Duration::from_millis(50));
+        let started = tokio::time::Instant::now();
+
+        assert!(repository.add_item(&item("Milch")).await.is_ok());
+        assert_eq!(repository.list_items().await.unwrap().len(), 1);
+        assert!(started.elapsed() < Duration::from_millis(150));
+    }
+
+    #[tokio::test]
+    async fn skips_shadow_for_unknown_items() {
+        let primary = Arc::new(InMemoryShoppingListRepository::new());
+        primary.add_item(&item("Milch")).await.unwrap();
+        let shadow = Arc::new(InMemoryShoppingListRepository::new());
+        shadow.add_item(&item("Milch")).await.unwrap();
+        let repository = ShadowShoppingListRepository::new(primary.clone(), shadow.clone());
+
+        repository.remove_item("0").await.unwrap();
+
+        assert!(primary.items().is_empty());
+        assert_eq!(shadow.items(), vec![item("Milch")]);
+    }
+}
@@ -0,0 +1,7 @@
+mod grammar;
+mod log_replay;
+mod request_factory;
+
+pub use grammar::{parse_utterance, SimulatedIntent};
+pub use log_replay::extract_requests;
+pub use request_factory::RequestFactory;
@@ -0,0 +1,457 @@
+use crate::adapters::alexa::{intent_names, slot_names};
+
+/// Intent recognized from a typed utterance.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SimulatedIntent {
+    /// The skill was opened without a command.
+    Launch,
+    /// A named intent with its slot values.
+    Intent {
+        name: &'static str,
+        slots: Vec<(&'static str, String)>,
+    },
+}
+
+/// Utterance patterns that add an item, as (prefix, suffix) pairs.
+///
+/// The text between prefix and suffix becomes the item slot value.
+const ADD_ITEM_PATTERNS: &[(&str, &str)] = &[
+    ("füge ", " zur einkaufsliste hinzu"),
+    ("füge ", " hinzu"),
+    ("setze ", " auf die liste"),
+    ("setz ", " auf die liste"),
+    ("ich brauche ", ""),
+    ("wir brauchen ", ""),
+];
+
+/// Utterance patterns that add a bundle, as (prefix, suffix) pairs around
+/// its name.
+const ADD_BUNDLE_PATTERNS: &[(&str, &str)] = &[
+    ("füge mein ", " zur einkaufsliste hinzu"),
+    ("füge mein ", " hinzu"),
+    ("ich brauche mein ", ""),
+];
+
+/// Utterance patterns that add an item to a bundle, as (prefix, connector,
+/// suffix) triples around the item and the bundle name.
+const EXTEND_BUNDLE_PATTERNS: &[(&str, &str, &str)] = &[
+    ("füge ", " zu meinem ", " hinzu"),
+    ("nimm ", " in mein ", " auf"),
+];
+
+/// Patterns deleting or reading back a bundle, as (intent, prefix, suffix)
+/// triples around its name.
+const BUNDLE_PATTERNS: &[(&str, &str, &str)] = &[
+    (intent_names::DELETE_BUNDLE, "lösche mein ", ""),
+    (intent_names::READ_BUNDLE, "lies mein ", " vor"),
+    (intent_names::READ_BUNDLE, "was ist in meinem ", ""),
+];
+
+/// Utterance patterns that remove an item, as (prefix, suffix) pairs.
+const REMOVE_ITEM_PATTERNS: &[(&str, &str)] = &[
+    ("entferne ", " von der einkaufsliste"),
+    ("entferne ", " von der liste"),
+    ("lösche ", " von der liste"),
+    ("entferne ", ""),
+    ("lösche ", ""),
+];
+
+/// Utterance patterns that add an item with a reminder, as (prefix, suffix)
+/// pairs. The simulator has no reminder duration, so the default applies.
+const REMIND_PATTERNS: &[(&str, &str)] = &[
+    ("erinnere mich, ", " zu kaufen"),
+    ("erinnere mich an ", ""),
+    ("erinnere mich ", " zu kaufen"),
+];
+
+/// Patterns naming an occasion list, as (intent, prefix, connector) triples.
+///
+/// The text between prefix and connector becomes the item slot value, the
+/// text after the connector the list name, e.g. "setz Kerzen auf die
+/// Geburtstagsliste".
+const LIST_CONTEXT_PATTERNS: &[(&str, &str, &str)] = &[
+    (intent_names::ADD_ITEM, "setz ", " auf die "),
+    (intent_names::ADD_ITEM, "setze ", " auf die "),
+    (intent_names::REMOVE_ITEM, "entferne ", " von der "),
+    (intent_names::REMOVE_ITEM, "lösche ", " von der "),
+];
+
+/// Patterns reading an occasion list, as (prefix, suffix) pairs around the
+/// list name.
+const READ_LIST_CONTEXT_PATTERNS: &[(&str, &str)] =
+    &[("was steht auf der ", ""), ("lies die ", " vor")];
+
+/// Utterances that open the skill.
+const LAUNCH_PHRASES: &[&str] = &["", "öffne cookidoo", "starte cookidoo", "cookidoo"];
+
+/// Converts a typed German utterance into an intent, mimicking the
+/// interaction model closely enough for local testing.
+///
+/// Anything that matches no pattern is routed to `AMAZON.FallbackIntent`.
+pub fn parse_utterance(utterance: &str) -> SimulatedIntent {
+    let trimmed = utterance.trim().trim_end_matches(['.', '!', '?']);
+    let lower = trimmed.to_lowercase();
+
+    if LAUNCH_PHRASES.contains(&lower.as_str()) {
+        return SimulatedIntent::Launch;
+    }
+
+    match lower.as_str() {
+        "hilfe" | "was kann ich sagen" => return intent(intent_names::HELP),
+        "stopp" | "stop" | "ende" => return intent(intent_names::STOP),
+        "abbrechen" => return intent(intent_names::CANCEL),
+        "was steht auf der liste"
+        | "was steht auf meiner einkaufsliste"
+        | "lies die liste vor"
+        | "lies meine einkaufsliste vor" => return intent(intent_names::READ_LIST),
+        "was fehlt noch" | "was muss ich noch kaufen" | "was ist noch offen" => {
+            return intent(intent_names::READ_OPEN_ITEMS)
+        }
+        "welche sets habe ich" => return intent(intent_names::READ_BUNDLE),
+        _ => {}
+    }
+
+    for (prefix, connector, suffix) in EXTEND_BUNDLE_PATTERNS {
+        let Some(text) = between(trimmed, prefix, suffix) else {
+            continue;
+        };
+        if let Some((start, end)) = find_ignore_case(text, connector) {
+            let item = text[..start].trim();
+            let bundle = text[end..].trim();
+            if !item.is_empty() && !bundle.is_empty() {
+                return SimulatedIntent::Intent {
+                    name: intent_names::EXTEND_BUNDLE,
+                    slots: vec![
+                        (slot_names::BUNDLE, bundle.to_string()),
+                        (slot_names::ITEM, item.to_string()),
+                    ],
+                };
+            }
+        }
+    }
+
+    for (name, prefix, suffix) in BUNDLE_PATTERNS {
+        if let Some(bundle) = between(trimmed, prefix, suffix) {
+            return SimulatedIntent::Intent {
+                name,
+                slots: vec![(slot_names::BUNDLE, bundle.to_string())],
+            };
+        }
+    }
+
+    for (prefix, suffix) in ADD_BUNDLE_PATTERNS {
+        if let Some(bundle) = between(trimmed, prefix, suffix) {
+            return SimulatedIntent::Intent {
+                name: intent_names::ADD_BUNDLE,
+                slots: vec![(slot_names::BUNDLE, bundle.to_string())],
+            };
+        }
+    }
+
+    for (name, prefix, connector) in LIST_CONTEXT_PATTERNS {
+        let Some(rest) = strip_prefix_ignore_case(trimmed, prefix) else {
+            continue;
+        };
+        if let Some((start, end)) = rfind_ignore_case(rest, connector) {
+            let list = rest[end..].trim();
+            if is_occasion_list(list) {
+                let item = rest[..start].trim();
+                if !item.is_empty() {
+                    return SimulatedIntent::Intent {
+                        name,
+                        slots: vec![
+                            (slot_names::ITEM, item.to_string()),
+                            (slot_names::LIST_NAME, list.to_string()),
+                        ],
+                    };
+                }
+            }
+        }
+    }
+
+    for (prefix, suffix) in READ_LIST_CONTEXT_PATTERNS {
+        if let Some(list) = between(trimmed, prefix, suffix) {
+            if is_occasion_list(list) {
+                return SimulatedIntent::Intent {
+                    name: intent_names::READ_LIST,
+                    slots: vec![(slot_names::LIST_NAME, list.to_string())],
+                };
+            }
+        }
+    }
+
+    for (name, patterns) in [
+        (intent_names::ADD_ITEM, ADD_ITEM_PATTERNS),
+        (intent_names::REMOVE_ITEM, REMOVE_ITEM_PATTERNS),
+        (intent_names::REMIND, REMIND_PATTERNS),
+    ] {
+        for (prefix, suffix) in patterns {
+            if let Some(item) = between(trimmed, prefix, suffix) {
+                return SimulatedIntent::Intent {
+                    name,
+                    slots: vec![(slot_names::ITEM, item.to_string())],
+                };
+            }
+        }
+    }
+
+    intent(intent_names::FALLBACK)
+}
+
+/// Returns the non-empty text between prefix and suffix, matched case-insensitively.
+fn between<'a>(trimmed: &'a str, prefix: &str, suffix: &str) -> Option<&'a str> {
+    let rest = strip_prefix_ignore_case(trimmed, prefix)?;
+    let text = strip_suffix_ignore_case(rest, suffix)?;
+    Some(text.trim()).filter(|text| !text.is_empty())
+}
+
+// Patterns are lowercase; the text is compared char by char, since
+// lowercasing can change its byte length ("ẞ" becomes "ß") and offsets in
+// a lowercased copy would not fit the original.
+
+/// Strips a lowercase `prefix` from `text`, ignoring the case of `text`.
+fn strip_prefix_ignore_case<'a>(text: &'a str, prefix: &str) -> Option<&'a str> {
+    let mut rest = prefix;
+    for (index, c) in text.char_indices() {
+        if rest.is_empty() {
+            return Some(&text[index..]);
+        }
+        rest = rest.strip_prefix(c.to_lowercase().collect::<String>().as_str())?;
+    }
+    rest.is_empty().then_some("")
+}
+
+/// Strips a lowercase `suffix` from `text`, ignoring the case of `text`.
+fn strip_suffix_ignore_case<'a>(text: &'a str, suffix: &str) -> Option<&'a str> {
+    let mut rest = suffix;
+    for (index, c) in text.char_indices().rev() {
+        if rest.is_empty() {
+            return Some(&text[..index + c.len_utf8()]);
+        }
+        rest = rest.strip_suffix(c.to_lowercase().collect::<String>().as_str())?;
+    }
+    rest.is_empty().then_some("")
+}
+
+/// Returns the byte range of the first occurrence of a lowercase `needle`
+/// in `text`, ignoring the case of `text`.
+fn find_ignore_case(text: &str, needle: &str) -> Option<(usize, usize)> {
+    text.char_indices()
+        .map(|(start, _)| start)
+        .find_map(|start| match_at(text, start, needle))
+}
+
+/// Like [`find_ignore_case`], but returns the last occurrence.
+fn rfind_ignore_case(text: &str, needle: &str) -> Option<(usize, usize)> {
+    text.char_indices()
+        .rev()
+        .map(|(start, _)| start)
+        .find_map(|start| match_at(text, start, needle))
+}
+
+fn match_at(text: &str, start: usize, needle: &str) -> Option<(usize, usize)> {
+    let rest = strip_prefix_ignore_case(&text[start..], needle)?;
+    Some((start, text.len() - rest.len()))
+}
+
+/// Returns true for a list name other than the shopping list itself.
+fn is_occasion_list(name: &str) -> bool {
+    let lower = name.to_lowercase();
+    lower.ends_with("liste") && lower != "liste" && lower != "einkaufsliste"
+}
+
+fn intent(name: &'static str) -> SimulatedIntent {
+    SimulatedIntent::Intent {
+        name,
+        slots: Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn add_item(item: &str) -> SimulatedIntent {
+        SimulatedIntent::Intent {
+            name: intent_names::ADD_ITEM,
+            slots: vec![(slot_names::ITEM, item.to_string())],
+        }
+    }
+
+    #[test]
+    fn empty_utterance_launches() {
+        assert_eq!(parse_utterance(""), SimulatedIntent::Launch);
+        assert_eq!(parse_utterance("Öffne Cookidoo"), SimulatedIntent::Launch);
+    }
+
+    #[test]
+    fn parses_add_item_with_hinzu() {
+        assert_eq!(parse_utterance("Füge Milch hinzu"), add_item("Milch"));
+    }
+
+    #[test]
+    fn parses_add_item_to_shopping_list() {
+        assert_eq!(
+            parse_utterance("füge frische Eier zur Einkaufsliste hinzu."),
+            add_item("frische Eier")
+        );
+    }
+
+    #[test]
+    fn keeps_original_spelling_when_lowercase_differs_in_length() {
+        assert_eq!(
+            parse_utterance("Füge GROẞE Eier hinzu"),
+            add_item("GROẞE Eier")
+        );
+        assert_eq!(
+            parse_utterance("Setz İnci Tee auf die Geburtstagsliste"),
+            SimulatedIntent::Intent {
+                name: intent_names::ADD_ITEM,
+                slots: vec![
+                    (slot_names::ITEM, "İnci Tee".to_string()),
+                    (slot_names::LIST_NAME, "Geburtstagsliste".to_string()),
+                ],
+            }
+        );
+        assert_eq!(
+            parse_utterance("FÜGE ẞ ZU MEINEM GRILL-SET HINZU"),
+            SimulatedIntent::Intent {
+                name: intent_names::EXTEND_BUNDLE,
+                slots: vec![
+                    (slot_names::BUNDLE, "GRILL-SET".to_string()),
+                    (slot_names::ITEM, "ẞ".to_string()),
+                ],
+            }
+        );
+    }
+
+    #[test]
+    fn parses_ich_brauche() {
+        assert_eq!(parse_utterance("Ich brauche Butter"), add_item("Butter"));
+    }
+
+    #[test]
+    fn parses_builtin_intents() {
+        assert_eq!(parse_utterance("Hilfe"), intent(intent_names::HELP));
+        assert_eq!(parse_utterance("Stopp"), intent(intent_names::STOP));
+        assert_eq!(parse_utterance("abbrechen"), intent(intent_names::CANCEL));
+    }
+
+    #[test]
+    fn parses_read_list() {
+        assert_eq!(
+            parse_utterance("Was steht auf meiner Einkaufsliste?"),
+            intent(intent_names::READ_LIST)
+        );
+        assert_eq!(
+            parse_utterance("Was fehlt noch?"),
+            intent(intent_names::READ_OPEN_ITEMS)
+        );
+    }
+
+    #[test]
+    fn parses_add_bundle() {
+        assert_eq!(
+            parse_utterance("Füge mein Frühstücks-Set hinzu"),
+            SimulatedIntent::Intent {
+                name: intent_names::ADD_BUNDLE,
+                slots: vec![(slot_names::BUNDLE, "Frühstücks-Set".to_string())],
+            }
+        );
+    }
+
+    #[test]
+    fn parses_bundle_management() {
+        assert_eq!(
+            parse_utterance("Füge Butter zu meinem Frühstücks-Set hinzu"),
+            SimulatedIntent::Intent {
+                name: intent_names::EXTEND_BUNDLE,
+                slots: vec![
+                    (slot_names::BUNDLE, "Frühstücks-Set".to_string()),
+                    (slot_names::ITEM, "Butter".to_string()),
+                ],
+            }
+        );
+        assert_eq!(
+            parse_utterance("Lösche mein Grill-Set"),
+            SimulatedIntent::Intent {
+                name: intent_names::DELETE_BUNDLE,
+                slots: vec![(slot_names::BUNDLE, "Grill-Set".to_string())],
+            }
+        );
+        assert_eq!(
+            parse_utterance("Welche Sets habe ich?"),
+            intent(intent_names::READ_BUNDLE)
+        );
+    }
+
+    #[test]
+    fn parses_add_to_occasion_list() {
+        assert_eq!(
+            parse_utterance("Setz Kerzen auf die Geburtstagsliste"),
+            SimulatedIntent::Intent {
+                name: intent_names::ADD_ITEM,
+                slots: vec![
+                    (slot_names::ITEM, "Kerzen".to_string()),
+                    (slot_names::LIST_NAME, "Geburtstagsliste".to_string()),
+                ],
+            }
+        );
+    }
+
+    #[test]
+    fn shopping_list_is_not_an_occasion_list() {
+        assert_eq!(
+            parse_utterance("setz Milch auf die Liste"),
+            add_item("Milch")
+        );
+    }
+
+    #[test]
+    fn parses_remove_item() {
+        assert_eq!(
+            parse_utterance("Entferne Milch von der Liste"),
+            SimulatedIntent::Intent {
+                name: intent_names::REMOVE_ITEM,
+                slots: vec![(slot_names::ITEM, "Milch".to_string())],
+            }
+        );
+    }
+
+    #[test]
+    fn parses_read_occasion_list() {
+        assert_eq!(
+            parse_utterance("Lies die Geburtstagsliste vor"),
+            SimulatedIntent::Intent {
+                name: intent_names::READ_LIST,
+                slots: vec![(slot_names::LIST_NAME, "Geburtstagsliste".to_string())],
+            }
+        );
+    }
+
+    #[test]
+    fn add_pattern_without_item_falls_back() {
+        assert_eq!(
+            parse_utterance("füge hinzu"),
+            intent(intent_names::FALLBACK)
+        );
+    }
+
+    #[test]
+    fn unmatched_utterance_falls_back() {
+        assert_eq!(
+            parse_utterance("wie wird das Wetter"),
+            intent(intent_names::FALLBACK)
+        );
+    }
+
+    #[test]
+    fn parses_reminder() {
+        let expected = SimulatedIntent::Intent {
+            name: intent_names::REMIND,
+            slots: vec![(slot_names::ITEM, "Milch".to_string())],
+        };
+        assert_eq!(parse_utterance("Erinnere mich, Milch zu kaufen"), expected);
+        assert_eq!(parse_utterance("erinnere mich an Milch"), expected);
+    }
+}
@@ -0,0 +1,128 @@
+use serde_json::Value;
+
+use crate::adapters::logging::PAYLOAD_FIELD;
+
+/// Fields that may hold the request or the log line wrapping it: the
+/// exports of `aws logs filter-log-events` (`events`, `message`) and of
+/// Logs Insights (`@message`), and the skill's own capture.
+const WRAPPING_FIELDS: &[&str] = &["events", "message", "@message", PAYLOAD_FIELD];
+
+/// Returns the Alexa request payloads found in exported log lines, in
+/// order.
+///
+/// Each line may be a request itself, a skill log line with the request
+/// in its [`PAYLOAD_FIELD`], or a CloudWatch export wrapping such a line,
+/// also with a timestamp before the JSON. Lines without a request are
+/// skipped.
+pub fn extract_requests(text: &str) -> Vec<Value> {
+    let mut requests = Vec::new();
+    for line in text.lines() {
+        if let Some(value) = parse_json(line) {
+            collect(value, &mut requests);
+        }
+    }
+    requests
+}
+
+/// Parses `text` as JSON, skipping a prefix such as a timestamp or a
+/// bracketed log level before the JSON.
+///
+/// A prefix may itself contain brackets, so parsing is tried from each
+/// `{` and `[` in turn until one yields JSON.
+fn parse_json(text: &str) -> Option<Value> {
+    text.match_indices(['{', '['])
+        .find_map(|(start, _)| serde_json::from_str(text[start..].trim()).ok())
+}
+
+fn collect(value: Value, requests: &mut Vec<Value>) {
+    match value {
+        Value::Array(values) => {
+            for value in values {
+                collect(value, requests);
+            }
+        }
+        Value::Object(_) if is_request(&value) => requests.push(value),
+        Value::Object(mut object) => {
+            for field in WRAPPING_FIELDS {
+                match object.remove(*field) {
+                    Some(Value::String(text)) => {
+                        if let Some(value) = parse_json(&text) {
+                            collect(value, requests);
+                        }
+                    }
+                    Some(value) => collect(value, requests),
+                    None => {}
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Returns true for an Alexa request envelope.
+fn is_request(value: &Value) -> bool {
+    value.get("version").is_some() && value.get("request").is_some_and(Value::is_object)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn request(id: &str) -> Value {
+        json!({
+            "version": "1.0",
+            "request": {"type": "LaunchRequest", "requestId": id}
+        })
+    }
+
+    #[test]
+    fn reads_requests_from_all_line_formats() {
+        let captured = json!({
+            "level": "INFO",
+            "message": "Captured Alexa request",
+            "payload": request("2").to_string()
+        });
+        let exported = json!({"timestamp": 1, "message": captured.to_string()});
+        let text = format!(
+            "{}\n{}\n2024-01-27T10:00:00Z {}\n{}\n",
+            request("1"),
+            captured,
+            json!({"level": "INFO", "payload": request("3")}),
+            json!({"events": [exported, {"@message": request("4").to_string()}]}),
+        );
+
+        let ids: Vec<_> = extract_requests(&text)
+            .iter()
+            .map(|request| request["request"]["requestId"].clone())
+            .collect();
+
+        assert_eq!(ids, ["1", "2", "3", "2", "4"]);
+    }
+
+    #[test]
+    fn reads_requests_after_bracketed_prefix() {
+        let text = format!(
+            "[INFO] 2024-01-27T10:00:00Z abc {}\n[{},{}]\n",
+            request("1"),
+            request("2"),
+            request("3"),
+        );
+
+        let ids: Vec<_> = extract_requests(&text)
+            .iter()
+            .map(|request| request["request"]["requestId"].clone())
+            .collect();
+
+        assert_eq!(ids, ["1", "2", "3"]);
+    }
+
+    #[test]
+    fn skips_lines_without_request() {
+        let text = format!(
+            "START RequestId: abc\n{}\n",
+            json!({"level": "INFO", "message": "Received Alexa request"})
+        );
+        assert!(extract_requests(&text).is_empty());
+    }
+}
@@ -0,0 +1,163 @@
+use serde_json::{json, Map, Value};
+
+use crate::adapters::alexa::AlexaRequest;
+
+use super::grammar::SimulatedIntent;
+
+/// Locale used for all simulated requests.
+const LOCALE: &str = "de-DE";
+
+/// Fixed timestamp for simulated requests; the skill does not inspect it.
+const TIMESTAMP: &str = "2024-01-01T00:00:00Z";
+
+/// Builds Alexa request envelopes for a simulated session.
+///
+/// Keeps track of the session so follow-up requests look like they came
+/// from the same conversation, as they would on a real device.
+pub struct RequestFactory {
+    session_counter: u32,
+    request_counter: u32,
+    session_is_new: bool,
+}
+
+impl RequestFactory {
+    /// Creates a new factory starting a fresh session.
+    pub fn new() -> Self {
+        Self {
+            session_counter: 1,
+            request_counter: 0,
+            session_is_new: true,
+        }
+    }
+
+    /// Starts a new session for the next request.
+    pub fn end_session(&mut self) {
+        self.session_counter += 1;
+        self.session_is_new = true;
+    }
+
+    /// Builds the JSON envelope for the given intent.
+    pub fn build_json(&mut self, intent: &SimulatedIntent) -> Value {
+        self.request_counter += 1;
+
+        let request = match intent {
+            SimulatedIntent::Launch => json!({
+                "type": "LaunchRequest",
+                "requestId": self.request_id(),
+                "timestamp": TIMESTAMP,
+                "locale": LOCALE
+            }),
+            SimulatedIntent::Intent { name, slots } => {
+                let slots: Map<String, Value> = slots
+                    .iter()
+                    .map(|(slot, value)| {
+                        (slot.to_string(), json!({ "name": slot, "value": value }))
+                    })
+                    .collect();
+
+                json!({
+                    "type": "IntentRequest",
+                    "requestId": self.request_id(),
+                    "timestamp": TIMESTAMP,
+                    "locale": LOCALE,
+                    "intent": { "name": name, "slots": slots }
+                })
+            }
+        };
+
+        let envelope = json!({
+            "version": "1.0",
+            "session": {
+                "new": self.session_is_new,
+                "sessionId": format!("simulator.session.{}", self.session_counter),
+                "application": { "applicationId": "simulator.skill" },
+                "user": { "userId": "simulator.user" }
+            },
+            "request": request
+        });
+
+        self.session_is_new = false;
+        envelope
+    }
+
+    /// Builds a typed Alexa request for the given intent.
+    pub fn build(&mut self, intent: &SimulatedIntent) -> AlexaRequest {
+        serde_json::from_value(self.build_json(intent))
+            .expect("Simulated request must match the Alexa request model")
+    }
+
+    fn request_id(&self) -> String {
+        format!("simulator.request.{}", self.request_counter)
+    }
+}
+
+impl Default for RequestFactory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::alexa::{intent_names, slot_names};
+
+    #[test]
+    fn builds_launch_request() {
+        let mut factory = RequestFactory::new();
+        let json = factory.build_json(&SimulatedIntent::Launch);
+
+        assert_eq!(json["request"]["type"], "LaunchRequest");
+        assert_eq!(json["session"]["new"], true);
+    }
+
+    #[test]
+    fn builds_intent_request_with_slots() {
+        let mut factory = RequestFactory::new();
+        let json = factory.build_json(&SimulatedIntent::Intent {
+            name: intent_names::ADD_ITEM,
+            slots: vec![(slot_names::ITEM, "Milch".to_string())],
+        });
+
+        assert_eq!(json["request"]["intent"]["name"], "AddItemIntent");
+        assert_eq!(json["request"]["intent"]["slots"]["Item"]["value"], "Milch");
+    }
+
+    #[test]
+    fn follow_up_requests_share_session() {
+        let mut factory = RequestFactory::new();
+        let first = factory.build_json(&SimulatedIntent::Launch);
+        let second = factory.build_json(&SimulatedIntent::Launch);
+
+        assert_eq!(
+            first["session"]["sessionId"],
+            second["session"]["sessionId"]
+        );
+        assert_eq!(second["session"]["new"], false);
+        assert_ne!(
+            first["request"]["requestId"],
+            second["request"]["requestId"]
+        );
+    }
+
+    #[test]
+    fn end_session_starts_new_session() {
+        let mut factory = RequestFactory::new();
+        let first = factory.build_json(&SimulatedIntent::Launch);
+        factory.end_session();
+        let second = factory.build_json(&SimulatedIntent::Launch);
+
+        assert_ne!(
+            first["session"]["sessionId"],
+            second["session"]["sessionId"]
+        );
+        assert_eq!(second["session"]["new"], true);
+    }
+
+    #[test]
+    fn built_request_deserializes() {
+        let mut factory = RequestFactory::new();
+        let request = factory.build(&SimulatedIntent::Launch);
+        assert_eq!(request.version, "1.0");
+    }
+}
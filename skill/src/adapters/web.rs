@@ -0,0 +1,102 @@
+use std::fmt::Debug;
+use std::io;
+
+use axum::extract::DefaultBodyLimit;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::serve::Listener;
+use axum::{Json, Router};
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use crate::adapters::replies::add_item_speech;
+use crate::domain::models::AddItemOutcome;
+use crate::domain::services::AddItemService;
+
+/// Largest accepted request body; Alexa requests are well below this.
+pub const MAX_BODY_BYTES: usize = 64 * 1024;
+
+/// Serves a router on the given listener until it fails.
+///
+/// Shared by serve mode and the self-hosted endpoint, so both limit request
+/// bodies (larger ones are answered with 413) and handle connections the
+/// same way. Each connection is handled on its own task.
+pub async fn serve<L>(listener: L, router: Router) -> io::Result<()>
+where
+    L: Listener,
+    L::Addr: Debug,
+{
+    info!(address = ?listener.local_addr()?, "HTTP server listening");
+
+    axum::serve(
+        listener,
+        router.layer(DefaultBodyLimit::max(MAX_BODY_BYTES)),
+    )
+    .await
+}
+
+/// Request body of adding an item.
+#[derive(Debug, Deserialize)]
+struct AddItemBody {
+    name: String,
+}
+
+/// Body of successful write requests.
+#[derive(Debug, Serialize)]
+pub(crate) struct MessageDto {
+    pub message: String,
+}
+
+/// Body of failed requests.
+#[derive(Debug, Serialize)]
+pub(crate) struct ErrorDto {
+    pub error: String,
+    /// Support code of a failed backend call, e.g. "E302".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+}
+
+/// Answers with a JSON body.
+pub(crate) fn json<T: Serialize>(status: StatusCode, body: &T) -> Response {
+    (status, Json(body)).into_response()
+}
+
+/// Answers with an [`ErrorDto`] without support code.
+pub(crate) fn error(status: StatusCode, message: impl Into<String>) -> Response {
+    json(
+        status,
+        &ErrorDto {
+            error: message.into(),
+            code: None,
+        },
+    )
+}
+
+/// Adds the item named in a `{"name": "Milch"}` body and answers with the
+/// outcome's speech.
+pub(crate) async fn add_item(service: &AddItemService, body: &[u8]) -> Response {
+    let Ok(AddItemBody { name }) = serde_json::from_slice(body) else {
+        return error(StatusCode::BAD_REQUEST, "Expected {\"name\": \"...\"}");
+    };
+
+    let outcome = service.execute(&name).await;
+    let message = add_item_speech(&outcome);
+    let status = match &outcome {
+        AddItemOutcome::Added { .. } => StatusCode::CREATED,
+        AddItemOutcome::Duplicate { .. } | AddItemOutcome::ListFull => StatusCode::CONFLICT,
+        AddItemOutcome::InvalidName { .. } => StatusCode::BAD_REQUEST,
+        AddItemOutcome::RateLimited => StatusCode::TOO_MANY_REQUESTS,
+        AddItemOutcome::Maintenance => StatusCode::SERVICE_UNAVAILABLE,
+        AddItemOutcome::AuthFailed | AddItemOutcome::Unavailable => StatusCode::BAD_GATEWAY,
+    };
+    match outcome.error_code() {
+        None => json(status, &MessageDto { message }),
+        Some(code) => json(
+            status,
+            &ErrorDto {
+                error: message,
+                code: Some(code.to_string()),
+            },
+        ),
+    }
+}
@@ -1,7 +1,9 @@
 mod config;
 mod dependency_injection;
 mod lambda_handler;
+mod skill_event;
 
 pub use config::AppConfig;
 pub use dependency_injection::Container;
-pub use lambda_handler::handle_request;
+pub use lambda_handler::{handle_event, handle_payload};
+pub use skill_event::{PayloadError, SkillEvent};
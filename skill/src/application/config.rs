@@ -1,52 +1,485 @@
-use std::env;
+mod settings;
 
-use crate::domain::models::CookidooCredentials;
+use std::num::NonZeroU32;
 
-/// Environment variable names.
+use http::header::{HeaderName, HeaderValue};
+
+use crate::adapters::alexa::PhraseVariation;
+use crate::adapters::cookidoo::{AuthHeader, AuthHeaderError, FixtureMode, ProxyConfig};
+use crate::adapters::logging::{CaptureMode, LogScrubber, RequestCapture};
+use crate::domain::models::{
+    Category, CookidooCredentials, HouseholdAccount, HouseholdAccounts, ItemBundle, ItemBundles,
+    Market, PantryStaples, QuantityStyle, Secret, SpeechStyle,
+};
+
+use settings::{SettingValue, Settings};
+
+/// Environment variable names for error messages; every setting is
+/// declared in [`Settings`].
 mod env_vars {
     pub const COOKIDOO_EMAIL: &str = "COOKIDOO_EMAIL";
     pub const COOKIDOO_PASSWORD: &str = "COOKIDOO_PASSWORD";
     pub const COOKIDOO_CLIENT_ID: &str = "COOKIDOO_CLIENT_ID";
     pub const COOKIDOO_CLIENT_SECRET: &str = "COOKIDOO_CLIENT_SECRET";
+    pub const COOKIDOO_REFRESH_TOKEN: &str = "COOKIDOO_REFRESH_TOKEN";
+    pub const COOKIDOO_REFRESH_TOKEN_PARAMETER: &str = "COOKIDOO_REFRESH_TOKEN_PARAMETER";
+    pub const REFRESH_TOKEN_TABLE_NAME: &str = "REFRESH_TOKEN_TABLE_NAME";
+    pub const COOKIDOO_CREDENTIALS_SECRET: &str = "COOKIDOO_CREDENTIALS_SECRET";
+    pub const COOKIDOO_PASSWORD_GRANT: &str = "COOKIDOO_PASSWORD_GRANT";
+    pub const COOKIDOO_BASE_URL: &str = "COOKIDOO_BASE_URL";
+    pub const COOKIDOO_TOKEN_PATH: &str = "COOKIDOO_TOKEN_PATH";
+    pub const COOKIDOO_SHOPPING_PATH: &str = "COOKIDOO_SHOPPING_PATH";
+    pub const COOKIDOO_PROXY_URL: &str = "COOKIDOO_PROXY_URL";
+    pub const COOKIDOO_PROXY_USERNAME: &str = "COOKIDOO_PROXY_USERNAME";
+    pub const COOKIDOO_SHADOW_BASE_URL: &str = "COOKIDOO_SHADOW_BASE_URL";
+    pub const COOKIDOO_SHADOW_EMAIL: &str = "COOKIDOO_SHADOW_EMAIL";
+    pub const COOKIDOO_SHADOW_PASSWORD: &str = "COOKIDOO_SHADOW_PASSWORD";
+    pub const LOG_CAPTURE_REQUESTS: &str = "LOG_CAPTURE_REQUESTS";
+    pub const LOG_CAPTURE_KEY: &str = "LOG_CAPTURE_KEY";
+    pub const TLS_CERT_PATH: &str = "TLS_CERT_PATH";
+    pub const TLS_KEY_PATH: &str = "TLS_KEY_PATH";
+    pub const ALEXA_SKILL_ID: &str = "ALEXA_SKILL_ID";
 }
 
 /// Application configuration loaded from environment variables.
 #[derive(Debug, Clone)]
 pub struct AppConfig {
     cookidoo_credentials: CookidooCredentials,
+    cookidoo_credentials_secret: Option<String>,
     cookidoo_client_id: String,
-    cookidoo_client_secret: String,
+    cookidoo_client_secret: Secret,
+    cookidoo_refresh_token: Option<Secret>,
+    cookidoo_refresh_token_parameter: Option<String>,
+    refresh_token_table_name: Option<String>,
+    cookidoo_password_grant: bool,
+    idempotency_table_name: Option<String>,
+    occasion_list_table_name: Option<String>,
+    preferences_table_name: Option<String>,
+    history_table_name: Option<String>,
+    permissions_table_name: Option<String>,
+    category_overrides: Vec<(String, Category)>,
+    item_aliases: Vec<(String, String)>,
+    item_alias_table_name: Option<String>,
+    item_bundles: ItemBundles,
+    maintenance_mode: bool,
+    maintenance_parameter_name: Option<String>,
+    cookidoo_fixture_mode: Option<FixtureMode>,
+    cookidoo_fixture_dir: String,
+    speech_style: SpeechStyle,
+    add_more_prompt: bool,
+    item_quantity_style: QuantityStyle,
+    pantry_staples: PantryStaples,
+    phrase_variation: PhraseVariation,
+    cookidoo_market: Option<Market>,
+    cookidoo_base_url: Option<String>,
+    cookidoo_token_path: Option<String>,
+    cookidoo_shopping_path: Option<String>,
+    cookidoo_proxy: Option<ProxyConfig>,
+    household_accounts: HouseholdAccounts,
+    cookidoo_startup_check: bool,
+    cookidoo_shadow_base_url: Option<String>,
+    cookidoo_shadow_credentials: Option<CookidooCredentials>,
+    cookidoo_user_agent: Option<HeaderValue>,
+    cookidoo_headers: Vec<(HeaderName, HeaderValue)>,
+    cookidoo_accept_language: bool,
+    cookidoo_rate_limit: Option<(NonZeroU32, f64)>,
+    log_scrubber: LogScrubber,
+    log_capture_requests: CaptureMode,
+    log_capture_key: Option<Secret>,
+    serve_addr: Option<String>,
+    http_server_addr: Option<String>,
+    alexa_skill_id: Option<String>,
+    tls: Option<(String, String)>,
+    home_assistant_token: Option<Secret>,
 }
 
+/// Directory for recorded Cookidoo fixtures unless `COOKIDOO_FIXTURE_DIR` is set.
+const DEFAULT_FIXTURE_DIR: &str = "tests/fixtures/cookidoo_recorded";
+
+/// Placeholder printed in [`AppConfig::summary`] instead of a secret's value.
+const REDACTED: &str = "[REDACTED]";
+
 impl AppConfig {
     /// Loads configuration from environment variables.
     ///
+    /// Every variable can also be set in the JSON file named by
+    /// `CONFIG_FILE`, e.g. `{"SPEECH_STYLE": "brief"}`; the environment takes
+    /// precedence over the file, and the file over the defaults.
+    ///
     /// # Required Environment Variables
-    /// - `COOKIDOO_EMAIL`: Cookidoo account email
-    /// - `COOKIDOO_PASSWORD`: Cookidoo account password
     /// - `COOKIDOO_CLIENT_ID`: Cookidoo OAuth client ID
     /// - `COOKIDOO_CLIENT_SECRET`: Cookidoo OAuth client secret
+    /// - `COOKIDOO_REFRESH_TOKEN`: refresh token from the authorization-code
+    ///   flow, or `COOKIDOO_REFRESH_TOKEN_PARAMETER`: SSM parameter holding
+    ///   it (as written by `cookidoo-login`), or `REFRESH_TOKEN_TABLE_NAME`
+    ///   (see below), or else `COOKIDOO_EMAIL` and
+    ///   `COOKIDOO_PASSWORD` for the password grant, or
+    ///   `COOKIDOO_CREDENTIALS_SECRET`: Secrets Manager secret holding
+    ///   `{"email": "...", "password": "..."}`, read again when Cookidoo
+    ///   rejects the password so a rotation needs no redeploy
+    ///
+    /// # Optional Environment Variables
+    /// - `COOKIDOO_PASSWORD_GRANT`: `true` falls back to the password grant
+    ///   (then requiring `COOKIDOO_EMAIL`/`COOKIDOO_PASSWORD`) when the
+    ///   refresh token is rejected (default: `false` with a refresh token)
+    /// - `REFRESH_TOKEN_TABLE_NAME`: DynamoDB table holding the refresh
+    ///   token (as written by `cookidoo-login`); refresh tokens Cookidoo
+    ///   rotates are written back to it, and it takes precedence over
+    ///   `COOKIDOO_REFRESH_TOKEN` once it holds one (requires the `dynamodb`
+    ///   feature)
+    /// - `IDEMPOTENCY_TABLE_NAME`: DynamoDB table for duplicate request
+    ///   detection (requires the `dynamodb` feature; in-memory otherwise)
+    /// - `OCCASION_LIST_TABLE_NAME`: DynamoDB table for gift/occasion lists
+    ///   (requires the `dynamodb` feature; in-memory otherwise)
+    /// - `PREFERENCES_TABLE_NAME`: DynamoDB table for per-user preferences
+    ///   (requires the `dynamodb` feature; in-memory otherwise)
+    /// - `HISTORY_TABLE_NAME`: DynamoDB table for the history of added items
+    ///   (requires the `dynamodb` feature; in-memory otherwise)
+    /// - `PERMISSIONS_TABLE_NAME`: DynamoDB table for the permissions users
+    ///   granted (requires the `dynamodb` feature; in-memory otherwise)
+    /// - `CATEGORY_OVERRIDES`: extra category keywords as
+    ///   `keyword=Category;...`, e.g. `Tofu=Milchprodukte;Hafermilch=Getränke`
+    /// - `ITEM_ALIASES`: extra item aliases as `Alias=Name;...`, e.g.
+    ///   `Haferdrink=Hafermilch;Tempo=Taschentücher`
+    /// - `ITEM_ALIAS_TABLE_NAME`: DynamoDB table with item aliases read at
+    ///   cold start (requires the `dynamodb` feature); `ITEM_ALIASES` wins
+    /// - `ITEM_BUNDLES`: item bundles added by name as
+    ///   `Name=Item,Item;...`, e.g. `Frühstücks-Set=Brötchen,Butter,Marmelade`
+    /// - `MAINTENANCE_MODE`: `true` answers all requests with a maintenance
+    ///   notice (default: `false`)
+    /// - `MAINTENANCE_PARAMETER_NAME`: SSM parameter holding the maintenance
+    ///   flag, read via the Parameters and Secrets Lambda Extension; takes
+    ///   precedence over `MAINTENANCE_MODE`
+    /// - `COOKIDOO_FIXTURE_MODE`: `record` writes sanitized Cookidoo responses
+    ///   to fixtures, `replay` answers requests from them (development only)
+    /// - `COOKIDOO_FIXTURE_DIR`: fixture directory (default:
+    ///   `tests/fixtures/cookidoo_recorded`)
+    /// - `SPEECH_STYLE`: `brief` confirms with just the item ("Okay, Milch.")
+    ///   and shortens the welcome (default: `full`)
+    /// - `ADD_MORE_PROMPT`: `true` asks "Noch etwas?" after an add and adds
+    ///   the next item named (default: `false`)
+    /// - `ITEM_QUANTITY_STYLE`: `label` writes "drei Zitronen" as one entry
+    ///   "3x Zitronen", `repeat` adds "Zitronen" three times (default:
+    ///   `label`)
+    /// - `PANTRY_STAPLES`: comma-separated items skipped when a list is
+    ///   transferred to the shopping list, e.g. `Salz,Pfeffer,Öl`, for users
+    ///   who have not stored their own (default: none)
+    /// - `PHRASE_VARIATION`: `random` varies phrasings per request, `session`
+    ///   per conversation, and a number fixes them for tests (default:
+    ///   `random`)
+    /// - `COOKIDOO_MARKET`: country code of the Cookidoo market, e.g. `AT`
+    ///   (default: detected from the device address, else `DE`)
+    /// - `COOKIDOO_BASE_URL`: Cookidoo API host, e.g. another regional host
+    ///   or a staging proxy; all markets are then served from it
+    ///   (default: the host of the market)
+    /// - `COOKIDOO_TOKEN_PATH`: OAuth token endpoint path (default:
+    ///   `/ciam/auth/token`)
+    /// - `COOKIDOO_SHOPPING_PATH`: additional items API path, with
+    ///   `{language}` replaced by the market's language (default:
+    ///   `/shopping/{language}/additional-items`)
+    /// - `COOKIDOO_PROXY_URL`: proxy for Cookidoo requests, e.g. the egress
+    ///   proxy of a VPC (default: `HTTPS_PROXY` from the environment, if set)
+    /// - `COOKIDOO_PROXY_USERNAME`, `COOKIDOO_PROXY_PASSWORD`: basic auth
+    ///   credentials for `COOKIDOO_PROXY_URL`
+    /// - `COOKIDOO_ACCOUNTS`: further Cookidoo accounts of the household as a
+    ///   JSON array, e.g. `[{"name": "Papa", "credentialsSecret": "...",
+    ///   "personIds": ["amzn1.ask.person..."]}]`, where the Secrets Manager
+    ///   secret holds the account's `{"email": "...", "password": "..."}`
+    ///   like `COOKIDOO_CREDENTIALS_SECRET` (or `"email"` and `"password"`
+    ///   given inline); chosen by voice or by a recognized speaker with
+    ///   "benutze Papas Liste" (password grant only)
+    /// - `COOKIDOO_STARTUP_CHECK`: `true` signs in to Cookidoo at cold start,
+    ///   so wrong credentials are logged once with what to fix instead of
+    ///   failing every request alike (default: `false`)
+    /// - `COOKIDOO_SHADOW_BASE_URL`: second Cookidoo host every list
+    ///   operation is repeated on, logging where it answers differently,
+    ///   e.g. to validate a new endpoint before switching to it; requires
+    ///   `COOKIDOO_SHADOW_EMAIL` and `COOKIDOO_SHADOW_PASSWORD` of another
+    ///   account, so the repeated operations never touch the user's list
+    /// - `COOKIDOO_USER_AGENT`: `User-Agent` of Cookidoo requests (default:
+    ///   `AlexaCookidooSkill/1.0`)
+    /// - `COOKIDOO_HEADERS`: extra headers of Cookidoo requests as
+    ///   `Name=value;...`, e.g. `X-Client-Version=4.1`
+    /// - `COOKIDOO_ACCEPT_LANGUAGE`: `true` sends the market's language,
+    ///   e.g. `de-AT`, as `Accept-Language` (default: `false`)
+    /// - `COOKIDOO_RATE_LIMIT`: burst size and sustained requests per second
+    ///   of Cookidoo requests as `burst/rate`, e.g. `10/5` (the default);
+    ///   both must be positive
+    /// - `LOG_SCRUB_DENY_FIELDS`, `LOG_SCRUB_ALLOW_FIELDS`: further log
+    ///   fields to redact or to log unchanged, comma-separated, e.g.
+    ///   `item_name` to hide spoken items
+    /// - `LOG_SCRUB_SENSITIVE_SLOTS`: slots whose values are redacted in
+    ///   logs, comma-separated, e.g. `Item,Note` (default: `Note,Query`)
+    /// - `LOG_CAPTURE_REQUESTS`: `on` logs every incoming request without
+    ///   personal data, `hashed` also hashes what the user said (default:
+    ///   `off`)
+    /// - `LOG_CAPTURE_KEY`: secret key of the hashes in captured requests,
+    ///   required unless `LOG_CAPTURE_REQUESTS` is `off`
+    /// - `SERVE_ADDR`: listen address of `serve` (default: `0.0.0.0:8080`)
+    /// - `HTTP_SERVER_ADDR`: listen address of `http-server` (default:
+    ///   `0.0.0.0:8443`)
+    /// - `ALEXA_SKILL_ID`: ID of the skill `http-server` answers, e.g.
+    ///   `amzn1.ask.skill.…`; required by `http-server`, which rejects
+    ///   requests for other skills
+    /// - `TLS_CERT_PATH`, `TLS_KEY_PATH`: PEM files for HTTPS in
+    ///   `http-server`, set together (default: plain HTTP)
+    /// - `HOME_ASSISTANT_TOKEN`: bearer token enabling the Home Assistant
+    ///   endpoint of `http-server`
     ///
     /// # Errors
-    /// Returns an error if any required environment variable is missing.
+    /// Returns an error if a required setting is missing or one cannot be
+    /// parsed; with several such settings, [`ConfigError::Multiple`] lists
+    /// all of them.
     pub fn from_env() -> Result<Self, ConfigError> {
-        let email = env::var(env_vars::COOKIDOO_EMAIL)
-            .map_err(|_| ConfigError::MissingEnvVar(env_vars::COOKIDOO_EMAIL.to_string()))?;
+        let (settings, errors) = Settings::load()?;
+        Self::from_settings(settings, errors, true)
+    }
+
+    /// Loads only the optional settings, leaving the Cookidoo credentials
+    /// empty; the OAuth client is kept if set.
+    ///
+    /// For local runs against a backend that does not talk to Cookidoo, such
+    /// as the mock backend of `skill-cli`, and for `cookidoo-login`.
+    ///
+    /// # Errors
+    /// Returns an error if an optional setting cannot be parsed.
+    pub fn from_env_without_credentials() -> Result<Self, ConfigError> {
+        let (settings, errors) = Settings::load()?;
+        Self::from_settings(settings, errors, false)
+    }
+
+    fn from_settings(
+        settings: Settings,
+        mut errors: Vec<ConfigError>,
+        require_credentials: bool,
+    ) -> Result<Self, ConfigError> {
+        let mut cookidoo_credentials = CookidooCredentials::new("", "");
+        let mut cookidoo_refresh_token = None;
+        let mut cookidoo_refresh_token_parameter = None;
+        let mut refresh_token_table_name = None;
+        let mut cookidoo_password_grant = true;
+        let cookidoo_credentials_secret = settings.cookidoo_credentials_secret;
+        let email = settings.cookidoo_email;
+        let password = settings
+            .cookidoo_password
+            .map(|password| password.expose().to_string());
+        if require_credentials {
+            let has_refresh_token = settings.cookidoo_refresh_token.is_some()
+                || settings.cookidoo_refresh_token_parameter.is_some()
+                || settings.refresh_token_table_name.is_some();
+
+            // The password grant is the default only without a refresh token
+            cookidoo_password_grant = settings
+                .cookidoo_password_grant
+                .unwrap_or(!has_refresh_token);
+            if !cookidoo_password_grant && !has_refresh_token {
+                errors.push(ConfigError::InvalidEnvVar(
+                    env_vars::COOKIDOO_PASSWORD_GRANT.to_string(),
+                    format!(
+                        "disabling the password grant requires {}",
+                        env_vars::COOKIDOO_REFRESH_TOKEN
+                    ),
+                ));
+            }
+
+            if cookidoo_password_grant && cookidoo_credentials_secret.is_some() {
+                // The secret replaces these once read
+                cookidoo_credentials = CookidooCredentials::new(
+                    email.unwrap_or_default(),
+                    password.unwrap_or_default(),
+                );
+            } else if cookidoo_password_grant {
+                cookidoo_credentials = CookidooCredentials::new(
+                    required(&mut errors, env_vars::COOKIDOO_EMAIL, email),
+                    required(&mut errors, env_vars::COOKIDOO_PASSWORD, password),
+                );
+            }
+            cookidoo_refresh_token = settings.cookidoo_refresh_token;
+            cookidoo_refresh_token_parameter = settings.cookidoo_refresh_token_parameter;
+            refresh_token_table_name = settings.refresh_token_table_name;
+        }
+
+        // The OAuth client is also kept when the credentials are not
+        // required, for `cookidoo-login`
+        let cookidoo_client_id = match settings.cookidoo_client_id {
+            None if require_credentials => {
+                required(&mut errors, env_vars::COOKIDOO_CLIENT_ID, None)
+            }
+            client_id => client_id.unwrap_or_default(),
+        };
+        let cookidoo_client_secret = match settings.cookidoo_client_secret {
+            None if require_credentials => Secret::new(required(
+                &mut errors,
+                env_vars::COOKIDOO_CLIENT_SECRET,
+                None,
+            )),
+            client_secret => client_secret.unwrap_or_default(),
+        };
+        // Without a usable client every sign-in fails, so refuse to start
+        if require_credentials
+            && !cookidoo_client_id.is_empty()
+            && !cookidoo_client_secret.is_empty()
+        {
+            if let Err(e) = auth_header(&cookidoo_client_id, cookidoo_client_secret.expose()) {
+                errors.push(e);
+            }
+        }
+
+        let cookidoo_proxy = match (
+            settings.cookidoo_proxy_url,
+            settings.cookidoo_proxy_username,
+        ) {
+            (Some(url), Some(username)) => Some(
+                ProxyConfig::new(url).with_basic_auth(
+                    username,
+                    settings
+                        .cookidoo_proxy_password
+                        .as_ref()
+                        .map(Secret::expose)
+                        .unwrap_or_default(),
+                ),
+            ),
+            (Some(url), None) => Some(ProxyConfig::new(url)),
+            (None, Some(_)) => {
+                errors.push(ConfigError::InvalidEnvVar(
+                    env_vars::COOKIDOO_PROXY_USERNAME.to_string(),
+                    format!("requires {}", env_vars::COOKIDOO_PROXY_URL),
+                ));
+                None
+            }
+            (None, None) => None,
+        };
+
+        let log_scrubber = LogScrubber::with_settings(
+            settings.log_scrub_sensitive_slots.as_deref(),
+            &settings.log_scrub_deny_fields.unwrap_or_default(),
+            &settings.log_scrub_allow_fields.unwrap_or_default(),
+        );
+
+        let log_capture_requests = settings.log_capture_requests.unwrap_or_default();
+        let log_capture_key = settings
+            .log_capture_key
+            .filter(|key| !key.expose().trim().is_empty());
+        if log_capture_requests != CaptureMode::Off && log_capture_key.is_none() {
+            errors.push(ConfigError::InvalidEnvVar(
+                env_vars::LOG_CAPTURE_REQUESTS.to_string(),
+                format!("requires {}", env_vars::LOG_CAPTURE_KEY),
+            ));
+        }
 
-        let password = env::var(env_vars::COOKIDOO_PASSWORD)
-            .map_err(|_| ConfigError::MissingEnvVar(env_vars::COOKIDOO_PASSWORD.to_string()))?;
+        let household_accounts = settings.cookidoo_accounts.unwrap_or_default();
+        let cookidoo_shadow_credentials = match (
+            &settings.cookidoo_shadow_base_url,
+            settings.cookidoo_shadow_email,
+            settings.cookidoo_shadow_password,
+        ) {
+            (Some(_), email, password) => {
+                let credentials = CookidooCredentials::new(
+                    required(&mut errors, env_vars::COOKIDOO_SHADOW_EMAIL, email),
+                    required(
+                        &mut errors,
+                        env_vars::COOKIDOO_SHADOW_PASSWORD,
+                        password.map(|password| password.expose().to_string()),
+                    ),
+                );
+                let shares_account = |other: &CookidooCredentials| {
+                    !other.email().is_empty()
+                        && other.email().eq_ignore_ascii_case(credentials.email())
+                };
+                if shares_account(&cookidoo_credentials)
+                    || household_accounts
+                        .accounts()
+                        .iter()
+                        .any(|account| shares_account(account.credentials()))
+                {
+                    errors.push(ConfigError::InvalidEnvVar(
+                        env_vars::COOKIDOO_SHADOW_EMAIL.to_string(),
+                        "must name another account than the skill's, or the shadow \
+                        repeats every change on the user's list"
+                            .to_string(),
+                    ));
+                }
+                Some(credentials)
+            }
+            (None, None, None) => None,
+            (None, _, _) => {
+                errors.push(ConfigError::InvalidEnvVar(
+                    env_vars::COOKIDOO_SHADOW_EMAIL.to_string(),
+                    format!("requires {}", env_vars::COOKIDOO_SHADOW_BASE_URL),
+                ));
+                None
+            }
+        };
 
-        let client_id = env::var(env_vars::COOKIDOO_CLIENT_ID)
-            .map_err(|_| ConfigError::MissingEnvVar(env_vars::COOKIDOO_CLIENT_ID.to_string()))?;
+        if settings.tls_cert_path.is_some() != settings.tls_key_path.is_some() {
+            errors.push(ConfigError::InvalidEnvVar(
+                env_vars::TLS_CERT_PATH.to_string(),
+                format!("must be set together with {}", env_vars::TLS_KEY_PATH),
+            ));
+        }
 
-        let client_secret = env::var(env_vars::COOKIDOO_CLIENT_SECRET).map_err(|_| {
-            ConfigError::MissingEnvVar(env_vars::COOKIDOO_CLIENT_SECRET.to_string())
-        })?;
+        if errors.len() > 1 {
+            return Err(ConfigError::Multiple(errors));
+        }
+        if let Some(error) = errors.pop() {
+            return Err(error);
+        }
 
+        let base_url = |url: String| url.trim().trim_end_matches('/').to_string();
+        let path = |path: String| path.trim().to_string();
         Ok(Self {
-            cookidoo_credentials: CookidooCredentials::new(email, password),
-            cookidoo_client_id: client_id,
-            cookidoo_client_secret: client_secret,
+            cookidoo_credentials,
+            cookidoo_credentials_secret,
+            cookidoo_client_id,
+            cookidoo_client_secret,
+            cookidoo_refresh_token,
+            cookidoo_refresh_token_parameter,
+            refresh_token_table_name,
+            cookidoo_password_grant,
+            idempotency_table_name: settings.idempotency_table_name,
+            occasion_list_table_name: settings.occasion_list_table_name,
+            preferences_table_name: settings.preferences_table_name,
+            history_table_name: settings.history_table_name,
+            permissions_table_name: settings.permissions_table_name,
+            category_overrides: settings.category_overrides.unwrap_or_default(),
+            item_aliases: settings.item_aliases.unwrap_or_default(),
+            item_alias_table_name: settings.item_alias_table_name,
+            item_bundles: settings.item_bundles.unwrap_or_default(),
+            maintenance_mode: settings.maintenance_mode.unwrap_or(false),
+            maintenance_parameter_name: settings.maintenance_parameter_name,
+            cookidoo_fixture_mode: settings.cookidoo_fixture_mode,
+            cookidoo_fixture_dir: settings
+                .cookidoo_fixture_dir
+                .unwrap_or_else(|| DEFAULT_FIXTURE_DIR.to_string()),
+            speech_style: settings.speech_style.unwrap_or_default(),
+            add_more_prompt: settings.add_more_prompt.unwrap_or(false),
+            item_quantity_style: settings.item_quantity_style.unwrap_or_default(),
+            pantry_staples: settings.pantry_staples.unwrap_or_default(),
+            phrase_variation: settings.phrase_variation.unwrap_or_default(),
+            cookidoo_market: settings.cookidoo_market,
+            cookidoo_base_url: settings.cookidoo_base_url.map(base_url),
+            cookidoo_token_path: settings.cookidoo_token_path.map(path),
+            cookidoo_shopping_path: settings.cookidoo_shopping_path.map(path),
+            cookidoo_proxy,
+            household_accounts,
+            cookidoo_startup_check: settings.cookidoo_startup_check.unwrap_or(false),
+            cookidoo_shadow_base_url: settings.cookidoo_shadow_base_url.map(base_url),
+            cookidoo_shadow_credentials,
+            cookidoo_user_agent: settings.cookidoo_user_agent,
+            cookidoo_headers: settings.cookidoo_headers.unwrap_or_default(),
+            cookidoo_accept_language: settings.cookidoo_accept_language.unwrap_or(false),
+            cookidoo_rate_limit: settings.cookidoo_rate_limit,
+            log_scrubber,
+            log_capture_requests,
+            log_capture_key,
+            serve_addr: settings.serve_addr,
+            http_server_addr: settings.http_server_addr,
+            alexa_skill_id: settings
+                .alexa_skill_id
+                .map(|id| id.trim().to_string())
+                .filter(|id| !id.is_empty()),
+            tls: settings.tls_cert_path.zip(settings.tls_key_path),
+            home_assistant_token: settings.home_assistant_token,
         })
     }
 
@@ -55,6 +488,12 @@ impl AppConfig {
         &self.cookidoo_credentials
     }
 
+    /// Returns the Secrets Manager secret holding the Cookidoo credentials,
+    /// if configured.
+    pub fn cookidoo_credentials_secret(&self) -> Option<&str> {
+        self.cookidoo_credentials_secret.as_deref()
+    }
+
     /// Returns the Cookidoo OAuth client ID.
     pub fn cookidoo_client_id(&self) -> &str {
         &self.cookidoo_client_id
@@ -62,7 +501,833 @@ impl AppConfig {
 
     /// Returns the Cookidoo OAuth client secret.
     pub fn cookidoo_client_secret(&self) -> &str {
-        &self.cookidoo_client_secret
+        self.cookidoo_client_secret.expose()
+    }
+
+    /// Builds the Basic authorization header for Cookidoo OAuth requests
+    /// from the client ID and secret.
+    ///
+    /// # Errors
+    /// Returns an error naming the variable that cannot form the header.
+    pub fn cookidoo_auth_header(&self) -> Result<AuthHeader, ConfigError> {
+        auth_header(
+            &self.cookidoo_client_id,
+            self.cookidoo_client_secret.expose(),
+        )
+    }
+
+    /// Returns the refresh token provisioned for Cookidoo, if configured.
+    pub fn cookidoo_refresh_token(&self) -> Option<&str> {
+        self.cookidoo_refresh_token.as_ref().map(Secret::expose)
+    }
+
+    /// Returns the SSM parameter holding the Cookidoo refresh token, if
+    /// configured; read once at cold start.
+    pub fn cookidoo_refresh_token_parameter(&self) -> Option<&str> {
+        self.cookidoo_refresh_token_parameter.as_deref()
+    }
+
+    /// Returns the DynamoDB table holding the Cookidoo refresh token, if
+    /// configured.
+    pub fn refresh_token_table_name(&self) -> Option<&str> {
+        self.refresh_token_table_name.as_deref()
+    }
+
+    /// Returns true if the Cookidoo password grant may be used.
+    pub fn cookidoo_password_grant(&self) -> bool {
+        self.cookidoo_password_grant
+    }
+
+    /// Returns the settings the Cookidoo sign-in comes from, such as
+    /// `COOKIDOO_CREDENTIALS_SECRET`, joined by "or", for messages telling
+    /// what to fix when Cookidoo rejects it.
+    pub fn cookidoo_credentials_source(&self) -> String {
+        let mut sources = Vec::new();
+        if self.refresh_token_table_name.is_some() {
+            sources.push(env_vars::REFRESH_TOKEN_TABLE_NAME);
+        }
+        if self.cookidoo_refresh_token.is_some() {
+            sources.push(env_vars::COOKIDOO_REFRESH_TOKEN);
+        }
+        if self.cookidoo_refresh_token_parameter.is_some() {
+            sources.push(env_vars::COOKIDOO_REFRESH_TOKEN_PARAMETER);
+        }
+        if self.cookidoo_password_grant {
+            if self.cookidoo_credentials_secret.is_some() {
+                sources.push(env_vars::COOKIDOO_CREDENTIALS_SECRET);
+            } else {
+                sources.push("COOKIDOO_EMAIL and COOKIDOO_PASSWORD");
+            }
+        }
+        sources.join(" or ")
+    }
+
+    /// Returns the DynamoDB table name for idempotency records, if configured.
+    pub fn idempotency_table_name(&self) -> Option<&str> {
+        self.idempotency_table_name.as_deref()
+    }
+
+    /// Returns the DynamoDB table name for occasion lists, if configured.
+    pub fn occasion_list_table_name(&self) -> Option<&str> {
+        self.occasion_list_table_name.as_deref()
+    }
+
+    /// Returns the DynamoDB table name for user preferences, if configured.
+    pub fn preferences_table_name(&self) -> Option<&str> {
+        self.preferences_table_name.as_deref()
+    }
+
+    /// Returns the DynamoDB table name for granted permissions, if
+    /// configured.
+    pub fn permissions_table_name(&self) -> Option<&str> {
+        self.permissions_table_name.as_deref()
+    }
+
+    /// Returns the DynamoDB table name for the add history, if configured.
+    pub fn history_table_name(&self) -> Option<&str> {
+        self.history_table_name.as_deref()
+    }
+
+    /// Returns the configured category keyword overrides.
+    pub fn category_overrides(&self) -> &[(String, Category)] {
+        &self.category_overrides
+    }
+
+    /// Returns the configured item aliases as (alias, name) pairs.
+    pub fn item_aliases(&self) -> &[(String, String)] {
+        &self.item_aliases
+    }
+
+    /// Returns the DynamoDB table name for item aliases, if configured.
+    pub fn item_alias_table_name(&self) -> Option<&str> {
+        self.item_alias_table_name.as_deref()
+    }
+
+    /// Returns the item bundles added by name.
+    pub fn item_bundles(&self) -> &ItemBundles {
+        &self.item_bundles
+    }
+
+    /// Returns true if maintenance mode is switched on via the environment.
+    pub fn maintenance_mode(&self) -> bool {
+        self.maintenance_mode
+    }
+
+    /// Returns the SSM parameter holding the maintenance flag, if configured.
+    pub fn maintenance_parameter_name(&self) -> Option<&str> {
+        self.maintenance_parameter_name.as_deref()
+    }
+
+    /// Returns whether Cookidoo traffic is recorded or replayed, if enabled.
+    pub fn cookidoo_fixture_mode(&self) -> Option<FixtureMode> {
+        self.cookidoo_fixture_mode
+    }
+
+    /// Returns the directory for recorded Cookidoo fixtures.
+    pub fn cookidoo_fixture_dir(&self) -> &str {
+        &self.cookidoo_fixture_dir
+    }
+
+    /// Returns how verbose confirmations and the welcome are.
+    pub fn speech_style(&self) -> SpeechStyle {
+        self.speech_style
+    }
+
+    /// Returns true if a successful add asks for another item.
+    pub fn add_more_prompt(&self) -> bool {
+        self.add_more_prompt
+    }
+
+    /// Returns how a spoken count is written to the list.
+    pub fn item_quantity_style(&self) -> QuantityStyle {
+        self.item_quantity_style
+    }
+
+    /// Returns the items skipped when a list is transferred, unless a user
+    /// stored their own.
+    pub fn pantry_staples(&self) -> &PantryStaples {
+        &self.pantry_staples
+    }
+
+    /// Returns the second Cookidoo host list operations are shadow-written
+    /// to, if configured.
+    pub fn cookidoo_shadow_base_url(&self) -> Option<&str> {
+        self.cookidoo_shadow_base_url.as_deref()
+    }
+
+    /// Returns the credentials of the account the shadow host signs in
+    /// with, set together with the shadow host.
+    pub fn cookidoo_shadow_credentials(&self) -> Option<&CookidooCredentials> {
+        self.cookidoo_shadow_credentials.as_ref()
+    }
+
+    /// Returns true if Cookidoo is signed in to at cold start to check the
+    /// credentials.
+    pub fn cookidoo_startup_check(&self) -> bool {
+        self.cookidoo_startup_check
+    }
+
+    /// Returns how alternative phrasings are chosen.
+    pub fn phrase_variation(&self) -> PhraseVariation {
+        self.phrase_variation
+    }
+
+    /// Returns the configured Cookidoo market, if any.
+    pub fn cookidoo_market(&self) -> Option<Market> {
+        self.cookidoo_market
+    }
+
+    /// Returns the Cookidoo API base URL override, if configured.
+    pub fn cookidoo_base_url(&self) -> Option<&str> {
+        self.cookidoo_base_url.as_deref()
+    }
+
+    /// Returns the OAuth token endpoint path override, if configured.
+    pub fn cookidoo_token_path(&self) -> Option<&str> {
+        self.cookidoo_token_path.as_deref()
+    }
+
+    /// Returns the additional items API path override, if configured.
+    pub fn cookidoo_shopping_path(&self) -> Option<&str> {
+        self.cookidoo_shopping_path.as_deref()
+    }
+
+    /// Returns the explicit proxy for Cookidoo requests, if configured.
+    pub fn cookidoo_proxy(&self) -> Option<&ProxyConfig> {
+        self.cookidoo_proxy.as_ref()
+    }
+
+    /// Returns the `User-Agent` override for Cookidoo requests, if
+    /// configured.
+    pub fn cookidoo_user_agent(&self) -> Option<&HeaderValue> {
+        self.cookidoo_user_agent.as_ref()
+    }
+
+    /// Returns the extra headers sent with Cookidoo requests.
+    pub fn cookidoo_headers(&self) -> &[(HeaderName, HeaderValue)] {
+        &self.cookidoo_headers
+    }
+
+    /// Returns true if Cookidoo requests carry the market's language as
+    /// `Accept-Language`.
+    pub fn cookidoo_accept_language(&self) -> bool {
+        self.cookidoo_accept_language
+    }
+
+    /// Returns the configured burst size and requests per second of
+    /// Cookidoo requests, if set.
+    pub fn cookidoo_rate_limit(&self) -> Option<(NonZeroU32, f64)> {
+        self.cookidoo_rate_limit
+    }
+
+    /// Returns the further Cookidoo accounts of the household.
+    pub fn household_accounts(&self) -> &HouseholdAccounts {
+        &self.household_accounts
+    }
+
+    /// Returns the scrubber for log lines.
+    pub fn log_scrubber(&self) -> &LogScrubber {
+        &self.log_scrubber
+    }
+
+    /// Returns the capture of incoming requests.
+    pub fn request_capture(&self) -> RequestCapture {
+        match &self.log_capture_key {
+            Some(key) => RequestCapture::new(self.log_capture_requests, key.expose()),
+            None => RequestCapture::default(),
+        }
+    }
+
+    /// Returns the listen address of the `serve` binary, if configured.
+    pub fn serve_addr(&self) -> Option<&str> {
+        self.serve_addr.as_deref()
+    }
+
+    /// Returns the listen address of the `http-server` binary, if
+    /// configured.
+    pub fn http_server_addr(&self) -> Option<&str> {
+        self.http_server_addr.as_deref()
+    }
+
+    /// Returns the ID of the skill the `http-server` binary answers, if
+    /// configured.
+    pub fn alexa_skill_id(&self) -> Option<&str> {
+        self.alexa_skill_id.as_deref()
+    }
+
+    /// Returns the ID of the skill the `http-server` binary answers.
+    ///
+    /// # Errors
+    /// Returns [`ConfigError::MissingEnvVar`] if `ALEXA_SKILL_ID` is not set.
+    pub fn require_alexa_skill_id(&self) -> Result<&str, ConfigError> {
+        self.alexa_skill_id()
+            .ok_or_else(|| ConfigError::MissingEnvVar(env_vars::ALEXA_SKILL_ID.to_string()))
+    }
+
+    /// Returns the PEM certificate and key paths for HTTPS, if configured.
+    pub fn tls_paths(&self) -> Option<(&str, &str)> {
+        self.tls
+            .as_ref()
+            .map(|(cert, key)| (cert.as_str(), key.as_str()))
+    }
+
+    /// Returns the bearer token of the Home Assistant endpoint, if
+    /// configured.
+    pub fn home_assistant_token(&self) -> Option<&Secret> {
+        self.home_assistant_token.as_ref()
+    }
+
+    /// Checks the formats of the loaded values.
+    ///
+    /// Loading only checks that required variables are present; this catches
+    /// values that are present but unusable, such as a blank client secret
+    /// or a mistyped e-mail, before the first request fails on them.
+    ///
+    /// # Errors
+    /// Returns an error naming the first variable with an unusable value.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        let invalid = |name: &str, reason: &str| {
+            Err(ConfigError::InvalidEnvVar(
+                name.to_string(),
+                reason.to_string(),
+            ))
+        };
+
+        self.cookidoo_auth_header()?;
+        if self.cookidoo_password_grant && self.cookidoo_credentials_secret.is_none() {
+            if !is_email(self.cookidoo_credentials.email()) {
+                return invalid(env_vars::COOKIDOO_EMAIL, "expected an e-mail address");
+            }
+            if self.cookidoo_credentials.password().is_empty() {
+                return invalid(env_vars::COOKIDOO_PASSWORD, "must not be empty");
+            }
+        }
+        if self
+            .cookidoo_refresh_token
+            .as_ref()
+            .is_some_and(|token| token.expose().contains(char::is_whitespace))
+        {
+            return invalid(
+                env_vars::COOKIDOO_REFRESH_TOKEN,
+                "must not contain whitespace",
+            );
+        }
+        for (name, base_url) in [
+            (env_vars::COOKIDOO_BASE_URL, &self.cookidoo_base_url),
+            (
+                env_vars::COOKIDOO_SHADOW_BASE_URL,
+                &self.cookidoo_shadow_base_url,
+            ),
+        ] {
+            if let Some(base_url) = base_url {
+                let parsed = reqwest::Url::parse(base_url);
+                if !parsed.is_ok_and(|url| matches!(url.scheme(), "http" | "https")) {
+                    return invalid(name, "expected an http(s) URL");
+                }
+            }
+        }
+        for (name, path) in [
+            (env_vars::COOKIDOO_TOKEN_PATH, &self.cookidoo_token_path),
+            (
+                env_vars::COOKIDOO_SHOPPING_PATH,
+                &self.cookidoo_shopping_path,
+            ),
+        ] {
+            if path.as_ref().is_some_and(|path| !path.starts_with('/')) {
+                return invalid(name, "expected a path starting with '/'");
+            }
+        }
+        if self
+            .cookidoo_proxy
+            .as_ref()
+            .is_some_and(|proxy| proxy.build().is_err())
+        {
+            return invalid(env_vars::COOKIDOO_PROXY_URL, "expected a proxy URL");
+        }
+        Ok(())
+    }
+
+    /// Describes the effective configuration for the cold-start log.
+    ///
+    /// Secrets are shown only as set or unset, and of the e-mail address
+    /// only the domain is kept.
+    pub fn summary(&self) -> String {
+        let secret = |set: bool| if set { REDACTED } else { "unset" };
+        let optional = |value: Option<&str>| value.unwrap_or("unset").to_string();
+        let email = self.cookidoo_credentials.email();
+
+        [
+            (
+                "cookidoo_email",
+                match email.split_once('@') {
+                    Some((_, domain)) => format!("***@{domain}"),
+                    None if email.is_empty() => "unset".to_string(),
+                    None => REDACTED.to_string(),
+                },
+            ),
+            (
+                "cookidoo_password",
+                secret(!self.cookidoo_credentials.password().is_empty()).to_string(),
+            ),
+            (
+                "cookidoo_credentials_secret",
+                optional(self.cookidoo_credentials_secret()),
+            ),
+            ("cookidoo_client_id", self.cookidoo_client_id.clone()),
+            (
+                "cookidoo_client_secret",
+                secret(!self.cookidoo_client_secret.is_empty()).to_string(),
+            ),
+            (
+                "cookidoo_refresh_token",
+                secret(self.cookidoo_refresh_token.is_some()).to_string(),
+            ),
+            (
+                "cookidoo_refresh_token_parameter",
+                optional(self.cookidoo_refresh_token_parameter()),
+            ),
+            (
+                "refresh_token_table_name",
+                optional(self.refresh_token_table_name()),
+            ),
+            (
+                "cookidoo_password_grant",
+                self.cookidoo_password_grant.to_string(),
+            ),
+            (
+                "idempotency_table_name",
+                optional(self.idempotency_table_name()),
+            ),
+            (
+                "occasion_list_table_name",
+                optional(self.occasion_list_table_name()),
+            ),
+            (
+                "preferences_table_name",
+                optional(self.preferences_table_name()),
+            ),
+            ("history_table_name", optional(self.history_table_name())),
+            (
+                "permissions_table_name",
+                optional(self.permissions_table_name()),
+            ),
+            (
+                "category_overrides",
+                self.category_overrides.len().to_string(),
+            ),
+            ("item_aliases", self.item_aliases.len().to_string()),
+            (
+                "item_alias_table_name",
+                optional(self.item_alias_table_name()),
+            ),
+            (
+                "item_bundles",
+                self.item_bundles.bundles().len().to_string(),
+            ),
+            ("maintenance_mode", self.maintenance_mode.to_string()),
+            (
+                "maintenance_parameter_name",
+                optional(self.maintenance_parameter_name()),
+            ),
+            (
+                "cookidoo_fixture_mode",
+                match self.cookidoo_fixture_mode {
+                    Some(FixtureMode::Record) => "record".to_string(),
+                    Some(FixtureMode::Replay) => "replay".to_string(),
+                    None => "unset".to_string(),
+                },
+            ),
+            ("cookidoo_fixture_dir", self.cookidoo_fixture_dir.clone()),
+            ("speech_style", format!("{:?}", self.speech_style)),
+            ("add_more_prompt", self.add_more_prompt.to_string()),
+            (
+                "item_quantity_style",
+                format!("{:?}", self.item_quantity_style),
+            ),
+            ("pantry_staples", self.pantry_staples.names().join(",")),
+            ("phrase_variation", format!("{:?}", self.phrase_variation)),
+            (
+                "cookidoo_market",
+                optional(self.cookidoo_market.as_ref().map(Market::country_code)),
+            ),
+            ("cookidoo_base_url", optional(self.cookidoo_base_url())),
+            ("cookidoo_token_path", optional(self.cookidoo_token_path())),
+            (
+                "cookidoo_shopping_path",
+                optional(self.cookidoo_shopping_path()),
+            ),
+            (
+                "cookidoo_proxy_url",
+                optional(
+                    self.cookidoo_proxy
+                        .as_ref()
+                        .map(ProxyConfig::display_url)
+                        .as_deref(),
+                ),
+            ),
+            (
+                "cookidoo_proxy_credentials",
+                secret(
+                    self.cookidoo_proxy
+                        .as_ref()
+                        .is_some_and(ProxyConfig::has_credentials),
+                )
+                .to_string(),
+            ),
+            (
+                "cookidoo_accounts",
+                optional(
+                    Some(
+                        self.household_accounts
+                            .accounts()
+                            .iter()
+                            .map(HouseholdAccount::name)
+                            .collect::<Vec<_>>()
+                            .join(","),
+                    )
+                    .filter(|names| !names.is_empty())
+                    .as_deref(),
+                ),
+            ),
+            (
+                "cookidoo_startup_check",
+                self.cookidoo_startup_check.to_string(),
+            ),
+            (
+                "cookidoo_shadow_base_url",
+                optional(self.cookidoo_shadow_base_url()),
+            ),
+            (
+                "cookidoo_user_agent",
+                optional(
+                    self.cookidoo_user_agent
+                        .as_ref()
+                        .and_then(|value| value.to_str().ok()),
+                ),
+            ),
+            (
+                "cookidoo_headers",
+                optional(
+                    Some(
+                        self.cookidoo_headers
+                            .iter()
+                            .map(|(name, _)| name.as_str())
+                            .collect::<Vec<_>>()
+                            .join(","),
+                    )
+                    .filter(|names| !names.is_empty())
+                    .as_deref(),
+                ),
+            ),
+            (
+                "cookidoo_accept_language",
+                self.cookidoo_accept_language.to_string(),
+            ),
+            (
+                "cookidoo_rate_limit",
+                optional(
+                    self.cookidoo_rate_limit
+                        .map(|(burst, rate)| format!("{burst}/{rate}"))
+                        .as_deref(),
+                ),
+            ),
+            (
+                "log_capture_requests",
+                format!("{:?}", self.log_capture_requests),
+            ),
+            (
+                "log_capture_key",
+                secret(self.log_capture_key.is_some()).to_string(),
+            ),
+            ("serve_addr", optional(self.serve_addr())),
+            ("http_server_addr", optional(self.http_server_addr())),
+            ("alexa_skill_id", optional(self.alexa_skill_id())),
+            (
+                "tls_cert_path",
+                optional(self.tls_paths().map(|(cert, _)| cert)),
+            ),
+            (
+                "home_assistant_token",
+                secret(self.home_assistant_token.is_some()).to_string(),
+            ),
+        ]
+        .iter()
+        .map(|(key, value)| format!("{key}={value}"))
+        .collect::<Vec<_>>()
+        .join(" ")
+    }
+}
+
+/// Returns true for a plausible e-mail address: one `@` with a non-empty
+/// local part and a dotted domain.
+fn is_email(value: &str) -> bool {
+    let Some((local, domain)) = value.split_once('@') else {
+        return false;
+    };
+    !local.is_empty()
+        && !value.contains(char::is_whitespace)
+        && domain.contains('.')
+        && domain
+            .split('.')
+            .all(|label| !label.is_empty() && !label.contains('@'))
+}
+
+/// Returns `value`, recording it as missing in `errors` instead.
+fn required(errors: &mut Vec<ConfigError>, name: &str, value: Option<String>) -> String {
+    value.unwrap_or_else(|| {
+        errors.push(ConfigError::MissingEnvVar(name.to_string()));
+        String::new()
+    })
+}
+
+/// Builds the Basic authorization header for Cookidoo OAuth requests,
+/// naming the variable that cannot form it.
+fn auth_header(client_id: &str, client_secret: &str) -> Result<AuthHeader, ConfigError> {
+    AuthHeader::new(client_id, client_secret).map_err(|e| {
+        let name = match e {
+            AuthHeaderError::BlankClientSecret => env_vars::COOKIDOO_CLIENT_SECRET,
+            AuthHeaderError::ControlCharacter if !client_id.contains(char::is_control) => {
+                env_vars::COOKIDOO_CLIENT_SECRET
+            }
+            _ => env_vars::COOKIDOO_CLIENT_ID,
+        };
+        ConfigError::InvalidEnvVar(name.to_string(), e.to_string())
+    })
+}
+
+/// Splits `value` at `;` into trimmed, non-empty entries.
+fn entries(value: &str) -> impl Iterator<Item = &str> {
+    value
+        .split(';')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+}
+
+/// A boolean switch such as `true`, `1`, `off`.
+impl SettingValue for bool {
+    fn parse_setting(value: &str) -> Result<Self, String> {
+        match value.trim().to_lowercase().as_str() {
+            "true" | "1" | "on" | "yes" => Ok(true),
+            "false" | "0" | "off" | "no" => Ok(false),
+            other => Err(format!("expected true or false, got '{other}'")),
+        }
+    }
+}
+
+/// A comma-separated list, e.g. of log fields.
+impl SettingValue for Vec<String> {
+    fn parse_setting(value: &str) -> Result<Self, String> {
+        Ok(value
+            .split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .map(str::to_string)
+            .collect())
+    }
+}
+
+impl SettingValue for FixtureMode {
+    fn parse_setting(value: &str) -> Result<Self, String> {
+        FixtureMode::parse(value).ok_or_else(|| format!("expected record or replay, got '{value}'"))
+    }
+}
+
+impl SettingValue for SpeechStyle {
+    fn parse_setting(value: &str) -> Result<Self, String> {
+        SpeechStyle::parse(value).ok_or_else(|| format!("expected full or brief, got '{value}'"))
+    }
+}
+
+impl SettingValue for QuantityStyle {
+    fn parse_setting(value: &str) -> Result<Self, String> {
+        QuantityStyle::parse(value)
+            .ok_or_else(|| format!("expected label or repeat, got '{value}'"))
+    }
+}
+
+impl SettingValue for PantryStaples {
+    fn parse_setting(value: &str) -> Result<Self, String> {
+        Ok(PantryStaples::parse(value))
+    }
+}
+
+impl SettingValue for PhraseVariation {
+    fn parse_setting(value: &str) -> Result<Self, String> {
+        PhraseVariation::parse(value)
+            .ok_or_else(|| format!("expected random, session or a seed, got '{value}'"))
+    }
+}
+
+impl SettingValue for Market {
+    fn parse_setting(value: &str) -> Result<Self, String> {
+        Market::from_country_code(value)
+            .ok_or_else(|| format!("no Cookidoo market for country code '{value}'"))
+    }
+}
+
+impl SettingValue for CaptureMode {
+    fn parse_setting(value: &str) -> Result<Self, String> {
+        CaptureMode::parse(value)
+            .ok_or_else(|| format!("expected off, on or hashed, got '{value}'"))
+    }
+}
+
+impl SettingValue for HeaderValue {
+    fn parse_setting(value: &str) -> Result<Self, String> {
+        HeaderValue::from_str(value.trim())
+            .map_err(|_| "contains characters not allowed in a header".to_string())
+    }
+}
+
+/// A rate limit as `burst/rate`, e.g. `10/5`; a zero burst or rate would
+/// stall every request, so both must be positive.
+impl SettingValue for (NonZeroU32, f64) {
+    fn parse_setting(value: &str) -> Result<Self, String> {
+        let (burst, rate) = value
+            .split_once('/')
+            .ok_or("expected burst/rate, e.g. 10/5")?;
+        let burst = burst
+            .trim()
+            .parse::<u32>()
+            .ok()
+            .and_then(NonZeroU32::new)
+            .ok_or("burst must be a positive whole number")?;
+        let rate = rate
+            .trim()
+            .parse::<f64>()
+            .ok()
+            .filter(|rate| rate.is_finite() && *rate > 0.0)
+            .ok_or("rate must be a positive number")?;
+        Ok((burst, rate))
+    }
+}
+
+/// `keyword=Category` pairs separated by `;`.
+impl SettingValue for Vec<(String, Category)> {
+    fn parse_setting(value: &str) -> Result<Self, String> {
+        entries(value)
+            .map(|pair| {
+                let (keyword, category) = pair
+                    .split_once('=')
+                    .ok_or_else(|| format!("expected keyword=Category, got '{pair}'"))?;
+                let category = category.parse::<Category>().map_err(|e| e.to_string())?;
+                Ok((keyword.trim().to_string(), category))
+            })
+            .collect()
+    }
+}
+
+/// `alias=name` pairs separated by `;`.
+impl SettingValue for Vec<(String, String)> {
+    fn parse_setting(value: &str) -> Result<Self, String> {
+        entries(value)
+            .map(|pair| {
+                let (alias, name) = pair
+                    .split_once('=')
+                    .map(|(alias, name)| (alias.trim(), name.trim()))
+                    .filter(|(alias, name)| !alias.is_empty() && !name.is_empty())
+                    .ok_or_else(|| format!("expected Alias=Name, got '{pair}'"))?;
+                Ok((alias.to_string(), name.to_string()))
+            })
+            .collect()
+    }
+}
+
+/// `Name=Item,Item` bundles separated by `;`.
+impl SettingValue for ItemBundles {
+    fn parse_setting(value: &str) -> Result<Self, String> {
+        entries(value).try_fold(ItemBundles::new(), |bundles, pair| {
+            let bundle = pair
+                .split_once('=')
+                .map(|(name, items)| ItemBundle::new(name, items.split(',')))
+                .filter(|bundle| !bundle.name().is_empty() && !bundle.items().is_empty())
+                .ok_or_else(|| format!("expected Name=Item,Item, got '{pair}'"))?;
+            Ok(bundles.with_bundle(bundle))
+        })
+    }
+}
+
+/// `Name=value` headers separated by `;`.
+impl SettingValue for Vec<(HeaderName, HeaderValue)> {
+    fn parse_setting(value: &str) -> Result<Self, String> {
+        entries(value)
+            .map(|pair| {
+                let (name, value) = pair
+                    .split_once('=')
+                    .ok_or_else(|| format!("expected Name=value, got '{pair}'"))?;
+                let name = HeaderName::from_bytes(name.trim().as_bytes())
+                    .map_err(|_| format!("invalid header name '{}'", name.trim()))?;
+                let value = HeaderValue::from_str(value.trim())
+                    .map_err(|_| format!("invalid value for header '{name}'"))?;
+                Ok((name, value))
+            })
+            .collect()
+    }
+}
+
+/// One entry of `COOKIDOO_ACCOUNTS`.
+#[derive(serde::Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+struct AccountSetting {
+    name: String,
+    #[serde(default)]
+    email: String,
+    #[serde(default)]
+    password: String,
+    credentials_secret: Option<String>,
+    #[serde(default)]
+    person_ids: Vec<String>,
+}
+
+/// The JSON array of further household accounts.
+impl SettingValue for HouseholdAccounts {
+    fn parse_setting(value: &str) -> Result<Self, String> {
+        let settings: Vec<AccountSetting> = serde_json::from_str(value)
+            .map_err(|e| format!("expected a JSON array of accounts: {e}"))?;
+
+        let mut accounts = HouseholdAccounts::new();
+        for setting in settings {
+            let name = setting.name.trim();
+            if name.is_empty() {
+                return Err("account names must not be empty".to_string());
+            }
+            if accounts
+                .accounts()
+                .iter()
+                .any(|other| other.name().to_lowercase() == name.to_lowercase())
+            {
+                return Err(format!("duplicate account '{name}'"));
+            }
+            let mut account = match setting.credentials_secret {
+                Some(secret_id) => {
+                    if !setting.email.is_empty() || !setting.password.is_empty() {
+                        return Err(format!(
+                            "'{name}' takes either credentialsSecret or email and password"
+                        ));
+                    }
+                    if secret_id.trim().is_empty() {
+                        return Err(format!("credentialsSecret of '{name}' must not be empty"));
+                    }
+                    HouseholdAccount::new(name, CookidooCredentials::new("", ""))
+                        .with_credentials_secret(secret_id.trim())
+                }
+                None => {
+                    if !is_email(&setting.email) {
+                        return Err(format!("expected an e-mail address for '{name}'"));
+                    }
+                    if setting.password.is_empty() {
+                        return Err(format!("password for '{name}' must not be empty"));
+                    }
+                    HouseholdAccount::new(
+                        name,
+                        CookidooCredentials::new(setting.email, setting.password),
+                    )
+                }
+            };
+            account = account.with_person_ids(setting.person_ids);
+            accounts = accounts.with_account(account);
+        }
+        Ok(accounts)
     }
 }
 
@@ -71,17 +1336,29 @@ impl AppConfig {
 pub enum ConfigError {
     #[error("Missing required environment variable: {0}")]
     MissingEnvVar(String),
+
+    #[error("Invalid value for environment variable {0}: {1}")]
+    InvalidEnvVar(String, String),
+
+    #[error("{}", .0.iter().map(ToString::to_string).collect::<Vec<_>>().join("; "))]
+    Multiple(Vec<ConfigError>),
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use std::env;
+    use std::sync::Mutex;
+
+    /// Serializes tests that modify the process environment.
+    static ENV_LOCK: Mutex<()> = Mutex::new(());
 
     fn with_env_vars<F, R>(vars: &[(&str, &str)], f: F) -> R
     where
         F: FnOnce() -> R,
     {
+        let _guard = ENV_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+
         // Set vars
         for (key, value) in vars {
             env::set_var(key, value);
@@ -112,10 +1389,1030 @@ mod tests {
                 assert_eq!(config.cookidoo_credentials().password(), "secret123");
                 assert_eq!(config.cookidoo_client_id(), "my-client-id");
                 assert_eq!(config.cookidoo_client_secret(), "my-client-secret");
+                assert_eq!(config.idempotency_table_name(), None);
+                assert_eq!(config.occasion_list_table_name(), None);
+                assert_eq!(config.preferences_table_name(), None);
+                assert_eq!(config.history_table_name(), None);
+                assert_eq!(config.permissions_table_name(), None);
+                assert!(!config.maintenance_mode());
+                assert_eq!(config.maintenance_parameter_name(), None);
+            },
+        );
+    }
+
+    #[test]
+    fn refresh_token_replaces_password() {
+        with_env_vars(
+            &[
+                ("COOKIDOO_CLIENT_ID", "my-client-id"),
+                ("COOKIDOO_CLIENT_SECRET", "my-client-secret"),
+                ("COOKIDOO_REFRESH_TOKEN", "refresh-123"),
+            ],
+            || {
+                let config = AppConfig::from_env().unwrap();
+                assert_eq!(config.cookidoo_refresh_token(), Some("refresh-123"));
+                assert!(!config.cookidoo_password_grant());
+                assert_eq!(config.cookidoo_credentials().password(), "");
+                assert_eq!(
+                    config.cookidoo_credentials_source(),
+                    "COOKIDOO_REFRESH_TOKEN"
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn refresh_token_parameter_replaces_password() {
+        with_env_vars(
+            &[
+                ("COOKIDOO_CLIENT_ID", "my-client-id"),
+                ("COOKIDOO_CLIENT_SECRET", "my-client-secret"),
+                (
+                    "COOKIDOO_REFRESH_TOKEN_PARAMETER",
+                    "/cookidoo-skill/refresh-token",
+                ),
+            ],
+            || {
+                let config = AppConfig::from_env().unwrap();
+                assert_eq!(
+                    config.cookidoo_refresh_token_parameter(),
+                    Some("/cookidoo-skill/refresh-token")
+                );
+                assert_eq!(config.cookidoo_refresh_token(), None);
+                assert!(!config.cookidoo_password_grant());
+            },
+        );
+    }
+
+    #[test]
+    fn refresh_token_table_replaces_password() {
+        with_env_vars(
+            &[
+                ("COOKIDOO_CLIENT_ID", "my-client-id"),
+                ("COOKIDOO_CLIENT_SECRET", "my-client-secret"),
+                ("REFRESH_TOKEN_TABLE_NAME", "refresh-tokens"),
+            ],
+            || {
+                let config = AppConfig::from_env().unwrap();
+                assert_eq!(config.refresh_token_table_name(), Some("refresh-tokens"));
+                assert!(!config.cookidoo_password_grant());
             },
         );
     }
 
+    #[test]
+    fn credentials_secret_replaces_password() {
+        with_env_vars(
+            &[
+                ("COOKIDOO_CLIENT_ID", "my-client-id"),
+                ("COOKIDOO_CLIENT_SECRET", "my-client-secret"),
+                ("COOKIDOO_CREDENTIALS_SECRET", "cookidoo-skill/credentials"),
+            ],
+            || {
+                let config = AppConfig::from_env().unwrap();
+                assert_eq!(
+                    config.cookidoo_credentials_secret(),
+                    Some("cookidoo-skill/credentials")
+                );
+                assert!(config.cookidoo_password_grant());
+                assert!(config.validate().is_ok());
+                assert!(config
+                    .summary()
+                    .contains("cookidoo_credentials_secret=cookidoo-skill/credentials"));
+                assert_eq!(
+                    config.cookidoo_credentials_source(),
+                    "COOKIDOO_CREDENTIALS_SECRET"
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn password_grant_fallback_requires_credentials() {
+        with_env_vars(
+            &[
+                ("COOKIDOO_CLIENT_ID", "my-client-id"),
+                ("COOKIDOO_CLIENT_SECRET", "my-client-secret"),
+                ("COOKIDOO_REFRESH_TOKEN", "refresh-123"),
+                ("COOKIDOO_PASSWORD_GRANT", "true"),
+            ],
+            || {
+                let message = AppConfig::from_env().unwrap_err().to_string();
+                assert!(message.contains("COOKIDOO_EMAIL"));
+                assert!(message.contains("COOKIDOO_PASSWORD"));
+            },
+        );
+    }
+
+    #[test]
+    fn disabling_password_grant_requires_refresh_token() {
+        with_env_vars(
+            &[
+                ("COOKIDOO_EMAIL", "test@example.com"),
+                ("COOKIDOO_PASSWORD", "secret123"),
+                ("COOKIDOO_CLIENT_ID", "my-client-id"),
+                ("COOKIDOO_CLIENT_SECRET", "my-client-secret"),
+                ("COOKIDOO_PASSWORD_GRANT", "false"),
+            ],
+            || {
+                let result = AppConfig::from_env();
+                assert!(
+                    matches!(result, Err(ConfigError::InvalidEnvVar(name, _)) if name == "COOKIDOO_PASSWORD_GRANT")
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn validates_loaded_config() {
+        with_env_vars(
+            &[
+                ("COOKIDOO_EMAIL", "test@example.com"),
+                ("COOKIDOO_PASSWORD", "secret123"),
+                ("COOKIDOO_CLIENT_ID", "my-client-id"),
+                ("COOKIDOO_CLIENT_SECRET", "my-client-secret"),
+            ],
+            || {
+                assert!(AppConfig::from_env().unwrap().validate().is_ok());
+            },
+        );
+    }
+
+    #[test]
+    fn builds_auth_header_from_client_credentials() {
+        with_env_vars(
+            &[
+                ("COOKIDOO_EMAIL", "test@example.com"),
+                ("COOKIDOO_PASSWORD", "secret123"),
+                ("COOKIDOO_CLIENT_ID", "client_id"),
+                ("COOKIDOO_CLIENT_SECRET", "client_secret"),
+            ],
+            || {
+                let header = AppConfig::from_env()
+                    .unwrap()
+                    .cookidoo_auth_header()
+                    .unwrap();
+                assert_eq!(header.expose(), "Basic Y2xpZW50X2lkOmNsaWVudF9zZWNyZXQ=");
+            },
+        );
+    }
+
+    #[test]
+    fn loading_rejects_colon_in_client_id() {
+        with_env_vars(
+            &[
+                ("COOKIDOO_EMAIL", "test@example.com"),
+                ("COOKIDOO_PASSWORD", "secret123"),
+                ("COOKIDOO_CLIENT_ID", "my:client"),
+                ("COOKIDOO_CLIENT_SECRET", "my-client-secret"),
+            ],
+            || {
+                let result = AppConfig::from_env();
+                assert!(
+                    matches!(result, Err(ConfigError::InvalidEnvVar(name, _)) if name == "COOKIDOO_CLIENT_ID")
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn validation_rejects_malformed_email() {
+        with_env_vars(
+            &[
+                ("COOKIDOO_EMAIL", "test@example"),
+                ("COOKIDOO_PASSWORD", "secret123"),
+                ("COOKIDOO_CLIENT_ID", "my-client-id"),
+                ("COOKIDOO_CLIENT_SECRET", "my-client-secret"),
+            ],
+            || {
+                let result = AppConfig::from_env().unwrap().validate();
+                assert!(
+                    matches!(result, Err(ConfigError::InvalidEnvVar(name, _)) if name == "COOKIDOO_EMAIL")
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn blank_client_secret_counts_as_missing() {
+        with_env_vars(
+            &[
+                ("COOKIDOO_CLIENT_ID", "my-client-id"),
+                ("COOKIDOO_CLIENT_SECRET", "  "),
+                ("COOKIDOO_REFRESH_TOKEN", "refresh-123"),
+            ],
+            || {
+                let result = AppConfig::from_env();
+                assert!(
+                    matches!(result, Err(ConfigError::MissingEnvVar(name)) if name == "COOKIDOO_CLIENT_SECRET")
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn reports_all_problems_at_once() {
+        with_env_vars(
+            &[
+                ("COOKIDOO_EMAIL", "test@example.com"),
+                ("COOKIDOO_PASSWORD", "secret123"),
+                ("SPEECH_STYLE", "laut"),
+                ("COOKIDOO_MARKET", "XX"),
+            ],
+            || match AppConfig::from_env() {
+                Err(ConfigError::Multiple(errors)) => {
+                    let names: Vec<&str> = errors
+                        .iter()
+                        .map(|error| match error {
+                            ConfigError::MissingEnvVar(name)
+                            | ConfigError::InvalidEnvVar(name, _) => name.as_str(),
+                            ConfigError::Multiple(_) => "",
+                        })
+                        .collect();
+                    assert_eq!(
+                        names,
+                        [
+                            "SPEECH_STYLE",
+                            "COOKIDOO_MARKET",
+                            "COOKIDOO_CLIENT_ID",
+                            "COOKIDOO_CLIENT_SECRET"
+                        ]
+                    );
+                }
+                other => panic!("expected all problems, got {other:?}"),
+            },
+        );
+    }
+
+    #[test]
+    fn loads_settings_from_config_file() {
+        let path = std::env::temp_dir().join("alexa-cookidoo-config-test.json");
+        std::fs::write(
+            &path,
+            r#"{"COOKIDOO_CLIENT_ID": "file-client-id", "SPEECH_STYLE": "brief"}"#,
+        )
+        .unwrap();
+
+        with_env_vars(
+            &[
+                ("CONFIG_FILE", path.to_str().unwrap()),
+                ("COOKIDOO_EMAIL", "test@example.com"),
+                ("COOKIDOO_PASSWORD", "secret123"),
+                ("COOKIDOO_CLIENT_SECRET", "my-client-secret"),
+                ("SPEECH_STYLE", "full"),
+            ],
+            || {
+                let config = AppConfig::from_env().unwrap();
+                assert_eq!(config.cookidoo_client_id(), "file-client-id");
+                assert_eq!(config.speech_style(), SpeechStyle::Full);
+            },
+        );
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn summary_redacts_secrets() {
+        with_env_vars(
+            &[
+                ("COOKIDOO_EMAIL", "test@example.com"),
+                ("COOKIDOO_PASSWORD", "secret123"),
+                ("COOKIDOO_CLIENT_ID", "my-client-id"),
+                ("COOKIDOO_CLIENT_SECRET", "my-client-secret"),
+                ("COOKIDOO_MARKET", "at"),
+            ],
+            || {
+                let summary = AppConfig::from_env().unwrap().summary();
+                assert!(summary.contains("cookidoo_email=***@example.com"));
+                assert!(summary.contains("cookidoo_client_id=my-client-id"));
+                assert!(summary.contains("cookidoo_client_secret=[REDACTED]"));
+                assert!(summary.contains("cookidoo_refresh_token=unset"));
+                assert!(summary.contains("cookidoo_market=AT"));
+                assert!(!summary.contains("secret123"));
+                assert!(!summary.contains("my-client-secret"));
+            },
+        );
+    }
+
+    #[test]
+    fn loads_endpoint_overrides() {
+        with_env_vars(
+            &[
+                ("COOKIDOO_EMAIL", "test@example.com"),
+                ("COOKIDOO_PASSWORD", "secret123"),
+                ("COOKIDOO_CLIENT_ID", "my-client-id"),
+                ("COOKIDOO_CLIENT_SECRET", "my-client-secret"),
+                ("COOKIDOO_BASE_URL", "https://staging.example.com/"),
+                ("COOKIDOO_TOKEN_PATH", "/auth/token"),
+                ("COOKIDOO_SHOPPING_PATH", "/v2/{language}/items"),
+                ("COOKIDOO_SHADOW_BASE_URL", "https://next.example.com/"),
+                ("COOKIDOO_SHADOW_EMAIL", "shadow@example.com"),
+                ("COOKIDOO_SHADOW_PASSWORD", "shadow123"),
+            ],
+            || {
+                let config = AppConfig::from_env().unwrap();
+                assert_eq!(
+                    config.cookidoo_base_url(),
+                    Some("https://staging.example.com")
+                );
+                assert_eq!(config.cookidoo_token_path(), Some("/auth/token"));
+                assert_eq!(
+                    config.cookidoo_shopping_path(),
+                    Some("/v2/{language}/items")
+                );
+                assert_eq!(
+                    config.cookidoo_shadow_base_url(),
+                    Some("https://next.example.com")
+                );
+                assert_eq!(
+                    config.cookidoo_shadow_credentials().map(|c| c.email()),
+                    Some("shadow@example.com")
+                );
+                assert!(config.validate().is_ok());
+            },
+        );
+    }
+
+    #[test]
+    fn shadow_host_requires_another_account() {
+        let base = [
+            ("COOKIDOO_EMAIL", "test@example.com"),
+            ("COOKIDOO_PASSWORD", "secret123"),
+            ("COOKIDOO_CLIENT_ID", "my-client-id"),
+            ("COOKIDOO_CLIENT_SECRET", "my-client-secret"),
+        ];
+        let cases: [(&[(&str, &str)], &str); 3] = [
+            (
+                &[("COOKIDOO_SHADOW_BASE_URL", "https://next.example.com")],
+                "COOKIDOO_SHADOW_EMAIL",
+            ),
+            (
+                &[
+                    ("COOKIDOO_SHADOW_BASE_URL", "https://next.example.com"),
+                    ("COOKIDOO_SHADOW_EMAIL", "Test@Example.com"),
+                    ("COOKIDOO_SHADOW_PASSWORD", "secret123"),
+                ],
+                "another account",
+            ),
+            (
+                &[
+                    ("COOKIDOO_SHADOW_EMAIL", "shadow@example.com"),
+                    ("COOKIDOO_SHADOW_PASSWORD", "shadow123"),
+                ],
+                "requires COOKIDOO_SHADOW_BASE_URL",
+            ),
+        ];
+        for (vars, expected) in cases {
+            let vars: Vec<_> = base.iter().chain(vars).copied().collect();
+            with_env_vars(&vars, || {
+                let message = AppConfig::from_env().unwrap_err().to_string();
+                assert!(message.contains(expected), "{message}");
+            });
+        }
+    }
+
+    #[test]
+    fn blank_endpoint_paths_keep_defaults() {
+        with_env_vars(
+            &[
+                ("COOKIDOO_EMAIL", "test@example.com"),
+                ("COOKIDOO_PASSWORD", "secret123"),
+                ("COOKIDOO_CLIENT_ID", "my-client-id"),
+                ("COOKIDOO_CLIENT_SECRET", "my-client-secret"),
+                ("COOKIDOO_TOKEN_PATH", "  "),
+                ("COOKIDOO_SHOPPING_PATH", " /v2/{language}/items "),
+            ],
+            || {
+                let config = AppConfig::from_env().unwrap();
+                assert_eq!(config.cookidoo_token_path(), None);
+                assert_eq!(
+                    config.cookidoo_shopping_path(),
+                    Some("/v2/{language}/items")
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn loads_client_headers() {
+        with_env_vars(
+            &[
+                ("COOKIDOO_EMAIL", "test@example.com"),
+                ("COOKIDOO_PASSWORD", "secret123"),
+                ("COOKIDOO_CLIENT_ID", "my-client-id"),
+                ("COOKIDOO_CLIENT_SECRET", "my-client-secret"),
+                ("COOKIDOO_USER_AGENT", "Thermomix/2.0 "),
+                ("COOKIDOO_HEADERS", "X-Client-Version=4.1; X-Token=a=b"),
+                ("COOKIDOO_ACCEPT_LANGUAGE", "true"),
+            ],
+            || {
+                let config = AppConfig::from_env().unwrap();
+                assert_eq!(config.cookidoo_user_agent().unwrap(), "Thermomix/2.0");
+                let headers: Vec<_> = config
+                    .cookidoo_headers()
+                    .iter()
+                    .map(|(name, value)| (name.as_str(), value.to_str().unwrap()))
+                    .collect();
+                assert_eq!(headers, [("x-client-version", "4.1"), ("x-token", "a=b")]);
+                assert!(config.cookidoo_accept_language());
+                assert!(config
+                    .summary()
+                    .contains("cookidoo_headers=x-client-version,x-token "));
+            },
+        );
+    }
+
+    #[test]
+    fn loads_rate_limit() {
+        with_env_vars(&[("COOKIDOO_RATE_LIMIT", "4/0.5")], || {
+            let config = AppConfig::from_env_without_credentials().unwrap();
+            assert_eq!(
+                config.cookidoo_rate_limit(),
+                Some((NonZeroU32::new(4).unwrap(), 0.5))
+            );
+        });
+    }
+
+    #[test]
+    fn rejects_zero_rate_limit() {
+        for value in ["0/5", "10/0", "10", "10/-1"] {
+            with_env_vars(&[("COOKIDOO_RATE_LIMIT", value)], || {
+                let result = AppConfig::from_env_without_credentials();
+                assert!(
+                    matches!(&result, Err(ConfigError::InvalidEnvVar(name, _)) if name == "COOKIDOO_RATE_LIMIT"),
+                    "{value}: {result:?}"
+                );
+            });
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_client_headers() {
+        for value in ["X-Client", "Bad Name=1", "X-Client=line\nbreak"] {
+            with_env_vars(
+                &[
+                    ("COOKIDOO_EMAIL", "test@example.com"),
+                    ("COOKIDOO_PASSWORD", "secret123"),
+                    ("COOKIDOO_CLIENT_ID", "my-client-id"),
+                    ("COOKIDOO_CLIENT_SECRET", "my-client-secret"),
+                    ("COOKIDOO_HEADERS", value),
+                ],
+                || match AppConfig::from_env() {
+                    Err(ConfigError::InvalidEnvVar(name, _)) => {
+                        assert_eq!(name, "COOKIDOO_HEADERS", "for {value:?}")
+                    }
+                    other => panic!("expected invalid headers for {value:?}, got {other:?}"),
+                },
+            );
+        }
+    }
+
+    #[test]
+    fn validation_rejects_unusable_endpoint_overrides() {
+        let vars = [
+            ("COOKIDOO_EMAIL", "test@example.com"),
+            ("COOKIDOO_PASSWORD", "secret123"),
+            ("COOKIDOO_CLIENT_ID", "my-client-id"),
+            ("COOKIDOO_CLIENT_SECRET", "my-client-secret"),
+        ];
+        for (name, value) in [
+            ("COOKIDOO_BASE_URL", "staging.example.com"),
+            ("COOKIDOO_TOKEN_PATH", "auth/token"),
+        ] {
+            let mut env = vars.to_vec();
+            env.push((name, value));
+            with_env_vars(&env, || {
+                let result = AppConfig::from_env().unwrap().validate();
+                assert!(
+                    matches!(result, Err(ConfigError::InvalidEnvVar(ref invalid, _)) if invalid == name),
+                    "{name}={value} accepted"
+                );
+            });
+        }
+    }
+
+    #[test]
+    fn loads_proxy_with_credentials() {
+        with_env_vars(
+            &[
+                ("COOKIDOO_EMAIL", "test@example.com"),
+                ("COOKIDOO_PASSWORD", "secret123"),
+                ("COOKIDOO_CLIENT_ID", "my-client-id"),
+                ("COOKIDOO_CLIENT_SECRET", "my-client-secret"),
+                ("COOKIDOO_PROXY_URL", "http://proxy.internal:3128"),
+                ("COOKIDOO_PROXY_USERNAME", "skill"),
+                ("COOKIDOO_PROXY_PASSWORD", "proxy-secret"),
+            ],
+            || {
+                let config = AppConfig::from_env().unwrap();
+                let proxy = config.cookidoo_proxy().unwrap();
+                assert_eq!(proxy.display_url(), "http://proxy.internal:3128/");
+                assert!(proxy.has_credentials());
+                assert!(config.validate().is_ok());
+
+                let summary = config.summary();
+                assert!(summary.contains("cookidoo_proxy_credentials=[REDACTED]"));
+                assert!(!summary.contains("proxy-secret"));
+            },
+        );
+    }
+
+    #[test]
+    fn loads_household_accounts() {
+        with_env_vars(
+            &[
+                ("COOKIDOO_EMAIL", "test@example.com"),
+                ("COOKIDOO_PASSWORD", "secret123"),
+                ("COOKIDOO_CLIENT_ID", "my-client-id"),
+                ("COOKIDOO_CLIENT_SECRET", "my-client-secret"),
+                (
+                    "COOKIDOO_ACCOUNTS",
+                    r#"[{"name": "Papa", "email": "papa@example.com", "password": "papa-secret", "personIds": ["amzn1.ask.person.PAPA"]}]"#,
+                ),
+            ],
+            || {
+                let config = AppConfig::from_env().unwrap();
+                let accounts = config.household_accounts();
+                let papa = accounts.for_person("amzn1.ask.person.PAPA").unwrap();
+                assert_eq!(papa.name(), "Papa");
+                assert_eq!(papa.credentials().email(), "papa@example.com");
+
+                let summary = config.summary();
+                assert!(summary.contains("cookidoo_accounts=Papa"));
+                assert!(!summary.contains("papa-secret"));
+            },
+        );
+    }
+
+    #[test]
+    fn loads_household_account_from_secret() {
+        with_env_vars(
+            &[
+                ("COOKIDOO_EMAIL", "test@example.com"),
+                ("COOKIDOO_PASSWORD", "secret123"),
+                ("COOKIDOO_CLIENT_ID", "my-client-id"),
+                ("COOKIDOO_CLIENT_SECRET", "my-client-secret"),
+                (
+                    "COOKIDOO_ACCOUNTS",
+                    r#"[{"name": "Papa", "credentialsSecret": "cookidoo-skill/papa"}]"#,
+                ),
+            ],
+            || {
+                let config = AppConfig::from_env().unwrap();
+                let papa = config.household_accounts().find("Papa").unwrap();
+                assert_eq!(papa.credentials_secret(), Some("cookidoo-skill/papa"));
+            },
+        );
+    }
+
+    #[test]
+    fn returns_error_for_invalid_household_accounts() {
+        for accounts in [
+            r#"{"name": "Papa"}"#,
+            r#"[{"name": " ", "email": "papa@example.com", "password": "pw"}]"#,
+            r#"[{"name": "Papa", "email": "papa", "password": "pw"}]"#,
+            r#"[{"name": "Papa", "email": "a@example.com", "password": "pw"}, {"name": "papa", "email": "b@example.com", "password": "pw"}]"#,
+            r#"[{"name": "Papa", "credentialsSecret": " "}]"#,
+            r#"[{"name": "Papa", "credentialsSecret": "papa", "password": "pw"}]"#,
+        ] {
+            with_env_vars(
+                &[
+                    ("COOKIDOO_EMAIL", "test@example.com"),
+                    ("COOKIDOO_PASSWORD", "secret123"),
+                    ("COOKIDOO_CLIENT_ID", "my-client-id"),
+                    ("COOKIDOO_CLIENT_SECRET", "my-client-secret"),
+                    ("COOKIDOO_ACCOUNTS", accounts),
+                ],
+                || {
+                    let result = AppConfig::from_env();
+                    assert!(
+                        matches!(result, Err(ConfigError::InvalidEnvVar(name, _)) if name == "COOKIDOO_ACCOUNTS"),
+                        "{accounts}"
+                    );
+                },
+            );
+        }
+    }
+
+    #[test]
+    fn proxy_username_requires_url() {
+        with_env_vars(
+            &[
+                ("COOKIDOO_EMAIL", "test@example.com"),
+                ("COOKIDOO_PASSWORD", "secret123"),
+                ("COOKIDOO_CLIENT_ID", "my-client-id"),
+                ("COOKIDOO_CLIENT_SECRET", "my-client-secret"),
+                ("COOKIDOO_PROXY_USERNAME", "skill"),
+            ],
+            || {
+                let result = AppConfig::from_env();
+                assert!(
+                    matches!(result, Err(ConfigError::InvalidEnvVar(name, _)) if name == "COOKIDOO_PROXY_USERNAME")
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn recognizes_email_shape() {
+        assert!(is_email("max.mustermann@example.de"));
+        assert!(!is_email("max.mustermann"));
+        assert!(!is_email("@example.de"));
+        assert!(!is_email("max@example..de"));
+        assert!(!is_email("max@exa@mple.de"));
+        assert!(!is_email("max @example.de"));
+    }
+
+    #[test]
+    fn loads_config_without_credentials() {
+        with_env_vars(&[("MAINTENANCE_MODE", "true")], || {
+            let config = AppConfig::from_env_without_credentials().unwrap();
+            assert_eq!(config.cookidoo_credentials().email(), "");
+            assert_eq!(config.cookidoo_client_id(), "");
+            assert!(config.maintenance_mode());
+        });
+    }
+
+    #[test]
+    fn keeps_oauth_client_without_credentials() {
+        with_env_vars(
+            &[
+                ("COOKIDOO_CLIENT_ID", "my-client-id"),
+                ("COOKIDOO_CLIENT_SECRET", "my-client-secret"),
+            ],
+            || {
+                let config = AppConfig::from_env_without_credentials().unwrap();
+                assert_eq!(config.cookidoo_client_id(), "my-client-id");
+                assert_eq!(config.cookidoo_client_secret(), "my-client-secret");
+            },
+        );
+    }
+
+    #[test]
+    fn loads_server_and_logging_settings() {
+        with_env_vars(
+            &[
+                ("HTTP_SERVER_ADDR", "127.0.0.1:9443"),
+                ("ALEXA_SKILL_ID", " amzn1.ask.skill.test-skill "),
+                ("TLS_CERT_PATH", "cert.pem"),
+                ("TLS_KEY_PATH", "key.pem"),
+                ("HOME_ASSISTANT_TOKEN", "ha-token"),
+                ("LOG_CAPTURE_REQUESTS", "hashed"),
+                ("LOG_CAPTURE_KEY", "capture-key"),
+            ],
+            || {
+                let config = AppConfig::from_env_without_credentials().unwrap();
+                assert_eq!(config.http_server_addr(), Some("127.0.0.1:9443"));
+                assert_eq!(
+                    config.require_alexa_skill_id().unwrap(),
+                    "amzn1.ask.skill.test-skill"
+                );
+                assert_eq!(config.tls_paths(), Some(("cert.pem", "key.pem")));
+                assert_eq!(
+                    config.home_assistant_token().map(Secret::expose),
+                    Some("ha-token")
+                );
+                assert_eq!(config.log_capture_requests, CaptureMode::HashedSlots);
+                assert!(!config.summary().contains("ha-token"));
+                assert!(!config.summary().contains("capture-key"));
+            },
+        );
+        with_env_vars(&[("ALEXA_SKILL_ID", " ")], || {
+            let config = AppConfig::from_env_without_credentials().unwrap();
+            assert!(matches!(
+                config.require_alexa_skill_id(),
+                Err(ConfigError::MissingEnvVar(name)) if name == "ALEXA_SKILL_ID"
+            ));
+        });
+        with_env_vars(&[("TLS_CERT_PATH", "cert.pem")], || {
+            let result = AppConfig::from_env_without_credentials();
+            assert!(
+                matches!(result, Err(ConfigError::InvalidEnvVar(name, _)) if name == "TLS_CERT_PATH")
+            );
+        });
+        with_env_vars(&[("LOG_CAPTURE_REQUESTS", "verbose")], || {
+            assert!(AppConfig::from_env_without_credentials().is_err());
+        });
+        with_env_vars(&[("LOG_CAPTURE_REQUESTS", "on")], || {
+            let result = AppConfig::from_env_without_credentials();
+            assert!(
+                matches!(result, Err(ConfigError::InvalidEnvVar(name, _)) if name == "LOG_CAPTURE_REQUESTS")
+            );
+        });
+    }
+
+    #[test]
+    fn loads_maintenance_settings() {
+        with_env_vars(
+            &[
+                ("COOKIDOO_EMAIL", "test@example.com"),
+                ("COOKIDOO_PASSWORD", "secret123"),
+                ("COOKIDOO_CLIENT_ID", "my-client-id"),
+                ("COOKIDOO_CLIENT_SECRET", "my-client-secret"),
+                ("MAINTENANCE_MODE", "On"),
+                ("MAINTENANCE_PARAMETER_NAME", "/cookidoo-skill/maintenance"),
+            ],
+            || {
+                let config = AppConfig::from_env().unwrap();
+                assert!(config.maintenance_mode());
+                assert_eq!(
+                    config.maintenance_parameter_name(),
+                    Some("/cookidoo-skill/maintenance")
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn loads_fixture_settings() {
+        with_env_vars(
+            &[
+                ("COOKIDOO_FIXTURE_MODE", "replay"),
+                ("COOKIDOO_FIXTURE_DIR", "fixtures/cookidoo"),
+            ],
+            || {
+                let config = AppConfig::from_env_without_credentials().unwrap();
+                assert_eq!(config.cookidoo_fixture_mode(), Some(FixtureMode::Replay));
+                assert_eq!(config.cookidoo_fixture_dir(), "fixtures/cookidoo");
+            },
+        );
+
+        with_env_vars(&[("COOKIDOO_FIXTURE_MODE", "live")], || {
+            let result = AppConfig::from_env_without_credentials();
+            assert!(
+                matches!(result, Err(ConfigError::InvalidEnvVar(name, _)) if name == "COOKIDOO_FIXTURE_MODE")
+            );
+        });
+    }
+
+    #[test]
+    fn loads_add_more_prompt() {
+        with_env_vars(&[], || {
+            let config = AppConfig::from_env_without_credentials().unwrap();
+            assert!(!config.add_more_prompt());
+        });
+
+        with_env_vars(&[("ADD_MORE_PROMPT", "true")], || {
+            let config = AppConfig::from_env_without_credentials().unwrap();
+            assert!(config.add_more_prompt());
+        });
+    }
+
+    #[test]
+    fn loads_cookidoo_startup_check() {
+        with_env_vars(&[], || {
+            let config = AppConfig::from_env_without_credentials().unwrap();
+            assert!(!config.cookidoo_startup_check());
+        });
+
+        with_env_vars(&[("COOKIDOO_STARTUP_CHECK", "true")], || {
+            let config = AppConfig::from_env_without_credentials().unwrap();
+            assert!(config.cookidoo_startup_check());
+        });
+    }
+
+    #[test]
+    fn loads_phrase_variation() {
+        with_env_vars(&[], || {
+            let config = AppConfig::from_env_without_credentials().unwrap();
+            assert_eq!(config.phrase_variation(), PhraseVariation::Random);
+        });
+
+        with_env_vars(&[("PHRASE_VARIATION", "0")], || {
+            let config = AppConfig::from_env_without_credentials().unwrap();
+            assert_eq!(config.phrase_variation(), PhraseVariation::Seeded(0));
+        });
+
+        with_env_vars(&[("PHRASE_VARIATION", "sometimes")], || {
+            assert!(AppConfig::from_env_without_credentials().is_err());
+        });
+    }
+
+    #[test]
+    fn loads_speech_style() {
+        with_env_vars(&[], || {
+            let config = AppConfig::from_env_without_credentials().unwrap();
+            assert_eq!(config.speech_style(), SpeechStyle::Full);
+        });
+
+        with_env_vars(&[("SPEECH_STYLE", "brief")], || {
+            let config = AppConfig::from_env_without_credentials().unwrap();
+            assert_eq!(config.speech_style(), SpeechStyle::Brief);
+        });
+
+        with_env_vars(&[("SPEECH_STYLE", "chatty")], || {
+            let result = AppConfig::from_env_without_credentials();
+            assert!(
+                matches!(result, Err(ConfigError::InvalidEnvVar(name, _)) if name == "SPEECH_STYLE")
+            );
+        });
+    }
+
+    #[test]
+    fn loads_item_quantity_style() {
+        with_env_vars(&[], || {
+            let config = AppConfig::from_env_without_credentials().unwrap();
+            assert_eq!(config.item_quantity_style(), QuantityStyle::Label);
+        });
+
+        with_env_vars(&[("ITEM_QUANTITY_STYLE", "repeat")], || {
+            let config = AppConfig::from_env_without_credentials().unwrap();
+            assert_eq!(config.item_quantity_style(), QuantityStyle::Repeat);
+        });
+
+        with_env_vars(&[("ITEM_QUANTITY_STYLE", "3x")], || {
+            let result = AppConfig::from_env_without_credentials();
+            assert!(
+                matches!(result, Err(ConfigError::InvalidEnvVar(name, _)) if name == "ITEM_QUANTITY_STYLE")
+            );
+        });
+    }
+
+    #[test]
+    fn loads_pantry_staples() {
+        with_env_vars(&[], || {
+            let config = AppConfig::from_env_without_credentials().unwrap();
+            assert!(config.pantry_staples().is_empty());
+        });
+
+        with_env_vars(&[("PANTRY_STAPLES", "Salz, Pfeffer,Öl")], || {
+            let config = AppConfig::from_env_without_credentials().unwrap();
+            assert_eq!(config.pantry_staples().names(), ["Salz", "Pfeffer", "Öl"]);
+        });
+    }
+
+    #[test]
+    fn loads_cookidoo_market() {
+        with_env_vars(&[], || {
+            let config = AppConfig::from_env_without_credentials().unwrap();
+            assert_eq!(config.cookidoo_market(), None);
+        });
+
+        with_env_vars(&[("COOKIDOO_MARKET", "at")], || {
+            let config = AppConfig::from_env_without_credentials().unwrap();
+            assert_eq!(config.cookidoo_market(), Market::from_country_code("AT"));
+        });
+
+        with_env_vars(&[("COOKIDOO_MARKET", "JP")], || {
+            let result = AppConfig::from_env_without_credentials();
+            assert!(
+                matches!(result, Err(ConfigError::InvalidEnvVar(name, _)) if name == "COOKIDOO_MARKET")
+            );
+        });
+    }
+
+    #[test]
+    fn returns_error_for_invalid_maintenance_mode() {
+        with_env_vars(
+            &[
+                ("COOKIDOO_EMAIL", "test@example.com"),
+                ("COOKIDOO_PASSWORD", "secret123"),
+                ("COOKIDOO_CLIENT_ID", "my-client-id"),
+                ("COOKIDOO_CLIENT_SECRET", "my-client-secret"),
+                ("MAINTENANCE_MODE", "maybe"),
+            ],
+            || {
+                let result = AppConfig::from_env();
+                assert!(matches!(result, Err(ConfigError::InvalidEnvVar(_, _))));
+            },
+        );
+    }
+
+    #[test]
+    fn loads_optional_table_names() {
+        with_env_vars(
+            &[
+                ("COOKIDOO_EMAIL", "test@example.com"),
+                ("COOKIDOO_PASSWORD", "secret123"),
+                ("COOKIDOO_CLIENT_ID", "my-client-id"),
+                ("COOKIDOO_CLIENT_SECRET", "my-client-secret"),
+                ("IDEMPOTENCY_TABLE_NAME", "idempotency"),
+                ("OCCASION_LIST_TABLE_NAME", "occasion-lists"),
+                ("PREFERENCES_TABLE_NAME", "preferences"),
+                ("HISTORY_TABLE_NAME", "history"),
+                ("PERMISSIONS_TABLE_NAME", "permissions"),
+            ],
+            || {
+                let config = AppConfig::from_env().unwrap();
+                assert_eq!(config.idempotency_table_name(), Some("idempotency"));
+                assert_eq!(config.occasion_list_table_name(), Some("occasion-lists"));
+                assert_eq!(config.preferences_table_name(), Some("preferences"));
+                assert_eq!(config.history_table_name(), Some("history"));
+                assert_eq!(config.permissions_table_name(), Some("permissions"));
+            },
+        );
+    }
+
+    #[test]
+    fn loads_category_overrides() {
+        with_env_vars(
+            &[
+                ("COOKIDOO_EMAIL", "test@example.com"),
+                ("COOKIDOO_PASSWORD", "secret123"),
+                ("COOKIDOO_CLIENT_ID", "my-client-id"),
+                ("COOKIDOO_CLIENT_SECRET", "my-client-secret"),
+                (
+                    "CATEGORY_OVERRIDES",
+                    "Tofu=Milchprodukte; Hafermilch = getränke;",
+                ),
+            ],
+            || {
+                let config = AppConfig::from_env().unwrap();
+                assert_eq!(
+                    config.category_overrides(),
+                    &[
+                        ("Tofu".to_string(), Category::Dairy),
+                        ("Hafermilch".to_string(), Category::Beverages),
+                    ]
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn returns_error_for_invalid_category_override() {
+        with_env_vars(
+            &[
+                ("COOKIDOO_EMAIL", "test@example.com"),
+                ("COOKIDOO_PASSWORD", "secret123"),
+                ("COOKIDOO_CLIENT_ID", "my-client-id"),
+                ("COOKIDOO_CLIENT_SECRET", "my-client-secret"),
+                ("CATEGORY_OVERRIDES", "Tofu=Sonstiges"),
+            ],
+            || {
+                let result = AppConfig::from_env();
+                assert!(matches!(result, Err(ConfigError::InvalidEnvVar(_, _))));
+            },
+        );
+    }
+
+    #[test]
+    fn loads_item_aliases() {
+        with_env_vars(
+            &[
+                ("COOKIDOO_EMAIL", "test@example.com"),
+                ("COOKIDOO_PASSWORD", "secret123"),
+                ("COOKIDOO_CLIENT_ID", "my-client-id"),
+                ("COOKIDOO_CLIENT_SECRET", "my-client-secret"),
+                (
+                    "ITEM_ALIASES",
+                    "Haferdrink=Hafermilch; Tempo = Taschentücher;",
+                ),
+            ],
+            || {
+                let config = AppConfig::from_env().unwrap();
+                assert_eq!(
+                    config.item_aliases(),
+                    &[
+                        ("Haferdrink".to_string(), "Hafermilch".to_string()),
+                        ("Tempo".to_string(), "Taschentücher".to_string()),
+                    ]
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn returns_error_for_invalid_item_alias() {
+        with_env_vars(
+            &[
+                ("COOKIDOO_EMAIL", "test@example.com"),
+                ("COOKIDOO_PASSWORD", "secret123"),
+                ("COOKIDOO_CLIENT_ID", "my-client-id"),
+                ("COOKIDOO_CLIENT_SECRET", "my-client-secret"),
+                ("ITEM_ALIASES", "Haferdrink="),
+            ],
+            || {
+                let result = AppConfig::from_env();
+                assert!(matches!(result, Err(ConfigError::InvalidEnvVar(_, _))));
+            },
+        );
+    }
+
+    #[test]
+    fn loads_item_bundles() {
+        with_env_vars(
+            &[(
+                "ITEM_BUNDLES",
+                "Frühstücks-Set=Brötchen, Butter,Marmelade; Grillabend=Würstchen;",
+            )],
+            || {
+                let config = AppConfig::from_env_without_credentials().unwrap();
+                let bundles = config.item_bundles().bundles();
+                assert_eq!(bundles.len(), 2);
+                assert_eq!(bundles[0].name(), "Frühstücks-Set");
+                assert_eq!(bundles[0].items(), ["Brötchen", "Butter", "Marmelade"]);
+                assert_eq!(bundles[1].items(), ["Würstchen"]);
+            },
+        );
+
+        with_env_vars(&[("ITEM_BUNDLES", "Frühstücks-Set=,")], || {
+            let result = AppConfig::from_env_without_credentials();
+            assert!(
+                matches!(result, Err(ConfigError::InvalidEnvVar(name, _)) if name == "ITEM_BUNDLES")
+            );
+        });
+    }
+
     #[test]
     fn returns_error_when_email_missing() {
         with_env_vars(
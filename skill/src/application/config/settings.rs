@@ -0,0 +1,354 @@
+use std::env;
+use std::fs;
+use std::num::NonZeroU32;
+
+use http::header::{HeaderName, HeaderValue};
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer};
+use serde_json::{Map, Value};
+
+use super::ConfigError;
+use crate::adapters::alexa::PhraseVariation;
+use crate::adapters::cookidoo::FixtureMode;
+use crate::adapters::logging::CaptureMode;
+use crate::domain::models::{
+    Category, HouseholdAccounts, ItemBundles, Market, PantryStaples, QuantityStyle, Secret,
+    SpeechStyle,
+};
+
+/// Environment variable naming a JSON file with settings.
+pub const CONFIG_FILE: &str = "CONFIG_FILE";
+
+/// A setting parsed from its text value.
+pub(super) trait SettingValue: Sized {
+    /// Parses `value`, describing what was expected if it is invalid.
+    fn parse_setting(value: &str) -> Result<Self, String>;
+}
+
+/// Settings keyed by their environment variable names.
+///
+/// Values are layered: the JSON object in `CONFIG_FILE` (if set), then the
+/// environment. Blank values count as unset in every layer, so an empty
+/// variable does not hide a value from the file. Each value is parsed into
+/// its type here; an invalid one is reported and left unset, so
+/// [`AppConfig`](super::AppConfig) can report it together with every
+/// missing one. Secrets are held as [`Secret`], so debug output hides them.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "SCREAMING_SNAKE_CASE")]
+pub(super) struct Settings {
+    pub cookidoo_email: Option<String>,
+    pub cookidoo_password: Option<Secret>,
+    pub cookidoo_credentials_secret: Option<String>,
+    pub cookidoo_client_id: Option<String>,
+    pub cookidoo_client_secret: Option<Secret>,
+    pub cookidoo_refresh_token: Option<Secret>,
+    pub cookidoo_refresh_token_parameter: Option<String>,
+    pub refresh_token_table_name: Option<String>,
+    #[serde(deserialize_with = "setting")]
+    pub cookidoo_password_grant: Option<bool>,
+    pub idempotency_table_name: Option<String>,
+    pub occasion_list_table_name: Option<String>,
+    pub preferences_table_name: Option<String>,
+    pub history_table_name: Option<String>,
+    pub permissions_table_name: Option<String>,
+    #[serde(deserialize_with = "setting")]
+    pub category_overrides: Option<Vec<(String, Category)>>,
+    #[serde(deserialize_with = "setting")]
+    pub item_aliases: Option<Vec<(String, String)>>,
+    pub item_alias_table_name: Option<String>,
+    #[serde(deserialize_with = "setting")]
+    pub item_bundles: Option<ItemBundles>,
+    #[serde(deserialize_with = "setting")]
+    pub maintenance_mode: Option<bool>,
+    pub maintenance_parameter_name: Option<String>,
+    #[serde(deserialize_with = "setting")]
+    pub cookidoo_fixture_mode: Option<FixtureMode>,
+    pub cookidoo_fixture_dir: Option<String>,
+    #[serde(deserialize_with = "setting")]
+    pub speech_style: Option<SpeechStyle>,
+    #[serde(deserialize_with = "setting")]
+    pub add_more_prompt: Option<bool>,
+    #[serde(deserialize_with = "setting")]
+    pub item_quantity_style: Option<QuantityStyle>,
+    #[serde(deserialize_with = "setting")]
+    pub pantry_staples: Option<PantryStaples>,
+    #[serde(deserialize_with = "setting")]
+    pub phrase_variation: Option<PhraseVariation>,
+    #[serde(deserialize_with = "setting")]
+    pub cookidoo_market: Option<Market>,
+    pub cookidoo_base_url: Option<String>,
+    pub cookidoo_token_path: Option<String>,
+    pub cookidoo_shopping_path: Option<String>,
+    pub cookidoo_proxy_url: Option<String>,
+    pub cookidoo_proxy_username: Option<String>,
+    pub cookidoo_proxy_password: Option<Secret>,
+    #[serde(deserialize_with = "setting")]
+    pub cookidoo_accounts: Option<HouseholdAccounts>,
+    #[serde(deserialize_with = "setting")]
+    pub cookidoo_startup_check: Option<bool>,
+    pub cookidoo_shadow_base_url: Option<String>,
+    pub cookidoo_shadow_email: Option<String>,
+    pub cookidoo_shadow_password: Option<Secret>,
+    #[serde(deserialize_with = "setting")]
+    pub cookidoo_user_agent: Option<HeaderValue>,
+    #[serde(deserialize_with = "setting")]
+    pub cookidoo_headers: Option<Vec<(HeaderName, HeaderValue)>>,
+    #[serde(deserialize_with = "setting")]
+    pub cookidoo_accept_language: Option<bool>,
+    #[serde(deserialize_with = "setting")]
+    pub cookidoo_rate_limit: Option<(NonZeroU32, f64)>,
+    #[serde(deserialize_with = "setting")]
+    pub log_scrub_deny_fields: Option<Vec<String>>,
+    #[serde(deserialize_with = "setting")]
+    pub log_scrub_allow_fields: Option<Vec<String>>,
+    #[serde(deserialize_with = "setting")]
+    pub log_scrub_sensitive_slots: Option<Vec<String>>,
+    #[serde(deserialize_with = "setting")]
+    pub log_capture_requests: Option<CaptureMode>,
+    pub log_capture_key: Option<Secret>,
+    pub serve_addr: Option<String>,
+    pub http_server_addr: Option<String>,
+    pub alexa_skill_id: Option<String>,
+    pub tls_cert_path: Option<String>,
+    pub tls_key_path: Option<String>,
+    pub home_assistant_token: Option<Secret>,
+}
+
+/// Deserializes a text value with its [`SettingValue`] parser.
+fn setting<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: SettingValue,
+{
+    let value = String::deserialize(deserializer)?;
+    T::parse_setting(&value)
+        .map(Some)
+        .map_err(de::Error::custom)
+}
+
+impl Settings {
+    /// Loads the layered settings: `CONFIG_FILE`, then the environment.
+    ///
+    /// Returns the settings together with the errors of the values that
+    /// could not be parsed.
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be read, is not a JSON object of
+    /// strings, numbers and booleans, or names an unknown setting.
+    pub fn load() -> Result<(Self, Vec<ConfigError>), ConfigError> {
+        let file = match env::var(CONFIG_FILE)
+            .ok()
+            .filter(|path| !path.trim().is_empty())
+        {
+            Some(path) => {
+                let contents = fs::read_to_string(&path).map_err(|e| {
+                    ConfigError::InvalidEnvVar(CONFIG_FILE.to_string(), format!("{path}: {e}"))
+                })?;
+                Some(contents)
+            }
+            None => None,
+        };
+        Self::layered(file.as_deref(), |name| env::var(name).ok())
+    }
+
+    /// Layers the JSON `file` and the variables returned by `var`.
+    fn layered(
+        file: Option<&str>,
+        var: impl Fn(&str) -> Option<String>,
+    ) -> Result<(Self, Vec<ConfigError>), ConfigError> {
+        let invalid_file =
+            |reason: String| ConfigError::InvalidEnvVar(CONFIG_FILE.to_string(), reason);
+        let names = Self::names();
+        let mut values = Map::new();
+
+        if let Some(file) = file {
+            let overrides: Map<String, Value> = serde_json::from_str(file)
+                .map_err(|e| invalid_file(format!("expected a JSON object: {e}")))?;
+            for (name, value) in overrides {
+                if !names.contains(&name.as_str()) {
+                    return Err(invalid_file(format!("unknown setting {name}")));
+                }
+                let value = match value {
+                    Value::String(value) => value,
+                    Value::Bool(value) => value.to_string(),
+                    Value::Number(value) => value.to_string(),
+                    Value::Null => continue,
+                    _ => return Err(invalid_file(format!("{name} must be a scalar value"))),
+                };
+                if !value.trim().is_empty() {
+                    values.insert(name, Value::String(value));
+                }
+            }
+        }
+
+        for name in names {
+            if let Some(value) = var(name).filter(|value| !value.trim().is_empty()) {
+                values.insert(name.to_string(), Value::String(value));
+            }
+        }
+
+        // Parse each value on its own first, so every invalid one is
+        // reported rather than only the first
+        let mut errors = Vec::new();
+        for name in names {
+            let Some(value) = values.get(*name) else {
+                continue;
+            };
+            let single = Map::from_iter([(name.to_string(), value.clone())]);
+            if let Err(e) = serde_json::from_value::<Self>(Value::Object(single)) {
+                errors.push(ConfigError::InvalidEnvVar(name.to_string(), e.to_string()));
+                values.remove(*name);
+            }
+        }
+
+        let settings = serde_json::from_value(Value::Object(values))
+            .map_err(|e| invalid_file(e.to_string()))?;
+        Ok((settings, errors))
+    }
+
+    /// Returns the setting names, as declared to serde.
+    fn names() -> &'static [&'static str] {
+        let mut names: &'static [&'static str] = &[];
+        let _ = Self::deserialize(FieldNames(&mut names));
+        names
+    }
+}
+
+/// Deserializer recording the field names a struct asks for.
+struct FieldNames<'a>(&'a mut &'static [&'static str]);
+
+impl<'de> Deserializer<'de> for FieldNames<'_> {
+    type Error = de::value::Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value, Self::Error> {
+        Err(de::Error::custom("expected a struct"))
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        _visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        *self.0 = fields;
+        Err(de::Error::custom("field names recorded"))
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map enum identifier ignored_any
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn layered(
+        file: Option<&str>,
+        vars: &[(&str, &str)],
+    ) -> Result<(Settings, Vec<ConfigError>), ConfigError> {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect();
+        Settings::layered(file, |name| vars.get(name).cloned())
+    }
+
+    #[test]
+    fn starts_unset() {
+        let (settings, errors) = layered(None, &[]).unwrap();
+        assert!(errors.is_empty());
+        assert_eq!(settings.cookidoo_email, None);
+        assert_eq!(settings.cookidoo_fixture_dir, None);
+    }
+
+    #[test]
+    fn names_every_setting() {
+        let names = Settings::names();
+        assert!(names.contains(&"COOKIDOO_EMAIL"));
+        assert!(names.contains(&"HOME_ASSISTANT_TOKEN"));
+    }
+
+    #[test]
+    fn environment_overrides_file() {
+        let (settings, _) = layered(
+            Some(r#"{"COOKIDOO_CLIENT_ID": "from-file", "SPEECH_STYLE": "brief"}"#),
+            &[("COOKIDOO_CLIENT_ID", "from-env")],
+        )
+        .unwrap();
+        assert_eq!(settings.cookidoo_client_id.as_deref(), Some("from-env"));
+        assert_eq!(settings.speech_style, Some(SpeechStyle::Brief));
+    }
+
+    #[test]
+    fn blank_environment_value_keeps_file_value() {
+        let (settings, _) = layered(
+            Some(r#"{"COOKIDOO_MARKET": "AT"}"#),
+            &[("COOKIDOO_MARKET", " ")],
+        )
+        .unwrap();
+        assert_eq!(
+            settings.cookidoo_market.as_ref().map(Market::country_code),
+            Some("AT")
+        );
+    }
+
+    #[test]
+    fn file_accepts_booleans_and_numbers() {
+        let (settings, _) = layered(Some(r#"{"MAINTENANCE_MODE": true}"#), &[]).unwrap();
+        assert_eq!(settings.maintenance_mode, Some(true));
+    }
+
+    #[test]
+    fn reports_every_invalid_value() {
+        let (settings, errors) = layered(
+            Some(r#"{"SPEECH_STYLE": "laut"}"#),
+            &[("ADD_MORE_PROMPT", "vielleicht"), ("COOKIDOO_MARKET", "AT")],
+        )
+        .unwrap();
+        let names: Vec<String> = errors
+            .into_iter()
+            .map(|error| match error {
+                ConfigError::InvalidEnvVar(name, _) => name,
+                other => panic!("unexpected {other:?}"),
+            })
+            .collect();
+        assert_eq!(names, ["SPEECH_STYLE", "ADD_MORE_PROMPT"]);
+        assert_eq!(settings.speech_style, None);
+        assert!(settings.cookidoo_market.is_some());
+    }
+
+    #[test]
+    fn debug_hides_secrets() {
+        let (settings, _) = layered(
+            None,
+            &[
+                ("COOKIDOO_PASSWORD", "hunter2"),
+                ("COOKIDOO_CLIENT_SECRET", "client-secret"),
+                ("HOME_ASSISTANT_TOKEN", "ha-token"),
+            ],
+        )
+        .unwrap();
+        let debug = format!("{settings:?}");
+        assert!(!debug.contains("hunter2"));
+        assert!(!debug.contains("client-secret"));
+        assert!(!debug.contains("ha-token"));
+    }
+
+    #[test]
+    fn rejects_unknown_file_settings() {
+        let result = layered(Some(r#"{"COOKIDOO_EMIAL": "max@example.com"}"#), &[]);
+        assert!(
+            matches!(result, Err(ConfigError::InvalidEnvVar(name, reason)) if name == CONFIG_FILE && reason.contains("COOKIDOO_EMIAL"))
+        );
+    }
+
+    #[test]
+    fn rejects_nested_file_values() {
+        let result = layered(Some(r#"{"ITEM_ALIASES": ["Tempo"]}"#), &[]);
+        assert!(result.is_err());
+    }
+}
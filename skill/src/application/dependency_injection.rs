@@ -1,54 +1,634 @@
+use std::path::Path;
 use std::sync::Arc;
+use std::time::Duration;
 
-use crate::adapters::alexa::AlexaSkillHandler;
+use tracing::{error, info, warn};
+
+use crate::adapters::alexa::{AlexaDeviceAddressClient, AlexaSkillHandler};
 use crate::adapters::cookidoo::{
-    CookidooAuthAdapter, CookidooClient, CookidooShoppingListAdapter, TokenCache,
+    AccountRegistry, CookidooAuthAdapter, CookidooClient, CookidooShoppingListAdapter,
+    FixtureRecorder, TokenCache, TokenProvider,
+};
+use crate::adapters::maintenance::{
+    CachedMaintenanceFlag, SsmMaintenanceFlag, StaticMaintenanceFlag,
+};
+use crate::adapters::memory::{
+    InMemoryHistoryRepository, InMemoryIdempotencyStore, InMemoryOccasionListStore,
+    InMemoryPermissionStore, InMemoryPreferencesRepository,
+};
+use crate::adapters::parameters::{ParametersExtensionClient, SecretCredentialStore};
+use crate::adapters::shadow::ShadowShoppingListRepository;
+use crate::domain::models::DomainError;
+use crate::domain::ports::{
+    HistoryRepository, IdempotencyStore, MaintenanceFlag, OccasionListStore, PermissionStore,
+    PreferencesRepository, RefreshTokenStore, ShoppingListRepository,
+};
+use crate::domain::services::{
+    AddItemService, AliasDictionary, BundleService, CategoryClassifier, HistoryService,
+    ListSummaryService, MarketService, OccasionListService, PreferencesService, ReadListService,
+    RemoveItemService, SkillEventService, UserDataService,
 };
-use crate::domain::services::AddItemService;
 
 use super::config::AppConfig;
 
+/// How long the cold start waits for the Cookidoo sign-in of
+/// `COOKIDOO_STARTUP_CHECK`; Lambda allows ten seconds for the whole init.
+const STARTUP_CHECK_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Subdirectory of the fixture directory holding the shadow host's
+/// fixtures, so they do not overwrite those of the primary host.
+const SHADOW_FIXTURE_DIR: &str = "shadow";
+
 /// Dependency injection container holding all wired components.
 ///
 /// This container is created once at Lambda cold start and reused
 /// across warm invocations for optimal performance.
 pub struct Container {
-    handler: AlexaSkillHandler<CookidooShoppingListAdapter>,
+    handler: AlexaSkillHandler,
+    add_item_service: Arc<AddItemService>,
+    user_data_service: Arc<UserDataService>,
 }
 
 impl Container {
     /// Creates a new container with all dependencies wired together.
-    pub fn new(config: AppConfig) -> Self {
-        // Create shared HTTP client
-        let client = CookidooClient::new();
+    ///
+    /// # Errors
+    /// Returns an error if the Cookidoo client ID and secret cannot form an
+    /// authorization header, or if the refresh token is configured in SSM,
+    /// Secrets Manager or a refresh token table that cannot be read, or if a
+    /// credentials secret cannot be read; starting without either would
+    /// fail every request.
+    pub async fn new(config: AppConfig) -> Result<Self, DomainError> {
+        // Create shared HTTP client (on the configured host, recording or
+        // replaying fixtures if enabled)
+        if let Some(base_url) = config.cookidoo_base_url() {
+            info!(base_url = %base_url, "Using configured Cookidoo host");
+        }
+        let client = Self::client(
+            &config,
+            config.cookidoo_base_url(),
+            Path::new(config.cookidoo_fixture_dir()),
+        )?;
 
         // Create shared token cache (survives across invocations)
         let token_cache = Arc::new(TokenCache::new());
 
-        // Create auth adapter with shared cache
-        let auth_adapter = Arc::new(CookidooAuthAdapter::with_cache(
+        // Create auth adapter with shared cache (refresh token flow if
+        // provisioned, password grant as configured)
+        let auth_header = config
+            .cookidoo_auth_header()
+            .map_err(|e| DomainError::AuthenticationFailed(e.to_string()))?;
+        let mut auth_adapter = CookidooAuthAdapter::with_auth_header(
             client.clone(),
             config.cookidoo_credentials().clone(),
-            config.cookidoo_client_id(),
-            config.cookidoo_client_secret(),
+            auth_header.clone(),
             token_cache,
-        ));
+        )
+        .with_password_grant(config.cookidoo_password_grant());
+        if let Some(token_path) = config.cookidoo_token_path() {
+            auth_adapter = auth_adapter.with_token_path(token_path);
+        }
+        if let Some(secret_id) = config.cookidoo_credentials_secret() {
+            info!(secret_id = %secret_id, "Using Cookidoo credentials from Secrets Manager");
+            auth_adapter =
+                auth_adapter.with_credential_store(Arc::new(SecretCredentialStore::new(secret_id)));
+            auth_adapter.reload_credentials().await.map_err(|e| {
+                error!(secret_id = %secret_id, error = %e, "Failed to read Cookidoo credentials");
+                e
+            })?;
+        }
+        let refresh_token_store = Self::refresh_token_store(&config).await;
+        if let Some(refresh_token) =
+            Self::refresh_token(&config, refresh_token_store.as_deref()).await?
+        {
+            info!("Using provisioned Cookidoo refresh token");
+            auth_adapter = auth_adapter.with_refresh_token(refresh_token);
+        }
+        if let Some(store) = refresh_token_store {
+            auth_adapter = auth_adapter.with_refresh_token_store(store);
+        }
+        let primary_email = auth_adapter.current_credentials().email().to_string();
+        let mut auth_provider: Arc<dyn TokenProvider> = Arc::new(auth_adapter);
+
+        // Further household accounts sign in with their own credentials and
+        // keep their own token cache
+        let accounts = config.household_accounts().accounts();
+        if !accounts.is_empty() {
+            let mut registry = AccountRegistry::new(auth_provider);
+            for account in accounts {
+                let mut account_adapter = CookidooAuthAdapter::with_auth_header(
+                    client.clone(),
+                    account.credentials().clone(),
+                    auth_header.clone(),
+                    Arc::new(TokenCache::new()),
+                )
+                .with_password_grant(true);
+                if let Some(token_path) = config.cookidoo_token_path() {
+                    account_adapter = account_adapter.with_token_path(token_path);
+                }
+                if let Some(secret_id) = account.credentials_secret() {
+                    account_adapter = account_adapter
+                        .with_credential_store(Arc::new(SecretCredentialStore::new(secret_id)));
+                    account_adapter.reload_credentials().await.map_err(|e| {
+                        error!(
+                            account = %account.name(),
+                            secret_id = %secret_id,
+                            error = %e,
+                            "Failed to read Cookidoo credentials"
+                        );
+                        e
+                    })?;
+                }
+                registry = registry.with_account(account.name(), Arc::new(account_adapter));
+            }
+            info!(
+                accounts = accounts.len(),
+                "Using further household accounts"
+            );
+            auth_provider = Arc::new(registry);
+        }
+
+        // Create shopping list adapter, shadow-writing to a second host if
+        // configured
+        let mut shopping_list: Arc<dyn ShoppingListRepository> = Arc::new(
+            Self::shopping_list_adapter(&config, client, auth_provider.clone()),
+        );
+        if let (Some(shadow_url), Some(shadow_credentials)) = (
+            config.cookidoo_shadow_base_url(),
+            config.cookidoo_shadow_credentials(),
+        ) {
+            // The configured e-mail was checked already; one read from the
+            // credentials secret is only known now
+            if shadow_credentials
+                .email()
+                .eq_ignore_ascii_case(&primary_email)
+            {
+                error!("Shadow host would sign in to the skill's own Cookidoo account");
+                return Err(DomainError::AuthenticationFailed(
+                    "shadow host must use another Cookidoo account".to_string(),
+                ));
+            }
+            info!(base_url = %shadow_url, "Shadow-writing to second Cookidoo host");
+            let shadow_client = Self::client(
+                &config,
+                Some(shadow_url),
+                &Path::new(config.cookidoo_fixture_dir()).join(SHADOW_FIXTURE_DIR),
+            )?;
+            let mut shadow_auth = CookidooAuthAdapter::with_auth_header(
+                shadow_client.clone(),
+                shadow_credentials.clone(),
+                auth_header.clone(),
+                Arc::new(TokenCache::new()),
+            )
+            .with_password_grant(true);
+            if let Some(token_path) = config.cookidoo_token_path() {
+                shadow_auth = shadow_auth.with_token_path(token_path);
+            }
+            let shadow = Self::shopping_list_adapter(&config, shadow_client, Arc::new(shadow_auth));
+            shopping_list = Arc::new(ShadowShoppingListRepository::new(
+                shopping_list,
+                Arc::new(shadow),
+            ));
+        }
+        if config.cookidoo_startup_check() {
+            Self::check_credentials(
+                shopping_list.as_ref(),
+                &config.cookidoo_credentials_source(),
+            )
+            .await;
+        }
+
+        // Refresh expiring tokens after each request, so the next one does
+        // not wait for it
+        let Container {
+            handler,
+            add_item_service,
+            user_data_service,
+        } = Container::with_repository(config, shopping_list).await;
+        Ok(Container {
+            handler: handler.with_token_refresh(auth_provider),
+            add_item_service,
+            user_data_service,
+        })
+    }
+
+    /// Creates the HTTP client for Cookidoo on `base_url` (default: the
+    /// market's host), with the configured headers and proxy, and with
+    /// fixtures in `fixture_dir` if enabled.
+    ///
+    /// The proxy URL was validated with the configuration, so an invalid
+    /// one is not expected here; if it still fails, so does startup.
+    fn client(
+        config: &AppConfig,
+        base_url: Option<&str>,
+        fixture_dir: &Path,
+    ) -> Result<CookidooClient, DomainError> {
+        let mut client = match base_url {
+            Some(base_url) => CookidooClient::with_base_url(base_url),
+            None => CookidooClient::new(),
+        };
+        if let Some(user_agent) = config.cookidoo_user_agent() {
+            client = client.with_user_agent(user_agent.clone());
+        }
+        for (name, value) in config.cookidoo_headers() {
+            client = client.with_header(name.clone(), value.clone());
+        }
+        if config.cookidoo_accept_language() {
+            client = client.with_market_language();
+        }
+        if let Some((burst, requests_per_second)) = config.cookidoo_rate_limit() {
+            client = client.with_rate_limit(burst, requests_per_second);
+        }
+        if let Some(proxy) = config.cookidoo_proxy() {
+            info!(proxy = %proxy.display_url(), "Using configured proxy for Cookidoo");
+            client = client.with_proxy(proxy)?;
+        }
+        Ok(match config.cookidoo_fixture_mode() {
+            Some(mode) => {
+                info!(mode = ?mode, dir = %fixture_dir.display(), "Using Cookidoo fixtures");
+                client.with_fixture_recorder(FixtureRecorder::new(mode, fixture_dir))
+            }
+            None => client,
+        })
+    }
+
+    /// Creates the shopping list adapter on `client`.
+    ///
+    /// The adapter remembers Cookidoo maintenance per market for a minute,
+    /// so requests in the meantime are answered at once instead of waiting
+    /// on Cookidoo.
+    fn shopping_list_adapter(
+        config: &AppConfig,
+        client: CookidooClient,
+        auth_provider: Arc<dyn TokenProvider>,
+    ) -> CookidooShoppingListAdapter {
+        let adapter = CookidooShoppingListAdapter::new(client, auth_provider);
+        match config.cookidoo_shopping_path() {
+            Some(shopping_path) => adapter.with_shopping_path(shopping_path),
+            None => adapter,
+        }
+    }
+
+    /// Signs in to Cookidoo once, so wrong credentials are reported at cold
+    /// start rather than by every request. Failures and a slow sign-in are
+    /// logged only; the container is created either way.
+    ///
+    /// `source` names the settings the credentials come from, so the log
+    /// line tells which of them to fix.
+    async fn check_credentials(shopping_list: &dyn ShoppingListRepository, source: &str) {
+        match tokio::time::timeout(STARTUP_CHECK_TIMEOUT, shopping_list.warm_up()).await {
+            Ok(Ok(())) => info!("Cookidoo credentials accepted"),
+            Ok(Err(e @ DomainError::AuthenticationFailed(_))) => error!(
+                error = %e,
+                "Cookidoo rejected the configured credentials; every request will fail \
+                until the credentials in {} are fixed",
+                source
+            ),
+            Ok(Err(e)) => warn!(error = %e, "Cookidoo credentials could not be checked"),
+            Err(_) => warn!(
+                timeout_ms = STARTUP_CHECK_TIMEOUT.as_millis() as u64,
+                "Cookidoo credential check timed out"
+            ),
+        }
+    }
+
+    /// Returns the refresh token: the one in the refresh token store, else
+    /// the configured one, reading it from SSM if it is stored there.
+    ///
+    /// The store holds the token Cookidoo rotated last, so it takes
+    /// precedence over the one the skill was deployed with.
+    ///
+    /// # Errors
+    /// Returns the error of a store or parameter that cannot be read.
+    async fn refresh_token(
+        config: &AppConfig,
+        store: Option<&dyn RefreshTokenStore>,
+    ) -> Result<Option<String>, DomainError> {
+        if let Some(store) = store {
+            match store.load().await {
+                Ok(Some(refresh_token)) => return Ok(Some(refresh_token)),
+                Ok(None) => info!("Refresh token store is empty"),
+                Err(e) => {
+                    error!(error = %e, "Failed to read Cookidoo refresh token from store");
+                    return Err(e);
+                }
+            }
+        }
+
+        if let Some(refresh_token) = config.cookidoo_refresh_token() {
+            return Ok(Some(refresh_token.to_string()));
+        }
+
+        let Some(parameter_name) = config.cookidoo_refresh_token_parameter() else {
+            return Ok(None);
+        };
+        match ParametersExtensionClient::new()
+            .get_parameter(parameter_name)
+            .await
+        {
+            Ok(refresh_token) => Ok(Some(refresh_token.trim().to_string())),
+            Err(e) => {
+                error!(error = %e, parameter_name = %parameter_name, "Failed to read Cookidoo refresh token");
+                Err(e)
+            }
+        }
+    }
+
+    /// Creates a container wired like [`Container::new`] but backed by the
+    /// given shopping list repository instead of Cookidoo.
+    ///
+    /// Used for local runs against a mock backend.
+    pub async fn with_repository(
+        config: AppConfig,
+        shopping_list: Arc<dyn ShoppingListRepository>,
+    ) -> Self {
+        // Create stores (DynamoDB if configured, in-memory otherwise)
+        let stores = Self::stores(&config).await;
+
+        // Create category classifier (built-in keywords plus configured overrides)
+        let classifier = Arc::new(
+            CategoryClassifier::new().with_overrides(config.category_overrides().iter().cloned()),
+        );
+
+        // Create item dictionary (built-in aliases, then table, then environment)
+        let dictionary = Arc::new(
+            AliasDictionary::new()
+                .with_aliases(stores.item_aliases)
+                .with_aliases(config.item_aliases().iter().cloned()),
+        );
+
+        // Create domain services
+        let add_item_service = Arc::new(
+            AddItemService::new(shopping_list.clone())
+                .with_idempotency_store(stores.idempotency)
+                .with_classifier(classifier.clone())
+                .with_dictionary(dictionary.clone())
+                .with_quantity_style(config.item_quantity_style()),
+        );
+        let read_list_service = Arc::new(ReadListService::new(shopping_list.clone()));
+        let list_summary_service = Arc::new(
+            ListSummaryService::new(read_list_service.clone()).with_classifier(classifier),
+        );
+        let remove_item_service =
+            Arc::new(RemoveItemService::new(shopping_list).with_dictionary(dictionary.clone()));
+        let occasion_list_service = Arc::new(
+            OccasionListService::new(stores.occasion_lists.clone()).with_dictionary(dictionary),
+        );
+        let preferences_service = Arc::new(PreferencesService::new(stores.preferences.clone()));
+        let bundle_service = Arc::new(
+            BundleService::new(stores.preferences.clone())
+                .with_bundles(Arc::new(config.item_bundles().clone())),
+        );
+        let history_service = Arc::new(HistoryService::new(stores.history.clone()));
+
+        let user_data_service = Arc::new(
+            UserDataService::new()
+                .with_preferences_repository(stores.preferences)
+                .with_history_repository(stores.history)
+                .with_occasion_list_store(stores.occasion_lists)
+                .with_permission_store(stores.permissions.clone()),
+        );
+
+        // Create skill event service (permission state in the permission store;
+        // user data is deleted when the skill is disabled)
+        let skill_event_service = Arc::new(
+            SkillEventService::new(stores.permissions)
+                .with_user_data_service(user_data_service.clone()),
+        );
 
-        // Create shopping list adapter
-        let shopping_list_adapter =
-            Arc::new(CookidooShoppingListAdapter::new(client, auth_adapter));
+        // Create maintenance flag (SSM parameter if configured, cached briefly)
+        let maintenance_flag: Arc<dyn MaintenanceFlag> = match config.maintenance_parameter_name() {
+            Some(parameter_name) => {
+                info!(parameter_name = %parameter_name, "Using SSM maintenance flag");
+                Arc::new(CachedMaintenanceFlag::new(Arc::new(
+                    SsmMaintenanceFlag::new(parameter_name),
+                )))
+            }
+            None => Arc::new(StaticMaintenanceFlag::new(config.maintenance_mode())),
+        };
 
-        // Create domain service
-        let add_item_service = Arc::new(AddItemService::new(shopping_list_adapter));
+        // Create market service (configured market, else from the device address)
+        let market_service = MarketService::new(Arc::new(AlexaDeviceAddressClient::new()));
+        let market_service = Arc::new(match config.cookidoo_market() {
+            Some(market) => {
+                info!(market = %market.country_code(), "Using configured Cookidoo market");
+                market_service.with_market(market)
+            }
+            None => market_service,
+        });
 
         // Create Alexa handler
-        let handler = AlexaSkillHandler::new(add_item_service);
+        let handler = AlexaSkillHandler::new(
+            add_item_service.clone(),
+            read_list_service,
+            remove_item_service,
+        )
+        .with_list_summary_service(list_summary_service)
+        .with_occasion_list_service(occasion_list_service)
+        .with_skill_event_service(skill_event_service)
+        .with_preferences_service(preferences_service)
+        .with_history_service(history_service)
+        .with_user_data_service(user_data_service.clone())
+        .with_market_service(market_service)
+        .with_household_accounts(Arc::new(config.household_accounts().clone()))
+        .with_bundle_service(bundle_service)
+        .with_maintenance_flag(maintenance_flag)
+        .with_speech_style(config.speech_style())
+        .with_pantry(config.pantry_staples().clone())
+        .with_add_more(config.add_more_prompt())
+        .with_phrase_variation(config.phrase_variation());
 
-        Self { handler }
+        Self {
+            handler,
+            add_item_service,
+            user_data_service,
+        }
     }
 
     /// Returns a reference to the Alexa skill handler.
-    pub fn handler(&self) -> &AlexaSkillHandler<CookidooShoppingListAdapter> {
+    pub fn handler(&self) -> &AlexaSkillHandler {
         &self.handler
     }
+
+    /// Returns the add-item service, for entrypoints beside the skill handler.
+    pub fn add_item_service(&self) -> Arc<AddItemService> {
+        self.add_item_service.clone()
+    }
+
+    /// Returns the service deleting a user's stored data, for the
+    /// `delete-user-data` admin tool.
+    pub fn user_data_service(&self) -> Arc<UserDataService> {
+        self.user_data_service.clone()
+    }
+
+    /// Consumes the container, returning the Alexa skill handler.
+    pub fn into_handler(self) -> AlexaSkillHandler {
+        self.handler
+    }
+
+    #[cfg(feature = "dynamodb")]
+    async fn stores(config: &AppConfig) -> Stores {
+        use crate::adapters::dynamodb::{
+            DynamoDbHistoryRepository, DynamoDbIdempotencyStore, DynamoDbItemAliasTable,
+            DynamoDbOccasionListStore, DynamoDbPermissionStore, DynamoDbPreferencesRepository,
+        };
+        use tracing::warn;
+
+        let idempotency_table = config.idempotency_table_name();
+        let occasion_list_table = config.occasion_list_table_name();
+        let preferences_table = config.preferences_table_name();
+        let history_table = config.history_table_name();
+        let item_alias_table = config.item_alias_table_name();
+        let permissions_table = config.permissions_table_name();
+
+        let client = if idempotency_table.is_some()
+            || occasion_list_table.is_some()
+            || preferences_table.is_some()
+            || history_table.is_some()
+            || item_alias_table.is_some()
+            || permissions_table.is_some()
+        {
+            let sdk_config = aws_config::load_from_env().await;
+            Some(aws_sdk_dynamodb::Client::new(&sdk_config))
+        } else {
+            None
+        };
+
+        let idempotency: Arc<dyn IdempotencyStore> = match (&client, idempotency_table) {
+            (Some(client), Some(table_name)) => {
+                info!(table_name = %table_name, "Using DynamoDB idempotency store");
+                Arc::new(DynamoDbIdempotencyStore::new(client.clone(), table_name))
+            }
+            _ => Arc::new(InMemoryIdempotencyStore::new()),
+        };
+
+        let occasion_lists: Arc<dyn OccasionListStore> = match (&client, occasion_list_table) {
+            (Some(client), Some(table_name)) => {
+                info!(table_name = %table_name, "Using DynamoDB occasion list store");
+                Arc::new(DynamoDbOccasionListStore::new(client.clone(), table_name))
+            }
+            _ => Arc::new(InMemoryOccasionListStore::new()),
+        };
+
+        let preferences: Arc<dyn PreferencesRepository> = match (&client, preferences_table) {
+            (Some(client), Some(table_name)) => {
+                info!(table_name = %table_name, "Using DynamoDB preferences repository");
+                Arc::new(DynamoDbPreferencesRepository::new(
+                    client.clone(),
+                    table_name,
+                ))
+            }
+            _ => Arc::new(InMemoryPreferencesRepository::new()),
+        };
+
+        let history: Arc<dyn HistoryRepository> = match (&client, history_table) {
+            (Some(client), Some(table_name)) => {
+                info!(table_name = %table_name, "Using DynamoDB history repository");
+                Arc::new(DynamoDbHistoryRepository::new(client.clone(), table_name))
+            }
+            _ => Arc::new(InMemoryHistoryRepository::new()),
+        };
+
+        let permissions: Arc<dyn PermissionStore> = match (&client, permissions_table) {
+            (Some(client), Some(table_name)) => {
+                info!(table_name = %table_name, "Using DynamoDB permission store");
+                Arc::new(DynamoDbPermissionStore::new(client.clone(), table_name))
+            }
+            _ => Arc::new(InMemoryPermissionStore::new()),
+        };
+
+        let item_aliases = match (&client, item_alias_table) {
+            (Some(client), Some(table_name)) => {
+                match DynamoDbItemAliasTable::new(client.clone(), table_name)
+                    .load()
+                    .await
+                {
+                    Ok(aliases) => {
+                        info!(table_name = %table_name, count = aliases.len(), "Loaded item aliases");
+                        aliases
+                    }
+                    Err(e) => {
+                        warn!(error = %e, "Failed to load item aliases, using built-in ones");
+                        Vec::new()
+                    }
+                }
+            }
+            _ => Vec::new(),
+        };
+
+        Stores {
+            idempotency,
+            occasion_lists,
+            preferences,
+            history,
+            permissions,
+            item_aliases,
+        }
+    }
+
+    /// Creates the store rotated refresh tokens are written to, if
+    /// configured.
+    #[cfg(feature = "dynamodb")]
+    async fn refresh_token_store(config: &AppConfig) -> Option<Arc<dyn RefreshTokenStore>> {
+        use crate::adapters::dynamodb::DynamoDbRefreshTokenStore;
+
+        let table_name = config.refresh_token_table_name()?;
+        info!(table_name = %table_name, "Using DynamoDB refresh token store");
+        let sdk_config = aws_config::load_from_env().await;
+        Some(Arc::new(DynamoDbRefreshTokenStore::new(
+            aws_sdk_dynamodb::Client::new(&sdk_config),
+            table_name,
+        )))
+    }
+
+    #[cfg(not(feature = "dynamodb"))]
+    async fn refresh_token_store(config: &AppConfig) -> Option<Arc<dyn RefreshTokenStore>> {
+        if config.refresh_token_table_name().is_some() {
+            warn!("REFRESH_TOKEN_TABLE_NAME is set but the dynamodb feature is disabled");
+        }
+        None
+    }
+
+    #[cfg(not(feature = "dynamodb"))]
+    async fn stores(config: &AppConfig) -> Stores {
+        use tracing::warn;
+
+        if config.idempotency_table_name().is_some() {
+            warn!("IDEMPOTENCY_TABLE_NAME is set but the dynamodb feature is disabled");
+        }
+        if config.occasion_list_table_name().is_some() {
+            warn!("OCCASION_LIST_TABLE_NAME is set but the dynamodb feature is disabled");
+        }
+        if config.preferences_table_name().is_some() {
+            warn!("PREFERENCES_TABLE_NAME is set but the dynamodb feature is disabled");
+        }
+        if config.history_table_name().is_some() {
+            warn!("HISTORY_TABLE_NAME is set but the dynamodb feature is disabled");
+        }
+        if config.item_alias_table_name().is_some() {
+            warn!("ITEM_ALIAS_TABLE_NAME is set but the dynamodb feature is disabled");
+        }
+        if config.permissions_table_name().is_some() {
+            warn!("PERMISSIONS_TABLE_NAME is set but the dynamodb feature is disabled");
+        }
+        info!("Using in-memory stores");
+        Stores {
+            idempotency: Arc::new(InMemoryIdempotencyStore::new()),
+            occasion_lists: Arc::new(InMemoryOccasionListStore::new()),
+            preferences: Arc::new(InMemoryPreferencesRepository::new()),
+            history: Arc::new(InMemoryHistoryRepository::new()),
+            permissions: Arc::new(InMemoryPermissionStore::new()),
+            item_aliases: Vec::new(),
+        }
+    }
+}
+
+/// Persistence backends selected from the configuration.
+struct Stores {
+    idempotency: Arc<dyn IdempotencyStore>,
+    occasion_lists: Arc<dyn OccasionListStore>,
+    preferences: Arc<dyn PreferencesRepository>,
+    history: Arc<dyn HistoryRepository>,
+    permissions: Arc<dyn PermissionStore>,
+    item_aliases: Vec<(String, String)>,
 }
@@ -1,51 +1,137 @@
-use lambda_runtime::LambdaEvent;
+use std::any::Any;
+use std::panic::AssertUnwindSafe;
+use std::time::Instant;
+
+use futures_util::FutureExt;
 use serde_json::Value;
-use tracing::{error, info};
+use tracing::{error, info, warn};
 
-use crate::adapters::alexa::AlexaRequest;
+use super::skill_event::SkillEvent;
 use crate::adapters::alexa::AlexaSkillHandler;
-use crate::domain::ports::ShoppingListRepository;
+use crate::adapters::alexa::{MessageId, ResponseValidator, Speech};
+use crate::adapters::cookidoo::{RequestAccount, RequestMarket, RequestToken};
+use crate::adapters::logging::Metric;
+
+/// Metric recording how long an Alexa request took to answer.
+const RESPONSE_TIME_METRIC: &str = "ResponseTime";
 
-/// Handles an incoming Lambda event.
+/// Metric counting requests whose handling panicked.
+const PANIC_METRIC: &str = "HandlerPanic";
+
+/// Handles a raw JSON payload, e.g. from the HTTP server.
+///
+/// Classifies the payload as a [`SkillEvent`] and hands it to
+/// [`handle_event`].
+pub async fn handle_payload(payload: Value, handler: &AlexaSkillHandler) -> Value {
+    handle_event(SkillEvent::from_value(payload), handler).await
+}
+
+/// Handles a skill event; the core path shared by the Lambda runtime and
+/// the HTTP server.
 ///
 /// This function:
-/// 1. Parses the incoming event as an Alexa request
-/// 2. Delegates to the Alexa skill handler
-/// 3. Returns the response as JSON
+/// 1. Answers scheduled warm-up events by refreshing the Cookidoo token,
+///    without touching the Alexa path
+/// 2. Answers payloads that are no valid Alexa request with an error
+///    response, logging where the payload broke
+/// 3. Delegates to the Alexa skill handler, with Cookidoo calls going to
+///    the market and household account of the request; a panic while
+///    choosing them or handling is logged and answered with an apology
+///    instead of failing the invocation
+/// 4. Repairs the response where it breaks Alexa's limits
+/// 5. Starts refreshing an expiring Cookidoo token in the background
+/// 6. Records the response time, written with the other telemetry after
+///    the response is sent when running on Lambda
+/// 7. Returns the response as JSON
 ///
-/// # Errors
-/// Returns an error if the request cannot be parsed or if serialization fails.
-pub async fn handle_request<R: ShoppingListRepository>(
-    event: LambdaEvent<Value>,
-    handler: &AlexaSkillHandler<R>,
-) -> Result<Value, lambda_runtime::Error> {
-    let (payload, _context) = event.into_parts();
+/// Responses that cannot be serialized are answered with a generic error
+/// response.
+pub async fn handle_event(event: SkillEvent, handler: &AlexaSkillHandler) -> Value {
+    let alexa_request = match event {
+        SkillEvent::WarmUp => {
+            info!("Received warm-up event");
+            let warmed = handler.warm_up().await;
+            return serde_json::json!({ "warmed": warmed });
+        }
+        SkillEvent::Invalid(e) => {
+            error!(
+                kind = e.kind(),
+                path = e.path(),
+                error = %e,
+                "Failed to parse Alexa request"
+            );
+            return error_response(&Speech::new(MessageId::RequestInvalid).render_default());
+        }
+        SkillEvent::Alexa(request) => *request,
+    };
 
     info!("Received Alexa request");
+    let started = Instant::now();
 
-    // Parse the incoming request
-    let alexa_request: AlexaRequest = match serde_json::from_value(payload) {
-        Ok(req) => req,
-        Err(e) => {
-            error!(error = %e, "Failed to parse Alexa request");
-            return Ok(error_response("Fehler beim Verarbeiten der Anfrage."));
+    // Handle the request, with the account-linking token (if any) used for
+    // Cookidoo instead of the configured account; a panic anywhere in it,
+    // including choosing the market and account, is answered with an
+    // apology
+    let request_id = alexa_request.request.request_id().to_string();
+    let handled = AssertUnwindSafe(async {
+        let market = handler.market(&alexa_request).await;
+        let account = handler.account(&alexa_request).await;
+        let linked_token = alexa_request.linked_access_token().map(str::to_string);
+        RequestMarket::scope(
+            market,
+            RequestAccount::scope(
+                account,
+                RequestToken::scope(linked_token, handler.handle(alexa_request)),
+            ),
+        )
+        .await
+    })
+    .catch_unwind()
+    .await;
+    let mut response = match handled {
+        Ok(response) => response,
+        Err(panic) => {
+            error!(
+                request_id = %request_id,
+                panic = panic_message(panic.as_ref()),
+                "Panicked while handling Alexa request"
+            );
+            Metric::count(PANIC_METRIC).emit();
+            return error_response(&Speech::new(MessageId::InternalError).render_default());
         }
     };
 
-    // Handle the request
-    let response = handler.handle(alexa_request).await;
+    // Validate the response, so Alexa does not reject it with a generic error
+    let repairs = ResponseValidator::validate(&mut response);
+    if !repairs.is_empty() {
+        warn!(repairs = ?repairs, "Repaired response violating Alexa constraints");
+    }
+
+    // Refresh outside the request, so the next one finds a fresh token
+    handler.refresh_in_background();
 
     // Serialize the response
-    match serde_json::to_value(&response) {
+    let value = match serde_json::to_value(&response) {
         Ok(value) => {
             info!("Sending Alexa response");
-            Ok(value)
+            value
         }
         Err(e) => {
             error!(error = %e, "Failed to serialize Alexa response");
-            Ok(error_response("Interner Fehler."))
+            error_response(&Speech::new(MessageId::InternalError).render_default())
         }
-    }
+    };
+    Metric::milliseconds(RESPONSE_TIME_METRIC, started.elapsed()).emit();
+    value
+}
+
+/// Returns the message a panic was raised with.
+fn panic_message(panic: &(dyn Any + Send)) -> &str {
+    panic
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| panic.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("unknown panic")
 }
 
 /// Creates a generic error response for Alexa.
@@ -65,30 +151,90 @@ fn error_response(message: &str) -> Value {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::models::{DomainError, ShoppingListItem};
-    use crate::domain::ports::ShoppingListRepository;
-    use crate::domain::services::AddItemService;
+    use crate::domain::models::{
+        AddedItem, CookidooCredentials, DomainError, HouseholdAccount, HouseholdAccounts,
+        ShoppingListEntry, ShoppingListItem, UserPreferences,
+    };
+    use crate::domain::ports::{PreferencesRepository, ShoppingListRepository};
+    use crate::domain::services::{
+        AddItemService, PreferencesService, ReadListService, RemoveItemService,
+    };
     use async_trait::async_trait;
-    use lambda_runtime::Context;
     use std::sync::Arc;
 
     struct MockRepository;
 
     #[async_trait]
     impl ShoppingListRepository for MockRepository {
-        async fn add_item(&self, _item: &ShoppingListItem) -> Result<(), DomainError> {
+        async fn add_item(&self, _item: &ShoppingListItem) -> Result<Vec<AddedItem>, DomainError> {
+            Ok(Vec::new())
+        }
+
+        async fn list_items(&self) -> Result<Vec<ShoppingListEntry>, DomainError> {
+            Ok(Vec::new())
+        }
+
+        async fn remove_item(&self, _id: &str) -> Result<(), DomainError> {
             Ok(())
         }
     }
 
-    fn make_mock_handler() -> AlexaSkillHandler<MockRepository> {
-        let service = Arc::new(AddItemService::new(Arc::new(MockRepository)));
-        AlexaSkillHandler::new(service)
+    struct PanickingRepository;
+
+    #[async_trait]
+    impl ShoppingListRepository for PanickingRepository {
+        async fn add_item(&self, _item: &ShoppingListItem) -> Result<Vec<AddedItem>, DomainError> {
+            panic!("adapter bug");
+        }
+
+        async fn list_items(&self) -> Result<Vec<ShoppingListEntry>, DomainError> {
+            panic!("adapter bug");
+        }
+
+        async fn remove_item(&self, _id: &str) -> Result<(), DomainError> {
+            panic!("adapter bug");
+        }
+
+        async fn mark_owned(&self, _id: &str) -> Result<(), DomainError> {
+            panic!("adapter bug");
+        }
+    }
+
+    struct PanickingPreferences;
+
+    #[async_trait]
+    impl PreferencesRepository for PanickingPreferences {
+        async fn get(&self, _user_id: &str) -> Result<UserPreferences, DomainError> {
+            panic!("store bug");
+        }
+
+        async fn save(
+            &self,
+            _user_id: &str,
+            _preferences: &UserPreferences,
+        ) -> Result<(), DomainError> {
+            panic!("store bug");
+        }
+
+        async fn delete(&self, _user_id: &str) -> Result<(), DomainError> {
+            panic!("store bug");
+        }
+
+        async fn delete_all(&self, _user_id: &str) -> Result<(), DomainError> {
+            panic!("store bug");
+        }
     }
 
-    fn make_lambda_event(payload: Value) -> LambdaEvent<Value> {
-        let context = Context::default();
-        LambdaEvent::new(payload, context)
+    fn make_handler(repo: Arc<dyn ShoppingListRepository>) -> AlexaSkillHandler {
+        AlexaSkillHandler::new(
+            Arc::new(AddItemService::new(repo.clone())),
+            Arc::new(ReadListService::new(repo.clone())),
+            Arc::new(RemoveItemService::new(repo)),
+        )
+    }
+
+    fn make_mock_handler() -> AlexaSkillHandler {
+        make_handler(Arc::new(MockRepository))
     }
 
     #[tokio::test]
@@ -104,11 +250,8 @@ mod tests {
             }
         });
 
-        let event = make_lambda_event(payload);
-        let result = handle_request(event, &handler).await;
+        let response = handle_payload(payload, &handler).await;
 
-        assert!(result.is_ok());
-        let response = result.unwrap();
         assert_eq!(response["version"], "1.0");
         assert!(response["response"]["outputSpeech"]["text"]
             .as_str()
@@ -116,6 +259,100 @@ mod tests {
             .contains("Willkommen"));
     }
 
+    #[tokio::test]
+    async fn answers_panic_with_apology() {
+        let handler = make_handler(Arc::new(PanickingRepository));
+        let payload = serde_json::json!({
+            "version": "1.0",
+            "request": {
+                "type": "IntentRequest",
+                "requestId": "req-123",
+                "timestamp": "2024-01-27T10:00:00Z",
+                "locale": "de-DE",
+                "intent": {
+                    "name": "AddItemIntent",
+                    "slots": {"Item": {"name": "Item", "value": "Milch"}}
+                }
+            }
+        });
+
+        let response = handle_payload(payload, &handler).await;
+
+        assert!(response["response"]["outputSpeech"]["text"]
+            .as_str()
+            .unwrap()
+            .contains("schiefgelaufen"));
+        assert_eq!(response["response"]["shouldEndSession"], true);
+    }
+
+    #[tokio::test]
+    async fn answers_panic_choosing_the_account_with_apology() {
+        let handler = make_mock_handler()
+            .with_preferences_service(Arc::new(PreferencesService::new(Arc::new(
+                PanickingPreferences,
+            ))))
+            .with_household_accounts(Arc::new(HouseholdAccounts::new().with_account(
+                HouseholdAccount::new("Papa", CookidooCredentials::new("papa@example.de", "pw")),
+            )));
+        let payload = serde_json::json!({
+            "version": "1.0",
+            "context": {"System": {
+                "user": {"userId": "amzn1.ask.account.test"},
+                "person": {"personId": "amzn1.ask.person.papa"}
+            }},
+            "request": {
+                "type": "LaunchRequest",
+                "requestId": "req-123",
+                "timestamp": "2024-01-27T10:00:00Z",
+                "locale": "de-DE"
+            }
+        });
+
+        let response = handle_payload(payload, &handler).await;
+
+        assert!(response["response"]["outputSpeech"]["text"]
+            .as_str()
+            .unwrap()
+            .contains("schiefgelaufen"));
+    }
+
+    #[tokio::test]
+    async fn handles_invalid_payload_without_lambda_event() {
+        let handler = make_mock_handler();
+        let payload = serde_json::json!({"version": "1.0", "request": {"type": "Unknown"}});
+
+        let result = handle_payload(payload, &handler).await;
+
+        assert_eq!(
+            result["response"]["outputSpeech"]["text"],
+            "Fehler beim Verarbeiten der Anfrage."
+        );
+    }
+
+    #[tokio::test]
+    async fn answers_warm_up_event() {
+        let handler = make_mock_handler();
+
+        let result = handle_payload(serde_json::json!({"warmer": true}), &handler).await;
+
+        assert_eq!(result, serde_json::json!({"warmed": true}));
+    }
+
+    #[tokio::test]
+    async fn treats_scheduled_event_as_warm_up() {
+        let handler = make_mock_handler();
+        let payload = serde_json::json!({
+            "version": "0",
+            "source": "aws.events",
+            "detail-type": "Scheduled Event",
+            "detail": {}
+        });
+
+        let result = handle_payload(payload, &handler).await;
+
+        assert_eq!(result["warmed"], true);
+    }
+
     #[tokio::test]
     async fn handles_invalid_json() {
         let handler = make_mock_handler();
@@ -123,11 +360,8 @@ mod tests {
             "invalid": "request"
         });
 
-        let event = make_lambda_event(payload);
-        let result = handle_request(event, &handler).await;
+        let response = handle_payload(payload, &handler).await;
 
-        assert!(result.is_ok());
-        let response = result.unwrap();
         assert!(response["response"]["shouldEndSession"].as_bool().unwrap());
     }
 }
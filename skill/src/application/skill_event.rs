@@ -0,0 +1,271 @@
+use serde::{Deserialize, Deserializer};
+use serde_json::Value;
+use thiserror::Error;
+
+use crate::adapters::alexa::{is_known_request_type, AlexaRequest};
+use crate::adapters::logging::RequestCapture;
+
+/// Event delivered to the skill's Lambda function.
+///
+/// Deserializing never fails: payloads that are neither a warm-up event nor
+/// a valid Alexa request become [`SkillEvent::Invalid`] with a diagnosis of
+/// what is wrong, so they can be logged and answered instead of being
+/// rejected by the Lambda runtime with a bare serde message. Unknown fields
+/// are ignored.
+#[derive(Debug)]
+pub enum SkillEvent {
+    /// Scheduled warm-up event (see [`SkillEvent::from_value`]).
+    WarmUp,
+    /// Alexa skill request.
+    Alexa(Box<AlexaRequest>),
+    /// Payload that could not be parsed.
+    Invalid(PayloadError),
+}
+
+/// Why a payload is not a valid Alexa request.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum PayloadError {
+    #[error("missing field `{path}`")]
+    MissingField { path: String },
+
+    #[error("wrong type at `{path}`: {detail}")]
+    WrongType { path: String, detail: String },
+
+    #[error("unknown request type `{request_type}`: {detail}")]
+    UnknownRequestType {
+        request_type: String,
+        detail: String,
+    },
+
+    #[error("invalid value at `{path}`: {detail}")]
+    Invalid { path: String, detail: String },
+}
+
+impl PayloadError {
+    /// Returns the dotted path of the offending field, e.g.
+    /// `request.intent.name`.
+    pub fn path(&self) -> &str {
+        match self {
+            PayloadError::MissingField { path }
+            | PayloadError::WrongType { path, .. }
+            | PayloadError::Invalid { path, .. } => path,
+            PayloadError::UnknownRequestType { .. } => "request.type",
+        }
+    }
+
+    /// Returns a short name of the kind of error, for log fields.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            PayloadError::MissingField { .. } => "missing_field",
+            PayloadError::WrongType { .. } => "wrong_type",
+            PayloadError::UnknownRequestType { .. } => "unknown_request_type",
+            PayloadError::Invalid { .. } => "invalid",
+        }
+    }
+
+    /// Diagnoses a failed parse of `payload`.
+    ///
+    /// Request types the skill does not know are kept as unknown requests,
+    /// so a parse failure below `request` of such a type is reported as an
+    /// unknown request type rather than as the field that happened to break.
+    fn diagnose(payload: &Value, error: serde_path_to_error::Error<serde_json::Error>) -> Self {
+        let path = error.path().to_string();
+        let detail = error.inner().to_string();
+
+        let request_type = payload
+            .pointer("/request/type")
+            .and_then(Value::as_str)
+            .filter(|request_type| !is_known_request_type(request_type));
+        if let (Some(request_type), true) = (request_type, path.starts_with("request")) {
+            return PayloadError::UnknownRequestType {
+                request_type: request_type.to_string(),
+                detail,
+            };
+        }
+
+        if let Some(field) = missing_field(&detail) {
+            let path = if path == "." {
+                field.to_string()
+            } else {
+                format!("{path}.{field}")
+            };
+            return PayloadError::MissingField { path };
+        }
+
+        if detail.starts_with("invalid type") {
+            PayloadError::WrongType { path, detail }
+        } else {
+            PayloadError::Invalid { path, detail }
+        }
+    }
+}
+
+impl SkillEvent {
+    /// Classifies a raw payload.
+    ///
+    /// Warm-up events are `{"warmer": true}` or a plain EventBridge
+    /// scheduled event. All other payloads are logged first if request
+    /// capture is enabled (see [`RequestCapture`]).
+    pub fn from_value(payload: Value) -> Self {
+        if is_warm_up(&payload) {
+            return SkillEvent::WarmUp;
+        }
+        RequestCapture::global().capture(&payload);
+
+        match serde_path_to_error::deserialize(&payload) {
+            Ok(request) => SkillEvent::Alexa(Box::new(request)),
+            Err(e) => SkillEvent::Invalid(PayloadError::diagnose(&payload, e)),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for SkillEvent {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Value::deserialize(deserializer).map(SkillEvent::from_value)
+    }
+}
+
+fn is_warm_up(payload: &Value) -> bool {
+    payload.get("warmer") == Some(&Value::Bool(true))
+        || (payload.get("source").and_then(Value::as_str) == Some("aws.events")
+            && payload.get("detail-type").and_then(Value::as_str) == Some("Scheduled Event"))
+}
+
+/// Extracts the field name from serde's "missing field `name`" message.
+fn missing_field(detail: &str) -> Option<&str> {
+    detail
+        .strip_prefix("missing field `")?
+        .split('`')
+        .next()
+        .filter(|field| !field.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn launch_request() -> Value {
+        json!({
+            "version": "1.0",
+            "request": {
+                "type": "LaunchRequest",
+                "requestId": "req-1",
+                "timestamp": "2024-01-27T10:00:00Z",
+                "locale": "de-DE"
+            }
+        })
+    }
+
+    fn diagnosis(payload: Value) -> PayloadError {
+        match SkillEvent::from_value(payload) {
+            SkillEvent::Invalid(error) => error,
+            other => panic!("expected invalid payload, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn parses_alexa_request_with_unknown_fields() {
+        let mut payload = launch_request();
+        payload["somethingNew"] = json!({"nested": true});
+        payload["request"]["shinyField"] = json!(1);
+
+        assert!(matches!(
+            SkillEvent::from_value(payload),
+            SkillEvent::Alexa(_)
+        ));
+    }
+
+    #[test]
+    fn recognizes_warm_up_events() {
+        assert!(matches!(
+            SkillEvent::from_value(json!({"warmer": true})),
+            SkillEvent::WarmUp
+        ));
+        assert!(matches!(
+            SkillEvent::from_value(json!({
+                "source": "aws.events",
+                "detail-type": "Scheduled Event"
+            })),
+            SkillEvent::WarmUp
+        ));
+    }
+
+    #[test]
+    fn deserializes_any_json() {
+        let event: SkillEvent = serde_json::from_str(r#"{"invalid": "request"}"#).unwrap();
+
+        assert!(matches!(event, SkillEvent::Invalid(_)));
+    }
+
+    #[test]
+    fn diagnoses_missing_top_level_field() {
+        let mut payload = launch_request();
+        payload.as_object_mut().unwrap().remove("version");
+
+        assert_eq!(
+            diagnosis(payload),
+            PayloadError::MissingField {
+                path: "version".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn diagnoses_missing_nested_field() {
+        let payload = json!({
+            "version": "1.0",
+            "session": {"sessionId": "s-1", "new": true, "application": {"applicationId": "a"}},
+            "request": {
+                "type": "LaunchRequest",
+                "requestId": "req-1",
+                "timestamp": "2024-01-27T10:00:00Z",
+                "locale": "de-DE"
+            }
+        });
+
+        assert_eq!(
+            diagnosis(payload),
+            PayloadError::MissingField {
+                path: "session.user".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn diagnoses_wrong_type() {
+        let mut payload = launch_request();
+        payload["version"] = json!(1);
+
+        let error = diagnosis(payload);
+
+        assert_eq!(error.kind(), "wrong_type");
+        assert_eq!(error.path(), "version");
+    }
+
+    #[test]
+    fn diagnoses_missing_field_in_request() {
+        let mut payload = launch_request();
+        payload["request"]
+            .as_object_mut()
+            .unwrap()
+            .remove("requestId");
+
+        assert_eq!(
+            diagnosis(payload),
+            PayloadError::MissingField {
+                path: "request.requestId".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn diagnoses_unknown_request_type() {
+        let payload = json!({"version": "1.0", "request": {"type": "Unknown"}});
+
+        let error = diagnosis(payload);
+
+        assert_eq!(error.kind(), "unknown_request_type");
+        assert!(error.to_string().contains("`Unknown`"));
+    }
+}
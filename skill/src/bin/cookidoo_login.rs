@@ -0,0 +1,276 @@
+//! Provisioning tool for the Cookidoo refresh token.
+//!
+//! Signs in to Cookidoo in the browser (authorization-code flow with PKCE),
+//! exchanges the code for tokens and stores the refresh token where the
+//! skill reads it, so the password never has to be configured:
+//!
+//! ```text
+//! cargo run --bin cookidoo-login -- --store ssm:/cookidoo-skill/refresh-token
+//! ```
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+use anyhow::{bail, Context, Result};
+use base64::prelude::*;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+use alexa_cookidoo_skill::adapters::cookidoo::{CookidooAuthAdapter, CookidooClient, Pkce};
+use alexa_cookidoo_skill::adapters::parameters::SECRETS_MANAGER_PREFIX;
+use alexa_cookidoo_skill::application::AppConfig;
+use alexa_cookidoo_skill::domain::models::CookidooCredentials;
+
+const USAGE: &str = "Usage: cookidoo-login [--store <target>] [--redirect-uri <uri>] [--paste]
+
+Signs in to Cookidoo in the browser and stores the refresh token for the skill.
+Reads COOKIDOO_CLIENT_ID and COOKIDOO_CLIENT_SECRET from the environment or .env.
+
+Targets:
+  env               print COOKIDOO_REFRESH_TOKEN=... (default)
+  env-file:<path>   set COOKIDOO_REFRESH_TOKEN in the given .env file
+  ssm:<name>        SSM SecureString parameter (uses the aws CLI)
+  secret:<id>       Secrets Manager secret (uses the aws CLI)
+  dynamodb:<table>  DynamoDB refresh token table (uses the aws CLI)
+
+Options:
+  --redirect-uri    redirect URI registered for the client
+                    (default: http://localhost:8765/callback)
+  --paste           paste the redirected URL instead of listening on localhost";
+
+/// Redirect URI used unless `--redirect-uri` is given.
+const DEFAULT_REDIRECT_URI: &str = "http://localhost:8765/callback";
+
+/// Page shown in the browser once the code was received.
+const DONE_PAGE: &str = "<!doctype html><meta charset=\"utf-8\">\
+    <p>Anmeldung abgeschlossen. Du kannst dieses Fenster schließen.</p>";
+
+/// Where the refresh token is written.
+enum Store {
+    Stdout,
+    EnvFile(String),
+    Ssm(String),
+    Secret(String),
+    DynamoDb(String),
+}
+
+impl Store {
+    fn parse(target: &str) -> Result<Self> {
+        Ok(match target.split_once(':') {
+            None if target == "env" => Store::Stdout,
+            Some(("env-file", path)) if !path.is_empty() => Store::EnvFile(path.to_string()),
+            Some(("ssm", name)) if !name.is_empty() => Store::Ssm(name.to_string()),
+            Some(("secret", id)) if !id.is_empty() => Store::Secret(id.to_string()),
+            Some(("dynamodb", table)) if !table.is_empty() => Store::DynamoDb(table.to_string()),
+            _ => bail!("Unknown store '{}'\n\n{}", target, USAGE),
+        })
+    }
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    // Load .env file if present (for the OAuth client credentials)
+    let _ = dotenvy::from_filename("../.env").or_else(|_| dotenvy::dotenv());
+
+    let mut store = Store::Stdout;
+    let mut redirect_uri = DEFAULT_REDIRECT_URI.to_string();
+    let mut paste = false;
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--store" => store = Store::parse(&args.next().context("--store needs a target")?)?,
+            "--redirect-uri" => redirect_uri = args.next().context("--redirect-uri needs a URI")?,
+            "--paste" => paste = true,
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                return Ok(());
+            }
+            other => bail!("Unknown argument '{}'\n\n{}", other, USAGE),
+        }
+    }
+
+    let config = AppConfig::from_env_without_credentials()?;
+    if config.cookidoo_client_id().is_empty() {
+        bail!("COOKIDOO_CLIENT_ID is not set");
+    }
+    if config.cookidoo_client_secret().is_empty() {
+        bail!("COOKIDOO_CLIENT_SECRET is not set");
+    }
+    let client = match config.cookidoo_base_url() {
+        Some(base_url) => CookidooClient::with_base_url(base_url),
+        None => CookidooClient::new(),
+    };
+    let mut auth = CookidooAuthAdapter::new(
+        client,
+        CookidooCredentials::new("", ""),
+        config.cookidoo_client_id(),
+        config.cookidoo_client_secret(),
+    );
+    if let Some(token_path) = config.cookidoo_token_path() {
+        auth = auth.with_token_path(token_path);
+    }
+
+    let pkce = Pkce::generate();
+    let state = random_state()?;
+    println!(
+        "Open this URL and sign in to Cookidoo:\n\n{}\n",
+        auth.authorization_url(&redirect_uri, &pkce, &state)
+    );
+
+    let redirect = if paste {
+        read_pasted_redirect()?
+    } else {
+        receive_redirect(&redirect_uri).await?
+    };
+    let code = CookidooAuthAdapter::authorization_code_from_redirect(&redirect, &state)?;
+    let token = auth
+        .exchange_authorization_code(&code, &redirect_uri, &pkce)
+        .await?;
+
+    write_token(&store, token.refresh_token())
+}
+
+/// Returns an unguessable value tying the redirect to this login.
+fn random_state() -> Result<String> {
+    let mut bytes = [0u8; 16];
+    getrandom::fill(&mut bytes).map_err(|e| anyhow::anyhow!("No random source: {}", e))?;
+    Ok(BASE64_URL_SAFE_NO_PAD.encode(bytes))
+}
+
+/// Reads the URL the browser was redirected to from stdin.
+fn read_pasted_redirect() -> Result<String> {
+    print!("Paste the URL from the browser's address bar after signing in: ");
+    std::io::stdout().flush()?;
+    let mut line = String::new();
+    std::io::stdin().read_line(&mut line)?;
+    Ok(line.trim().to_string())
+}
+
+/// Listens on the redirect URI's port until the browser delivers the
+/// redirect, and returns its request target.
+async fn receive_redirect(redirect_uri: &str) -> Result<String> {
+    let url = reqwest::Url::parse(redirect_uri).context("Invalid redirect URI")?;
+    if !matches!(url.host_str(), Some("localhost" | "127.0.0.1")) {
+        bail!("Redirect URI {} is not local; use --paste", redirect_uri);
+    }
+    let port = url.port_or_known_default().unwrap_or(80);
+    let listener = TcpListener::bind(("127.0.0.1", port))
+        .await
+        .with_context(|| format!("Cannot listen on port {}", port))?;
+    println!("Waiting for the redirect on {} ...", redirect_uri);
+
+    loop {
+        let (mut stream, _) = listener.accept().await?;
+        let mut buffer = vec![0u8; 8192];
+        let read = stream.read(&mut buffer).await?;
+        let request = String::from_utf8_lossy(&buffer[..read]);
+        let target = request
+            .lines()
+            .next()
+            .and_then(|line| line.split_whitespace().nth(1))
+            .unwrap_or_default()
+            .to_string();
+
+        // Browsers also ask for a favicon; only the redirect path counts
+        if !target.starts_with(url.path()) {
+            stream
+                .write_all(b"HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\n\r\n")
+                .await?;
+            continue;
+        }
+
+        let response = format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: text/html; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            DONE_PAGE.len(),
+            DONE_PAGE
+        );
+        stream.write_all(response.as_bytes()).await?;
+        return Ok(target);
+    }
+}
+
+/// Writes the refresh token to the chosen store.
+fn write_token(store: &Store, refresh_token: &str) -> Result<()> {
+    match store {
+        Store::Stdout => println!("COOKIDOO_REFRESH_TOKEN={}", refresh_token),
+        Store::EnvFile(path) => {
+            let existing = std::fs::read_to_string(path).unwrap_or_default();
+            let mut lines: Vec<String> = existing
+                .lines()
+                .filter(|line| !line.starts_with("COOKIDOO_REFRESH_TOKEN="))
+                .map(str::to_string)
+                .collect();
+            lines.push(format!("COOKIDOO_REFRESH_TOKEN={}", refresh_token));
+            std::fs::write(path, lines.join("\n") + "\n")
+                .with_context(|| format!("Cannot write {}", path))?;
+            println!("Refresh token written to {}", path);
+        }
+        Store::Ssm(name) => {
+            aws_cli(
+                &["ssm", "put-parameter"],
+                serde_json::json!({
+                    "Name": name,
+                    "Value": refresh_token,
+                    "Type": "SecureString",
+                    "Overwrite": true,
+                }),
+            )?;
+            println!("Refresh token stored. Configure the skill with:");
+            println!("COOKIDOO_REFRESH_TOKEN_PARAMETER={}", name);
+        }
+        Store::Secret(id) => {
+            aws_cli(
+                &["secretsmanager", "put-secret-value"],
+                serde_json::json!({
+                    "SecretId": id,
+                    "SecretString": refresh_token,
+                }),
+            )?;
+            println!("Refresh token stored. Configure the skill with:");
+            println!(
+                "COOKIDOO_REFRESH_TOKEN_PARAMETER={}{}",
+                SECRETS_MANAGER_PREFIX, id
+            );
+        }
+        Store::DynamoDb(table) => {
+            // The item DynamoDbRefreshTokenStore reads
+            aws_cli(
+                &["dynamodb", "put-item"],
+                serde_json::json!({
+                    "TableName": table,
+                    "Item": {
+                        "id": {"S": "cookidoo"},
+                        "refreshToken": {"S": refresh_token},
+                    },
+                }),
+            )?;
+            println!("Refresh token stored. Configure the skill with:");
+            println!("REFRESH_TOKEN_TABLE_NAME={}", table);
+        }
+    }
+    Ok(())
+}
+
+/// Runs an aws CLI command, passing its input on stdin so the token does
+/// not show up in the process list.
+fn aws_cli(command: &[&str], input: serde_json::Value) -> Result<()> {
+    let mut child = Command::new("aws")
+        .args(command)
+        .args(["--cli-input-json", "file:///dev/stdin"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .spawn()
+        .context("Cannot run the aws CLI")?;
+
+    child
+        .stdin
+        .take()
+        .context("aws CLI has no stdin")?
+        .write_all(input.to_string().as_bytes())?;
+
+    let status = child.wait()?;
+    if !status.success() {
+        bail!("aws {} failed with {}", command.join(" "), status);
+    }
+    Ok(())
+}
@@ -0,0 +1,75 @@
+//! Admin tool deleting everything the skill stored about users.
+//!
+//! Removes the preferences, add history, occasion lists and granted
+//! permissions of the given Alexa user IDs from the stores configured in the environment (the
+//! DynamoDB tables of the deployment), e.g. to answer a deletion request
+//! that did not come through the skill:
+//!
+//! ```text
+//! cargo run --bin delete-user-data -- amzn1.ask.account.AAA amzn1.ask.account.BBB
+//! ```
+
+use std::sync::Arc;
+
+use anyhow::{bail, Result};
+
+use alexa_cookidoo_skill::adapters::memory::InMemoryShoppingListRepository;
+use alexa_cookidoo_skill::application::{AppConfig, Container};
+
+const USAGE: &str = "Usage: delete-user-data <alexa user id>...
+
+Deletes the preferences, add history, occasion lists and permissions of the
+given users from the stores configured in the environment or .env
+(PREFERENCES_TABLE_NAME, HISTORY_TABLE_NAME, OCCASION_LIST_TABLE_NAME,
+PERMISSIONS_TABLE_NAME). The Cookidoo shopping list is not touched.";
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let _ = dotenvy::from_filename("../.env").or_else(|_| dotenvy::dotenv());
+
+    let mut user_ids = Vec::new();
+    for arg in std::env::args().skip(1) {
+        match arg.as_str() {
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                return Ok(());
+            }
+            _ => user_ids.push(arg),
+        }
+    }
+    if user_ids.is_empty() {
+        bail!("no user IDs given\n\n{}", USAGE);
+    }
+
+    // Only the stores are needed, so no Cookidoo account is configured
+    let config = AppConfig::from_env_without_credentials()?;
+    if config.preferences_table_name().is_none()
+        && config.history_table_name().is_none()
+        && config.occasion_list_table_name().is_none()
+        && config.permissions_table_name().is_none()
+    {
+        bail!("no user data tables configured\n\n{}", USAGE);
+    }
+    let container =
+        Container::with_repository(config, Arc::new(InMemoryShoppingListRepository::new())).await;
+    let service = container.user_data_service();
+
+    let mut failed = 0;
+    for user_id in &user_ids {
+        match service.delete(user_id).await {
+            Ok(()) => println!("deleted {}", user_id),
+            Err(e) => {
+                eprintln!("failed {}: {}", user_id, e);
+                failed += 1;
+            }
+        }
+    }
+    if failed > 0 {
+        bail!(
+            "{} of {} users could not be deleted",
+            failed,
+            user_ids.len()
+        );
+    }
+    Ok(())
+}
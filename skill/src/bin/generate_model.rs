@@ -0,0 +1,17 @@
+//! Prints the Alexa interaction model (de-DE) as JSON.
+//!
+//! The model is built from the same intent and slot names the skill parses,
+//! so deploying its output keeps code and model in sync:
+//!
+//! ```text
+//! cargo run --bin generate-model > skill-package/interactionModels/custom/de-DE.json
+//! ```
+
+use std::io::{self, Write};
+
+use alexa_cookidoo_skill::adapters::alexa::InteractionModel;
+
+fn main() -> io::Result<()> {
+    let json = serde_json::to_string_pretty(&InteractionModel::german())?;
+    writeln!(io::stdout().lock(), "{}", json)
+}
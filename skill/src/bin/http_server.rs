@@ -0,0 +1,75 @@
+//! HTTPS entrypoint for self-hosting the skill instead of AWS Lambda.
+//!
+//! Serves Alexa requests on `POST /` and `POST /alexa`, verifying their
+//! signatures, with the same configuration and wiring as the Lambda.
+//!
+//! - `ALEXA_SKILL_ID` is required; requests for other skills are rejected
+//!   with 403, as any skill's requests carry a valid signature.
+//! - The listen address is taken from the first argument or
+//!   `HTTP_SERVER_ADDR` (default `0.0.0.0:8443`).
+//! - `TLS_CERT_PATH` and `TLS_KEY_PATH` point to PEM files for HTTPS. Without
+//!   them the server speaks plain HTTP, for use behind a TLS-terminating
+//!   reverse proxy.
+//! - `HOME_ASSISTANT_TOKEN` enables `POST /api/items` for Home Assistant,
+//!   authenticated with `Authorization: Bearer <token>`.
+
+use std::path::Path;
+use std::sync::Arc;
+
+use anyhow::Result;
+use tokio::net::TcpListener;
+use tracing::{info, warn};
+
+use alexa_cookidoo_skill::adapters::http_server::{
+    serve, tls_acceptor, AlexaEndpoint, HomeAssistantApi, SignatureVerifier,
+};
+use alexa_cookidoo_skill::adapters::logging;
+use alexa_cookidoo_skill::application::{AppConfig, Container};
+
+/// Address used when neither an argument nor `HTTP_SERVER_ADDR` is given.
+const DEFAULT_ADDR: &str = "0.0.0.0:8443";
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    // Load .env file if present (for local development)
+    let _ = dotenvy::from_filename("../.env");
+
+    let config = AppConfig::from_env()?;
+    logging::init(config.log_scrubber().clone(), config.request_capture());
+    info!(config = %config.summary(), "Configuration loaded");
+    config.validate()?;
+
+    let addr = std::env::args()
+        .nth(1)
+        .or_else(|| config.http_server_addr().map(str::to_string))
+        .unwrap_or_else(|| DEFAULT_ADDR.to_string());
+
+    let tls = match config.tls_paths() {
+        Some((cert, key)) => Some(tls_acceptor(Path::new(cert), Path::new(key))?),
+        None => {
+            warn!("TLS_CERT_PATH/TLS_KEY_PATH not set, serving plain HTTP");
+            None
+        }
+    };
+    let skill_id = config.require_alexa_skill_id()?.to_string();
+    let home_assistant_token = config.home_assistant_token().cloned();
+
+    let container = Container::new(config).await?;
+    info!("Initialization complete, starting HTTP server");
+
+    let home_assistant = home_assistant_token
+        .and_then(|token| HomeAssistantApi::new(container.add_item_service(), token));
+
+    let mut endpoint = AlexaEndpoint::new(
+        Arc::new(container.into_handler()),
+        SignatureVerifier::new(),
+        skill_id,
+    );
+    if let Some(api) = home_assistant {
+        info!("Home Assistant endpoint enabled");
+        endpoint = endpoint.with_home_assistant_api(api);
+    }
+
+    serve(TcpListener::bind(&addr).await?, tls, endpoint.router()).await?;
+    Ok(())
+}
@@ -0,0 +1,102 @@
+//! Replays Alexa requests from exported CloudWatch logs.
+//!
+//! Reads log lines from a file (or stdin with `-`), picks out the request
+//! payloads and runs them through the real `Container` in order, printing
+//! each answer, so a failure a user reported can be reproduced locally.
+//! Payloads are logged by the skill with `LOG_CAPTURE_REQUESTS` set; raw
+//! requests and CloudWatch exports of such lines are read as well.
+//! Without `--mock` the live Cookidoo API is used with the credentials
+//! from `.env`:
+//!
+//! ```text
+//! aws logs filter-log-events --log-group-name /aws/lambda/alexa-cookidoo-skill \
+//!     --filter-pattern '"Captured Alexa request"' > events.json
+//! cargo run --bin replay -- --mock events.json
+//! ```
+
+use std::io::Read;
+use std::sync::Arc;
+
+use anyhow::{bail, Context, Result};
+use serde_json::Value;
+
+use alexa_cookidoo_skill::adapters::alexa::AlexaSkillHandler;
+use alexa_cookidoo_skill::adapters::memory::InMemoryShoppingListRepository;
+use alexa_cookidoo_skill::adapters::simulator::extract_requests;
+use alexa_cookidoo_skill::application::{handle_payload, AppConfig, Container};
+
+const USAGE: &str = "Usage: replay [--mock] [--json] <log file | ->
+
+Options:
+  --mock          use an in-memory list instead of the live Cookidoo API
+  --json          print the full requests and responses";
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    // Load .env file if present (for the live Cookidoo API)
+    let _ = dotenvy::from_filename("../.env").or_else(|_| dotenvy::dotenv());
+
+    let mut mock = false;
+    let mut show_json = false;
+    let mut path = None;
+    for arg in std::env::args().skip(1) {
+        match arg.as_str() {
+            "--mock" => mock = true,
+            "--json" => show_json = true,
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                return Ok(());
+            }
+            _ if path.is_none() => path = Some(arg),
+            other => bail!("unexpected argument '{}'\n\n{}", other, USAGE),
+        }
+    }
+    let Some(path) = path else {
+        bail!("no log file given\n\n{}", USAGE);
+    };
+
+    let text = if path == "-" {
+        let mut text = String::new();
+        std::io::stdin().read_to_string(&mut text)?;
+        text
+    } else {
+        std::fs::read_to_string(&path).with_context(|| format!("cannot read {}", path))?
+    };
+    let requests = extract_requests(&text);
+    if requests.is_empty() {
+        bail!("no Alexa requests found in {}", path);
+    }
+
+    let container = if mock {
+        let config = AppConfig::from_env_without_credentials()?;
+        let repository = Arc::new(InMemoryShoppingListRepository::new());
+        Container::with_repository(config, repository).await
+    } else {
+        let config = AppConfig::from_env()
+            .context("Cookidoo credentials missing; configure .env or use --mock")?;
+        Container::new(config).await?
+    };
+    replay(container.handler(), requests, show_json).await
+}
+
+/// Runs the requests in order and prints each answer.
+async fn replay(handler: &AlexaSkillHandler, requests: Vec<Value>, show_json: bool) -> Result<()> {
+    for request in requests {
+        let request_type = request["request"]["type"].as_str().unwrap_or("?");
+        let intent = request["request"]["intent"]["name"].as_str();
+        println!("> {}", intent.unwrap_or(request_type));
+        if show_json {
+            println!("{}", serde_json::to_string_pretty(&request)?);
+        }
+
+        let response = handle_payload(request, handler).await;
+        if show_json {
+            println!("{}", serde_json::to_string_pretty(&response)?);
+        }
+        let speech = &response["response"]["outputSpeech"];
+        let text = speech["text"].as_str().or_else(|| speech["ssml"].as_str());
+        println!("Alexa: {}", text.unwrap_or("(keine Sprachausgabe)"));
+    }
+
+    Ok(())
+}
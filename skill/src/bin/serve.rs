@@ -0,0 +1,65 @@
+//! Serve mode: runs the skill as a plain-HTTP service on the local network.
+//!
+//! Family members can manage the in-memory shopping list from a phone
+//! browser (`GET /`, REST under `/items`), while Alexa requests posted to
+//! `/alexa` (and Dialogflow webhook requests posted to `/dialogflow`) are
+//! handled by the same services and backend. No Cookidoo account is needed.
+//!
+//! The listen address is taken from the first argument or `SERVE_ADDR`
+//! (default `0.0.0.0:8080`).
+
+use std::sync::Arc;
+
+use tokio::net::TcpListener;
+
+use alexa_cookidoo_skill::adapters::alexa::AlexaSkillHandler;
+use alexa_cookidoo_skill::adapters::google::DialogflowHandler;
+use alexa_cookidoo_skill::adapters::logging;
+use alexa_cookidoo_skill::adapters::memory::InMemoryShoppingListRepository;
+use alexa_cookidoo_skill::adapters::rest::RestApi;
+use alexa_cookidoo_skill::adapters::web::serve;
+use alexa_cookidoo_skill::application::AppConfig;
+use alexa_cookidoo_skill::domain::services::{AddItemService, ReadListService, RemoveItemService};
+
+/// Address used when neither an argument nor `SERVE_ADDR` is given.
+const DEFAULT_ADDR: &str = "0.0.0.0:8080";
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let config = AppConfig::from_env_without_credentials()?;
+    logging::init(config.log_scrubber().clone(), config.request_capture());
+
+    let addr = std::env::args()
+        .nth(1)
+        .or_else(|| config.serve_addr().map(str::to_string))
+        .unwrap_or_else(|| DEFAULT_ADDR.to_string());
+
+    let repository = Arc::new(InMemoryShoppingListRepository::new());
+    let add_item_service = Arc::new(AddItemService::new(repository.clone()));
+    let read_list_service = Arc::new(ReadListService::new(repository.clone()));
+    let remove_item_service = Arc::new(RemoveItemService::new(repository));
+    let handler = Arc::new(AlexaSkillHandler::new(
+        add_item_service.clone(),
+        read_list_service.clone(),
+        remove_item_service.clone(),
+    ));
+
+    let dialogflow_handler = Arc::new(DialogflowHandler::new(
+        add_item_service.clone(),
+        read_list_service.clone(),
+        remove_item_service.clone(),
+    ));
+
+    let api = Arc::new(
+        RestApi::new(
+            add_item_service,
+            read_list_service,
+            remove_item_service,
+            handler,
+        )
+        .with_dialogflow_handler(dialogflow_handler),
+    );
+
+    serve(TcpListener::bind(&addr).await?, api.router()).await?;
+    Ok(())
+}
@@ -0,0 +1,81 @@
+//! Interactive end-to-end simulator for the skill.
+//!
+//! Type utterances as you would speak them to Alexa ("Füge Milch hinzu");
+//! they are converted to Alexa requests, handled by the real skill handler
+//! against the in-memory dry-run backend, and the speech output is printed.
+
+use std::io::{self, BufRead, Write};
+use std::sync::Arc;
+
+use alexa_cookidoo_skill::adapters::alexa::AlexaSkillHandler;
+use alexa_cookidoo_skill::adapters::memory::InMemoryShoppingListRepository;
+use alexa_cookidoo_skill::adapters::simulator::{parse_utterance, RequestFactory};
+use alexa_cookidoo_skill::domain::services::{AddItemService, ReadListService, RemoveItemService};
+
+const USAGE: &str = "Cookidoo-Simulator. Sprich mit dem Skill, z.B. \"Füge Milch hinzu\".\n\
+    Befehle: :liste (Dry-Run-Liste anzeigen), :json (Anfragen ein/aus), :ende (beenden)";
+
+#[tokio::main]
+async fn main() -> io::Result<()> {
+    let repository = Arc::new(InMemoryShoppingListRepository::new());
+    let handler = AlexaSkillHandler::new(
+        Arc::new(AddItemService::new(repository.clone())),
+        Arc::new(ReadListService::new(repository.clone())),
+        Arc::new(RemoveItemService::new(repository.clone())),
+    );
+
+    let mut factory = RequestFactory::new();
+    let mut show_json = false;
+
+    println!("{}", USAGE);
+
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+
+    loop {
+        print!("> ");
+        io::stdout().flush()?;
+
+        let Some(line) = lines.next() else {
+            break;
+        };
+        let line = line?;
+
+        match line.trim() {
+            ":ende" | ":quit" => break,
+            ":liste" => {
+                let items = repository.items();
+                if items.is_empty() {
+                    println!("(Liste ist leer)");
+                }
+                for item in items {
+                    println!("- {}", item.name());
+                }
+                continue;
+            }
+            ":json" => {
+                show_json = !show_json;
+                continue;
+            }
+            _ => {}
+        }
+
+        let intent = parse_utterance(&line);
+        let request_json = factory.build_json(&intent);
+        if show_json {
+            println!("{}", serde_json::to_string_pretty(&request_json)?);
+        }
+
+        let request = serde_json::from_value(request_json)?;
+        let response = handler.handle(request).await;
+
+        println!("Alexa: {}", response.response.output_speech.text);
+
+        if response.response.should_end_session {
+            println!("(Sitzung beendet)");
+            factory.end_session();
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,140 @@
+//! Command-line test harness for the skill.
+//!
+//! Builds Alexa requests for the given commands and runs them in one session
+//! through the real `Container`, printing the speech output. Without
+//! `--mock` the live Cookidoo API is used with the credentials from `.env`;
+//! with `--mock` an in-memory list stands in for Cookidoo:
+//!
+//! ```text
+//! cargo run --bin skill-cli -- --mock launch add Milch add "frische Eier" read
+//! ```
+
+use std::sync::Arc;
+
+use anyhow::{bail, Context, Result};
+
+use alexa_cookidoo_skill::adapters::alexa::{intent_names, slot_names, AlexaSkillHandler};
+use alexa_cookidoo_skill::adapters::memory::InMemoryShoppingListRepository;
+use alexa_cookidoo_skill::adapters::simulator::{RequestFactory, SimulatedIntent};
+use alexa_cookidoo_skill::application::{AppConfig, Container};
+
+const USAGE: &str = "Usage: skill-cli [--mock] [--json] <command>...
+
+Commands:
+  launch          open the skill
+  add <item>      AddItemIntent with the given item slot
+  remove <item>   RemoveItemIntent with the given item slot
+  read            ReadListIntent
+  help            AMAZON.HelpIntent
+
+Options:
+  --mock          use an in-memory list instead of the live Cookidoo API
+  --json          print the generated Alexa requests";
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    // Load .env file if present (for the live Cookidoo API)
+    let _ = dotenvy::from_filename("../.env").or_else(|_| dotenvy::dotenv());
+
+    let mut mock = false;
+    let mut show_json = false;
+    let mut words = Vec::new();
+    for arg in std::env::args().skip(1) {
+        match arg.as_str() {
+            "--mock" => mock = true,
+            "--json" => show_json = true,
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                return Ok(());
+            }
+            _ => words.push(arg),
+        }
+    }
+
+    let intents = parse_commands(&words)?;
+
+    if mock {
+        let config = AppConfig::from_env_without_credentials()?;
+        let repository = Arc::new(InMemoryShoppingListRepository::new());
+        let container = Container::with_repository(config, repository.clone()).await;
+        run(container.handler(), &intents, show_json).await?;
+
+        println!("Mock-Liste:");
+        for item in repository.items() {
+            println!("- {}", item.name());
+        }
+    } else {
+        let config = AppConfig::from_env()
+            .context("Cookidoo credentials missing; configure .env or use --mock")?;
+        let container = Container::new(config).await?;
+        run(container.handler(), &intents, show_json).await?;
+    }
+
+    Ok(())
+}
+
+/// Converts command-line words into the intents to simulate.
+fn parse_commands(words: &[String]) -> Result<Vec<SimulatedIntent>> {
+    if words.is_empty() {
+        bail!("no commands given\n\n{}", USAGE);
+    }
+
+    let mut intents = Vec::new();
+    let mut words = words.iter();
+    while let Some(command) = words.next() {
+        let intent = match command.as_str() {
+            "launch" => SimulatedIntent::Launch,
+            "read" => intent(intent_names::READ_LIST, None),
+            "help" => intent(intent_names::HELP, None),
+            "add" | "remove" => {
+                let item = words
+                    .next()
+                    .with_context(|| format!("'{}' needs an item", command))?;
+                let name = if command == "add" {
+                    intent_names::ADD_ITEM
+                } else {
+                    intent_names::REMOVE_ITEM
+                };
+                intent(name, Some(item))
+            }
+            other => bail!("unknown command '{}'\n\n{}", other, USAGE),
+        };
+        intents.push(intent);
+    }
+    Ok(intents)
+}
+
+fn intent(name: &'static str, item: Option<&String>) -> SimulatedIntent {
+    SimulatedIntent::Intent {
+        name,
+        slots: item
+            .map(|item| (slot_names::ITEM, item.clone()))
+            .into_iter()
+            .collect(),
+    }
+}
+
+/// Runs the intents in one session and prints each response.
+async fn run(
+    handler: &AlexaSkillHandler,
+    intents: &[SimulatedIntent],
+    show_json: bool,
+) -> Result<()> {
+    let mut factory = RequestFactory::new();
+
+    for intent in intents {
+        let request_json = factory.build_json(intent);
+        if show_json {
+            println!("{}", serde_json::to_string_pretty(&request_json)?);
+        }
+
+        let response = handler.handle(serde_json::from_value(request_json)?).await;
+        println!("Alexa: {}", response.response.output_speech.text);
+
+        if response.response.should_end_session {
+            factory.end_session();
+        }
+    }
+
+    Ok(())
+}
@@ -1,3 +0,0 @@
-pub mod models;
-pub mod ports;
-pub mod services;
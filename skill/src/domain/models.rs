@@ -1,7 +0,0 @@
-mod auth;
-mod error;
-mod shopping_list_item;
-
-pub use auth::{AuthToken, CookidooCredentials};
-pub use error::DomainError;
-pub use shopping_list_item::ShoppingListItem;
@@ -1,5 +0,0 @@
-mod authentication_service;
-mod shopping_list_repository;
-
-pub use authentication_service::AuthenticationService;
-pub use shopping_list_repository::ShoppingListRepository;
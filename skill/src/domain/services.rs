@@ -1,3 +0,0 @@
-mod add_item_service;
-
-pub use add_item_service::AddItemService;
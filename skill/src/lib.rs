@@ -1,3 +1,19 @@
+//! Alexa skill adding items to the Cookidoo shopping list.
+//!
+//! The skill is layered on three crates of the workspace, re-exported here
+//! under their module names:
+//!
+//! - [`domain`] (`shopping-list-domain`): the shopping list models, the
+//!   ports and the services using them
+//! - [`adapters::cookidoo`] (`cookidoo-client`): the Cookidoo API
+//! - [`adapters::alexa`] (`alexa-skill-kit`): the Alexa request and
+//!   response models, session state and speech, together with the handler
+//!   built on them here
+//!
+//! [`adapters`] holds the other implementations of the ports (Dialogflow,
+//! storage), and [`application`] the configuration, wiring and request
+//! handling. The Lambda binary is the `skill-lambda` crate.
+
 pub mod adapters;
 pub mod application;
-pub mod domain;
+pub use shopping_list_domain as domain;
@@ -4,17 +4,48 @@ use std::sync::Arc;
 
 use async_trait::async_trait;
 
-use alexa_cookidoo_skill::adapters::alexa::{AlexaRequest, AlexaSkillHandler};
-use alexa_cookidoo_skill::domain::models::{DomainError, ShoppingListItem};
+use alexa_cookidoo_skill::adapters::alexa::{AlexaRequest, AlexaSkillHandler, PhraseVariation};
+use alexa_cookidoo_skill::domain::models::{
+    AddedItem, DomainError, ShoppingListEntry, ShoppingListItem,
+};
 use alexa_cookidoo_skill::domain::ports::ShoppingListRepository;
-use alexa_cookidoo_skill::domain::services::AddItemService;
+use alexa_cookidoo_skill::domain::services::{AddItemService, ReadListService, RemoveItemService};
 
 /// Mock repository that always succeeds.
 struct SuccessRepository;
 
 #[async_trait]
 impl ShoppingListRepository for SuccessRepository {
-    async fn add_item(&self, _item: &ShoppingListItem) -> Result<(), DomainError> {
+    async fn add_item(&self, _item: &ShoppingListItem) -> Result<Vec<AddedItem>, DomainError> {
+        Ok(Vec::new())
+    }
+
+    async fn list_items(&self) -> Result<Vec<ShoppingListEntry>, DomainError> {
+        Ok(vec![
+            ShoppingListEntry::new("id-1", "Milch", false),
+            ShoppingListEntry::new("id-2", "Eier", true),
+        ])
+    }
+
+    async fn remove_item(&self, _id: &str) -> Result<(), DomainError> {
+        Ok(())
+    }
+}
+
+/// Mock repository with an empty shopping list.
+struct EmptyRepository;
+
+#[async_trait]
+impl ShoppingListRepository for EmptyRepository {
+    async fn add_item(&self, _item: &ShoppingListItem) -> Result<Vec<AddedItem>, DomainError> {
+        Ok(Vec::new())
+    }
+
+    async fn list_items(&self) -> Result<Vec<ShoppingListEntry>, DomainError> {
+        Ok(Vec::new())
+    }
+
+    async fn remove_item(&self, _id: &str) -> Result<(), DomainError> {
         Ok(())
     }
 }
@@ -24,11 +55,21 @@ struct FailingRepository;
 
 #[async_trait]
 impl ShoppingListRepository for FailingRepository {
-    async fn add_item(&self, _item: &ShoppingListItem) -> Result<(), DomainError> {
+    async fn add_item(&self, _item: &ShoppingListItem) -> Result<Vec<AddedItem>, DomainError> {
         Err(DomainError::RepositoryError(
             "Connection failed".to_string(),
         ))
     }
+
+    async fn list_items(&self) -> Result<Vec<ShoppingListEntry>, DomainError> {
+        Err(DomainError::RepositoryError(
+            "Connection failed".to_string(),
+        ))
+    }
+
+    async fn remove_item(&self, _id: &str) -> Result<(), DomainError> {
+        Ok(())
+    }
 }
 
 /// Mock repository that fails with an auth error.
@@ -36,16 +77,31 @@ struct AuthFailingRepository;
 
 #[async_trait]
 impl ShoppingListRepository for AuthFailingRepository {
-    async fn add_item(&self, _item: &ShoppingListItem) -> Result<(), DomainError> {
+    async fn add_item(&self, _item: &ShoppingListItem) -> Result<Vec<AddedItem>, DomainError> {
+        Err(DomainError::AuthenticationFailed(
+            "Invalid token".to_string(),
+        ))
+    }
+
+    async fn list_items(&self) -> Result<Vec<ShoppingListEntry>, DomainError> {
         Err(DomainError::AuthenticationFailed(
             "Invalid token".to_string(),
         ))
     }
+
+    async fn remove_item(&self, _id: &str) -> Result<(), DomainError> {
+        Ok(())
+    }
 }
 
-fn create_handler<R: ShoppingListRepository>(repo: R) -> AlexaSkillHandler<R> {
-    let service = Arc::new(AddItemService::new(Arc::new(repo)));
-    AlexaSkillHandler::new(service)
+fn create_handler(repo: impl ShoppingListRepository + 'static) -> AlexaSkillHandler {
+    let repo: Arc<dyn ShoppingListRepository> = Arc::new(repo);
+    AlexaSkillHandler::new(
+        Arc::new(AddItemService::new(repo.clone())),
+        Arc::new(ReadListService::new(repo.clone())),
+        Arc::new(RemoveItemService::new(repo)),
+    )
+    .with_phrase_variation(PhraseVariation::Seeded(0))
 }
 
 fn load_fixture(name: &str) -> AlexaRequest {
@@ -78,6 +134,17 @@ async fn help_request_returns_help_message() {
     assert!(response.response.output_speech.text.contains("hinzufügen"));
 }
 
+#[tokio::test]
+async fn session_ended_with_error_is_acknowledged_silently() {
+    let handler = create_handler(SuccessRepository);
+    let request = load_fixture("session_ended_error_request.json");
+
+    let response = handler.handle(request).await;
+
+    assert!(response.response.should_end_session);
+    assert!(response.response.output_speech.text.is_empty());
+}
+
 #[tokio::test]
 async fn stop_request_returns_goodbye() {
     let handler = create_handler(SuccessRepository);
@@ -143,6 +210,43 @@ async fn add_item_auth_error_returns_auth_message() {
     assert!(response.response.output_speech.text.contains("Anmeldung"));
 }
 
+#[tokio::test]
+async fn read_list_request_reads_items() {
+    let handler = create_handler(SuccessRepository);
+    let request = load_fixture("read_list_request.json");
+
+    let response = handler.handle(request).await;
+
+    assert!(response.response.should_end_session);
+    assert!(response
+        .response
+        .output_speech
+        .text
+        .contains("Milch und Eier"));
+}
+
+#[tokio::test]
+async fn read_list_request_on_empty_list_suggests_adding() {
+    let handler = create_handler(EmptyRepository);
+    let request = load_fixture("read_list_request.json");
+
+    let response = handler.handle(request).await;
+
+    assert!(!response.response.should_end_session);
+    assert!(response.response.output_speech.text.contains("leer"));
+}
+
+#[tokio::test]
+async fn permission_accepted_event_is_acknowledged_silently() {
+    let handler = create_handler(SuccessRepository);
+    let request = load_fixture("permission_accepted_event.json");
+
+    let response = handler.handle(request).await;
+
+    assert!(response.response.should_end_session);
+    assert!(response.response.output_speech.text.is_empty());
+}
+
 #[tokio::test]
 async fn response_version_is_correct() {
     let handler = create_handler(SuccessRepository);
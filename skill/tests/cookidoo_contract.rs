@@ -0,0 +1,183 @@
+//! Contract tests against the live Cookidoo API.
+//!
+//! These tests detect API drift (renamed fields, changed endpoints) before
+//! users report broken adds. They need real credentials and modify the
+//! account's shopping list, so they are ignored by default and additionally
+//! gated on `COOKIDOO_CONTRACT_TESTS=1`:
+//!
+//! ```bash
+//! COOKIDOO_CONTRACT_TESTS=1 cargo test --test cookidoo_contract -- --ignored
+//! ```
+//!
+//! Credentials are read from the same variables as the Lambda (see
+//! `.env.example`). Items added by the tests are removed again.
+
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde_json::Value;
+
+use alexa_cookidoo_skill::adapters::cookidoo::{
+    CookidooAuthAdapter, CookidooClient, CookidooShoppingListAdapter,
+};
+use alexa_cookidoo_skill::application::AppConfig;
+use alexa_cookidoo_skill::domain::models::ShoppingListItem;
+use alexa_cookidoo_skill::domain::ports::{AuthenticationService, ShoppingListRepository};
+
+/// Environment variable that must be `1` for the contract tests to run.
+const CONTRACT_TESTS_VAR: &str = "COOKIDOO_CONTRACT_TESTS";
+
+/// Loads the live configuration, or `None` if contract tests are disabled.
+fn live_config() -> Option<AppConfig> {
+    let _ = dotenvy::from_filename("../.env");
+    if std::env::var(CONTRACT_TESTS_VAR).as_deref() != Ok("1") {
+        eprintln!("skipping contract test: set {CONTRACT_TESTS_VAR}=1 to run against Cookidoo");
+        return None;
+    }
+    Some(AppConfig::from_env().expect("contract tests need Cookidoo credentials"))
+}
+
+fn auth_adapter(client: &CookidooClient, config: &AppConfig) -> Arc<CookidooAuthAdapter> {
+    Arc::new(CookidooAuthAdapter::new(
+        client.clone(),
+        config.cookidoo_credentials().clone(),
+        config.cookidoo_client_id(),
+        config.cookidoo_client_secret(),
+    ))
+}
+
+/// A unique item name, so concurrent runs and leftovers do not interfere.
+fn unique_item_name() -> String {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_nanos();
+    format!("Vertragstest {nanos}")
+}
+
+/// Fetches the raw list response, bypassing the adapter's lenient parsing.
+async fn raw_list(client: &CookidooClient, auth: &CookidooAuthAdapter) -> Value {
+    let token = auth.get_valid_token().await.unwrap();
+    let response = client
+        .send(
+            client
+                .inner()
+                .unwrap()
+                .get(client.url("/shopping/de-DE/additional-items"))
+                .header("Authorization", format!("Bearer {}", token)),
+        )
+        .await
+        .unwrap();
+    assert!(
+        response.status().is_success(),
+        "list: {}",
+        response.status()
+    );
+    response.json().await.unwrap()
+}
+
+#[tokio::test]
+#[ignore = "runs against the live Cookidoo API"]
+async fn auth_and_refresh_match_models() {
+    let Some(config) = live_config() else { return };
+    let client = CookidooClient::new();
+    let auth = auth_adapter(&client, &config);
+
+    let token = auth
+        .authenticate(config.cookidoo_credentials())
+        .await
+        .expect("token response no longer matches CookidooAuthResponse");
+    assert!(!token.access_token().is_empty());
+    assert!(!token.refresh_token().is_empty());
+    assert!(!token.is_expired(), "expires_in no longer in seconds");
+
+    let refreshed = auth
+        .refresh_token(token.refresh_token())
+        .await
+        .expect("refresh response no longer matches CookidooAuthResponse");
+    assert!(!refreshed.access_token().is_empty());
+}
+
+#[tokio::test]
+#[ignore = "runs against the live Cookidoo API"]
+async fn add_list_and_remove_match_models() {
+    let Some(config) = live_config() else { return };
+    let client = CookidooClient::new();
+    let auth = auth_adapter(&client, &config);
+    let adapter = CookidooShoppingListAdapter::new(client.clone(), auth.clone());
+    let name = unique_item_name();
+
+    // Add: the adapter tolerates a missing "data" array, the contract does not
+    let added = adapter
+        .add_item(&ShoppingListItem::new(&name).unwrap())
+        .await
+        .expect("add request rejected");
+    let added = added
+        .iter()
+        .find(|item| item.name() == name)
+        .expect("add response no longer reports created items under \"data\"");
+    let id = added.id().to_string();
+
+    // List: every item must carry the fields CookidooItem deserializes
+    let raw = raw_list(&client, &auth).await;
+    let items = raw["additionalItems"]
+        .as_array()
+        .expect("list response no longer has \"additionalItems\"");
+    for item in items {
+        assert!(item["id"].is_string(), "item without \"id\": {item}");
+        assert!(item["name"].is_string(), "item without \"name\": {item}");
+        assert!(
+            item["isOwned"].is_boolean(),
+            "item without \"isOwned\": {item}"
+        );
+    }
+    let entries = adapter.list_items().await.expect("list response rejected");
+    assert!(entries.iter().any(|entry| entry.id() == id));
+
+    // Remove: the item must be gone afterwards
+    adapter
+        .remove_item(&id)
+        .await
+        .expect("remove request rejected");
+    let entries = adapter.list_items().await.unwrap();
+    assert!(
+        !entries.iter().any(|entry| entry.id() == id),
+        "removed item {name} is still on the list"
+    );
+}
+
+#[tokio::test]
+#[ignore = "runs against the live Cookidoo API"]
+async fn names_at_length_limit_round_trip() {
+    let Some(config) = live_config() else { return };
+    let client = CookidooClient::new();
+    let auth = auth_adapter(&client, &config);
+    let adapter = CookidooShoppingListAdapter::new(client, auth);
+
+    // 200 user-perceived characters, with umlauts, a combining accent and
+    // an emoji sequence, the longest name the skill accepts
+    let prefix = unique_item_name();
+    let fill = ["K", "ä", "s", "e", "🥛", "e\u{0301}", "👍🏽"];
+    let mut name = format!("{prefix} ");
+    let mut length = name.chars().count();
+    for grapheme in fill.iter().cycle().take(200 - length) {
+        name.push_str(grapheme);
+        length += 1;
+    }
+    assert_eq!(length, 200);
+    let item = ShoppingListItem::new(&name).expect("name within the skill's limit");
+
+    let added = adapter
+        .add_item(&item)
+        .await
+        .expect("Cookidoo rejected a name the skill accepts");
+    let stored = added
+        .iter()
+        .find(|item| item.name().starts_with(&prefix))
+        .expect("added item missing from add response");
+    let id = stored.id().to_string();
+    let stored_name = stored.name().to_string();
+
+    adapter.remove_item(&id).await.expect("remove rejected");
+    assert_eq!(stored_name, name, "Cookidoo shortened or altered the name");
+}
@@ -1,16 +1,23 @@
 //! Integration tests for the Cookidoo adapter using wiremock.
 
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
-use wiremock::matchers::{body_string_contains, header, method, path};
+use async_trait::async_trait;
+
+use wiremock::matchers::{
+    body_json, body_string_contains, header, method, path, query_param, query_param_is_missing,
+};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
 use alexa_cookidoo_skill::adapters::cookidoo::{
-    CookidooAuthAdapter, CookidooClient, CookidooShoppingListAdapter,
+    CookidooAuthAdapter, CookidooClient, CookidooShoppingListAdapter, MaintenanceWindow, Pkce,
+    ProxyConfig, RequestMarket, RequestToken,
 };
-use alexa_cookidoo_skill::domain::models::{CookidooCredentials, ShoppingListItem};
-use alexa_cookidoo_skill::domain::ports::ShoppingListRepository;
+use alexa_cookidoo_skill::domain::models::{
+    Category, CookidooCredentials, DomainError, Market, ShoppingListItem,
+};
+use alexa_cookidoo_skill::domain::ports::{RefreshTokenStore, ShoppingListRepository};
 
 fn test_credentials() -> CookidooCredentials {
     CookidooCredentials::new("test@example.com", "testpassword")
@@ -156,9 +163,47 @@ async fn add_item_success() {
     let shopping_list = CookidooShoppingListAdapter::new(client, auth);
 
     let item = ShoppingListItem::new("Milk").unwrap();
-    let result = shopping_list.add_item(&item).await;
+    let added = shopping_list.add_item(&item).await.unwrap();
 
-    assert!(result.is_ok());
+    assert_eq!(added.len(), 1);
+    assert_eq!(added[0].id(), "test-item-id");
+    assert_eq!(added[0].name(), "Milk");
+}
+
+#[tokio::test]
+async fn add_item_writes_note_into_item_name() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/ciam/auth/token"))
+        .respond_with(auth_success_response())
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/shopping/de-DE/additional-items/add"))
+        .and(body_json(serde_json::json!({
+            "itemsValue": ["Milk (fettarm)"]
+        })))
+        .respond_with(add_item_success_response())
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = CookidooClient::with_base_url(mock_server.uri());
+    let auth = Arc::new(CookidooAuthAdapter::new(
+        client.clone(),
+        test_credentials(),
+        test_client_id(),
+        test_client_secret(),
+    ));
+    let shopping_list = CookidooShoppingListAdapter::new(client, auth);
+
+    let item = ShoppingListItem::new("Milk")
+        .unwrap()
+        .with_note("fettarm")
+        .unwrap();
+    shopping_list.add_item(&item).await.unwrap();
 }
 
 #[tokio::test]
@@ -213,9 +258,11 @@ async fn add_item_fails_on_server_error() {
         .mount(&mock_server)
         .await;
 
+    // A failed add may still have reached the list, so it is not repeated
     Mock::given(method("POST"))
         .and(path("/shopping/de-DE/additional-items/add"))
         .respond_with(ResponseTemplate::new(500).set_body_string("Internal Server Error"))
+        .expect(1)
         .mount(&mock_server)
         .await;
 
@@ -231,6 +278,143 @@ async fn add_item_fails_on_server_error() {
     let item = ShoppingListItem::new("Milk").unwrap();
     let result = shopping_list.add_item(&item).await;
 
+    assert!(matches!(result, Err(DomainError::Unreachable(_))));
+}
+
+#[tokio::test]
+async fn add_item_fails_fast_on_client_error() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/ciam/auth/token"))
+        .respond_with(auth_success_response())
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/shopping/de-DE/additional-items/add"))
+        .respond_with(ResponseTemplate::new(400).set_body_string("Bad Request"))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = CookidooClient::with_base_url(mock_server.uri());
+    let auth = Arc::new(CookidooAuthAdapter::new(
+        client.clone(),
+        test_credentials(),
+        test_client_id(),
+        test_client_secret(),
+    ));
+    let shopping_list = CookidooShoppingListAdapter::new(client, auth);
+
+    let item = ShoppingListItem::new("Milk").unwrap();
+    let result = shopping_list.add_item(&item).await;
+
+    assert!(result.is_err());
+    assert!(!matches!(result, Err(DomainError::Unreachable(_))));
+}
+
+#[tokio::test]
+async fn list_items_retries_server_error() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/ciam/auth/token"))
+        .respond_with(auth_success_response())
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/shopping/de-DE/additional-items"))
+        .respond_with(ResponseTemplate::new(502))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/shopping/de-DE/additional-items"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "additionalItems": [{"id": "id-1", "name": "Milch", "isOwned": false}]
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = CookidooClient::with_base_url(mock_server.uri());
+    let auth = Arc::new(CookidooAuthAdapter::new(
+        client.clone(),
+        test_credentials(),
+        test_client_id(),
+        test_client_secret(),
+    ));
+    let shopping_list = CookidooShoppingListAdapter::new(client, auth);
+
+    let entries = shopping_list.list_items().await.unwrap();
+
+    assert_eq!(entries.len(), 1);
+}
+
+#[tokio::test]
+async fn list_items_gives_up_after_repeated_server_errors() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/ciam/auth/token"))
+        .respond_with(auth_success_response())
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/shopping/de-DE/additional-items"))
+        .respond_with(ResponseTemplate::new(503))
+        .expect(3)
+        .mount(&mock_server)
+        .await;
+
+    let client = CookidooClient::with_base_url(mock_server.uri());
+    let auth = Arc::new(CookidooAuthAdapter::new(
+        client.clone(),
+        test_credentials(),
+        test_client_id(),
+        test_client_secret(),
+    ));
+    let shopping_list = CookidooShoppingListAdapter::new(client, auth);
+
+    let result = shopping_list.list_items().await;
+
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn slow_server_error_is_not_retried_past_budget() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/ciam/auth/token"))
+        .respond_with(auth_success_response())
+        .mount(&mock_server)
+        .await;
+
+    // Leaves less of the retry budget than the first backoff
+    Mock::given(method("GET"))
+        .and(path("/shopping/de-DE/additional-items"))
+        .respond_with(ResponseTemplate::new(503).set_delay(Duration::from_millis(3900)))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = CookidooClient::with_base_url(mock_server.uri());
+    let auth = Arc::new(CookidooAuthAdapter::new(
+        client.clone(),
+        test_credentials(),
+        test_client_id(),
+        test_client_secret(),
+    ));
+    let shopping_list = CookidooShoppingListAdapter::new(client, auth);
+
+    let result = shopping_list.list_items().await;
+
     assert!(result.is_err());
 }
 
@@ -284,3 +468,1177 @@ async fn token_refresh_on_expiry() {
     let token2 = auth.get_valid_token().await.unwrap();
     assert_eq!(token2, "refreshed-token");
 }
+
+#[tokio::test]
+async fn background_refresh_replaces_expiring_token() {
+    let mock_server = MockServer::start().await;
+
+    // Initial auth with a token inside the refresh buffer
+    Mock::given(method("POST"))
+        .and(path("/ciam/auth/token"))
+        .and(body_string_contains("grant_type=password"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "access_token": "expiring-token",
+            "refresh_token": "test-refresh-token",
+            "expires_in": 60,
+            "token_type": "Bearer"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/ciam/auth/token"))
+        .and(body_string_contains("grant_type=refresh_token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "access_token": "refreshed-token",
+            "refresh_token": "new-refresh-token",
+            "expires_in": 3600,
+            "token_type": "Bearer"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = CookidooClient::with_base_url(mock_server.uri());
+    let auth = Arc::new(CookidooAuthAdapter::new(
+        client,
+        test_credentials(),
+        test_client_id(),
+        test_client_secret(),
+    ));
+    assert_eq!(auth.get_valid_token().await.unwrap(), "expiring-token");
+
+    let refresh = auth.spawn_refresh().expect("refresh should be spawned");
+    // Only one refresh runs at a time
+    assert!(auth.spawn_refresh().is_none());
+    refresh.await.unwrap();
+
+    assert_eq!(auth.get_valid_token().await.unwrap(), "refreshed-token");
+    // A fresh token needs no refresh
+    assert!(auth.spawn_refresh().is_none());
+}
+
+#[tokio::test]
+async fn add_item_maps_maintenance_response() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/ciam/auth/token"))
+        .respond_with(auth_success_response())
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/shopping/de-DE/additional-items/add"))
+        .respond_with(ResponseTemplate::new(503).set_body_json(serde_json::json!({
+            "code": "maintenance",
+            "message": "Scheduled maintenance",
+            "traceId": "trace-123"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = CookidooClient::with_base_url(mock_server.uri());
+    let auth = Arc::new(CookidooAuthAdapter::new(
+        client.clone(),
+        test_credentials(),
+        test_client_id(),
+        test_client_secret(),
+    ));
+    let shopping_list = CookidooShoppingListAdapter::new(client, auth);
+
+    let item = ShoppingListItem::new("Milk").unwrap();
+    let result = shopping_list.add_item(&item).await;
+
+    assert!(matches!(result, Err(DomainError::ServiceUnavailable(_))));
+}
+
+#[tokio::test]
+async fn maintenance_response_short_circuits_following_requests() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/ciam/auth/token"))
+        .respond_with(auth_success_response())
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/shopping/de-DE/additional-items/add"))
+        .respond_with(ResponseTemplate::new(503).set_body_json(serde_json::json!({
+            "code": "maintenance",
+            "message": "Scheduled maintenance"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/shopping/de-DE/additional-items"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "additionalItems": []
+        })))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let client = CookidooClient::with_base_url(mock_server.uri());
+    let auth = Arc::new(CookidooAuthAdapter::new(
+        client.clone(),
+        test_credentials(),
+        test_client_id(),
+        test_client_secret(),
+    ));
+    let shopping_list = CookidooShoppingListAdapter::new(client, auth)
+        .with_maintenance_window(Arc::new(MaintenanceWindow::new()));
+
+    let item = ShoppingListItem::new("Milk").unwrap();
+    let first = shopping_list.add_item(&item).await;
+    let second = shopping_list.add_item(&item).await;
+    let list = shopping_list.list_items().await;
+
+    assert!(matches!(first, Err(DomainError::ServiceUnavailable(_))));
+    assert!(matches!(second, Err(DomainError::ServiceUnavailable(_))));
+    assert!(matches!(list, Err(DomainError::ServiceUnavailable(_))));
+}
+
+#[tokio::test]
+async fn add_item_retries_after_rate_limit() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/ciam/auth/token"))
+        .respond_with(auth_success_response())
+        .mount(&mock_server)
+        .await;
+
+    // First add item call is rate limited with an immediate retry
+    Mock::given(method("POST"))
+        .and(path("/shopping/de-DE/additional-items/add"))
+        .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "0"))
+        .up_to_n_times(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/shopping/de-DE/additional-items/add"))
+        .respond_with(add_item_success_response())
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = CookidooClient::with_base_url(mock_server.uri());
+    let auth = Arc::new(CookidooAuthAdapter::new(
+        client.clone(),
+        test_credentials(),
+        test_client_id(),
+        test_client_secret(),
+    ));
+    let shopping_list = CookidooShoppingListAdapter::new(client, auth);
+
+    let item = ShoppingListItem::new("Milk").unwrap();
+    let result = shopping_list.add_item(&item).await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn add_item_gives_up_when_retry_after_exceeds_budget() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/ciam/auth/token"))
+        .respond_with(auth_success_response())
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/shopping/de-DE/additional-items/add"))
+        .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "120"))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = CookidooClient::with_base_url(mock_server.uri());
+    let auth = Arc::new(CookidooAuthAdapter::new(
+        client.clone(),
+        test_credentials(),
+        test_client_id(),
+        test_client_secret(),
+    ));
+    let shopping_list = CookidooShoppingListAdapter::new(client, auth);
+
+    let item = ShoppingListItem::new("Milk").unwrap();
+    let result = shopping_list.add_item(&item).await;
+
+    assert!(matches!(result, Err(DomainError::RateLimited(_))));
+}
+
+#[tokio::test]
+async fn list_items_success() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/ciam/auth/token"))
+        .respond_with(auth_success_response())
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/shopping/de-DE/additional-items"))
+        .and(header("Authorization", "Bearer test-access-token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "additionalItems": [
+                {"id": "id-1", "name": "Milch", "isOwned": false},
+                {"id": "id-2", "name": "Eier", "isOwned": true}
+            ]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = CookidooClient::with_base_url(mock_server.uri());
+    let auth = Arc::new(CookidooAuthAdapter::new(
+        client.clone(),
+        test_credentials(),
+        test_client_id(),
+        test_client_secret(),
+    ));
+    let shopping_list = CookidooShoppingListAdapter::new(client, auth);
+
+    let entries = shopping_list.list_items().await.unwrap();
+
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].id(), "id-1");
+    assert_eq!(entries[0].name(), "Milch");
+    assert!(entries[1].is_owned());
+}
+
+#[tokio::test]
+async fn list_items_follows_pages() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/ciam/auth/token"))
+        .respond_with(auth_success_response())
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/shopping/de-DE/additional-items"))
+        .and(query_param_is_missing("page"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "additionalItems": [{"id": "id-1", "name": "Milch", "isOwned": false}],
+            "nextPage": 2
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/shopping/de-DE/additional-items"))
+        .and(query_param("page", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "additionalItems": [{"id": "id-2", "name": "Eier", "isOwned": false}]
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = CookidooClient::with_base_url(mock_server.uri());
+    let auth = Arc::new(CookidooAuthAdapter::new(
+        client.clone(),
+        test_credentials(),
+        test_client_id(),
+        test_client_secret(),
+    ));
+    let shopping_list = CookidooShoppingListAdapter::new(client, auth);
+
+    let entries = shopping_list.list_items().await.unwrap();
+
+    let names: Vec<&str> = entries.iter().map(|entry| entry.name()).collect();
+    assert_eq!(names, ["Milch", "Eier"]);
+}
+
+#[tokio::test]
+async fn list_items_stops_when_page_repeats() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/ciam/auth/token"))
+        .respond_with(auth_success_response())
+        .mount(&mock_server)
+        .await;
+
+    // A list that always claims another page
+    Mock::given(method("GET"))
+        .and(path("/shopping/de-DE/additional-items"))
+        .and(query_param_is_missing("page"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "additionalItems": [{"id": "id-1", "name": "Milch", "isOwned": false}],
+            "nextPage": 2
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/shopping/de-DE/additional-items"))
+        .and(query_param("page", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "additionalItems": [{"id": "id-2", "name": "Eier", "isOwned": false}],
+            "nextPage": 2
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = CookidooClient::with_base_url(mock_server.uri());
+    let auth = Arc::new(CookidooAuthAdapter::new(
+        client.clone(),
+        test_credentials(),
+        test_client_id(),
+        test_client_secret(),
+    ));
+    let shopping_list = CookidooShoppingListAdapter::new(client, auth);
+
+    let entries = shopping_list.list_items().await.unwrap();
+
+    let names: Vec<&str> = entries.iter().map(|entry| entry.name()).collect();
+    assert_eq!(names, ["Milch", "Eier"]);
+}
+
+#[tokio::test]
+async fn list_items_stops_when_page_brings_nothing_new() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/ciam/auth/token"))
+        .respond_with(auth_success_response())
+        .mount(&mock_server)
+        .await;
+
+    // Every page repeats the first one
+    Mock::given(method("GET"))
+        .and(path("/shopping/de-DE/additional-items"))
+        .and(query_param_is_missing("page"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "additionalItems": [{"id": "id-1", "name": "Milch", "isOwned": false}],
+            "nextPage": 2
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/shopping/de-DE/additional-items"))
+        .and(query_param("page", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "additionalItems": [{"id": "id-1", "name": "Milch", "isOwned": false}],
+            "nextPage": 3
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = CookidooClient::with_base_url(mock_server.uri());
+    let auth = Arc::new(CookidooAuthAdapter::new(
+        client.clone(),
+        test_credentials(),
+        test_client_id(),
+        test_client_secret(),
+    ));
+    let shopping_list = CookidooShoppingListAdapter::new(client, auth);
+
+    let entries = shopping_list.list_items().await.unwrap();
+
+    assert_eq!(entries.len(), 1);
+}
+
+#[tokio::test]
+async fn list_is_reused_until_the_next_write() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/ciam/auth/token"))
+        .respond_with(auth_success_response())
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/shopping/de-DE/additional-items"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "additionalItems": [{"id": "id-1", "name": "Milch", "isOwned": false}]
+        })))
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/shopping/de-DE/additional-items/remove"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&mock_server)
+        .await;
+
+    let client = CookidooClient::with_base_url(mock_server.uri());
+    let auth = Arc::new(CookidooAuthAdapter::new(
+        client.clone(),
+        test_credentials(),
+        test_client_id(),
+        test_client_secret(),
+    ));
+    let shopping_list = CookidooShoppingListAdapter::new(client, auth);
+
+    // Two turns reading the list share one fetch; the removal invalidates it
+    shopping_list.list_items().await.unwrap();
+    shopping_list.list_items().await.unwrap();
+    shopping_list.remove_item("id-1").await.unwrap();
+    shopping_list.list_items().await.unwrap();
+}
+
+#[tokio::test]
+async fn cached_list_is_not_shared_between_linked_accounts() {
+    let mock_server = MockServer::start().await;
+
+    for (token, name) in [("token-a", "Milch"), ("token-b", "Eier")] {
+        Mock::given(method("GET"))
+            .and(path("/shopping/de-DE/additional-items"))
+            .and(header(
+                "Authorization",
+                format!("Bearer {}", token).as_str(),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "additionalItems": [{"id": "id-1", "name": name, "isOwned": false}]
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+    }
+
+    let client = CookidooClient::with_base_url(mock_server.uri());
+    let auth = Arc::new(CookidooAuthAdapter::new(
+        client.clone(),
+        test_credentials(),
+        test_client_id(),
+        test_client_secret(),
+    ));
+    let shopping_list = CookidooShoppingListAdapter::new(client, auth);
+
+    let first = RequestToken::scope(Some("token-a".to_string()), shopping_list.list_items())
+        .await
+        .unwrap();
+    let second = RequestToken::scope(Some("token-b".to_string()), shopping_list.list_items())
+        .await
+        .unwrap();
+
+    assert_eq!(first[0].name(), "Milch");
+    assert_eq!(second[0].name(), "Eier");
+}
+
+#[tokio::test]
+async fn concurrent_list_fetches_are_coalesced() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/ciam/auth/token"))
+        .respond_with(auth_success_response())
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/shopping/de-DE/additional-items"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!({
+                    "additionalItems": [{"id": "id-1", "name": "Milch", "isOwned": false}]
+                }))
+                .set_delay(Duration::from_millis(200)),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = CookidooClient::with_base_url(mock_server.uri());
+    let auth = Arc::new(CookidooAuthAdapter::new(
+        client.clone(),
+        test_credentials(),
+        test_client_id(),
+        test_client_secret(),
+    ));
+    // Authenticate up front so all fetches start at the same time
+    auth.get_valid_token().await.unwrap();
+    let shopping_list = CookidooShoppingListAdapter::new(client, auth);
+
+    let (a, b, c) = tokio::join!(
+        shopping_list.list_items(),
+        shopping_list.list_items(),
+        shopping_list.list_items(),
+    );
+
+    assert_eq!(a.unwrap().len(), 1);
+    assert_eq!(b.unwrap().len(), 1);
+    assert_eq!(c.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn remove_item_posts_item_id() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/ciam/auth/token"))
+        .respond_with(auth_success_response())
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/shopping/de-DE/additional-items/remove"))
+        .and(header("Authorization", "Bearer test-access-token"))
+        .and(body_string_contains(r#""additionalItemIds":["id-1"]"#))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = CookidooClient::with_base_url(mock_server.uri());
+    let auth = Arc::new(CookidooAuthAdapter::new(
+        client.clone(),
+        test_credentials(),
+        test_client_id(),
+        test_client_secret(),
+    ));
+    let shopping_list = CookidooShoppingListAdapter::new(client, auth);
+
+    let result = shopping_list.remove_item("id-1").await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn mark_owned_edits_item_ownership() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/ciam/auth/token"))
+        .respond_with(auth_success_response())
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/shopping/de-DE/additional-items/ownership/edit"))
+        .and(header("Authorization", "Bearer test-access-token"))
+        .and(body_string_contains(r#"{"id":"id-1","isOwned":true"#))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = CookidooClient::with_base_url(mock_server.uri());
+    let auth = Arc::new(CookidooAuthAdapter::new(
+        client.clone(),
+        test_credentials(),
+        test_client_id(),
+        test_client_secret(),
+    ));
+    let shopping_list = CookidooShoppingListAdapter::new(client, auth);
+
+    let result = shopping_list.mark_owned("id-1").await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn add_item_sends_category() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/ciam/auth/token"))
+        .respond_with(auth_success_response())
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/shopping/de-DE/additional-items/add"))
+        .and(body_string_contains(r#""category":"Milchprodukte""#))
+        .respond_with(add_item_success_response())
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = CookidooClient::with_base_url(mock_server.uri());
+    let auth = Arc::new(CookidooAuthAdapter::new(
+        client.clone(),
+        test_credentials(),
+        test_client_id(),
+        test_client_secret(),
+    ));
+    let shopping_list = CookidooShoppingListAdapter::new(client, auth);
+
+    let item = ShoppingListItem::new("Milch")
+        .unwrap()
+        .with_category(Category::Dairy);
+    let result = shopping_list.add_item(&item).await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn request_token_skips_password_grant() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/ciam/auth/token"))
+        .respond_with(auth_success_response())
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/shopping/de-DE/additional-items/add"))
+        .and(header("Authorization", "Bearer linked-token"))
+        .respond_with(add_item_success_response())
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = CookidooClient::with_base_url(mock_server.uri());
+    let auth = Arc::new(CookidooAuthAdapter::new(
+        client.clone(),
+        test_credentials(),
+        test_client_id(),
+        test_client_secret(),
+    ));
+    let shopping_list = CookidooShoppingListAdapter::new(client, auth);
+
+    let item = ShoppingListItem::new("Milk").unwrap();
+    let result = RequestToken::scope(
+        Some("linked-token".to_string()),
+        shopping_list.add_item(&item),
+    )
+    .await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn rejected_request_token_is_not_retried() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/ciam/auth/token"))
+        .respond_with(auth_success_response())
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/shopping/de-DE/additional-items/add"))
+        .respond_with(ResponseTemplate::new(401))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = CookidooClient::with_base_url(mock_server.uri());
+    let auth = Arc::new(CookidooAuthAdapter::new(
+        client.clone(),
+        test_credentials(),
+        test_client_id(),
+        test_client_secret(),
+    ));
+    let shopping_list = CookidooShoppingListAdapter::new(client, auth);
+
+    let item = ShoppingListItem::new("Milk").unwrap();
+    let result = RequestToken::scope(
+        Some("expired-token".to_string()),
+        shopping_list.add_item(&item),
+    )
+    .await;
+
+    assert!(matches!(result, Err(DomainError::AuthenticationFailed(_))));
+}
+
+#[tokio::test]
+async fn provisioned_refresh_token_skips_password_grant() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/ciam/auth/token"))
+        .and(body_string_contains("grant_type=refresh_token"))
+        .and(body_string_contains("refresh_token=provisioned-token"))
+        .respond_with(auth_success_response())
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/ciam/auth/token"))
+        .and(body_string_contains("grant_type=password"))
+        .respond_with(auth_success_response())
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let client = CookidooClient::with_base_url(mock_server.uri());
+    let auth = CookidooAuthAdapter::new(
+        client,
+        CookidooCredentials::new("", ""),
+        test_client_id(),
+        test_client_secret(),
+    )
+    .with_refresh_token("provisioned-token")
+    .with_password_grant(false);
+
+    assert_eq!(auth.get_valid_token().await.unwrap(), "test-access-token");
+    // The second call is served from the cache
+    assert_eq!(auth.get_valid_token().await.unwrap(), "test-access-token");
+}
+
+/// Refresh token store remembering every saved token.
+#[derive(Default)]
+struct RecordingTokenStore {
+    saved: Mutex<Vec<String>>,
+}
+
+#[async_trait]
+impl RefreshTokenStore for RecordingTokenStore {
+    async fn load(&self) -> Result<Option<String>, DomainError> {
+        Ok(self.saved.lock().unwrap().last().cloned())
+    }
+
+    async fn save(&self, refresh_token: &str) -> Result<(), DomainError> {
+        self.saved.lock().unwrap().push(refresh_token.to_string());
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn rotated_refresh_token_is_stored() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/ciam/auth/token"))
+        .and(body_string_contains("refresh_token=provisioned-token"))
+        .respond_with(auth_success_response())
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let store = Arc::new(RecordingTokenStore::default());
+    let client = CookidooClient::with_base_url(mock_server.uri());
+    let auth = CookidooAuthAdapter::new(
+        client,
+        CookidooCredentials::new("", ""),
+        test_client_id(),
+        test_client_secret(),
+    )
+    .with_refresh_token("provisioned-token")
+    .with_refresh_token_store(store.clone())
+    .with_password_grant(false);
+
+    assert_eq!(auth.get_valid_token().await.unwrap(), "test-access-token");
+    assert_eq!(
+        *store.saved.lock().unwrap(),
+        vec!["test-refresh-token".to_string()]
+    );
+}
+
+#[tokio::test]
+async fn rejected_refresh_token_fails_without_password_grant() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/ciam/auth/token"))
+        .and(body_string_contains("grant_type=refresh_token"))
+        .respond_with(ResponseTemplate::new(400))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/ciam/auth/token"))
+        .and(body_string_contains("grant_type=password"))
+        .respond_with(auth_success_response())
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let client = CookidooClient::with_base_url(mock_server.uri());
+    let auth = CookidooAuthAdapter::new(
+        client,
+        test_credentials(),
+        test_client_id(),
+        test_client_secret(),
+    )
+    .with_refresh_token("revoked-token")
+    .with_password_grant(false);
+
+    assert!(auth.get_valid_token().await.is_err());
+}
+
+#[tokio::test]
+async fn rejected_refresh_token_falls_back_to_password_grant() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/ciam/auth/token"))
+        .and(body_string_contains("grant_type=refresh_token"))
+        .respond_with(ResponseTemplate::new(400))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/ciam/auth/token"))
+        .and(body_string_contains("grant_type=password"))
+        .respond_with(auth_success_response())
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = CookidooClient::with_base_url(mock_server.uri());
+    let auth = CookidooAuthAdapter::new(
+        client,
+        test_credentials(),
+        test_client_id(),
+        test_client_secret(),
+    )
+    .with_refresh_token("revoked-token");
+
+    assert_eq!(auth.get_valid_token().await.unwrap(), "test-access-token");
+}
+
+#[tokio::test]
+async fn exchanges_authorization_code_with_pkce_verifier() {
+    let mock_server = MockServer::start().await;
+    let pkce = Pkce::from_verifier("dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk");
+
+    Mock::given(method("POST"))
+        .and(path("/ciam/auth/token"))
+        .and(body_string_contains("grant_type=authorization_code"))
+        .and(body_string_contains("code=auth-code"))
+        .and(body_string_contains(
+            "code_verifier=dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk",
+        ))
+        .respond_with(auth_success_response())
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = CookidooClient::with_base_url(mock_server.uri());
+    let auth = CookidooAuthAdapter::new(
+        client,
+        CookidooCredentials::new("", ""),
+        test_client_id(),
+        test_client_secret(),
+    );
+
+    let url = auth.authorization_url("http://localhost:8765/callback", &pkce, "state-1");
+    assert!(url.starts_with(&format!("{}/ciam/auth/authorize?", mock_server.uri())));
+    assert!(url.contains("code_challenge=E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM"));
+    assert!(url.contains("code_challenge_method=S256"));
+
+    let token = auth
+        .exchange_authorization_code("auth-code", "http://localhost:8765/callback", &pkce)
+        .await
+        .unwrap();
+    assert_eq!(token.refresh_token(), "test-refresh-token");
+}
+
+#[tokio::test]
+async fn add_item_uses_market_of_request() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/ciam/auth/token"))
+        .respond_with(auth_success_response())
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/shopping/de-AT/additional-items/add"))
+        .respond_with(add_item_success_response())
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = CookidooClient::with_base_url(mock_server.uri());
+    let auth = Arc::new(CookidooAuthAdapter::new(
+        client.clone(),
+        test_credentials(),
+        test_client_id(),
+        test_client_secret(),
+    ));
+    let shopping_list = CookidooShoppingListAdapter::new(client, auth);
+
+    let item = ShoppingListItem::new("Milk").unwrap();
+    let austria = Market::from_country_code("AT").unwrap();
+    let result = RequestMarket::scope(austria, shopping_list.add_item(&item)).await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn add_item_uses_configured_paths() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/proxy/token"))
+        .respond_with(auth_success_response())
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/proxy/shopping/de-DE/add"))
+        .respond_with(add_item_success_response())
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = CookidooClient::with_base_url(mock_server.uri());
+    let auth = Arc::new(
+        CookidooAuthAdapter::new(
+            client.clone(),
+            test_credentials(),
+            test_client_id(),
+            test_client_secret(),
+        )
+        .with_token_path("/proxy/token"),
+    );
+    let shopping_list = CookidooShoppingListAdapter::new(client, auth)
+        .with_shopping_path("/proxy/shopping/{language}");
+
+    let item = ShoppingListItem::new("Milk").unwrap();
+    let result = shopping_list.add_item(&item).await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn requests_go_through_configured_proxy() {
+    let proxy = MockServer::start().await;
+
+    // The proxy receives the absolute-form requests for the Cookidoo host
+    Mock::given(method("POST"))
+        .and(path("/ciam/auth/token"))
+        .and(header(
+            "Proxy-Authorization",
+            "Basic c2tpbGw6cHJveHktc2VjcmV0",
+        ))
+        .respond_with(auth_success_response())
+        .expect(1)
+        .mount(&proxy)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/shopping/de-DE/additional-items/add"))
+        .and(header(
+            "Proxy-Authorization",
+            "Basic c2tpbGw6cHJveHktc2VjcmV0",
+        ))
+        .respond_with(add_item_success_response())
+        .expect(1)
+        .mount(&proxy)
+        .await;
+
+    let client = CookidooClient::with_base_url("http://cookidoo.invalid")
+        .with_proxy(&ProxyConfig::new(proxy.uri()).with_basic_auth("skill", "proxy-secret"))
+        .unwrap();
+    let auth = Arc::new(CookidooAuthAdapter::new(
+        client.clone(),
+        test_credentials(),
+        test_client_id(),
+        test_client_secret(),
+    ));
+    let shopping_list = CookidooShoppingListAdapter::new(client, auth);
+
+    let item = ShoppingListItem::new("Milk").unwrap();
+    let result = shopping_list.add_item(&item).await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn warm_up_fetches_token_once() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/ciam/auth/token"))
+        .respond_with(auth_success_response())
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/shopping/de-DE/additional-items/add"))
+        .and(header("Authorization", "Bearer test-access-token"))
+        .respond_with(add_item_success_response())
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = CookidooClient::with_base_url(mock_server.uri());
+    let auth = Arc::new(CookidooAuthAdapter::new(
+        client.clone(),
+        test_credentials(),
+        test_client_id(),
+        test_client_secret(),
+    ));
+    let shopping_list = CookidooShoppingListAdapter::new(client, auth);
+
+    shopping_list.warm_up().await.unwrap();
+    // The user request reuses the token fetched by the warm-up
+    let item = ShoppingListItem::new("Milk").unwrap();
+    assert!(shopping_list.add_item(&item).await.is_ok());
+}
+
+#[tokio::test]
+async fn pathological_names_reach_cookidoo_as_clean_json() {
+    let cases = [
+        ("Milch\"}],\"category\":\"x", "Milch\"}],\"category\":\"x"),
+        ("Eier\\\n\u{0}\u{1b}[2J", "Eier\\ [2J"),
+        ("\u{feff}Ha\u{200b}fer\u{00ad}milch\u{202e}", "Hafermilch"),
+        ("Brot\r\n\tmit  Körnern", "Brot mit Körnern"),
+    ];
+
+    for (spoken, stored) in cases {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/ciam/auth/token"))
+            .respond_with(auth_success_response())
+            .mount(&mock_server)
+            .await;
+
+        // Matches only if the body parses as JSON with exactly this value
+        Mock::given(method("POST"))
+            .and(path("/shopping/de-DE/additional-items/add"))
+            .and(body_json(serde_json::json!({ "itemsValue": [stored] })))
+            .respond_with(add_item_success_response())
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = CookidooClient::with_base_url(mock_server.uri());
+        let auth = Arc::new(CookidooAuthAdapter::new(
+            client.clone(),
+            test_credentials(),
+            test_client_id(),
+            test_client_secret(),
+        ));
+        let shopping_list = CookidooShoppingListAdapter::new(client, auth);
+
+        let item = ShoppingListItem::new(spoken).unwrap();
+        assert_eq!(item.name(), stored);
+        assert!(shopping_list.add_item(&item).await.is_ok(), "{:?}", spoken);
+    }
+}
+
+#[tokio::test]
+async fn add_items_sends_one_request_per_category() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/ciam/auth/token"))
+        .respond_with(auth_success_response())
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/shopping/de-DE/additional-items/add"))
+        .and(body_json(serde_json::json!({
+            "itemsValue": ["Milch", "Butter"],
+            "category": "Milchprodukte"
+        })))
+        .respond_with(add_item_success_response())
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/shopping/de-DE/additional-items/add"))
+        .and(body_json(
+            serde_json::json!({ "itemsValue": ["Servietten"] }),
+        ))
+        .respond_with(add_item_success_response())
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = CookidooClient::with_base_url(mock_server.uri());
+    let auth = Arc::new(CookidooAuthAdapter::new(
+        client.clone(),
+        test_credentials(),
+        test_client_id(),
+        test_client_secret(),
+    ));
+    let shopping_list = CookidooShoppingListAdapter::new(client, auth);
+
+    let dairy = |name| {
+        ShoppingListItem::new(name)
+            .unwrap()
+            .with_category(Category::Dairy)
+    };
+    let items = [
+        dairy("Milch"),
+        ShoppingListItem::new("Servietten").unwrap(),
+        dairy("Butter"),
+    ];
+    let results = shopping_list.add_items(&items).await;
+
+    assert!(results.iter().all(Result::is_ok), "{:?}", results);
+}
+
+#[tokio::test]
+async fn add_items_reports_groups_added_before_a_failed_request() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/ciam/auth/token"))
+        .respond_with(auth_success_response())
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/shopping/de-DE/additional-items/add"))
+        .and(body_json(serde_json::json!({
+            "itemsValue": ["Milch", "Butter"],
+            "category": "Milchprodukte"
+        })))
+        .respond_with(add_item_success_response())
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/shopping/de-DE/additional-items/add"))
+        .and(body_json(
+            serde_json::json!({ "itemsValue": ["Servietten"] }),
+        ))
+        .respond_with(ResponseTemplate::new(400).set_body_string("Bad request"))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = CookidooClient::with_base_url(mock_server.uri());
+    let auth = Arc::new(CookidooAuthAdapter::new(
+        client.clone(),
+        test_credentials(),
+        test_client_id(),
+        test_client_secret(),
+    ));
+    let shopping_list = CookidooShoppingListAdapter::new(client, auth);
+
+    let dairy = |name| {
+        ShoppingListItem::new(name)
+            .unwrap()
+            .with_category(Category::Dairy)
+    };
+    let items = [
+        dairy("Milch"),
+        ShoppingListItem::new("Servietten").unwrap(),
+        dairy("Butter"),
+    ];
+    let results = shopping_list.add_items(&items).await;
+
+    assert!(results[0].is_ok());
+    assert!(results[1].is_err());
+    assert!(results[2].is_ok());
+}
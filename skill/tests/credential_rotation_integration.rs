@@ -0,0 +1,156 @@
+//! Integration tests for reloading rotated Cookidoo credentials using
+//! wiremock.
+
+use std::sync::Arc;
+
+use wiremock::matchers::{body_string_contains, header, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use alexa_cookidoo_skill::adapters::cookidoo::{CookidooAuthAdapter, CookidooClient};
+use alexa_cookidoo_skill::adapters::parameters::SecretCredentialStore;
+use alexa_cookidoo_skill::domain::models::CookidooCredentials;
+use alexa_cookidoo_skill::domain::ports::CredentialStore;
+
+const SECRET_ID: &str = "cookidoo-skill/credentials";
+
+async fn mock_secret(server: &MockServer, password: &str, version: &str) {
+    Mock::given(method("GET"))
+        .and(path("/secretsmanager/get"))
+        .and(query_param("secretId", SECRET_ID))
+        .and(header("X-Aws-Parameters-Secrets-Token", "session-token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "Name": SECRET_ID,
+            "SecretString": serde_json::json!({
+                "email": "test@example.com",
+                "password": password
+            })
+            .to_string(),
+            "VersionId": version
+        })))
+        .mount(server)
+        .await;
+}
+
+async fn mock_password_grant(server: &MockServer, password: &str, response: ResponseTemplate) {
+    Mock::given(method("POST"))
+        .and(path("/ciam/auth/token"))
+        .and(body_string_contains(format!("password={}", password)))
+        .respond_with(response)
+        .mount(server)
+        .await;
+}
+
+fn invalid_grant() -> ResponseTemplate {
+    ResponseTemplate::new(400).set_body_json(serde_json::json!({
+        "error": "invalid_grant",
+        "error_description": "Invalid credentials"
+    }))
+}
+
+fn token_response() -> ResponseTemplate {
+    ResponseTemplate::new(200).set_body_json(serde_json::json!({
+        "access_token": "rotated-access-token",
+        "refresh_token": "rotated-refresh-token",
+        "expires_in": 3600
+    }))
+}
+
+fn store(server: &MockServer) -> Arc<SecretCredentialStore> {
+    Arc::new(SecretCredentialStore::with_endpoint(
+        server.uri(),
+        SECRET_ID,
+        "session-token",
+    ))
+}
+
+fn adapter(server: &MockServer) -> CookidooAuthAdapter {
+    CookidooAuthAdapter::new(
+        CookidooClient::with_base_url(server.uri()),
+        CookidooCredentials::new("test@example.com", "old-password"),
+        "client_id",
+        "client_secret",
+    )
+}
+
+#[tokio::test]
+async fn reads_credentials_with_version() {
+    let server = MockServer::start().await;
+    mock_secret(&server, "new-password", "v2").await;
+
+    let stored = store(&server).current().await.unwrap();
+
+    assert_eq!(stored.credentials().email(), "test@example.com");
+    assert_eq!(stored.credentials().password(), "new-password");
+    assert_eq!(stored.version(), "v2");
+}
+
+#[tokio::test]
+async fn secret_without_credentials_is_an_error() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/secretsmanager/get"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "SecretString": "not json",
+            "VersionId": "v1"
+        })))
+        .mount(&server)
+        .await;
+
+    assert!(store(&server).current().await.is_err());
+}
+
+#[tokio::test]
+async fn rejected_password_is_retried_with_rotated_secret() {
+    let server = MockServer::start().await;
+    mock_secret(&server, "new-password", "v2").await;
+    mock_password_grant(&server, "old-password", invalid_grant()).await;
+    mock_password_grant(&server, "new-password", token_response()).await;
+
+    let auth = adapter(&server).with_credential_store(store(&server));
+
+    assert_eq!(
+        auth.get_valid_token().await.unwrap(),
+        "rotated-access-token"
+    );
+}
+
+#[tokio::test]
+async fn unchanged_secret_is_not_retried() {
+    let server = MockServer::start().await;
+    mock_secret(&server, "old-password", "v1").await;
+    Mock::given(method("POST"))
+        .and(path("/ciam/auth/token"))
+        .respond_with(invalid_grant())
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let auth = adapter(&server).with_credential_store(store(&server));
+    assert!(auth.reload_credentials().await.unwrap());
+    assert!(!auth.reload_credentials().await.unwrap());
+
+    // The loaded version is current, so the rejection is final
+    assert!(auth.get_valid_token().await.is_err());
+}
+
+#[tokio::test]
+async fn rejected_password_without_store_fails() {
+    let server = MockServer::start().await;
+    mock_password_grant(&server, "old-password", invalid_grant()).await;
+
+    assert!(adapter(&server).get_valid_token().await.is_err());
+}
+
+#[tokio::test]
+async fn unreadable_secret_is_reported() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/secretsmanager/get"))
+        .respond_with(ResponseTemplate::new(500))
+        .mount(&server)
+        .await;
+
+    let auth = adapter(&server).with_credential_store(store(&server));
+
+    assert!(auth.reload_credentials().await.is_err());
+}
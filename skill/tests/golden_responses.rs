@@ -0,0 +1,183 @@
+//! Golden-file tests for the Alexa response wire format.
+//!
+//! Every request fixture in `tests/fixtures` (`*_request.json`,
+//! `*_event.json`) is run through the handler and the full response JSON is
+//! compared against `tests/fixtures/golden/<fixture>.json`.
+//!
+//! After an intended change to the responses, regenerate the expected files
+//! and review the diff:
+//!
+//! ```text
+//! UPDATE_GOLDEN=1 cargo test --test golden_responses
+//! ```
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde_json::Value;
+
+use alexa_cookidoo_skill::adapters::alexa::{AlexaSkillHandler, PhraseVariation};
+use alexa_cookidoo_skill::application::handle_payload;
+use alexa_cookidoo_skill::domain::models::{
+    AddedItem, DomainError, ShoppingListEntry, ShoppingListItem,
+};
+use alexa_cookidoo_skill::domain::ports::ShoppingListRepository;
+use alexa_cookidoo_skill::domain::services::{AddItemService, ReadListService, RemoveItemService};
+
+const FIXTURES: &str = "tests/fixtures";
+const GOLDEN: &str = "tests/fixtures/golden";
+
+/// Repository with a fixed list, so responses are the same on every run.
+struct FixedRepository;
+
+#[async_trait]
+impl ShoppingListRepository for FixedRepository {
+    async fn add_item(&self, item: &ShoppingListItem) -> Result<Vec<AddedItem>, DomainError> {
+        Ok(vec![AddedItem::new("id-new", item.name())])
+    }
+
+    async fn list_items(&self) -> Result<Vec<ShoppingListEntry>, DomainError> {
+        Ok(vec![
+            ShoppingListEntry::new("id-1", "Milch", false),
+            ShoppingListEntry::new("id-2", "Eier", true),
+            ShoppingListEntry::new("id-3", "Mehl", false),
+        ])
+    }
+
+    async fn remove_item(&self, _id: &str) -> Result<(), DomainError> {
+        Ok(())
+    }
+}
+
+fn create_handler() -> AlexaSkillHandler {
+    let repo: Arc<dyn ShoppingListRepository> = Arc::new(FixedRepository);
+    AlexaSkillHandler::new(
+        Arc::new(AddItemService::new(repo.clone())),
+        Arc::new(ReadListService::new(repo.clone())),
+        Arc::new(RemoveItemService::new(repo)),
+    )
+    .with_phrase_variation(PhraseVariation::Seeded(0))
+}
+
+/// Returns the request fixtures, sorted for a stable report.
+fn request_fixtures() -> Vec<PathBuf> {
+    let mut fixtures: Vec<PathBuf> = std::fs::read_dir(FIXTURES)
+        .expect("Failed to read fixtures directory")
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| {
+            let name = path.file_name().unwrap().to_string_lossy();
+            name.ends_with("_request.json") || name.ends_with("_event.json")
+        })
+        .collect();
+    fixtures.sort();
+    fixtures
+}
+
+fn read_json(path: &Path) -> Value {
+    let content = std::fs::read_to_string(path)
+        .unwrap_or_else(|_| panic!("Failed to read {}", path.display()));
+    serde_json::from_str(&content)
+        .unwrap_or_else(|e| panic!("Failed to parse {}: {}", path.display(), e))
+}
+
+fn write_json(path: &Path, value: &Value) {
+    let mut content = serde_json::to_string_pretty(value).unwrap();
+    content.push('\n');
+    std::fs::write(path, content)
+        .unwrap_or_else(|e| panic!("Failed to write {}: {}", path.display(), e));
+}
+
+/// Collects the JSON pointers at which `actual` differs from `expected`.
+fn differences(pointer: &str, expected: &Value, actual: &Value, found: &mut Vec<String>) {
+    match (expected, actual) {
+        (Value::Object(expected), Value::Object(actual)) => {
+            let mut keys: Vec<&String> = expected.keys().chain(actual.keys()).collect();
+            keys.sort();
+            keys.dedup();
+            for key in keys {
+                let pointer = format!("{}/{}", pointer, key);
+                match (expected.get(key), actual.get(key)) {
+                    (Some(expected), Some(actual)) => {
+                        differences(&pointer, expected, actual, found);
+                    }
+                    (Some(_), None) => found.push(format!("{}: missing", pointer)),
+                    (None, Some(actual)) => {
+                        found.push(format!("{}: unexpected {}", pointer, actual))
+                    }
+                    (None, None) => unreachable!(),
+                }
+            }
+        }
+        (Value::Array(expected_items), Value::Array(actual_items))
+            if expected_items.len() == actual_items.len() =>
+        {
+            for (index, (expected, actual)) in expected_items.iter().zip(actual_items).enumerate() {
+                differences(&format!("{}/{}", pointer, index), expected, actual, found);
+            }
+        }
+        _ if expected != actual => {
+            found.push(format!(
+                "{}: expected {}, got {}",
+                pointer, expected, actual
+            ));
+        }
+        _ => {}
+    }
+}
+
+#[tokio::test]
+async fn responses_match_golden_files() {
+    let update = std::env::var_os("UPDATE_GOLDEN").is_some();
+    let handler = create_handler();
+    let mut failures = Vec::new();
+
+    let fixtures = request_fixtures();
+    assert!(!fixtures.is_empty(), "No request fixtures found");
+
+    for fixture in fixtures {
+        let name = fixture.file_name().unwrap().to_string_lossy().into_owned();
+        let golden = Path::new(GOLDEN).join(&name);
+        let response = handle_payload(read_json(&fixture), &handler).await;
+
+        if update {
+            std::fs::create_dir_all(GOLDEN).unwrap();
+            write_json(&golden, &response);
+            continue;
+        }
+
+        if !golden.exists() {
+            failures.push(format!(
+                "{}: no golden file (run with UPDATE_GOLDEN=1)",
+                name
+            ));
+            continue;
+        }
+
+        let mut found = Vec::new();
+        differences("", &read_json(&golden), &response, &mut found);
+        failures.extend(found.into_iter().map(|diff| format!("{}: {}", name, diff)));
+    }
+
+    assert!(
+        failures.is_empty(),
+        "Responses differ from golden files (run with UPDATE_GOLDEN=1 to accept):\n{}",
+        failures.join("\n")
+    );
+}
+
+#[tokio::test]
+async fn golden_files_have_a_request_fixture() {
+    let Ok(entries) = std::fs::read_dir(GOLDEN) else {
+        return;
+    };
+
+    for entry in entries {
+        let name = entry.unwrap().file_name();
+        assert!(
+            Path::new(FIXTURES).join(&name).exists(),
+            "Stale golden file without fixture: {}",
+            name.to_string_lossy()
+        );
+    }
+}
@@ -0,0 +1,290 @@
+//! Integration tests for Alexa request signature verification and the
+//! self-hosted HTTP endpoint.
+//!
+//! The fixtures in `tests/fixtures/alexa_signature` were created with
+//! OpenSSL: a test root CA, a signing certificate for
+//! `echo-api.amazon.com` issued by it, a second one for another host, and
+//! signatures of `launch_request.json` by both certificates.
+#![cfg(feature = "http-server")]
+
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use tokio::net::TcpListener;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use alexa_cookidoo_skill::adapters::alexa::AlexaSkillHandler;
+use alexa_cookidoo_skill::adapters::http_server::{
+    serve, AlexaEndpoint, HomeAssistantApi, SignatureError, SignatureVerifier,
+    CERT_CHAIN_URL_HEADER, SIGNATURE_HEADER,
+};
+use alexa_cookidoo_skill::adapters::memory::InMemoryShoppingListRepository;
+use alexa_cookidoo_skill::domain::services::{AddItemService, ReadListService, RemoveItemService};
+
+const FIXTURES: &str = "tests/fixtures/alexa_signature";
+const CERT_URL: &str = "https://s3.amazonaws.com/echo.api/echo-api-cert.pem";
+const WRONG_SAN_CERT_URL: &str = "https://s3.amazonaws.com/echo.api/wrong-san-cert.pem";
+/// Application ID named by `launch_request.json`.
+const SKILL_ID: &str = "amzn1.ask.skill.test-skill";
+
+/// `request.timestamp` of `launch_request.json` (2024-01-27T10:00:00Z).
+fn request_time() -> SystemTime {
+    UNIX_EPOCH + Duration::from_secs(1_706_349_600)
+}
+
+fn fixture(name: &str) -> Vec<u8> {
+    std::fs::read(format!("{}/{}", FIXTURES, name)).unwrap()
+}
+
+fn body() -> Vec<u8> {
+    std::fs::read("tests/fixtures/launch_request.json").unwrap()
+}
+
+fn signature(name: &str) -> String {
+    String::from_utf8(fixture(name)).unwrap()
+}
+
+/// Serves both certificate chains under `/echo.api/`.
+async fn cert_server() -> MockServer {
+    let server = MockServer::start().await;
+    for name in ["echo-api-cert.pem", "wrong-san-cert.pem"] {
+        Mock::given(method("GET"))
+            .and(path(format!("/echo.api/{}", name)))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(fixture(name)))
+            .mount(&server)
+            .await;
+    }
+    server
+}
+
+fn verifier(server: &MockServer) -> SignatureVerifier {
+    SignatureVerifier::new()
+        .with_root_certificates(&fixture("test_root_ca.pem"))
+        .unwrap()
+        .with_cert_endpoint(server.uri())
+}
+
+#[tokio::test]
+async fn accepts_correctly_signed_request() {
+    let server = cert_server().await;
+
+    let result = verifier(&server)
+        .verify_at(
+            Some(CERT_URL),
+            Some(&signature("launch_request.sig")),
+            &body(),
+            request_time() + Duration::from_secs(30),
+        )
+        .await;
+
+    assert!(result.is_ok(), "{:?}", result);
+}
+
+#[tokio::test]
+async fn rejects_tampered_body() {
+    let server = cert_server().await;
+    let tampered = String::from_utf8(body())
+        .unwrap()
+        .replace("LaunchRequest", "IntentRequest");
+
+    let result = verifier(&server)
+        .verify_at(
+            Some(CERT_URL),
+            Some(&signature("launch_request.sig")),
+            tampered.as_bytes(),
+            request_time(),
+        )
+        .await;
+
+    assert!(matches!(result, Err(SignatureError::InvalidSignature)));
+}
+
+#[tokio::test]
+async fn rejects_certificate_for_other_host() {
+    let server = cert_server().await;
+
+    let result = verifier(&server)
+        .verify_at(
+            Some(WRONG_SAN_CERT_URL),
+            Some(&signature("launch_request_wrong_san.sig")),
+            &body(),
+            request_time(),
+        )
+        .await;
+
+    assert!(matches!(result, Err(SignatureError::InvalidCertificate(_))));
+}
+
+#[tokio::test]
+async fn rejects_certificate_from_untrusted_root() {
+    let server = cert_server().await;
+    let verifier = SignatureVerifier::new().with_cert_endpoint(server.uri());
+
+    let result = verifier
+        .verify_at(
+            Some(CERT_URL),
+            Some(&signature("launch_request.sig")),
+            &body(),
+            request_time(),
+        )
+        .await;
+
+    assert!(matches!(result, Err(SignatureError::InvalidCertificate(_))));
+}
+
+#[tokio::test]
+async fn rejects_stale_request() {
+    let server = cert_server().await;
+
+    let result = verifier(&server)
+        .verify_at(
+            Some(CERT_URL),
+            Some(&signature("launch_request.sig")),
+            &body(),
+            request_time() + Duration::from_secs(3600),
+        )
+        .await;
+
+    assert!(matches!(result, Err(SignatureError::InvalidTimestamp(_))));
+}
+
+#[tokio::test]
+async fn downloads_certificate_chain_once() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/echo.api/echo-api-cert.pem"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(fixture("echo-api-cert.pem")))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let verifier = verifier(&server);
+
+    for _ in 0..2 {
+        verifier
+            .verify_at(
+                Some(CERT_URL),
+                Some(&signature("launch_request.sig")),
+                &body(),
+                request_time(),
+            )
+            .await
+            .unwrap();
+    }
+}
+
+/// Starts the Alexa endpoint over plain HTTP and returns its base URL.
+async fn start_endpoint() -> String {
+    let repo = Arc::new(InMemoryShoppingListRepository::new());
+    let handler = Arc::new(AlexaSkillHandler::new(
+        Arc::new(AddItemService::new(repo.clone())),
+        Arc::new(ReadListService::new(repo.clone())),
+        Arc::new(RemoveItemService::new(repo)),
+    ));
+    let endpoint = AlexaEndpoint::new(handler, SignatureVerifier::new(), SKILL_ID);
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    tokio::spawn(serve(listener, None, endpoint.router()));
+    url
+}
+
+#[tokio::test]
+async fn endpoint_rejects_unverified_requests() {
+    let url = start_endpoint().await;
+    let client = reqwest::Client::new();
+
+    let unsigned = client
+        .post(format!("{}/alexa", url))
+        .body(body())
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(unsigned.status(), 400);
+
+    let foreign_cert = client
+        .post(&url)
+        .header(
+            CERT_CHAIN_URL_HEADER,
+            "https://example.com/echo.api/cert.pem",
+        )
+        .header(SIGNATURE_HEADER, signature("launch_request.sig"))
+        .body(body())
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(foreign_cert.status(), 400);
+}
+
+#[tokio::test]
+async fn endpoint_rejects_requests_for_other_skills() {
+    let url = start_endpoint().await;
+    let client = reqwest::Client::new();
+    let launch = String::from_utf8(body()).unwrap();
+
+    let foreign_skill = client
+        .post(&url)
+        .header(CERT_CHAIN_URL_HEADER, CERT_URL)
+        .header(SIGNATURE_HEADER, signature("launch_request.sig"))
+        .body(launch.replace(SKILL_ID, "amzn1.ask.skill.other-skill"))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(foreign_skill.status(), 403);
+
+    let mut anonymous: serde_json::Value = serde_json::from_str(&launch).unwrap();
+    anonymous["session"]
+        .as_object_mut()
+        .unwrap()
+        .remove("application");
+    let without_skill = client.post(&url).json(&anonymous).send().await.unwrap();
+    assert_eq!(without_skill.status(), 403);
+}
+
+#[tokio::test]
+async fn endpoint_routes_only_alexa_posts() {
+    let url = start_endpoint().await;
+    let client = reqwest::Client::new();
+
+    let get = client.get(format!("{}/alexa", url)).send().await.unwrap();
+    assert_eq!(get.status(), 405);
+
+    let unknown = client.post(format!("{}/items", url)).send().await.unwrap();
+    assert_eq!(unknown.status(), 404);
+}
+
+#[tokio::test]
+async fn home_assistant_adds_items_with_bearer_token() {
+    let repo = Arc::new(InMemoryShoppingListRepository::new());
+    let add = Arc::new(AddItemService::new(repo.clone()));
+    let handler = Arc::new(AlexaSkillHandler::new(
+        add.clone(),
+        Arc::new(ReadListService::new(repo.clone())),
+        Arc::new(RemoveItemService::new(repo.clone())),
+    ));
+    let endpoint = AlexaEndpoint::new(handler, SignatureVerifier::new(), SKILL_ID)
+        .with_home_assistant_api(HomeAssistantApi::new(add, "ha-token").unwrap());
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/api/items", listener.local_addr().unwrap());
+    tokio::spawn(serve(listener, None, endpoint.router()));
+    let client = reqwest::Client::new();
+
+    let unauthorized = client
+        .post(&url)
+        .json(&serde_json::json!({"name": "Milch"}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(unauthorized.status(), 401);
+
+    let created = client
+        .post(&url)
+        .bearer_auth("ha-token")
+        .json(&serde_json::json!({"name": "Milch"}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(created.status(), 201);
+    assert_eq!(repo.items()[0].name(), "Milch");
+}
@@ -0,0 +1,152 @@
+//! Property tests for item name validation, sanitization, normalization
+//! and intent parsing with arbitrary Unicode input.
+//!
+//! Each property runs against a few thousand generated names. A failing
+//! case is shrunk to a minimal input and saved under
+//! `proptest-regressions/`, so it is replayed first on later runs;
+//! set `PROPTEST_CASES` to run more cases.
+
+use proptest::prelude::*;
+use proptest::sample::select;
+use unicode_segmentation::UnicodeSegmentation;
+
+use alexa_cookidoo_skill::adapters::alexa::{parse_intent, AlexaRequest, ParsedIntent};
+use alexa_cookidoo_skill::domain::models::ShoppingListItem;
+use alexa_cookidoo_skill::domain::services::ItemNameNormalizer;
+
+/// Generated cases per property.
+const CASES: u32 = 2000;
+
+/// Item name limit, in user-perceived characters.
+const MAX_ITEM_NAME_LENGTH: usize = 200;
+
+/// Characters that need care: multi-byte letters, Unicode whitespace,
+/// combining marks, emoji with modifiers and joiners, and case mappings
+/// that change length ("ß" -> "SS").
+const TRICKY_CHARS: &[char] = &[
+    'ä', 'ö', 'ü', 'Ä', 'ß', 'ẞ', 'é', 'ǅ', 'İ', 'ﬀ', ' ', '\t', '\n', '\u{a0}', '\u{2009}',
+    '\u{3000}', '\u{200b}', '\u{200d}', '\u{0301}', '\u{0308}', '\u{fe0f}', '🥛', '🧀', '👍', '🏽',
+    '-', '\'', '"', '\\', '0', '9',
+];
+
+/// Mixes ASCII letters, tricky characters, spaces and arbitrary Unicode.
+fn name_char() -> impl Strategy<Value = char> {
+    prop_oneof![
+        (b'a'..=b'z').prop_map(char::from),
+        select(TRICKY_CHARS),
+        Just(' '),
+        any::<char>(),
+    ]
+}
+
+/// Returns strings of up to `max_len` characters.
+fn name(max_len: usize) -> impl Strategy<Value = String> {
+    proptest::collection::vec(name_char(), 0..=max_len)
+        .prop_map(|chars| chars.into_iter().collect())
+}
+
+fn add_item_request(item: &str, locale: &str) -> AlexaRequest {
+    serde_json::from_value(serde_json::json!({
+        "version": "1.0",
+        "request": {
+            "type": "IntentRequest",
+            "requestId": "amzn1.echo-api.request.property",
+            "timestamp": "2024-01-27T10:00:00Z",
+            "locale": locale,
+            "intent": {
+                "name": "AddItemIntent",
+                "slots": { "Item": { "name": "Item", "value": item } }
+            }
+        }
+    }))
+    .unwrap()
+}
+
+/// Returns true if `name` contains nothing that should reach Cookidoo:
+/// no control characters, no invisible formatting, no stray whitespace.
+fn is_clean(name: &str) -> bool {
+    !name.is_empty()
+        && name.trim() == name
+        && !name.contains("  ")
+        && name.chars().all(|c| {
+            !c.is_control()
+                && !matches!(c, '\u{200b}' | '\u{feff}' | '\u{00ad}')
+                && (c == ' ' || !c.is_whitespace())
+        })
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(CASES))]
+
+    #[test]
+    fn item_validation_cleans_and_limits_characters(input in name(260)) {
+        // Combining marks and emoji sequences count once
+        let sanitized = ShoppingListItem::sanitize(&input);
+        let valid =
+            !sanitized.is_empty() && sanitized.graphemes(true).count() <= MAX_ITEM_NAME_LENGTH;
+
+        match ShoppingListItem::new(&input) {
+            Ok(item) => {
+                prop_assert!(valid, "accepted invalid name {:?}", item.name());
+                prop_assert!(is_clean(item.name()), "unclean name {:?}", item.name());
+                prop_assert_eq!(
+                    ShoppingListItem::new(item.name()).ok(),
+                    Some(item),
+                    "validation is not idempotent"
+                );
+            }
+            Err(e) => prop_assert!(!valid, "rejected valid name: {}", e),
+        }
+    }
+
+    #[test]
+    fn normalizer_output_is_clean(
+        input in name(80),
+        locale in select(&["de-DE", "de-AT", "en-US", "fr-FR", ""][..]),
+    ) {
+        let normalized = ItemNameNormalizer::normalize(&input, locale);
+        let words = ShoppingListItem::sanitize(&input).split_whitespace().count();
+
+        prop_assert!(
+            normalized.is_empty() || is_clean(&normalized),
+            "unclean output {:?}",
+            normalized
+        );
+        // Only a leading German article may be dropped
+        let dropped = words - normalized.split_whitespace().count();
+        prop_assert!(
+            dropped <= usize::from(locale.starts_with("de")),
+            "words lost: {:?}",
+            normalized
+        );
+    }
+
+    #[test]
+    fn parser_yields_clean_item_names(input in name(80)) {
+        match parse_intent(&add_item_request(&input, "de-DE")) {
+            ParsedIntent::AddItem { item_name, .. } => {
+                prop_assert!(is_clean(&item_name), "unclean item name {:?}", item_name)
+            }
+            ParsedIntent::Unknown => prop_assert!(
+                ShoppingListItem::sanitize(&input).is_empty(),
+                "rejected {:?}",
+                input
+            ),
+            other => prop_assert!(false, "unexpected intent {:?}", other),
+        }
+    }
+}
+
+#[test]
+fn multi_byte_names_up_to_limit_are_accepted() {
+    for c in ["ä", "ß", "€", "🥛", "a\u{0308}", "👍🏽"] {
+        let name = c.repeat(MAX_ITEM_NAME_LENGTH);
+        assert!(
+            ShoppingListItem::new(&name).is_ok(),
+            "{} x {:?} rejected",
+            MAX_ITEM_NAME_LENGTH,
+            c
+        );
+        assert!(ShoppingListItem::new(format!("{}{}", name, c)).is_err());
+    }
+}
@@ -0,0 +1,143 @@
+//! Latency regression check for the full request path against a mocked
+//! Cookidoo.
+//!
+//! Reads a large shopping list through the Lambda payload handler and the
+//! Cookidoo adapter many times and compares the 95th percentile with the
+//! baseline in `tests/fixtures/latency_baseline.json`, failing if it got
+//! more than [`TOLERANCE`] slower. Timings depend on the machine and the
+//! build, so the check is ignored by `cargo test`; run it on the machine
+//! that recorded the baseline:
+//!
+//! ```text
+//! cargo test --release --test latency_integration -- --ignored
+//! UPDATE_LATENCY_BASELINE=1 cargo test --release --test latency_integration -- --ignored
+//! ```
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use alexa_cookidoo_skill::adapters::alexa::AlexaSkillHandler;
+use alexa_cookidoo_skill::adapters::cookidoo::{
+    CookidooAuthAdapter, CookidooClient, CookidooShoppingListAdapter,
+};
+use alexa_cookidoo_skill::application::handle_payload;
+use alexa_cookidoo_skill::domain::models::CookidooCredentials;
+use alexa_cookidoo_skill::domain::services::{AddItemService, ReadListService, RemoveItemService};
+
+/// File holding the recorded 95th percentile.
+const BASELINE: &str = "tests/fixtures/latency_baseline.json";
+
+/// How much slower than the baseline the 95th percentile may get.
+const TOLERANCE: f64 = 0.5;
+
+/// Measured requests after the warm-up request.
+const SAMPLES: usize = 200;
+
+fn load_fixture(name: &str) -> String {
+    let path = format!("tests/fixtures/{}", name);
+    std::fs::read_to_string(&path).unwrap_or_else(|_| panic!("Failed to read fixture: {}", path))
+}
+
+async fn cookidoo_handler(mock_server: &MockServer) -> AlexaSkillHandler {
+    Mock::given(method("POST"))
+        .and(path("/ciam/auth/token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "access_token": "test-access-token",
+            "refresh_token": "test-refresh-token",
+            "expires_in": 3600,
+            "token_type": "Bearer"
+        })))
+        .mount(mock_server)
+        .await;
+
+    let list: serde_json::Value =
+        serde_json::from_str(&load_fixture("cookidoo_large_list.json")).unwrap();
+    Mock::given(method("GET"))
+        .and(path("/shopping/de-DE/additional-items"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(list))
+        .mount(mock_server)
+        .await;
+
+    let client = CookidooClient::with_base_url(mock_server.uri());
+    let auth = Arc::new(CookidooAuthAdapter::new(
+        client.clone(),
+        CookidooCredentials::new("test@example.com", "testpassword"),
+        "client_id",
+        "client_secret",
+    ));
+    let repository = Arc::new(CookidooShoppingListAdapter::new(client, auth));
+
+    AlexaSkillHandler::new(
+        Arc::new(AddItemService::new(repository.clone())),
+        Arc::new(ReadListService::new(repository.clone())),
+        Arc::new(RemoveItemService::new(repository)),
+    )
+}
+
+/// Reads the recorded 95th percentile.
+fn baseline() -> Duration {
+    let baseline: serde_json::Value = serde_json::from_str(
+        &std::fs::read_to_string(BASELINE)
+            .unwrap_or_else(|_| panic!("No baseline in {BASELINE}; record one first")),
+    )
+    .unwrap();
+    Duration::from_micros(baseline["p95_us"].as_u64().expect("p95_us in baseline"))
+}
+
+#[tokio::test]
+#[ignore = "timings depend on the machine; run with --release --ignored"]
+async fn read_large_list_does_not_regress() {
+    let mock_server = MockServer::start().await;
+    let handler = cookidoo_handler(&mock_server).await;
+    let request = load_fixture("large_read_list_request.json");
+
+    // Warm-up: fetches the token and opens the connection
+    let response = handle_payload(serde_json::from_str(&request).unwrap(), &handler).await;
+    let speech = response["response"]["outputSpeech"]["text"]
+        .as_str()
+        .unwrap_or_default()
+        .to_string();
+    assert!(
+        speech.contains("Milch"),
+        "unexpected response: {}",
+        response
+    );
+
+    let mut latencies = Vec::with_capacity(SAMPLES);
+    for _ in 0..SAMPLES {
+        let payload = serde_json::from_str(&request).unwrap();
+        let start = Instant::now();
+        handle_payload(payload, &handler).await;
+        latencies.push(start.elapsed());
+    }
+    latencies.sort();
+    let p95 = latencies[SAMPLES * 95 / 100 - 1];
+    let median = latencies[SAMPLES / 2];
+
+    if std::env::var_os("UPDATE_LATENCY_BASELINE").is_some() {
+        let baseline = serde_json::json!({
+            "p95_us": p95.as_micros() as u64,
+            "median_us": median.as_micros() as u64,
+        });
+        std::fs::write(
+            BASELINE,
+            serde_json::to_string_pretty(&baseline).unwrap() + "\n",
+        )
+        .unwrap();
+        return;
+    }
+
+    let baseline = baseline();
+    let limit = baseline.mul_f64(1.0 + TOLERANCE);
+    assert!(
+        p95 <= limit,
+        "p95 latency {:?} regressed past {:?} (baseline {:?}, median {:?})",
+        p95,
+        limit,
+        baseline,
+        median
+    );
+}
@@ -0,0 +1,72 @@
+//! Integration tests for the SSM maintenance flag using wiremock.
+
+use std::sync::Arc;
+
+use wiremock::matchers::{header, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use alexa_cookidoo_skill::adapters::maintenance::{CachedMaintenanceFlag, SsmMaintenanceFlag};
+use alexa_cookidoo_skill::domain::ports::MaintenanceFlag;
+
+const PARAMETER: &str = "/cookidoo-skill/maintenance";
+
+async fn mock_parameter(server: &MockServer, value: &str) {
+    Mock::given(method("GET"))
+        .and(path("/systemsmanager/parameters/get"))
+        .and(query_param("name", PARAMETER))
+        .and(header("X-Aws-Parameters-Secrets-Token", "session-token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "Parameter": {"Name": PARAMETER, "Type": "String", "Value": value, "Version": 3}
+        })))
+        .mount(server)
+        .await;
+}
+
+fn flag(server: &MockServer) -> SsmMaintenanceFlag {
+    SsmMaintenanceFlag::with_endpoint(server.uri(), PARAMETER, "session-token")
+}
+
+#[tokio::test]
+async fn reads_active_flag_from_ssm() {
+    let server = MockServer::start().await;
+    mock_parameter(&server, "true").await;
+
+    assert!(flag(&server).is_active().await.unwrap());
+}
+
+#[tokio::test]
+async fn other_values_disable_the_flag() {
+    let server = MockServer::start().await;
+    mock_parameter(&server, "off").await;
+
+    assert!(!flag(&server).is_active().await.unwrap());
+}
+
+#[tokio::test]
+async fn missing_parameter_is_an_error() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(400).set_body_string("ParameterNotFound"))
+        .mount(&server)
+        .await;
+
+    assert!(flag(&server).is_active().await.is_err());
+}
+
+#[tokio::test]
+async fn cached_flag_reads_ssm_once() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/systemsmanager/parameters/get"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "Parameter": {"Value": "true"}
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let cached = CachedMaintenanceFlag::new(Arc::new(flag(&server)));
+
+    assert!(cached.is_active().await.unwrap());
+    assert!(cached.is_active().await.unwrap());
+}
@@ -0,0 +1,93 @@
+//! Integration tests for serve mode over a real socket.
+#![cfg(feature = "rest-api")]
+
+use std::sync::Arc;
+
+use tokio::net::TcpListener;
+
+use alexa_cookidoo_skill::adapters::alexa::AlexaSkillHandler;
+use alexa_cookidoo_skill::adapters::memory::InMemoryShoppingListRepository;
+use alexa_cookidoo_skill::adapters::rest::RestApi;
+use alexa_cookidoo_skill::adapters::web::serve;
+use alexa_cookidoo_skill::domain::services::{AddItemService, ReadListService, RemoveItemService};
+
+/// Starts the REST API on an ephemeral port and returns its base URL.
+async fn start_server() -> String {
+    let repo = Arc::new(InMemoryShoppingListRepository::new());
+    let add = Arc::new(AddItemService::new(repo.clone()));
+    let read = Arc::new(ReadListService::new(repo.clone()));
+    let remove = Arc::new(RemoveItemService::new(repo));
+    let handler = Arc::new(AlexaSkillHandler::new(
+        add.clone(),
+        read.clone(),
+        remove.clone(),
+    ));
+    let api = Arc::new(RestApi::new(add, read, remove, handler));
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    tokio::spawn(serve(listener, api.router()));
+    url
+}
+
+#[tokio::test]
+async fn voice_and_rest_share_the_same_list() {
+    let url = start_server().await;
+    let client = reqwest::Client::new();
+
+    let fixture = std::fs::read_to_string("tests/fixtures/add_item_request.json").unwrap();
+    let alexa: serde_json::Value = client
+        .post(format!("{}/alexa", url))
+        .body(fixture)
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(alexa["response"]["shouldEndSession"], true);
+
+    let added = client
+        .post(format!("{}/items", url))
+        .json(&serde_json::json!({"name": "Eier"}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(added.status(), 201);
+
+    let items: Vec<serde_json::Value> = client
+        .get(format!("{}/items", url))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(items.len(), 2);
+    assert_eq!(items[1]["name"], "Eier");
+
+    let removed = client
+        .delete(format!(
+            "{}/items/{}",
+            url,
+            items[0]["id"].as_str().unwrap()
+        ))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(removed.status(), 200);
+}
+
+#[tokio::test]
+async fn rejects_oversized_bodies() {
+    let url = start_server().await;
+
+    let response = reqwest::Client::new()
+        .post(format!("{}/items", url))
+        .body(vec![b'x'; 128 * 1024])
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), 413);
+}